@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS match_histories;
+DROP TABLE IF EXISTS account_ratings;
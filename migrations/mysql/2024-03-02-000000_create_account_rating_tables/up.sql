@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS account_ratings (
+    account_id INT PRIMARY KEY NOT NULL,
+    rating INT NOT NULL DEFAULT 1000,
+    win_count INT NOT NULL DEFAULT 0,
+    lose_count INT NOT NULL DEFAULT 0,
+    draw_count INT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS match_histories (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    account_id INT NOT NULL,
+    opponent_account_id INT NOT NULL,
+    finish_position INT NOT NULL,
+    rating_before INT NOT NULL,
+    rating_after INT NOT NULL,
+    finished_at BIGINT NOT NULL,
+    INDEX match_histories_account_id_index (account_id)
+);
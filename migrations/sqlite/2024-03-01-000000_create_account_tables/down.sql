@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS deck_cards;
+DROP TABLE IF EXISTS account_decks;
+DROP TABLE IF EXISTS account_cards;
+DROP TABLE IF EXISTS account_points;
+DROP TABLE IF EXISTS accounts;
@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS accounts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL UNIQUE,
+    password TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS account_points (
+    account_id INTEGER PRIMARY KEY NOT NULL,
+    gold INTEGER NOT NULL DEFAULT 0,
+    event_check INTEGER NOT NULL DEFAULT 1,
+    free_gacha_check DATE NOT NULL DEFAULT '2000-01-01'
+);
+
+CREATE TABLE IF NOT EXISTS account_cards (
+    account_id INTEGER NOT NULL,
+    card_id INTEGER NOT NULL,
+    card_count INTEGER NOT NULL,
+    PRIMARY KEY (account_id, card_id)
+);
+
+CREATE TABLE IF NOT EXISTS account_decks (
+    deck_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id INTEGER NOT NULL,
+    deck_name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS deck_cards (
+    deck_id INTEGER NOT NULL,
+    card_id INTEGER NOT NULL,
+    card_count INTEGER NOT NULL,
+    PRIMARY KEY (deck_id, card_id)
+);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS match_histories_account_id_index;
+DROP TABLE IF EXISTS match_histories;
+DROP TABLE IF EXISTS account_ratings;
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS account_ratings (
+    account_id INTEGER PRIMARY KEY NOT NULL,
+    rating INTEGER NOT NULL DEFAULT 1000,
+    win_count INTEGER NOT NULL DEFAULT 0,
+    lose_count INTEGER NOT NULL DEFAULT 0,
+    draw_count INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS match_histories (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id INTEGER NOT NULL,
+    opponent_account_id INTEGER NOT NULL,
+    finish_position INTEGER NOT NULL,
+    rating_before INTEGER NOT NULL,
+    rating_after INTEGER NOT NULL,
+    finished_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS match_histories_account_id_index ON match_histories (account_id);
@@ -1,3 +1,4 @@
 pub mod service;
 pub mod repository;
-pub mod entity;
\ No newline at end of file
+pub mod entity;
+pub mod protocol;
@@ -0,0 +1,62 @@
+use crate::account::service::account_service::AccountService;
+use crate::account::service::account_service_impl::AccountServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_request_generator::{create_account_delete_request, create_account_modify_request, create_login_request, create_logout_request, create_register_request};
+use crate::request_generator::session_request_generator::create_session_login_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Account Register
+    protocol_registry.register(1, "ACCOUNT_REGISTER", create_register_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_register(request).await;
+        ResponseType::ACCOUNT_REGISTER(response)
+    });
+
+    // Account Login
+    protocol_registry.register(2, "ACCOUNT_LOGIN", create_login_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_login(request).await;
+        ResponseType::ACCOUNT_LOGIN(response)
+    });
+
+    // Account Session Login
+    protocol_registry.register(3, "ACCOUNT_SESSION_LOGIN", create_session_login_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_session_login(request).await;
+        ResponseType::ACCOUNT_LOGIN(response)
+    });
+
+    // Account Logout
+    protocol_registry.register(4, "ACCOUNT_LOGOUT", create_logout_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_logout(request).await;
+        ResponseType::ACCOUNT_LOGOUT(response)
+    });
+
+    // Account Modify
+    protocol_registry.register(5, "ACCOUNT_MODIFY", create_account_modify_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_modify(request).await;
+        ResponseType::ACCOUNT_MODIFY(response)
+    });
+
+    // Account Delete
+    protocol_registry.register(6, "ACCOUNT_DELETE", create_account_delete_request, |request| async move {
+        let account_service_mutex = AccountServiceImpl::get_instance();
+        let account_service = account_service_mutex.lock().await;
+
+        let response = account_service.account_delete(request).await;
+        ResponseType::ACCOUNT_DELETE(response)
+    });
+}
@@ -6,7 +6,7 @@ use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
 
 use diesel::query_dsl::methods::{FilterDsl, FindDsl};
-use diesel::{Connection, MysqlConnection, QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension};
+use diesel::{QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension};
 
 use diesel::dsl::Eq;
 use diesel::sql_types::Text;
@@ -15,26 +15,19 @@ use crate::account::entity::account::Account;
 use crate::account::entity::account::accounts::columns;
 use crate::account::entity::account::accounts::dsl::accounts;
 use crate::account::repository::account_repository::AccountRepository;
-use crate::common::env::env_detector::EnvDetector;
-use crate::mysql_config::mysql_connection::MysqlDatabaseConnection;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
 
-pub struct AccountRepositoryImpl {
-    mysql_database_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>,
-}
+pub struct AccountRepositoryImpl;
 
 impl AccountRepositoryImpl {
-    pub fn new(mysql_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>) -> Self {
-        AccountRepositoryImpl {
-            mysql_database_connection: mysql_connection
-        }
+    pub fn new() -> Self {
+        AccountRepositoryImpl
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<AccountRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<AccountRepositoryImpl>> =
-                Arc::new(AsyncMutex::new(AccountRepositoryImpl::new(
-                    MysqlDatabaseConnection::get_instance()
-            )));
+                Arc::new(AsyncMutex::new(AccountRepositoryImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -48,13 +41,12 @@ impl AccountRepository for AccountRepositoryImpl {
 
         println!("AccountRepositoryImpl: save()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::insert_into(accounts)
-            .values(&account)
-            .execute(&mut connection)
+        // id 는 auto increment 에 맡김 (SQLite 는 0 을 그대로 저장함)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(accounts)
+                .values((columns::user_id.eq(account.user_id()), columns::password.eq(account.password())))
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account saved successfully.");
@@ -75,15 +67,12 @@ impl AccountRepository for AccountRepositoryImpl {
 
         println!("AccountRepositoryImpl: find_by_user_id()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        let select_clause = accounts.select((columns::id, columns::user_id, columns::password));
-        let where_clause = FilterDsl::filter(accounts, columns::user_id.eq(user_id));
-        let found_accounts = where_clause
-            .select((columns::id, columns::user_id, columns::password))
-            .load::<Account>(&mut connection)?;
+        let found_accounts = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(accounts, columns::user_id.eq(user_id));
+            where_clause
+                .select((columns::id, columns::user_id, columns::password))
+                .load::<Account>(connection)
+        }).await?;
 
         let found_account = found_accounts
             .into_iter()
@@ -97,13 +86,10 @@ impl AccountRepository for AccountRepositoryImpl {
 
         println!("AccountRepositoryImpl: delete()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::delete(FilterDsl::filter(accounts, columns::user_id.eq(account.user_id())))
-
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(FilterDsl::filter(accounts, columns::user_id.eq(account.user_id())))
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account deleted successfully.");
@@ -120,17 +106,18 @@ impl AccountRepository for AccountRepositoryImpl {
 
         println!("AccountRepositoryImpl: update()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-        // 넘겨 받은 새로운 비밀번호를 암호화 합니다.
-        let hashed_password = hash(account_new_password, 12).expect("hashed_password fail");
-
-        match diesel::update(FilterDsl::filter(accounts, columns::user_id.eq(account.user_id)))
-            .set((
-                columns::password.eq(hashed_password),
-            ))
-            .execute(&mut connection)
+        // 넘겨 받은 새로운 비밀번호를 암호화 합니다. (bcrypt 도 CPU 를 오래 쓰므로 같이 blocking 스레드에서 처리)
+        let account_new_password = account_new_password.to_string();
+
+        match StorageConnectionPool::run_blocking(move |connection| {
+            let hashed_password = hash(account_new_password, 12).expect("hashed_password fail");
+
+            diesel::update(FilterDsl::filter(accounts, columns::user_id.eq(account.user_id)))
+                .set((
+                    columns::password.eq(hashed_password),
+                ))
+                .execute(connection)
+        }).await
         {
             Ok(num) => {
                 println!("Account updated successfully.");
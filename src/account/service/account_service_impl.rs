@@ -10,6 +10,9 @@ use crate::account::repository::account_repository::AccountRepository;
 use crate::account::repository::account_repository_impl::AccountRepositoryImpl;
 use crate::account_point::repository::account_point_repository::AccountPointRepository;
 use crate::account_point::repository::account_point_repository_impl::AccountPointRepositoryImpl;
+use crate::account_rating::entity::account_rating::AccountRating;
+use crate::account_rating::repository::account_rating_repository::AccountRatingRepository;
+use crate::account_rating::repository::account_rating_repository_impl::AccountRatingRepositoryImpl;
 
 use crate::account::service::account_service::AccountService;
 
@@ -46,6 +49,7 @@ use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepo
 pub struct AccountServiceImpl {
     repository: Arc<AsyncMutex<AccountRepositoryImpl>>,
     account_point_repository: Arc<AsyncMutex<AccountPointRepositoryImpl>>,
+    account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
     account_card_repository: Arc<AsyncMutex<AccountCardRepositoryImpl>>,
     account_deck_repository: Arc<AsyncMutex<AccountDeckRepositoryImpl>>,
@@ -55,6 +59,7 @@ pub struct AccountServiceImpl {
 impl AccountServiceImpl {
     pub fn new(repository: Arc<AsyncMutex<AccountRepositoryImpl>>,
                account_point_repository: Arc<AsyncMutex<AccountPointRepositoryImpl>>,
+               account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
                redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
                account_card_repository: Arc<AsyncMutex<AccountCardRepositoryImpl>>,
                account_deck_repository: Arc<AsyncMutex<AccountDeckRepositoryImpl>>,
@@ -63,6 +68,7 @@ impl AccountServiceImpl {
         AccountServiceImpl {
             repository,
             account_point_repository,
+            account_rating_repository,
             redis_in_memory_repository,
             account_card_repository,
             account_deck_repository,
@@ -78,6 +84,7 @@ impl AccountServiceImpl {
                         AccountServiceImpl::new(
                             AccountRepositoryImpl::get_instance(),
                             AccountPointRepositoryImpl::get_instance(),
+                            AccountRatingRepositoryImpl::get_instance(),
                             RedisInMemoryRepositoryImpl::get_instance(),
                             AccountCardRepositoryImpl::get_instance(),
                             AccountDeckRepositoryImpl::get_instance(),
@@ -111,6 +118,11 @@ impl AccountService for AccountServiceImpl {
         drop(account_point_repository);
         drop(account_repository);
 
+        // 매칭에 사용할 레이팅 정보를 기본값으로 생성
+        let account_rating_repository = self.account_rating_repository.lock().await;
+        let result_account_rating = account_rating_repository.save_account_rating(AccountRating::new(found_account_id)).await;
+        drop(account_rating_repository);
+
         // 배포카드 id
         let distribute_cards = vec![19,8,8,8,9,9,25,25,25,27,27,27,151,20,20,20,2,2,2,26,26,26,
                                     30,31,31,31,32,32,32,33,33,35,35,36,36,93,93,93,93,93];
@@ -146,7 +158,7 @@ impl AccountService for AccountServiceImpl {
         account_deck_card_repository.save_deck_card_list(deck_card_list).await.unwrap();
         drop(account_deck_card_repository);
 
-        if result_account.is_ok() && result_account_point.is_ok() {
+        if result_account.is_ok() && result_account_point.is_ok() && result_account_rating.is_ok() {
             return AccountRegisterResponse::new(true)
         }
         return AccountRegisterResponse::new(false)
@@ -262,6 +274,10 @@ impl AccountService for AccountServiceImpl {
                 let _ = account_point_repository_guard.delete_account_points(account_unique_id).await;
                 drop(account_point_repository_guard);
 
+                let account_rating_repository_guard = self.account_rating_repository.lock().await;
+                let _ = account_rating_repository_guard.delete_account_rating(account_unique_id).await;
+                drop(account_rating_repository_guard);
+
                 let mut redis_repository_guard = self.redis_in_memory_repository.lock().await;
                 let _ = redis_repository_guard.del(account_delete_request.session_id()).await;
                 drop(redis_repository_guard);
@@ -1,3 +1,4 @@
 pub mod entity;
 pub mod service;
-pub mod repository;
\ No newline at end of file
+pub mod repository;
+pub mod protocol;
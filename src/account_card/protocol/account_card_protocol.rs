@@ -0,0 +1,16 @@
+use crate::account_card::service::account_card_service::AccountCardService;
+use crate::account_card::service::account_card_service_impl::AccountCardServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_card_request_generator::create_account_card_list_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_card_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Account Card List
+    protocol_registry.register(31, "ACCOUNT_CARD_LIST", create_account_card_list_request, |request| async move {
+        let account_card_service_mutex = AccountCardServiceImpl::get_instance();
+        let account_card_service_mutex_guard = account_card_service_mutex.lock().await;
+
+        let response = account_card_service_mutex_guard.account_card_list(request).await;
+        ResponseType::ACCOUNT_CARD_LIST(response)
+    });
+}
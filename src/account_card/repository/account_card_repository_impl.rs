@@ -5,14 +5,13 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 use diesel::query_dsl::methods::{FilterDsl, FindDsl};
-use diesel::{Connection, MysqlConnection, QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension, Insertable};
+use diesel::{QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension, Insertable};
 use diesel::associations::HasTable;
 use diesel::result::Error;
 
-use crate::common::env::env_detector::EnvDetector;
 use crate::account_card::entity::account_card::account_cards::{account_id, columns};
 use crate::account_card::entity::account_card::account_cards::dsl::account_cards;
-use crate::mysql_config::mysql_connection::MysqlDatabaseConnection;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
 
 use crate::account_card::entity::card::Card;
 use crate::account_card::entity::account_card::AccountCard;
@@ -20,23 +19,17 @@ use crate::account_card::entity::account_card_list::AccountCardList;
 use crate::account_card::repository::account_card_repository::AccountCardRepository;
 
 
-pub struct AccountCardRepositoryImpl {
-    mysql_database_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>,
-}
+pub struct AccountCardRepositoryImpl;
 
 impl AccountCardRepositoryImpl {
-    pub fn new(mysql_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>) -> Self {
-        AccountCardRepositoryImpl {
-            mysql_database_connection: mysql_connection
-        }
+    pub fn new() -> Self {
+        AccountCardRepositoryImpl
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<AccountCardRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<AccountCardRepositoryImpl>> =
-                Arc::new(AsyncMutex::new(AccountCardRepositoryImpl::new(
-                    MysqlDatabaseConnection::get_instance()
-            )));
+                Arc::new(AsyncMutex::new(AccountCardRepositoryImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -50,16 +43,14 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
 
         println!("AccountCardRepositoryImpl: get_card_list()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let mut card_list: Vec<HashMap<i32, i32>> = Vec::new();
 
-        let where_clause = FilterDsl::filter(account_cards, account_id.eq(account_id));
-        let found_cards = where_clause
-            .select((account_id, card_id, card_count))
-            .load::<AccountCard>(&mut connection)?;
+        let found_cards = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(account_cards, account_id.eq(account_id));
+            where_clause
+                .select((account_id, card_id, card_count))
+                .load::<AccountCard>(connection)
+        }).await?;
 
         let found_card = found_cards.into_iter()
             .filter(|account_card| account_card.account_id == request_account_unique_id);
@@ -94,19 +85,16 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
 
         println!("AccountCardRepositoryImpl: update_card_count()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        let select_clause = account_cards.select((account_id, card_id, card_count));
         let where_clause = FilterDsl::filter(account_cards,
             account_id.eq(shop_account_id).and(card_id.eq(shop_update_card.0)));
 
         let update_count = shop_update_card.1 + 1;
 
-        match diesel::update(where_clause)
-            .set((columns::card_count.eq(update_count)))
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::update(where_clause)
+                .set((columns::card_count.eq(update_count)))
+                .execute(connection)
+        }).await
         {
             Ok(num) => {
                 println!("card count updated successfully.");
@@ -125,19 +113,17 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
 
         println!("AccountCardRepositoryImpl: save_new_card()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let save_card = AccountCard {
             account_id: account_unique_id,
             card_id: shop_card_id,
             card_count: 1,
         };
 
-        match diesel::insert_into(account_cards)
-            .values(&save_card)
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(account_cards)
+                .values(&save_card)
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("new card saved successfully.");
@@ -155,14 +141,12 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
 
         println!("AccountCardRepositoryImpl: delete_all_account_cards()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let where_clause = FilterDsl::filter(account_cards, account_id.eq(account_unique_id));
 
-        match diesel::delete(where_clause)
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(where_clause)
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("All cards of account id : {} removed successfully.", account_unique_id);
@@ -182,14 +166,12 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
 
         println!("AccountDeckCardRepositoryImpl: get_account_deck_card_list()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        let where_clause = FilterDsl::filter(deck_cards, deck_id.eq(deck_id));
-        let found_cards = where_clause
-            .select((deck_id, card_id, card_count))
-            .load::<AccountCard>(&mut connection);
+        let found_cards = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(deck_cards, deck_id.eq(deck_id));
+            where_clause
+                .select((deck_id, card_id, card_count))
+                .load::<AccountCard>(connection)
+        }).await;
 
         let found_cards_unwrap = found_cards.unwrap();
 
@@ -208,13 +190,11 @@ impl AccountCardRepository for AccountCardRepositoryImpl {
         use crate::account_card::entity::account_card::account_cards::dsl::*;
         println!("AccountDeckCardRepositoryImpl: save()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        diesel::insert_into(account_cards::table())
-            .values(account_card)
-            .execute(&mut connection).expect("덱 저장 실패!");
+        StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(account_cards::table())
+                .values(account_card)
+                .execute(connection)
+        }).await.expect("덱 저장 실패!");
     }
 }
 
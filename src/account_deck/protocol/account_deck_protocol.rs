@@ -0,0 +1,52 @@
+use crate::account_deck::service::account_deck_service::AccountDeckService;
+use crate::account_deck::service::account_deck_service_impl::AccountDeckServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_deck_request_generator::{create_deck_delete_request, create_deck_list_request, create_deck_modify_request, create_deck_register_request};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_deck_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Battle Deck List
+    protocol_registry.register(16, "BATTLE_DECK_LIST", create_deck_list_request, |request| async move {
+        let account_deck_service_mutex = AccountDeckServiceImpl::get_instance();
+        let account_deck_service = account_deck_service_mutex.lock().await;
+
+        let response = account_deck_service.account_deck_list(request).await;
+        ResponseType::BATTLE_DECK_LIST(response)
+    });
+
+    // Account Deck Register
+    protocol_registry.register(41, "ACCOUNT_DECK_REGISTER", create_deck_register_request, |request| async move {
+        let account_deck_service_mutex = AccountDeckServiceImpl::get_instance();
+        let account_deck_service = account_deck_service_mutex.lock().await;
+
+        let response = account_deck_service.account_deck_register(request).await;
+        ResponseType::ACCOUNT_DECK_REGISTER(response)
+    });
+
+    // Account Deck (Name) List
+    protocol_registry.register(42, "ACCOUNT_DECK_LIST", create_deck_list_request, |request| async move {
+        let account_deck_service_mutex = AccountDeckServiceImpl::get_instance();
+        let account_deck_service = account_deck_service_mutex.lock().await;
+
+        let response = account_deck_service.account_deck_list(request).await;
+        ResponseType::ACCOUNT_DECK_LIST(response)
+    });
+
+    // Account Deck (Name) Modify
+    protocol_registry.register(43, "ACCOUNT_DECK_MODIFY", create_deck_modify_request, |request| async move {
+        let account_deck_service_mutex = AccountDeckServiceImpl::get_instance();
+        let account_deck_service = account_deck_service_mutex.lock().await;
+
+        let response = account_deck_service.account_deck_modify(request).await;
+        ResponseType::ACCOUNT_DECK_MODIFY(response)
+    });
+
+    // Account Deck (All info) Delete
+    protocol_registry.register(44, "ACCOUNT_DECK_DELETE", create_deck_delete_request, |request| async move {
+        let account_deck_service_mutex = AccountDeckServiceImpl::get_instance();
+        let account_deck_service = account_deck_service_mutex.lock().await;
+
+        let response = account_deck_service.account_deck_delete(request).await;
+        ResponseType::ACCOUNT_DECK_DELETE(response)
+    });
+}
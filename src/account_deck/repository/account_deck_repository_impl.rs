@@ -5,37 +5,30 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 use diesel::query_dsl::methods::{FilterDsl};
-use diesel::{Connection, MysqlConnection, QueryDsl, ExpressionMethods, RunQueryDsl, Insertable, BoolExpressionMethods};
+use diesel::{QueryDsl, ExpressionMethods, RunQueryDsl, Insertable, BoolExpressionMethods};
 use diesel::result::Error;
 
 use crate::account_deck::entity::account_deck::account_decks::{columns};
 use crate::account_deck::entity::account_deck::account_decks::dsl::account_decks;
 
-use crate::common::env::env_detector::EnvDetector;
-use crate::mysql_config::mysql_connection::MysqlDatabaseConnection;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
 
 use crate::account_deck::entity::account_deck::AccountDeck;
 use crate::account_deck::repository::account_deck_repository::AccountDeckRepository;
 use crate::account_deck::service::request::account_deck_delete_request::AccountDeckDeleteRequest;
 use crate::account_deck::service::request::account_deck_modify_request::AccountDeckModifyRequest;
 
-pub struct AccountDeckRepositoryImpl {
-    mysql_database_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>,
-}
+pub struct AccountDeckRepositoryImpl;
 
 impl AccountDeckRepositoryImpl {
-    pub fn new(mysql_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>) -> Self {
-        AccountDeckRepositoryImpl {
-            mysql_database_connection: mysql_connection
-        }
+    pub fn new() -> Self {
+        AccountDeckRepositoryImpl
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<AccountDeckRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<AccountDeckRepositoryImpl>> =
-                Arc::new(AsyncMutex::new(AccountDeckRepositoryImpl::new(
-                    MysqlDatabaseConnection::get_instance()
-            )));
+                Arc::new(AsyncMutex::new(AccountDeckRepositoryImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -49,13 +42,12 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: save()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::insert_into(account_decks)
-            .values(&deck)
-            .execute(&mut connection)
+        // deck_id 는 auto increment 에 맡김 (SQLite 는 0 을 그대로 저장함)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(account_decks)
+                .values((columns::account_id.eq(deck.account_id()), columns::deck_name.eq(deck.deck_name())))
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account Deck saved successfully.");
@@ -74,16 +66,14 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: get_deck_id_list()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let mut account_deck_id_list = Vec::new();
 
-        let where_clause = FilterDsl::filter(account_decks, account_id.eq(account_id));
-        let all_account_decks = where_clause
-            .select((columns::deck_id, columns::account_id, columns::deck_name))
-            .load::<AccountDeck>(&mut connection)?;
+        let all_account_decks = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(account_decks, account_id.eq(account_id));
+            where_clause
+                .select((columns::deck_id, columns::account_id, columns::deck_name))
+                .load::<AccountDeck>(connection)
+        }).await?;
 
         let specific_account_decks = all_account_decks.into_iter()
             .filter(|account_deck|account_deck.account_id == account_unique_id);
@@ -102,16 +92,14 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: get_list_by_user_int_id()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let mut deck_list: Vec<HashMap<i32, String>> = Vec::new();
 
-        let where_clause = FilterDsl::filter(account_decks, account_id.eq(account_id));
-        let found_decks = where_clause
-            .select((columns::deck_id, columns::account_id, columns::deck_name))
-            .load::<AccountDeck>(&mut connection)?;
+        let found_decks = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(account_decks, account_id.eq(account_id));
+            where_clause
+                .select((columns::deck_id, columns::account_id, columns::deck_name))
+                .load::<AccountDeck>(connection)
+        }).await?;
 
         let found_deck = found_decks.into_iter()
             .filter(|account_deck|account_deck.account_id == request);
@@ -145,14 +133,12 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: update_data()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
+        match StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause =
+                FilterDsl::filter(account_decks, deck_id.eq(modify_deck.deck_id()).and(account_id.eq(int_id)));
 
-        let where_clause =
-            FilterDsl::filter(account_decks, deck_id.eq(modify_deck.deck_id()).and(account_id.eq(int_id)));
-
-        match diesel::update(where_clause).set(deck_name.eq(modify_deck.deck_name())).execute(&mut connection)
+            diesel::update(where_clause).set(deck_name.eq(modify_deck.deck_name())).execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account Deck updated successfully.");
@@ -171,13 +157,11 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: delete()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let where_clause =
             FilterDsl::filter(account_decks, deck_id.eq(deck_unique_id));
-        match diesel::delete(where_clause).execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(where_clause).execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account deck id : {} removed successfully.", deck_unique_id);
@@ -195,13 +179,11 @@ impl AccountDeckRepository for AccountDeckRepositoryImpl {
 
         println!("AccountDeckRepositoryImpl: delete_all_account_decks()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let where_clause =
             FilterDsl::filter(account_decks, account_id.eq(account_unique_id));
-        match diesel::delete(where_clause).execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(where_clause).execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("All decks of account id : {} removed successfully.", account_unique_id);
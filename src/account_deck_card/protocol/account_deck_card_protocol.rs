@@ -0,0 +1,34 @@
+use crate::account_deck_card::controller::account_deck_card_controller::AccountDeckCardController;
+use crate::account_deck_card::controller::account_deck_card_controller_impl::AccountDeckCardControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_deck_card_request_generator::{create_account_deck_card_list_request_form, create_account_deck_card_modify_request_form, create_account_deck_configuration_request_form};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_deck_card_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Account Deck Card Configuration
+    protocol_registry.register(51, "DECK_CARD_CONFIGURATION", create_account_deck_configuration_request_form, |request| async move {
+        let deck_card_controller_mutex = AccountDeckCardControllerImpl::get_instance();
+        let deck_card_controller_mutex_guard = deck_card_controller_mutex.lock().await;
+
+        let response = deck_card_controller_mutex_guard.deck_configuration_register(request).await;
+        ResponseType::DECK_CARD_CONFIGURATION(response)
+    });
+
+    // Account Deck Card List
+    protocol_registry.register(52, "DECK_CARD_LIST", create_account_deck_card_list_request_form, |request| async move {
+        let deck_card_controller_mutex = AccountDeckCardControllerImpl::get_instance();
+        let deck_card_controller_mutex_guard = deck_card_controller_mutex.lock().await;
+
+        let response = deck_card_controller_mutex_guard.deck_card_list(request).await;
+        ResponseType::DECK_CARD_LIST(response)
+    });
+
+    // Account Deck Card Modify
+    protocol_registry.register(53, "DECK_CARD_MODIFY", create_account_deck_card_modify_request_form, |request| async move {
+        let deck_card_controller_mutex = AccountDeckCardControllerImpl::get_instance();
+        let deck_card_controller_mutex_guard = deck_card_controller_mutex.lock().await;
+
+        let response = deck_card_controller_mutex_guard.deck_card_modify(request).await;
+        ResponseType::DECK_CARD_MODIFY(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod account_deck_card_protocol;
@@ -5,33 +5,26 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 use diesel::query_dsl::methods::{FilterDsl, FindDsl};
-use diesel::{Connection, MysqlConnection, QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension, Insertable};
+use diesel::{Connection, QueryDsl, ExpressionMethods, RunQueryDsl, OptionalExtension, Insertable};
 use diesel::associations::HasTable;
 use diesel::result::Error;
 
-use crate::common::env::env_detector::EnvDetector;
-use crate::mysql_config::mysql_connection::MysqlDatabaseConnection;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
 
 use crate::account_deck_card::entity::account_deck_card::AccountDeckCard;
 use crate::account_deck_card::repository::account_deck_card_repository::AccountDeckCardRepository;
 
-pub struct AccountDeckCardRepositoryImpl {
-    mysql_database_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>,
-}
+pub struct AccountDeckCardRepositoryImpl;
 
 impl AccountDeckCardRepositoryImpl {
-    pub fn new(mysql_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>) -> Self {
-        AccountDeckCardRepositoryImpl {
-            mysql_database_connection: mysql_connection
-        }
+    pub fn new() -> Self {
+        AccountDeckCardRepositoryImpl
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<AccountDeckCardRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<AccountDeckCardRepositoryImpl >> =
-                Arc::new(AsyncMutex::new(AccountDeckCardRepositoryImpl::new(
-                    MysqlDatabaseConnection::get_instance()
-            )));
+                Arc::new(AsyncMutex::new(AccountDeckCardRepositoryImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -43,13 +36,17 @@ impl AccountDeckCardRepository for AccountDeckCardRepositoryImpl {
         use crate::account_deck_card::entity::account_deck_card::deck_cards::dsl::*;
         println!("AccountDeckCardRepositoryImpl: save()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        diesel::insert_into(deck_cards::table())
-            .values(deck_card_list)
-            .execute(&mut connection).expect("덱 저장 실패!");
+        // 다중 행 INSERT 는 백엔드마다 문법이 달라 한 트랜잭션 안에서 한 행씩 저장
+        StorageConnectionPool::run_blocking(move |connection| {
+            connection.transaction::<_, Error, _>(|connection| {
+                for deck_card in deck_card_list {
+                    diesel::insert_into(deck_cards::table())
+                        .values(deck_card)
+                        .execute(connection)?;
+                }
+                Ok(())
+            })
+        }).await.expect("덱 저장 실패!");
 
         Ok("덱 저장에 성공하였습니다.".to_string())
     }
@@ -61,16 +58,14 @@ impl AccountDeckCardRepository for AccountDeckCardRepositoryImpl {
 
         println!("AccountDeckCardRepositoryImpl: get_card_list()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let mut card_list: Vec<HashMap<i32, i32>> = Vec::new();
 
-        let where_clause = FilterDsl::filter(deck_cards, deck_id.eq(deck_id));
-        let found_cards = where_clause
-            .select((deck_id, card_id, card_count))
-            .load::<AccountDeckCard>(&mut connection)?;
+        let found_cards = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(deck_cards, deck_id.eq(deck_id));
+            where_clause
+                .select((deck_id, card_id, card_count))
+                .load::<AccountDeckCard>(connection)
+        }).await?;
 
         let found_card = found_cards.into_iter()
             .filter(|deck_card| deck_card.deck_id == request_deck_id);
@@ -91,13 +86,11 @@ impl AccountDeckCardRepository for AccountDeckCardRepositoryImpl {
 
         println!("AccountDeckCardRepositoryImpl: delete_deck_cards()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
         let where_clause = FilterDsl::filter(deck_cards, deck_id.eq(deck_unique_id));
 
-        match diesel::delete(where_clause).execute(&mut connection) {
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(where_clause).execute(connection)
+        }).await {
             Ok(_) => {
                 println!("Account Deck cards deleted successfully.");
                 Ok(())
@@ -1,7 +1,7 @@
 use std::os::raw::c_uint;
 use bcrypt::{BcryptError};
+use chrono::{Datelike, NaiveDate};
 use diesel::{Expression, Insertable, Queryable, table};
-use diesel::mysql::data_types::MysqlTime;
 
 #[derive(Queryable, Insertable, Debug)]
 #[table_name = "account_points"]
@@ -10,7 +10,7 @@ pub struct AccountPoint {
     pub account_id: i32,
     pub gold: i32,
     pub event_check: i32,
-    pub free_gacha_check: MysqlTime,
+    pub free_gacha_check: NaiveDate,
 }
 
 table! {
@@ -23,7 +23,7 @@ table! {
 }
 
 impl AccountPoint {
-    pub fn new(account_id: i32, gold: i32, event_check: i32, free_gacha_check: MysqlTime) -> Result<Self, BcryptError> {
+    pub fn new(account_id: i32, gold: i32, event_check: i32, free_gacha_check: NaiveDate) -> Result<Self, BcryptError> {
 
         Ok(AccountPoint {
             account_id,
@@ -37,10 +37,10 @@ impl AccountPoint {
 
     pub fn gold(&self) -> i32 { self.gold }
     pub fn event_check(&self) -> i32 { self.event_check }
-    pub fn get_free_gacha_year(&self) -> c_uint { self.free_gacha_check.year }
-    pub fn get_free_gacha_month(&self) -> c_uint { self.free_gacha_check.month }
-    pub fn get_free_gacha_day(&self) -> c_uint { self.free_gacha_check.day }
-    pub fn get_free_gacha_date(&self) -> Vec<c_uint> { vec![self.free_gacha_check.year, self.free_gacha_check.month, self.free_gacha_check.day]}
+    pub fn get_free_gacha_year(&self) -> c_uint { self.free_gacha_check.year() as c_uint }
+    pub fn get_free_gacha_month(&self) -> c_uint { self.free_gacha_check.month() }
+    pub fn get_free_gacha_day(&self) -> c_uint { self.free_gacha_check.day() }
+    pub fn get_free_gacha_date(&self) -> Vec<c_uint> { vec![self.get_free_gacha_year(), self.get_free_gacha_month(), self.get_free_gacha_day()]}
 }
 //
 // impl std::fmt::Display for AccountPoint {
@@ -0,0 +1,25 @@
+use crate::account_point::service::account_point_service::AccountPointService;
+use crate::account_point::service::account_point_service_impl::AccountPointServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_point_request_generator::{create_gain_gold_request, create_pay_gold_request};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_point_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Gain gold
+    protocol_registry.register(91, "GAIN_GOLD", create_gain_gold_request, |request| async move {
+        let account_point_service_mutex = AccountPointServiceImpl::get_instance();
+        let account_point_service = account_point_service_mutex.lock().await;
+
+        let response = account_point_service.gain_gold(request).await;
+        ResponseType::GAIN_GOLD(response)
+    });
+
+    // Pay gold
+    protocol_registry.register(92, "PAY_GOLD", create_pay_gold_request, |request| async move {
+        let account_point_service_mutex = AccountPointServiceImpl::get_instance();
+        let account_point_service = account_point_service_mutex.lock().await;
+
+        let response = account_point_service.pay_gold(request).await;
+        ResponseType::PAY_GOLD(response)
+    });
+}
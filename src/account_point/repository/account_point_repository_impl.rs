@@ -6,7 +6,7 @@ use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
 
 use diesel::query_dsl::methods::{FilterDsl};
-use diesel::{Connection, MysqlConnection, QueryDsl, ExpressionMethods, RunQueryDsl};
+use diesel::{QueryDsl, ExpressionMethods, RunQueryDsl};
 use diesel::dsl::today;
 use crate::account_deck_card::repository::account_deck_card_repository_impl::AccountDeckCardRepositoryImpl;
 
@@ -15,26 +15,19 @@ use crate::account_point::entity::account_point::AccountPoint;
 use crate::account_point::entity::account_point::account_points::dsl::{account_id, account_points};
 use crate::account_point::entity::account_point::account_points::columns;
 use crate::account_point::repository::account_point_repository::AccountPointRepository;
-use crate::common::env::env_detector::EnvDetector;
-use crate::mysql_config::mysql_connection::MysqlDatabaseConnection;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
 
-pub struct AccountPointRepositoryImpl {
-    mysql_database_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>,
-}
+pub struct AccountPointRepositoryImpl;
 
 impl AccountPointRepositoryImpl {
-    pub fn new(mysql_connection: Arc<AsyncMutex<MysqlDatabaseConnection>>) -> Self {
-        AccountPointRepositoryImpl {
-            mysql_database_connection: mysql_connection
-        }
+    pub fn new() -> Self {
+        AccountPointRepositoryImpl
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<AccountPointRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<AccountPointRepositoryImpl>> =
-                Arc::new(AsyncMutex::new(AccountPointRepositoryImpl::new(
-                    MysqlDatabaseConnection::get_instance()
-            )));
+                Arc::new(AsyncMutex::new(AccountPointRepositoryImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -52,13 +45,11 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
 
         println!("AccountPointRepository: save_account_points()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::insert_into(account_points)
-            .values(&account_id_only)
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(account_points)
+                .values(&account_id_only)
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account point saved successfully.");
@@ -78,15 +69,12 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
 
         println!("AccountPointRepositoryImpl: find_by_account_id()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        let select_clause = account_points.select((columns::account_id, columns::gold));
-        let where_clause = FilterDsl::filter(account_points, columns::account_id.eq(account_id));
-        let found_accounts = where_clause
-            .select((columns::account_id, columns::gold, columns::event_check, columns::free_gacha_check))
-            .load::<AccountPoint>(&mut connection)?;
+        let found_accounts = StorageConnectionPool::run_blocking(move |connection| {
+            let where_clause = FilterDsl::filter(account_points, columns::account_id.eq(account_id));
+            where_clause
+                .select((columns::account_id, columns::gold, columns::event_check, columns::free_gacha_check))
+                .load::<AccountPoint>(connection)
+        }).await?;
 
         let found_account = found_accounts
             .into_iter()
@@ -98,15 +86,13 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
     async fn update_gold(&self, account_point: AccountPoint, golds: i32) -> Result<usize, diesel::result::Error> {
         println!("AccountPointRepositoryImpl: update_gold()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_point.account_id)))
-            .set((
-                columns::gold.eq(golds),
-            ))
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_point.account_id)))
+                .set((
+                    columns::gold.eq(golds),
+                ))
+                .execute(connection)
+        }).await
         {
             Ok(num) => {
                 println!("Gold points updated successfully.");
@@ -124,13 +110,10 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
 
         println!("AccountPointRepositoryImpl: delete()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::delete(FilterDsl::filter(account_points, columns::account_id.eq(account_user_id)))
-
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::delete(FilterDsl::filter(account_points, columns::account_id.eq(account_user_id)))
+                .execute(connection)
+        }).await
         {
             Ok(_) => {
                 println!("Account_point deleted successfully.");
@@ -145,15 +128,13 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
     async fn update_event_check(&self, account_unique_id: i32) -> Result<usize, diesel::result::Error> {
         println!("AccountPointRepositoryImpl: update_event_check()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_unique_id)))
-            .set((
-                columns::event_check.eq(0),
-            ))
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_unique_id)))
+                .set((
+                    columns::event_check.eq(0),
+                ))
+                .execute(connection)
+        }).await
         {
             Ok(num) => {
                 println!("event check updated successfully.");
@@ -168,15 +149,13 @@ impl AccountPointRepository for AccountPointRepositoryImpl {
     async fn update_free_gacha_check(&self, account_unique_id: i32) -> Result<usize, diesel::result::Error> {
         println!("AccountPointRepositoryImpl: update_free_gacha_check()");
 
-        let database_url = EnvDetector::get_mysql_url().expect("DATABASE_URL이 설정되어 있어야 합니다.");
-        let mut connection = MysqlConnection::establish(&database_url)
-            .expect("Failed to establish a new connection");
-
-        match diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_unique_id)))
-            .set((
-                columns::free_gacha_check.eq(today),
-            ))
-            .execute(&mut connection)
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::update(FilterDsl::filter(account_points, columns::account_id.eq(account_unique_id)))
+                .set((
+                    columns::free_gacha_check.eq(today),
+                ))
+                .execute(connection)
+        }).await
         {
             Ok(num) => {
                 println!("free gacha check updated successfully.");
@@ -0,0 +1,74 @@
+use diesel::{Insertable, Queryable, table};
+use crate::account_rating::entity::elo_rating_calculator::DEFAULT_RATING;
+use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum;
+
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[table_name = "account_ratings"]
+pub struct AccountRating {
+    #[column_name = "account_id"]
+    pub account_id: i32,
+    pub rating: i32,
+    pub win_count: i32,
+    pub lose_count: i32,
+    pub draw_count: i32,
+}
+
+table! {
+    account_ratings (account_id) {
+        account_id -> Integer,
+        rating -> Integer,
+        win_count -> Integer,
+        lose_count -> Integer,
+        draw_count -> Integer,
+    }
+}
+
+impl AccountRating {
+    pub fn new(account_id: i32) -> Self {
+        AccountRating {
+            account_id,
+            rating: DEFAULT_RATING,
+            win_count: 0,
+            lose_count: 0,
+            draw_count: 0,
+        }
+    }
+
+    pub fn account_id(&self) -> i32 { self.account_id }
+    pub fn rating(&self) -> i32 { self.rating }
+    pub fn win_count(&self) -> i32 { self.win_count }
+    pub fn lose_count(&self) -> i32 { self.lose_count }
+    pub fn draw_count(&self) -> i32 { self.draw_count }
+
+    pub fn apply_battle_result(&mut self, new_rating: i32, finish_position: FinishPositionEnum) {
+        self.rating = new_rating;
+
+        match finish_position {
+            FinishPositionEnum::Winner => self.win_count += 1,
+            FinishPositionEnum::Loser => self.lose_count += 1,
+            FinishPositionEnum::Draw => self.draw_count += 1,
+            FinishPositionEnum::Dummy => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum::{Draw, Winner};
+
+    #[test]
+    fn test_apply_battle_result() {
+        let mut account_rating = AccountRating::new(7);
+        assert_eq!(account_rating.rating(), DEFAULT_RATING);
+
+        account_rating.apply_battle_result(1016, Winner);
+        account_rating.apply_battle_result(1010, Draw);
+        println!("account_rating: {:?}", account_rating);
+
+        assert_eq!(account_rating.rating(), 1010);
+        assert_eq!(account_rating.win_count(), 1);
+        assert_eq!(account_rating.lose_count(), 0);
+        assert_eq!(account_rating.draw_count(), 1);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum;
+
+pub const DEFAULT_RATING: i32 = 1000;
+pub const K_FACTOR: f64 = 32.0;
+
+pub struct EloRatingCalculator;
+
+impl EloRatingCalculator {
+    // 상대 레이팅 대비 기대 승률 (0.0 ~ 1.0)
+    pub fn expected_score(rating: i32, opponent_rating: i32) -> f64 {
+        let rating_gap = (opponent_rating - rating) as f64;
+        1.0 / (1.0 + 10f64.powf(rating_gap / 400.0))
+    }
+
+    pub fn actual_score(finish_position: FinishPositionEnum) -> Option<f64> {
+        match finish_position {
+            FinishPositionEnum::Winner => Some(1.0),
+            FinishPositionEnum::Loser => Some(0.0),
+            FinishPositionEnum::Draw => Some(0.5),
+            FinishPositionEnum::Dummy => None,
+        }
+    }
+
+    // 결과가 확정되지 않은 경우(Dummy) 레이팅은 그대로 유지
+    pub fn calculate_new_rating(rating: i32, opponent_rating: i32, finish_position: FinishPositionEnum) -> i32 {
+        match Self::actual_score(finish_position) {
+            Some(actual_score) => {
+                let expected_score = Self::expected_score(rating, opponent_rating);
+                rating + (K_FACTOR * (actual_score - expected_score)).round() as i32
+            }
+            None => rating,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum::{Draw, Dummy, Loser, Winner};
+
+    #[test]
+    fn test_expected_score_of_equal_ratings() {
+        let expected_score = EloRatingCalculator::expected_score(1000, 1000);
+        assert!((expected_score - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_calculate_new_rating_between_equal_ratings() {
+        assert_eq!(EloRatingCalculator::calculate_new_rating(1000, 1000, Winner), 1016);
+        assert_eq!(EloRatingCalculator::calculate_new_rating(1000, 1000, Loser), 984);
+        assert_eq!(EloRatingCalculator::calculate_new_rating(1000, 1000, Draw), 1000);
+        assert_eq!(EloRatingCalculator::calculate_new_rating(1000, 1000, Dummy), 1000);
+    }
+
+    #[test]
+    fn test_upset_moves_rating_more_than_expected_win() {
+        let underdog_gain = EloRatingCalculator::calculate_new_rating(1000, 1400, Winner) - 1000;
+        let favorite_gain = EloRatingCalculator::calculate_new_rating(1400, 1000, Winner) - 1400;
+        println!("underdog_gain: {}, favorite_gain: {}", underdog_gain, favorite_gain);
+
+        assert!(underdog_gain > favorite_gain);
+        assert_eq!(underdog_gain + favorite_gain, K_FACTOR as i32);
+    }
+}
@@ -0,0 +1,55 @@
+use diesel::{Insertable, Queryable, table};
+use serde::{Deserialize, Serialize};
+
+#[derive(Queryable, Insertable, Debug, Clone, Serialize, Deserialize)]
+#[table_name = "match_histories"]
+pub struct MatchHistory {
+    #[column_name = "id"]
+    pub id: i32,
+    pub account_id: i32,
+    pub opponent_account_id: i32,
+    pub finish_position: i32,
+    pub rating_before: i32,
+    pub rating_after: i32,
+    pub finished_at: i64,
+}
+
+table! {
+    match_histories (id) {
+        id -> Integer,
+        account_id -> Integer,
+        opponent_account_id -> Integer,
+        finish_position -> Integer,
+        rating_before -> Integer,
+        rating_after -> Integer,
+        finished_at -> BigInt,
+    }
+}
+
+impl MatchHistory {
+    pub fn new(account_id: i32,
+               opponent_account_id: i32,
+               finish_position: i32,
+               rating_before: i32,
+               rating_after: i32,
+               finished_at: i64) -> Self {
+
+        MatchHistory {
+            id: 0,
+            account_id,
+            opponent_account_id,
+            finish_position,
+            rating_before,
+            rating_after,
+            finished_at,
+        }
+    }
+
+    pub fn id(&self) -> i32 { self.id }
+    pub fn account_id(&self) -> i32 { self.account_id }
+    pub fn opponent_account_id(&self) -> i32 { self.opponent_account_id }
+    pub fn finish_position(&self) -> i32 { self.finish_position }
+    pub fn rating_before(&self) -> i32 { self.rating_before }
+    pub fn rating_after(&self) -> i32 { self.rating_after }
+    pub fn finished_at(&self) -> i64 { self.finished_at }
+}
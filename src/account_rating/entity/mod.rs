@@ -0,0 +1,3 @@
+pub mod account_rating;
+pub mod match_history;
+pub mod elo_rating_calculator;
@@ -0,0 +1,25 @@
+use crate::account_rating::service::account_rating_service::AccountRatingService;
+use crate::account_rating::service::account_rating_service_impl::AccountRatingServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::account_rating_request_generator::{create_account_rating_request, create_match_history_list_request};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_account_rating_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // My rating
+    protocol_registry.register(93, "ACCOUNT_RATING", create_account_rating_request, |request| async move {
+        let account_rating_service_mutex = AccountRatingServiceImpl::get_instance();
+        let account_rating_service = account_rating_service_mutex.lock().await;
+
+        let response = account_rating_service.get_account_rating(request).await;
+        ResponseType::ACCOUNT_RATING(response)
+    });
+
+    // My match history
+    protocol_registry.register(94, "MATCH_HISTORY_LIST", create_match_history_list_request, |request| async move {
+        let account_rating_service_mutex = AccountRatingServiceImpl::get_instance();
+        let account_rating_service = account_rating_service_mutex.lock().await;
+
+        let response = account_rating_service.get_match_history_list(request).await;
+        ResponseType::MATCH_HISTORY_LIST(response)
+    });
+}
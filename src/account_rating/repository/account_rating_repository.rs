@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::account_rating::entity::account_rating::AccountRating;
+use crate::account_rating::entity::match_history::MatchHistory;
+
+#[async_trait]
+pub trait AccountRatingRepository {
+    async fn save_account_rating(&self, account_rating: AccountRating) -> Result<(), diesel::result::Error>;
+    async fn find_by_account_id(&self, account_unique_id: i32) -> Result<Option<AccountRating>, diesel::result::Error>;
+    async fn find_or_create_by_account_id(&self, account_unique_id: i32) -> Result<AccountRating, diesel::result::Error>;
+    async fn update_account_rating(&self, account_rating: &AccountRating) -> Result<usize, diesel::result::Error>;
+    async fn delete_account_rating(&self, account_unique_id: i32) -> Result<(), diesel::result::Error>;
+    async fn save_match_history(&self, match_history: MatchHistory) -> Result<(), diesel::result::Error>;
+    async fn find_match_history_list_by_account_id(&self, account_unique_id: i32, max_count: i64) -> Result<Vec<MatchHistory>, diesel::result::Error>;
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use diesel::{Connection, QueryDsl, ExpressionMethods, RunQueryDsl};
+use diesel::query_dsl::methods::FilterDsl;
+
+use crate::account_rating::entity::account_rating::AccountRating;
+use crate::account_rating::entity::account_rating::account_ratings::dsl::account_ratings;
+use crate::account_rating::entity::account_rating::account_ratings::columns;
+use crate::account_rating::entity::match_history::MatchHistory;
+use crate::account_rating::entity::match_history::match_histories::dsl::match_histories;
+use crate::account_rating::entity::match_history::match_histories::columns as match_history_columns;
+use crate::account_rating::repository::account_rating_repository::AccountRatingRepository;
+use crate::storage_config::storage_connection_pool::StorageConnectionPool;
+
+pub struct AccountRatingRepositoryImpl;
+
+impl AccountRatingRepositoryImpl {
+    pub fn new() -> Self {
+        AccountRatingRepositoryImpl
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<AccountRatingRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<AccountRatingRepositoryImpl>> =
+                Arc::new(AsyncMutex::new(AccountRatingRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl AccountRatingRepository for AccountRatingRepositoryImpl {
+    async fn save_account_rating(&self, account_rating: AccountRating) -> Result<(), diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: save_account_rating()");
+
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(account_ratings)
+                .values(&account_rating)
+                .execute(connection)
+        }).await
+        {
+            Ok(_) => {
+                println!("Account rating saved successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error saving account rating: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn find_by_account_id(&self, account_unique_id: i32) -> Result<Option<AccountRating>, diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: find_by_account_id()");
+
+        let found_account_ratings = StorageConnectionPool::run_blocking(move |connection| {
+            FilterDsl::filter(account_ratings, columns::account_id.eq(account_unique_id))
+                .load::<AccountRating>(connection)
+        }).await?;
+
+        Ok(found_account_ratings.into_iter().next())
+    }
+
+    // 레이팅 도입 이전에 가입한 계정은 첫 조회 시 기본 레이팅으로 생성
+    async fn find_or_create_by_account_id(&self, account_unique_id: i32) -> Result<AccountRating, diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: find_or_create_by_account_id()");
+
+        if let Some(found_account_rating) = self.find_by_account_id(account_unique_id).await? {
+            return Ok(found_account_rating)
+        }
+
+        let account_rating = AccountRating::new(account_unique_id);
+        self.save_account_rating(account_rating.clone()).await?;
+
+        Ok(account_rating)
+    }
+
+    async fn update_account_rating(&self, account_rating: &AccountRating) -> Result<usize, diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: update_account_rating()");
+
+        let account_rating = account_rating.clone();
+
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::update(FilterDsl::filter(account_ratings, columns::account_id.eq(account_rating.account_id())))
+                .set((
+                    columns::rating.eq(account_rating.rating()),
+                    columns::win_count.eq(account_rating.win_count()),
+                    columns::lose_count.eq(account_rating.lose_count()),
+                    columns::draw_count.eq(account_rating.draw_count()),
+                ))
+                .execute(connection)
+        }).await
+        {
+            Ok(num) => {
+                println!("Account rating updated successfully.");
+                Ok(num)
+            }
+            Err(e) => {
+                eprintln!("Error updating account rating: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete_account_rating(&self, account_unique_id: i32) -> Result<(), diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: delete_account_rating()");
+
+        match StorageConnectionPool::run_blocking(move |connection| {
+            connection.transaction::<_, diesel::result::Error, _>(|connection| {
+                diesel::delete(FilterDsl::filter(match_histories, match_history_columns::account_id.eq(account_unique_id)))
+                    .execute(connection)?;
+
+                diesel::delete(FilterDsl::filter(account_ratings, columns::account_id.eq(account_unique_id)))
+                    .execute(connection)
+            })
+        }).await
+        {
+            Ok(_) => {
+                println!("Account rating deleted successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error deleting account rating: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn save_match_history(&self, match_history: MatchHistory) -> Result<(), diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: save_match_history()");
+
+        match StorageConnectionPool::run_blocking(move |connection| {
+            diesel::insert_into(match_histories)
+                .values((
+                    match_history_columns::account_id.eq(match_history.account_id()),
+                    match_history_columns::opponent_account_id.eq(match_history.opponent_account_id()),
+                    match_history_columns::finish_position.eq(match_history.finish_position()),
+                    match_history_columns::rating_before.eq(match_history.rating_before()),
+                    match_history_columns::rating_after.eq(match_history.rating_after()),
+                    match_history_columns::finished_at.eq(match_history.finished_at()),
+                ))
+                .execute(connection)
+        }).await
+        {
+            Ok(_) => {
+                println!("Match history saved successfully.");
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error saving match history: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn find_match_history_list_by_account_id(&self, account_unique_id: i32, max_count: i64) -> Result<Vec<MatchHistory>, diesel::result::Error> {
+        println!("AccountRatingRepositoryImpl: find_match_history_list_by_account_id()");
+
+        StorageConnectionPool::run_blocking(move |connection| {
+            FilterDsl::filter(match_histories, match_history_columns::account_id.eq(account_unique_id))
+                .order(match_history_columns::id.desc())
+                .limit(max_count)
+                .load::<MatchHistory>(connection)
+        }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_find_or_create_by_account_id() {
+        let repository_mutex = AccountRatingRepositoryImpl::get_instance();
+        let repository_guard = repository_mutex.lock().await;
+
+        let account_rating = repository_guard.find_or_create_by_account_id(1).await;
+        println!("account_rating: {:?}", account_rating);
+        assert!(account_rating.is_ok());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod account_rating_repository;
+pub mod account_rating_repository_impl;
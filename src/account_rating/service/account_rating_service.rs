@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::account_rating::service::request::account_rating_request::AccountRatingRequest;
+use crate::account_rating::service::request::match_history_list_request::MatchHistoryListRequest;
+use crate::account_rating::service::response::account_rating_response::AccountRatingResponse;
+use crate::account_rating::service::response::match_history_list_response::MatchHistoryListResponse;
+
+#[async_trait]
+pub trait AccountRatingService {
+    async fn get_account_rating(&self, account_rating_request: AccountRatingRequest) -> AccountRatingResponse;
+    async fn get_match_history_list(&self, match_history_list_request: MatchHistoryListRequest) -> MatchHistoryListResponse;
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::account_rating::repository::account_rating_repository::AccountRatingRepository;
+use crate::account_rating::repository::account_rating_repository_impl::AccountRatingRepositoryImpl;
+use crate::account_rating::service::account_rating_service::AccountRatingService;
+use crate::account_rating::service::request::account_rating_request::AccountRatingRequest;
+use crate::account_rating::service::request::match_history_list_request::MatchHistoryListRequest;
+use crate::account_rating::service::response::account_rating_response::AccountRatingResponse;
+use crate::account_rating::service::response::match_history_list_response::MatchHistoryListResponse;
+use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
+use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
+
+const MAX_MATCH_HISTORY_COUNT: i64 = 20;
+
+pub struct AccountRatingServiceImpl {
+    repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
+    redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
+}
+
+impl AccountRatingServiceImpl {
+    pub fn new(repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
+               redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>) -> Self {
+
+        AccountRatingServiceImpl {
+            repository,
+            redis_in_memory_repository
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<AccountRatingServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<AccountRatingServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        AccountRatingServiceImpl::new(
+                            AccountRatingRepositoryImpl::get_instance(),
+                            RedisInMemoryRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn parse_account_unique_id(&self, session_id: &str) -> i32 {
+        let mut redis_in_memory_repository = self.redis_in_memory_repository.lock().await;
+        let account_unique_id_option_string = redis_in_memory_repository.get(session_id).await;
+        let account_unique_id_string = account_unique_id_option_string.unwrap_or("-1".to_string());
+        account_unique_id_string.parse().unwrap_or(-1)
+    }
+}
+
+#[async_trait]
+impl AccountRatingService for AccountRatingServiceImpl {
+    async fn get_account_rating(&self, account_rating_request: AccountRatingRequest) -> AccountRatingResponse {
+        println!("AccountRatingServiceImpl: get_account_rating()");
+
+        let account_unique_id = self.parse_account_unique_id(account_rating_request.get_session_id()).await;
+        if account_unique_id == -1 {
+            return AccountRatingResponse::failure()
+        }
+
+        let account_rating_repository = self.repository.lock().await;
+        match account_rating_repository.find_or_create_by_account_id(account_unique_id).await {
+            Ok(account_rating) => {
+                AccountRatingResponse::new(
+                    true,
+                    account_rating.rating(),
+                    account_rating.win_count(),
+                    account_rating.lose_count(),
+                    account_rating.draw_count())
+            }
+            Err(e) => {
+                println!("Account rating loading error: {:?}", e);
+                AccountRatingResponse::failure()
+            }
+        }
+    }
+
+    async fn get_match_history_list(&self, match_history_list_request: MatchHistoryListRequest) -> MatchHistoryListResponse {
+        println!("AccountRatingServiceImpl: get_match_history_list()");
+
+        let account_unique_id = self.parse_account_unique_id(match_history_list_request.get_session_id()).await;
+        if account_unique_id == -1 {
+            return MatchHistoryListResponse::new(Vec::new())
+        }
+
+        let account_rating_repository = self.repository.lock().await;
+        match account_rating_repository.find_match_history_list_by_account_id(account_unique_id, MAX_MATCH_HISTORY_COUNT).await {
+            Ok(match_history_list) => MatchHistoryListResponse::new(match_history_list),
+            Err(e) => {
+                println!("Match history loading error: {:?}", e);
+                MatchHistoryListResponse::new(Vec::new())
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod account_rating_service;
+pub mod account_rating_service_impl;
+pub mod request;
+pub mod response;
@@ -0,0 +1,13 @@
+#[derive(Debug)]
+pub struct AccountRatingRequest {
+    session_id: String
+}
+
+impl AccountRatingRequest {
+    pub fn new(session_id: String) -> Self {
+        AccountRatingRequest {
+            session_id: session_id.to_string()
+        }
+    }
+    pub fn get_session_id(&self) -> &str { &self.session_id }
+}
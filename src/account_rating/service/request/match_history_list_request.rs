@@ -0,0 +1,13 @@
+#[derive(Debug)]
+pub struct MatchHistoryListRequest {
+    session_id: String
+}
+
+impl MatchHistoryListRequest {
+    pub fn new(session_id: String) -> Self {
+        MatchHistoryListRequest {
+            session_id: session_id.to_string()
+        }
+    }
+    pub fn get_session_id(&self) -> &str { &self.session_id }
+}
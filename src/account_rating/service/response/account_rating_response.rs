@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountRatingResponse {
+    is_success: bool,
+    rating: i32,
+    win_count: i32,
+    lose_count: i32,
+    draw_count: i32,
+}
+
+impl AccountRatingResponse {
+    pub fn new(is_success: bool, rating: i32, win_count: i32, lose_count: i32, draw_count: i32) -> Self {
+        AccountRatingResponse { is_success, rating, win_count, lose_count, draw_count }
+    }
+    pub fn failure() -> Self { AccountRatingResponse::new(false, -1, 0, 0, 0) }
+    pub fn is_success(&self) -> bool { self.is_success }
+    pub fn get_rating(&self) -> i32 { self.rating }
+    pub fn get_win_count(&self) -> i32 { self.win_count }
+    pub fn get_lose_count(&self) -> i32 { self.lose_count }
+    pub fn get_draw_count(&self) -> i32 { self.draw_count }
+}
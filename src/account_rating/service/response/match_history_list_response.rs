@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use crate::account_rating::entity::match_history::MatchHistory;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchHistoryListResponse {
+    match_history_list: Vec<MatchHistory>
+}
+
+impl MatchHistoryListResponse {
+    pub fn new(match_history_list: Vec<MatchHistory>) -> Self { MatchHistoryListResponse { match_history_list } }
+    pub fn get_match_history_list(&self) -> &Vec<MatchHistory> { &self.match_history_list }
+}
@@ -0,0 +1,2 @@
+pub mod account_rating_response;
+pub mod match_history_list_response;
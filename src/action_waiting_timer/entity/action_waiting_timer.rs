@@ -23,4 +23,43 @@ impl ActionWaitingTimer {
             false
         }
     }
+    pub fn get_remaining_time(&self, id: i32, duration: Duration) -> Option<Duration> {
+        self.timers.get(&id).map(|start_time| duration.saturating_sub(start_time.elapsed()))
+    }
+    pub fn remove_timer(&mut self, id: i32) -> bool {
+        self.timers.remove(&id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_time_of_started_timer() {
+        let mut action_waiting_timer = ActionWaitingTimer::new();
+        let duration = Duration::from_secs(60);
+
+        assert_eq!(action_waiting_timer.get_remaining_time(1, duration), None);
+
+        action_waiting_timer.start_timer(1);
+        let remaining_time = action_waiting_timer.get_remaining_time(1, duration).unwrap();
+
+        assert!(remaining_time <= duration);
+        assert!(remaining_time > Duration::from_secs(59));
+        assert!(!action_waiting_timer.check_timer(1, duration));
+    }
+
+    #[test]
+    fn test_expired_timer() {
+        let mut action_waiting_timer = ActionWaitingTimer::new();
+
+        action_waiting_timer.start_timer(1);
+
+        assert!(action_waiting_timer.check_timer(1, Duration::ZERO));
+        assert_eq!(action_waiting_timer.get_remaining_time(1, Duration::ZERO), Some(Duration::ZERO));
+        assert!(action_waiting_timer.remove_timer(1));
+        assert!(!action_waiting_timer.remove_timer(1));
+        assert!(!action_waiting_timer.check_timer(1, Duration::ZERO));
+    }
 }
@@ -3,5 +3,8 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait ActionWaitingTimerRepository {
-    async fn set_action_waiting_timer(&mut self, account_unique_id: i32, duration: Duration) -> bool;
-}
\ No newline at end of file
+    async fn set_action_waiting_timer(&mut self, battle_room_id: i32, duration: Duration) -> bool;
+    async fn check_action_waiting_timer_expired(&self, battle_room_id: i32, duration: Duration) -> bool;
+    async fn get_remaining_action_waiting_time(&self, battle_room_id: i32, duration: Duration) -> Option<Duration>;
+    async fn remove_action_waiting_timer(&mut self, battle_room_id: i32) -> bool;
+}
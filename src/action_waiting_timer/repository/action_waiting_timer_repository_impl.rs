@@ -30,13 +30,29 @@ impl ActionWaitingTimerRepositoryImpl {
 
 #[async_trait]
 impl ActionWaitingTimerRepository for ActionWaitingTimerRepositoryImpl {
-
-
-    async fn set_action_waiting_timer(&mut self, opponent_id: i32, duration: Duration) -> bool {
+    async fn set_action_waiting_timer(&mut self, battle_room_id: i32, duration: Duration) -> bool {
         println!("ActionWaitingTimerRepositoryImpl: set_action_waiting_timer()");
 
-        self.action_waiting_timer.start_timer(opponent_id);
+        self.action_waiting_timer.start_timer(battle_room_id);
 
         return true
     }
-}
\ No newline at end of file
+
+    async fn check_action_waiting_timer_expired(&self, battle_room_id: i32, duration: Duration) -> bool {
+        println!("ActionWaitingTimerRepositoryImpl: check_action_waiting_timer_expired()");
+
+        self.action_waiting_timer.check_timer(battle_room_id, duration)
+    }
+
+    async fn get_remaining_action_waiting_time(&self, battle_room_id: i32, duration: Duration) -> Option<Duration> {
+        println!("ActionWaitingTimerRepositoryImpl: get_remaining_action_waiting_time()");
+
+        self.action_waiting_timer.get_remaining_time(battle_room_id, duration)
+    }
+
+    async fn remove_action_waiting_timer(&mut self, battle_room_id: i32) -> bool {
+        println!("ActionWaitingTimerRepositoryImpl: remove_action_waiting_timer()");
+
+        self.action_waiting_timer.remove_timer(battle_room_id)
+    }
+}
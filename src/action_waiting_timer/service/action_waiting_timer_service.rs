@@ -1,10 +1,18 @@
 use async_trait::async_trait;
 use crate::action_waiting_timer::service::request::action_waiting_timer_request::ActionWaitingTimerRequest;
+use crate::action_waiting_timer::service::request::check_action_waiting_timer_expired_request::CheckActionWaitingTimerExpiredRequest;
+use crate::action_waiting_timer::service::request::get_remaining_action_waiting_time_request::GetRemainingActionWaitingTimeRequest;
+use crate::action_waiting_timer::service::request::remove_action_waiting_timer_request::RemoveActionWaitingTimerRequest;
 use crate::action_waiting_timer::service::response::action_waiting_timer_response::ActionWaitingTimerResponse;
+use crate::action_waiting_timer::service::response::check_action_waiting_timer_expired_response::CheckActionWaitingTimerExpiredResponse;
+use crate::action_waiting_timer::service::response::get_remaining_action_waiting_time_response::GetRemainingActionWaitingTimeResponse;
+use crate::action_waiting_timer::service::response::remove_action_waiting_timer_response::RemoveActionWaitingTimerResponse;
 
 
 #[async_trait]
 pub trait ActionWaitingTimerService {
     async fn set_action_waiting_time(&self, action_waiting_timer_request: ActionWaitingTimerRequest) -> ActionWaitingTimerResponse;
-
-}
\ No newline at end of file
+    async fn get_remaining_action_waiting_time(&self, get_remaining_action_waiting_time_request: GetRemainingActionWaitingTimeRequest) -> GetRemainingActionWaitingTimeResponse;
+    async fn check_action_waiting_timer_expired(&self, check_action_waiting_timer_expired_request: CheckActionWaitingTimerExpiredRequest) -> CheckActionWaitingTimerExpiredResponse;
+    async fn remove_action_waiting_timer(&self, remove_action_waiting_timer_request: RemoveActionWaitingTimerRequest) -> RemoveActionWaitingTimerResponse;
+}
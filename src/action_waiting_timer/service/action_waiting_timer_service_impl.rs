@@ -1,5 +1,4 @@
 use std::sync::Arc;
-use std::time::Duration;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
@@ -7,22 +6,31 @@ use crate::action_waiting_timer::repository::action_waiting_timer_repository::Ac
 use crate::action_waiting_timer::repository::action_waiting_timer_repository_impl::ActionWaitingTimerRepositoryImpl;
 use crate::action_waiting_timer::service::action_waiting_timer_service::ActionWaitingTimerService;
 use crate::action_waiting_timer::service::request::action_waiting_timer_request::ActionWaitingTimerRequest;
+use crate::action_waiting_timer::service::request::check_action_waiting_timer_expired_request::CheckActionWaitingTimerExpiredRequest;
+use crate::action_waiting_timer::service::request::get_remaining_action_waiting_time_request::GetRemainingActionWaitingTimeRequest;
+use crate::action_waiting_timer::service::request::remove_action_waiting_timer_request::RemoveActionWaitingTimerRequest;
 use crate::action_waiting_timer::service::response::action_waiting_timer_response::ActionWaitingTimerResponse;
-use crate::battle_wait_queue::service::response::battle_match_cancel_response::BattleMatchCancelResponse;
-use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
-use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
+use crate::action_waiting_timer::service::response::check_action_waiting_timer_expired_response::CheckActionWaitingTimerExpiredResponse;
+use crate::action_waiting_timer::service::response::get_remaining_action_waiting_time_response::GetRemainingActionWaitingTimeResponse;
+use crate::action_waiting_timer::service::response::remove_action_waiting_timer_response::RemoveActionWaitingTimerResponse;
+use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
+use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::server_config::server_config::ServerConfig;
 
 pub struct ActionWaitingTimerServiceImpl {
     action_waiting_timer_repository: Arc<AsyncMutex<ActionWaitingTimerRepositoryImpl>>,
+    battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
 }
 
 impl ActionWaitingTimerServiceImpl {
     pub fn new(
                action_waiting_timer_repository: Arc<AsyncMutex<ActionWaitingTimerRepositoryImpl>>,
+               battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
     ) -> Self {
 
         ActionWaitingTimerServiceImpl {
-            action_waiting_timer_repository
+            action_waiting_timer_repository,
+            battle_room_repository,
         }
     }
 
@@ -32,33 +40,81 @@ impl ActionWaitingTimerServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         ActionWaitingTimerServiceImpl::new(
-                            ActionWaitingTimerRepositoryImpl::get_instance())));
+                            ActionWaitingTimerRepositoryImpl::get_instance(),
+                            BattleRoomRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
     }
 
+    // 턴 타이머는 방 단위로 관리되므로 계정이 속한 방 번호를 찾는다
+    async fn find_battle_room_id(&self, account_unique_id: i32) -> Option<i32> {
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let battle_room_id = battle_room_repository_guard.what_is_the_room_number(account_unique_id).await;
+        drop(battle_room_repository_guard);
+        battle_room_id
+    }
 }
 
 #[async_trait]
 impl ActionWaitingTimerService for ActionWaitingTimerServiceImpl {
+    async fn set_action_waiting_time(&self, action_waiting_timer_request: ActionWaitingTimerRequest) -> ActionWaitingTimerResponse {
+        println!("ActionWaitingTimerServiceImpl: set_action_waiting_time()");
 
+        let account_unique_id = action_waiting_timer_request.get_account_unique_id();
+        let battle_room_id = match self.find_battle_room_id(account_unique_id).await {
+            Some(battle_room_id) => battle_room_id,
+            None => return ActionWaitingTimerResponse::new(false),
+        };
 
+        let mut action_waiting_timer_repository_guard = self.action_waiting_timer_repository.lock().await;
+        let response =
+            action_waiting_timer_repository_guard.set_action_waiting_timer(
+                battle_room_id, ServerConfig::get_instance().get_turn_time_limit()).await;
 
-    async fn set_action_waiting_time(&self, action_waiting_timer_request: ActionWaitingTimerRequest) -> ActionWaitingTimerResponse {
-        println!("ActionWaitingTimerServiceImpl: measure_action_waiting_time()");
-        let account_unique_id = action_waiting_timer_request.get_account_unique_id();
+        ActionWaitingTimerResponse::new(response)
+    }
+
+    async fn get_remaining_action_waiting_time(&self, get_remaining_action_waiting_time_request: GetRemainingActionWaitingTimeRequest) -> GetRemainingActionWaitingTimeResponse {
+        println!("ActionWaitingTimerServiceImpl: get_remaining_action_waiting_time()");
 
-        let mut action_waiting_timer_repository = self.action_waiting_timer_repository.lock().await;
+        let turn_time_limit = ServerConfig::get_instance().get_turn_time_limit();
+        let account_unique_id = get_remaining_action_waiting_time_request.get_account_unique_id();
 
+        // 아직 턴 타이머가 시작되지 않았다면 제한 시간 전체가 남아 있는 것으로 본다
+        let remaining_time = match self.find_battle_room_id(account_unique_id).await {
+            Some(battle_room_id) => {
+                let action_waiting_timer_repository_guard = self.action_waiting_timer_repository.lock().await;
+                action_waiting_timer_repository_guard.get_remaining_action_waiting_time(
+                    battle_room_id, turn_time_limit).await.unwrap_or(turn_time_limit)
+            },
+            None => turn_time_limit,
+        };
 
-        let mut response=action_waiting_timer_repository.set_action_waiting_timer(account_unique_id, Duration::from_secs(60)).await;
+        let remaining_seconds = (remaining_time.as_millis() as u64 + 999) / 1000;
 
+        GetRemainingActionWaitingTimeResponse::new(remaining_seconds)
+    }
 
+    async fn check_action_waiting_timer_expired(&self, check_action_waiting_timer_expired_request: CheckActionWaitingTimerExpiredRequest) -> CheckActionWaitingTimerExpiredResponse {
+        println!("ActionWaitingTimerServiceImpl: check_action_waiting_timer_expired()");
 
-        if response==true {
-            return ActionWaitingTimerResponse::new(true)
-        }
+        let action_waiting_timer_repository_guard = self.action_waiting_timer_repository.lock().await;
+        let is_expired =
+            action_waiting_timer_repository_guard.check_action_waiting_timer_expired(
+                check_action_waiting_timer_expired_request.get_battle_room_id(),
+                ServerConfig::get_instance().get_turn_time_limit()).await;
 
-        return ActionWaitingTimerResponse::new(false)
+        CheckActionWaitingTimerExpiredResponse::new(is_expired)
     }
-}
\ No newline at end of file
+
+    async fn remove_action_waiting_timer(&self, remove_action_waiting_timer_request: RemoveActionWaitingTimerRequest) -> RemoveActionWaitingTimerResponse {
+        println!("ActionWaitingTimerServiceImpl: remove_action_waiting_timer()");
+
+        let mut action_waiting_timer_repository_guard = self.action_waiting_timer_repository.lock().await;
+        let is_success =
+            action_waiting_timer_repository_guard.remove_action_waiting_timer(
+                remove_action_waiting_timer_request.get_battle_room_id()).await;
+
+        RemoveActionWaitingTimerResponse::new(is_success)
+    }
+}
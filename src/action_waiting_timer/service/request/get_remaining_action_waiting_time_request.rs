@@ -1,11 +1,11 @@
 #[derive(Debug)]
-pub struct ApplyStatusEffectDamageIterativelyRequest {
+pub struct GetRemainingActionWaitingTimeRequest {
     account_unique_id: i32,
 }
 
-impl ApplyStatusEffectDamageIterativelyRequest {
+impl GetRemainingActionWaitingTimeRequest {
     pub fn new(account_unique_id: i32) -> Self {
-        ApplyStatusEffectDamageIterativelyRequest {
+        GetRemainingActionWaitingTimeRequest {
             account_unique_id,
         }
     }
@@ -1 +1,4 @@
-pub mod action_waiting_timer_request;
\ No newline at end of file
+pub mod action_waiting_timer_request;
+pub mod get_remaining_action_waiting_time_request;
+pub mod check_action_waiting_timer_expired_request;
+pub mod remove_action_waiting_timer_request;
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveActionWaitingTimerRequest {
+    battle_room_id: i32,
+}
+
+impl RemoveActionWaitingTimerRequest {
+    pub fn new(battle_room_id: i32) -> Self {
+        RemoveActionWaitingTimerRequest {
+            battle_room_id,
+        }
+    }
+
+    pub fn get_battle_room_id(&self) -> i32 {
+        self.battle_room_id
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct CheckActionWaitingTimerExpiredResponse {
+    is_expired: bool,
+}
+
+impl CheckActionWaitingTimerExpiredResponse {
+    pub fn new(is_expired: bool) -> Self {
+        CheckActionWaitingTimerExpiredResponse {
+            is_expired,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired
+    }
+}
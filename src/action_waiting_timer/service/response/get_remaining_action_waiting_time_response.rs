@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct GetRemainingActionWaitingTimeResponse {
+    remaining_seconds: u64,
+}
+
+impl GetRemainingActionWaitingTimeResponse {
+    pub fn new(remaining_seconds: u64) -> Self {
+        GetRemainingActionWaitingTimeResponse {
+            remaining_seconds,
+        }
+    }
+
+    pub fn get_remaining_seconds(&self) -> u64 {
+        self.remaining_seconds
+    }
+}
@@ -1 +1,4 @@
-pub mod action_waiting_timer_response;
\ No newline at end of file
+pub mod action_waiting_timer_response;
+pub mod get_remaining_action_waiting_time_response;
+pub mod check_action_waiting_timer_expired_response;
+pub mod remove_action_waiting_timer_response;
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveActionWaitingTimerResponse {
+    is_success: bool,
+}
+
+impl RemoveActionWaitingTimerResponse {
+    pub fn new(is_success: bool) -> Self {
+        RemoveActionWaitingTimerResponse {
+            is_success,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
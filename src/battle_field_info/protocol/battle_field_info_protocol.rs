@@ -0,0 +1,16 @@
+use crate::battle_field_info::service::battle_field_info_service::BattleFieldInfoService;
+use crate::battle_field_info::service::battle_field_info_service_impl::BattleFieldInfoServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::remain_deck_card_count_request_generator::create_remain_deck_card_count_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_field_info_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Battlefield info remain my deck card count
+    protocol_registry.register_battle_room_action(101, "REMAIN_DECK_CARD_COUNT", create_remain_deck_card_count_request, |request| async move {
+        let battle_field_info_service_mutex = BattleFieldInfoServiceImpl::get_instance();
+        let mut battle_field_info_service = battle_field_info_service_mutex.lock().await;
+
+        let response = battle_field_info_service.get_remain_deck_card_count(request).await;
+        ResponseType::REMAIN_DECK_CARD_COUNT(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod battle_field_info_protocol;
@@ -16,17 +16,14 @@ use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepo
 
 pub struct BattleFieldInfoServiceImpl {
     battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
-    game_deck_repository:   Arc<AsyncMutex<GameDeckRepositoryImpl>>,
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>
 }
 
 impl BattleFieldInfoServiceImpl {
     pub fn new(battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
-               game_deck_repository:   Arc<AsyncMutex<GameDeckRepositoryImpl>>,
                redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,) -> Self {
         BattleFieldInfoServiceImpl {
             battle_room_repository,
-            game_deck_repository,
             redis_in_memory_repository,
         }
     }
@@ -38,7 +35,6 @@ impl BattleFieldInfoServiceImpl {
                     AsyncMutex::new(
                         BattleFieldInfoServiceImpl::new(
                             BattleRoomRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
                             RedisInMemoryRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
@@ -59,7 +55,8 @@ impl BattleFieldInfoService for BattleFieldInfoServiceImpl {
         let battle_room_repository_guard=self.battle_room_repository.lock().await;
         let opponent_id= battle_room_repository_guard.find_opponent_unique_id(account_unique_id).await.unwrap();
 
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         if get_my_remain_deck_card_count_request.get_who()=="me"
         {
             let mut count=game_deck_repository_guard.get_remain_deck_card_count(account_unique_id);
@@ -0,0 +1,16 @@
+use crate::battle_finish::service::battle_finish_service::BattleFinishService;
+use crate::battle_finish::service::battle_finish_service_impl::BattleFinishServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::battle_finish_generator::create_battle_finish_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_finish_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Battle finish
+    protocol_registry.register_battle_room_action(4442, "BATTLE_FINISH", create_battle_finish_request, |request| async move {
+        let battle_finish_service_mutex = BattleFinishServiceImpl::get_instance();
+        let battle_finish_service = battle_finish_service_mutex.lock().await;
+
+        let response = battle_finish_service.battle_finish_for_player_battle(request).await;
+        ResponseType::BATTLE_FINISH(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod battle_finish_protocol;
@@ -6,6 +6,7 @@ use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::time;
 use tokio::time::sleep;
+use chrono::Local;
 use uuid::Uuid;
 
 use crate::account::repository::account_repository::AccountRepository;
@@ -15,8 +16,14 @@ use crate::account::service::account_service_impl::AccountServiceImpl;
 use crate::account::service::request::account_register_request::AccountRegisterRequest;
 use crate::account_point::repository::account_point_repository::AccountPointRepository;
 use crate::account_point::repository::account_point_repository_impl::AccountPointRepositoryImpl;
+use crate::account_rating::entity::elo_rating_calculator::EloRatingCalculator;
+use crate::account_rating::entity::match_history::MatchHistory;
+use crate::account_rating::repository::account_rating_repository::AccountRatingRepository;
+use crate::account_rating::repository::account_rating_repository_impl::AccountRatingRepositoryImpl;
 
 use crate::battle_finish::service::battle_finish_service::BattleFinishService;
+use crate::battle_finish::service::request::battle_finish_for_abandoned_player_request::BattleFinishForAbandonedPlayerRequest;
+use crate::battle_finish::service::request::battle_finish_for_drawn_player_request::BattleFinishForDrawnPlayerRequest;
 use crate::battle_finish::service::request::battle_finish_request::BattleFinishRequest;
 use crate::battle_finish::service::response::battle_finish_response::BattleFinishResponse;
 
@@ -25,8 +32,17 @@ use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repo
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository_impl::BattleReadyAccountHashRepositoryImpl;
 use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
 use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::battle_room_actor::entity::battle_room_state::spawn_in_battle_room;
+use crate::battle_room_actor::repository::battle_room_actor_repository::BattleRoomActorRepository;
+use crate::battle_room_actor::repository::battle_room_actor_repository_impl::BattleRoomActorRepositoryImpl;
 use crate::game_card_support_usage_counter::repository::game_card_support_usage_counter_repository::GameCardSupportUsageCounterRepository;
 use crate::game_card_support_usage_counter::repository::game_card_support_usage_counter_repository_impl::GameCardSupportUsageCounterRepositoryImpl;
+use crate::game_card_trap::repository::game_card_trap_repository::GameCardTrapRepository;
+use crate::game_card_trap::repository::game_card_trap_repository_impl::GameCardTrapRepositoryImpl;
+use crate::game_card_environment::repository::game_card_environment_repository::GameCardEnvironmentRepository;
+use crate::game_card_environment::repository::game_card_environment_repository_impl::GameCardEnvironmentRepositoryImpl;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_deck::repository::game_deck_repository::GameDeckRepository;
 use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
 use crate::game_field_energy::repository::game_field_energy_repository::GameFieldEnergyRepository;
@@ -45,6 +61,7 @@ use crate::game_round::repository::game_round_repository::GameRoundRepository;
 use crate::game_round::repository::game_round_repository_impl::GameRoundRepositoryImpl;
 use crate::game_turn::repository::game_turn_repository::GameTurnRepository;
 use crate::game_turn::repository::game_turn_repository_impl::GameTurnRepositoryImpl;
+use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum;
 use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum::{Draw, Loser, Winner};
 use crate::game_winner_check::repository::game_winner_check_repository::GameWinnerCheckRepository;
 use crate::game_winner_check::repository::game_winner_check_repository_impl::GameWinnerCheckRepositoryImpl;
@@ -53,16 +70,12 @@ use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepo
 
 pub struct BattleFinishServiceImpl {
     battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
     game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
     game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
     game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>,
     account_point_repository: Arc<AsyncMutex<AccountPointRepositoryImpl>>,
+    account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
     battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
     game_winner_check_repository: Arc<AsyncMutex<GameWinnerCheckRepositoryImpl>>,
@@ -70,31 +83,23 @@ pub struct BattleFinishServiceImpl {
 
 impl BattleFinishServiceImpl {
     pub fn new(battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
-               game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-               game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-               game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
                game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
                game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-               game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
                game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
                game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>,
                account_point_repository: Arc<AsyncMutex<AccountPointRepositoryImpl>>,
+               account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
                redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
                battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
                game_winner_check_repository: Arc<AsyncMutex<GameWinnerCheckRepositoryImpl>>, ) -> Self {
         BattleFinishServiceImpl {
             battle_ready_account_hash_repository,
-            game_deck_repository,
-            game_hand_repository,
-            game_field_energy_repository,
-            game_field_unit_repository,
             game_lost_zone_repository,
             game_main_character_repository,
-            game_tomb_repository,
             game_round_repository,
             game_card_support_usage_counter_repository,
             account_point_repository,
+            account_rating_repository,
             redis_in_memory_repository,
             battle_room_repository,
             game_winner_check_repository,
@@ -108,22 +113,123 @@ impl BattleFinishServiceImpl {
                     AsyncMutex::new(
                         BattleFinishServiceImpl::new(
                             BattleReadyAccountHashRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
-                            GameFieldEnergyRepositoryImpl::get_instance(),
-                            GameFieldUnitRepositoryImpl::get_instance(),
                             GameLostZoneRepositoryImpl::get_instance(),
                             GameMainCharacterRepositoryImpl::get_instance(),
-                            GameTombRepositoryImpl::get_instance(),
                             GameRoundRepositoryImpl::get_instance(),
                             GameCardSupportUsageCounterRepositoryImpl::get_instance(),
                             AccountPointRepositoryImpl::get_instance(),
+                            AccountRatingRepositoryImpl::get_instance(),
                             RedisInMemoryRepositoryImpl::get_instance(),
                             BattleRoomRepositoryImpl::get_instance(),
                             GameWinnerCheckRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
     }
+
+    // 양측 레이팅을 대전 이전 값 기준으로 한 번에 계산하고 전적을 남긴다
+    async fn settle_battle_rating(&self,
+                                  account_unique_id: i32,
+                                  finish_position: FinishPositionEnum,
+                                  opponent_unique_id: i32,
+                                  opponent_finish_position: FinishPositionEnum) -> bool {
+
+        println!("BattleFinishServiceImpl: settle_battle_rating()");
+
+        let account_rating_repository_guard = self.account_rating_repository.lock().await;
+
+        let (mut account_rating, mut opponent_rating) = match (
+            account_rating_repository_guard.find_or_create_by_account_id(account_unique_id).await,
+            account_rating_repository_guard.find_or_create_by_account_id(opponent_unique_id).await) {
+            (Ok(account_rating), Ok(opponent_rating)) => (account_rating, opponent_rating),
+            _ => {
+                println!("Failed to load account ratings");
+                return false
+            }
+        };
+
+        let rating_before = account_rating.rating();
+        let opponent_rating_before = opponent_rating.rating();
+        let rating_after = EloRatingCalculator::calculate_new_rating(rating_before, opponent_rating_before, finish_position);
+        let opponent_rating_after = EloRatingCalculator::calculate_new_rating(opponent_rating_before, rating_before, opponent_finish_position);
+        println!("rating: {} -> {}, opponent rating: {} -> {}", rating_before, rating_after, opponent_rating_before, opponent_rating_after);
+
+        account_rating.apply_battle_result(rating_after, finish_position);
+        opponent_rating.apply_battle_result(opponent_rating_after, opponent_finish_position);
+
+        let finished_at = Local::now().timestamp();
+        let match_history = MatchHistory::new(
+            account_unique_id, opponent_unique_id, finish_position as i32, rating_before, rating_after, finished_at);
+        let opponent_match_history = MatchHistory::new(
+            opponent_unique_id, account_unique_id, opponent_finish_position as i32, opponent_rating_before, opponent_rating_after, finished_at);
+
+        let result_rating = account_rating_repository_guard.update_account_rating(&account_rating).await;
+        let result_opponent_rating = account_rating_repository_guard.update_account_rating(&opponent_rating).await;
+        let result_match_history = account_rating_repository_guard.save_match_history(match_history).await;
+        let result_opponent_match_history = account_rating_repository_guard.save_match_history(opponent_match_history).await;
+
+        result_rating.is_ok() && result_opponent_rating.is_ok() &&
+            result_match_history.is_ok() && result_opponent_match_history.is_ok()
+    }
+
+    // 승패 결과에 따라 골드 보상 지급
+    async fn give_battle_reward(&self, user_id: i32, finish_position: FinishPositionEnum) -> bool {
+        let mut reward_gold: i32 = 0;
+        if finish_position == Winner {
+            println!("Winner 보상 100 gold");
+            reward_gold = 100;
+        } else if finish_position == Loser {
+            println!("Loser 보상 50 gold");
+            reward_gold = 50;
+        } else if finish_position == Draw {
+            println!("Draw 보상 70 gold");
+            reward_gold = 70;
+        } else {
+            println!("finish_position_enum_error");
+        }
+
+        let account_point_repository_guard = self.account_point_repository.lock().await;
+
+        let found_account_point = match account_point_repository_guard.find_by_account_id(user_id).await {
+            Ok(Some(found_account_point)) => found_account_point,
+            _ => {
+                println!("Account point of {} not found", user_id);
+                return false
+            }
+        };
+        let current_gold = found_account_point.gold;
+        let result_gold = current_gold + reward_gold;
+        let result_reward = account_point_repository_guard.update_gold(found_account_point, result_gold).await;
+        drop(account_point_repository_guard);
+
+        result_reward.is_ok()
+    }
+
+    // 본인의 승패 결과를 꺼내 지우고, 상대 결과가 남아 있다면 양측 레이팅을 정산
+    async fn settle_finish_position(&self, user_id: i32) -> Option<FinishPositionEnum> {
+        println!("BattleFinishServiceImpl: settle_finish_position()");
+
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let opponent_unique_id_option = battle_room_repository_guard.find_opponent_unique_id(user_id).await;
+        drop(battle_room_repository_guard);
+
+        let mut game_winner_check_repository_guard = self.game_winner_check_repository.lock().await;
+        let finish_position = game_winner_check_repository_guard.get_finish_position_enum(user_id).copied()?;
+
+        // 상대 결과가 아직 남아 있다면 먼저 종료 요청한 쪽이 양측 레이팅을 정산
+        let opponent_finish_position_option = opponent_unique_id_option.and_then(|opponent_unique_id|
+            game_winner_check_repository_guard.get_finish_position_enum(opponent_unique_id).copied());
+        game_winner_check_repository_guard.remove_finish_position_by_account_id(user_id);
+
+        drop(game_winner_check_repository_guard);
+
+        if let (Some(opponent_unique_id), Some(opponent_finish_position)) = (opponent_unique_id_option, opponent_finish_position_option) {
+            if !self.settle_battle_rating(user_id, finish_position, opponent_unique_id, opponent_finish_position).await {
+                println!("Failed to settle battle rating");
+            }
+        }
+
+        Some(finish_position)
+    }
 }
 
 pub async fn remove_player_deck(user_id: i32) {
@@ -226,6 +332,36 @@ pub async fn remove_player_support_card_usage_counter(user_id: i32) {
     sleep(time::Duration::from_millis(300)).await;
 }
 
+pub async fn remove_player_trap_zone(user_id: i32) {
+    let game_card_trap_repository_mutex = GameCardTrapRepositoryImpl::get_instance();
+    let mut game_card_trap_repository_guard = game_card_trap_repository_mutex.lock().await;
+    game_card_trap_repository_guard.remove_game_trap_zone_hash_by_account_unique_id(user_id);
+    println!("game_trap_zone_map: {:?}", game_card_trap_repository_guard.get_game_trap_zone_map());
+    drop(game_card_trap_repository_guard);
+
+    sleep(time::Duration::from_millis(300)).await;
+}
+
+pub async fn remove_player_environment_slot(user_id: i32) {
+    let game_card_environment_repository_mutex = GameCardEnvironmentRepositoryImpl::get_instance();
+    let mut game_card_environment_repository_guard = game_card_environment_repository_mutex.lock().await;
+    game_card_environment_repository_guard.remove_game_environment_slot_hash_by_account_unique_id(user_id);
+    println!("game_environment_slot_map: {:?}", game_card_environment_repository_guard.get_game_environment_slot_map());
+    drop(game_card_environment_repository_guard);
+
+    sleep(time::Duration::from_millis(300)).await;
+}
+
+pub async fn remove_player_game_event_bus(user_id: i32) {
+    let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+    let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+    game_event_bus_repository_guard.remove_game_event_bus_hash_by_account_unique_id(user_id);
+    println!("game_event_bus_map: {:?}", game_event_bus_repository_guard.get_game_event_bus_map());
+    drop(game_event_bus_repository_guard);
+
+    sleep(time::Duration::from_millis(300)).await;
+}
+
 // pub async fn player_battle_ready_account_hash_config_thread(user_id: i32) {
 //     let battle_ready_account_hash_repository_mutex = BattleReadyAccountHashRepositoryImpl::get_instance();
 //     let mut battle_ready_account_hash_repository_guard = battle_ready_account_hash_repository_mutex.lock().await;
@@ -250,25 +386,41 @@ pub async fn remove_player_battle_ready_account_hash_status(user_id: i32) {
 pub async fn remove_battle_room_player(user_id: i32) {
     let battle_room_repository_mutex = BattleRoomRepositoryImpl::get_instance();
     let mut battle_room_repository_guard = battle_room_repository_mutex.lock().await;
+    let battle_room_id_option = battle_room_repository_guard.what_is_the_room_number(user_id).await;
     battle_room_repository_guard.remove_battle_room_player(user_id).await;
+
+    // 방이 비었다면 해당 방의 Actor 도 정리
+    let is_battle_room_removed = match battle_room_id_option {
+        Some(battle_room_id) => battle_room_repository_guard.get_players_in_battle_room(battle_room_id).await.is_none(),
+        None => false,
+    };
     drop(battle_room_repository_guard);
 
+    if let (true, Some(battle_room_id)) = (is_battle_room_removed, battle_room_id_option) {
+        let battle_room_actor_repository_mutex = BattleRoomActorRepositoryImpl::get_instance();
+        let mut battle_room_actor_repository_guard = battle_room_actor_repository_mutex.lock().await;
+        battle_room_actor_repository_guard.remove_battle_room_actor(battle_room_id).await;
+    }
+
     sleep(time::Duration::from_millis(300)).await;
 }
 
 pub async fn spawn_async_task_for_finish_battle(user_id: i32) {
-    let task_deck_remove = tokio::spawn(remove_player_deck(user_id));
-    let task_hand_remove = tokio::spawn(remove_player_hand(user_id));
-    let task_field_energy_remove = tokio::spawn(remove_player_field_energy(user_id));
-    let task_field_unit_remove = tokio::spawn(remove_player_field_unit(user_id));
-    let task_lost_zone_remove = tokio::spawn(remove_player_lost_zone(user_id));
-    let task_main_character_remove = tokio::spawn(remove_player_main_character(user_id));
-    let task_tomb_remove = tokio::spawn(remove_player_tomb(user_id));
-    let task_round_remove = tokio::spawn(remove_player_round(user_id));
-    let task_turn_remove = tokio::spawn(remove_player_turn(user_id));
-    let task_support_usage_counter_remove = tokio::spawn(remove_player_support_card_usage_counter(user_id));
-    let task_battle_ready_account_hash_status_remove = tokio::spawn(remove_player_battle_ready_account_hash_status(user_id));
-    let task_remove_battle_room_player = tokio::spawn(remove_battle_room_player(user_id));
+    let task_deck_remove = spawn_in_battle_room(remove_player_deck(user_id));
+    let task_hand_remove = spawn_in_battle_room(remove_player_hand(user_id));
+    let task_field_energy_remove = spawn_in_battle_room(remove_player_field_energy(user_id));
+    let task_field_unit_remove = spawn_in_battle_room(remove_player_field_unit(user_id));
+    let task_lost_zone_remove = spawn_in_battle_room(remove_player_lost_zone(user_id));
+    let task_main_character_remove = spawn_in_battle_room(remove_player_main_character(user_id));
+    let task_tomb_remove = spawn_in_battle_room(remove_player_tomb(user_id));
+    let task_round_remove = spawn_in_battle_room(remove_player_round(user_id));
+    let task_turn_remove = spawn_in_battle_room(remove_player_turn(user_id));
+    let task_support_usage_counter_remove = spawn_in_battle_room(remove_player_support_card_usage_counter(user_id));
+    let task_trap_zone_remove = spawn_in_battle_room(remove_player_trap_zone(user_id));
+    let task_environment_slot_remove = spawn_in_battle_room(remove_player_environment_slot(user_id));
+    let task_game_event_bus_remove = spawn_in_battle_room(remove_player_game_event_bus(user_id));
+    let task_battle_ready_account_hash_status_remove = spawn_in_battle_room(remove_player_battle_ready_account_hash_status(user_id));
+    let task_remove_battle_room_player = spawn_in_battle_room(remove_battle_room_player(user_id));
 
     let _ = tokio::try_join!(
         task_deck_remove,
@@ -281,6 +433,9 @@ pub async fn spawn_async_task_for_finish_battle(user_id: i32) {
         task_round_remove,
         task_turn_remove,
         task_support_usage_counter_remove,
+        task_trap_zone_remove,
+        task_environment_slot_remove,
+        task_game_event_bus_remove,
         task_battle_ready_account_hash_status_remove,
         task_remove_battle_room_player,
     );
@@ -297,40 +452,52 @@ impl BattleFinishService for BattleFinishServiceImpl {
         println!("battle_finish_for_player_battle_user_id: {:?}", user_id);
         drop(redis_repository_guard);
 
-        let mut reward_gold: i32 = 0;
-        let mut game_winner_check_repository_guard = self.game_winner_check_repository.lock().await;
-        let mut user_finish_position = game_winner_check_repository_guard.get_finish_position_enum(user_id).unwrap();
-        if *user_finish_position == Winner {
-            println!("Winner 보상 100 gold");
-            reward_gold = 100;
-        } else if *user_finish_position == Loser {
-            println!("Loser 보상 50 gold");
-            reward_gold = 50;
-        } else if *user_finish_position == Draw {
-            println!("Draw 보상 70 gold");
-            reward_gold = 70;
-        } else {
-            println!("finish_position_enum_error");
+        let user_finish_position = self.settle_finish_position(user_id).await.unwrap();
+        let result_reward = self.give_battle_reward(user_id, user_finish_position).await;
+
+        println!("BattleFinishServiceImpl: spawn_async_task_for_finish_battle()");
+        let result_task_for_finish_battle = spawn_in_battle_room(spawn_async_task_for_finish_battle(user_id)).await;
+
+        if result_task_for_finish_battle.is_ok() && result_reward {
+            return BattleFinishResponse::new(true)
         }
-        game_winner_check_repository_guard.remove_finish_position_by_account_id(user_id);
+        return BattleFinishResponse::new(false)
+    }
 
-        drop(game_winner_check_repository_guard);
+    // 접속이 끊겨 기권 처리된 플레이어는 종료 요청을 보낼 수 없으므로 서버가 대신 정리 (보상 없음)
+    async fn battle_finish_for_abandoned_player(&self, battle_finish_for_abandoned_player_request: BattleFinishForAbandonedPlayerRequest) -> BattleFinishResponse {
+        println!("BattleFinishServiceImpl: battle_finish_for_abandoned_player()");
 
-        let mut account_point_repository_guard = self.account_point_repository.lock().await;
+        let user_id = battle_finish_for_abandoned_player_request.get_account_unique_id();
 
-        let found_account_point = account_point_repository_guard.find_by_account_id(user_id).await.unwrap().unwrap();
-        let current_gold = found_account_point.gold;
-        let result_gold = current_gold + reward_gold;
-        let result_reward = account_point_repository_guard.update_gold(found_account_point, result_gold).await;
-        drop(account_point_repository_guard);
+        if self.settle_finish_position(user_id).await.is_none() {
+            println!("Finish position of account {} not found", user_id);
+        }
 
         println!("BattleFinishServiceImpl: spawn_async_task_for_finish_battle()");
-        let result_task_for_finish_battle = tokio::spawn(spawn_async_task_for_finish_battle(user_id)).await;
+        let result_task_for_finish_battle = spawn_in_battle_room(spawn_async_task_for_finish_battle(user_id)).await;
 
-        if result_task_for_finish_battle.is_ok() && result_reward.is_ok() {
-            return BattleFinishResponse::new(true)
-        }
-        return BattleFinishResponse::new(false)
+        BattleFinishResponse::new(result_task_for_finish_battle.is_ok())
+    }
+
+    // 서버 종료로 무승부 처리된 플레이어도 종료 요청을 기다리지 않고 서버가 보상까지 지급
+    async fn battle_finish_for_drawn_player(&self, battle_finish_for_drawn_player_request: BattleFinishForDrawnPlayerRequest) -> BattleFinishResponse {
+        println!("BattleFinishServiceImpl: battle_finish_for_drawn_player()");
+
+        let user_id = battle_finish_for_drawn_player_request.get_account_unique_id();
+
+        let result_reward = match self.settle_finish_position(user_id).await {
+            Some(finish_position) => self.give_battle_reward(user_id, finish_position).await,
+            None => {
+                println!("Finish position of account {} not found", user_id);
+                false
+            }
+        };
+
+        println!("BattleFinishServiceImpl: spawn_async_task_for_finish_battle()");
+        let result_task_for_finish_battle = spawn_in_battle_room(spawn_async_task_for_finish_battle(user_id)).await;
+
+        BattleFinishResponse::new(result_task_for_finish_battle.is_ok() && result_reward)
     }
 }
 
@@ -474,6 +641,15 @@ mod tests {
 
         sleep(time::Duration::from_millis(300)).await;
 
+        // game_trap_zone 정보 생성
+        let game_card_trap_repository_mutex = GameCardTrapRepositoryImpl::get_instance();
+        let mut game_card_trap_repository_guard = game_card_trap_repository_mutex.lock().await;
+        game_card_trap_repository_guard.create_game_trap_zone_object(user_id);
+        println!("game_trap_zone_map: {:?}", game_card_trap_repository_guard.get_game_trap_zone_map());
+        drop(game_card_trap_repository_guard);
+
+        sleep(time::Duration::from_millis(300)).await;
+
         // game_finish_position_map 정보 생성
         let game_winner_check_repository_mutex = GameWinnerCheckRepositoryImpl::get_instance();
         let mut game_winner_check_repository_guard = game_winner_check_repository_mutex.lock().await;
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct BattleFinishForAbandonedPlayerRequest {
+    account_unique_id: i32,
+}
+
+impl BattleFinishForAbandonedPlayerRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        BattleFinishForAbandonedPlayerRequest {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
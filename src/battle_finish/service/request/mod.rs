@@ -1 +1,3 @@
-pub mod battle_finish_request;
\ No newline at end of file
+pub mod battle_finish_request;
+pub mod battle_finish_for_abandoned_player_request;
+pub mod battle_finish_for_drawn_player_request;
\ No newline at end of file
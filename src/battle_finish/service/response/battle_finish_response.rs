@@ -9,4 +9,8 @@ impl BattleFinishResponse {
     pub fn new(is_success: bool) -> Self {
         BattleFinishResponse { is_success }
     }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
 }
\ No newline at end of file
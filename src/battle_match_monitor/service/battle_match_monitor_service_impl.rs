@@ -11,11 +11,13 @@ use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repo
 
 use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
 use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::battle_wait_queue::repository::battle_wait_queue_repository::BattleWaitQueueRepository;
 use crate::battle_wait_queue::repository::battle_wait_queue_repository_impl::BattleWaitQueueRepositoryImpl;
 use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller::GameBattleFieldMonitorController;
 use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller_impl::GameBattleFieldMonitorControllerImpl;
 use crate::mulligan_monitor::service::mulligan_monitor_service::MulliganMonitorService;
 use crate::mulligan_monitor::service::mulligan_monitor_service_impl::MulliganMonitorServiceImpl;
+use crate::server_config::server_config::ServerConfig;
 
 pub struct BattleMatchMonitorServiceImpl {
     battle_wait_queue_repository: Arc<AsyncMutex<BattleWaitQueueRepositoryImpl>>,
@@ -46,44 +48,70 @@ impl BattleMatchMonitorServiceImpl {
         }
         INSTANCE.clone()
     }
+
+    // 레이팅 창 안에서 짝이 맞은 두 플레이어로 방을 만들고, 만들었다면 true 를 반환
+    async fn match_rating_matched_players(&self) -> bool {
+        let battle_wait_queue_repository_guard = self.battle_wait_queue_repository.lock().await;
+        let wait_queue_clone_mutex = battle_wait_queue_repository_guard.get_battle_wait_queue();
+        drop(battle_wait_queue_repository_guard);
+
+        let wait_queue_clone_guard = wait_queue_clone_mutex.lock().await;
+        let items = wait_queue_clone_guard.dequeue_rating_matched_players().await;
+        drop(wait_queue_clone_guard);
+
+        if items.len() != 2 {
+            return false
+        }
+
+        let mut battle_ready_account_hash_repository_guard = self.battle_ready_account_hash_repository.lock().await;
+
+        battle_ready_account_hash_repository_guard.save_battle_ready_account_hash(items[0], BattleReadyAccountHashStatus::PREPARE).await;
+        battle_ready_account_hash_repository_guard.save_battle_ready_account_hash(items[1], BattleReadyAccountHashStatus::PREPARE).await;
+        drop(battle_ready_account_hash_repository_guard);
+
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+
+        let battle_room_id = battle_room_repository_guard.set_players_to_battle_room(items).await.expect("전투 배치 실패");
+        drop(battle_room_repository_guard);
+
+        // 방 단위 감시 작업은 방이 닫히면 스스로 종료됨
+        tokio::spawn(async move {
+            let game_battle_field_monitor_controller = GameBattleFieldMonitorControllerImpl::new();
+            game_battle_field_monitor_controller.battle_field_monitoring(battle_room_id).await;
+        });
+
+        tokio::spawn(async move {
+            let mulligan_monitor_service = MulliganMonitorServiceImpl::new();
+            mulligan_monitor_service.mulligan_monitoring(battle_room_id).await;
+        });
+
+        true
+    }
 }
 
 #[async_trait]
 impl BattleMatchMonitorService for BattleMatchMonitorServiceImpl {
     async fn check_battle_match(&self) {
-        loop {
-            let battle_wait_queue_repository_guard = self.battle_wait_queue_repository.lock().await;
-            let wait_queue_clone_mutex = battle_wait_queue_repository_guard.get_battle_wait_queue();
-            let wait_queue_clone_guard = wait_queue_clone_mutex.lock().await;
-
-            let items = wait_queue_clone_guard.dequeue_n_players(2).await;
+        // 대기 중 레이팅 창이 넓어지는 것은 신호가 없으므로 두 명 이상 기다릴 때만 주기적으로 다시 확인
+        let rating_window_retry_interval = ServerConfig::get_instance().get_matchmaker_poll_interval();
 
-            if items.len() == 2 {
-                let mut battle_ready_account_hash_repository_guard = self.battle_ready_account_hash_repository.lock().await;
+        let battle_wait_queue_repository_guard = self.battle_wait_queue_repository.lock().await;
+        let battle_match_signal = battle_wait_queue_repository_guard.get_battle_match_signal();
+        drop(battle_wait_queue_repository_guard);
 
-                battle_ready_account_hash_repository_guard.save_battle_ready_account_hash(items[0], BattleReadyAccountHashStatus::PREPARE).await;
-                battle_ready_account_hash_repository_guard.save_battle_ready_account_hash(items[1], BattleReadyAccountHashStatus::PREPARE).await;
-                drop(battle_ready_account_hash_repository_guard);
-
-                let battle_room_repository_guard = self.battle_room_repository.lock().await;
-
-                battle_room_repository_guard.set_players_to_battle_room(items).await.expect("전투 배치 실패");
-                let battle_room_count = battle_room_repository_guard.get_battle_room_count().await;
-                let battle_room_number = battle_room_count - 1;
-                drop(battle_room_repository_guard);
+        loop {
+            while self.match_rating_matched_players().await {}
 
-                tokio::spawn(async move {
-                    let game_battle_field_monitor_controller = GameBattleFieldMonitorControllerImpl::new();
-                    game_battle_field_monitor_controller.battle_field_monitoring(battle_room_count).await;
-                });
+            let battle_wait_queue_repository_guard = self.battle_wait_queue_repository.lock().await;
+            let wait_queue_length = battle_wait_queue_repository_guard.get_wait_queue_length().await;
+            drop(battle_wait_queue_repository_guard);
 
-                tokio::spawn(async move {
-                    let mulligan_monitor_service = MulliganMonitorServiceImpl::new();
-                    mulligan_monitor_service.mulligan_monitoring(battle_room_number).await;
-                });
+            if wait_queue_length < 2 {
+                battle_match_signal.notified().await;
+                continue
             }
 
-            tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+            let _ = tokio::time::timeout(rating_window_retry_interval, battle_match_signal.notified()).await;
         }
     }
 }
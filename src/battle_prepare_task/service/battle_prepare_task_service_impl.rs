@@ -7,11 +7,17 @@ use tokio::{task, time};
 use tokio::time::sleep;
 use crate::battle_prepare_task::service::battle_prepare_task_service::BattlePrepareTaskService;
 
+use crate::battle_room_actor::entity::battle_room_state::spawn_in_battle_room;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::find_battle_room_actor_handle_of_account;
 use crate::battle_ready_account_hash::entity::battle_ready_account_hash_status::BattleReadyAccountHashStatus;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository::BattleReadyAccountHashRepository;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository_impl::BattleReadyAccountHashRepositoryImpl;
 use crate::game_card_support_usage_counter::repository::game_card_support_usage_counter_repository::GameCardSupportUsageCounterRepository;
 use crate::game_card_support_usage_counter::repository::game_card_support_usage_counter_repository_impl::GameCardSupportUsageCounterRepositoryImpl;
+use crate::game_card_trap::repository::game_card_trap_repository::GameCardTrapRepository;
+use crate::game_card_trap::repository::game_card_trap_repository_impl::GameCardTrapRepositoryImpl;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_deck::repository::game_deck_repository::GameDeckRepository;
 use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
 use crate::game_field_energy::repository::game_field_energy_repository::GameFieldEnergyRepository;
@@ -34,37 +40,22 @@ use crate::game_turn::repository::game_turn_repository_impl::GameTurnRepositoryI
 // TODO: 사실상 지금 필요 없어졌으나 우선은 유지시킴 (비즈니스 로직에 지대한 영향 없음)
 pub struct BattlePrepareTaskServiceImpl {
     battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
     game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
     game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
     game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>,
 }
 
 impl BattlePrepareTaskServiceImpl {
     pub fn new(battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
-               game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-               game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-               game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
                game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
                game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-               game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
                game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
                game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>) -> Self {
         BattlePrepareTaskServiceImpl {
             battle_ready_account_hash_repository,
-            game_deck_repository,
-            game_hand_repository,
-            game_field_energy_repository,
-            game_field_unit_repository,
             game_lost_zone_repository,
             game_main_character_repository,
-            game_tomb_repository,
             game_round_repository,
             game_card_support_usage_counter_repository
         }
@@ -77,13 +68,8 @@ impl BattlePrepareTaskServiceImpl {
                     AsyncMutex::new(
                         BattlePrepareTaskServiceImpl::new(
                             BattleReadyAccountHashRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
-                            GameFieldEnergyRepositoryImpl::get_instance(),
-                            GameFieldUnitRepositoryImpl::get_instance(),
                             GameLostZoneRepositoryImpl::get_instance(),
                             GameMainCharacterRepositoryImpl::get_instance(),
-                            GameTombRepositoryImpl::get_instance(),
                             GameRoundRepositoryImpl::get_instance(),
                             GameCardSupportUsageCounterRepositoryImpl::get_instance())));
         }
@@ -190,17 +176,37 @@ pub async fn player_support_card_usage_counter_init_thread(user_id: i32) {
     sleep(time::Duration::from_millis(300)).await;
 }
 
+pub async fn player_trap_zone_init_thread(user_id: i32) {
+    let game_card_trap_repository_mutex = GameCardTrapRepositoryImpl::get_instance();
+    let mut game_card_trap_repository_guard = game_card_trap_repository_mutex.lock().await;
+    game_card_trap_repository_guard.create_game_trap_zone_object(user_id);
+    drop(game_card_trap_repository_guard);
+
+    sleep(time::Duration::from_millis(300)).await;
+}
+
+pub async fn player_game_event_bus_init_thread(user_id: i32) {
+    let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+    let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+    game_event_bus_repository_guard.create_game_event_bus_object(user_id);
+    drop(game_event_bus_repository_guard);
+
+    sleep(time::Duration::from_millis(300)).await;
+}
+
 pub async fn spawn_async_task_for_prepare_battle(user_id: i32) {
-    let task_deck_init = tokio::spawn(player_deck_init_thread(user_id));
-    let task_hand_init = tokio::spawn(player_hand_init_thread(user_id));
-    let task_field_energy_init = tokio::spawn(player_field_energy_init_thread(user_id));
-    let task_field_unit_init = tokio::spawn(player_field_unit_init_thread(user_id));
-    let task_lost_zone_init = tokio::spawn(player_lost_zone_init_thread(user_id));
-    let task_main_character_init = tokio::spawn(player_main_character_init_thread(user_id));
-    let task_tomb_init = tokio::spawn(player_tomb_init_thread(user_id));
-    let task_round_init = tokio::spawn(player_round_init_thread(user_id));
-    let task_turn_init = tokio::spawn(player_turn_init_thread(user_id));
-    let task_support_usage_counter_init = tokio::spawn(player_support_card_usage_counter_init_thread(user_id));
+    let task_deck_init = spawn_in_battle_room(player_deck_init_thread(user_id));
+    let task_hand_init = spawn_in_battle_room(player_hand_init_thread(user_id));
+    let task_field_energy_init = spawn_in_battle_room(player_field_energy_init_thread(user_id));
+    let task_field_unit_init = spawn_in_battle_room(player_field_unit_init_thread(user_id));
+    let task_lost_zone_init = spawn_in_battle_room(player_lost_zone_init_thread(user_id));
+    let task_main_character_init = spawn_in_battle_room(player_main_character_init_thread(user_id));
+    let task_tomb_init = spawn_in_battle_room(player_tomb_init_thread(user_id));
+    let task_round_init = spawn_in_battle_room(player_round_init_thread(user_id));
+    let task_turn_init = spawn_in_battle_room(player_turn_init_thread(user_id));
+    let task_support_usage_counter_init = spawn_in_battle_room(player_support_card_usage_counter_init_thread(user_id));
+    let task_trap_zone_init = spawn_in_battle_room(player_trap_zone_init_thread(user_id));
+    let task_game_event_bus_init = spawn_in_battle_room(player_game_event_bus_init_thread(user_id));
 
     let _ = tokio::try_join!(
         task_deck_init,
@@ -213,10 +219,12 @@ pub async fn spawn_async_task_for_prepare_battle(user_id: i32) {
         task_round_init,
         task_turn_init,
         task_support_usage_counter_init,
+        task_trap_zone_init,
+        task_game_event_bus_init,
     );
 
     let task_battle_ready_account_hash_config =
-        tokio::spawn(player_battle_ready_account_hash_config_thread(user_id));
+        spawn_in_battle_room(player_battle_ready_account_hash_config_thread(user_id));
 
     if let Ok(_) = task_battle_ready_account_hash_config.await {
         println!("Thread for User {} completed.", user_id);
@@ -225,6 +233,22 @@ pub async fn spawn_async_task_for_prepare_battle(user_id: i32) {
     }
 }
 
+// 게임 상태는 방의 Actor 가 소유하므로 해당 방의 Actor 에서 생성
+// 아직 방에 배치되지 않았다면 상태가 PREPARE 로 남아 다음 순회에서 다시 시도됨
+async fn prepare_player_in_battle_room(user_id: i32) {
+    let battle_room_actor_handle = match find_battle_room_actor_handle_of_account(user_id).await {
+        Some(battle_room_actor_handle) => battle_room_actor_handle,
+        None => {
+            println!("User {} is not placed in a battle room yet.", user_id);
+            return
+        }
+    };
+
+    if battle_room_actor_handle.prepare_player(user_id).await.is_none() {
+        eprintln!("Preparing battle for User {} aborted.", user_id);
+    }
+}
+
 #[async_trait]
 impl BattlePrepareTaskService for BattlePrepareTaskServiceImpl {
 
@@ -243,7 +267,7 @@ impl BattlePrepareTaskService for BattlePrepareTaskServiceImpl {
             drop(battle_ready_account_hash_repository_guard);
 
             let handles = users_to_process.into_iter().map(|user_id| {
-                tokio::spawn(spawn_async_task_for_prepare_battle(user_id))
+                tokio::spawn(prepare_player_in_battle_room(user_id))
             }).collect::<Vec<_>>();
 
             for handle in handles {
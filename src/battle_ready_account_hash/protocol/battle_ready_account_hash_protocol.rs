@@ -0,0 +1,26 @@
+use crate::battle_ready_account_hash::service::battle_ready_account_hash_service::BattleReadyAccountHashService;
+use crate::battle_ready_account_hash::service::battle_ready_account_hash_service_impl::BattleReadyAccountHashServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::battle_ready_account_hash_request_generator::create_battle_ready_account_hash_request;
+use crate::request_generator::check_battle_prepare_request_generator::create_check_battle_prepare_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_ready_account_hash_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Is Ready For Battle
+    protocol_registry.register(12, "BATTLE_READY", create_battle_ready_account_hash_request, |request| async move {
+        let battle_ready_account_hash_service_mutex = BattleReadyAccountHashServiceImpl::get_instance();
+        let battle_ready_account_hash_service_guard = battle_ready_account_hash_service_mutex.lock().await;
+
+        let response = battle_ready_account_hash_service_guard.check_ready_for_battle(request).await;
+        ResponseType::BATTLE_READY(response)
+    });
+
+    // Check Battle Prepare (CHECK_BATTLE_PREPARE)
+    protocol_registry.register(14, "CHECK_BATTLE_PREPARE", create_check_battle_prepare_request, |request| async move {
+        let battle_ready_account_hash_service_mutex = BattleReadyAccountHashServiceImpl::get_instance();
+        let battle_ready_account_hash_service_guard = battle_ready_account_hash_service_mutex.lock().await;
+
+        let response = battle_ready_account_hash_service_guard.check_prepare_for_battle(request).await;
+        ResponseType::CHECK_BATTLE_PREPARE(response)
+    });
+}
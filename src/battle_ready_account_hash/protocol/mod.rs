@@ -0,0 +1 @@
+pub mod battle_ready_account_hash_protocol;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::battle_resume::controller::request_form::resume_battle_request_form::ResumeBattleRequestForm;
+use crate::battle_resume::controller::response_form::resume_battle_response_form::ResumeBattleResponseForm;
+
+#[async_trait]
+pub trait BattleResumeController {
+    async fn request_to_resume_battle(
+        &self, resume_battle_request_form: ResumeBattleRequestForm)
+        -> ResumeBattleResponseForm;
+}
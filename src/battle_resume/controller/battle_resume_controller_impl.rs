@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+use crate::action_waiting_timer::service::action_waiting_timer_service::ActionWaitingTimerService;
+use crate::action_waiting_timer::service::action_waiting_timer_service_impl::ActionWaitingTimerServiceImpl;
+use crate::battle_resume::controller::battle_resume_controller::BattleResumeController;
+use crate::battle_resume::controller::request_form::resume_battle_request_form::ResumeBattleRequestForm;
+use crate::battle_resume::controller::response_form::resume_battle_response_form::ResumeBattleResponseForm;
+use crate::battle_resume::service::battle_resume_service::BattleResumeService;
+use crate::battle_resume::service::battle_resume_service_impl::BattleResumeServiceImpl;
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::redis::service::redis_in_memory_service::RedisInMemoryService;
+use crate::redis::service::redis_in_memory_service_impl::RedisInMemoryServiceImpl;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+
+pub struct BattleResumeControllerImpl {
+    battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+    redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+    game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+    action_waiting_timer_service: Arc<AsyncMutex<ActionWaitingTimerServiceImpl>>,
+    battle_resume_service: Arc<AsyncMutex<BattleResumeServiceImpl>>,
+}
+
+impl BattleResumeControllerImpl {
+    pub fn new(battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+               redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+               game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+               action_waiting_timer_service: Arc<AsyncMutex<ActionWaitingTimerServiceImpl>>,
+               battle_resume_service: Arc<AsyncMutex<BattleResumeServiceImpl>>,
+    ) -> Self {
+        BattleResumeControllerImpl {
+            battle_room_service,
+            redis_in_memory_service,
+            game_winner_check_service,
+            action_waiting_timer_service,
+            battle_resume_service,
+        }
+    }
+    pub fn get_instance() -> Arc<AsyncMutex<BattleResumeControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<BattleResumeControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        BattleResumeControllerImpl::new(
+                            BattleRoomServiceImpl::get_instance(),
+                            RedisInMemoryServiceImpl::get_instance(),
+                            GameWinnerCheckServiceImpl::get_instance(),
+                            ActionWaitingTimerServiceImpl::get_instance(),
+                            BattleResumeServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn is_valid_session(&self, request: GetValueWithKeyRequest) -> i32 {
+        let redis_in_memory_service_guard = self.redis_in_memory_service.lock().await;
+        let session_validation_response = redis_in_memory_service_guard.get_value_with_key(request).await;
+        drop(redis_in_memory_service_guard);
+        let value_string = session_validation_response.get_value();
+        value_string.parse::<i32>().unwrap_or_else(|_| { -1 })
+    }
+
+    async fn get_opponent_unique_id(&self, find_opponent_by_account_id_request: FindOpponentByAccountIdRequest) -> i32 {
+        let battle_room_service_guard = self.battle_room_service.lock().await;
+        let find_opponent_by_account_id_response = battle_room_service_guard.find_opponent_by_account_unique_id(find_opponent_by_account_id_request).await;
+        drop(battle_room_service_guard);
+        find_opponent_by_account_id_response.get_opponent_unique_id()
+    }
+}
+
+#[async_trait]
+impl BattleResumeController for BattleResumeControllerImpl {
+    async fn request_to_resume_battle(
+        &self, resume_battle_request_form: ResumeBattleRequestForm)
+        -> ResumeBattleResponseForm {
+
+        println!("BattleResumeControllerImpl: request_to_resume_battle()");
+
+        let account_unique_id = self.is_valid_session(
+            resume_battle_request_form
+                .to_get_value_with_key_request()).await;
+
+        if account_unique_id == -1 {
+            println!("Invalid session");
+            return ResumeBattleResponseForm::default()
+        }
+
+        let opponent_unique_id =
+            self.get_opponent_unique_id(
+                resume_battle_request_form
+                    .to_find_opponent_by_account_id_request(account_unique_id)).await;
+
+        if opponent_unique_id == -1 {
+            println!("There is no battle to resume.");
+            return ResumeBattleResponseForm::default()
+        }
+
+        // 승패가 이미 결정되었다면 battle finish 로 정리해야 함
+        let game_winner_check_service_guard = self.game_winner_check_service.lock().await;
+        let check_game_finished_response =
+            game_winner_check_service_guard.check_game_finished(
+                resume_battle_request_form
+                    .to_check_game_finished_request(account_unique_id)).await;
+        drop(game_winner_check_service_guard);
+
+        if check_game_finished_response.is_finished() {
+            println!("Battle is already finished.");
+            return ResumeBattleResponseForm::default()
+        }
+
+        let battle_resume_service_guard = self.battle_resume_service.lock().await;
+        let get_battle_field_snapshot_response =
+            battle_resume_service_guard.get_battle_field_snapshot(
+                resume_battle_request_form
+                    .to_get_battle_field_snapshot_request(account_unique_id, opponent_unique_id)).await;
+        drop(battle_resume_service_guard);
+
+        let battle_field_snapshot = match get_battle_field_snapshot_response.get_battle_field_snapshot() {
+            Some(battle_field_snapshot) => battle_field_snapshot.clone(),
+            None => {
+                println!("Battle has not started yet.");
+                return ResumeBattleResponseForm::default()
+            }
+        };
+
+        let action_waiting_timer_service_guard = self.action_waiting_timer_service.lock().await;
+        let get_remaining_action_waiting_time_response =
+            action_waiting_timer_service_guard.get_remaining_action_waiting_time(
+                resume_battle_request_form
+                    .to_get_remaining_action_waiting_time_request(account_unique_id)).await;
+        drop(action_waiting_timer_service_guard);
+
+        ResumeBattleResponseForm::from_snapshot(
+            account_unique_id,
+            battle_field_snapshot,
+            get_remaining_action_waiting_time_response.get_remaining_seconds())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod battle_resume_controller;
+pub mod battle_resume_controller_impl;
+pub mod request_form;
+pub mod response_form;
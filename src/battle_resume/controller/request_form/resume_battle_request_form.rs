@@ -0,0 +1,61 @@
+use crate::action_waiting_timer::service::request::get_remaining_action_waiting_time_request::GetRemainingActionWaitingTimeRequest;
+use crate::battle_resume::service::request::get_battle_field_snapshot_request::GetBattleFieldSnapshotRequest;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_winner_check::service::request::check_game_finished_request::CheckGameFinishedRequest;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+
+#[derive(Debug)]
+pub struct ResumeBattleRequestForm {
+    session_id: String,
+}
+
+impl ResumeBattleRequestForm {
+    pub fn new(
+        session_id: String,
+    ) -> Self {
+
+        ResumeBattleRequestForm {
+            session_id,
+        }
+    }
+
+    pub fn to_get_value_with_key_request(
+        &self) -> GetValueWithKeyRequest {
+
+        GetValueWithKeyRequest::new(&self.session_id)
+    }
+
+    pub fn to_find_opponent_by_account_id_request(
+        &self,
+        account_unique_id: i32) -> FindOpponentByAccountIdRequest {
+
+        FindOpponentByAccountIdRequest::new(
+            account_unique_id)
+    }
+
+    pub fn to_check_game_finished_request(
+        &self,
+        account_unique_id: i32) -> CheckGameFinishedRequest {
+
+        CheckGameFinishedRequest::new(
+            account_unique_id)
+    }
+
+    pub fn to_get_remaining_action_waiting_time_request(
+        &self,
+        account_unique_id: i32) -> GetRemainingActionWaitingTimeRequest {
+
+        GetRemainingActionWaitingTimeRequest::new(
+            account_unique_id)
+    }
+
+    pub fn to_get_battle_field_snapshot_request(
+        &self,
+        account_unique_id: i32,
+        opponent_unique_id: i32) -> GetBattleFieldSnapshotRequest {
+
+        GetBattleFieldSnapshotRequest::new(
+            account_unique_id,
+            opponent_unique_id)
+    }
+}
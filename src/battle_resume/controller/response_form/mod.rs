@@ -0,0 +1 @@
+pub mod resume_battle_response_form;
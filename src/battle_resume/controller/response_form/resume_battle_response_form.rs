@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use crate::battle_resume::entity::battle_field_snapshot::BattleFieldSnapshot;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeBattleResponseForm {
+    is_success: bool,
+    battle_field_snapshot: Option<BattleFieldSnapshot>,
+    remaining_turn_time_seconds: u64,
+    // 새 소켓을 Connection Context 에 다시 연결할 때만 사용하며 클라이언트에는 보내지 않음
+    #[serde(skip)]
+    account_unique_id: i32,
+}
+
+impl ResumeBattleResponseForm {
+    pub fn new(is_success: bool,
+               battle_field_snapshot: Option<BattleFieldSnapshot>,
+               remaining_turn_time_seconds: u64,
+               account_unique_id: i32,
+    ) -> Self {
+
+        ResumeBattleResponseForm {
+            is_success,
+            battle_field_snapshot,
+            remaining_turn_time_seconds,
+            account_unique_id,
+        }
+    }
+
+    pub fn from_snapshot(
+        account_unique_id: i32,
+        battle_field_snapshot: BattleFieldSnapshot,
+        remaining_turn_time_seconds: u64,
+    ) -> ResumeBattleResponseForm {
+
+        ResumeBattleResponseForm::new(
+            true,
+            Some(battle_field_snapshot),
+            remaining_turn_time_seconds,
+            account_unique_id)
+    }
+
+    pub fn default() -> ResumeBattleResponseForm {
+        ResumeBattleResponseForm::new(
+            false,
+            None,
+            0,
+            -1)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
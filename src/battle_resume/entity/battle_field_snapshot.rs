@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::battle_resume::entity::environment_slot_snapshot::EnvironmentSlotSnapshot;
+use crate::battle_resume::entity::player_battle_field_snapshot::PlayerBattleFieldSnapshot;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+// 재접속한 플레이어 시점의 전장 정보 (상대의 비공개 정보는 제외)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BattleFieldSnapshot {
+    round: i32,
+    is_your_turn: bool,
+    player_battle_field_map: HashMap<PlayerIndex, PlayerBattleFieldSnapshot>,
+    environment_slot: Option<EnvironmentSlotSnapshot>,
+}
+
+impl BattleFieldSnapshot {
+    pub fn new(round: i32,
+               is_your_turn: bool,
+               your_battle_field: PlayerBattleFieldSnapshot,
+               opponent_battle_field: PlayerBattleFieldSnapshot,
+               environment_slot: Option<EnvironmentSlotSnapshot>) -> Self {
+
+        let mut player_battle_field_map = HashMap::new();
+        player_battle_field_map.insert(PlayerIndex::You, your_battle_field);
+        player_battle_field_map.insert(PlayerIndex::Opponent, opponent_battle_field.redact_hidden_information());
+
+        BattleFieldSnapshot {
+            round,
+            is_your_turn,
+            player_battle_field_map,
+            environment_slot,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_card_environment::entity::game_environment_slot::GameEnvironmentSlot;
+
+    #[test]
+    fn test_opponent_hand_is_redacted() {
+        let your_battle_field = PlayerBattleFieldSnapshot::new(vec![19, 8], 30, 1, 2, 100, HashMap::new(), vec![151]);
+        let opponent_battle_field = PlayerBattleFieldSnapshot::new(vec![25, 27, 151], 28, 0, 1, 80, HashMap::new(), vec![152, 153]);
+
+        let environment_slot = EnvironmentSlotSnapshot::from_game_environment_slot(&GameEnvironmentSlot::new(171, 2, 2), 1);
+
+        let battle_field_snapshot = BattleFieldSnapshot::new(3, true, your_battle_field, opponent_battle_field, Some(environment_slot));
+        let json = serde_json::to_value(&battle_field_snapshot).unwrap();
+        let player_battle_field_map = &json["player_battle_field_map"];
+
+        assert_eq!(player_battle_field_map["You"]["hand_card_list"], serde_json::json!([19, 8]));
+        assert_eq!(player_battle_field_map["You"]["hand_card_count"], 2);
+        assert_eq!(player_battle_field_map["Opponent"]["hand_card_list"], serde_json::json!([]));
+        assert_eq!(player_battle_field_map["Opponent"]["hand_card_count"], 3);
+        assert_eq!(player_battle_field_map["Opponent"]["deck_card_count"], 28);
+        assert_eq!(player_battle_field_map["You"]["trap_card_list"], serde_json::json!([151]));
+        assert_eq!(player_battle_field_map["Opponent"]["trap_card_list"], serde_json::json!([]));
+        assert_eq!(player_battle_field_map["Opponent"]["trap_card_count"], 2);
+        assert_eq!(json["environment_slot"]["environment_card_id"], 171);
+        assert_eq!(json["environment_slot"]["owner"], serde_json::json!("Opponent"));
+        assert_eq!(json["environment_slot"]["remaining_turn"], 2);
+    }
+}
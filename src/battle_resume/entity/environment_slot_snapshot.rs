@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use crate::game_card_environment::entity::game_environment_slot::GameEnvironmentSlot;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+// 양측이 공유하는 공개 정보이며, 배치한 플레이어만 재접속한 플레이어 시점으로 표시
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvironmentSlotSnapshot {
+    environment_card_id: i32,
+    owner: PlayerIndex,
+    remaining_turn: i32,
+}
+
+impl EnvironmentSlotSnapshot {
+    pub fn from_game_environment_slot(game_environment_slot: &GameEnvironmentSlot, account_unique_id: i32) -> Self {
+        let owner = if game_environment_slot.get_owner_unique_id() == account_unique_id {
+            PlayerIndex::You
+        } else {
+            PlayerIndex::Opponent
+        };
+
+        EnvironmentSlotSnapshot {
+            environment_card_id: game_environment_slot.get_environment_card_id(),
+            owner,
+            remaining_turn: game_environment_slot.get_remaining_turn(),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use crate::game_field_unit::entity::game_field_unit_card::GameFieldUnitCard;
+use crate::ui_data_generator::entity::attached_energy_info::AttachedEnergyInfo;
+use crate::ui_data_generator::entity::extra_effect_info::ExtraEffectInfo;
+use crate::ui_data_generator::entity::harmful_effect_info::HarmfulStatusInfo;
+
+// 필드에 공개된 유닛 정보이므로 양측 모두 그대로 전달
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldUnitSnapshot {
+    unit_card_id: i32,
+    attached_energy: AttachedEnergyInfo,
+    extra_effect: ExtraEffectInfo,
+    harmful_status: HarmfulStatusInfo,
+    attack_point: i32,
+    current_health_point: i32,
+    max_health_point: i32,
+    is_alive: bool,
+    turn_action: bool,
+}
+
+impl FieldUnitSnapshot {
+    pub fn from_game_field_unit_card(game_field_unit_card: &GameFieldUnitCard) -> Self {
+        let extra_effect_list = game_field_unit_card.get_extra_status_effect_list()
+            .iter()
+            .map(|extra_status_effect| extra_status_effect.get_extra_effect().clone())
+            .collect();
+
+        FieldUnitSnapshot {
+            unit_card_id: game_field_unit_card.get_card(),
+            attached_energy: game_field_unit_card.get_attached_energy().to_attached_energy_info(),
+            extra_effect: ExtraEffectInfo::new(extra_effect_list),
+            harmful_status: HarmfulStatusInfo::new(game_field_unit_card.get_harmful_status_list()),
+            attack_point: game_field_unit_card.get_unit_attack_point(),
+            current_health_point: game_field_unit_card.get_unit_health_point().get_current_health_point(),
+            max_health_point: game_field_unit_card.get_unit_health_point().get_max_health_point(),
+            is_alive: game_field_unit_card.is_alive(),
+            turn_action: game_field_unit_card.get_turn_action(),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod battle_field_snapshot;
+pub mod player_battle_field_snapshot;
+pub mod field_unit_snapshot;
+pub mod environment_slot_snapshot;
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::battle_resume::entity::field_unit_snapshot::FieldUnitSnapshot;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerBattleFieldSnapshot {
+    hand_card_list: Vec<i32>,
+    hand_card_count: i32,
+    deck_card_count: i32,
+    tomb_card_count: i32,
+    field_energy: i32,
+    main_character_health_point: i32,
+    field_unit_map: HashMap<i32, FieldUnitSnapshot>,
+    trap_card_list: Vec<i32>,
+    trap_card_count: i32,
+}
+
+impl PlayerBattleFieldSnapshot {
+    pub fn new(hand_card_list: Vec<i32>,
+               deck_card_count: i32,
+               tomb_card_count: i32,
+               field_energy: i32,
+               main_character_health_point: i32,
+               field_unit_map: HashMap<i32, FieldUnitSnapshot>,
+               trap_card_list: Vec<i32>) -> Self {
+
+        PlayerBattleFieldSnapshot {
+            hand_card_count: hand_card_list.len() as i32,
+            hand_card_list,
+            deck_card_count,
+            tomb_card_count,
+            field_energy,
+            main_character_health_point,
+            field_unit_map,
+            trap_card_count: trap_card_list.len() as i32,
+            trap_card_list,
+        }
+    }
+
+    // 상대에게 보여줄 때는 손패와 뒷면 함정의 내용을 지우고 장 수만 남김
+    pub fn redact_hidden_information(mut self) -> Self {
+        self.hand_card_list.clear();
+        self.trap_card_list.clear();
+        self
+    }
+}
@@ -0,0 +1,17 @@
+use crate::battle_resume::controller::battle_resume_controller::BattleResumeController;
+use crate::battle_resume::controller::battle_resume_controller_impl::BattleResumeControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::resume_battle_request_form_generator::create_resume_battle_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_resume_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Resume Battle
+    // 상대의 행동이 절반만 반영된 상태를 담지 않도록 스냅샷도 방의 Actor 에서 생성
+    protocol_registry.register_battle_room_action(23, "RESUME_BATTLE", create_resume_battle_request_form, |request| async move {
+        let battle_resume_controller_mutex = BattleResumeControllerImpl::get_instance();
+        let battle_resume_controller_mutex_guard = battle_resume_controller_mutex.lock().await;
+
+        let response = battle_resume_controller_mutex_guard.request_to_resume_battle(request).await;
+        ResponseType::RESUME_BATTLE(response)
+    });
+}
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use crate::battle_resume::service::request::get_battle_field_snapshot_request::GetBattleFieldSnapshotRequest;
+use crate::battle_resume::service::response::get_battle_field_snapshot_response::GetBattleFieldSnapshotResponse;
+
+#[async_trait]
+pub trait BattleResumeService {
+    async fn get_battle_field_snapshot(&self, get_battle_field_snapshot_request: GetBattleFieldSnapshotRequest) -> GetBattleFieldSnapshotResponse;
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+use crate::battle_resume::entity::battle_field_snapshot::BattleFieldSnapshot;
+use crate::battle_resume::entity::environment_slot_snapshot::EnvironmentSlotSnapshot;
+use crate::battle_resume::entity::field_unit_snapshot::FieldUnitSnapshot;
+use crate::battle_resume::entity::player_battle_field_snapshot::PlayerBattleFieldSnapshot;
+use crate::battle_resume::service::battle_resume_service::BattleResumeService;
+use crate::battle_resume::service::request::get_battle_field_snapshot_request::GetBattleFieldSnapshotRequest;
+use crate::battle_resume::service::response::get_battle_field_snapshot_response::GetBattleFieldSnapshotResponse;
+use crate::game_card_environment::repository::game_card_environment_repository_impl::GameCardEnvironmentRepositoryImpl;
+use crate::game_card_trap::repository::game_card_trap_repository_impl::GameCardTrapRepositoryImpl;
+use crate::game_deck::repository::game_deck_repository::GameDeckRepository;
+use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
+use crate::game_field_energy::repository::game_field_energy_repository_impl::GameFieldEnergyRepositoryImpl;
+use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
+use crate::game_hand::repository::game_hand_repository_impl::GameHandRepositoryImpl;
+use crate::game_main_character::repository::game_main_character_repository_impl::GameMainCharacterRepositoryImpl;
+use crate::game_round::repository::game_round_repository_impl::GameRoundRepositoryImpl;
+use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
+use crate::game_turn::repository::game_turn_repository_impl::GameTurnRepositoryImpl;
+
+pub struct BattleResumeServiceImpl {
+    game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
+    game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
+    game_card_trap_repository: Arc<AsyncMutex<GameCardTrapRepositoryImpl>>,
+    game_card_environment_repository: Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>>,
+}
+
+impl BattleResumeServiceImpl {
+    pub fn new(game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
+               game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
+               game_card_trap_repository: Arc<AsyncMutex<GameCardTrapRepositoryImpl>>,
+               game_card_environment_repository: Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>>,
+    ) -> Self {
+
+        BattleResumeServiceImpl {
+            game_main_character_repository,
+            game_round_repository,
+            game_card_trap_repository,
+            game_card_environment_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<BattleResumeServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<BattleResumeServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        BattleResumeServiceImpl::new(
+                            GameMainCharacterRepositoryImpl::get_instance(),
+                            GameRoundRepositoryImpl::get_instance(),
+                            GameCardTrapRepositoryImpl::get_instance(),
+                            GameCardEnvironmentRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    // 게임 정보가 하나라도 없다면 진행 중인 전투가 아님
+    async fn make_player_battle_field_snapshot(&self, account_unique_id: i32) -> Option<PlayerBattleFieldSnapshot> {
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+        let hand_card_list: Vec<i32> = game_hand_repository_guard.get_game_hand_map().get(&account_unique_id)?
+            .get_all_card_list_in_game_hand()
+            .iter()
+            .map(|game_hand_card| game_hand_card.get_card())
+            .collect();
+        drop(game_hand_repository_guard);
+
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let game_deck_repository_guard = game_deck_repository_mutex.lock().await;
+        let deck_card_count = game_deck_repository_guard.get_remain_deck_card_count(account_unique_id);
+        drop(game_deck_repository_guard);
+
+        let game_tomb_repository_mutex = GameTombRepositoryImpl::get_instance();
+        let mut game_tomb_repository_guard = game_tomb_repository_mutex.lock().await;
+        let tomb_card_count = game_tomb_repository_guard.get_game_tomb_map().get(&account_unique_id)
+            .map_or(0, |game_tomb| game_tomb.get_tomb_card_list().get_tomb_card_list().len() as i32);
+        drop(game_tomb_repository_guard);
+
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
+        let field_energy = game_field_energy_repository_guard.get_game_field_energy_map().get(&account_unique_id)
+            .map_or(0, |game_field_energy| game_field_energy.get_energy_count());
+        drop(game_field_energy_repository_guard);
+
+        let mut game_main_character_repository_guard = self.game_main_character_repository.lock().await;
+        let main_character_health_point = game_main_character_repository_guard.get_game_main_character_map().get(&account_unique_id)?
+            .get_health_point();
+        drop(game_main_character_repository_guard);
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let field_unit_map: HashMap<i32, FieldUnitSnapshot> = game_field_unit_repository_guard.get_game_field_unit_map().get(&account_unique_id)
+            .map(|game_field_unit| game_field_unit.get_all_unit_list_in_game_field()
+                .iter()
+                .enumerate()
+                .map(|(unit_index, game_field_unit_card)|
+                    (unit_index as i32, FieldUnitSnapshot::from_game_field_unit_card(game_field_unit_card)))
+                .collect())
+            .unwrap_or_default();
+        drop(game_field_unit_repository_guard);
+
+        let game_card_trap_repository_guard = self.game_card_trap_repository.lock().await;
+        let trap_card_list = game_card_trap_repository_guard.get_game_trap_zone_map().get(&account_unique_id)
+            .map(|game_trap_zone| game_trap_zone.get_face_down_trap_card_list().clone())
+            .unwrap_or_default();
+        drop(game_card_trap_repository_guard);
+
+        Some(PlayerBattleFieldSnapshot::new(
+            hand_card_list, deck_card_count, tomb_card_count, field_energy, main_character_health_point, field_unit_map, trap_card_list))
+    }
+}
+
+#[async_trait]
+impl BattleResumeService for BattleResumeServiceImpl {
+    async fn get_battle_field_snapshot(&self, get_battle_field_snapshot_request: GetBattleFieldSnapshotRequest) -> GetBattleFieldSnapshotResponse {
+        println!("BattleResumeServiceImpl: get_battle_field_snapshot()");
+
+        let account_unique_id = get_battle_field_snapshot_request.get_account_unique_id();
+        let opponent_unique_id = get_battle_field_snapshot_request.get_opponent_unique_id();
+
+        let mut game_round_repository_guard = self.game_round_repository.lock().await;
+        let round_option = game_round_repository_guard.get_game_round_map().get(&account_unique_id)
+            .map(|game_round| game_round.get_round());
+        drop(game_round_repository_guard);
+
+        let game_turn_repository_mutex = GameTurnRepositoryImpl::get_instance();
+        let mut game_turn_repository_guard = game_turn_repository_mutex.lock().await;
+        let turn_option = game_turn_repository_guard.get_game_turn_map().get(&account_unique_id)
+            .map(|game_turn| game_turn.get_turn());
+        drop(game_turn_repository_guard);
+
+        let (round, turn) = match (round_option, turn_option) {
+            (Some(round), Some(turn)) => (round, turn),
+            _ => return GetBattleFieldSnapshotResponse::new(None),
+        };
+
+        let game_card_environment_repository_guard = self.game_card_environment_repository.lock().await;
+        let environment_slot = game_card_environment_repository_guard.get_game_environment_slot_map().get(&account_unique_id)
+            .map(|game_environment_slot| EnvironmentSlotSnapshot::from_game_environment_slot(game_environment_slot, account_unique_id));
+        drop(game_card_environment_repository_guard);
+
+        let your_battle_field = self.make_player_battle_field_snapshot(account_unique_id).await;
+        let opponent_battle_field = self.make_player_battle_field_snapshot(opponent_unique_id).await;
+
+        match (your_battle_field, opponent_battle_field) {
+            (Some(your_battle_field), Some(opponent_battle_field)) =>
+                // 계정의 turn 값과 현재 round 값이 일치하면 본인 턴
+                GetBattleFieldSnapshotResponse::new(
+                    Some(BattleFieldSnapshot::new(round, turn == round, your_battle_field, opponent_battle_field, environment_slot))),
+            _ => GetBattleFieldSnapshotResponse::new(None),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod battle_resume_service;
+pub mod battle_resume_service_impl;
+pub mod request;
+pub mod response;
@@ -0,0 +1,18 @@
+use crate::battle_resume::entity::battle_field_snapshot::BattleFieldSnapshot;
+
+#[derive(Debug)]
+pub struct GetBattleFieldSnapshotResponse {
+    battle_field_snapshot: Option<BattleFieldSnapshot>,
+}
+
+impl GetBattleFieldSnapshotResponse {
+    pub fn new(battle_field_snapshot: Option<BattleFieldSnapshot>) -> Self {
+        GetBattleFieldSnapshotResponse {
+            battle_field_snapshot,
+        }
+    }
+
+    pub fn get_battle_field_snapshot(&self) -> &Option<BattleFieldSnapshot> {
+        &self.battle_field_snapshot
+    }
+}
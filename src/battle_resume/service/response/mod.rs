@@ -0,0 +1 @@
+pub mod get_battle_field_snapshot_response;
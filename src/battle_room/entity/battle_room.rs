@@ -78,6 +78,10 @@ impl BattleRoom {
         *ROOM_COUNTER.lock().unwrap()
     }
 
+    pub fn get_id(&self) -> i32 {
+        self.id
+    }
+
     pub fn get_player_id_list(&self) -> &Vec<i32> {
         &self.player_id_list
     }
@@ -1,3 +1,4 @@
 pub mod entity;
 pub mod repository;
-pub mod service;
\ No newline at end of file
+pub mod service;
+pub mod protocol;
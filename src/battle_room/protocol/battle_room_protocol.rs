@@ -0,0 +1,16 @@
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::what_is_the_room_number_request_generator::create_what_is_the_room_number_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_room_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // WHAT_IS_THE_ROOM_NUMBER
+    protocol_registry.register(15, "WHAT_IS_THE_ROOM_NUMBER", create_what_is_the_room_number_request, |request| async move {
+        let battle_room_service_mutex = BattleRoomServiceImpl::get_instance();
+        let battle_room_service_guard = battle_room_service_mutex.lock().await;
+
+        let response = battle_room_service_guard.what_is_the_room_number(request).await;
+        ResponseType::WHAT_IS_THE_ROOM_NUMBER(response)
+    });
+}
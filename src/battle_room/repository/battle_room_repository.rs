@@ -3,9 +3,10 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait BattleRoomRepository {
-    async fn set_players_to_battle_room(&self, account_unique_id_list: Vec<i32>) -> Result<bool, Box<dyn Error>>;
+    async fn set_players_to_battle_room(&self, account_unique_id_list: Vec<i32>) -> Result<i32, Box<dyn Error>>;
     async fn remove_battle_room_player(&mut self, account_unique_id: i32) -> bool;
-    async fn get_players_in_battle_room(&self, battle_room_number: usize) -> Option<Vec<i32>>;
+    async fn get_players_in_battle_room(&self, battle_room_id: i32) -> Option<Vec<i32>>;
     async fn what_is_the_room_number(&self, account_unique_id: i32) -> Option<i32>;
     async fn find_opponent_unique_id(&self, account_unique_id: i32) -> Option<i32>;
+    async fn find_every_battle_room_id(&self) -> Vec<i32>;
 }
\ No newline at end of file
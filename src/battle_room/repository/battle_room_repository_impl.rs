@@ -6,22 +6,25 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::watch;
 use crate::battle_room::entity::battle_room::BattleRoom;
 use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
 
 // TODO: 1 대 1 배틀 룸을 관리하는 것 (Domain 정리가 필요하다 파악됨)
+// battle_room_map 은 BattleRoom 고유 id 를 key 로 사용하므로 방이 삭제되어도 다른 방의 id 는 변하지 않음
+// battle_room_closed_sender_map 은 방이 비워지는 순간 true 를 보내 방 단위 감시 작업들이 함께 끝나도록 함
 pub struct BattleRoomRepositoryImpl {
-    battle_room_count: Arc<AsyncMutex<i32>>,
-    battle_room_list: Arc<AsyncMutex<Vec<BattleRoom>>>,
-    battle_room_account_hash: Arc<AsyncMutex<HashMap<i32, i32>>>
+    battle_room_map: Arc<AsyncMutex<HashMap<i32, BattleRoom>>>,
+    battle_room_account_hash: Arc<AsyncMutex<HashMap<i32, i32>>>,
+    battle_room_closed_sender_map: Arc<AsyncMutex<HashMap<i32, watch::Sender<bool>>>>,
 }
 
 impl BattleRoomRepositoryImpl {
     pub fn new() -> Self {
         BattleRoomRepositoryImpl {
-            battle_room_list: Arc::new(AsyncMutex::new(Vec::new())),
-            battle_room_count: Arc::new(AsyncMutex::new(0)),
-            battle_room_account_hash: Arc::new(AsyncMutex::new(HashMap::new()))
+            battle_room_map: Arc::new(AsyncMutex::new(HashMap::new())),
+            battle_room_account_hash: Arc::new(AsyncMutex::new(HashMap::new())),
+            battle_room_closed_sender_map: Arc::new(AsyncMutex::new(HashMap::new())),
         }
     }
 
@@ -30,8 +33,22 @@ impl BattleRoomRepositoryImpl {
     }
 
     pub async fn get_battle_room_count(&self) -> usize {
-        let list = self.battle_room_list.lock().await;
-        list.len()
+        let battle_room_map_guard = self.battle_room_map.lock().await;
+        battle_room_map_guard.len()
+    }
+
+    // 이미 닫힌 방이라면 None 을 반환하므로 감시 작업을 시작하지 않으면 됨
+    pub async fn subscribe_battle_room_closed(&self, battle_room_id: i32) -> Option<watch::Receiver<bool>> {
+        let battle_room_closed_sender_map_guard = self.battle_room_closed_sender_map.lock().await;
+        battle_room_closed_sender_map_guard.get(&battle_room_id)
+            .map(|battle_room_closed_sender| battle_room_closed_sender.subscribe())
+    }
+
+    async fn close_battle_room(&self, battle_room_id: i32) {
+        let mut battle_room_closed_sender_map_guard = self.battle_room_closed_sender_map.lock().await;
+        if let Some(battle_room_closed_sender) = battle_room_closed_sender_map_guard.remove(&battle_room_id) {
+            battle_room_closed_sender.send_replace(true);
+        }
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<BattleRoomRepositoryImpl>> {
@@ -45,40 +62,59 @@ impl BattleRoomRepositoryImpl {
 
 #[async_trait]
 impl BattleRoomRepository for BattleRoomRepositoryImpl {
-    async fn set_players_to_battle_room(&self, account_unique_id_list: Vec<i32>) -> Result<bool, Box<dyn Error>> {
+    async fn set_players_to_battle_room(&self, account_unique_id_list: Vec<i32>) -> Result<i32, Box<dyn Error>> {
         println!("BattleRoomRepositoryImpl: set_player_to_battle_room() -> {:?}", account_unique_id_list);
 
-        let mut battle_room_list_guard = self.battle_room_list.lock().await;
-        let mut battle_room_count_guard = self.battle_room_count.lock().await;
+        let mut battle_room_map_guard = self.battle_room_map.lock().await;
 
         let mut battle_room = BattleRoom::new();
         battle_room.add_player(account_unique_id_list[0]);
         battle_room.add_player(account_unique_id_list[1]);
 
-        // battle_room_list_guard.push(BattleRoom::new());
-        battle_room_list_guard.push(battle_room);
+        let battle_room_id = battle_room.get_id();
+        battle_room_map_guard.insert(battle_room_id, battle_room);
 
         let mut battle_room_account_hash_guard = self.battle_room_account_hash.lock().await;
-        battle_room_account_hash_guard.insert(account_unique_id_list[0], *battle_room_count_guard);
-        battle_room_account_hash_guard.insert(account_unique_id_list[1], *battle_room_count_guard);
+        battle_room_account_hash_guard.insert(account_unique_id_list[0], battle_room_id);
+        battle_room_account_hash_guard.insert(account_unique_id_list[1], battle_room_id);
+        drop(battle_room_account_hash_guard);
+
+        let (battle_room_closed_sender, _) = watch::channel(false);
+        let mut battle_room_closed_sender_map_guard = self.battle_room_closed_sender_map.lock().await;
+        battle_room_closed_sender_map_guard.insert(battle_room_id, battle_room_closed_sender);
+        drop(battle_room_closed_sender_map_guard);
 
-        *battle_room_count_guard += 1;
-        println!("battle_room_count: {}", *battle_room_count_guard);
+        println!("battle_room_id: {}, battle_room_count: {}", battle_room_id, battle_room_map_guard.len());
 
-        Ok(true)
+        Ok(battle_room_id)
     }
 
     async fn remove_battle_room_player(&mut self, account_unique_id: i32) -> bool {
         println!("BattleRoomRepositoryImpl: remove_battle_room_player()");
-        let room_number = self.what_is_the_room_number(account_unique_id).await.unwrap();
-        let mut battle_room_list_guard = self.battle_room_list.lock().await;
-        let mut battle_room = battle_room_list_guard.get_mut(room_number as usize).unwrap();
-        battle_room.remove_player_for_finish(account_unique_id);
-        println!("battle_room: {:?}", battle_room);
+        let battle_room_id = match self.what_is_the_room_number(account_unique_id).await {
+            Some(battle_room_id) => battle_room_id,
+            None => {
+                println!("Account not in any room");
+                return false
+            }
+        };
+
+        let mut battle_room_map_guard = self.battle_room_map.lock().await;
+        let mut is_battle_room_closed = false;
+        if let Some(battle_room) = battle_room_map_guard.get_mut(&battle_room_id) {
+            battle_room.remove_player_for_finish(account_unique_id);
+            println!("battle_room: {:?}", battle_room);
+
             if battle_room.is_empty() {
-                battle_room_list_guard.remove(room_number as usize);
+                battle_room_map_guard.remove(&battle_room_id);
+                is_battle_room_closed = true;
             }
-        drop(battle_room_list_guard);
+        }
+        drop(battle_room_map_guard);
+
+        if is_battle_room_closed {
+            self.close_battle_room(battle_room_id).await;
+        }
 
         let mut battle_room_account_hash_guard = self.battle_room_account_hash.lock().await;
         battle_room_account_hash_guard.remove(&account_unique_id);
@@ -87,16 +123,16 @@ impl BattleRoomRepository for BattleRoomRepositoryImpl {
         return false;
     }
 
-    async fn get_players_in_battle_room(&self, battle_room_number: usize) -> Option<Vec<i32>> {
-        println!("BattleRoomRepositoryImpl: get_players_in_battle_room_number = {}", battle_room_number as i32);
+    async fn get_players_in_battle_room(&self, battle_room_id: i32) -> Option<Vec<i32>> {
+        println!("BattleRoomRepositoryImpl: get_players_in_battle_room_id = {}", battle_room_id);
 
-        let mut battle_room_list_guard = self.battle_room_list.lock().await;
+        let battle_room_map_guard = self.battle_room_map.lock().await;
 
-        if battle_room_number < battle_room_list_guard.len() {
-            return Some(battle_room_list_guard[battle_room_number].get_player_id_list().clone())
+        if let Some(battle_room) = battle_room_map_guard.get(&battle_room_id) {
+            return Some(battle_room.get_player_id_list().clone())
         }
 
-        drop(battle_room_list_guard);
+        drop(battle_room_map_guard);
 
         println!("Battle room does not exist!");
         None
@@ -137,6 +173,11 @@ impl BattleRoomRepository for BattleRoomRepositoryImpl {
             }
         }
     }
+
+    async fn find_every_battle_room_id(&self) -> Vec<i32> {
+        let battle_room_map_guard = self.battle_room_map.lock().await;
+        battle_room_map_guard.keys().copied().collect()
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +195,7 @@ mod tests {
 
         let result = battle_room_repository.set_players_to_battle_room(account_vector).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        let first_battle_room_id = result.unwrap();
 
         let mut other_account_vector: Vec<i32> = Vec::new();
         other_account_vector.push(3);
@@ -162,7 +203,7 @@ mod tests {
 
         let result = battle_room_repository.set_players_to_battle_room(other_account_vector).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        assert_ne!(result.unwrap(), first_battle_room_id);
     }
 
     #[tokio::test]
@@ -233,27 +274,27 @@ mod tests {
         let result = battle_room_repository_guard.set_players_to_battle_room(account_vector_1).await;
         let result = battle_room_repository_guard.set_players_to_battle_room(account_vector_2).await;
 
-        println!("battle_room_list_before_test: {:?}", battle_room_repository_guard.battle_room_list);
+        println!("battle_room_map_before_test: {:?}", battle_room_repository_guard.battle_room_map);
         let room_number = battle_room_repository_guard.what_is_the_room_number(account_unique_id_1).await.unwrap();
         println!("room_number: {:?}", room_number);
 
-        let battle_room_list_guard = battle_room_repository_guard.battle_room_list.lock().await;
-        let battle_room = battle_room_list_guard.get(room_number as usize).unwrap();
+        let battle_room_map_guard = battle_room_repository_guard.battle_room_map.lock().await;
+        let battle_room = battle_room_map_guard.get(&room_number).unwrap();
         println!("battle_room_before_test: {:?}", battle_room);
-        drop(battle_room_list_guard);
+        drop(battle_room_map_guard);
 
         let battle_room_account_guard = battle_room_repository_guard.battle_room_account_hash.lock().await;
         println!("battle_room_account_hash_before_test: {:?}", battle_room_account_guard);
         drop(battle_room_account_guard);
 
-        // account_unique_id 관련하여 battle_room_list 안의 BattleRoom 정보 삭제, battle_room_account_hash 안의 계정 정보 삭제
+        // account_unique_id 관련하여 battle_room_map 안의 BattleRoom 정보 삭제, battle_room_account_hash 안의 계정 정보 삭제
         let result_remove = battle_room_repository_guard.remove_battle_room_player(account_unique_id_1).await;
         let result_remove = battle_room_repository_guard.remove_battle_room_player(account_unique_id_2).await;
-        println!("battle_room_list_after_remove_players: {:?}", battle_room_repository_guard.battle_room_list);
+        println!("battle_room_map_after_remove_players: {:?}", battle_room_repository_guard.battle_room_map);
 
-        let battle_room_list_guard = battle_room_repository_guard.battle_room_list.lock().await;
-        println!("battle_room_after_test: {:?}", battle_room_list_guard);
-        drop(battle_room_list_guard);
+        let battle_room_map_guard = battle_room_repository_guard.battle_room_map.lock().await;
+        println!("battle_room_after_test: {:?}", battle_room_map_guard);
+        drop(battle_room_map_guard);
 
         let battle_room_account_hash_guard = battle_room_repository_guard.battle_room_account_hash.lock().await;
         println!("battle_room_account_hash_after_test: {:?}", battle_room_account_hash_guard);
@@ -261,7 +302,7 @@ mod tests {
 
         println!("account_vector: {:?}", account_vector_3);
         let result = battle_room_repository_guard.set_players_to_battle_room(account_vector_3).await;
-        println!("battle_room_list_after_test: {:?}", battle_room_repository_guard.battle_room_list);
+        println!("battle_room_map_after_test: {:?}", battle_room_repository_guard.battle_room_map);
 
     }
 
@@ -275,13 +316,48 @@ mod tests {
 
         let result = battle_room_repository.set_players_to_battle_room(account_vector).await;
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        let battle_room_id = result.unwrap();
 
-        let players_in_the_room_0_option = battle_room_repository.get_players_in_battle_room(0).await;
-        let players_in_the_room_1_option = battle_room_repository.get_players_in_battle_room(1).await;
+        let players_in_the_room_0_option = battle_room_repository.get_players_in_battle_room(battle_room_id).await;
+        let players_in_the_room_1_option = battle_room_repository.get_players_in_battle_room(battle_room_id + 1).await;
 
         assert_eq!(players_in_the_room_0_option.is_some(), true);
         assert_eq!(players_in_the_room_1_option.is_none(), true);
         println!("players_in_the_room_0: {:?}", players_in_the_room_0_option.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_remove_battle_room_keeps_other_room_id() {
+        let mut battle_room_repository = BattleRoomRepositoryImpl::new();
+
+        let first_battle_room_id = battle_room_repository.set_players_to_battle_room(vec![21, 22]).await.unwrap();
+        let second_battle_room_id = battle_room_repository.set_players_to_battle_room(vec![23, 24]).await.unwrap();
+
+        battle_room_repository.remove_battle_room_player(21).await;
+        battle_room_repository.remove_battle_room_player(22).await;
+
+        assert_eq!(battle_room_repository.get_players_in_battle_room(first_battle_room_id).await, None);
+        assert_eq!(battle_room_repository.what_is_the_room_number(23).await, Some(second_battle_room_id));
+        assert_eq!(battle_room_repository.get_players_in_battle_room(second_battle_room_id).await, Some(vec![23, 24]));
+        assert_eq!(battle_room_repository.find_opponent_unique_id(24).await, Some(23));
+    }
+
+    #[tokio::test]
+    async fn test_battle_room_closed_signal_after_every_player_removed() {
+        let mut battle_room_repository = BattleRoomRepositoryImpl::new();
+
+        let battle_room_id = battle_room_repository.set_players_to_battle_room(vec![31, 32]).await.unwrap();
+        let mut battle_room_closed_receiver =
+            battle_room_repository.subscribe_battle_room_closed(battle_room_id).await.unwrap();
+
+        battle_room_repository.remove_battle_room_player(31).await;
+        assert!(!*battle_room_closed_receiver.borrow());
+
+        battle_room_repository.remove_battle_room_player(32).await;
+        let closed = tokio::time::timeout(
+            Duration::from_millis(100),
+            battle_room_closed_receiver.wait_for(|is_closed| *is_closed)).await;
+        assert!(closed.is_ok());
+        assert!(battle_room_repository.subscribe_battle_room_closed(battle_room_id).await.is_none());
+    }
 }
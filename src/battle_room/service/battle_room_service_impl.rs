@@ -11,9 +11,11 @@ use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepos
 use crate::battle_room::service::battle_room_service::BattleRoomService;
 use crate::battle_room::service::request::battle_match_request::BattleMatchRequest;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::battle_room::service::request::get_players_in_battle_room_request::GetPlayersInBattleRoomRequest;
 use crate::battle_room::service::request::what_is_the_room_number_request::WhatIsTheRoomNumberRequest;
 use crate::battle_room::service::response::battle_match_response::BattleMatchResponse;
 use crate::battle_room::service::response::find_opponent_by_account_id_response::FindOpponentByAccountIdResponse;
+use crate::battle_room::service::response::get_players_in_battle_room_response::GetPlayersInBattleRoomResponse;
 use crate::battle_room::service::response::what_is_the_room_number_response::WhatIsTheRoomNumberResponse;
 use crate::battle_wait_queue::repository::battle_wait_queue_repository::BattleWaitQueueRepository;
 use crate::battle_wait_queue::repository::battle_wait_queue_repository_impl::BattleWaitQueueRepositoryImpl;
@@ -94,4 +96,14 @@ impl BattleRoomService for BattleRoomServiceImpl {
 
         return FindOpponentByAccountIdResponse::new(maybe_opponent_unique_id.unwrap_or(-1))
     }
-}
\ No newline at end of file
+
+    async fn get_players_in_battle_room(&self, get_players_in_battle_room_request: GetPlayersInBattleRoomRequest) -> GetPlayersInBattleRoomResponse {
+        println!("BattleRoomServiceImpl: get_players_in_battle_room()");
+
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let maybe_player_list = battle_room_repository_guard.get_players_in_battle_room(
+            get_players_in_battle_room_request.get_battle_room_id()).await;
+
+        return GetPlayersInBattleRoomResponse::new(maybe_player_list.unwrap_or_default())
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct GetPlayersInBattleRoomRequest {
+    battle_room_id: i32,
+}
+
+impl GetPlayersInBattleRoomRequest {
+    pub fn new(battle_room_id: i32) -> Self {
+        GetPlayersInBattleRoomRequest {
+            battle_room_id
+        }
+    }
+
+    pub fn get_battle_room_id(&self) -> i32 {
+        self.battle_room_id
+    }
+}
@@ -1,3 +1,4 @@
 pub mod battle_match_request;
 pub mod what_is_the_room_number_request;
-pub mod find_opponent_by_account_id_request;
\ No newline at end of file
+pub mod find_opponent_by_account_id_request;
+pub mod get_players_in_battle_room_request;
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPlayersInBattleRoomResponse {
+    player_list: Vec<i32>,
+}
+
+impl GetPlayersInBattleRoomResponse {
+    pub fn new(player_list: Vec<i32>) -> Self {
+        GetPlayersInBattleRoomResponse { player_list }
+    }
+
+    pub fn get_player_list(&self) -> &Vec<i32> {
+        &self.player_list
+    }
+}
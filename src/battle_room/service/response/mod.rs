@@ -1,3 +1,4 @@
 pub mod battle_match_response;
 pub mod what_is_the_room_number_response;
-pub mod find_opponent_by_account_id_response;
\ No newline at end of file
+pub mod find_opponent_by_account_id_response;
+pub mod get_players_in_battle_room_response;
\ No newline at end of file
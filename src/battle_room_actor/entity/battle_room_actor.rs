@@ -0,0 +1,237 @@
+use std::sync::Arc;
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+use crate::battle_finish::service::battle_finish_service_impl::BattleFinishServiceImpl;
+use crate::battle_prepare_task::service::battle_prepare_task_service_impl::spawn_async_task_for_prepare_battle;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::battle_room_actor::entity::battle_room_actor_handle::BattleRoomActorHandle;
+use crate::battle_room_actor::entity::battle_room_command::BattleRoomCommand;
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::run_battle_room_action;
+use crate::connection_liveness::service::connection_liveness_service_impl::ConnectionLivenessServiceImpl;
+use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller_impl::GameBattleFieldMonitorControllerImpl;
+use crate::game_event_bus::controller::game_event_bus_controller::GameEventBusController;
+use crate::game_event_bus::controller::game_event_bus_controller_impl::GameEventBusControllerImpl;
+use crate::game_event_bus::controller::request_form::settle_pending_game_event_request_form::SettlePendingGameEventRequestForm;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
+use crate::game_turn::controller::game_turn_controller::GameTurnController;
+use crate::game_turn::controller::game_turn_controller_impl::GameTurnControllerImpl;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::protocol_registry::entity::protocol_handler::ProtocolHandler;
+use crate::response_generator::response_type::ResponseType;
+use crate::server_shutdown::controller::server_shutdown_controller_impl::ServerShutdownControllerImpl;
+
+const BATTLE_ROOM_COMMAND_CAPACITY: usize = 32;
+
+// 배틀 룸 하나를 담당하는 task
+// 방의 손패, 필드, 덱, 무덤, 에너지, 턴, 이벤트 버스 상태를 소유하며, 명령은 도착 순서대로 하나씩 이 상태 안에서 처리됨
+// 다른 방과는 상태를 공유하지 않으므로 서로 기다리지 않음
+// 각 명령은 별도 task 로 실행하므로 한 명령이 panic 으로 끝나도 방의 다음 명령은 계속 처리됨
+pub struct BattleRoomActor {
+    battle_room_id: i32,
+    battle_room_state: Arc<BattleRoomState>,
+    command_receiver: mpsc::Receiver<BattleRoomCommand>,
+}
+
+impl BattleRoomActor {
+    pub fn spawn(battle_room_id: i32) -> BattleRoomActorHandle {
+        let (command_sender, command_receiver) = mpsc::channel(BATTLE_ROOM_COMMAND_CAPACITY);
+
+        let battle_room_actor = BattleRoomActor {
+            battle_room_id,
+            battle_room_state: Arc::new(BattleRoomState::new()),
+            command_receiver,
+        };
+        tokio::spawn(battle_room_actor.run());
+
+        BattleRoomActorHandle::new(battle_room_id, command_sender)
+    }
+
+    async fn run(mut self) {
+        println!("BattleRoomActor {}: started", self.battle_room_id);
+
+        while let Some(command) = self.command_receiver.recv().await {
+            let command_future =
+                self.battle_room_state.clone().scope(
+                    BattleRoomActor::handle_command(self.battle_room_id, command));
+
+            // 끝날 때까지 기다린 뒤 다음 명령을 받으므로 같은 방의 명령은 섞이지 않음
+            if let Err(join_error) = tokio::spawn(command_future).await {
+                eprintln!("BattleRoomActor {}: command aborted: {}", self.battle_room_id, join_error);
+            }
+        }
+
+        println!("BattleRoomActor {}: stopped", self.battle_room_id);
+    }
+
+    // 요청한 쪽이 이미 사라졌다면 결과는 버림
+    async fn handle_command(battle_room_id: i32, command: BattleRoomCommand) {
+        match command {
+            BattleRoomCommand::HandleAction { protocol_number, account_unique_id, action_handler, request_data, reply_sender } => {
+                let response = BattleRoomActor::handle_action(protocol_number, account_unique_id, action_handler, request_data).await;
+                let _ = reply_sender.send(response);
+            }
+            BattleRoomCommand::PreparePlayer { account_unique_id, reply_sender } => {
+                spawn_async_task_for_prepare_battle(account_unique_id).await;
+                let _ = reply_sender.send(());
+            }
+            BattleRoomCommand::ExpireTurn { reply_sender } => {
+                let game_turn_controller_mutex = GameTurnControllerImpl::get_instance();
+                let game_turn_controller_guard = game_turn_controller_mutex.lock().await;
+                game_turn_controller_guard.request_turn_end_by_time_out(battle_room_id).await;
+                drop(game_turn_controller_guard);
+
+                let _ = reply_sender.send(());
+            }
+            BattleRoomCommand::ForfeitAbandonedPlayer { abandoned_account_unique_id, reply_sender } => {
+                GameBattleFieldMonitorControllerImpl::forfeit_abandoned_player(
+                    GameWinnerCheckServiceImpl::get_instance(),
+                    BattleFinishServiceImpl::get_instance(),
+                    ConnectionLivenessServiceImpl::get_instance(),
+                    abandoned_account_unique_id).await;
+
+                let _ = reply_sender.send(());
+            }
+            BattleRoomCommand::DrawBattleRoom { reply_sender } => {
+                let is_drawn = ServerShutdownControllerImpl::draw_battle_room(
+                    BattleRoomServiceImpl::get_instance(),
+                    GameWinnerCheckServiceImpl::get_instance(),
+                    BattleFinishServiceImpl::get_instance(),
+                    battle_room_id).await;
+
+                let _ = reply_sender.send(is_drawn);
+            }
+        }
+    }
+
+    // 행동 중 발행되어 처리되지 않은 이벤트는 같은 명령 안에서 바로 정리하여 다음 행동에 섞이지 않게 함
+    async fn handle_action(protocol_number: i64,
+                           account_unique_id: i32,
+                           action_handler: ProtocolHandler,
+                           request_data: JsonValue) -> ResponseType {
+
+        let response = run_battle_room_action(protocol_number, &action_handler, &request_data).await;
+
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        let game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+        let has_pending_game_event = game_event_bus_repository_guard.has_pending_game_event();
+        drop(game_event_bus_repository_guard);
+
+        if has_pending_game_event {
+            let game_event_bus_controller_mutex = GameEventBusControllerImpl::get_instance();
+            let game_event_bus_controller_guard = game_event_bus_controller_mutex.lock().await;
+            game_event_bus_controller_guard.settle_pending_game_event(
+                SettlePendingGameEventRequestForm::new(account_unique_id)).await;
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use serde_json::json;
+    use tokio::sync::Mutex as AsyncMutex;
+    use tokio::time::sleep;
+    use super::*;
+    use crate::client_program::service::response::client_program_exit_response::ClientProgramExitResponse;
+    use crate::game_hand::repository::game_hand_repository_impl::GameHandRepositoryImpl;
+    use crate::response_generator::response_type::ResponseType;
+
+    fn record_action(action_log: Arc<AsyncMutex<Vec<i32>>>, action_id: i32, delay_milliseconds: u64) -> ProtocolHandler {
+        Arc::new(move |_request_data| {
+            let action_log = action_log.clone();
+
+            Ok(Box::pin(async move {
+                action_log.lock().await.push(action_id);
+                sleep(Duration::from_millis(delay_milliseconds)).await;
+                action_log.lock().await.push(action_id);
+
+                ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(true))
+            }))
+        })
+    }
+
+    #[tokio::test]
+    async fn test_actions_in_same_room_are_not_interleaved() {
+        let action_log = Arc::new(AsyncMutex::new(Vec::new()));
+        let battle_room_actor_handle = BattleRoomActor::spawn(1);
+
+        let first_handle = battle_room_actor_handle.clone();
+        let first_action = record_action(action_log.clone(), 1, 50);
+        let first_task = tokio::spawn(async move { first_handle.handle_action(0, 1, first_action, json!({})).await });
+        sleep(Duration::from_millis(10)).await;
+
+        let second_action = record_action(action_log.clone(), 2, 0);
+        let second_response = battle_room_actor_handle.handle_action(0, 2, second_action, json!({})).await;
+
+        assert!(first_task.await.unwrap().is_some());
+        assert!(second_response.is_some());
+        assert_eq!(*action_log.lock().await, vec![1, 1, 2, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_actor_keeps_running_when_action_panics() {
+        let action_log = Arc::new(AsyncMutex::new(Vec::new()));
+        let battle_room_actor_handle = BattleRoomActor::spawn(2);
+        let panicking_action: ProtocolHandler = Arc::new(|_request_data| {
+            Ok(Box::pin(async move {
+                panic!("broken action");
+            }))
+        });
+
+        assert!(battle_room_actor_handle.handle_action(0, 1, panicking_action, json!({})).await.is_none());
+        sleep(Duration::from_millis(10)).await;
+        assert!(!battle_room_actor_handle.is_closed());
+
+        let next_action = record_action(action_log.clone(), 3, 0);
+        assert!(battle_room_actor_handle.handle_action(0, 1, next_action, json!({})).await.is_some());
+        assert_eq!(*action_log.lock().await, vec![3, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_prepared_player_state_is_owned_by_battle_room() {
+        let action_log = Arc::new(AsyncMutex::new(Vec::new()));
+        let battle_room_actor_handle = BattleRoomActor::spawn(3);
+        let other_battle_room_actor_handle = BattleRoomActor::spawn(4);
+
+        // 진행 중인 행동이 끝난 뒤에 게임 상태가 생성됨
+        let action_handle = battle_room_actor_handle.clone();
+        let action = record_action(action_log.clone(), 1, 50);
+        let action_task = tokio::spawn(async move { action_handle.handle_action(0, 9601, action, json!({})).await });
+        sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(battle_room_actor_handle.prepare_player(9601).await, Some(()));
+        assert!(action_task.await.unwrap().is_some());
+        assert_eq!(*action_log.lock().await, vec![1, 1]);
+
+        let has_game_hand_log = Arc::new(AsyncMutex::new(Vec::new()));
+        let check_game_hand: ProtocolHandler = {
+            let has_game_hand_log = has_game_hand_log.clone();
+            Arc::new(move |_request_data| {
+                let has_game_hand_log = has_game_hand_log.clone();
+
+                Ok(Box::pin(async move {
+                    let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+                    let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+                    let has_game_hand = game_hand_repository_guard.get_game_hand_map().contains_key(&9601);
+                    has_game_hand_log.lock().await.push(has_game_hand);
+
+                    ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(true))
+                }))
+            })
+        };
+
+        assert!(battle_room_actor_handle.handle_action(0, 9601, check_game_hand.clone(), json!({})).await.is_some());
+        assert!(other_battle_room_actor_handle.handle_action(0, 9601, check_game_hand, json!({})).await.is_some());
+        // 다른 방과 전역 저장소에는 생성되지 않음
+        assert_eq!(*has_game_hand_log.lock().await, vec![true, false]);
+
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+        assert!(!game_hand_repository_guard.get_game_hand_map().contains_key(&9601));
+    }
+}
@@ -0,0 +1,68 @@
+use serde_json::Value as JsonValue;
+use tokio::sync::{mpsc, oneshot};
+use crate::battle_room_actor::entity::battle_room_command::BattleRoomCommand;
+use crate::protocol_registry::entity::protocol_handler::ProtocolHandler;
+use crate::response_generator::response_type::ResponseType;
+
+#[derive(Debug, Clone)]
+pub struct BattleRoomActorHandle {
+    battle_room_id: i32,
+    command_sender: mpsc::Sender<BattleRoomCommand>,
+}
+
+impl BattleRoomActorHandle {
+    pub fn new(battle_room_id: i32, command_sender: mpsc::Sender<BattleRoomCommand>) -> Self {
+        BattleRoomActorHandle {
+            battle_room_id,
+            command_sender,
+        }
+    }
+
+    pub fn get_battle_room_id(&self) -> i32 {
+        self.battle_room_id
+    }
+
+    // 방 정리로 모든 handle 이 사라져 Actor 가 종료되었다면 더 이상 명령을 받을 수 없음
+    pub fn is_closed(&self) -> bool {
+        self.command_sender.is_closed()
+    }
+
+    // 명령이 처리되지 못하고 Actor 가 사라졌거나 처리 도중 panic 이 발생하면 None
+    async fn send_command<Reply>(&self, create_command: impl FnOnce(oneshot::Sender<Reply>) -> BattleRoomCommand) -> Option<Reply> {
+        let (reply_sender, reply_receiver) = oneshot::channel();
+
+        self.command_sender.send(create_command(reply_sender)).await.ok()?;
+        reply_receiver.await.ok()
+    }
+
+    pub async fn handle_action(&self,
+                               protocol_number: i64,
+                               account_unique_id: i32,
+                               action_handler: ProtocolHandler,
+                               request_data: JsonValue) -> Option<ResponseType> {
+
+        self.send_command(|reply_sender| BattleRoomCommand::HandleAction {
+            protocol_number,
+            account_unique_id,
+            action_handler,
+            request_data,
+            reply_sender,
+        }).await
+    }
+
+    pub async fn prepare_player(&self, account_unique_id: i32) -> Option<()> {
+        self.send_command(|reply_sender| BattleRoomCommand::PreparePlayer { account_unique_id, reply_sender }).await
+    }
+
+    pub async fn expire_turn(&self) -> Option<()> {
+        self.send_command(|reply_sender| BattleRoomCommand::ExpireTurn { reply_sender }).await
+    }
+
+    pub async fn forfeit_abandoned_player(&self, abandoned_account_unique_id: i32) -> Option<()> {
+        self.send_command(|reply_sender| BattleRoomCommand::ForfeitAbandonedPlayer { abandoned_account_unique_id, reply_sender }).await
+    }
+
+    pub async fn draw_battle_room(&self) -> Option<bool> {
+        self.send_command(|reply_sender| BattleRoomCommand::DrawBattleRoom { reply_sender }).await
+    }
+}
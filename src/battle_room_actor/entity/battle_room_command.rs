@@ -0,0 +1,36 @@
+use serde_json::Value as JsonValue;
+use tokio::sync::oneshot;
+use crate::protocol_registry::entity::protocol_handler::ProtocolHandler;
+use crate::response_generator::response_type::ResponseType;
+
+// 배틀 룸 Actor 가 처리하는 명령
+// 각 명령은 방의 상태 안에서 다른 명령과 섞이지 않게 끝까지 처리되고, 결과는 reply_sender 로 전달됨
+pub enum BattleRoomCommand {
+    // 플레이어의 게임 내 행동
+    // 요청은 Actor 안에서 등록된 handler 로 처리하고, 행동으로 발행된 이벤트까지 정리한 뒤 응답
+    HandleAction {
+        protocol_number: i64,
+        account_unique_id: i32,
+        action_handler: ProtocolHandler,
+        request_data: JsonValue,
+        reply_sender: oneshot::Sender<ResponseType>,
+    },
+    // 매칭된 플레이어의 덱, 손패, 필드 등 게임 상태 생성
+    PreparePlayer {
+        account_unique_id: i32,
+        reply_sender: oneshot::Sender<()>,
+    },
+    // 턴 제한 시간 초과로 인한 턴 종료
+    ExpireTurn {
+        reply_sender: oneshot::Sender<()>,
+    },
+    // 연결이 끊긴 채 돌아오지 않은 플레이어의 기권 처리
+    ForfeitAbandonedPlayer {
+        abandoned_account_unique_id: i32,
+        reply_sender: oneshot::Sender<()>,
+    },
+    // 서버 종료 시 진행 중인 경기를 무승부로 종료
+    DrawBattleRoom {
+        reply_sender: oneshot::Sender<bool>,
+    },
+}
@@ -0,0 +1,122 @@
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task::JoinHandle;
+use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
+use crate::game_field_energy::repository::game_field_energy_repository_impl::GameFieldEnergyRepositoryImpl;
+use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
+use crate::game_hand::repository::game_hand_repository_impl::GameHandRepositoryImpl;
+use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
+use crate::game_turn::repository::game_turn_repository_impl::GameTurnRepositoryImpl;
+
+tokio::task_local! {
+    static CURRENT_BATTLE_ROOM_STATE: Arc<BattleRoomState>;
+}
+
+// 배틀 룸 Actor 가 소유하는 방 단위 게임 상태
+// Actor 가 명령을 처리하는 동안에는 각 저장소의 get_instance() 가 전역 저장소 대신 이 방의 저장소를 반환
+pub struct BattleRoomState {
+    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
+    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
+    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
+    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
+    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
+    game_turn_repository: Arc<AsyncMutex<GameTurnRepositoryImpl>>,
+    game_event_bus_repository: Arc<AsyncMutex<GameEventBusRepositoryImpl>>,
+}
+
+impl BattleRoomState {
+    pub fn new() -> Self {
+        BattleRoomState {
+            game_hand_repository: Arc::new(AsyncMutex::new(GameHandRepositoryImpl::new())),
+            game_field_unit_repository: Arc::new(AsyncMutex::new(GameFieldUnitRepositoryImpl::new())),
+            game_deck_repository: Arc::new(AsyncMutex::new(GameDeckRepositoryImpl::new())),
+            game_tomb_repository: Arc::new(AsyncMutex::new(GameTombRepositoryImpl::new())),
+            game_field_energy_repository: Arc::new(AsyncMutex::new(GameFieldEnergyRepositoryImpl::new())),
+            game_turn_repository: Arc::new(AsyncMutex::new(GameTurnRepositoryImpl::new())),
+            game_event_bus_repository: Arc::new(AsyncMutex::new(GameEventBusRepositoryImpl::new())),
+        }
+    }
+
+    // 현재 task 가 배틀 룸 Actor 안에서 실행 중이라면 그 방의 상태
+    pub fn current() -> Option<Arc<BattleRoomState>> {
+        CURRENT_BATTLE_ROOM_STATE.try_with(|battle_room_state| battle_room_state.clone()).ok()
+    }
+
+    pub async fn scope<ScopeFuture: Future>(self: Arc<Self>, scope_future: ScopeFuture) -> ScopeFuture::Output {
+        CURRENT_BATTLE_ROOM_STATE.scope(self, scope_future).await
+    }
+
+    pub fn get_game_hand_repository(&self) -> Arc<AsyncMutex<GameHandRepositoryImpl>> {
+        self.game_hand_repository.clone()
+    }
+
+    pub fn get_game_field_unit_repository(&self) -> Arc<AsyncMutex<GameFieldUnitRepositoryImpl>> {
+        self.game_field_unit_repository.clone()
+    }
+
+    pub fn get_game_deck_repository(&self) -> Arc<AsyncMutex<GameDeckRepositoryImpl>> {
+        self.game_deck_repository.clone()
+    }
+
+    pub fn get_game_tomb_repository(&self) -> Arc<AsyncMutex<GameTombRepositoryImpl>> {
+        self.game_tomb_repository.clone()
+    }
+
+    pub fn get_game_field_energy_repository(&self) -> Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>> {
+        self.game_field_energy_repository.clone()
+    }
+
+    pub fn get_game_turn_repository(&self) -> Arc<AsyncMutex<GameTurnRepositoryImpl>> {
+        self.game_turn_repository.clone()
+    }
+
+    pub fn get_game_event_bus_repository(&self) -> Arc<AsyncMutex<GameEventBusRepositoryImpl>> {
+        self.game_event_bus_repository.clone()
+    }
+}
+
+// task-local 은 새 task 로 이어지지 않으므로 방 안에서 띄우는 작업은 이 함수로 같은 방의 상태를 넘겨줌
+pub fn spawn_in_battle_room<SpawnFuture>(spawn_future: SpawnFuture) -> JoinHandle<SpawnFuture::Output>
+    where SpawnFuture: Future + Send + 'static,
+          SpawnFuture::Output: Send + 'static {
+
+    match BattleRoomState::current() {
+        Some(battle_room_state) => tokio::spawn(battle_room_state.scope(spawn_future)),
+        None => tokio::spawn(spawn_future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_hand::repository::game_hand_repository::GameHandRepository;
+
+    async fn has_game_hand(account_unique_id: i32) -> bool {
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+        game_hand_repository_guard.get_game_hand_map().contains_key(&account_unique_id)
+    }
+
+    #[tokio::test]
+    async fn test_repository_resolves_to_battle_room_state_inside_scope() {
+        let first_battle_room_state = Arc::new(BattleRoomState::new());
+        let second_battle_room_state = Arc::new(BattleRoomState::new());
+
+        first_battle_room_state.clone().scope(async {
+            let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+            game_hand_repository_mutex.lock().await.create_game_hand_object(9501);
+        }).await;
+
+        assert!(first_battle_room_state.clone().scope(has_game_hand(9501)).await);
+        assert!(!second_battle_room_state.clone().scope(has_game_hand(9501)).await);
+        assert!(!has_game_hand(9501).await);
+
+        // 방 안에서 띄운 작업도 같은 방의 상태를 사용
+        let spawned_task = first_battle_room_state.clone().scope(async {
+            spawn_in_battle_room(has_game_hand(9501))
+        }).await;
+        assert!(spawned_task.await.unwrap());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod battle_room_actor;
+pub mod battle_room_actor_handle;
+pub mod battle_room_command;
+pub mod battle_room_state;
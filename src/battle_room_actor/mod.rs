@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod protocol;
+pub mod repository;
+pub mod service;
@@ -0,0 +1,89 @@
+use serde_json::Value as JsonValue;
+use crate::battle_room_actor::entity::battle_room_actor_handle::BattleRoomActorHandle;
+use crate::battle_room_actor::service::battle_room_actor_service::BattleRoomActorService;
+use crate::battle_room_actor::service::battle_room_actor_service_impl::BattleRoomActorServiceImpl;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_account_unique_id_request::FindBattleRoomActorByAccountUniqueIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_battle_room_id_request::FindBattleRoomActorByBattleRoomIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_request::FindBattleRoomActorRequest;
+use crate::common::message::protocol_error_code_enum::ProtocolErrorCode;
+use crate::protocol_registry::entity::protocol_error_response_form::ProtocolErrorResponseForm;
+use crate::protocol_registry::entity::protocol_handler::ProtocolHandler;
+use crate::response_generator::response_type::ResponseType;
+
+// 게임 내 행동은 해당 배틀 룸의 Actor 로 보내 방이 소유한 상태 안에서 순서대로 처리
+// 방을 찾지 못하면 (세션 만료, 이미 끝난 게임 등) 바로 실행하여 각 controller 가 기존처럼 실패 응답을 만들도록 함
+pub async fn dispatch_battle_room_action(protocol_number: i64,
+                                         session_id: Option<String>,
+                                         action_handler: ProtocolHandler,
+                                         request_data: JsonValue) -> ResponseType {
+    let session_id = match session_id {
+        Some(session_id) => session_id,
+        None => return run_battle_room_action(protocol_number, &action_handler, &request_data).await,
+    };
+
+    let battle_room_actor_service_mutex = BattleRoomActorServiceImpl::get_instance();
+    let battle_room_actor_service_guard = battle_room_actor_service_mutex.lock().await;
+    let find_battle_room_actor_response =
+        battle_room_actor_service_guard.find_battle_room_actor(FindBattleRoomActorRequest::new(session_id)).await;
+    // 다른 방의 행동이 기다리지 않도록 Actor 를 찾은 즉시 lock 을 풀어야 함
+    drop(battle_room_actor_service_guard);
+
+    let battle_room_actor_handle = match find_battle_room_actor_response.get_battle_room_actor_handle() {
+        Some(battle_room_actor_handle) => battle_room_actor_handle.clone(),
+        None => return run_battle_room_action(protocol_number, &action_handler, &request_data).await,
+    };
+
+    let response_option =
+        battle_room_actor_handle.handle_action(
+            protocol_number,
+            find_battle_room_actor_response.get_account_unique_id(),
+            action_handler,
+            request_data).await;
+
+    match response_option {
+        Some(response) => response,
+        None => {
+            eprintln!("Battle room {} aborted protocol {}", battle_room_actor_handle.get_battle_room_id(), protocol_number);
+            ResponseType::PROTOCOL_ERROR(
+                ProtocolErrorResponseForm::new(protocol_number,
+                                               ProtocolErrorCode::BattleRoomActionAborted,
+                                               "battle room action aborted".to_string()))
+        }
+    }
+}
+
+// 요청은 dispatch 전에 이미 한 번 읽었으므로 여기서 실패하는 경우는 없지만, 실패하더라도 PROTOCOL_ERROR 로 응답
+pub async fn run_battle_room_action(protocol_number: i64, action_handler: &ProtocolHandler, request_data: &JsonValue) -> ResponseType {
+    match action_handler(request_data) {
+        Ok(action_future) => action_future.await,
+        Err(request_parse_error) =>
+            ResponseType::PROTOCOL_ERROR(
+                ProtocolErrorResponseForm::from_request_parse_error(protocol_number, &request_parse_error)),
+    }
+}
+
+// 턴 시간 초과, 기권, 서버 종료처럼 요청 없이 방 상태를 바꾸는 경로가 명령을 보낼 Actor
+// 이미 사라진 방이라면 None
+pub async fn find_battle_room_actor_handle(battle_room_id: i32) -> Option<BattleRoomActorHandle> {
+    let battle_room_actor_service_mutex = BattleRoomActorServiceImpl::get_instance();
+    let battle_room_actor_service_guard = battle_room_actor_service_mutex.lock().await;
+    let find_battle_room_actor_response =
+        battle_room_actor_service_guard.find_battle_room_actor_by_battle_room_id(
+            FindBattleRoomActorByBattleRoomIdRequest::new(battle_room_id)).await;
+    drop(battle_room_actor_service_guard);
+
+    find_battle_room_actor_response.get_battle_room_actor_handle().cloned()
+}
+
+// 매칭 직후 게임 상태 생성처럼 계정만 알고 있는 경로가 명령을 보낼 Actor
+// 아직 방에 배치되지 않았다면 None
+pub async fn find_battle_room_actor_handle_of_account(account_unique_id: i32) -> Option<BattleRoomActorHandle> {
+    let battle_room_actor_service_mutex = BattleRoomActorServiceImpl::get_instance();
+    let battle_room_actor_service_guard = battle_room_actor_service_mutex.lock().await;
+    let find_battle_room_actor_response =
+        battle_room_actor_service_guard.find_battle_room_actor_by_account_unique_id(
+            FindBattleRoomActorByAccountUniqueIdRequest::new(account_unique_id)).await;
+    drop(battle_room_actor_service_guard);
+
+    find_battle_room_actor_response.get_battle_room_actor_handle().cloned()
+}
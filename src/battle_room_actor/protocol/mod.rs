@@ -0,0 +1 @@
+pub mod battle_room_action_dispatcher;
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use crate::battle_room_actor::entity::battle_room_actor_handle::BattleRoomActorHandle;
+
+#[async_trait]
+pub trait BattleRoomActorRepository {
+    async fn find_or_spawn_battle_room_actor(&mut self, battle_room_id: i32) -> BattleRoomActorHandle;
+    async fn remove_battle_room_actor(&mut self, battle_room_id: i32) -> bool;
+}
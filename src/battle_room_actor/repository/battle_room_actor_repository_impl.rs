@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+use crate::battle_room_actor::entity::battle_room_actor::BattleRoomActor;
+use crate::battle_room_actor::entity::battle_room_actor_handle::BattleRoomActorHandle;
+use crate::battle_room_actor::repository::battle_room_actor_repository::BattleRoomActorRepository;
+
+// battle_room_id 를 key 로 각 방의 Actor 에 명령을 보낼 handle 을 보관
+pub struct BattleRoomActorRepositoryImpl {
+    battle_room_actor_map: HashMap<i32, BattleRoomActorHandle>,
+}
+
+impl BattleRoomActorRepositoryImpl {
+    pub fn new() -> Self {
+        BattleRoomActorRepositoryImpl {
+            battle_room_actor_map: HashMap::new(),
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<BattleRoomActorRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<BattleRoomActorRepositoryImpl>> =
+                Arc::new(AsyncMutex::new(BattleRoomActorRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl BattleRoomActorRepository for BattleRoomActorRepositoryImpl {
+    // 방의 첫 행동이 들어올 때 Actor 를 띄우고, 이미 종료된 Actor 는 새로 띄움
+    async fn find_or_spawn_battle_room_actor(&mut self, battle_room_id: i32) -> BattleRoomActorHandle {
+        if let Some(battle_room_actor_handle) = self.battle_room_actor_map.get(&battle_room_id) {
+            if !battle_room_actor_handle.is_closed() {
+                return battle_room_actor_handle.clone()
+            }
+        }
+
+        println!("BattleRoomActorRepositoryImpl: find_or_spawn_battle_room_actor() -> spawn {}", battle_room_id);
+
+        let battle_room_actor_handle = BattleRoomActor::spawn(battle_room_id);
+        self.battle_room_actor_map.insert(battle_room_id, battle_room_actor_handle.clone());
+
+        battle_room_actor_handle
+    }
+
+    // handle 을 버리면 Actor 는 남은 명령을 모두 처리한 뒤 종료됨
+    async fn remove_battle_room_actor(&mut self, battle_room_id: i32) -> bool {
+        println!("BattleRoomActorRepositoryImpl: remove_battle_room_actor() -> {}", battle_room_id);
+
+        self.battle_room_actor_map.remove(&battle_room_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_find_or_spawn_reuses_actor_until_removed() {
+        let mut battle_room_actor_repository = BattleRoomActorRepositoryImpl::new();
+
+        battle_room_actor_repository.find_or_spawn_battle_room_actor(7).await;
+        battle_room_actor_repository.find_or_spawn_battle_room_actor(7).await;
+        assert_eq!(battle_room_actor_repository.battle_room_actor_map.len(), 1);
+
+        assert!(battle_room_actor_repository.remove_battle_room_actor(7).await);
+        assert!(!battle_room_actor_repository.remove_battle_room_actor(7).await);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod battle_room_actor_repository;
+pub mod battle_room_actor_repository_impl;
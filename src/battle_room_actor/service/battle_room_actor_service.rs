@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_account_unique_id_request::FindBattleRoomActorByAccountUniqueIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_battle_room_id_request::FindBattleRoomActorByBattleRoomIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_request::FindBattleRoomActorRequest;
+use crate::battle_room_actor::service::response::find_battle_room_actor_response::FindBattleRoomActorResponse;
+
+#[async_trait]
+pub trait BattleRoomActorService {
+    async fn find_battle_room_actor(&self, find_battle_room_actor_request: FindBattleRoomActorRequest) -> FindBattleRoomActorResponse;
+    async fn find_battle_room_actor_by_account_unique_id(&self, find_battle_room_actor_by_account_unique_id_request: FindBattleRoomActorByAccountUniqueIdRequest) -> FindBattleRoomActorResponse;
+    async fn find_battle_room_actor_by_battle_room_id(&self, find_battle_room_actor_by_battle_room_id_request: FindBattleRoomActorByBattleRoomIdRequest) -> FindBattleRoomActorResponse;
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
+use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::battle_room_actor::repository::battle_room_actor_repository::BattleRoomActorRepository;
+use crate::battle_room_actor::repository::battle_room_actor_repository_impl::BattleRoomActorRepositoryImpl;
+use crate::battle_room_actor::service::battle_room_actor_service::BattleRoomActorService;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_account_unique_id_request::FindBattleRoomActorByAccountUniqueIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_by_battle_room_id_request::FindBattleRoomActorByBattleRoomIdRequest;
+use crate::battle_room_actor::service::request::find_battle_room_actor_request::FindBattleRoomActorRequest;
+use crate::battle_room_actor::service::response::find_battle_room_actor_response::FindBattleRoomActorResponse;
+use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
+use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
+
+pub struct BattleRoomActorServiceImpl {
+    battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
+    battle_room_actor_repository: Arc<AsyncMutex<BattleRoomActorRepositoryImpl>>,
+}
+
+impl BattleRoomActorServiceImpl {
+    pub fn new(battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
+               battle_room_actor_repository: Arc<AsyncMutex<BattleRoomActorRepositoryImpl>>) -> Self {
+
+        BattleRoomActorServiceImpl {
+            battle_room_repository,
+            battle_room_actor_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<BattleRoomActorServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<BattleRoomActorServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        BattleRoomActorServiceImpl::new(
+                            BattleRoomRepositoryImpl::get_instance(),
+                            BattleRoomActorRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    // 세션 조회가 필요한 경우에만 세션 저장소를 사용하여, 방 번호나 계정으로 찾는 경로는 세션 저장소와 무관하게 동작
+    async fn find_account_unique_id(&self, session_id: &str) -> Option<i32> {
+        let redis_in_memory_repository_mutex = RedisInMemoryRepositoryImpl::get_instance();
+        let mut redis_in_memory_repository_guard = redis_in_memory_repository_mutex.lock().await;
+        let account_unique_id_string = redis_in_memory_repository_guard.get(session_id).await?;
+        drop(redis_in_memory_repository_guard);
+
+        account_unique_id_string.parse().ok()
+    }
+}
+
+#[async_trait]
+impl BattleRoomActorService for BattleRoomActorServiceImpl {
+    async fn find_battle_room_actor(&self, find_battle_room_actor_request: FindBattleRoomActorRequest) -> FindBattleRoomActorResponse {
+        println!("BattleRoomActorServiceImpl: find_battle_room_actor()");
+
+        let account_unique_id = match self.find_account_unique_id(find_battle_room_actor_request.get_session_id()).await {
+            Some(account_unique_id) => account_unique_id,
+            None => return FindBattleRoomActorResponse::new(-1, None),
+        };
+
+        self.find_battle_room_actor_by_account_unique_id(
+            FindBattleRoomActorByAccountUniqueIdRequest::new(account_unique_id)).await
+    }
+
+    // 매칭 직후 게임 상태 생성처럼 세션 없이 계정으로 방을 찾는 경로가 사용
+    async fn find_battle_room_actor_by_account_unique_id(&self, find_battle_room_actor_by_account_unique_id_request: FindBattleRoomActorByAccountUniqueIdRequest) -> FindBattleRoomActorResponse {
+        println!("BattleRoomActorServiceImpl: find_battle_room_actor_by_account_unique_id()");
+
+        let account_unique_id = find_battle_room_actor_by_account_unique_id_request.get_account_unique_id();
+
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let battle_room_id_option = battle_room_repository_guard.what_is_the_room_number(account_unique_id).await;
+        drop(battle_room_repository_guard);
+
+        let battle_room_id = match battle_room_id_option {
+            Some(battle_room_id) => battle_room_id,
+            None => return FindBattleRoomActorResponse::new(account_unique_id, None),
+        };
+
+        let mut battle_room_actor_repository_guard = self.battle_room_actor_repository.lock().await;
+        let battle_room_actor_handle = battle_room_actor_repository_guard.find_or_spawn_battle_room_actor(battle_room_id).await;
+
+        FindBattleRoomActorResponse::new(account_unique_id, Some(battle_room_actor_handle))
+    }
+
+    // 요청 없이 방 상태를 바꾸는 경로 (턴 시간 초과, 기권, 서버 종료) 가 사용하며, 이미 사라진 방에는 Actor 를 띄우지 않음
+    async fn find_battle_room_actor_by_battle_room_id(&self, find_battle_room_actor_by_battle_room_id_request: FindBattleRoomActorByBattleRoomIdRequest) -> FindBattleRoomActorResponse {
+        println!("BattleRoomActorServiceImpl: find_battle_room_actor_by_battle_room_id()");
+
+        let battle_room_id = find_battle_room_actor_by_battle_room_id_request.get_battle_room_id();
+
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let player_list_option = battle_room_repository_guard.get_players_in_battle_room(battle_room_id).await;
+        drop(battle_room_repository_guard);
+
+        if player_list_option.is_none() {
+            return FindBattleRoomActorResponse::new(-1, None)
+        }
+
+        let mut battle_room_actor_repository_guard = self.battle_room_actor_repository.lock().await;
+        let battle_room_actor_handle = battle_room_actor_repository_guard.find_or_spawn_battle_room_actor(battle_room_id).await;
+
+        FindBattleRoomActorResponse::new(-1, Some(battle_room_actor_handle))
+    }
+}
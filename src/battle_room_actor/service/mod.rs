@@ -0,0 +1,4 @@
+pub mod battle_room_actor_service;
+pub mod battle_room_actor_service_impl;
+pub mod request;
+pub mod response;
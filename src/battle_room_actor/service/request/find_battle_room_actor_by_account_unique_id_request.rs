@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct FindBattleRoomActorByAccountUniqueIdRequest {
+    account_unique_id: i32,
+}
+
+impl FindBattleRoomActorByAccountUniqueIdRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        FindBattleRoomActorByAccountUniqueIdRequest {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
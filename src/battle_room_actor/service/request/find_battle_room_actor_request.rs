@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct FindBattleRoomActorRequest {
+    session_id: String,
+}
+
+impl FindBattleRoomActorRequest {
+    pub fn new(session_id: String) -> Self {
+        FindBattleRoomActorRequest {
+            session_id,
+        }
+    }
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+}
@@ -0,0 +1,3 @@
+pub mod find_battle_room_actor_request;
+pub mod find_battle_room_actor_by_battle_room_id_request;
+pub mod find_battle_room_actor_by_account_unique_id_request;
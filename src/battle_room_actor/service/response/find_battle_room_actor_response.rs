@@ -0,0 +1,24 @@
+use crate::battle_room_actor::entity::battle_room_actor_handle::BattleRoomActorHandle;
+
+#[derive(Debug)]
+pub struct FindBattleRoomActorResponse {
+    account_unique_id: i32,
+    battle_room_actor_handle: Option<BattleRoomActorHandle>,
+}
+
+impl FindBattleRoomActorResponse {
+    pub fn new(account_unique_id: i32, battle_room_actor_handle: Option<BattleRoomActorHandle>) -> Self {
+        FindBattleRoomActorResponse {
+            account_unique_id,
+            battle_room_actor_handle,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+
+    pub fn get_battle_room_actor_handle(&self) -> Option<&BattleRoomActorHandle> {
+        self.battle_room_actor_handle.as_ref()
+    }
+}
@@ -0,0 +1 @@
+pub mod find_battle_room_actor_response;
@@ -3,6 +3,8 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
+use crate::action_waiting_timer::service::action_waiting_timer_service::ActionWaitingTimerService;
+use crate::action_waiting_timer::service::action_waiting_timer_service_impl::ActionWaitingTimerServiceImpl;
 use crate::battle_room::service::battle_room_service::BattleRoomService;
 use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
@@ -35,6 +37,7 @@ pub struct BattleStartControllerImpl {
     game_field_energy_service: Arc<AsyncMutex<GameFieldEnergyServiceImpl>>,
     ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
     rock_paper_scissors_service: Arc<AsyncMutex<RockPaperScissorsServiceImpl>>,
+    action_waiting_timer_service: Arc<AsyncMutex<ActionWaitingTimerServiceImpl>>,
 }
 
 impl BattleStartControllerImpl {
@@ -46,6 +49,7 @@ impl BattleStartControllerImpl {
                game_field_energy_service: Arc<AsyncMutex<GameFieldEnergyServiceImpl>>,
                ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
                rock_paper_scissors_service: Arc<AsyncMutex<RockPaperScissorsServiceImpl>>,
+               action_waiting_timer_service: Arc<AsyncMutex<ActionWaitingTimerServiceImpl>>,
     ) -> Self {
         BattleStartControllerImpl {
             game_hand_service,
@@ -56,6 +60,7 @@ impl BattleStartControllerImpl {
             game_field_energy_service,
             ui_data_generator_service,
             rock_paper_scissors_service,
+            action_waiting_timer_service,
         }
     }
     pub fn get_instance() -> Arc<AsyncMutex<BattleStartControllerImpl>> {
@@ -71,7 +76,8 @@ impl BattleStartControllerImpl {
                             RedisInMemoryServiceImpl::get_instance(),
                             GameFieldEnergyServiceImpl::get_instance(),
                             UiDataGeneratorServiceImpl::get_instance(),
-                            RockPaperScissorsServiceImpl::get_instance())));
+                            RockPaperScissorsServiceImpl::get_instance(),
+                            ActionWaitingTimerServiceImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -84,6 +90,16 @@ impl BattleStartControllerImpl {
         value_string.parse::<i32>().unwrap_or_else(|_| { -1 })
     }
 
+    async fn get_remaining_turn_time_seconds(&self, battle_start_request_form: &BattleStartRequestForm, account_unique_id: i32) -> u64 {
+        let action_waiting_timer_service_guard = self.action_waiting_timer_service.lock().await;
+        let get_remaining_action_waiting_time_response =
+            action_waiting_timer_service_guard.get_remaining_action_waiting_time(
+                battle_start_request_form
+                    .to_get_remaining_action_waiting_time_request(account_unique_id)).await;
+        drop(action_waiting_timer_service_guard);
+        get_remaining_action_waiting_time_response.get_remaining_seconds()
+    }
+
     async fn get_opponent_unique_id(&self, find_opponent_by_account_id_request: FindOpponentByAccountIdRequest) -> i32 {
         let battle_room_service_guard = self.battle_room_service.lock().await;
         let find_opponent_by_account_id_response = battle_room_service_guard.find_opponent_by_account_unique_id(find_opponent_by_account_id_request).await;
@@ -170,6 +186,19 @@ impl BattleStartController for BattleStartControllerImpl {
 
             drop(game_field_energy_service_guard);
 
+            // 선공 플레이어의 턴 제한 시간 측정 시작
+            let action_waiting_timer_service_guard =
+                self.action_waiting_timer_service.lock().await;
+
+            action_waiting_timer_service_guard.set_action_waiting_time(
+                battle_start_request_form
+                    .to_action_waiting_timer_request(account_unique_id)).await;
+
+            drop(action_waiting_timer_service_guard);
+
+            let remaining_turn_time_seconds =
+                self.get_remaining_turn_time_seconds(&battle_start_request_form, account_unique_id).await;
+
             let mut ui_data_generator_service_guard =
                 self.ui_data_generator_service.lock().await;
 
@@ -188,10 +217,14 @@ impl BattleStartController for BattleStartControllerImpl {
 
             return BattleStartResponseForm::from_response_for_winner(
                 generate_draw_my_deck_data_response,
-                generate_my_field_energy_data_response)
+                generate_my_field_energy_data_response,
+                remaining_turn_time_seconds)
         }
 
         if check_rock_paper_scissors_winner_response.get_am_i_winner() == LOSE {
+            let remaining_turn_time_seconds =
+                self.get_remaining_turn_time_seconds(&battle_start_request_form, account_unique_id).await;
+
             let mut ui_data_generator_service_guard =
                 self.ui_data_generator_service.lock().await;
 
@@ -209,7 +242,8 @@ impl BattleStartController for BattleStartControllerImpl {
 
             return BattleStartResponseForm::from_response_for_loser(
                 generate_draw_opponent_deck_data_response,
-                generate_opponent_field_energy_response)
+                generate_opponent_field_energy_response,
+                remaining_turn_time_seconds)
         }
 
         BattleStartResponseForm::default()
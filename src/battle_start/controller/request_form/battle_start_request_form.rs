@@ -1,3 +1,5 @@
+use crate::action_waiting_timer::service::request::action_waiting_timer_request::ActionWaitingTimerRequest;
+use crate::action_waiting_timer::service::request::get_remaining_action_waiting_time_request::GetRemainingActionWaitingTimeRequest;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_deck::service::request::draw_cards_from_deck_request::DrawCardsFromDeckRequest;
 use crate::game_field_energy::service::request::add_field_energy_with_amount_request::AddFieldEnergyWithAmountRequest;
@@ -57,6 +59,22 @@ impl BattleStartRequestForm {
             account_unique_id)
     }
 
+    pub fn to_action_waiting_timer_request(
+        &self,
+        account_unique_id: i32) -> ActionWaitingTimerRequest {
+
+        ActionWaitingTimerRequest::new(
+            account_unique_id)
+    }
+
+    pub fn to_get_remaining_action_waiting_time_request(
+        &self,
+        account_unique_id: i32) -> GetRemainingActionWaitingTimeRequest {
+
+        GetRemainingActionWaitingTimeRequest::new(
+            account_unique_id)
+    }
+
     pub fn to_draw_cards_from_deck_request(
         &self,
         account_unique_id: i32) -> DrawCardsFromDeckRequest {
@@ -12,13 +12,15 @@ pub struct BattleStartResponseForm {
     player_draw_card_list_map: HashMap<PlayerIndex, Vec<i32>>,
     player_draw_count_map: HashMap<PlayerIndex, i32>,
     player_field_energy_map: HashMap<PlayerIndex, i32>,
+    remaining_turn_time_seconds: u64,
 }
 
 impl BattleStartResponseForm {
     pub fn new(is_your_turn: bool,
                player_draw_card_list_map: HashMap<PlayerIndex, Vec<i32>>,
                player_draw_count_map: HashMap<PlayerIndex, i32>,
-               player_field_energy_map: HashMap<PlayerIndex, i32>
+               player_field_energy_map: HashMap<PlayerIndex, i32>,
+               remaining_turn_time_seconds: u64,
     ) -> Self {
 
         BattleStartResponseForm {
@@ -26,12 +28,14 @@ impl BattleStartResponseForm {
             player_draw_card_list_map,
             player_draw_count_map,
             player_field_energy_map,
+            remaining_turn_time_seconds,
         }
     }
 
     pub fn from_response_for_winner(
         generate_draw_my_deck_data_response: GenerateDrawMyDeckDataResponse,
         generate_my_field_energy_data_response: GenerateMyFieldEnergyDataResponse,
+        remaining_turn_time_seconds: u64,
     ) -> BattleStartResponseForm {
 
         BattleStartResponseForm::new(
@@ -40,12 +44,14 @@ impl BattleStartResponseForm {
                 .get_player_drawn_card_list_map_for_response().clone(),
             HashMap::new(),
             generate_my_field_energy_data_response
-                .get_player_field_energy_map_for_response().clone())
+                .get_player_field_energy_map_for_response().clone(),
+            remaining_turn_time_seconds)
     }
 
     pub fn from_response_for_loser(
         generate_draw_opponent_deck_data_response: GenerateDrawOpponentDeckDataResponse,
         generate_opponent_field_energy_data_response: GenerateOpponentFieldEnergyDataResponse,
+        remaining_turn_time_seconds: u64,
     ) -> BattleStartResponseForm {
 
         BattleStartResponseForm::new(
@@ -54,7 +60,8 @@ impl BattleStartResponseForm {
             generate_draw_opponent_deck_data_response
                 .get_player_drawn_card_count_map_for_response().clone(),
             generate_opponent_field_energy_data_response
-                .get_player_field_energy_map_for_response().clone())
+                .get_player_field_energy_map_for_response().clone(),
+            remaining_turn_time_seconds)
     }
 
     pub fn default() -> BattleStartResponseForm {
@@ -62,6 +69,7 @@ impl BattleStartResponseForm {
             false,
             HashMap::new(),
             HashMap::new(),
-            HashMap::new())
+            HashMap::new(),
+            0)
     }
 }
\ No newline at end of file
@@ -1 +1,2 @@
-pub mod controller;
\ No newline at end of file
+pub mod controller;
+pub mod protocol;
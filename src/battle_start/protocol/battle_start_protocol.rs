@@ -0,0 +1,16 @@
+use crate::battle_start::controller::battle_start_controller::BattleStartController;
+use crate::battle_start::controller::battle_start_controller_impl::BattleStartControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::battle_start_request_form_generator::create_battle_start_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_start_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Battle Start
+    protocol_registry.register_battle_room_action(21, "BATTLE_START", create_battle_start_request_form, |request| async move {
+        let battle_start_controller_mutex = BattleStartControllerImpl::get_instance();
+        let battle_start_controller_mutex_guard = battle_start_controller_mutex.lock().await;
+
+        let response = battle_start_controller_mutex_guard.request_to_start_battle(request).await;
+        ResponseType::BATTLE_START(response)
+    });
+}
@@ -1,29 +1,46 @@
 use tokio::sync::Mutex;
+use tokio::time::Instant;
+use std::collections::HashMap;
 use std::sync::Arc;
+use crate::account_rating::entity::elo_rating_calculator::DEFAULT_RATING;
+use crate::battle_wait_queue::entity::rating_match_window::RatingMatchWindow;
+use crate::battle_wait_queue::entity::waiting_player_info::WaitingPlayerInfo;
 
 #[derive(Debug)]
 pub struct BattleWaitQueue {
     pub player_id_list: Mutex<Vec<i32>>,
+    waiting_player_info_map: Mutex<HashMap<i32, WaitingPlayerInfo>>,
+    rating_match_window: RatingMatchWindow,
 }
 
 impl BattleWaitQueue {
     pub fn new() -> BattleWaitQueue {
         BattleWaitQueue {
             player_id_list: Mutex::new(Vec::new()),
+            waiting_player_info_map: Mutex::new(HashMap::new()),
+            rating_match_window: RatingMatchWindow::default(),
         }
     }
 
     pub async fn enqueue_player(&self, player_id: i32) {
+        self.enqueue_player_with_rating(player_id, DEFAULT_RATING).await;
+    }
+
+    pub async fn enqueue_player_with_rating(&self, player_id: i32, rating: i32) {
         let mut guard = self.player_id_list.lock().await;
+        let mut waiting_player_info_guard = self.waiting_player_info_map.lock().await;
         guard.push(player_id);
+        waiting_player_info_guard.insert(player_id, WaitingPlayerInfo::new(rating, Instant::now()));
 
         println!("player_id_list: {:?}", guard);
     }
 
     pub async fn dequeue_player(&self, player_id: i32) -> bool {
         let mut guard = self.player_id_list.lock().await;
+        let mut waiting_player_info_guard = self.waiting_player_info_map.lock().await;
         if guard.contains(&player_id) {
             guard.retain(|id| id != &player_id);
+            waiting_player_info_guard.remove(&player_id);
             return true
         }
 
@@ -33,13 +50,16 @@ impl BattleWaitQueue {
 
     pub async fn process_queue(&self, max_players: usize) {
         let mut guard = self.player_id_list.lock().await;
+        let mut waiting_player_info_guard = self.waiting_player_info_map.lock().await;
         while guard.len() > max_players {
-            guard.remove(0);
+            let removed_player_id = guard.remove(0);
+            waiting_player_info_guard.remove(&removed_player_id);
         }
     }
 
     pub async fn dequeue_n_players(&self, count: usize) -> Vec<i32> {
         let mut guard = self.player_id_list.lock().await;
+        let mut waiting_player_info_guard = self.waiting_player_info_map.lock().await;
         let mut dequeued_players = Vec::new();
 
         if guard.len() >= count {
@@ -47,8 +67,41 @@ impl BattleWaitQueue {
             dequeued_players.push(guard.pop().unwrap());
         }
 
+        for dequeued_player in &dequeued_players {
+            waiting_player_info_guard.remove(dequeued_player);
+        }
+
         dequeued_players
     }
+
+    pub async fn dequeue_rating_matched_players(&self) -> Vec<i32> {
+        self.dequeue_rating_matched_players_at(Instant::now()).await
+    }
+
+    pub async fn dequeue_rating_matched_players_at(&self, now: Instant) -> Vec<i32> {
+        let mut guard = self.player_id_list.lock().await;
+        let mut waiting_player_info_guard = self.waiting_player_info_map.lock().await;
+
+        let candidate_list: Vec<(i32, WaitingPlayerInfo)> = guard.iter()
+            .map(|player_id| {
+                let waiting_player_info = waiting_player_info_guard
+                    .entry(*player_id)
+                    .or_insert_with(|| WaitingPlayerInfo::new(DEFAULT_RATING, now));
+                (*player_id, *waiting_player_info)
+            })
+            .collect();
+
+        match self.rating_match_window.find_matched_pair(&candidate_list, now) {
+            Some((player_id, opponent_id)) => {
+                guard.retain(|id| *id != player_id && *id != opponent_id);
+                waiting_player_info_guard.remove(&player_id);
+                waiting_player_info_guard.remove(&opponent_id);
+
+                vec![player_id, opponent_id]
+            }
+            None => Vec::new(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -155,4 +208,34 @@ mod tests {
         assert_eq!(guard.len(), 1);
         assert_eq!(guard[0], 1);
     }
+
+    #[tokio::test]
+    async fn test_dequeue_rating_matched_players() {
+        let waiting_queue = Arc::new(BattleWaitQueue::new());
+
+        waiting_queue.enqueue_player_with_rating(1, 1000).await;
+        waiting_queue.enqueue_player_with_rating(2, 1500).await;
+        waiting_queue.enqueue_player_with_rating(3, 1050).await;
+
+        let dequeued_players = waiting_queue.dequeue_rating_matched_players().await;
+        assert_eq!(dequeued_players, vec![1, 3]);
+
+        let guard = waiting_queue.player_id_list.lock().await;
+        assert_eq!(*guard, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_rating_matched_players_after_long_wait() {
+        let waiting_queue = Arc::new(BattleWaitQueue::new());
+
+        waiting_queue.enqueue_player_with_rating(1, 1000).await;
+        waiting_queue.enqueue_player_with_rating(2, 1500).await;
+
+        let dequeued_players = waiting_queue.dequeue_rating_matched_players().await;
+        assert!(dequeued_players.is_empty());
+
+        let later = Instant::now() + Duration::from_secs(60);
+        let dequeued_players = waiting_queue.dequeue_rating_matched_players_at(later).await;
+        assert_eq!(dequeued_players, vec![1, 2]);
+    }
 }
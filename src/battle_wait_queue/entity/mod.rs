@@ -1 +1,3 @@
 pub mod battle_wait_queue;
+pub mod waiting_player_info;
+pub mod rating_match_window;
@@ -0,0 +1,108 @@
+use tokio::time::Instant;
+use crate::battle_wait_queue::entity::waiting_player_info::WaitingPlayerInfo;
+
+pub const BASE_RATING_WINDOW: i32 = 100;
+pub const RATING_WINDOW_GROWTH_PER_SECOND: i32 = 10;
+pub const MAX_RATING_WINDOW: i32 = 1000;
+
+// 대기 시간이 길어질수록 허용 레이팅 차이를 넓혀 매칭이 영원히 밀리지 않도록 함
+#[derive(Debug, Clone, Copy)]
+pub struct RatingMatchWindow {
+    base_window: i32,
+    growth_per_second: i32,
+    max_window: i32,
+}
+
+impl RatingMatchWindow {
+    pub fn new(base_window: i32, growth_per_second: i32, max_window: i32) -> Self {
+        RatingMatchWindow { base_window, growth_per_second, max_window }
+    }
+
+    pub fn window_for(&self, waiting_player_info: &WaitingPlayerInfo, now: Instant) -> i32 {
+        let waited_seconds = waiting_player_info.waited_duration(now).as_secs() as i32;
+        let window = self.base_window.saturating_add(self.growth_per_second.saturating_mul(waited_seconds));
+
+        window.min(self.max_window)
+    }
+
+    // 오래 기다린 플레이어부터 창 안에서 가장 레이팅이 가까운 상대를 찾는다
+    pub fn find_matched_pair(&self, candidate_list: &[(i32, WaitingPlayerInfo)], now: Instant) -> Option<(i32, i32)> {
+        let mut ordered_candidate_list = candidate_list.to_vec();
+        ordered_candidate_list.sort_by_key(|(_, waiting_player_info)| waiting_player_info.get_enqueued_at());
+
+        for (index, (player_id, waiting_player_info)) in ordered_candidate_list.iter().enumerate() {
+            let player_window = self.window_for(waiting_player_info, now);
+
+            let closest_opponent = ordered_candidate_list.iter()
+                .enumerate()
+                .filter(|(opponent_index, _)| *opponent_index != index)
+                .map(|(_, (opponent_id, opponent_info))| {
+                    let rating_gap = (waiting_player_info.get_rating() - opponent_info.get_rating()).abs();
+                    let allowed_window = player_window.max(self.window_for(opponent_info, now));
+                    (*opponent_id, rating_gap, allowed_window)
+                })
+                .filter(|(_, rating_gap, allowed_window)| rating_gap <= allowed_window)
+                .min_by_key(|(_, rating_gap, _)| *rating_gap);
+
+            if let Some((opponent_id, _, _)) = closest_opponent {
+                return Some((*player_id, opponent_id))
+            }
+        }
+
+        None
+    }
+}
+
+impl Default for RatingMatchWindow {
+    fn default() -> Self {
+        RatingMatchWindow::new(BASE_RATING_WINDOW, RATING_WINDOW_GROWTH_PER_SECOND, MAX_RATING_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::Duration;
+
+    #[test]
+    fn test_window_widens_with_wait_time() {
+        let rating_match_window = RatingMatchWindow::default();
+        let enqueued_at = Instant::now();
+        let waiting_player = WaitingPlayerInfo::new(1000, enqueued_at);
+
+        assert_eq!(rating_match_window.window_for(&waiting_player, enqueued_at), BASE_RATING_WINDOW);
+        assert_eq!(rating_match_window.window_for(&waiting_player, enqueued_at + Duration::from_secs(30)),
+                   BASE_RATING_WINDOW + 30 * RATING_WINDOW_GROWTH_PER_SECOND);
+        assert_eq!(rating_match_window.window_for(&waiting_player, enqueued_at + Duration::from_secs(3600)), MAX_RATING_WINDOW);
+    }
+
+    #[test]
+    fn test_find_matched_pair_prefers_closest_rating() {
+        let rating_match_window = RatingMatchWindow::default();
+        let enqueued_at = Instant::now();
+
+        let candidate_list = vec![
+            (3, WaitingPlayerInfo::new(1020, enqueued_at + Duration::from_secs(2))),
+            (2, WaitingPlayerInfo::new(1090, enqueued_at + Duration::from_secs(1))),
+            (1, WaitingPlayerInfo::new(1000, enqueued_at)),
+        ];
+
+        let now = enqueued_at + Duration::from_secs(3);
+        assert_eq!(rating_match_window.find_matched_pair(&candidate_list, now), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_find_matched_pair_waits_until_window_is_wide_enough() {
+        let rating_match_window = RatingMatchWindow::default();
+        let now = Instant::now();
+
+        let candidate_list = vec![
+            (1, WaitingPlayerInfo::new(1000, now)),
+            (2, WaitingPlayerInfo::new(1300, now)),
+        ];
+        assert_eq!(rating_match_window.find_matched_pair(&candidate_list, now), None);
+
+        let later = now + Duration::from_secs(20);
+        assert_eq!(rating_match_window.find_matched_pair(&candidate_list, later), Some((1, 2)));
+    }
+}
@@ -0,0 +1,20 @@
+use tokio::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct WaitingPlayerInfo {
+    rating: i32,
+    enqueued_at: Instant,
+}
+
+impl WaitingPlayerInfo {
+    pub fn new(rating: i32, enqueued_at: Instant) -> Self {
+        WaitingPlayerInfo { rating, enqueued_at }
+    }
+
+    pub fn get_rating(&self) -> i32 { self.rating }
+    pub fn get_enqueued_at(&self) -> Instant { self.enqueued_at }
+
+    pub fn waited_duration(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.enqueued_at)
+    }
+}
@@ -1,3 +1,4 @@
 pub mod repository;
 pub mod entity;
-pub mod service;
\ No newline at end of file
+pub mod service;
+pub mod protocol;
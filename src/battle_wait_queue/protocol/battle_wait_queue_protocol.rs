@@ -0,0 +1,26 @@
+use crate::battle_wait_queue::service::battle_wait_queue_service::BattleWaitQueueService;
+use crate::battle_wait_queue::service::battle_wait_queue_service_impl::BattleWaitQueueServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::battle_match_cancel_request_generator::create_battle_match_cancel_request;
+use crate::request_generator::battle_wait_queue_request_generator::create_battle_wait_queue_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_battle_wait_queue_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Battle Wait Queue for Match
+    protocol_registry.register(11, "BATTLE_WAIT_QUEUE_FOR_MATCH", create_battle_wait_queue_request, |request| async move {
+        let battle_wait_queue_service_mutex = BattleWaitQueueServiceImpl::get_instance();
+        let battle_wait_queue_service = battle_wait_queue_service_mutex.lock().await;
+
+        let response = battle_wait_queue_service.enqueue_player_id_to_wait_queue(request).await;
+        ResponseType::BATTLE_WAIT_QUEUE_FOR_MATCH(response)
+    });
+
+    // Battle Match Cancel
+    protocol_registry.register(13, "BATTLE_MATCH_CANCEL", create_battle_match_cancel_request, |request| async move {
+        let battle_wait_queue_service_mutex = BattleWaitQueueServiceImpl::get_instance();
+        let battle_wait_queue_service = battle_wait_queue_service_mutex.lock().await;
+
+        let response = battle_wait_queue_service.dequeue_player_id_from_wait_queue(request).await;
+        ResponseType::BATTLE_MATCH_CANCEL(response)
+    });
+}
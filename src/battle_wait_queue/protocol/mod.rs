@@ -0,0 +1 @@
+pub mod battle_wait_queue_protocol;
@@ -3,20 +3,24 @@ use std::error::Error;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::Notify;
 
 use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
 use crate::battle_wait_queue::entity::battle_wait_queue::BattleWaitQueue;
 use crate::battle_wait_queue::repository::battle_wait_queue_repository::BattleWaitQueueRepository;
 
 
+// 대기열이 바뀔 때마다 battle_match_signal 로 매칭 모니터를 깨워 즉시 짝을 찾도록 함
 pub struct BattleWaitQueueRepositoryImpl {
     battle_wait_queue: Arc<AsyncMutex<BattleWaitQueue>>,
+    battle_match_signal: Arc<Notify>,
 }
 
 impl BattleWaitQueueRepositoryImpl {
     pub fn new() -> Self {
         BattleWaitQueueRepositoryImpl {
             battle_wait_queue: Arc::new(AsyncMutex::new(BattleWaitQueue::new())),
+            battle_match_signal: Arc::new(Notify::new()),
         }
     }
 
@@ -24,6 +28,10 @@ impl BattleWaitQueueRepositoryImpl {
         Arc::clone(&self.battle_wait_queue)
     }
 
+    pub fn get_battle_match_signal(&self) -> Arc<Notify> {
+        Arc::clone(&self.battle_match_signal)
+    }
+
     pub fn get_instance() -> Arc<AsyncMutex<BattleWaitQueueRepositoryImpl>> {
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<BattleWaitQueueRepositoryImpl>> =
@@ -39,10 +47,26 @@ impl BattleWaitQueueRepository for BattleWaitQueueRepositoryImpl {
         println!("BattleWaitQueueRepositoryImpl: enqueue_player_id_for_wait()");
         let battle_wait_queue_guard = self.battle_wait_queue.lock().await;
         battle_wait_queue_guard.enqueue_player(account_unique_id).await;
+        self.battle_match_signal.notify_one();
+
+        Ok(true)
+    }
+
+    async fn enqueue_player_id_with_rating_for_wait(&self, account_unique_id: i32, rating: i32) -> Result<bool, Box<dyn Error>> {
+        println!("BattleWaitQueueRepositoryImpl: enqueue_player_id_with_rating_for_wait()");
+        let battle_wait_queue_guard = self.battle_wait_queue.lock().await;
+        battle_wait_queue_guard.enqueue_player_with_rating(account_unique_id, rating).await;
+        self.battle_match_signal.notify_one();
 
         Ok(true)
     }
 
+    async fn dequeue_rating_matched_players_from_wait_queue(&self) -> Vec<i32> {
+        println!("BattleWaitQueueRepositoryImpl: dequeue_rating_matched_players_from_wait_queue()");
+        let battle_wait_queue_guard = self.battle_wait_queue.lock().await;
+        battle_wait_queue_guard.dequeue_rating_matched_players().await
+    }
+
     async fn dequeue_two_players_from_wait_queue(&self, count: usize) -> Vec<i32> {
         println!("BattleWaitQueueRepositoryImpl: dequeue_two_players_from_wait_queue()");
         let battle_wait_queue_guard = self.battle_wait_queue.lock().await;
@@ -54,6 +78,7 @@ impl BattleWaitQueueRepository for BattleWaitQueueRepositoryImpl {
 
         let battle_wait_queue_guard = self.battle_wait_queue.lock().await;
         let result= battle_wait_queue_guard.dequeue_player(account_unique_id).await;
+        self.battle_match_signal.notify_one();
 
         Ok(result)
     }
@@ -122,4 +147,15 @@ mod tests {
         drop(queue_after_dequeue);
         drop(wait_queue_gaurd);
     }
+
+    #[tokio::test]
+    async fn test_enqueue_wakes_battle_match_signal() {
+        let repository = BattleWaitQueueRepositoryImpl::new();
+        let battle_match_signal = repository.get_battle_match_signal();
+
+        repository.enqueue_player_id_with_rating_for_wait(1, 1000).await.expect("Can't enqueue");
+
+        let woken = timeout(std::time::Duration::from_millis(100), battle_match_signal.notified()).await;
+        assert!(woken.is_ok());
+    }
 }
@@ -2,6 +2,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
+use crate::account_rating::entity::elo_rating_calculator::DEFAULT_RATING;
+use crate::account_rating::repository::account_rating_repository::AccountRatingRepository;
+use crate::account_rating::repository::account_rating_repository_impl::AccountRatingRepositoryImpl;
 use crate::battle_ready_account_hash::entity::battle_ready_account_hash_status::BattleReadyAccountHashStatus;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository::BattleReadyAccountHashRepository;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository_impl::BattleReadyAccountHashRepositoryImpl;
@@ -23,6 +26,7 @@ pub struct BattleWaitQueueServiceImpl {
     battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
     battle_wait_queue_repository: Arc<AsyncMutex<BattleWaitQueueRepositoryImpl>>,
     match_waiting_timer_repository: Arc<AsyncMutex<MatchWaitingTimerRepositoryImpl>>,
+    account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
 }
 
 impl BattleWaitQueueServiceImpl {
@@ -30,13 +34,15 @@ impl BattleWaitQueueServiceImpl {
                battle_ready_account_hash_repository: Arc<AsyncMutex<BattleReadyAccountHashRepositoryImpl>>,
                battle_wait_queue_repository: Arc<AsyncMutex<BattleWaitQueueRepositoryImpl>>,
                match_waiting_timer_repository: Arc<AsyncMutex<MatchWaitingTimerRepositoryImpl>>,
+               account_rating_repository: Arc<AsyncMutex<AccountRatingRepositoryImpl>>,
             ) -> Self {
 
         BattleWaitQueueServiceImpl {
             redis_in_memory_repository,
             battle_ready_account_hash_repository,
             battle_wait_queue_repository,
-            match_waiting_timer_repository
+            match_waiting_timer_repository,
+            account_rating_repository
         }
     }
 
@@ -49,7 +55,8 @@ impl BattleWaitQueueServiceImpl {
                             RedisInMemoryRepositoryImpl::get_instance(),
                             BattleReadyAccountHashRepositoryImpl::get_instance(),
                             BattleWaitQueueRepositoryImpl::get_instance(),
-                            MatchWaitingTimerRepositoryImpl::get_instance())));
+                            MatchWaitingTimerRepositoryImpl::get_instance(),
+                            AccountRatingRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -70,6 +77,12 @@ impl BattleWaitQueueService for BattleWaitQueueServiceImpl {
 
         let account_unique_id = self.parse_account_unique_id(battle_wait_queue_request.get_session_id()).await;
 
+        let account_rating_repository = self.account_rating_repository.lock().await;
+        let rating = account_rating_repository.find_or_create_by_account_id(account_unique_id).await
+            .map(|account_rating| account_rating.rating())
+            .unwrap_or(DEFAULT_RATING);
+        drop(account_rating_repository);
+
         let battle_wait_queue_repository = self.battle_wait_queue_repository.lock().await;
 
         let mut match_waiting_timer_repository = self.match_waiting_timer_repository.lock().await;
@@ -78,7 +91,7 @@ impl BattleWaitQueueService for BattleWaitQueueServiceImpl {
         let mut battle_ready_account_hash_repository = self.battle_ready_account_hash_repository.lock().await;
         battle_ready_account_hash_repository.save_battle_ready_account_hash(account_unique_id, BattleReadyAccountHashStatus::WAIT).await;
 
-        let response = battle_wait_queue_repository.enqueue_player_id_for_wait(account_unique_id).await;
+        let response = battle_wait_queue_repository.enqueue_player_id_with_rating_for_wait(account_unique_id, rating).await;
 
         if response.is_ok() {
             return BattleWaitQueueResponse::new(true)
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+use crate::card_effect_definition::entity::energy_cost_definition::EnergyCostDefinition;
+use crate::game_card_active_skill::entity::active_skill_type::ActiveSkillType;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActiveSkillEffectDefinition {
+    required_energy: EnergyCostDefinition,
+    skill_type: ActiveSkillType,
+    skill_damage: i32,
+}
+
+impl ActiveSkillEffectDefinition {
+    pub fn get_required_energy(&self) -> &EnergyCostDefinition { &self.required_energy }
+    pub fn get_skill_type(&self) -> &ActiveSkillType { &self.skill_type }
+    pub fn get_skill_damage(&self) -> i32 { self.skill_damage }
+}
@@ -0,0 +1,20 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum CardEffectDefinitionError {
+    ReadFailed(String),
+    ParseFailed(String),
+    InvalidDefinition(String),
+}
+
+impl fmt::Display for CardEffectDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardEffectDefinitionError::ReadFailed(message) => write!(f, "카드 효과 정의 파일을 읽을 수 없습니다: {}", message),
+            CardEffectDefinitionError::ParseFailed(message) => write!(f, "카드 효과 정의 파싱 실패: {}", message),
+            CardEffectDefinitionError::InvalidDefinition(message) => write!(f, "잘못된 카드 효과 정의: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CardEffectDefinitionError {}
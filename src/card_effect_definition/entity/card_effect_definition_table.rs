@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+
+use crate::card_effect_definition::entity::active_skill_effect_definition::ActiveSkillEffectDefinition;
+use crate::card_effect_definition::entity::card_effect_definition_error::CardEffectDefinitionError;
+use crate::card_effect_definition::entity::energy_cost_definition::EnergyCostDefinition;
+use crate::card_effect_definition::entity::energy_effect_definition::EnergyEffectDefinition;
+use crate::card_effect_definition::entity::environment_effect_definition::EnvironmentEffectDefinition;
+use crate::card_effect_definition::entity::item_effect_definition::ItemEffectDefinition;
+use crate::card_effect_definition::entity::passive_skill_effect_definition::PassiveSkillEffectDefinition;
+use crate::card_effect_definition::entity::support_effect_definition::SupportEffectDefinition;
+use crate::card_effect_definition::entity::tool_effect_definition::ToolEffectDefinition;
+use crate::card_effect_definition::entity::token_unit_definition::TokenUnitDefinition;
+use crate::card_effect_definition::entity::trap_effect_definition::TrapEffectDefinition;
+use crate::game_card_passive_skill::entity::passive_skill_type::PassiveSkillType;
+
+const MIN_SKILL_SLOT: i32 = 1;
+const MAX_SKILL_SLOT: i32 = 3;
+
+// 카드 번호 -> 효과 정의, 스킬은 카드 번호 -> 슬롯 번호 -> 효과 정의
+// 알 수 없는 키나 값은 파싱 단계에서 실패시켜 서버 기동 시점에 바로 드러나도록 함
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct CardEffectDefinitionTable {
+    item: HashMap<i32, ItemEffectDefinition>,
+    support: HashMap<i32, SupportEffectDefinition>,
+    tool: HashMap<i32, ToolEffectDefinition>,
+    energy: HashMap<i32, EnergyEffectDefinition>,
+    active_skill: HashMap<i32, HashMap<i32, ActiveSkillEffectDefinition>>,
+    passive_skill: HashMap<i32, HashMap<i32, PassiveSkillEffectDefinition>>,
+    trap: HashMap<i32, TrapEffectDefinition>,
+    environment: HashMap<i32, EnvironmentEffectDefinition>,
+    token: HashMap<i32, TokenUnitDefinition>,
+}
+
+impl CardEffectDefinitionTable {
+    pub fn from_json_str(json: &str) -> Result<CardEffectDefinitionTable, CardEffectDefinitionError> {
+        let card_effect_definition_table: CardEffectDefinitionTable = serde_json::from_str(json)
+            .map_err(|e| CardEffectDefinitionError::ParseFailed(e.to_string()))?;
+
+        card_effect_definition_table.validate()?;
+        Ok(card_effect_definition_table)
+    }
+
+    fn validate(&self) -> Result<(), CardEffectDefinitionError> {
+        let card_number_list = self.item.keys()
+            .chain(self.support.keys())
+            .chain(self.tool.keys())
+            .chain(self.energy.keys())
+            .chain(self.active_skill.keys())
+            .chain(self.passive_skill.keys())
+            .chain(self.trap.keys())
+            .chain(self.environment.keys())
+            .chain(self.token.keys());
+
+        for card_number in card_number_list {
+            if *card_number <= 0 {
+                return Err(CardEffectDefinitionError::InvalidDefinition(
+                    format!("card number must be positive: {}", card_number)))
+            }
+        }
+
+        for (card_number, item_effect_definition) in &self.item {
+            Self::validate_energy_cost("item", *card_number, item_effect_definition.get_required_energy())?;
+        }
+        for (card_number, support_effect_definition) in &self.support {
+            Self::validate_energy_cost("support", *card_number, support_effect_definition.get_energy_from_deck())?;
+        }
+        for (card_number, tool_effect_definition) in &self.tool {
+            Self::validate_energy_cost("tool", *card_number, Some(tool_effect_definition.get_required_energy()))?;
+        }
+        for (card_number, skill_slot_map) in &self.active_skill {
+            Self::validate_skill_slot("active_skill", *card_number, skill_slot_map.keys())?;
+            for active_skill_effect_definition in skill_slot_map.values() {
+                Self::validate_energy_cost("active_skill", *card_number, Some(active_skill_effect_definition.get_required_energy()))?;
+            }
+        }
+        for (card_number, skill_slot_map) in &self.passive_skill {
+            Self::validate_skill_slot("passive_skill", *card_number, skill_slot_map.keys())?;
+            for passive_skill_effect_definition in skill_slot_map.values() {
+                self.validate_summon_token(*card_number, passive_skill_effect_definition)?;
+            }
+        }
+        for (card_number, trap_effect_definition) in &self.trap {
+            Self::validate_trap_damage(*card_number, trap_effect_definition.get_damage_to_triggering_unit())?;
+            Self::validate_trap_damage(*card_number, trap_effect_definition.get_damage_to_opponent_main_character())?;
+        }
+        for (card_number, environment_effect_definition) in &self.environment {
+            Self::validate_environment(*card_number, environment_effect_definition)?;
+        }
+        for (card_number, token_unit_definition) in &self.token {
+            Self::validate_token_unit(*card_number, token_unit_definition)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_energy_cost(kind: &str, card_number: i32, energy_cost: Option<&EnergyCostDefinition>) -> Result<(), CardEffectDefinitionError> {
+        match energy_cost {
+            Some(energy_cost) if energy_cost.get_count() < 0 => Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("{} {}: energy count must not be negative", kind, card_number))),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_trap_damage(card_number: i32, damage: Option<i32>) -> Result<(), CardEffectDefinitionError> {
+        match damage {
+            Some(damage) if damage < 0 => Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("trap {}: damage must not be negative", card_number))),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_environment(card_number: i32, environment_effect_definition: &EnvironmentEffectDefinition) -> Result<(), CardEffectDefinitionError> {
+        if environment_effect_definition.get_duration_turn() <= 0 {
+            return Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("environment {}: duration_turn must be positive", card_number)))
+        }
+
+        let modifier_list = [
+            environment_effect_definition.get_unit_attack_bonus(),
+            environment_effect_definition.get_extra_field_energy_per_turn(),
+            environment_effect_definition.get_extra_draw_per_turn(),
+            environment_effect_definition.get_status_effect_damage_bonus(),
+        ];
+
+        if modifier_list.iter().flatten().any(|modifier| *modifier < 0) {
+            return Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("environment {}: modifier must not be negative", card_number)))
+        }
+
+        Ok(())
+    }
+
+    fn validate_token_unit(card_number: i32, token_unit_definition: &TokenUnitDefinition) -> Result<(), CardEffectDefinitionError> {
+        if token_unit_definition.get_health_point() <= 0 {
+            return Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("token {}: health_point must be positive", card_number)))
+        }
+        if token_unit_definition.get_attack_point() < 0 || token_unit_definition.get_attack_required_energy() < 0 {
+            return Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("token {}: attack_point and attack_required_energy must not be negative", card_number)))
+        }
+        Ok(())
+    }
+
+    // 소환형 패시브는 token 항목에 정의된 토큰만 소환할 수 있음
+    fn validate_summon_token(&self, card_number: i32, passive_skill_effect_definition: &PassiveSkillEffectDefinition) -> Result<(), CardEffectDefinitionError> {
+        let is_summon_token_skill = passive_skill_effect_definition.get_skill_type() == &PassiveSkillType::SummonToken;
+
+        match passive_skill_effect_definition.get_summon_token_card_id() {
+            Some(token_card_id) if is_summon_token_skill && self.token.contains_key(&token_card_id) => Ok(()),
+            None if !is_summon_token_skill => Ok(()),
+            _ => Err(CardEffectDefinitionError::InvalidDefinition(
+                format!("passive_skill {}: summon_token_card_id must refer to a token only for SummonToken", card_number))),
+        }
+    }
+
+    fn validate_skill_slot<'a>(kind: &str, card_number: i32, skill_slot_list: impl Iterator<Item = &'a i32>) -> Result<(), CardEffectDefinitionError> {
+        for skill_slot in skill_slot_list {
+            if *skill_slot < MIN_SKILL_SLOT || *skill_slot > MAX_SKILL_SLOT {
+                return Err(CardEffectDefinitionError::InvalidDefinition(
+                    format!("{} {}: skill slot must be {}..={}, got {}", kind, card_number, MIN_SKILL_SLOT, MAX_SKILL_SLOT, skill_slot)))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_item_effect_definition_map(&self) -> &HashMap<i32, ItemEffectDefinition> { &self.item }
+    pub fn get_support_effect_definition_map(&self) -> &HashMap<i32, SupportEffectDefinition> { &self.support }
+    pub fn get_tool_effect_definition_map(&self) -> &HashMap<i32, ToolEffectDefinition> { &self.tool }
+    pub fn get_energy_effect_definition_map(&self) -> &HashMap<i32, EnergyEffectDefinition> { &self.energy }
+    pub fn get_active_skill_effect_definition_map(&self) -> &HashMap<i32, HashMap<i32, ActiveSkillEffectDefinition>> { &self.active_skill }
+    pub fn get_passive_skill_effect_definition_map(&self) -> &HashMap<i32, HashMap<i32, PassiveSkillEffectDefinition>> { &self.passive_skill }
+    pub fn get_trap_effect_definition_map(&self) -> &HashMap<i32, TrapEffectDefinition> { &self.trap }
+    pub fn get_environment_effect_definition_map(&self) -> &HashMap<i32, EnvironmentEffectDefinition> { &self.environment }
+    pub fn get_token_unit_definition_map(&self) -> &HashMap<i32, TokenUnitDefinition> { &self.token }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+    use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+    use crate::game_card_active_skill::entity::active_skill_type::ActiveSkillType;
+    use crate::game_field_unit::entity::extra_effect::ExtraEffect;
+    use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+
+    #[test]
+    fn test_from_json_str() {
+        let json = r#"{
+            "item": { "8": { "required_energy": { "race": "Undead", "count": 2 }, "alternatives_damage": 30, "apply_target_minimum_grade": "Legend" } },
+            "energy": { "151": { "race": "Undead", "status_effects": [ { "effect": "Freeze", "duration_turn": 1, "reuse_turn": 2 } ] } },
+            "active_skill": { "27": { "1": { "required_energy": { "race": "Undead", "count": 2 }, "skill_type": "SingleTarget", "skill_damage": 20 } } },
+            "trap": { "40": { "trigger_condition": "OnAttackDeclared", "damage_to_triggering_unit": 20 } },
+            "environment": { "45": { "duration_turn": 4, "target_race": "Undead", "unit_attack_bonus": 5, "extra_draw_per_turn": 1 } },
+            "passive_skill": { "46": { "1": { "skill_type": "SummonToken", "casting_conditions": ["OnDeath"], "skill_damage": 0, "summon_token_card_id": 47 } } },
+            "token": { "47": { "race": "Undead", "grade": "Common", "attack_point": 10, "health_point": 5 } }
+        }"#;
+
+        let table = CardEffectDefinitionTable::from_json_str(json).unwrap();
+
+        let item_effect_definition = table.get_item_effect_definition_map().get(&8).unwrap();
+        assert_eq!(item_effect_definition.get_required_energy(), Some(&EnergyCostDefinition::new(RaceEnum::Undead, 2)));
+        assert_eq!(item_effect_definition.get_alternatives_damage(), Some(30));
+        assert_eq!(item_effect_definition.get_apply_target_minimum_grade(), Some(GradeEnum::Legend));
+        assert_eq!(item_effect_definition.get_catastrophic_damage_for_field_unit(), None);
+
+        let status_effect_definition = &table.get_energy_effect_definition_map().get(&151).unwrap().get_status_effects()[0];
+        assert_eq!(status_effect_definition.get_effect(), &ExtraEffect::Freeze);
+        assert_eq!(status_effect_definition.get_effect_damage(), -1);
+
+        let active_skill_effect_definition = table.get_active_skill_effect_definition_map().get(&27).unwrap().get(&1).unwrap();
+        assert_eq!(active_skill_effect_definition.get_skill_type(), &ActiveSkillType::SingleTarget);
+        assert_eq!(active_skill_effect_definition.get_skill_damage(), 20);
+
+        let trap_effect_definition = table.get_trap_effect_definition_map().get(&40).unwrap();
+        assert_eq!(trap_effect_definition.get_trigger_condition(), TrapTriggerCondition::OnAttackDeclared);
+        assert_eq!(trap_effect_definition.get_damage_to_triggering_unit(), Some(20));
+        assert_eq!(trap_effect_definition.get_damage_to_opponent_main_character(), None);
+
+        let environment_effect_definition = table.get_environment_effect_definition_map().get(&45).unwrap();
+        assert_eq!(environment_effect_definition.get_duration_turn(), 4);
+        assert_eq!(environment_effect_definition.get_target_race(), Some(RaceEnum::Undead));
+        assert_eq!(environment_effect_definition.get_unit_attack_bonus(), Some(5));
+        assert_eq!(environment_effect_definition.get_extra_field_energy_per_turn(), None);
+
+        let token_unit_definition = table.get_token_unit_definition_map().get(&47).unwrap();
+        assert_eq!(token_unit_definition.get_race(), RaceEnum::Undead);
+        assert_eq!(token_unit_definition.get_grade(), GradeEnum::Common);
+        assert_eq!(token_unit_definition.get_health_point(), 5);
+        assert_eq!(token_unit_definition.get_attack_required_energy(), 0);
+
+        let passive_skill_effect_definition = table.get_passive_skill_effect_definition_map().get(&46).unwrap().get(&1).unwrap();
+        assert_eq!(passive_skill_effect_definition.get_skill_type(), &PassiveSkillType::SummonToken);
+        assert_eq!(passive_skill_effect_definition.get_summon_token_card_id(), Some(47));
+    }
+
+    #[test]
+    fn test_unknown_effect_key_fails() {
+        let json = r#"{ "item": { "8": { "alternatives_damag": 30 } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::ParseFailed(_))));
+
+        let json = r#"{ "spell": {} }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::ParseFailed(_))));
+
+        let json = r#"{ "tool": { "15": { "required_energy": { "race": "Dragon", "count": 2 } } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::ParseFailed(_))));
+    }
+
+    #[test]
+    fn test_invalid_definition_fails() {
+        let json = r#"{ "passive_skill": { "19": { "4": { "skill_type": "BroadArea", "casting_conditions": ["Deploy"], "skill_damage": 10 } } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "tool": { "15": { "required_energy": { "race": "Human", "count": -1 } } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "trap": { "40": { "trigger_condition": "OnItemUsed", "damage_to_opponent_main_character": -5 } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "environment": { "45": { "duration_turn": 0, "extra_draw_per_turn": 1 } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "token": { "47": { "race": "Undead", "grade": "Common", "attack_point": 10, "health_point": 0 } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "passive_skill": { "19": { "1": { "skill_type": "SummonToken", "casting_conditions": ["OnDeath"], "skill_damage": 0, "summon_token_card_id": 47 } } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+
+        let json = r#"{ "passive_skill": { "19": { "1": { "skill_type": "BroadArea", "casting_conditions": ["OnDeath"], "skill_damage": 10, "summon_token_card_id": 47 } } },
+                        "token": { "47": { "race": "Undead", "grade": "Common", "attack_point": 10, "health_point": 5 } } }"#;
+        assert!(matches!(CardEffectDefinitionTable::from_json_str(json), Err(CardEffectDefinitionError::InvalidDefinition(_))));
+    }
+}
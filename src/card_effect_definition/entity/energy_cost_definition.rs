@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnergyCostDefinition {
+    race: RaceEnum,
+    count: i32,
+}
+
+impl EnergyCostDefinition {
+    pub fn new(race: RaceEnum, count: i32) -> Self {
+        EnergyCostDefinition { race, count }
+    }
+
+    pub fn get_race(&self) -> RaceEnum {
+        self.race
+    }
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+}
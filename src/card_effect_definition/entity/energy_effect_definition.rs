@@ -0,0 +1,38 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusEffectDefinition {
+    effect: ExtraEffect,
+    #[serde(default = "not_applicable")]
+    duration_turn: i32,
+    #[serde(default = "not_applicable")]
+    effect_damage: i32,
+    #[serde(default = "not_applicable")]
+    reuse_turn: i32,
+}
+
+fn not_applicable() -> i32 { -1 }
+
+impl StatusEffectDefinition {
+    pub fn get_effect(&self) -> &ExtraEffect { &self.effect }
+    pub fn get_duration_turn(&self) -> i32 { self.duration_turn }
+    pub fn get_effect_damage(&self) -> i32 { self.effect_damage }
+    pub fn get_reuse_turn(&self) -> i32 { self.reuse_turn }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnergyEffectDefinition {
+    race: RaceEnum,
+    #[serde(default)]
+    status_effects: Vec<StatusEffectDefinition>,
+}
+
+impl EnergyEffectDefinition {
+    pub fn get_race(&self) -> RaceEnum { self.race }
+    pub fn get_status_effects(&self) -> &Vec<StatusEffectDefinition> { &self.status_effects }
+}
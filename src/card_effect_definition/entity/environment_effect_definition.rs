@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EnvironmentEffectDefinition {
+    duration_turn: i32,
+    #[serde(default)]
+    target_race: Option<RaceEnum>,
+    #[serde(default)]
+    unit_attack_bonus: Option<i32>,
+    #[serde(default)]
+    extra_field_energy_per_turn: Option<i32>,
+    #[serde(default)]
+    extra_draw_per_turn: Option<i32>,
+    #[serde(default)]
+    status_effect_damage_bonus: Option<i32>,
+}
+
+impl EnvironmentEffectDefinition {
+    pub fn get_duration_turn(&self) -> i32 { self.duration_turn }
+    pub fn get_target_race(&self) -> Option<RaceEnum> { self.target_race }
+    pub fn get_unit_attack_bonus(&self) -> Option<i32> { self.unit_attack_bonus }
+    pub fn get_extra_field_energy_per_turn(&self) -> Option<i32> { self.extra_field_energy_per_turn }
+    pub fn get_extra_draw_per_turn(&self) -> Option<i32> { self.extra_draw_per_turn }
+    pub fn get_status_effect_damage_bonus(&self) -> Option<i32> { self.status_effect_damage_bonus }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+use crate::card_effect_definition::entity::energy_cost_definition::EnergyCostDefinition;
+
+// 정의되지 않은 항목은 기존 핸들러와 동일하게 -1 (사용하지 않음) 으로 취급
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct ItemEffectDefinition {
+    required_energy: Option<EnergyCostDefinition>,
+    alternatives_damage: Option<i32>,
+    apply_target_minimum_grade: Option<GradeEnum>,
+    field_energy_addition_divider: Option<i32>,
+    catastrophic_damage_for_field_unit: Option<i32>,
+    catastrophic_damage_for_main_character: Option<i32>,
+    will_be_lost_deck_card_count: Option<i32>,
+    target_count_that_can_be_damaged: Option<i32>,
+    unit_list_that_can_be_sacrificed: Option<Vec<i32>>,
+    will_be_removed_energy_count: Option<i32>,
+    removal_amount_of_opponent_field_energy: Option<i32>,
+}
+
+impl ItemEffectDefinition {
+    pub fn get_required_energy(&self) -> Option<&EnergyCostDefinition> { self.required_energy.as_ref() }
+    pub fn get_alternatives_damage(&self) -> Option<i32> { self.alternatives_damage }
+    pub fn get_apply_target_minimum_grade(&self) -> Option<GradeEnum> { self.apply_target_minimum_grade }
+    pub fn get_field_energy_addition_divider(&self) -> Option<i32> { self.field_energy_addition_divider }
+    pub fn get_catastrophic_damage_for_field_unit(&self) -> Option<i32> { self.catastrophic_damage_for_field_unit }
+    pub fn get_catastrophic_damage_for_main_character(&self) -> Option<i32> { self.catastrophic_damage_for_main_character }
+    pub fn get_will_be_lost_deck_card_count(&self) -> Option<i32> { self.will_be_lost_deck_card_count }
+    pub fn get_target_count_that_can_be_damaged(&self) -> Option<i32> { self.target_count_that_can_be_damaged }
+    pub fn get_unit_list_that_can_be_sacrificed(&self) -> Option<&Vec<i32>> { self.unit_list_that_can_be_sacrificed.as_ref() }
+    pub fn get_will_be_removed_energy_count(&self) -> Option<i32> { self.will_be_removed_energy_count }
+    pub fn get_removal_amount_of_opponent_field_energy(&self) -> Option<i32> { self.removal_amount_of_opponent_field_energy }
+}
@@ -0,0 +1,13 @@
+pub mod card_effect_definition_error;
+pub mod card_effect_definition_table;
+pub mod energy_cost_definition;
+pub mod item_effect_definition;
+pub mod support_effect_definition;
+pub mod tool_effect_definition;
+pub mod energy_effect_definition;
+pub mod active_skill_effect_definition;
+pub mod passive_skill_effect_definition;
+pub mod trap_effect_definition;
+
+pub mod environment_effect_definition;
+pub mod token_unit_definition;
\ No newline at end of file
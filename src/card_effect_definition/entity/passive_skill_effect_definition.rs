@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use crate::game_card_passive_skill::entity::passive_skill_casting_condition::PassiveSkillCastingCondition;
+use crate::game_card_passive_skill::entity::passive_skill_type::PassiveSkillType;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PassiveSkillEffectDefinition {
+    skill_type: PassiveSkillType,
+    casting_conditions: Vec<PassiveSkillCastingCondition>,
+    skill_damage: i32,
+    #[serde(default)]
+    summon_token_card_id: Option<i32>,
+}
+
+impl PassiveSkillEffectDefinition {
+    pub fn get_skill_type(&self) -> &PassiveSkillType { &self.skill_type }
+    pub fn get_casting_conditions(&self) -> &Vec<PassiveSkillCastingCondition> { &self.casting_conditions }
+    pub fn get_skill_damage(&self) -> i32 { self.skill_damage }
+    pub fn get_summon_token_card_id(&self) -> Option<i32> { self.summon_token_card_id }
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+use crate::card_effect_definition::entity::energy_cost_definition::EnergyCostDefinition;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnitSearchDefinition {
+    grade_limit: GradeEnum,
+    count: i32,
+}
+
+impl UnitSearchDefinition {
+    pub fn get_grade_limit(&self) -> GradeEnum { self.grade_limit }
+    pub fn get_count(&self) -> i32 { self.count }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct SupportEffectDefinition {
+    energy_from_deck: Option<EnergyCostDefinition>,
+    need_to_find_card_id: Option<i32>,
+    need_to_draw_card_count: Option<i32>,
+    unit_from_deck: Option<UnitSearchDefinition>,
+}
+
+impl SupportEffectDefinition {
+    pub fn get_energy_from_deck(&self) -> Option<&EnergyCostDefinition> { self.energy_from_deck.as_ref() }
+    pub fn get_need_to_find_card_id(&self) -> Option<i32> { self.need_to_find_card_id }
+    pub fn get_need_to_draw_card_count(&self) -> Option<i32> { self.need_to_draw_card_count }
+    pub fn get_unit_from_deck(&self) -> Option<&UnitSearchDefinition> { self.unit_from_deck.as_ref() }
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenUnitDefinition {
+    race: RaceEnum,
+    grade: GradeEnum,
+    attack_point: i32,
+    health_point: i32,
+    #[serde(default)]
+    attack_required_energy: i32,
+}
+
+impl TokenUnitDefinition {
+    pub fn get_race(&self) -> RaceEnum { self.race }
+    pub fn get_grade(&self) -> GradeEnum { self.grade }
+    pub fn get_attack_point(&self) -> i32 { self.attack_point }
+    pub fn get_health_point(&self) -> i32 { self.health_point }
+    pub fn get_attack_required_energy(&self) -> i32 { self.attack_required_energy }
+}
\ No newline at end of file
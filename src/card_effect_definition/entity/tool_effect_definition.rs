@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+use crate::card_effect_definition::entity::energy_cost_definition::EnergyCostDefinition;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolEffectDefinition {
+    required_energy: EnergyCostDefinition,
+    #[serde(default)]
+    enhance_attack_point: Option<i32>,
+}
+
+impl ToolEffectDefinition {
+    pub fn get_required_energy(&self) -> &EnergyCostDefinition { &self.required_energy }
+    pub fn get_enhance_attack_point(&self) -> Option<i32> { self.enhance_attack_point }
+}
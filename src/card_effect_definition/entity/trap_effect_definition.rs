@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TrapEffectDefinition {
+    trigger_condition: TrapTriggerCondition,
+    #[serde(default)]
+    damage_to_triggering_unit: Option<i32>,
+    #[serde(default)]
+    damage_to_opponent_main_character: Option<i32>,
+}
+
+impl TrapEffectDefinition {
+    pub fn get_trigger_condition(&self) -> TrapTriggerCondition { self.trigger_condition }
+    pub fn get_damage_to_triggering_unit(&self) -> Option<i32> { self.damage_to_triggering_unit }
+    pub fn get_damage_to_opponent_main_character(&self) -> Option<i32> { self.damage_to_opponent_main_character }
+}
@@ -0,0 +1,5 @@
+use crate::card_effect_definition::entity::card_effect_definition_table::CardEffectDefinitionTable;
+
+pub trait CardEffectDefinitionRepository {
+    fn get_card_effect_definition_table(&self) -> &CardEffectDefinitionTable;
+}
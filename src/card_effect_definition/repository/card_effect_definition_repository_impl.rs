@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+use crate::card_effect_definition::entity::card_effect_definition_error::CardEffectDefinitionError;
+use crate::card_effect_definition::entity::card_effect_definition_table::CardEffectDefinitionTable;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::common::env::env_detector::EnvDetector;
+use crate::common::path::root_path::RootPath;
+
+const DEFAULT_CARD_EFFECT_DEFINITION_PATH: &str = "resources/card_effect/card_effect_definition.json";
+
+pub struct CardEffectDefinitionRepositoryImpl {
+    card_effect_definition_table: CardEffectDefinitionTable,
+}
+
+impl CardEffectDefinitionRepositoryImpl {
+    fn new() -> Self {
+        let definition_path = Self::get_definition_path();
+
+        match Self::load_from_path(&definition_path) {
+            Ok(card_effect_definition_repository) => card_effect_definition_repository,
+            Err(err) => {
+                eprintln!("{:?}: {}", definition_path, err);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn get_definition_path() -> PathBuf {
+        let definition_path = EnvDetector::get_card_effect_definition_path()
+            .unwrap_or(DEFAULT_CARD_EFFECT_DEFINITION_PATH.to_string());
+
+        let definition_path_buf = PathBuf::from(&definition_path);
+        if definition_path_buf.is_absolute() {
+            return definition_path_buf
+        }
+
+        RootPath::make_full_path(&definition_path).unwrap_or(definition_path_buf)
+    }
+
+    pub fn load_from_path(definition_path: &PathBuf) -> Result<Self, CardEffectDefinitionError> {
+        let json = fs::read_to_string(definition_path)
+            .map_err(|e| CardEffectDefinitionError::ReadFailed(e.to_string()))?;
+
+        let card_effect_definition_table = CardEffectDefinitionTable::from_json_str(&json)?;
+
+        Ok(CardEffectDefinitionRepositoryImpl { card_effect_definition_table })
+    }
+
+    // 기동 후에는 읽기만 하므로 Mutex 없이 공유 (동기 컨텍스트인 각 카드 핸들러 저장소 생성자에서 사용)
+    pub fn get_instance() -> Arc<CardEffectDefinitionRepositoryImpl> {
+        lazy_static! {
+            static ref INSTANCE: Arc<CardEffectDefinitionRepositoryImpl> =
+                Arc::new(CardEffectDefinitionRepositoryImpl::new());
+        }
+        INSTANCE.clone()
+    }
+}
+
+impl CardEffectDefinitionRepository for CardEffectDefinitionRepositoryImpl {
+    fn get_card_effect_definition_table(&self) -> &CardEffectDefinitionTable {
+        &self.card_effect_definition_table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_bundled_card_effect_definition() {
+        let definition_path = RootPath::make_full_path(DEFAULT_CARD_EFFECT_DEFINITION_PATH).unwrap();
+        let repository = CardEffectDefinitionRepositoryImpl::load_from_path(&definition_path);
+
+        assert!(repository.is_ok(), "{:?}", repository.err());
+        let repository = repository.unwrap();
+        assert!(repository.get_card_effect_definition_table().get_item_effect_definition_map().contains_key(&8));
+    }
+}
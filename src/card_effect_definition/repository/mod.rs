@@ -0,0 +1,2 @@
+pub mod card_effect_definition_repository;
+pub mod card_effect_definition_repository_impl;
@@ -0,0 +1,16 @@
+use crate::check_connecting::service::check_connecting_service::CheckConnectingService;
+use crate::check_connecting::service::check_connecting_service_impl::CheckConnectingServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::check_connecting_request_generator::create_check_connecting_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_check_connecting_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Check Connecting
+    protocol_registry.register(4441, "CHECK_CONNECTING", create_check_connecting_request, |request| async move {
+        let check_connecting_service_mutex = CheckConnectingServiceImpl::get_instance();
+        let check_connecting_service = check_connecting_service_mutex.lock().await;
+
+        let response = check_connecting_service.checked_response(request).await;
+        ResponseType::CHECK_CONNECTING(response)
+    });
+}
@@ -1,19 +1,25 @@
+use std::io::ErrorKind;
 use std::sync::Arc;
 use std::time::Duration;
 use async_trait::async_trait;
 use chrono::{Timelike, Utc};
 use lazy_static::lazy_static;
-use tokio::io::AsyncWriteExt;
 
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::time::timeout;
 use crate::client_socket_accept::entity::client_socket::ClientSocket;
 use crate::check_connecting::entity::check_connecting_message_form::CheckConnectingMessageForm;
+use crate::common::frame::length_prefixed_frame_codec::LengthPrefixedFrameCodec;
 
+use crate::response_generator::response_envelope::ResponseEnvelope;
 use crate::response_generator::response_type::ResponseType::SEND_MESSAGE_CHECK_CONNECTING;
 use crate::check_connecting::repository::check_connecting_repository::CheckConnectingRepository;
 use crate::connection_context::repository::connection_context_repository_impl::ConnectionContextRepositoryImpl;
+use crate::connection_liveness::repository::connection_liveness_repository::ConnectionLivenessRepository;
+use crate::connection_liveness::repository::connection_liveness_repository_impl::ConnectionLivenessRepositoryImpl;
 use crate::response_generator::response_type::ResponseType;
+use crate::server_config::server_config::ServerConfig;
+use crate::transmitter::repository::transmitter_repository_impl::find_negotiated_protocol;
 
 pub struct CheckConnectingRepositoryImpl{}
 
@@ -44,18 +50,20 @@ impl CheckConnectingRepository for CheckConnectingRepositoryImpl {
         receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    SEND_MESSAGE_CHECK_CONNECTING(
-                        send_message_for_checking_connect_response)))).await;
+                    ResponseEnvelope::notification(
+                        SEND_MESSAGE_CHECK_CONNECTING(
+                            send_message_for_checking_connect_response))))).await;
 
         println!("Run_time_out()");
         // receiver_transmitter 생성
         let stream_arc = client_socket.stream();
         let receiver_transmitter_channel_arc = client_socket.each_client_receiver_transmitter_channel();
         let receiver_transmitter_channel_clone = receiver_transmitter_channel_arc.clone();
+        let client_address = address.to_string();
 
         tokio::task::spawn(async move {
             println!("Transmitter transmit() loop");
-
+            let frame_codec = LengthPrefixedFrameCodec::from_env();
 
             // 응답을 여부를 확인하며, 정해진 시간 안에(180 초) 응답이 없으면 response 는 Err 값을 갖고 반환
             if let receiver_transmitter_channel = receiver_transmitter_channel_clone {
@@ -73,14 +81,21 @@ impl CheckConnectingRepository for CheckConnectingRepositoryImpl {
                                 println!("Transmitter lock socket");
 
                                 let response_data = response.lock().await;
-                                let json_data = serde_json::to_string(&*response_data).expect("Failed to serialize to JSON");
+                                let negotiated_protocol = find_negotiated_protocol(&client_address).await;
+                                let json_data = response_data.to_versioned_json(&negotiated_protocol).to_string();
 
                                 println!("Transmitting data: {}", json_data);
                                 println!("Transmitted time: {}.{} sec", Utc::now().second(), Utc::now().timestamp_subsec_millis());
 
-                                client_socket_stream.write_all(json_data.as_bytes()).await.expect("Failed to write to client");
+                                match client_socket_stream.write_frame(&frame_codec, json_data.as_bytes()).await {
+                                    Ok(()) => {}
+                                    Err(err) if err.kind() == ErrorKind::InvalidInput => {
+                                        eprintln!("Failed to encode frame: {:?}", err);
+                                    }
+                                    Err(err) => panic!("Failed to write to client: {:?}", err),
+                                }
 
-                                if let ResponseType::CHECK_CONNECTING(check_connecting_response) = &*response_data {
+                                if let ResponseType::CHECK_CONNECTING(check_connecting_response) = response_data.get_response() {
                                     if check_connecting_response.get_is_success() == true {
                                         println!("checked_your_connecting");
 
@@ -94,9 +109,12 @@ impl CheckConnectingRepository for CheckConnectingRepositoryImpl {
                             }
                         }
                         Err(_) => {
-                            // TODO: 타임아웃이 발생한 경우 처리 (예를 들어 메모리를 사용 중인 객체 제거)
-                            let client_socket_stream = stream_arc.lock().await;
-                            drop(client_socket_stream);
+                            // 응답이 없는 연결은 닫힌 것으로 기록하고, 게임 중이라면 유예 시간 이후 기권 처리됨
+                            let connection_liveness_repository_mutex = ConnectionLivenessRepositoryImpl::get_instance();
+                            let mut connection_liveness_repository_guard = connection_liveness_repository_mutex.lock().await;
+                            connection_liveness_repository_guard.mark_connection_closed(
+                                &client_address, ServerConfig::get_instance().get_connection_grace_period()).await;
+                            drop(connection_liveness_repository_guard);
 
                             println!("Transmitter receive channel data timed out");
                             break;
@@ -0,0 +1,16 @@
+use crate::client_program::service::client_program_service::ClientProgramService;
+use crate::client_program::service::client_program_service_impl::ClientProgramServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::client_program_request_generator::create_client_program_exit_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_client_program_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Program Exit
+    protocol_registry.register(4444, "PROGRAM_EXIT", create_client_program_exit_request, |request| async move {
+        let client_program_service_mutex = ClientProgramServiceImpl::get_instance();
+        let client_program_service = client_program_service_mutex.lock().await;
+
+        let response = client_program_service.client_exit_program(request).await;
+        ResponseType::PROGRAM_EXIT(response)
+    });
+}
@@ -5,6 +5,7 @@ use crate::domain_initializer::initializer::{AcceptorReceiverChannel, AcceptorTr
 #[async_trait]
 pub trait ClientSocketAcceptController: Send + Sync {
     async fn accept_client(&self);
+    async fn accept_web_socket_client(&self);
     async fn inject_acceptor_receiver_channel(&self, acceptor_receiver_channel_arc: Arc<AcceptorReceiverChannel>);
     async fn inject_acceptor_transmitter_channel(&mut self, acceptor_transmitter_channel_arc: Arc<AcceptorTransmitterChannel>);
 }
\ No newline at end of file
@@ -34,8 +34,14 @@ impl ClientSocketAcceptControllerImpl {
 impl ClientSocketAcceptController for ClientSocketAcceptControllerImpl {
     async fn accept_client(&self) {
         println!("Client Socket Accept Controller: accept()");
-        let client_socket_accept_guard = self.service.lock().await;
-        client_socket_accept_guard.accept_client().await;
+        let client_socket_accept_service = self.service.lock().await.clone();
+        client_socket_accept_service.accept_client().await;
+    }
+
+    async fn accept_web_socket_client(&self) {
+        println!("Client Socket Accept Controller: accept_web_socket_client()");
+        let client_socket_accept_service = self.service.lock().await.clone();
+        client_socket_accept_service.accept_web_socket_client().await;
     }
 
     async fn inject_acceptor_receiver_channel(&self, acceptor_receiver_channel_arc: Arc<AcceptorReceiverChannel>) {
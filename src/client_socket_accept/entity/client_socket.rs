@@ -1,18 +1,18 @@
 use std::sync::Arc;
-use tokio::net::TcpStream;
 use tokio::sync::Mutex;
+use crate::client_socket_accept::entity::client_stream::ClientStream;
 use crate::client_socket_accept::repository::client_socket_accept_repository_impl::ReceiverTransmitterChannel;
 
 #[derive(Clone, Debug)]
 pub struct ClientSocket {
     address: String,
-    stream: Arc<Mutex<TcpStream>>,
+    stream: Arc<Mutex<ClientStream>>,
     each_client_receiver_transmitter_channel: Arc<ReceiverTransmitterChannel>,
 }
 
 impl ClientSocket {
     pub fn new(address: String,
-               stream: TcpStream,
+               stream: ClientStream,
                each_client_receiver_transmitter_channel: Arc<ReceiverTransmitterChannel>) -> Self {
 
         ClientSocket {
@@ -26,7 +26,7 @@ impl ClientSocket {
         &self.address
     }
 
-    pub fn stream(&self) -> Arc<Mutex<TcpStream>> {
+    pub fn stream(&self) -> Arc<Mutex<ClientStream>> {
         self.stream.clone()
     }
 
@@ -0,0 +1,122 @@
+use std::io;
+use std::net::SocketAddr;
+use futures::{SinkExt, StreamExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::common::frame::length_prefixed_frame_codec::LengthPrefixedFrameCodec;
+
+// Receiver / Transmitter 는 전송 계층과 무관하게 JSON frame 단위로만 주고 받음
+#[derive(Debug)]
+pub enum ClientStream {
+    Tcp(TcpStream),
+    WebSocket(WebSocketStream<TcpStream>),
+}
+
+impl ClientStream {
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        match self {
+            ClientStream::Tcp(stream) => stream.peer_addr(),
+            ClientStream::WebSocket(stream) => stream.get_ref().peer_addr(),
+        }
+    }
+
+    // 읽은 데이터를 frame codec 에 적재하고 읽은 바이트 수를 반환 (0 이면 연결 종료)
+    pub async fn read_into_frame_codec(&mut self,
+                                       buffer: &mut Vec<u8>,
+                                       frame_codec: &mut LengthPrefixedFrameCodec) -> io::Result<usize> {
+        match self {
+            ClientStream::Tcp(stream) => {
+                let bytes_read = stream.read(buffer).await?;
+                frame_codec.feed(&buffer[..bytes_read]);
+                Ok(bytes_read)
+            }
+            ClientStream::WebSocket(stream) => {
+                loop {
+                    let message = match stream.next().await {
+                        Some(Ok(message)) => message,
+                        Some(Err(err)) => return Err(io::Error::new(io::ErrorKind::Other, err)),
+                        None => return Ok(0),
+                    };
+
+                    // WebSocket 은 메시지 경계가 보장되므로 메시지 하나를 frame 하나로 취급
+                    match message {
+                        Message::Text(text) => {
+                            frame_codec.feed_whole_frame(text.as_bytes());
+                            return Ok(text.len())
+                        }
+                        Message::Binary(data) => {
+                            frame_codec.feed_whole_frame(&data);
+                            return Ok(data.len())
+                        }
+                        Message::Close(_) => return Ok(0),
+                        // Ping 에 대한 Pong 은 tungstenite 가 처리
+                        Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+                    }
+                }
+            }
+        }
+    }
+
+    pub async fn write_frame(&mut self, frame_codec: &LengthPrefixedFrameCodec, payload: &[u8]) -> io::Result<()> {
+        match self {
+            ClientStream::Tcp(stream) => {
+                let frame = frame_codec.encode(payload)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, format!("{:?}", err)))?;
+                stream.write_all(&frame).await
+            }
+            ClientStream::WebSocket(stream) => {
+                let text = String::from_utf8(payload.to_vec())
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+                stream.send(Message::Text(text.into())).await
+                    .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+            }
+        }
+    }
+
+    // 서버 쪽에서 먼저 연결을 끊어야 할 때 사용
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        match self {
+            ClientStream::Tcp(stream) => stream.shutdown().await,
+            ClientStream::WebSocket(stream) => {
+                stream.close(None).await
+                    .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{accept_async, client_async};
+
+    #[tokio::test]
+    async fn test_web_socket_stream_round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut client_stream = ClientStream::WebSocket(accept_async(stream).await.unwrap());
+            let mut frame_codec = LengthPrefixedFrameCodec::new(1024);
+            let mut buffer = vec![0; 1024];
+
+            client_stream.read_into_frame_codec(&mut buffer, &mut frame_codec).await.unwrap();
+            let frame = frame_codec.next_frame().unwrap().unwrap();
+            client_stream.write_frame(&frame_codec, &frame).await.unwrap();
+        });
+
+        let tcp_stream = TcpStream::connect(address).await.unwrap();
+        let (mut web_socket, _) = client_async(format!("ws://{}", address), tcp_stream).await.unwrap();
+        web_socket.send(Message::Text("{\"protocolNumber\":1}".into())).await.unwrap();
+
+        let echoed = web_socket.next().await.unwrap().unwrap();
+        assert_eq!(echoed.into_text().unwrap().as_str(), "{\"protocolNumber\":1}");
+
+        server.await.unwrap();
+    }
+}
@@ -1 +1,2 @@
-pub mod client_socket;
\ No newline at end of file
+pub mod client_socket;
+pub mod client_stream;
@@ -7,6 +7,7 @@ use crate::domain_initializer::initializer::{AcceptorReceiverChannel, AcceptorTr
 #[async_trait]
 pub trait ClientSocketAcceptRepository {
     async fn accept_client(&mut self, listener: &TcpListener);
+    async fn accept_web_socket_client(&mut self, listener: &TcpListener);
     async fn inject_acceptor_receiver_channel(&mut self, acceptor_receiver_channel_arc: Arc<AcceptorReceiverChannel>);
     async fn inject_acceptor_transmitter_channel(&mut self, acceptor_transmitter_channel_arc: Arc<AcceptorTransmitterChannel>);
 }
\ No newline at end of file
@@ -6,13 +6,29 @@ use tokio::sync::{Mutex as AsyncMutex, Mutex};
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use crate::client_socket_accept::entity::client_socket::ClientSocket;
+use crate::client_socket_accept::entity::client_stream::ClientStream;
 use crate::client_socket_accept::repository::client_socket_accept_repository::ClientSocketAcceptRepository;
 use crate::define_channel;
 use crate::domain_initializer::initializer::{AcceptorReceiverChannel, AcceptorTransmitterChannel};
-use crate::response_generator::response_type::ResponseType;
+use crate::response_generator::response_envelope::ResponseEnvelope;
 
 use tokio::sync::mpsc;
-define_channel!(ReceiverTransmitterChannel, Arc<Mutex<ResponseType>>);
+use tokio::time::timeout;
+use tokio_tungstenite::accept_async_with_config;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use crate::common::frame::length_prefixed_frame_codec::LengthPrefixedFrameCodec;
+define_channel!(ReceiverTransmitterChannel, Arc<Mutex<ResponseEnvelope>>);
+
+const WEB_SOCKET_HANDSHAKE_TIMEOUT_SECONDS: u64 = 5;
+
+// TCP frame 과 같은 상한을 적용해야 codec 에 넘기기 전에 tungstenite 가 큰 메시지를 버퍼링하지 않음
+fn create_web_socket_config() -> WebSocketConfig {
+    let max_frame_size = LengthPrefixedFrameCodec::max_frame_size_from_env();
+
+    WebSocketConfig::default()
+        .max_message_size(Some(max_frame_size))
+        .max_frame_size(Some(max_frame_size))
+}
 
 #[derive(Clone)]
 pub struct ClientSocketAcceptRepositoryImpl {
@@ -41,6 +57,41 @@ impl ClientSocketAcceptRepositoryImpl {
     pub fn get_client_list(&self) -> &Arc<AsyncMutex<HashMap<String, ClientSocket>>> {
         &self.client_list
     }
+
+    // 전송 계층(TCP / WebSocket)과 무관하게 동일한 Receiver / Transmitter 경로로 등록
+    async fn register_client(&self, address: String, stream: ClientStream) {
+        let each_client_receiver_transmitter_channel = ReceiverTransmitterChannel::new(1);
+        let each_client_receiver_transmitter_channel_arc = Arc::new(each_client_receiver_transmitter_channel.clone());
+
+        let client = ClientSocket::new(address, stream, each_client_receiver_transmitter_channel_arc);
+        let mut client_list_gaurd = self.client_list.lock().await;
+
+        client_list_gaurd.insert(client.address().to_string(), client.clone());
+        println!("Client List: {:?}", *client_list_gaurd);
+        drop(client_list_gaurd);
+
+        if let Some(acceptor_receiver_channel) = &self.acceptor_receiver_channel_arc {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            // let stream = client.stream();
+            // let _ = acceptor_receiver_channel.send(stream).await;
+            let _ = acceptor_receiver_channel.send(client.clone()).await;
+            println!("send socket info to receiver with ipc channel");
+        } else {
+            eprintln!("Acceptor Receiver channel is not initialized");
+        }
+
+        if let Some(acceptor_transmitter_channel) = &self.acceptor_transmitter_channel_arc {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+
+            // let stream = client.stream();
+            // let _ = acceptor_transmitter_channel.send(stream).await;
+            let _ = acceptor_transmitter_channel.send(client.clone()).await;
+            println!("send socket info to transmitter with ipc channel");
+        } else {
+            eprintln!("Acceptor Transmitter channel is not initialized");
+        }
+    }
 }
 
 #[async_trait]
@@ -53,36 +104,7 @@ impl ClientSocketAcceptRepository for ClientSocketAcceptRepositoryImpl {
                 Ok((stream, peer_addr)) => {
                     println!("Accepted client from: {}", peer_addr);
 
-                    let each_client_receiver_transmitter_channel = ReceiverTransmitterChannel::new(1);
-                    let each_client_receiver_transmitter_channel_arc = Arc::new(each_client_receiver_transmitter_channel.clone());
-
-                    let client = ClientSocket::new(peer_addr.to_string(), stream, each_client_receiver_transmitter_channel_arc);
-                    let mut client_list_gaurd = self.client_list.lock().await;
-
-                    client_list_gaurd.insert(client.address().to_string(), client.clone());
-                    println!("Client List: {:?}", *client_list_gaurd);
-
-                    if let Some(acceptor_receiver_channel) = &self.acceptor_receiver_channel_arc {
-                        tokio::time::sleep(Duration::from_millis(100)).await;
-
-                        // let stream = client.stream();
-                        // let _ = acceptor_receiver_channel.send(stream).await;
-                        let _ = acceptor_receiver_channel.send(client.clone()).await;
-                        println!("send socket info to receiver with ipc channel");
-                    } else {
-                        eprintln!("Acceptor Receiver channel is not initialized");
-                    }
-
-                    if let Some(acceptor_transmitter_channel) = &self.acceptor_transmitter_channel_arc {
-                        tokio::time::sleep(Duration::from_millis(100)).await;
-
-                        // let stream = client.stream();
-                        // let _ = acceptor_transmitter_channel.send(stream).await;
-                        let _ = acceptor_transmitter_channel.send(client.clone()).await;
-                        println!("send socket info to transmitter with ipc channel");
-                    } else {
-                        eprintln!("Acceptor Transmitter channel is not initialized");
-                    }
+                    self.register_client(peer_addr.to_string(), ClientStream::Tcp(stream)).await;
                 }
                 Err(err) => {
                     eprintln!("Error accepting client: {:?}", err);
@@ -93,6 +115,37 @@ impl ClientSocketAcceptRepository for ClientSocketAcceptRepositoryImpl {
         }
     }
 
+    async fn accept_web_socket_client(&mut self, listener: &TcpListener) {
+        println!("Client Socket Accept Repository: accept_web_socket_client()");
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer_addr)) => {
+                    println!("Accepted web socket client from: {}", peer_addr);
+
+                    // handshake 가 늦은 클라이언트가 다른 접속을 막지 않도록 별도 태스크에서 처리
+                    let repository = self.clone();
+                    tokio::spawn(async move {
+                        let web_socket_handshake = accept_async_with_config(stream, Some(create_web_socket_config()));
+                        match timeout(Duration::from_secs(WEB_SOCKET_HANDSHAKE_TIMEOUT_SECONDS), web_socket_handshake).await {
+                            Ok(Ok(web_socket_stream)) => {
+                                repository.register_client(peer_addr.to_string(), ClientStream::WebSocket(web_socket_stream)).await;
+                            }
+                            Ok(Err(err)) => {
+                                eprintln!("WebSocket handshake failed with {}: {:?}", peer_addr, err);
+                            }
+                            Err(_) => {
+                                eprintln!("WebSocket handshake timed out: {}", peer_addr);
+                            }
+                        }
+                    });
+                }
+                Err(err) => {
+                    eprintln!("Error accepting web socket client: {:?}", err);
+                }
+            }
+        }
+    }
+
     async fn inject_acceptor_receiver_channel(&mut self, acceptor_receiver_channel_arc: Arc<AcceptorReceiverChannel>) {
         self.acceptor_receiver_channel_arc = Option::from(acceptor_receiver_channel_arc);
     }
@@ -136,4 +189,13 @@ mod tests {
 
         assert!(listener.local_addr().is_ok());
     }
+
+    #[test]
+    fn test_web_socket_config_follows_max_frame_size() {
+        let web_socket_config = create_web_socket_config();
+        let max_frame_size = LengthPrefixedFrameCodec::max_frame_size_from_env();
+
+        assert_eq!(web_socket_config.max_message_size, Some(max_frame_size));
+        assert_eq!(web_socket_config.max_frame_size, Some(max_frame_size));
+    }
 }
@@ -46,17 +46,36 @@ impl ClientSocketAcceptService for ClientSocketAcceptServiceImpl {
 
         let server_socket_repository_guard = self.server_socket_repository.lock().await;
         println!("get server_socket_repository lock");
+        let listener_option = server_socket_repository_guard.get_listener().await;
+        drop(server_socket_repository_guard);
 
-        if let Some(listener) = server_socket_repository_guard.get_listener().await {
+        if let Some(listener) = listener_option {
+            // accept loop 가 끝나지 않으므로 lock 을 잡고 있지 않도록 복제본으로 수행 (client_list, channel 은 공유됨)
             println!("get client_socket_accept_repository lock");
-            let mut client_socket_accept_guard = self.client_socket_accept_repository.lock().await;
+            let mut client_socket_accept_repository = self.client_socket_accept_repository.lock().await.clone();
             let listener_guard = listener.lock().await;
-            client_socket_accept_guard.accept_client(&*listener_guard).await;
+            client_socket_accept_repository.accept_client(&*listener_guard).await;
         } else {
             eprintln!("Listener not available for accepting clients.");
         }
     }
 
+    async fn accept_web_socket_client(&self) {
+        println!("Client Socket Accept Service: accept_web_socket_client()");
+
+        let server_socket_repository_guard = self.server_socket_repository.lock().await;
+        let web_socket_listener_option = server_socket_repository_guard.get_web_socket_listener().await;
+        drop(server_socket_repository_guard);
+
+        if let Some(web_socket_listener) = web_socket_listener_option {
+            let mut client_socket_accept_repository = self.client_socket_accept_repository.lock().await.clone();
+            let web_socket_listener_guard = web_socket_listener.lock().await;
+            client_socket_accept_repository.accept_web_socket_client(&*web_socket_listener_guard).await;
+        } else {
+            eprintln!("WebSocket listener not available for accepting clients.");
+        }
+    }
+
     async fn inject_acceptor_receiver_channel(&self, acceptor_receiver_channel_arc: Arc<AcceptorReceiverChannel>) {
         let mut client_socket_accept_service_guard = self.client_socket_accept_repository.lock().await;
         client_socket_accept_service_guard.inject_acceptor_receiver_channel(acceptor_receiver_channel_arc).await;
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
 pub enum GradeEnum {
     Dummy = 0,
     Common = 1,
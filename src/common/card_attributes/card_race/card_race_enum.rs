@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Deserialize)]
 pub enum RaceEnum {
     Dummy = 0,
     Human = 1,
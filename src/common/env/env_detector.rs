@@ -17,9 +17,75 @@ impl EnvDetector {
         Self::get_var("TARGET_PORT")
     }
 
+    pub fn get_web_socket_port() -> Option<String> { Self::get_var("WEB_SOCKET_PORT") }
+
+    pub fn get_bind_host() -> Option<String> { Self::get_var("BIND_HOST") }
+
+    pub fn get_server_config_path() -> Option<String> { Self::get_var("SERVER_CONFIG_PATH") }
+
+    pub fn get_session_expired_seconds() -> Option<String> { Self::get_var("SESSION_EXPIRED_SECONDS") }
+
+    pub fn get_matchmaker_poll_interval_milliseconds() -> Option<String> { Self::get_var("MATCHMAKER_POLL_INTERVAL_MILLISECONDS") }
+
+    pub fn get_turn_time_limit_seconds() -> Option<String> { Self::get_var("TURN_TIME_LIMIT_SECONDS") }
+
+    pub fn get_mulligan_time_limit_seconds() -> Option<String> { Self::get_var("MULLIGAN_TIME_LIMIT_SECONDS") }
+
+    pub fn get_heartbeat_interval_seconds() -> Option<String> { Self::get_var("HEARTBEAT_INTERVAL_SECONDS") }
+
+    pub fn get_connection_grace_period_seconds() -> Option<String> { Self::get_var("CONNECTION_GRACE_PERIOD_SECONDS") }
+
+    pub fn get_shutdown_drain_timeout_seconds() -> Option<String> { Self::get_var("SHUTDOWN_DRAIN_TIMEOUT_SECONDS") }
+
+    pub fn get_rate_limit_auth_burst() -> Option<String> { Self::get_var("RATE_LIMIT_AUTH_BURST") }
+
+    pub fn get_rate_limit_auth_refill_per_minute() -> Option<String> { Self::get_var("RATE_LIMIT_AUTH_REFILL_PER_MINUTE") }
+
+    pub fn get_rate_limit_lobby_burst() -> Option<String> { Self::get_var("RATE_LIMIT_LOBBY_BURST") }
+
+    pub fn get_rate_limit_lobby_refill_per_minute() -> Option<String> { Self::get_var("RATE_LIMIT_LOBBY_REFILL_PER_MINUTE") }
+
+    pub fn get_rate_limit_shop_burst() -> Option<String> { Self::get_var("RATE_LIMIT_SHOP_BURST") }
+
+    pub fn get_rate_limit_shop_refill_per_minute() -> Option<String> { Self::get_var("RATE_LIMIT_SHOP_REFILL_PER_MINUTE") }
+
+    pub fn get_rate_limit_in_game_burst() -> Option<String> { Self::get_var("RATE_LIMIT_IN_GAME_BURST") }
+
+    pub fn get_rate_limit_in_game_refill_per_minute() -> Option<String> { Self::get_var("RATE_LIMIT_IN_GAME_REFILL_PER_MINUTE") }
+
+    pub fn get_rate_limit_default_burst() -> Option<String> { Self::get_var("RATE_LIMIT_DEFAULT_BURST") }
+
+    pub fn get_rate_limit_default_refill_per_minute() -> Option<String> { Self::get_var("RATE_LIMIT_DEFAULT_REFILL_PER_MINUTE") }
+
+    pub fn get_rate_limit_disconnect_violation_count() -> Option<String> { Self::get_var("RATE_LIMIT_DISCONNECT_VIOLATION_COUNT") }
+
+    pub fn get_rate_limit_violation_window_seconds() -> Option<String> { Self::get_var("RATE_LIMIT_VIOLATION_WINDOW_SECONDS") }
+
+    pub fn get_admin_token() -> Option<String> { Self::get_var("ADMIN_TOKEN") }
+
     pub fn get_mysql_url() -> Option<String> { Self::get_var("DATABASE_URL") }
 
+    pub fn get_storage_backend() -> Option<String> { Self::get_var("STORAGE_BACKEND") }
+
+    pub fn get_sqlite_database_url() -> Option<String> { Self::get_var("SQLITE_DATABASE_URL") }
+
+    pub fn get_storage_pool_max_size() -> Option<String> { Self::get_var("STORAGE_POOL_MAX_SIZE") }
+
+    pub fn get_storage_pool_min_idle() -> Option<String> { Self::get_var("STORAGE_POOL_MIN_IDLE") }
+
+    pub fn get_storage_pool_connection_timeout_seconds() -> Option<String> { Self::get_var("STORAGE_POOL_CONNECTION_TIMEOUT_SECONDS") }
+
+    pub fn get_storage_pool_idle_timeout_seconds() -> Option<String> { Self::get_var("STORAGE_POOL_IDLE_TIMEOUT_SECONDS") }
+
     pub fn get_redis_password() -> Option<String> { Self::get_var("REDIS_PASSWORD") }
+
+    pub fn get_redis_url() -> Option<String> { Self::get_var("REDIS_URL") }
+
+    pub fn get_session_store() -> Option<String> { Self::get_var("SESSION_STORE") }
+
+    pub fn get_card_effect_definition_path() -> Option<String> { Self::get_var("CARD_EFFECT_DEFINITION_PATH") }
+
+    pub fn get_max_frame_size() -> Option<String> { Self::get_var("MAX_FRAME_SIZE") }
 }
 
 
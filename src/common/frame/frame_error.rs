@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameError {
+    FrameTooLarge { frame_size: usize, max_frame_size: usize },
+}
@@ -0,0 +1,187 @@
+use crate::common::env::env_detector::EnvDetector;
+use crate::common::frame::frame_error::FrameError;
+
+pub const FRAME_HEADER_SIZE: usize = 4;
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 1024 * 1024;
+
+// 4 byte big-endian length 뒤에 payload 가 붙는 형태
+#[derive(Debug, Clone)]
+pub struct LengthPrefixedFrameCodec {
+    max_frame_size: usize,
+    read_buffer: Vec<u8>,
+    // 너무 큰 frame 의 payload 중 아직 도착하지 않아 앞으로 버려야 할 byte 수
+    discard_remaining: usize,
+}
+
+impl LengthPrefixedFrameCodec {
+    pub fn new(max_frame_size: usize) -> Self {
+        LengthPrefixedFrameCodec {
+            max_frame_size,
+            read_buffer: Vec::new(),
+            discard_remaining: 0,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(Self::max_frame_size_from_env())
+    }
+
+    pub fn max_frame_size_from_env() -> usize {
+        EnvDetector::get_max_frame_size()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    pub fn buffered_length(&self) -> usize {
+        self.read_buffer.len()
+    }
+
+    pub fn is_discarding(&self) -> bool {
+        self.discard_remaining > 0
+    }
+
+    pub fn encode(&self, payload: &[u8]) -> Result<Vec<u8>, FrameError> {
+        if payload.len() > self.max_frame_size {
+            return Err(FrameError::FrameTooLarge {
+                frame_size: payload.len(),
+                max_frame_size: self.max_frame_size,
+            })
+        }
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_SIZE + payload.len());
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(payload);
+
+        Ok(frame)
+    }
+
+    pub fn feed(&mut self, received_bytes: &[u8]) {
+        let discard_length = self.discard_remaining.min(received_bytes.len());
+        self.discard_remaining -= discard_length;
+
+        self.read_buffer.extend_from_slice(&received_bytes[discard_length..]);
+    }
+
+    // WebSocket 처럼 메시지 경계가 있는 전송 계층용, 크기 검사는 next_frame 에서 동일하게 수행
+    pub fn feed_whole_frame(&mut self, payload: &[u8]) {
+        self.read_buffer.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        self.read_buffer.extend_from_slice(payload);
+    }
+
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, FrameError> {
+        if self.read_buffer.len() < FRAME_HEADER_SIZE {
+            return Ok(None)
+        }
+
+        let mut header = [0u8; FRAME_HEADER_SIZE];
+        header.copy_from_slice(&self.read_buffer[..FRAME_HEADER_SIZE]);
+        let frame_size = u32::from_be_bytes(header) as usize;
+
+        if frame_size > self.max_frame_size {
+            // payload 를 정확히 frame_size 만큼 건너뛰어야 다음 header 부터 다시 읽을 수 있음
+            let buffered_payload_length = (self.read_buffer.len() - FRAME_HEADER_SIZE).min(frame_size);
+            self.read_buffer.drain(..FRAME_HEADER_SIZE + buffered_payload_length);
+            self.discard_remaining = frame_size - buffered_payload_length;
+
+            return Err(FrameError::FrameTooLarge {
+                frame_size,
+                max_frame_size: self.max_frame_size,
+            })
+        }
+
+        if self.read_buffer.len() < FRAME_HEADER_SIZE + frame_size {
+            return Ok(None)
+        }
+
+        let frame = self.read_buffer[FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + frame_size].to_vec();
+        self.read_buffer.drain(..FRAME_HEADER_SIZE + frame_size);
+
+        Ok(Some(frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode_single_frame() {
+        let mut codec = LengthPrefixedFrameCodec::new(64);
+        let encoded = codec.encode(br#"{"protocolNumber":1}"#).unwrap();
+
+        assert_eq!(&encoded[..FRAME_HEADER_SIZE], &(20u32).to_be_bytes());
+
+        codec.feed(&encoded);
+        assert_eq!(codec.next_frame().unwrap(), Some(br#"{"protocolNumber":1}"#.to_vec()));
+        assert_eq!(codec.next_frame().unwrap(), None);
+        assert_eq!(codec.buffered_length(), 0);
+    }
+
+    #[test]
+    fn test_reassemble_frame_split_across_reads() {
+        let mut codec = LengthPrefixedFrameCodec::new(1024);
+        let payload = vec![b'a'; 300];
+        let encoded = codec.encode(&payload).unwrap();
+
+        codec.feed(&encoded[..2]);
+        assert_eq!(codec.next_frame().unwrap(), None);
+
+        codec.feed(&encoded[2..100]);
+        assert_eq!(codec.next_frame().unwrap(), None);
+
+        codec.feed(&encoded[100..]);
+        assert_eq!(codec.next_frame().unwrap(), Some(payload));
+    }
+
+    #[test]
+    fn test_split_merged_frames() {
+        let mut codec = LengthPrefixedFrameCodec::new(1024);
+        let mut merged = codec.encode(b"first").unwrap();
+        merged.extend(codec.encode(b"second").unwrap());
+        merged.extend(&codec.encode(b"third").unwrap()[..3]);
+
+        codec.feed(&merged);
+        assert_eq!(codec.next_frame().unwrap(), Some(b"first".to_vec()));
+        assert_eq!(codec.next_frame().unwrap(), Some(b"second".to_vec()));
+        assert_eq!(codec.next_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn test_reject_frame_over_max_size() {
+        let mut codec = LengthPrefixedFrameCodec::new(8);
+
+        assert_eq!(codec.encode(b"123456789"),
+                   Err(FrameError::FrameTooLarge { frame_size: 9, max_frame_size: 8 }));
+
+        codec.feed(&(100u32).to_be_bytes());
+        codec.feed(b"garbage");
+        assert_eq!(codec.next_frame(),
+                   Err(FrameError::FrameTooLarge { frame_size: 100, max_frame_size: 8 }));
+        assert_eq!(codec.buffered_length(), 0);
+        assert!(codec.is_discarding());
+    }
+
+    #[test]
+    fn test_resume_after_discarding_frame_over_max_size() {
+        let mut codec = LengthPrefixedFrameCodec::new(8);
+        let valid_frame = codec.encode(b"valid").unwrap();
+
+        let mut oversized_frame = (20u32).to_be_bytes().to_vec();
+        oversized_frame.extend(vec![b'x'; 20]);
+
+        // 너무 큰 frame 의 payload 가 여러 번에 나뉘어 도착하고 바로 뒤에 정상 frame 이 붙는 경우
+        codec.feed(&oversized_frame[..10]);
+        assert_eq!(codec.next_frame(),
+                   Err(FrameError::FrameTooLarge { frame_size: 20, max_frame_size: 8 }));
+        assert_eq!(codec.next_frame().unwrap(), None);
+
+        let mut rest = oversized_frame[10..].to_vec();
+        rest.extend(&valid_frame);
+        codec.feed(&rest[..7]);
+        codec.feed(&rest[7..]);
+
+        assert!(!codec.is_discarding());
+        assert_eq!(codec.next_frame().unwrap(), Some(b"valid".to_vec()));
+        assert_eq!(codec.buffered_length(), 0);
+    }
+}
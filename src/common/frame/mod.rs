@@ -0,0 +1,2 @@
+pub mod frame_error;
+pub mod length_prefixed_frame_codec;
@@ -13,6 +13,10 @@ pub enum FalseMessage {
     NotYourTurnFieldEnergy = 13,
     UnattackableUnit = 14,
     UnitFrozen = 15,
+    TrapZoneFull = 16,
+    AttackCanceledByTrap = 17,
+    UnitStunned = 18,
+    UnitSilenced = 19,
 
 
 }
@@ -31,8 +35,19 @@ impl From<i32> for FalseMessage {
             13 => FalseMessage::NotYourTurnFieldEnergy,
             14 => FalseMessage::UnattackableUnit,
             15 => FalseMessage::UnitFrozen,
+            16 => FalseMessage::TrapZoneFull,
+            17 => FalseMessage::AttackCanceledByTrap,
+            18 => FalseMessage::UnitStunned,
+            19 => FalseMessage::UnitSilenced,
 
             _ => panic!("Invalid enum value"),
         }
     }
-}
\ No newline at end of file
+}
+
+impl FalseMessage {
+    // 게임 규칙상 실패가 아니라 요청 자체를 받아들일 수 없는 검증 실패 (PROTOCOL_ERROR 로 응답)
+    pub fn is_validation_failure(&self) -> bool {
+        matches!(self, FalseMessage::NotYourTurn | FalseMessage::NotYourTurnFieldEnergy)
+    }
+}
@@ -1 +1,2 @@
-pub mod false_message_enum;
\ No newline at end of file
+pub mod false_message_enum;
+pub mod protocol_error_code_enum;
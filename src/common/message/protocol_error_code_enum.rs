@@ -0,0 +1,37 @@
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ProtocolErrorCode {
+    MalformedMessage = 1,
+    MissingProtocolNumber = 2,
+    UnknownProtocol = 3,
+
+    MissingField = 11,
+    WrongFieldType = 12,
+    InvalidFieldValue = 13,
+
+    ValidationFailed = 21,
+
+    BattleRoomActionAborted = 31,
+
+    RateLimited = 41,
+
+    HandshakeRequired = 51,
+}
+
+impl From<i32> for ProtocolErrorCode {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ProtocolErrorCode::MalformedMessage,
+            2 => ProtocolErrorCode::MissingProtocolNumber,
+            3 => ProtocolErrorCode::UnknownProtocol,
+            11 => ProtocolErrorCode::MissingField,
+            12 => ProtocolErrorCode::WrongFieldType,
+            13 => ProtocolErrorCode::InvalidFieldValue,
+            21 => ProtocolErrorCode::ValidationFailed,
+            31 => ProtocolErrorCode::BattleRoomActionAborted,
+            41 => ProtocolErrorCode::RateLimited,
+            51 => ProtocolErrorCode::HandshakeRequired,
+
+            _ => panic!("Invalid enum value"),
+        }
+    }
+}
@@ -5,4 +5,5 @@ pub mod csv;
 pub mod converter;
 pub mod path;
 pub mod card_attributes;
-pub mod message;
\ No newline at end of file
+pub mod message;
+pub mod frame;
\ No newline at end of file
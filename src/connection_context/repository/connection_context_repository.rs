@@ -14,4 +14,6 @@ pub trait ConnectionContextRepository {
         // each_receiver_transmitter_channel: &Arc<ReceiverTransmitterChannel>,
         client_socket: Arc<Mutex<ClientSocket>>,
     ) -> bool;
-}
\ No newline at end of file
+
+    async fn remove_connection_context(&mut self, account_unique_id: i32) -> bool;
+}
@@ -54,6 +54,13 @@ impl ConnectionContextRepository for ConnectionContextRepositoryImpl {
 
         true
     }
+
+    async fn remove_connection_context(&mut self, account_unique_id: i32) -> bool {
+        println!("ConnectionContextRepositoryImpl: remove_connection_context()");
+
+        let mut map_guard = self.connection_context_map.lock().await;
+        map_guard.remove(&account_unique_id).is_some()
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +72,7 @@ mod tests {
     use super::*;
     use tokio::test;
     use tokio::time::sleep;
+    use crate::client_socket_accept::entity::client_stream::ClientStream;
 
     async fn fake_server(listener: Arc<Mutex<TcpListener>>, repository: ConnectionContextRepositoryImpl) {
         while let Ok((socket, _)) = listener.lock().await.accept().await {
@@ -76,7 +84,7 @@ mod tests {
                 AsyncMutex::new(
                     ClientSocket::new(
                         "fake".parse().unwrap(),
-                        socket,
+                        ClientStream::Tcp(socket),
                         Arc::new(ReceiverTransmitterChannel::new(1)))));
             let mut repository_clone = repository.clone();
             repository_clone.add_connection_context(account_unique_id, client_socket, ).await;
@@ -103,7 +111,7 @@ mod tests {
             AsyncMutex::new(
                 ClientSocket::new(
                     "fake".parse().unwrap(),
-                    stream,
+                    ClientStream::Tcp(stream),
                     Arc::new(ReceiverTransmitterChannel::new(1)))));
 
         let result = repository_guard.add_connection_context(account_unique_id, client_socket.clone(), ).await;
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use crate::connection_liveness::entity::connection_liveness_status::ConnectionLivenessStatus;
+
+// 클라이언트 주소 별 마지막 수신 시각을 기록
+pub struct ConnectionLiveness {
+    last_seen_map: HashMap<String, Instant>,
+    last_ping_map: HashMap<String, Instant>,
+    closed_address_set: HashSet<String>,
+}
+
+impl ConnectionLiveness {
+    pub fn new() -> ConnectionLiveness {
+        ConnectionLiveness {
+            last_seen_map: HashMap::new(),
+            last_ping_map: HashMap::new(),
+            closed_address_set: HashSet::new(),
+        }
+    }
+
+    pub fn record_activity(&mut self, address: &str) {
+        self.last_seen_map.insert(address.to_string(), Instant::now());
+        self.last_ping_map.remove(address);
+        self.closed_address_set.remove(address);
+    }
+
+    pub fn mark_closed(&mut self, address: &str) {
+        self.last_seen_map.entry(address.to_string()).or_insert_with(Instant::now);
+        self.closed_address_set.insert(address.to_string());
+    }
+
+    pub fn mark_pinged(&mut self, address: &str) {
+        self.last_ping_map.insert(address.to_string(), Instant::now());
+    }
+
+    pub fn is_ping_due(&self, address: &str, heartbeat_interval: Duration) -> bool {
+        match self.last_ping_map.get(address) {
+            Some(last_ping_at) => last_ping_at.elapsed() >= heartbeat_interval,
+            None => true,
+        }
+    }
+
+    pub fn judge(&self, address: &str, heartbeat_interval: Duration, grace_period: Duration) -> Option<ConnectionLivenessStatus> {
+        let silence = self.last_seen_map.get(address)?.elapsed();
+
+        if silence >= grace_period {
+            return Some(ConnectionLivenessStatus::Abandoned)
+        }
+        if self.closed_address_set.contains(address) {
+            return Some(ConnectionLivenessStatus::Disconnected)
+        }
+        if silence >= heartbeat_interval {
+            return Some(ConnectionLivenessStatus::Silent)
+        }
+
+        Some(ConnectionLivenessStatus::Alive)
+    }
+
+    // 게임 중이 아니어서 아무도 정리하지 않는 닫힌 연결 기록을 비움
+    pub fn remove_closed_older_than(&mut self, duration: Duration) {
+        let expired_address_list: Vec<String> = self.closed_address_set.iter()
+            .filter(|address| self.last_seen_map.get(*address)
+                .map_or(true, |last_seen_at| last_seen_at.elapsed() >= duration))
+            .cloned()
+            .collect();
+
+        for address in expired_address_list {
+            self.remove(&address);
+        }
+    }
+
+    pub fn remove(&mut self, address: &str) -> bool {
+        self.last_ping_map.remove(address);
+        self.closed_address_set.remove(address);
+        self.last_seen_map.remove(address).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_judge_connection_liveness() {
+        let mut connection_liveness = ConnectionLiveness::new();
+        let heartbeat_interval = Duration::from_secs(15);
+        let grace_period = Duration::from_secs(60);
+
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", heartbeat_interval, grace_period), None);
+
+        connection_liveness.record_activity("127.0.0.1:1234");
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", heartbeat_interval, grace_period),
+                   Some(ConnectionLivenessStatus::Alive));
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", Duration::ZERO, grace_period),
+                   Some(ConnectionLivenessStatus::Silent));
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", Duration::ZERO, Duration::ZERO),
+                   Some(ConnectionLivenessStatus::Abandoned));
+
+        connection_liveness.mark_closed("127.0.0.1:1234");
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", Duration::ZERO, grace_period),
+                   Some(ConnectionLivenessStatus::Disconnected));
+
+        connection_liveness.record_activity("127.0.0.1:1234");
+        assert_eq!(connection_liveness.judge("127.0.0.1:1234", heartbeat_interval, grace_period),
+                   Some(ConnectionLivenessStatus::Alive));
+    }
+
+    #[test]
+    fn test_ping_and_remove() {
+        let mut connection_liveness = ConnectionLiveness::new();
+        let heartbeat_interval = Duration::from_secs(15);
+
+        connection_liveness.record_activity("127.0.0.1:1234");
+        assert!(connection_liveness.is_ping_due("127.0.0.1:1234", heartbeat_interval));
+
+        connection_liveness.mark_pinged("127.0.0.1:1234");
+        assert!(!connection_liveness.is_ping_due("127.0.0.1:1234", heartbeat_interval));
+
+        connection_liveness.record_activity("127.0.0.1:5678");
+        connection_liveness.mark_closed("127.0.0.1:1234");
+        connection_liveness.remove_closed_older_than(Duration::ZERO);
+
+        assert!(!connection_liveness.remove("127.0.0.1:1234"));
+        assert!(connection_liveness.remove("127.0.0.1:5678"));
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionLivenessStatus {
+    Alive,
+    // heartbeat 간격 동안 아무 요청이 없어 응답 확인이 필요한 상태
+    Silent,
+    // 소켓이 닫혔지만 아직 유예 시간이 남아 있는 상태
+    Disconnected,
+    // 유예 시간 동안 응답이 없어 경기를 포기한 것으로 간주
+    Abandoned,
+}
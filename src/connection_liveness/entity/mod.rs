@@ -0,0 +1,2 @@
+pub mod connection_liveness;
+pub mod connection_liveness_status;
@@ -0,0 +1,13 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use crate::client_socket_accept::entity::client_socket::ClientSocket;
+use crate::connection_liveness::entity::connection_liveness_status::ConnectionLivenessStatus;
+
+#[async_trait]
+pub trait ConnectionLivenessRepository {
+    async fn record_connection_activity(&mut self, address: &str) -> bool;
+    async fn mark_connection_closed(&mut self, address: &str, grace_period: Duration) -> bool;
+    async fn judge_connection_liveness(&self, address: &str, heartbeat_interval: Duration, grace_period: Duration) -> Option<ConnectionLivenessStatus>;
+    async fn send_ping_if_due(&mut self, client_socket: &ClientSocket, heartbeat_interval: Duration) -> bool;
+    async fn remove_connection_liveness(&mut self, address: &str) -> bool;
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+use crate::check_connecting::entity::check_connecting_message_form::CheckConnectingMessageForm;
+use crate::client_socket_accept::entity::client_socket::ClientSocket;
+use crate::connection_liveness::entity::connection_liveness::ConnectionLiveness;
+use crate::connection_liveness::entity::connection_liveness_status::ConnectionLivenessStatus;
+use crate::connection_liveness::repository::connection_liveness_repository::ConnectionLivenessRepository;
+use crate::response_generator::response_envelope::ResponseEnvelope;
+use crate::response_generator::response_type::ResponseType::SEND_MESSAGE_CHECK_CONNECTING;
+
+pub struct ConnectionLivenessRepositoryImpl {
+    connection_liveness: ConnectionLiveness,
+}
+
+impl ConnectionLivenessRepositoryImpl {
+    pub fn new() -> Self {
+        ConnectionLivenessRepositoryImpl {
+            connection_liveness: ConnectionLiveness::new(),
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ConnectionLivenessRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ConnectionLivenessRepositoryImpl>> =
+                Arc::new(AsyncMutex::new(ConnectionLivenessRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ConnectionLivenessRepository for ConnectionLivenessRepositoryImpl {
+    // 요청이 들어올 때마다 호출되므로 로그를 남기지 않음
+    async fn record_connection_activity(&mut self, address: &str) -> bool {
+        self.connection_liveness.record_activity(address);
+
+        true
+    }
+
+    async fn mark_connection_closed(&mut self, address: &str, grace_period: Duration) -> bool {
+        println!("ConnectionLivenessRepositoryImpl: mark_connection_closed()");
+
+        self.connection_liveness.mark_closed(address);
+        self.connection_liveness.remove_closed_older_than(grace_period);
+
+        true
+    }
+
+    async fn judge_connection_liveness(&self, address: &str, heartbeat_interval: Duration, grace_period: Duration) -> Option<ConnectionLivenessStatus> {
+        self.connection_liveness.judge(address, heartbeat_interval, grace_period)
+    }
+
+    async fn send_ping_if_due(&mut self, client_socket: &ClientSocket, heartbeat_interval: Duration) -> bool {
+        let address = client_socket.address();
+
+        if !self.connection_liveness.is_ping_due(address, heartbeat_interval) {
+            return false
+        }
+
+        println!("ConnectionLivenessRepositoryImpl: send_ping_if_due()");
+
+        client_socket.each_client_receiver_transmitter_channel().send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        SEND_MESSAGE_CHECK_CONNECTING(
+                            CheckConnectingMessageForm::new(address)))))).await;
+
+        self.connection_liveness.mark_pinged(address);
+
+        true
+    }
+
+    async fn remove_connection_liveness(&mut self, address: &str) -> bool {
+        println!("ConnectionLivenessRepositoryImpl: remove_connection_liveness()");
+
+        self.connection_liveness.remove(address)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod connection_liveness_repository;
+pub mod connection_liveness_repository_impl;
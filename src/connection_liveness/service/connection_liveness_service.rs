@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::connection_liveness::service::request::check_connection_liveness_request::CheckConnectionLivenessRequest;
+use crate::connection_liveness::service::request::remove_connection_liveness_request::RemoveConnectionLivenessRequest;
+use crate::connection_liveness::service::response::check_connection_liveness_response::CheckConnectionLivenessResponse;
+use crate::connection_liveness::service::response::remove_connection_liveness_response::RemoveConnectionLivenessResponse;
+
+#[async_trait]
+pub trait ConnectionLivenessService {
+    async fn check_connection_liveness(&self, check_connection_liveness_request: CheckConnectionLivenessRequest) -> CheckConnectionLivenessResponse;
+    async fn remove_connection_liveness(&self, remove_connection_liveness_request: RemoveConnectionLivenessRequest) -> RemoveConnectionLivenessResponse;
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::client_socket_accept::entity::client_socket::ClientSocket;
+use crate::connection_context::repository::connection_context_repository::ConnectionContextRepository;
+use crate::connection_context::repository::connection_context_repository_impl::ConnectionContextRepositoryImpl;
+use crate::connection_liveness::entity::connection_liveness_status::ConnectionLivenessStatus;
+use crate::connection_liveness::repository::connection_liveness_repository::ConnectionLivenessRepository;
+use crate::connection_liveness::repository::connection_liveness_repository_impl::ConnectionLivenessRepositoryImpl;
+use crate::connection_liveness::service::connection_liveness_service::ConnectionLivenessService;
+use crate::connection_liveness::service::request::check_connection_liveness_request::CheckConnectionLivenessRequest;
+use crate::connection_liveness::service::request::remove_connection_liveness_request::RemoveConnectionLivenessRequest;
+use crate::connection_liveness::service::response::check_connection_liveness_response::CheckConnectionLivenessResponse;
+use crate::connection_liveness::service::response::remove_connection_liveness_response::RemoveConnectionLivenessResponse;
+use crate::server_config::server_config::ServerConfig;
+
+pub struct ConnectionLivenessServiceImpl {
+    connection_liveness_repository: Arc<AsyncMutex<ConnectionLivenessRepositoryImpl>>,
+    connection_context_repository: Arc<AsyncMutex<ConnectionContextRepositoryImpl>>,
+}
+
+impl ConnectionLivenessServiceImpl {
+    pub fn new(
+               connection_liveness_repository: Arc<AsyncMutex<ConnectionLivenessRepositoryImpl>>,
+               connection_context_repository: Arc<AsyncMutex<ConnectionContextRepositoryImpl>>,
+    ) -> Self {
+
+        ConnectionLivenessServiceImpl {
+            connection_liveness_repository,
+            connection_context_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ConnectionLivenessServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ConnectionLivenessServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ConnectionLivenessServiceImpl::new(
+                            ConnectionLivenessRepositoryImpl::get_instance(),
+                            ConnectionContextRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    // 생존 여부는 소켓 주소 단위로 기록되므로 계정에 연결된 소켓을 찾는다
+    async fn find_client_socket(&self, account_unique_id: i32) -> Option<ClientSocket> {
+        let connection_context_repository_guard = self.connection_context_repository.lock().await;
+        let client_socket_mutex =
+            connection_context_repository_guard.connection_context_map().lock().await.get(&account_unique_id).cloned();
+        drop(connection_context_repository_guard);
+
+        let client_socket = client_socket_mutex?.lock().await.clone();
+        Some(client_socket)
+    }
+}
+
+#[async_trait]
+impl ConnectionLivenessService for ConnectionLivenessServiceImpl {
+    async fn check_connection_liveness(&self, check_connection_liveness_request: CheckConnectionLivenessRequest) -> CheckConnectionLivenessResponse {
+        let account_unique_id = check_connection_liveness_request.get_account_unique_id();
+
+        let client_socket = match self.find_client_socket(account_unique_id).await {
+            Some(client_socket) => client_socket,
+            None => return CheckConnectionLivenessResponse::new(false),
+        };
+
+        let server_config = ServerConfig::get_instance();
+        let heartbeat_interval = server_config.get_heartbeat_interval();
+        let grace_period = server_config.get_connection_grace_period();
+
+        let mut connection_liveness_repository_guard = self.connection_liveness_repository.lock().await;
+        // 기록이 없다면 닫힌 지 오래되어 이미 정리된 연결
+        let connection_liveness_status =
+            connection_liveness_repository_guard.judge_connection_liveness(
+                client_socket.address(), heartbeat_interval, grace_period).await
+                .unwrap_or(ConnectionLivenessStatus::Abandoned);
+
+        if connection_liveness_status == ConnectionLivenessStatus::Silent {
+            connection_liveness_repository_guard.send_ping_if_due(&client_socket, heartbeat_interval).await;
+        }
+        drop(connection_liveness_repository_guard);
+
+        if connection_liveness_status == ConnectionLivenessStatus::Abandoned {
+            println!("ConnectionLivenessServiceImpl: account {} abandoned the connection", account_unique_id);
+        }
+
+        CheckConnectionLivenessResponse::new(connection_liveness_status == ConnectionLivenessStatus::Abandoned)
+    }
+
+    async fn remove_connection_liveness(&self, remove_connection_liveness_request: RemoveConnectionLivenessRequest) -> RemoveConnectionLivenessResponse {
+        println!("ConnectionLivenessServiceImpl: remove_connection_liveness()");
+
+        let account_unique_id = remove_connection_liveness_request.get_account_unique_id();
+
+        let client_socket = match self.find_client_socket(account_unique_id).await {
+            Some(client_socket) => client_socket,
+            None => return RemoveConnectionLivenessResponse::new(false),
+        };
+
+        let mut connection_liveness_repository_guard = self.connection_liveness_repository.lock().await;
+        connection_liveness_repository_guard.remove_connection_liveness(client_socket.address()).await;
+        drop(connection_liveness_repository_guard);
+
+        let mut connection_context_repository_guard = self.connection_context_repository.lock().await;
+        let is_success = connection_context_repository_guard.remove_connection_context(account_unique_id).await;
+        drop(connection_context_repository_guard);
+
+        RemoveConnectionLivenessResponse::new(is_success)
+    }
+}
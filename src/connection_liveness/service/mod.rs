@@ -0,0 +1,4 @@
+pub mod connection_liveness_service;
+pub mod connection_liveness_service_impl;
+pub mod request;
+pub mod response;
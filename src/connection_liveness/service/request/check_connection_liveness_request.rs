@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct CheckConnectionLivenessRequest {
+    account_unique_id: i32,
+}
+
+impl CheckConnectionLivenessRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        CheckConnectionLivenessRequest {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
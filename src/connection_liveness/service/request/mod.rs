@@ -0,0 +1,2 @@
+pub mod check_connection_liveness_request;
+pub mod remove_connection_liveness_request;
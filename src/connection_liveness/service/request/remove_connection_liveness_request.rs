@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveConnectionLivenessRequest {
+    account_unique_id: i32,
+}
+
+impl RemoveConnectionLivenessRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        RemoveConnectionLivenessRequest {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
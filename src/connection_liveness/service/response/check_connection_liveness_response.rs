@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct CheckConnectionLivenessResponse {
+    is_abandoned: bool,
+}
+
+impl CheckConnectionLivenessResponse {
+    pub fn new(is_abandoned: bool) -> Self {
+        CheckConnectionLivenessResponse {
+            is_abandoned,
+        }
+    }
+
+    pub fn is_abandoned(&self) -> bool {
+        self.is_abandoned
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveConnectionLivenessResponse {
+    is_success: bool,
+}
+
+impl RemoveConnectionLivenessResponse {
+    pub fn new(is_success: bool) -> Self {
+        RemoveConnectionLivenessResponse {
+            is_success,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
@@ -93,6 +93,12 @@ impl DeckConfigurationValidatorService for DeckConfigurationValidatorServiceImpl
             let kind_result = card_kinds_repository_guard.get_card_kind(card).await;
             let grade_result = card_grade_repository_guard.get_card_grade(card).await;
 
+            // 토큰은 효과로만 생성되므로 덱에 넣을 수 없음
+            if kind_result == KindsEnum::Token {
+                let token_card_error = format!("토큰 카드는 덱에 포함할 수 없습니다: {}", card);
+                return Err(token_card_error)
+            }
+
             if kind_result == KindsEnum::Energy {
                 if grade_result == GradeEnum::Common {
                     energy_card_list.push(card);
@@ -27,6 +27,42 @@ use crate::thread_worker::service::thread_worker_service_impl::ThreadWorkerServi
 
 use crate::common::mpsc::mpsc_creator::mpsc_channel::define_channel;
 
+use crate::account::protocol::account_protocol::register_account_protocol;
+use crate::account_card::protocol::account_card_protocol::register_account_card_protocol;
+use crate::account_deck::protocol::account_deck_protocol::register_account_deck_protocol;
+use crate::account_deck_card::protocol::account_deck_card_protocol::register_account_deck_card_protocol;
+use crate::account_point::protocol::account_point_protocol::register_account_point_protocol;
+use crate::account_rating::protocol::account_rating_protocol::register_account_rating_protocol;
+use crate::battle_field_info::protocol::battle_field_info_protocol::register_battle_field_info_protocol;
+use crate::battle_finish::protocol::battle_finish_protocol::register_battle_finish_protocol;
+use crate::battle_ready_account_hash::protocol::battle_ready_account_hash_protocol::register_battle_ready_account_hash_protocol;
+use crate::battle_room::protocol::battle_room_protocol::register_battle_room_protocol;
+use crate::battle_start::protocol::battle_start_protocol::register_battle_start_protocol;
+use crate::battle_resume::protocol::battle_resume_protocol::register_battle_resume_protocol;
+use crate::battle_wait_queue::protocol::battle_wait_queue_protocol::register_battle_wait_queue_protocol;
+use crate::check_connecting::protocol::check_connecting_protocol::register_check_connecting_protocol;
+use crate::rate_limit::protocol::rate_limit_protocol::register_rate_limit_protocol;
+use crate::client_program::protocol::client_program_protocol::register_client_program_protocol;
+use crate::fake_battle_room::protocol::fake_battle_room_protocol::register_fake_battle_room_protocol;
+use crate::game_card_active_skill::protocol::game_card_active_skill_protocol::register_game_card_active_skill_protocol;
+use crate::game_card_energy::protocol::game_card_energy_protocol::register_game_card_energy_protocol;
+use crate::game_card_item::protocol::game_card_item_protocol::register_game_card_item_protocol;
+use crate::game_card_trap::protocol::game_card_trap_protocol::register_game_card_trap_protocol;
+use crate::game_card_environment::protocol::game_card_environment_protocol::register_game_card_environment_protocol;
+use crate::game_card_passive_skill::protocol::game_card_passive_skill_protocol::register_game_card_passive_skill_protocol;
+use crate::game_card_support::protocol::game_card_support_protocol::register_game_card_support_protocol;
+use crate::game_card_unit::protocol::game_card_unit_protocol::register_game_card_unit_protocol;
+use crate::game_deck::protocol::game_deck_protocol::register_game_deck_protocol;
+use crate::game_field_energy::protocol::game_field_energy_protocol::register_game_field_energy_protocol;
+use crate::game_turn::protocol::game_turn_protocol::register_game_turn_protocol;
+use crate::game_winner_check::protocol::game_winner_check_protocol::register_game_winner_check_protocol;
+use crate::mulligan::protocol::mulligan_protocol::register_mulligan_protocol;
+use crate::rock_paper_scissors::protocol::rock_paper_scissors_protocol::register_rock_paper_scissors_protocol;
+use crate::shop::protocol::shop_protocol::register_shop_protocol;
+use crate::protocol_registry::service::protocol_registry_service::ProtocolRegistryService;
+use crate::protocol_registry::service::protocol_registry_service_impl::ProtocolRegistryServiceImpl;
+use crate::protocol_registry::entity::protocol_registration_error::ProtocolRegistrationError;
+
 use crate::receiver::controller::server_receiver_controller::ServerReceiverController;
 use crate::receiver::controller::server_receiver_controller_impl::ServerReceiverControllerImpl;
 
@@ -36,6 +72,13 @@ use crate::response_generator::response_type::ResponseType;
 
 use crate::transmitter::controller::transmitter_controller::TransmitterController;
 use crate::transmitter::controller::transmitter_controller_impl::TransmitterControllerImpl;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
+use crate::game_card_active_skill::repository::game_card_active_skill_repository_impl::GameCardActiveSkillRepositoryImpl;
+use crate::game_card_energy::repository::game_card_energy_repository_impl::GameCardEnergyRepositoryImpl;
+use crate::game_card_item::repository::game_card_item_repository_impl::GameCardItemRepositoryImpl;
+use crate::game_card_passive_skill::repository::game_card_passive_skill_repository_impl::GameCardPassiveSkillRepositoryImpl;
+use crate::game_card_support::repository::game_card_support_repository_impl::GameCardSupportRepositoryImpl;
+use crate::game_card_tool::repository::game_card_tool_repository_impl::GameCardToolRepositoryImpl;
 
 define_channel!(AcceptorReceiverChannel, ClientSocket);
 define_channel!(AcceptorTransmitterChannel, ClientSocket);
@@ -121,7 +164,59 @@ impl DomainInitializer {
         let _ = CardRaceServiceImpl::get_instance();
     }
 
-    pub async fn init_every_domain(&self) {
+    // 카드 효과 정의 파일의 오류는 첫 카드 사용 시점이 아니라 서버 기동 시점에 드러나야 함
+    pub async fn init_card_effect_definition_domain(&self) {
+        let _ = CardEffectDefinitionRepositoryImpl::get_instance();
+        let _ = GameCardItemRepositoryImpl::get_instance();
+        let _ = GameCardSupportRepositoryImpl::get_instance();
+        let _ = GameCardToolRepositoryImpl::get_instance();
+        let _ = GameCardEnergyRepositoryImpl::get_instance();
+        let _ = GameCardActiveSkillRepositoryImpl::get_instance();
+        let _ = GameCardPassiveSkillRepositoryImpl::get_instance();
+    }
+
+    // 중복되거나 잘못된 protocol 번호가 하나라도 있으면 서버를 띄우지 않도록 오류를 그대로 반환
+    pub async fn init_protocol_registry_domain(&self) -> Result<(), Vec<ProtocolRegistrationError>> {
+        let protocol_registry_service_mutex = ProtocolRegistryServiceImpl::get_instance();
+        let mut protocol_registry_service = protocol_registry_service_mutex.lock().await;
+
+        protocol_registry_service.register_domain_protocol(register_account_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_wait_queue_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_ready_account_hash_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_room_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_account_deck_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_deck_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_mulligan_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_rock_paper_scissors_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_start_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_resume_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_account_card_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_account_deck_card_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_shop_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_account_point_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_account_rating_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_field_info_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_support_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_unit_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_active_skill_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_field_energy_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_item_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_trap_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_environment_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_energy_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_card_passive_skill_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_turn_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_check_connecting_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_battle_finish_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_game_winner_check_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_client_program_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_rate_limit_protocol).await;
+        protocol_registry_service.register_domain_protocol(register_fake_battle_room_protocol).await;
+
+        protocol_registry_service.report_protocol_registration().await
+    }
+
+    pub async fn init_every_domain(&self) -> Result<(), Vec<ProtocolRegistrationError>> {
         /* IPC Channel List */
         let acceptor_receiver_channel = AcceptorReceiverChannel::new(1);
         let acceptor_receiver_channel_arc = Arc::new(acceptor_receiver_channel.clone());
@@ -160,6 +255,12 @@ impl DomainInitializer {
 
         /* Card Attribute Domain */
         self.init_card_attributes_domain().await;
+
+        /* Card Effect Definition Domain */
+        self.init_card_effect_definition_domain().await;
+
+        /* Protocol Registry Domain */
+        self.init_protocol_registry_domain().await
     }
 }
 
@@ -0,0 +1,43 @@
+use crate::fake_battle_room::controller::fake_battle_room_controller::FakeBattleRoomController;
+use crate::fake_battle_room::controller::fake_battle_room_controller_impl::FakeBattleRoomControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::fake_battle_room_create_request_form_generator::create_fake_battle_room_create_request_form;
+use crate::request_generator::fake_get_all_specific_kind_cards_from_deck_request_form_generator::create_fake_get_all_specific_kind_cards_from_deck_request_form;
+use crate::request_generator::fake_get_nether_from_deck_request_form_generator::create_fake_get_nether_from_deck_request_form;
+use crate::request_generator::fake_multi_draw_request_form_generator::create_fake_multi_draw_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_fake_battle_room_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Fake Battle Room Test
+    protocol_registry.register(8001, "FAKE_BATTLE_ROOM_CREATION", create_fake_battle_room_create_request_form, |request| async move {
+        let fake_battle_room_controller_mutex = FakeBattleRoomControllerImpl::get_instance();
+        let fake_battle_room_controller = fake_battle_room_controller_mutex.lock().await;
+
+        let response = fake_battle_room_controller.request_to_create_fake_battle_room(request).await;
+        ResponseType::FAKE_BATTLE_ROOM_CREATION(response)
+    });
+
+    protocol_registry.register_battle_room_action(8003, "FAKE_MULTI_DRAW", create_fake_multi_draw_request_form, |request| async move {
+        let fake_battle_room_controller_mutex = FakeBattleRoomControllerImpl::get_instance();
+        let fake_battle_room_controller = fake_battle_room_controller_mutex.lock().await;
+
+        let response = fake_battle_room_controller.request_to_fake_multi_draw(request).await;
+        ResponseType::FAKE_MULTI_DRAW(response)
+    });
+
+    protocol_registry.register_battle_room_action(8004, "FAKE_GET_NETHER", create_fake_get_nether_from_deck_request_form, |request| async move {
+        let fake_battle_room_controller_mutex = FakeBattleRoomControllerImpl::get_instance();
+        let fake_battle_room_controller = fake_battle_room_controller_mutex.lock().await;
+
+        let response = fake_battle_room_controller.request_to_get_nether_from_deck(request).await;
+        ResponseType::FAKE_GET_NETHER(response)
+    });
+
+    protocol_registry.register_battle_room_action(8005, "FAKE_GET_SPECIFIC_KIND_DECK_CARD", create_fake_get_all_specific_kind_cards_from_deck_request_form, |request| async move {
+        let fake_battle_room_controller_mutex = FakeBattleRoomControllerImpl::get_instance();
+        let fake_battle_room_controller = fake_battle_room_controller_mutex.lock().await;
+
+        let response = fake_battle_room_controller.request_to_get_all_cards_of_specific_kind_from_deck(request).await;
+        ResponseType::FAKE_GET_SPECIFIC_KIND_DECK_CARD(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod fake_battle_room_protocol;
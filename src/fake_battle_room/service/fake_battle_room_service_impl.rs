@@ -3,8 +3,10 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
-use crate::battle_prepare_task::service::battle_prepare_task_service_impl::{player_deck_init_thread, player_field_energy_init_thread, player_field_unit_init_thread, player_hand_init_thread, player_lost_zone_init_thread, player_main_character_init_thread, player_round_init_thread, player_support_card_usage_counter_init_thread, player_tomb_init_thread, player_turn_init_thread, spawn_async_task_for_prepare_battle};
+use crate::battle_prepare_task::service::battle_prepare_task_service_impl::{player_deck_init_thread, player_field_energy_init_thread, player_field_unit_init_thread, player_game_event_bus_init_thread, player_hand_init_thread, player_lost_zone_init_thread, player_main_character_init_thread, player_round_init_thread, player_support_card_usage_counter_init_thread, player_tomb_init_thread, player_trap_zone_init_thread, player_turn_init_thread};
 
+use crate::battle_room_actor::entity::battle_room_state::spawn_in_battle_room;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::find_battle_room_actor_handle;
 use crate::battle_ready_account_hash::entity::battle_ready_account_hash_status::BattleReadyAccountHashStatus;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository::BattleReadyAccountHashRepository;
 use crate::battle_ready_account_hash::repository::battle_ready_account_hash_repository_impl::BattleReadyAccountHashRepositoryImpl;
@@ -14,49 +16,29 @@ use crate::fake_battle_room::service::fake_battle_room_service::FakeBattleRoomSe
 use crate::fake_battle_room::service::request::create_battle_fake_room_request::CreateFakeBattleRoomRequest;
 use crate::fake_battle_room::service::response::create_battle_fake_room_response::CreateFakeBattleRoomResponse;
 use crate::game_card_support_usage_counter::repository::game_card_support_usage_counter_repository_impl::GameCardSupportUsageCounterRepositoryImpl;
-use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
-use crate::game_field_energy::repository::game_field_energy_repository_impl::GameFieldEnergyRepositoryImpl;
-use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
-use crate::game_hand::repository::game_hand_repository_impl::GameHandRepositoryImpl;
 use crate::game_lost_zone::repository::game_lost_zone_repository_impl::GameLostZoneRepositoryImpl;
 use crate::game_main_character::repository::game_main_character_repository_impl::GameMainCharacterRepositoryImpl;
 use crate::game_round::repository::game_round_repository_impl::GameRoundRepositoryImpl;
-use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
 
 pub struct FakeBattleRoomServiceImpl {
     battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
     game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
     game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
     game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>,
 }
 
 impl FakeBattleRoomServiceImpl {
     pub fn new(battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
-               game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-               game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-               game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
                game_lost_zone_repository: Arc<AsyncMutex<GameLostZoneRepositoryImpl>>,
                game_main_character_repository: Arc<AsyncMutex<GameMainCharacterRepositoryImpl>>,
-               game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
                game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
                game_card_support_usage_counter_repository: Arc<AsyncMutex<GameCardSupportUsageCounterRepositoryImpl>>) -> Self {
 
         FakeBattleRoomServiceImpl {
             battle_room_repository,
-            game_deck_repository,
-            game_hand_repository,
-            game_field_energy_repository,
-            game_field_unit_repository,
             game_lost_zone_repository,
             game_main_character_repository,
-            game_tomb_repository,
             game_round_repository,
             game_card_support_usage_counter_repository
         }
@@ -69,13 +51,8 @@ impl FakeBattleRoomServiceImpl {
                     AsyncMutex::new(
                         FakeBattleRoomServiceImpl::new(
                             BattleRoomRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
-                            GameFieldEnergyRepositoryImpl::get_instance(),
-                            GameFieldUnitRepositoryImpl::get_instance(),
                             GameLostZoneRepositoryImpl::get_instance(),
                             GameMainCharacterRepositoryImpl::get_instance(),
-                            GameTombRepositoryImpl::get_instance(),
                             GameRoundRepositoryImpl::get_instance(),
                             GameCardSupportUsageCounterRepositoryImpl::get_instance())));
         }
@@ -84,16 +61,18 @@ impl FakeBattleRoomServiceImpl {
 }
 
 pub async fn spawn_async_task_for_fake_battle_room(user_id: i32) {
-    let task_deck_init = tokio::spawn(player_deck_init_thread(user_id));
-    let task_hand_init = tokio::spawn(player_hand_init_thread(user_id));
-    let task_field_energy_init = tokio::spawn(player_field_energy_init_thread(user_id));
-    let task_field_unit_init = tokio::spawn(player_field_unit_init_thread(user_id));
-    let task_lost_zone_init = tokio::spawn(player_lost_zone_init_thread(user_id));
-    let task_main_character_init = tokio::spawn(player_main_character_init_thread(user_id));
-    let task_tomb_init = tokio::spawn(player_tomb_init_thread(user_id));
-    let task_round_init = tokio::spawn(player_round_init_thread(user_id));
-    let task_turn_init = tokio::spawn(player_turn_init_thread(user_id));
-    let task_support_usage_counter_init = tokio::spawn(player_support_card_usage_counter_init_thread(user_id));
+    let task_deck_init = spawn_in_battle_room(player_deck_init_thread(user_id));
+    let task_hand_init = spawn_in_battle_room(player_hand_init_thread(user_id));
+    let task_field_energy_init = spawn_in_battle_room(player_field_energy_init_thread(user_id));
+    let task_field_unit_init = spawn_in_battle_room(player_field_unit_init_thread(user_id));
+    let task_lost_zone_init = spawn_in_battle_room(player_lost_zone_init_thread(user_id));
+    let task_main_character_init = spawn_in_battle_room(player_main_character_init_thread(user_id));
+    let task_tomb_init = spawn_in_battle_room(player_tomb_init_thread(user_id));
+    let task_round_init = spawn_in_battle_room(player_round_init_thread(user_id));
+    let task_turn_init = spawn_in_battle_room(player_turn_init_thread(user_id));
+    let task_support_usage_counter_init = spawn_in_battle_room(player_support_card_usage_counter_init_thread(user_id));
+    let task_trap_zone_init = spawn_in_battle_room(player_trap_zone_init_thread(user_id));
+    let task_game_event_bus_init = spawn_in_battle_room(player_game_event_bus_init_thread(user_id));
 
     let _ = tokio::try_join!(
         task_deck_init,
@@ -106,6 +85,8 @@ pub async fn spawn_async_task_for_fake_battle_room(user_id: i32) {
         task_round_init,
         task_turn_init,
         task_support_usage_counter_init,
+        task_trap_zone_init,
+        task_game_event_bus_init,
     );
 }
 
@@ -123,16 +104,20 @@ impl FakeBattleRoomService for FakeBattleRoomServiceImpl {
 
         let battle_room_repository_guard = self.battle_room_repository.lock().await;
 
-        battle_room_repository_guard.set_players_to_battle_room(users_to_process.clone()).await.expect("전투 배치 실패");
-        let battle_room_count = battle_room_repository_guard.get_battle_room_count().await;
+        let battle_room_id = battle_room_repository_guard.set_players_to_battle_room(users_to_process.clone()).await.expect("전투 배치 실패");
+        println!("fake battle_room_id: {}", battle_room_id);
         drop(battle_room_repository_guard);
 
-        let handles = users_to_process.into_iter().map(|user_id| {
-            tokio::spawn(spawn_async_task_for_prepare_battle(user_id))
-        }).collect::<Vec<_>>();
+        // 게임 상태는 방의 Actor 가 소유하므로 방을 만든 뒤 그 Actor 에서 생성
+        let battle_room_actor_handle = match find_battle_room_actor_handle(battle_room_id).await {
+            Some(battle_room_actor_handle) => battle_room_actor_handle,
+            None => return CreateFakeBattleRoomResponse::new(false),
+        };
 
-        for handle in handles {
-            handle.await.expect("Failed to await spawned task");
+        for user_id in users_to_process {
+            if battle_room_actor_handle.prepare_player(user_id).await.is_none() {
+                return CreateFakeBattleRoomResponse::new(false)
+            }
         }
 
         return CreateFakeBattleRoomResponse::new(true)
@@ -1,23 +1,209 @@
+use std::sync::Arc;
 use async_trait::async_trait;
-use crate::battle_ready_account_hash::entity::battle_ready_account_hash_status::BattleReadyAccountHashStatus;
+
+use tokio::sync::Mutex as AsyncMutex;
+use crate::action_waiting_timer::service::action_waiting_timer_service::ActionWaitingTimerService;
+use crate::action_waiting_timer::service::action_waiting_timer_service_impl::ActionWaitingTimerServiceImpl;
+use crate::action_waiting_timer::service::request::check_action_waiting_timer_expired_request::CheckActionWaitingTimerExpiredRequest;
+use crate::action_waiting_timer::service::request::remove_action_waiting_timer_request::RemoveActionWaitingTimerRequest;
+use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::battle_room::service::request::get_players_in_battle_room_request::GetPlayersInBattleRoomRequest;
+use crate::battle_finish::service::battle_finish_service::BattleFinishService;
+use crate::battle_finish::service::battle_finish_service_impl::BattleFinishServiceImpl;
+use crate::battle_finish::service::request::battle_finish_for_abandoned_player_request::BattleFinishForAbandonedPlayerRequest;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::find_battle_room_actor_handle;
+use crate::connection_liveness::service::connection_liveness_service::ConnectionLivenessService;
+use crate::connection_liveness::service::connection_liveness_service_impl::ConnectionLivenessServiceImpl;
+use crate::connection_liveness::service::request::check_connection_liveness_request::CheckConnectionLivenessRequest;
+use crate::connection_liveness::service::request::remove_connection_liveness_request::RemoveConnectionLivenessRequest;
 
 use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller::GameBattleFieldMonitorController;
+use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::game_winner_check::service::request::check_game_finished_request::CheckGameFinishedRequest;
+use crate::game_winner_check::service::request::forfeit_request::ForfeitRequest;
 
-pub struct GameBattleFieldMonitorControllerImpl;
+pub struct GameBattleFieldMonitorControllerImpl {
+    battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
+    battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+    action_waiting_timer_service: Arc<AsyncMutex<ActionWaitingTimerServiceImpl>>,
+    game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+    connection_liveness_service: Arc<AsyncMutex<ConnectionLivenessServiceImpl>>,
+}
 
 impl GameBattleFieldMonitorControllerImpl {
     pub fn new() -> Self {
-        GameBattleFieldMonitorControllerImpl {}
+        GameBattleFieldMonitorControllerImpl {
+            battle_room_repository: BattleRoomRepositoryImpl::get_instance(),
+            battle_room_service: BattleRoomServiceImpl::get_instance(),
+            action_waiting_timer_service: ActionWaitingTimerServiceImpl::get_instance(),
+            game_winner_check_service: GameWinnerCheckServiceImpl::get_instance(),
+            connection_liveness_service: ConnectionLivenessServiceImpl::get_instance(),
+        }
+    }
+
+    // 방이 사라졌거나 승패가 결정되었다면 더 이상 턴 시간을 감시할 필요가 없음
+    async fn is_battle_over(&self, battle_room_id: i32) -> bool {
+        let battle_room_service_guard = self.battle_room_service.lock().await;
+        let player_list =
+            battle_room_service_guard.get_players_in_battle_room(
+                GetPlayersInBattleRoomRequest::new(battle_room_id)).await.get_player_list().clone();
+        drop(battle_room_service_guard);
+
+        if player_list.is_empty() {
+            return true
+        }
+
+        let game_winner_check_service_guard = self.game_winner_check_service.lock().await;
+        for player_unique_id in player_list {
+            let check_game_finished_response =
+                game_winner_check_service_guard.check_game_finished(
+                    CheckGameFinishedRequest::new(player_unique_id)).await;
+
+            if check_game_finished_response.is_finished() {
+                return true
+            }
+        }
+
+        false
+    }
+
+    // 유예 시간이 지나도록 응답이 없는 플레이어를 찾는다
+    async fn find_abandoned_player(&self, battle_room_id: i32) -> Option<i32> {
+        let battle_room_service_guard = self.battle_room_service.lock().await;
+        let player_list =
+            battle_room_service_guard.get_players_in_battle_room(
+                GetPlayersInBattleRoomRequest::new(battle_room_id)).await.get_player_list().clone();
+        drop(battle_room_service_guard);
+
+        let connection_liveness_service_guard = self.connection_liveness_service.lock().await;
+        for player_unique_id in player_list {
+            let check_connection_liveness_response =
+                connection_liveness_service_guard.check_connection_liveness(
+                    CheckConnectionLivenessRequest::new(player_unique_id)).await;
+
+            if check_connection_liveness_response.is_abandoned() {
+                return Some(player_unique_id)
+            }
+        }
+
+        None
+    }
+
+    // 떠난 플레이어를 기권 처리하고, 종료 요청을 보낼 수 없는 그 플레이어의 게임 정보를 대신 정리
+    // 방의 Actor 에서 실행되므로 진행 중인 행동과 섞이지 않음
+    pub(crate) async fn forfeit_abandoned_player(game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+                                                 battle_finish_service: Arc<AsyncMutex<BattleFinishServiceImpl>>,
+                                                 connection_liveness_service: Arc<AsyncMutex<ConnectionLivenessServiceImpl>>,
+                                                 abandoned_unique_id: i32) {
+
+        let mut game_winner_check_service_guard = game_winner_check_service.lock().await;
+        let forfeit_response =
+            game_winner_check_service_guard.set_game_winner_by_forfeit(
+                ForfeitRequest::new(abandoned_unique_id)).await;
+        drop(game_winner_check_service_guard);
+
+        if !forfeit_response.is_success() {
+            println!("Failed to forfeit abandoned account {}", abandoned_unique_id);
+        }
+
+        let battle_finish_service_guard = battle_finish_service.lock().await;
+        let battle_finish_response =
+            battle_finish_service_guard.battle_finish_for_abandoned_player(
+                BattleFinishForAbandonedPlayerRequest::new(abandoned_unique_id)).await;
+        drop(battle_finish_service_guard);
+
+        if !battle_finish_response.is_success() {
+            println!("Failed to clean up battle of abandoned account {}", abandoned_unique_id);
+        }
+
+        let connection_liveness_service_guard = connection_liveness_service.lock().await;
+        let remove_connection_liveness_response =
+            connection_liveness_service_guard.remove_connection_liveness(
+                RemoveConnectionLivenessRequest::new(abandoned_unique_id)).await;
+        drop(connection_liveness_service_guard);
+
+        if remove_connection_liveness_response.is_success() {
+            println!("Connection of abandoned account {} removed", abandoned_unique_id);
+        }
     }
 }
 
 #[async_trait]
 impl GameBattleFieldMonitorController for GameBattleFieldMonitorControllerImpl {
-    async fn battle_field_monitoring(&self, battle_room_number: usize) {
+    async fn battle_field_monitoring(&self, battle_room_id: i32) {
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        let battle_room_closed_receiver_option =
+            battle_room_repository_guard.subscribe_battle_room_closed(battle_room_id).await;
+        drop(battle_room_repository_guard);
+
+        let mut battle_room_closed_receiver = match battle_room_closed_receiver_option {
+            Some(battle_room_closed_receiver) => battle_room_closed_receiver,
+            None => {
+                println!("Battle room {} is already closed. Battle field monitoring is skipped.", battle_room_id);
+                return
+            }
+        };
+
         loop {
-            // println!("Battle Field Monitor -> Room number: {}", battle_room_number);
+            // println!("Battle Field Monitor -> Room id: {}", battle_room_id);
 
-            tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+            if self.is_battle_over(battle_room_id).await {
+                let action_waiting_timer_service_guard = self.action_waiting_timer_service.lock().await;
+                let remove_action_waiting_timer_response =
+                    action_waiting_timer_service_guard.remove_action_waiting_timer(
+                        RemoveActionWaitingTimerRequest::new(battle_room_id)).await;
+                drop(action_waiting_timer_service_guard);
+
+                if remove_action_waiting_timer_response.is_success() {
+                    println!("Turn timer for room id {} removed", battle_room_id);
+                }
+
+                break
+            }
+
+            if let Some(abandoned_unique_id) = self.find_abandoned_player(battle_room_id).await {
+                // 방의 행동과 섞이지 않도록 Actor 에서 정리하며, 다음 순회에서 감시가 종료됨
+                let forfeit_response_option = match find_battle_room_actor_handle(battle_room_id).await {
+                    Some(battle_room_actor_handle) => battle_room_actor_handle.forfeit_abandoned_player(abandoned_unique_id).await,
+                    None => None,
+                };
+
+                if forfeit_response_option.is_some() {
+                    continue
+                }
+
+                // 기권 처리가 중단되면 같은 계정이 다시 감지되므로 바로 재시도하지 않고 다음 점검까지 대기
+                println!("Forfeit of abandoned account {} aborted", abandoned_unique_id);
+            } else {
+                let action_waiting_timer_service_guard = self.action_waiting_timer_service.lock().await;
+                let is_turn_timed_out =
+                    action_waiting_timer_service_guard.check_action_waiting_timer_expired(
+                        CheckActionWaitingTimerExpiredRequest::new(battle_room_id)).await.is_expired();
+                drop(action_waiting_timer_service_guard);
+
+                if is_turn_timed_out {
+                    // 시간 초과로 인한 턴 종료도 플레이어의 턴 종료 요청과 같은 방의 Actor 에서 처리
+                    let turn_end_response_option = match find_battle_room_actor_handle(battle_room_id).await {
+                        Some(battle_room_actor_handle) => battle_room_actor_handle.expire_turn().await,
+                        None => None,
+                    };
+
+                    if turn_end_response_option.is_none() {
+                        println!("Turn end by time out for room id {} aborted", battle_room_id);
+                    }
+                }
+            }
+
+            // 방이 닫히면 다음 점검을 기다리지 않고 바로 정리 단계로 넘어감
+            tokio::select! {
+                _ = battle_room_closed_receiver.wait_for(|is_closed| *is_closed) => {}
+                _ = tokio::time::sleep(tokio::time::Duration::from_millis(1000)) => {}
+            }
         }
+
+        println!("Battle field monitoring for room id {} is finished.", battle_room_id);
     }
-}
\ No newline at end of file
+}
@@ -148,8 +148,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
         drop(game_protocol_validation_service_guard);
 
 
-        let unit_card_index_string = targeting_active_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = targeting_active_skill_request_form.get_unit_card_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -169,8 +168,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Active Skill Summary 획득
-        let usage_skill_index_string = targeting_active_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = targeting_active_skill_request_form.get_usage_skill_index();
 
         let mut game_card_active_skill_service_guard =
             self.game_card_active_skill_service.lock().await;
@@ -222,8 +220,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
 
         // 타게팅 데미지 적용
         // TODO: 현재에는 단일 타겟팅밖에 없으나 다중 타겟팅이 존재하는 경우 추가 처리 필요
-        let target_card_index_string = targeting_active_skill_request_form.get_opponent_target_card_index();
-        let opponent_target_unit_card_index = target_card_index_string.parse::<i32>().unwrap();
+        let opponent_target_unit_card_index = targeting_active_skill_request_form.get_opponent_target_card_index();
 
         let target_skill_type = summary_active_skill_effect_response.get_skill_type();
         let target_skill_damage = summary_active_skill_effect_response.get_skill_damage();
@@ -243,6 +240,8 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
                 game_field_unit_service_guard.attack_target_unit_with_extra_effect(
                     targeting_active_skill_request_form
                         .to_attack_target_with_extra_effect_request(
+                            account_unique_id,
+                            unit_card_index,
                             opponent_unique_id,
                             target_skill_damage,
                             extra_effect_list_of_unit_using_skill,
@@ -286,7 +285,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
         if judge_death_of_opponent_unit_response.get_dead_unit_id() != -1 {
             println!("공격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-            game_tomb_service_guard.add_used_card_to_tomb(
+            game_tomb_service_guard.add_dead_unit_to_tomb(
                 targeting_active_skill_request_form
                     .to_place_dead_unit_to_tomb_request(
                         opponent_unique_id,
@@ -398,8 +397,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Active Skill Summary 획득
-        let unit_card_index_string = non_targeting_active_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = non_targeting_active_skill_request_form.get_unit_card_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -416,8 +414,7 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
             return NonTargetingActiveSkillResponseForm::default()
         }
 
-        let usage_skill_index_string = non_targeting_active_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = non_targeting_active_skill_request_form.get_usage_skill_index();
 
         let mut game_card_active_skill_service_guard =
             self.game_card_active_skill_service.lock().await;
@@ -630,11 +627,9 @@ impl GameCardActiveSkillController for GameCardActiveSkillControllerImpl {
 
 
         // Battle Field 에서 공격하는 유닛의 index 를 토대로 id 값 확보
-        let unit_card_index_string = targeting_attack_active_skill_to_game_main_character_request_form.get_attacker_unit_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = targeting_attack_active_skill_to_game_main_character_request_form.get_attacker_unit_index();
 
-        let usage_skill_index_string = targeting_attack_active_skill_to_game_main_character_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = targeting_attack_active_skill_to_game_main_character_request_form.get_usage_skill_index();
 
 
         // 액션 가능한 턴인지 검증
@@ -39,19 +39,19 @@ use crate::ui_data_generator::service::request::generate_opponent_specific_unit_
 
 pub struct NonTargetingActiveSkillRequestForm {
     session_id: String,
-    unit_card_index: String,
-    usage_skill_index: String,
+    unit_card_index: i32,
+    usage_skill_index: i32,
 }
 
 impl NonTargetingActiveSkillRequestForm {
     pub fn new(session_id: String,
-               unit_card_index: String,
-               usage_skill_index: String) -> Self {
+               unit_card_index: i32,
+               usage_skill_index: i32) -> Self {
 
         NonTargetingActiveSkillRequestForm {
             session_id: session_id.to_string(),
-            unit_card_index: unit_card_index.to_string(),
-            usage_skill_index: usage_skill_index.to_string(),
+            unit_card_index,
+            usage_skill_index,
         }
     }
 
@@ -59,12 +59,12 @@ impl NonTargetingActiveSkillRequestForm {
         &self.session_id
     }
 
-    pub fn get_unit_card_index(&self) -> &str {
-        &self.unit_card_index
+    pub fn get_unit_card_index(&self) -> i32 {
+        self.unit_card_index
     }
 
-    pub fn get_usage_skill_index(&self) -> &str {
-        &self.usage_skill_index
+    pub fn get_usage_skill_index(&self) -> i32 {
+        self.usage_skill_index
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -35,20 +35,20 @@ use crate::ui_data_generator::service::request::generate_opponent_main_character
 
 pub struct TargetingAttackActiveSkillToGameMainCharacterRequestForm {
     session_id: String,
-    unit_card_index: String,
+    unit_card_index: i32,
     target_game_main_character_index: String,
-    usage_skill_index: String,
+    usage_skill_index: i32,
 
 }
 
 impl TargetingAttackActiveSkillToGameMainCharacterRequestForm {
-    pub fn new(session_id: String, unit_card_index: String, target_game_main_character_index: String, usage_skill_index: String,
+    pub fn new(session_id: String, unit_card_index: i32, target_game_main_character_index: String, usage_skill_index: i32,
     ) -> Self {
         TargetingAttackActiveSkillToGameMainCharacterRequestForm {
             session_id: session_id.to_string(),
-            unit_card_index: unit_card_index.to_string(),
+            unit_card_index,
             target_game_main_character_index: target_game_main_character_index.to_string(),
-            usage_skill_index: usage_skill_index.to_string(),
+            usage_skill_index,
         }
     }
 
@@ -56,15 +56,15 @@ impl TargetingAttackActiveSkillToGameMainCharacterRequestForm {
         &self.session_id
     }
 
-    pub fn get_attacker_unit_index(&self) -> &str {
-        &self.unit_card_index
+    pub fn get_attacker_unit_index(&self) -> i32 {
+        self.unit_card_index
     }
 
     pub fn get_target_game_main_character_index(&self) -> &str {
         &self.target_game_main_character_index
     }
-    pub fn get_usage_skill_index(&self) -> &str {
-        &self.usage_skill_index
+    pub fn get_usage_skill_index(&self) -> i32 {
+        self.usage_skill_index
     }
 
 
@@ -49,4 +49,6 @@ impl NonTargetingActiveSkillResponseForm {
             HashMap::new(),
             HashMap::new(),)
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
\ No newline at end of file
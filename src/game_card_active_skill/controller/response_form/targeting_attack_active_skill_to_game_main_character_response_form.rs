@@ -60,4 +60,6 @@ impl TargetingAttackActiveSkillToGameMainCharacterResponseForm {
             HashMap::new(),
             HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
@@ -1,4 +1,6 @@
-#[derive(PartialEq, Debug, Clone)]
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
 pub enum ActiveSkillType {
     Dummy = 0,
     SingleTarget = 1,
@@ -0,0 +1,27 @@
+use crate::card_effect_definition::entity::active_skill_effect_definition::ActiveSkillEffectDefinition;
+use crate::game_card_active_skill::entity::summary_active_skill_effect::SummaryActiveSkillEffect;
+use crate::game_card_active_skill::handler::game_card_active_skill_handler::GameCardActiveSkillHandler;
+
+pub struct DataDrivenActiveSkillFunction {
+    active_skill_effect_definition: ActiveSkillEffectDefinition,
+}
+
+impl DataDrivenActiveSkillFunction {
+    pub fn new(active_skill_effect_definition: ActiveSkillEffectDefinition) -> Self {
+        DataDrivenActiveSkillFunction { active_skill_effect_definition }
+    }
+}
+
+impl GameCardActiveSkillHandler for DataDrivenActiveSkillFunction {
+    unsafe fn summary_active_skill(&self) -> SummaryActiveSkillEffect {
+        println!("DataDrivenActiveSkillFunction: summary_active_skill()");
+
+        let definition = &self.active_skill_effect_definition;
+        let required_energy = definition.get_required_energy();
+
+        SummaryActiveSkillEffect::new(required_energy.get_race(),
+                                      required_energy.get_count(),
+                                      definition.get_skill_type().clone(),
+                                      definition.get_skill_damage())
+    }
+}
@@ -0,0 +1 @@
+pub mod game_card_active_skill_data_driven_handler_impl;
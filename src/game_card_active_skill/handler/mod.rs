@@ -1,2 +1,3 @@
 pub mod game_card_active_skill_handler;
-pub mod slot;
\ No newline at end of file
+pub mod slot;
+pub mod data_driven;
@@ -2,4 +2,5 @@ pub mod controller;
 pub mod repository;
 pub mod handler;
 pub mod entity;
-pub mod service;
\ No newline at end of file
+pub mod service;
+pub mod protocol;
@@ -0,0 +1,36 @@
+use crate::game_card_active_skill::controller::game_card_active_skill_controller::GameCardActiveSkillController;
+use crate::game_card_active_skill::controller::game_card_active_skill_controller_impl::GameCardActiveSkillControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::non_targeting_active_skill_request_form_generator::create_non_targeting_active_skill_request_form;
+use crate::request_generator::targeting_active_skill_request_form_generator::create_targeting_active_skill_request_form;
+use crate::request_generator::targeting_attack_active_skill_to_game_main_character_request_generator::create_targeting_attack_active_skill_to_game_main_character_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_active_skill_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Unit use targeting active skill
+    protocol_registry.register_battle_room_action(1001, "TARGETING_ACTIVE_SKILL", create_targeting_active_skill_request_form, |request| async move {
+        let game_card_active_skill_controller_mutex = GameCardActiveSkillControllerImpl::get_instance();
+        let game_card_active_skill_controller = game_card_active_skill_controller_mutex.lock().await;
+
+        let response = game_card_active_skill_controller.request_targeting_active_skill(request).await;
+        ResponseType::TARGETING_ACTIVE_SKILL(response)
+    });
+
+    // Unit use non-targeting active skill
+    protocol_registry.register_battle_room_action(1002, "NON_TARGETING_ACTIVE_SKILL", create_non_targeting_active_skill_request_form, |request| async move {
+        let game_card_active_skill_controller_mutex = GameCardActiveSkillControllerImpl::get_instance();
+        let game_card_active_skill_controller = game_card_active_skill_controller_mutex.lock().await;
+
+        let response = game_card_active_skill_controller.request_non_targeting_active_skill(request).await;
+        ResponseType::NON_TARGETING_ACTIVE_SKILL(response)
+    });
+
+    // Active Skill Targeting Attack To Game Main Character
+    protocol_registry.register_battle_room_action(1017, "TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER", create_targeting_attack_active_skill_to_game_main_character_request_form, |request| async move {
+        let game_card_active_skill_controller_mutex = GameCardActiveSkillControllerImpl::get_instance();
+        let game_card_active_skill_controller = game_card_active_skill_controller_mutex.lock().await;
+
+        let response = game_card_active_skill_controller.request_targeting_active_skill_to_game_main_character(request).await;
+        ResponseType::TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER(response)
+    });
+}
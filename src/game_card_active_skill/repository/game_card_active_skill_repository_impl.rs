@@ -9,6 +9,9 @@ use crate::game_card_active_skill::entity::required_energy::RequiredEnergy;
 use crate::game_card_active_skill::entity::summary_active_skill_effect::SummaryActiveSkillEffect;
 
 use crate::game_card_active_skill::handler::game_card_active_skill_handler::GameCardActiveSkillHandler;
+use crate::game_card_active_skill::handler::data_driven::game_card_active_skill_data_driven_handler_impl::DataDrivenActiveSkillFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 use crate::game_card_active_skill::repository::game_card_active_skill_repository::GameCardActiveSkillRepository;
 
 use crate::game_card_active_skill::handler::slot::first_slot::handler_of_27::game_card_unit_27_active_slot_1_handler_impl::UnitCard_27_Active_Slot_1_Function;
@@ -39,6 +42,16 @@ impl GameCardActiveSkillRepositoryImpl {
             active_skill_functions.insert(*active_skill_index, inner_map);
         }
 
+        // 카드 효과 정의 파일에 있는 스킬은 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (unit_card_number, skill_slot_map) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_active_skill_effect_definition_map() {
+            for (active_skill_index, active_skill_effect_definition) in skill_slot_map {
+                active_skill_functions.entry(*active_skill_index).or_insert_with(HashMap::new)
+                    .insert(*unit_card_number, Box::new(DataDrivenActiveSkillFunction::new(active_skill_effect_definition.clone())) as Box<dyn GameCardActiveSkillHandler>);
+            }
+        }
+
         GameCardActiveSkillRepositoryImpl { active_skill_functions }
     }
 
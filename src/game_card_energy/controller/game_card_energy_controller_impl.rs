@@ -133,8 +133,7 @@ impl GameCardEnergyController for GameCardEnergyControllerImpl {
             return AttachGeneralEnergyCardResponseForm::from_response_with_message(NotYourTurn)
         }
 
-        let energy_card_id_string = attach_energy_request_form.get_energy_card_id();
-        let energy_card_id = energy_card_id_string.parse::<i32>().unwrap();
+        let energy_card_id = attach_energy_request_form.get_energy_card_id();
 
         // 2. GameProtocolValidation Service 호출하여 Hand 에 있는지 확인하여 해킹 여부 검증
         let check_protocol_hacking_response =
@@ -191,8 +190,7 @@ impl GameCardEnergyController for GameCardEnergyControllerImpl {
 
         drop(game_card_energy_service_guard);
 
-        let unit_card_index_string = attach_energy_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = attach_energy_request_form.get_unit_card_index();
 
         // 6. Battle Field 유닛에 에너지 붙이기
         let mut game_field_unit_service_guard =
@@ -284,8 +282,7 @@ impl GameCardEnergyController for GameCardEnergyControllerImpl {
             return AttachSpecialEnergyCardResponseForm::default()
         }
 
-        let energy_card_id_string = attach_special_energy_request_form.get_energy_card_id();
-        let energy_card_id = energy_card_id_string.parse::<i32>().unwrap();
+        let energy_card_id = attach_special_energy_request_form.get_energy_card_id();
 
         // 2. GameProtocolValidation Service 호출하여 Hand 에 있는지 확인하여 해킹 여부 검증
         let mut game_protocol_validation_service_guard =
@@ -335,8 +332,7 @@ impl GameCardEnergyController for GameCardEnergyControllerImpl {
 
         drop(game_card_energy_service_guard);
 
-        let unit_card_index_string = attach_special_energy_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = attach_special_energy_request_form.get_unit_card_index();
 
         // 6. Battle Field 유닛에 특수 에너지 붙이기
         let mut game_field_unit_service_guard =
@@ -27,16 +27,16 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 
 pub struct AttachSpecialEnergyCardRequestForm {
     session_id: String,
-    unit_card_index: String,
-    energy_card_id: String,
+    unit_card_index: i32,
+    energy_card_id: i32,
 }
 
 impl AttachSpecialEnergyCardRequestForm {
-    pub fn new(session_id: String, unit_card_index: String, energy_card_id: String) -> Self {
+    pub fn new(session_id: String, unit_card_index: i32, energy_card_id: i32) -> Self {
         AttachSpecialEnergyCardRequestForm {
             session_id: session_id.to_string(),
-            unit_card_index: unit_card_index.to_string(),
-            energy_card_id: energy_card_id.to_string(),
+            unit_card_index,
+            energy_card_id,
         }
     }
 
@@ -44,12 +44,12 @@ impl AttachSpecialEnergyCardRequestForm {
         &self.session_id
     }
 
-    pub fn get_unit_card_index(&self) -> &str {
-        &self.unit_card_index
+    pub fn get_unit_card_index(&self) -> i32 {
+        self.unit_card_index
     }
 
-    pub fn get_energy_card_id(&self) -> &str {
-        &self.energy_card_id
+    pub fn get_energy_card_id(&self) -> i32 {
+        self.energy_card_id
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
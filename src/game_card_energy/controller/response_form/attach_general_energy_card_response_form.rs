@@ -51,4 +51,6 @@ impl AttachGeneralEnergyCardResponseForm {
             false_message as i32,
             HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
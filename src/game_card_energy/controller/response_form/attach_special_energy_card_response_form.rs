@@ -58,4 +58,6 @@ impl AttachSpecialEnergyCardResponseForm {
             HashMap::new(),
             HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
@@ -1,4 +1,3 @@
 pub mod summary_energy_card_effect;
 pub mod energy_card;
-pub mod status_effect;
-pub mod effect;
\ No newline at end of file
+pub mod status_effect;
\ No newline at end of file
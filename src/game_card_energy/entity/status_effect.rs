@@ -1,15 +1,15 @@
-use crate::game_card_energy::entity::effect::Effect;
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct StatusEffect {
-    effect: Effect,
+    effect: ExtraEffect,
     status_duration_turn: i32,
     effect_damage: i32,
     reuse_turn: i32,
 }
 
 impl StatusEffect {
-    pub fn new(effect: Effect, status_duration_turn: i32, effect_damage: i32, reuse_turn: i32) -> StatusEffect {
+    pub fn new(effect: ExtraEffect, status_duration_turn: i32, effect_damage: i32, reuse_turn: i32) -> StatusEffect {
         StatusEffect {
             effect: effect.clone(),
             status_duration_turn,
@@ -18,7 +18,7 @@ impl StatusEffect {
         }
     }
 
-    pub fn get_effect(&self) -> &Effect {
+    pub fn get_effect(&self) -> &ExtraEffect {
         &self.effect
     }
 
@@ -41,7 +41,7 @@ mod tests {
 
     #[test]
     fn test_status_effect() {
-        let effect = Effect::Darkfire;
+        let effect = ExtraEffect::DarkFire;
         let status_duration_turn = 3;
         let effect_damage = 10;
 
@@ -51,8 +51,8 @@ mod tests {
         assert_eq!(status_effect.get_status_duration_turn(), status_duration_turn);
         assert_eq!(status_effect.get_effect_damage(), effect_damage);
 
-        println!("Effect: {:?}", status_effect.get_effect());
+        println!("ExtraEffect: {:?}", status_effect.get_effect());
         println!("Status Duration Turn: {}", status_effect.get_status_duration_turn());
-        println!("Effect Damage: {}", status_effect.get_effect_damage());
+        println!("ExtraEffect Damage: {}", status_effect.get_effect_damage());
     }
 }
@@ -27,14 +27,14 @@ impl SummaryEnergyCardEffect {
 
 #[cfg(test)]
 mod tests {
-    use crate::game_card_energy::entity::effect::Effect::Darkfire;
+    use crate::game_field_unit::entity::extra_effect::ExtraEffect::DarkFire;
     use super::*;
 
     #[test]
     fn test_summary_energy_card_effect() {
         let race = RaceEnum::Undead;
         let status_effects = vec![
-            StatusEffect::new(Darkfire, 3, 10, -1),
+            StatusEffect::new(DarkFire, 3, 10, -1),
         ];
         let summary_energy_card_effect = SummaryEnergyCardEffect::new(race, status_effects.clone());
 
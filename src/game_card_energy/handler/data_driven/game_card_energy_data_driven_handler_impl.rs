@@ -0,0 +1,30 @@
+use crate::card_effect_definition::entity::energy_effect_definition::EnergyEffectDefinition;
+use crate::game_card_energy::entity::status_effect::StatusEffect;
+use crate::game_card_energy::entity::summary_energy_card_effect::SummaryEnergyCardEffect;
+use crate::game_card_energy::handler::game_card_energy_handler::GameCardEnergyHandler;
+
+pub struct DataDrivenEnergyCardFunction {
+    energy_effect_definition: EnergyEffectDefinition,
+}
+
+impl DataDrivenEnergyCardFunction {
+    pub fn new(energy_effect_definition: EnergyEffectDefinition) -> Self {
+        DataDrivenEnergyCardFunction { energy_effect_definition }
+    }
+}
+
+impl GameCardEnergyHandler for DataDrivenEnergyCardFunction {
+    unsafe fn use_specific_energy_card(&self) -> SummaryEnergyCardEffect {
+        println!("DataDrivenEnergyCardFunction: use_specific_energy_card()");
+
+        let status_effect_list = self.energy_effect_definition.get_status_effects().iter()
+            .map(|status_effect_definition| StatusEffect::new(
+                status_effect_definition.get_effect().clone(),
+                status_effect_definition.get_duration_turn(),
+                status_effect_definition.get_effect_damage(),
+                status_effect_definition.get_reuse_turn()))
+            .collect();
+
+        SummaryEnergyCardEffect::new(self.energy_effect_definition.get_race(), status_effect_list)
+    }
+}
@@ -0,0 +1 @@
+pub mod game_card_energy_data_driven_handler_impl;
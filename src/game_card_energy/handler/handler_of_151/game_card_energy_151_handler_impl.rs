@@ -1,5 +1,5 @@
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
-use crate::game_card_energy::entity::effect::Effect;
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_card_energy::entity::status_effect::StatusEffect;
 use crate::game_card_energy::entity::summary_energy_card_effect::SummaryEnergyCardEffect;
 use crate::game_card_energy::handler::game_card_energy_handler::GameCardEnergyHandler;
@@ -12,8 +12,8 @@ impl GameCardEnergyHandler for EnergyCard_151_Function {
 
         SummaryEnergyCardEffect::new(RaceEnum::Undead,
                                      vec![
-                                         StatusEffect::new(Effect::Freeze, 1, -1, 2),
-                                         StatusEffect::new(Effect::Darkfire, 3, 10, -1)
+                                         StatusEffect::new(ExtraEffect::Freeze, 1, -1, 2),
+                                         StatusEffect::new(ExtraEffect::DarkFire, 3, 10, -1)
                                      ])
     }
 }
\ No newline at end of file
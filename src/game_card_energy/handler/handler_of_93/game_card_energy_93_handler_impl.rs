@@ -1,5 +1,5 @@
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
-use crate::game_card_energy::entity::effect::Effect;
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_card_energy::entity::status_effect::StatusEffect;
 use crate::game_card_energy::entity::summary_energy_card_effect::SummaryEnergyCardEffect;
 use crate::game_card_energy::handler::game_card_energy_handler::GameCardEnergyHandler;
@@ -11,6 +11,6 @@ impl GameCardEnergyHandler for EnergyCard_93_Function {
         println!("EnergyCard_93_Function: use_specific_energy_card()");
 
         SummaryEnergyCardEffect::new(RaceEnum::Undead,
-                                     vec![StatusEffect::new(Effect::Dummy, -1, -1, -1)])
+                                     vec![StatusEffect::new(ExtraEffect::Dummy, -1, -1, -1)])
     }
 }
\ No newline at end of file
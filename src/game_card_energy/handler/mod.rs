@@ -1,3 +1,4 @@
 pub mod game_card_energy_handler;
 pub mod handler_of_93;
-pub mod handler_of_151;
\ No newline at end of file
+pub mod handler_of_151;
+pub mod data_driven;
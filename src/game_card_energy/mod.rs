@@ -2,4 +2,5 @@ pub mod controller;
 pub mod service;
 pub mod repository;
 pub mod handler;
-pub mod entity;
\ No newline at end of file
+pub mod entity;
+pub mod protocol;
@@ -0,0 +1,26 @@
+use crate::game_card_energy::controller::game_card_energy_controller::GameCardEnergyController;
+use crate::game_card_energy::controller::game_card_energy_controller_impl::GameCardEnergyControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::attach_general_energy_card_request_form_generator::create_attach_general_energy_card_request_form;
+use crate::request_generator::attach_special_energy_card_request_form_generator::create_attach_special_energy_card_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_energy_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Energy Card Usage
+    protocol_registry.register_battle_room_action(1010, "ATTACH_GENERAL_ENERGY", create_attach_general_energy_card_request_form, |request| async move {
+        let game_card_energy_controller_mutex = GameCardEnergyControllerImpl::get_instance();
+        let game_card_energy_controller = game_card_energy_controller_mutex.lock().await;
+
+        let response = game_card_energy_controller.request_to_attach_general_energy(request).await;
+        ResponseType::ATTACH_GENERAL_ENERGY(response)
+    });
+
+    // Special Energy Card Usage
+    protocol_registry.register_battle_room_action(1012, "ATTACH_SPECIAL_ENERGY", create_attach_special_energy_card_request_form, |request| async move {
+        let game_card_energy_controller_mutex = GameCardEnergyControllerImpl::get_instance();
+        let game_card_energy_controller = game_card_energy_controller_mutex.lock().await;
+
+        let response = game_card_energy_controller.request_to_attach_special_energy(request).await;
+        ResponseType::ATTACH_SPECIAL_ENERGY(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod game_card_energy_protocol;
@@ -5,11 +5,14 @@ use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
 
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
-use crate::game_card_energy::entity::effect::Effect;
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_card_energy::entity::status_effect::StatusEffect;
 use crate::game_card_energy::entity::summary_energy_card_effect::SummaryEnergyCardEffect;
 
 use crate::game_card_energy::handler::game_card_energy_handler::GameCardEnergyHandler;
+use crate::game_card_energy::handler::data_driven::game_card_energy_data_driven_handler_impl::DataDrivenEnergyCardFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 use crate::game_card_energy::handler::handler_of_151::game_card_energy_151_handler_impl::EnergyCard_151_Function;
 use crate::game_card_energy::handler::handler_of_93::game_card_energy_93_handler_impl::EnergyCard_93_Function;
 use crate::game_card_energy::repository::game_card_energy_repository::GameCardEnergyRepository;
@@ -25,7 +28,7 @@ impl GameCardEnergyHandler for NoneEnergyFunction {
         println!("아직 구현되지 않은 기능입니다.");
 
         SummaryEnergyCardEffect::new(
-            RaceEnum::Dummy, vec![StatusEffect::new(Effect::Dummy, -1, -1, -1)])
+            RaceEnum::Dummy, vec![StatusEffect::new(ExtraEffect::Dummy, -1, -1, -1)])
     }
 }
 
@@ -41,6 +44,13 @@ impl GameCardEnergyRepositoryImpl {
         energy_card_functions.insert(151, Box::new(EnergyCard_151_Function) as Box<dyn GameCardEnergyHandler>);
         energy_card_functions.insert(185, Box::new(NoneEnergyFunction) as Box<dyn GameCardEnergyHandler>);
 
+        // 카드 효과 정의 파일에 있는 카드는 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (energy_card_number, energy_effect_definition) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_energy_effect_definition_map() {
+            energy_card_functions.insert(*energy_card_number, Box::new(DataDrivenEnergyCardFunction::new(energy_effect_definition.clone())) as Box<dyn GameCardEnergyHandler>);
+        }
+
         GameCardEnergyRepositoryImpl { energy_card_functions }
     }
 
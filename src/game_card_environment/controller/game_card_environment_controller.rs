@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::game_card_environment::controller::request_form::deploy_environment_card_request_form::DeployEnvironmentCardRequestForm;
+use crate::game_card_environment::controller::response_form::deploy_environment_card_response_form::DeployEnvironmentCardResponseForm;
+
+#[async_trait]
+pub trait GameCardEnvironmentController {
+    async fn request_to_deploy_environment_card(
+        &self, deploy_environment_card_request_form: DeployEnvironmentCardRequestForm) -> DeployEnvironmentCardResponseForm;
+}
\ No newline at end of file
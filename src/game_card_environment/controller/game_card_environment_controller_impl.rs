@@ -0,0 +1,268 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::common::message::false_message_enum::FalseMessage::{MythicalCardRoundLimit, NotYourTurn};
+use crate::game_card_environment::controller::game_card_environment_controller::GameCardEnvironmentController;
+use crate::game_card_environment::controller::request_form::deploy_environment_card_request_form::DeployEnvironmentCardRequestForm;
+use crate::game_card_environment::controller::response_form::deploy_environment_card_response_form::DeployEnvironmentCardResponseForm;
+use crate::game_card_environment::service::game_card_environment_service::GameCardEnvironmentService;
+use crate::game_card_environment::service::game_card_environment_service_impl::GameCardEnvironmentServiceImpl;
+use crate::game_hand::service::game_hand_service::GameHandService;
+use crate::game_hand::service::game_hand_service_impl::GameHandServiceImpl;
+use crate::game_protocol_validation::service::game_protocol_validation_service::GameProtocolValidationService;
+use crate::game_protocol_validation::service::game_protocol_validation_service_impl::GameProtocolValidationServiceImpl;
+use crate::game_protocol_validation::service::request::can_use_card_request::CanUseCardRequest;
+use crate::game_protocol_validation::service::request::check_protocol_hacking_request::CheckProtocolHackingRequest;
+use crate::game_protocol_validation::service::request::is_it_environment_card_request::IsItEnvironmentCardRequest;
+use crate::game_tomb::service::game_tomb_service::GameTombService;
+use crate::game_tomb::service::game_tomb_service_impl::GameTombServiceImpl;
+use crate::notify_player_action_info::service::notify_player_action_info_service::NotifyPlayerActionInfoService;
+use crate::notify_player_action_info::service::notify_player_action_info_service_impl::NotifyPlayerActionInfoServiceImpl;
+use crate::redis::service::redis_in_memory_service::RedisInMemoryService;
+use crate::redis::service::redis_in_memory_service_impl::RedisInMemoryServiceImpl;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+use crate::ui_data_generator::service::ui_data_generator_service::UiDataGeneratorService;
+use crate::ui_data_generator::service::ui_data_generator_service_impl::UiDataGeneratorServiceImpl;
+
+pub struct GameCardEnvironmentControllerImpl {
+    game_hand_service: Arc<AsyncMutex<GameHandServiceImpl>>,
+    battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+    game_card_environment_service: Arc<AsyncMutex<GameCardEnvironmentServiceImpl>>,
+    game_tomb_service: Arc<AsyncMutex<GameTombServiceImpl>>,
+    game_protocol_validation_service: Arc<AsyncMutex<GameProtocolValidationServiceImpl>>,
+    redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+    notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+    ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+}
+
+impl GameCardEnvironmentControllerImpl {
+    pub fn new(game_hand_service: Arc<AsyncMutex<GameHandServiceImpl>>,
+               battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+               game_card_environment_service: Arc<AsyncMutex<GameCardEnvironmentServiceImpl>>,
+               game_tomb_service: Arc<AsyncMutex<GameTombServiceImpl>>,
+               game_protocol_validation_service: Arc<AsyncMutex<GameProtocolValidationServiceImpl>>,
+               redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+               notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+               ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+    ) -> Self {
+
+        GameCardEnvironmentControllerImpl {
+            game_hand_service,
+            battle_room_service,
+            game_card_environment_service,
+            game_tomb_service,
+            game_protocol_validation_service,
+            redis_in_memory_service,
+            notify_player_action_info_service,
+            ui_data_generator_service,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardEnvironmentControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardEnvironmentControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardEnvironmentControllerImpl::new(
+                            GameHandServiceImpl::get_instance(),
+                            BattleRoomServiceImpl::get_instance(),
+                            GameCardEnvironmentServiceImpl::get_instance(),
+                            GameTombServiceImpl::get_instance(),
+                            GameProtocolValidationServiceImpl::get_instance(),
+                            RedisInMemoryServiceImpl::get_instance(),
+                            NotifyPlayerActionInfoServiceImpl::get_instance(),
+                            UiDataGeneratorServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn is_valid_session(&self, request: GetValueWithKeyRequest) -> i32 {
+        let redis_in_memory_service_guard = self.redis_in_memory_service.lock().await;
+        let session_validation_response = redis_in_memory_service_guard.get_value_with_key(request).await;
+        drop(redis_in_memory_service_guard);
+        let value_string = session_validation_response.get_value();
+        value_string.parse::<i32>().unwrap_or_else(|_| { -1 })
+    }
+
+    async fn is_valid_protocol(&self, check_protocol_hacking_request: CheckProtocolHackingRequest) -> bool {
+        let mut game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let check_protocol_hacking_response = game_protocol_validation_service_guard.check_protocol_hacking(check_protocol_hacking_request).await;
+        drop(game_protocol_validation_service_guard);
+        check_protocol_hacking_response.is_success()
+    }
+
+    async fn is_it_environment_card(&self, is_it_environment_card_request: IsItEnvironmentCardRequest) -> bool {
+        let game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let is_it_environment_card_response = game_protocol_validation_service_guard.is_it_environment_card(is_it_environment_card_request).await;
+        drop(game_protocol_validation_service_guard);
+        is_it_environment_card_response.is_success()
+    }
+
+    async fn is_able_to_use(&self, can_use_card_request: CanUseCardRequest) -> bool {
+        let mut game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let can_use_card_response = game_protocol_validation_service_guard.can_use_card(can_use_card_request).await;
+        drop(game_protocol_validation_service_guard);
+        can_use_card_response.is_success()
+    }
+}
+
+#[async_trait]
+impl GameCardEnvironmentController for GameCardEnvironmentControllerImpl {
+    async fn request_to_deploy_environment_card(
+        &self, deploy_environment_card_request_form: DeployEnvironmentCardRequestForm) -> DeployEnvironmentCardResponseForm {
+
+        println!("GameCardEnvironmentControllerImpl: request_to_deploy_environment_card()");
+
+        // 1. Redis 에서 토큰을 가지고 있는지 검증
+        let account_unique_id = self.is_valid_session(
+            deploy_environment_card_request_form
+                .to_session_validation_request()).await;
+
+        if account_unique_id == -1 {
+            println!("Invalid session");
+            return DeployEnvironmentCardResponseForm::default()
+        }
+
+        // 2. 자신의 턴인지 검증
+        let game_protocol_validation_service_guard =
+            self.game_protocol_validation_service.lock().await;
+
+        let is_this_your_turn_response =
+            game_protocol_validation_service_guard.is_this_your_turn(
+                deploy_environment_card_request_form
+                    .to_is_this_your_turn_request(account_unique_id)).await;
+
+        if !is_this_your_turn_response.is_success() {
+            println!("당신의 턴이 아닙니다.");
+            return DeployEnvironmentCardResponseForm::from_false_response_with_message(NotYourTurn)
+        }
+
+        drop(game_protocol_validation_service_guard);
+
+        let environment_card_id = deploy_environment_card_request_form.get_environment_card_id();
+
+        // 3. Hand 에 있는지 확인하여 해킹 여부 검증
+        let check_protocol_hacking_response = self.is_valid_protocol(
+            deploy_environment_card_request_form
+                .to_check_protocol_hacking_request(account_unique_id, environment_card_id)).await;
+
+        if !check_protocol_hacking_response {
+            println!("해킹범을 검거합니다!");
+            return DeployEnvironmentCardResponseForm::default()
+        }
+
+        // 4. 실제 환경 카드가 맞는지 확인
+        let is_it_environment_response = self.is_it_environment_card(
+            deploy_environment_card_request_form
+                .to_is_it_environment_card_request(environment_card_id)).await;
+
+        if !is_it_environment_response {
+            println!("환경 카드가 아닌데 요청이 왔으므로 당신도 해킹범입니다.");
+            return DeployEnvironmentCardResponseForm::default()
+        }
+
+        // 5. 신화 카드 라운드 제약 검사
+        let can_use_card_response = self.is_able_to_use(
+            deploy_environment_card_request_form
+                .to_can_use_card_request(account_unique_id, environment_card_id)).await;
+
+        if !can_use_card_response {
+            println!("신화 카드는 4라운드 이후부터 사용 할 수 있습니다!");
+            return DeployEnvironmentCardResponseForm::from_false_response_with_message(MythicalCardRoundLimit)
+        }
+
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let opponent_unique_id =
+            battle_room_service_guard.find_opponent_by_account_unique_id(
+                deploy_environment_card_request_form
+                    .to_find_opponent_by_account_id_request(account_unique_id)).await.get_opponent_unique_id();
+
+        drop(battle_room_service_guard);
+
+        if opponent_unique_id == -1 {
+            return DeployEnvironmentCardResponseForm::default()
+        }
+
+        // 6. 공유 환경 슬롯에 배치 (기존 환경은 대체되며 수정치가 양 플레이어에게 적용됨)
+        let mut game_card_environment_service_guard =
+            self.game_card_environment_service.lock().await;
+
+        let deploy_environment_card_response =
+            game_card_environment_service_guard.deploy_environment_card(
+                deploy_environment_card_request_form
+                    .to_deploy_environment_card_request(
+                        account_unique_id, opponent_unique_id, environment_card_id)).await;
+
+        drop(game_card_environment_service_guard);
+
+        if !deploy_environment_card_response.is_success() {
+            println!("효과가 정의되지 않은 환경 카드입니다.");
+            return DeployEnvironmentCardResponseForm::default()
+        }
+
+        // 7. Hand 에서 환경 카드 사용 처리 (환경은 만료되거나 대체될 때까지 무덤으로 가지 않음)
+        let mut game_hand_service_guard =
+            self.game_hand_service.lock().await;
+
+        let used_environment_card_id =
+            game_hand_service_guard.use_environment_card(
+                deploy_environment_card_request_form
+                    .to_use_game_hand_environment_card_request(
+                        account_unique_id, environment_card_id)).await.get_found_environment_card_id();
+
+        drop(game_hand_service_guard);
+
+        // 8. 대체된 환경 카드는 원래 주인의 무덤으로 이동
+        let replaced_environment_card_id =
+            deploy_environment_card_response.get_replaced_environment_card_id();
+
+        if replaced_environment_card_id != -1 {
+            let mut game_tomb_service_guard =
+                self.game_tomb_service.lock().await;
+
+            game_tomb_service_guard.add_used_card_to_tomb(
+                deploy_environment_card_request_form
+                    .to_place_to_tomb_request(
+                        deploy_environment_card_response.get_replaced_environment_owner_unique_id(),
+                        replaced_environment_card_id)).await;
+
+            drop(game_tomb_service_guard);
+        }
+
+        // 9. 상대에게 환경 카드 배치 사실을 공지
+        let mut ui_data_generator_service_guard =
+            self.ui_data_generator_service.lock().await;
+
+        let generate_use_my_hand_card_data_response =
+            ui_data_generator_service_guard.generate_use_my_hand_card_data(
+                deploy_environment_card_request_form
+                    .to_generate_use_my_hand_card_data_request(used_environment_card_id)).await;
+
+        drop(ui_data_generator_service_guard);
+
+        let mut notify_player_action_info_service_guard =
+            self.notify_player_action_info_service.lock().await;
+
+        let notice_response =
+            notify_player_action_info_service_guard.notice_deploy_environment_card(
+                deploy_environment_card_request_form
+                    .to_notice_deploy_environment_card_request(
+                        opponent_unique_id,
+                        generate_use_my_hand_card_data_response
+                            .get_player_hand_use_map_for_notice().clone())).await;
+
+        println!("notice_response: {:?}", notice_response);
+
+        drop(notify_player_action_info_service_guard);
+
+        DeployEnvironmentCardResponseForm::new(
+            generate_use_my_hand_card_data_response.is_success_for_response(), -1)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod request_form;
+pub mod response_form;
+pub mod game_card_environment_controller;
+pub mod game_card_environment_controller_impl;
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_card_environment::service::request::deploy_environment_card_request::DeployEnvironmentCardRequest;
+use crate::game_hand::service::request::use_game_hand_environment_card_request::UseGameHandEnvironmentCardRequest;
+use crate::game_protocol_validation::service::request::can_use_card_request::CanUseCardRequest;
+use crate::game_protocol_validation::service::request::check_protocol_hacking_request::CheckProtocolHackingRequest;
+use crate::game_protocol_validation::service::request::is_it_environment_card_request::IsItEnvironmentCardRequest;
+use crate::game_protocol_validation::service::request::is_this_your_turn_request::IsThisYourTurnRequest;
+use crate::game_tomb::service::request::place_to_tomb_request::PlaceToTombRequest;
+use crate::notify_player_action_info::service::request::notice_deploy_environment_card_request::NoticeDeployEnvironmentCardRequest;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_request::GenerateUseMyHandCardDataRequest;
+
+#[derive(Debug)]
+pub struct DeployEnvironmentCardRequestForm {
+    session_id: String,
+    environment_card_id: i32,
+}
+
+impl DeployEnvironmentCardRequestForm {
+    pub fn new(session_id: &str, environment_card_id: i32) -> Self {
+        DeployEnvironmentCardRequestForm {
+            session_id: session_id.to_string(),
+            environment_card_id,
+        }
+    }
+
+    pub fn get_environment_card_id(&self) -> i32 { self.environment_card_id }
+
+    pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
+        GetValueWithKeyRequest::new(self.session_id.clone().as_str())
+    }
+
+    pub fn to_is_this_your_turn_request(&self,
+                                        account_unique_id: i32) -> IsThisYourTurnRequest {
+        IsThisYourTurnRequest::new(account_unique_id)
+    }
+
+    pub fn to_check_protocol_hacking_request(&self,
+                                             account_unique_id: i32,
+                                             environment_card_id: i32) -> CheckProtocolHackingRequest {
+        CheckProtocolHackingRequest::new(account_unique_id, environment_card_id)
+    }
+
+    pub fn to_is_it_environment_card_request(&self,
+                                             environment_card_id: i32) -> IsItEnvironmentCardRequest {
+        IsItEnvironmentCardRequest::new(environment_card_id)
+    }
+
+    pub fn to_can_use_card_request(&self,
+                                   account_unique_id: i32,
+                                   environment_card_id: i32) -> CanUseCardRequest {
+        CanUseCardRequest::new(account_unique_id, environment_card_id)
+    }
+
+    pub fn to_find_opponent_by_account_id_request(&self,
+                                                  account_unique_id: i32) -> FindOpponentByAccountIdRequest {
+        FindOpponentByAccountIdRequest::new(account_unique_id)
+    }
+
+    pub fn to_deploy_environment_card_request(&self,
+                                              account_unique_id: i32,
+                                              opponent_unique_id: i32,
+                                              environment_card_id: i32) -> DeployEnvironmentCardRequest {
+        DeployEnvironmentCardRequest::new(account_unique_id, opponent_unique_id, environment_card_id)
+    }
+
+    pub fn to_use_game_hand_environment_card_request(&self,
+                                                     account_unique_id: i32,
+                                                     environment_card_id: i32) -> UseGameHandEnvironmentCardRequest {
+        UseGameHandEnvironmentCardRequest::new(account_unique_id, environment_card_id)
+    }
+
+    pub fn to_place_to_tomb_request(&self,
+                                    account_unique_id: i32,
+                                    used_card_id: i32) -> PlaceToTombRequest {
+        PlaceToTombRequest::new(account_unique_id, used_card_id)
+    }
+
+    pub fn to_generate_use_my_hand_card_data_request(&self,
+                                                     used_hand_card_id: i32) -> GenerateUseMyHandCardDataRequest {
+        GenerateUseMyHandCardDataRequest::new(used_hand_card_id)
+    }
+
+    pub fn to_notice_deploy_environment_card_request(
+        &self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> NoticeDeployEnvironmentCardRequest {
+
+        NoticeDeployEnvironmentCardRequest::new(
+            opponent_unique_id,
+            player_hand_use_map_for_notice)
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod deploy_environment_card_response_form;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+
+// 환경 카드가 필드에 유지되는 동안 양 플레이어에게 공통으로 적용되는 보정치
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvironmentModifier {
+    target_race: RaceEnum,
+    unit_attack_bonus: i32,
+    extra_field_energy_per_turn: i32,
+    extra_draw_per_turn: i32,
+    status_effect_damage_bonus: i32,
+}
+
+impl EnvironmentModifier {
+    pub fn new() -> Self {
+        EnvironmentModifier {
+            target_race: RaceEnum::Dummy,
+            unit_attack_bonus: 0,
+            extra_field_energy_per_turn: 0,
+            extra_draw_per_turn: 0,
+            status_effect_damage_bonus: 0,
+        }
+    }
+
+    // target_race 가 Dummy 라면 모든 종족에 공격력 보정을 적용
+    pub fn get_target_race(&self) -> RaceEnum { self.target_race }
+    pub fn set_target_race(&mut self, target_race: RaceEnum) {
+        self.target_race = target_race;
+    }
+
+    pub fn get_unit_attack_bonus(&self) -> i32 { self.unit_attack_bonus }
+    pub fn set_unit_attack_bonus(&mut self, unit_attack_bonus: i32) {
+        self.unit_attack_bonus = unit_attack_bonus;
+    }
+
+    pub fn get_extra_field_energy_per_turn(&self) -> i32 { self.extra_field_energy_per_turn }
+    pub fn set_extra_field_energy_per_turn(&mut self, extra_field_energy_per_turn: i32) {
+        self.extra_field_energy_per_turn = extra_field_energy_per_turn;
+    }
+
+    pub fn get_extra_draw_per_turn(&self) -> i32 { self.extra_draw_per_turn }
+    pub fn set_extra_draw_per_turn(&mut self, extra_draw_per_turn: i32) {
+        self.extra_draw_per_turn = extra_draw_per_turn;
+    }
+
+    pub fn get_status_effect_damage_bonus(&self) -> i32 { self.status_effect_damage_bonus }
+    pub fn set_status_effect_damage_bonus(&mut self, status_effect_damage_bonus: i32) {
+        self.status_effect_damage_bonus = status_effect_damage_bonus;
+    }
+
+    pub fn get_unit_attack_bonus_of_race(&self, unit_race: RaceEnum) -> i32 {
+        if self.target_race == RaceEnum::Dummy || self.target_race == unit_race {
+            return self.unit_attack_bonus
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_unit_attack_bonus_of_race() {
+        let mut environment_modifier = EnvironmentModifier::new();
+        environment_modifier.set_unit_attack_bonus(5);
+        assert_eq!(environment_modifier.get_unit_attack_bonus_of_race(RaceEnum::Human), 5);
+
+        environment_modifier.set_target_race(RaceEnum::Undead);
+        assert_eq!(environment_modifier.get_unit_attack_bonus_of_race(RaceEnum::Undead), 5);
+        assert_eq!(environment_modifier.get_unit_attack_bonus_of_race(RaceEnum::Human), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::card_effect_definition::entity::environment_effect_definition::EnvironmentEffectDefinition;
+use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameCardEnvironmentEffect {
+    duration_turn: i32,
+    environment_modifier: EnvironmentModifier,
+}
+
+impl GameCardEnvironmentEffect {
+    pub fn new(duration_turn: i32, environment_modifier: EnvironmentModifier) -> Self {
+        GameCardEnvironmentEffect {
+            duration_turn,
+            environment_modifier,
+        }
+    }
+
+    pub fn from_definition(environment_effect_definition: &EnvironmentEffectDefinition) -> Self {
+        let mut environment_modifier = EnvironmentModifier::new();
+
+        if let Some(target_race) = environment_effect_definition.get_target_race() {
+            environment_modifier.set_target_race(target_race);
+        }
+        if let Some(unit_attack_bonus) = environment_effect_definition.get_unit_attack_bonus() {
+            environment_modifier.set_unit_attack_bonus(unit_attack_bonus);
+        }
+        if let Some(extra_field_energy_per_turn) = environment_effect_definition.get_extra_field_energy_per_turn() {
+            environment_modifier.set_extra_field_energy_per_turn(extra_field_energy_per_turn);
+        }
+        if let Some(extra_draw_per_turn) = environment_effect_definition.get_extra_draw_per_turn() {
+            environment_modifier.set_extra_draw_per_turn(extra_draw_per_turn);
+        }
+        if let Some(status_effect_damage_bonus) = environment_effect_definition.get_status_effect_damage_bonus() {
+            environment_modifier.set_status_effect_damage_bonus(status_effect_damage_bonus);
+        }
+
+        GameCardEnvironmentEffect::new(environment_effect_definition.get_duration_turn(), environment_modifier)
+    }
+
+    // 양 플레이어의 턴 종료를 모두 1턴으로 계산
+    pub fn get_duration_turn(&self) -> i32 { self.duration_turn }
+
+    pub fn get_environment_modifier(&self) -> &EnvironmentModifier { &self.environment_modifier }
+}
\ No newline at end of file
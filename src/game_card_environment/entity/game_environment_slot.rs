@@ -0,0 +1,33 @@
+// 양 플레이어가 공유하는 환경 슬롯 (새 환경 카드가 배치되면 이전 카드를 대체)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEnvironmentSlot {
+    environment_card_id: i32,
+    owner_unique_id: i32,
+    remaining_turn: i32,
+}
+
+impl GameEnvironmentSlot {
+    pub fn new(environment_card_id: i32, owner_unique_id: i32, remaining_turn: i32) -> GameEnvironmentSlot {
+        GameEnvironmentSlot {
+            environment_card_id,
+            owner_unique_id,
+            remaining_turn,
+        }
+    }
+
+    pub fn get_environment_card_id(&self) -> i32 { self.environment_card_id }
+
+    pub fn get_owner_unique_id(&self) -> i32 { self.owner_unique_id }
+
+    pub fn get_remaining_turn(&self) -> i32 { self.remaining_turn }
+
+    pub fn decrease_remaining_turn(&mut self) {
+        if self.remaining_turn > 0 {
+            self.remaining_turn -= 1;
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining_turn <= 0
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod environment_modifier;
+pub mod game_card_environment_effect;
+pub mod game_environment_slot;
\ No newline at end of file
@@ -0,0 +1,16 @@
+use crate::game_card_environment::controller::game_card_environment_controller::GameCardEnvironmentController;
+use crate::game_card_environment::controller::game_card_environment_controller_impl::GameCardEnvironmentControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::deploy_environment_card_request_form_generator::create_deploy_environment_card_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_environment_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Deploy Environment Card to Shared Slot
+    protocol_registry.register_battle_room_action(1019, "DEPLOY_ENVIRONMENT_CARD", create_deploy_environment_card_request_form, |request| async move {
+        let game_card_environment_controller_mutex = GameCardEnvironmentControllerImpl::get_instance();
+        let game_card_environment_controller = game_card_environment_controller_mutex.lock().await;
+
+        let response = game_card_environment_controller.request_to_deploy_environment_card(request).await;
+        ResponseType::DEPLOY_ENVIRONMENT_CARD(response)
+    });
+}
\ No newline at end of file
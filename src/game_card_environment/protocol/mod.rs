@@ -0,0 +1 @@
+pub mod game_card_environment_protocol;
\ No newline at end of file
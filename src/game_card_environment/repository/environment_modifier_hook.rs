@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+use crate::game_card_environment::entity::game_card_environment_effect::GameCardEnvironmentEffect;
+use crate::game_card_environment::entity::game_environment_slot::GameEnvironmentSlot;
+
+// 환경 슬롯의 원본은 GameCardEnvironmentRepositoryImpl 이 갖고,
+// 유닛/필드 에너지 저장소는 이 hook 으로 현재 보정치를 조회만 한다 (동기 계산 경로에서 사용하므로 std Mutex)
+#[derive(Clone)]
+pub struct EnvironmentModifierHook {
+    environment_card_effect_map: Arc<HashMap<i32, GameCardEnvironmentEffect>>,
+    game_environment_slot_map: Arc<Mutex<HashMap<i32, GameEnvironmentSlot>>>,
+}
+
+impl EnvironmentModifierHook {
+    pub fn new(environment_card_effect_map: HashMap<i32, GameCardEnvironmentEffect>) -> Self {
+        EnvironmentModifierHook {
+            environment_card_effect_map: Arc::new(environment_card_effect_map),
+            game_environment_slot_map: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn find_environment_modifier(&self, account_unique_id: i32) -> Option<EnvironmentModifier> {
+        let game_environment_slot_map_guard = self.game_environment_slot_map.lock().unwrap();
+        let game_environment_slot = game_environment_slot_map_guard.get(&account_unique_id)?;
+        let environment_card_effect = self.environment_card_effect_map.get(&game_environment_slot.get_environment_card_id())?;
+
+        Some(environment_card_effect.get_environment_modifier().clone())
+    }
+
+    pub(super) fn get_environment_card_effect_map(&self) -> &HashMap<i32, GameCardEnvironmentEffect> {
+        &self.environment_card_effect_map
+    }
+
+    pub(super) fn get_game_environment_slot_map(&self) -> &Mutex<HashMap<i32, GameEnvironmentSlot>> {
+        &self.game_environment_slot_map
+    }
+}
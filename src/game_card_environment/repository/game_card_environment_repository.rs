@@ -0,0 +1,10 @@
+use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+use crate::game_card_environment::entity::game_environment_slot::GameEnvironmentSlot;
+
+pub trait GameCardEnvironmentRepository {
+    fn is_registered_environment_card(&self, environment_card_id: i32) -> bool;
+    fn deploy_environment_card(&mut self, account_unique_id: i32, opponent_unique_id: i32, environment_card_id: i32) -> Option<GameEnvironmentSlot>;
+    fn get_environment_modifier(&self, account_unique_id: i32) -> Option<EnvironmentModifier>;
+    fn decrease_environment_remaining_turn(&mut self, account_unique_id: i32, opponent_unique_id: i32) -> Option<GameEnvironmentSlot>;
+    fn remove_game_environment_slot_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool;
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
+use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+use crate::game_card_environment::entity::game_card_environment_effect::GameCardEnvironmentEffect;
+use crate::game_card_environment::entity::game_environment_slot::GameEnvironmentSlot;
+use crate::game_card_environment::repository::environment_modifier_hook::EnvironmentModifierHook;
+use crate::game_card_environment::repository::game_card_environment_repository::GameCardEnvironmentRepository;
+
+pub struct GameCardEnvironmentRepositoryImpl {
+    // 환경 슬롯은 양 플레이어가 공유하므로 두 계정 모두에 같은 슬롯을 기록
+    // 유닛/필드 에너지 저장소와 같은 hook 을 공유하여 보정치의 원본을 여기 한 곳에만 둔다
+    environment_modifier_hook: EnvironmentModifierHook,
+}
+
+impl GameCardEnvironmentRepositoryImpl {
+    fn new() -> Self {
+        GameCardEnvironmentRepositoryImpl::with_environment_modifier_hook(
+            GameCardEnvironmentRepositoryImpl::get_environment_modifier_hook())
+    }
+
+    pub(crate) fn with_environment_modifier_hook(environment_modifier_hook: EnvironmentModifierHook) -> Self {
+        GameCardEnvironmentRepositoryImpl {
+            environment_modifier_hook,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn to_environment_modifier_hook(&self) -> EnvironmentModifierHook {
+        self.environment_modifier_hook.clone()
+    }
+
+    pub(crate) fn get_game_environment_slot_map(&self) -> HashMap<i32, GameEnvironmentSlot> {
+        self.environment_modifier_hook.get_game_environment_slot_map().lock().unwrap().clone()
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardEnvironmentRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+
+    // 유닛/필드 에너지 저장소는 동기 생성자에서 이 hook 을 받아 보정치를 조회
+    pub fn get_environment_modifier_hook() -> EnvironmentModifierHook {
+        lazy_static! {
+            static ref ENVIRONMENT_MODIFIER_HOOK: EnvironmentModifierHook = {
+                let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+                let environment_card_effect_map = card_effect_definition_repository
+                    .get_card_effect_definition_table().get_environment_effect_definition_map().iter()
+                    .map(|(environment_card_id, environment_effect_definition)|
+                        (*environment_card_id, GameCardEnvironmentEffect::from_definition(environment_effect_definition)))
+                    .collect();
+
+                EnvironmentModifierHook::new(environment_card_effect_map)
+            };
+        }
+        ENVIRONMENT_MODIFIER_HOOK.clone()
+    }
+}
+
+impl GameCardEnvironmentRepository for GameCardEnvironmentRepositoryImpl {
+    fn is_registered_environment_card(&self, environment_card_id: i32) -> bool {
+        self.environment_modifier_hook.get_environment_card_effect_map().contains_key(&environment_card_id)
+    }
+
+    fn deploy_environment_card(&mut self, account_unique_id: i32, opponent_unique_id: i32, environment_card_id: i32) -> Option<GameEnvironmentSlot> {
+        println!("GameCardEnvironmentRepositoryImpl: deploy_environment_card()");
+
+        let duration_turn = self.environment_modifier_hook.get_environment_card_effect_map()
+            .get(&environment_card_id)?.get_duration_turn();
+        let new_game_environment_slot = GameEnvironmentSlot::new(environment_card_id, account_unique_id, duration_turn);
+
+        let mut game_environment_slot_map_guard =
+            self.environment_modifier_hook.get_game_environment_slot_map().lock().unwrap();
+
+        game_environment_slot_map_guard.insert(opponent_unique_id, new_game_environment_slot.clone());
+        game_environment_slot_map_guard.insert(account_unique_id, new_game_environment_slot)
+    }
+
+    fn get_environment_modifier(&self, account_unique_id: i32) -> Option<EnvironmentModifier> {
+        self.environment_modifier_hook.find_environment_modifier(account_unique_id)
+    }
+
+    fn decrease_environment_remaining_turn(&mut self, account_unique_id: i32, opponent_unique_id: i32) -> Option<GameEnvironmentSlot> {
+        println!("GameCardEnvironmentRepositoryImpl: decrease_environment_remaining_turn()");
+
+        let mut game_environment_slot_map_guard =
+            self.environment_modifier_hook.get_game_environment_slot_map().lock().unwrap();
+
+        let game_environment_slot = game_environment_slot_map_guard.get_mut(&account_unique_id)?;
+        game_environment_slot.decrease_remaining_turn();
+
+        let updated_game_environment_slot = game_environment_slot.clone();
+
+        if !updated_game_environment_slot.is_expired() {
+            game_environment_slot_map_guard.insert(opponent_unique_id, updated_game_environment_slot);
+            return None
+        }
+
+        game_environment_slot_map_guard.remove(&opponent_unique_id);
+        game_environment_slot_map_guard.remove(&account_unique_id)
+    }
+
+    fn remove_game_environment_slot_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool {
+        self.environment_modifier_hook.get_game_environment_slot_map().lock().unwrap()
+            .remove(&account_unique_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deploy_environment_card_replaces_previous_one() {
+        let mut draw_environment_modifier = EnvironmentModifier::new();
+        draw_environment_modifier.set_extra_draw_per_turn(1);
+
+        let mut repository = GameCardEnvironmentRepositoryImpl::with_environment_modifier_hook(
+            EnvironmentModifierHook::new(HashMap::from([
+                (45, GameCardEnvironmentEffect::new(2, draw_environment_modifier.clone())),
+                (46, GameCardEnvironmentEffect::new(3, EnvironmentModifier::new()))])));
+
+        assert_eq!(repository.deploy_environment_card(1, 2, 99), None);
+        assert_eq!(repository.deploy_environment_card(1, 2, 46), None);
+        assert_eq!(repository.deploy_environment_card(2, 1, 45), Some(GameEnvironmentSlot::new(46, 1, 3)));
+
+        assert_eq!(repository.get_environment_modifier(1), Some(draw_environment_modifier.clone()));
+        assert_eq!(repository.get_environment_modifier(2), Some(draw_environment_modifier));
+    }
+
+    #[test]
+    fn test_decrease_environment_remaining_turn_until_expired() {
+        let mut repository = GameCardEnvironmentRepositoryImpl::with_environment_modifier_hook(
+            EnvironmentModifierHook::new(HashMap::from([(45, GameCardEnvironmentEffect::new(2, EnvironmentModifier::new()))])));
+
+        repository.deploy_environment_card(1, 2, 45);
+
+        assert_eq!(repository.decrease_environment_remaining_turn(1, 2), None);
+        assert_eq!(repository.get_game_environment_slot_map().get(&2).unwrap().get_remaining_turn(), 1);
+        assert_eq!(repository.decrease_environment_remaining_turn(2, 1), Some(GameEnvironmentSlot::new(45, 1, 0)));
+
+        assert!(repository.get_game_environment_slot_map().is_empty());
+        assert_eq!(repository.get_environment_modifier(1), None);
+        assert!(!repository.remove_game_environment_slot_hash_by_account_unique_id(1));
+    }
+}
\ No newline at end of file
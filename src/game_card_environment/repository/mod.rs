@@ -0,0 +1,3 @@
+pub mod game_card_environment_repository;
+pub mod game_card_environment_repository_impl;
+pub mod environment_modifier_hook;
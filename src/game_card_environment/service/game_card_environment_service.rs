@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::game_card_environment::service::request::decrease_environment_remaining_turn_request::DecreaseEnvironmentRemainingTurnRequest;
+use crate::game_card_environment::service::request::deploy_environment_card_request::DeployEnvironmentCardRequest;
+use crate::game_card_environment::service::request::get_environment_extra_draw_count_request::GetEnvironmentExtraDrawCountRequest;
+use crate::game_card_environment::service::response::decrease_environment_remaining_turn_response::DecreaseEnvironmentRemainingTurnResponse;
+use crate::game_card_environment::service::response::deploy_environment_card_response::DeployEnvironmentCardResponse;
+use crate::game_card_environment::service::response::get_environment_extra_draw_count_response::GetEnvironmentExtraDrawCountResponse;
+
+#[async_trait]
+pub trait GameCardEnvironmentService {
+    async fn deploy_environment_card(&mut self, deploy_environment_card_request: DeployEnvironmentCardRequest) -> DeployEnvironmentCardResponse;
+    async fn decrease_environment_remaining_turn(&mut self, decrease_environment_remaining_turn_request: DecreaseEnvironmentRemainingTurnRequest) -> DecreaseEnvironmentRemainingTurnResponse;
+    async fn get_environment_extra_draw_count(&self, get_environment_extra_draw_count_request: GetEnvironmentExtraDrawCountRequest) -> GetEnvironmentExtraDrawCountResponse;
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::game_card_environment::repository::game_card_environment_repository::GameCardEnvironmentRepository;
+use crate::game_card_environment::repository::game_card_environment_repository_impl::GameCardEnvironmentRepositoryImpl;
+use crate::game_card_environment::service::game_card_environment_service::GameCardEnvironmentService;
+use crate::game_card_environment::service::request::decrease_environment_remaining_turn_request::DecreaseEnvironmentRemainingTurnRequest;
+use crate::game_card_environment::service::request::deploy_environment_card_request::DeployEnvironmentCardRequest;
+use crate::game_card_environment::service::request::get_environment_extra_draw_count_request::GetEnvironmentExtraDrawCountRequest;
+use crate::game_card_environment::service::response::decrease_environment_remaining_turn_response::DecreaseEnvironmentRemainingTurnResponse;
+use crate::game_card_environment::service::response::deploy_environment_card_response::DeployEnvironmentCardResponse;
+use crate::game_card_environment::service::response::get_environment_extra_draw_count_response::GetEnvironmentExtraDrawCountResponse;
+
+pub struct GameCardEnvironmentServiceImpl {
+    game_card_environment_repository: Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>>,
+}
+
+impl GameCardEnvironmentServiceImpl {
+    pub fn new(game_card_environment_repository: Arc<AsyncMutex<GameCardEnvironmentRepositoryImpl>>) -> Self {
+        GameCardEnvironmentServiceImpl {
+            game_card_environment_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardEnvironmentServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardEnvironmentServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardEnvironmentServiceImpl::new(
+                            GameCardEnvironmentRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl GameCardEnvironmentService for GameCardEnvironmentServiceImpl {
+    async fn deploy_environment_card(&mut self, deploy_environment_card_request: DeployEnvironmentCardRequest) -> DeployEnvironmentCardResponse {
+        println!("GameCardEnvironmentServiceImpl: deploy_environment_card()");
+
+        let account_unique_id = deploy_environment_card_request.get_account_unique_id();
+        let opponent_unique_id = deploy_environment_card_request.get_opponent_unique_id();
+        let environment_card_id = deploy_environment_card_request.get_environment_card_id();
+
+        let mut game_card_environment_repository_guard = self.game_card_environment_repository.lock().await;
+
+        if !game_card_environment_repository_guard.is_registered_environment_card(environment_card_id) {
+            println!("효과가 정의되지 않은 환경 카드입니다: {}", environment_card_id);
+            return DeployEnvironmentCardResponse::default()
+        }
+
+        // 유닛/필드 에너지 저장소는 hook 으로 이 슬롯을 직접 조회하므로 별도 반영이 필요 없음
+        let maybe_replaced_environment_slot = game_card_environment_repository_guard.deploy_environment_card(
+            account_unique_id, opponent_unique_id, environment_card_id);
+
+        match maybe_replaced_environment_slot {
+            Some(replaced_environment_slot) => DeployEnvironmentCardResponse::new(
+                true,
+                replaced_environment_slot.get_environment_card_id(),
+                replaced_environment_slot.get_owner_unique_id()),
+            None => DeployEnvironmentCardResponse::new(true, -1, -1),
+        }
+    }
+
+    async fn decrease_environment_remaining_turn(&mut self, decrease_environment_remaining_turn_request: DecreaseEnvironmentRemainingTurnRequest) -> DecreaseEnvironmentRemainingTurnResponse {
+        println!("GameCardEnvironmentServiceImpl: decrease_environment_remaining_turn()");
+
+        let account_unique_id = decrease_environment_remaining_turn_request.get_account_unique_id();
+        let opponent_unique_id = decrease_environment_remaining_turn_request.get_opponent_unique_id();
+
+        let mut game_card_environment_repository_guard = self.game_card_environment_repository.lock().await;
+
+        let maybe_expired_environment_slot = game_card_environment_repository_guard.decrease_environment_remaining_turn(
+            account_unique_id, opponent_unique_id);
+
+        drop(game_card_environment_repository_guard);
+
+        match maybe_expired_environment_slot {
+            Some(expired_environment_slot) => DecreaseEnvironmentRemainingTurnResponse::new(
+                expired_environment_slot.get_environment_card_id(),
+                expired_environment_slot.get_owner_unique_id()),
+            None => DecreaseEnvironmentRemainingTurnResponse::default(),
+        }
+    }
+
+    async fn get_environment_extra_draw_count(&self, get_environment_extra_draw_count_request: GetEnvironmentExtraDrawCountRequest) -> GetEnvironmentExtraDrawCountResponse {
+        println!("GameCardEnvironmentServiceImpl: get_environment_extra_draw_count()");
+
+        let game_card_environment_repository_guard = self.game_card_environment_repository.lock().await;
+
+        let extra_draw_count = game_card_environment_repository_guard.get_environment_modifier(
+            get_environment_extra_draw_count_request.get_account_unique_id())
+            .map_or(0, |environment_modifier| environment_modifier.get_extra_draw_per_turn());
+
+        GetEnvironmentExtraDrawCountResponse::new(extra_draw_count)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod request;
+pub mod response;
+pub mod game_card_environment_service;
+pub mod game_card_environment_service_impl;
\ No newline at end of file
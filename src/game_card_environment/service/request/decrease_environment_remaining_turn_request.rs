@@ -0,0 +1,18 @@
+#[derive(Debug)]
+pub struct DecreaseEnvironmentRemainingTurnRequest {
+    account_unique_id: i32,
+    opponent_unique_id: i32,
+}
+
+impl DecreaseEnvironmentRemainingTurnRequest {
+    pub fn new(account_unique_id: i32, opponent_unique_id: i32) -> Self {
+        DecreaseEnvironmentRemainingTurnRequest {
+            account_unique_id,
+            opponent_unique_id
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+}
\ No newline at end of file
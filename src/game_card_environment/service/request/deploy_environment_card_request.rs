@@ -0,0 +1,22 @@
+#[derive(Debug)]
+pub struct DeployEnvironmentCardRequest {
+    account_unique_id: i32,
+    opponent_unique_id: i32,
+    environment_card_id: i32,
+}
+
+impl DeployEnvironmentCardRequest {
+    pub fn new(account_unique_id: i32, opponent_unique_id: i32, environment_card_id: i32) -> Self {
+        DeployEnvironmentCardRequest {
+            account_unique_id,
+            opponent_unique_id,
+            environment_card_id
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+
+    pub fn get_environment_card_id(&self) -> i32 { self.environment_card_id }
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+#[derive(Debug)]
+pub struct GetEnvironmentExtraDrawCountRequest {
+    account_unique_id: i32,
+}
+
+impl GetEnvironmentExtraDrawCountRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        GetEnvironmentExtraDrawCountRequest {
+            account_unique_id
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+}
\ No newline at end of file
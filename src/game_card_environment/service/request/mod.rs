@@ -0,0 +1,3 @@
+pub mod deploy_environment_card_request;
+pub mod decrease_environment_remaining_turn_request;
+pub mod get_environment_extra_draw_count_request;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DecreaseEnvironmentRemainingTurnResponse {
+    expired_environment_card_id: i32,
+    expired_environment_owner_unique_id: i32,
+}
+
+impl DecreaseEnvironmentRemainingTurnResponse {
+    pub fn new(expired_environment_card_id: i32, expired_environment_owner_unique_id: i32) -> Self {
+        DecreaseEnvironmentRemainingTurnResponse {
+            expired_environment_card_id,
+            expired_environment_owner_unique_id
+        }
+    }
+
+    pub fn default() -> DecreaseEnvironmentRemainingTurnResponse {
+        DecreaseEnvironmentRemainingTurnResponse::new(-1, -1)
+    }
+
+    pub fn get_expired_environment_card_id(&self) -> i32 { self.expired_environment_card_id }
+
+    pub fn get_expired_environment_owner_unique_id(&self) -> i32 { self.expired_environment_owner_unique_id }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DeployEnvironmentCardResponse {
+    is_success: bool,
+    replaced_environment_card_id: i32,
+    replaced_environment_owner_unique_id: i32,
+}
+
+impl DeployEnvironmentCardResponse {
+    pub fn new(is_success: bool,
+               replaced_environment_card_id: i32,
+               replaced_environment_owner_unique_id: i32) -> Self {
+        DeployEnvironmentCardResponse {
+            is_success,
+            replaced_environment_card_id,
+            replaced_environment_owner_unique_id
+        }
+    }
+
+    pub fn default() -> DeployEnvironmentCardResponse {
+        DeployEnvironmentCardResponse::new(false, -1, -1)
+    }
+
+    pub fn is_success(&self) -> bool { self.is_success }
+
+    pub fn get_replaced_environment_card_id(&self) -> i32 { self.replaced_environment_card_id }
+
+    pub fn get_replaced_environment_owner_unique_id(&self) -> i32 { self.replaced_environment_owner_unique_id }
+}
\ No newline at end of file
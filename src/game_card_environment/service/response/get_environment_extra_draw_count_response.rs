@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GetEnvironmentExtraDrawCountResponse {
+    extra_draw_count: i32,
+}
+
+impl GetEnvironmentExtraDrawCountResponse {
+    pub fn new(extra_draw_count: i32) -> Self {
+        GetEnvironmentExtraDrawCountResponse { extra_draw_count }
+    }
+
+    pub fn get_extra_draw_count(&self) -> i32 { self.extra_draw_count }
+}
\ No newline at end of file
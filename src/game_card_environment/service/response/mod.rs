@@ -0,0 +1,3 @@
+pub mod deploy_environment_card_response;
+pub mod decrease_environment_remaining_turn_response;
+pub mod get_environment_extra_draw_count_response;
\ No newline at end of file
@@ -34,6 +34,11 @@ use crate::game_card_item::service::game_card_item_service::GameCardItemService;
 use crate::game_card_item::service::game_card_item_service_impl::GameCardItemServiceImpl;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
 use crate::game_card_item::service::response::summary_item_card_effect_response::SummaryItemCardEffectResponse;
+use crate::game_card_trap::controller::game_card_trap_controller::GameCardTrapController;
+use crate::game_card_trap::controller::game_card_trap_controller_impl::GameCardTrapControllerImpl;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::MergeTriggerTrapCardResponseForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_deck::service::game_deck_service::GameDeckService;
 use crate::game_deck::service::game_deck_service_impl::GameDeckServiceImpl;
 
@@ -87,6 +92,7 @@ pub struct GameCardItemControllerImpl {
     notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
     ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
     game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+    game_card_trap_controller: Arc<AsyncMutex<GameCardTrapControllerImpl>>,
 }
 
 impl GameCardItemControllerImpl {
@@ -105,7 +111,8 @@ impl GameCardItemControllerImpl {
                card_race_service: Arc<AsyncMutex<CardRaceServiceImpl>>,
                notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
                ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
-               game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,) -> Self {
+               game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+               game_card_trap_controller: Arc<AsyncMutex<GameCardTrapControllerImpl>>,) -> Self {
 
         GameCardItemControllerImpl {
             game_hand_service,
@@ -124,6 +131,7 @@ impl GameCardItemControllerImpl {
             notify_player_action_info_service,
             ui_data_generator_service,
             game_winner_check_service,
+            game_card_trap_controller,
         }
     }
     pub fn get_instance() -> Arc<AsyncMutex<GameCardItemControllerImpl>> {
@@ -147,7 +155,8 @@ impl GameCardItemControllerImpl {
                             CardRaceServiceImpl::get_instance(),
                             NotifyPlayerActionInfoServiceImpl::get_instance(),
                             UiDataGeneratorServiceImpl::get_instance(),
-                            GameWinnerCheckServiceImpl::get_instance())));
+                            GameWinnerCheckServiceImpl::get_instance(),
+                            GameCardTrapControllerImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -200,6 +209,17 @@ impl GameCardItemControllerImpl {
         drop(game_card_item_service_guard);
         summary_item_card_effect_response
     }
+
+    // 상대방이 세팅한 함정 카드 중 아이템 사용에 반응하는 카드가 있다면 발동하고 그 결과를 응답에 합침
+    async fn respond_with_opponent_trap_card<ResponseForm>(
+        &self, trigger_trap_card_request_form: TriggerTrapCardRequestForm, response_form: ResponseForm) -> ResponseForm
+        where ResponseForm: MergeTriggerTrapCardResponseForm + Send {
+
+        let game_card_trap_controller_guard = self.game_card_trap_controller.lock().await;
+        let trigger_trap_card_response_form = game_card_trap_controller_guard.trigger_trap_card(trigger_trap_card_request_form).await;
+        drop(game_card_trap_controller_guard);
+        response_form.merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
+    }
 }
 
 #[async_trait]
@@ -236,8 +256,7 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let item_card_id_string = target_death_item_request_form.get_item_card_id();
-        let item_card_id = item_card_id_string.parse::<i32>().unwrap();
+        let item_card_id = target_death_item_request_form.get_item_card_id();
 
         // 3. Hand 에 있는지 확인하여 해킹 여부 검증
         let check_protocol_hacking_response = self.is_valid_protocol(
@@ -288,10 +307,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(battle_room_service_guard);
 
-        let opponent_target_unit_index_string =
-            target_death_item_request_form.get_opponent_target_unit_index();
         let opponent_target_unit_index =
-            opponent_target_unit_index_string.parse::<i32>().unwrap();
+            target_death_item_request_form.get_opponent_target_unit_index();
 
         // TODO: 추후 즉사 면역인 언데드 등등에 대한 조건도 필요함
         // 8. 타겟 인덱스 유닛이 신화 미만인지 확인
@@ -396,10 +413,16 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
             drop(notify_player_action_info_service_guard);
 
-            return TargetDeathItemResponseForm::from_response(
-                generate_use_my_hand_card_data_response,
-                generate_opponent_specific_unit_health_point_data_response,
-                generate_opponent_specific_unit_death_data_response)
+            return self.respond_with_opponent_trap_card(
+                target_death_item_request_form
+                    .to_trigger_trap_card_request_form(
+                        account_unique_id,
+                        TrapTriggerCondition::OnItemUsed,
+                        -1),
+                TargetDeathItemResponseForm::from_response(
+                    generate_use_my_hand_card_data_response,
+                    generate_opponent_specific_unit_health_point_data_response,
+                    generate_opponent_specific_unit_death_data_response)).await
         }
 
         // 12. Field Unit Service 를 호출하여 상대 유닛에 즉사 적용
@@ -468,10 +491,16 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(notify_player_action_info_service_guard);
 
-        TargetDeathItemResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_opponent_specific_unit_health_point_data_response,
-            generate_opponent_specific_unit_death_data_response)
+        self.respond_with_opponent_trap_card(
+            target_death_item_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            TargetDeathItemResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_opponent_specific_unit_health_point_data_response,
+                generate_opponent_specific_unit_death_data_response)).await
     }
 
     async fn request_to_use_add_field_energy_with_field_unit_health_point(
@@ -504,10 +533,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let item_card_id_string =
-            add_field_energy_with_field_unit_health_point_request_form.get_item_card_id();
         let item_card_id =
-            item_card_id_string.parse::<i32>().unwrap();
+            add_field_energy_with_field_unit_health_point_request_form.get_item_card_id();
 
         let check_protocol_hacking_response = self.is_valid_protocol(
             add_field_energy_with_field_unit_health_point_request_form
@@ -536,10 +563,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
             return AddFieldEnergyWithFieldUnitHealthPointResponseForm::from_false_response_with_message(MythicalCardRoundLimit)
         }
 
-        let field_unit_index_string =
-            add_field_energy_with_field_unit_health_point_request_form.get_field_unit_index();
         let field_unit_index =
-            field_unit_index_string.parse::<i32>().unwrap();
+            add_field_energy_with_field_unit_health_point_request_form.get_field_unit_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -677,10 +702,16 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(notify_player_action_info_service_guard);
 
-        AddFieldEnergyWithFieldUnitHealthPointResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_my_field_energy_data_response,
-            generate_my_specific_unit_death_data_response)
+        self.respond_with_opponent_trap_card(
+            add_field_energy_with_field_unit_health_point_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            AddFieldEnergyWithFieldUnitHealthPointResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_my_field_energy_data_response,
+                generate_my_specific_unit_death_data_response)).await
     }
 
     async fn request_to_use_catastrophic_damage_item(
@@ -714,8 +745,7 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         // TODO: 프로토콜 검증은 추후 추가
 
-        let item_card_id_string = catastrophic_damage_item_request_form.get_item_card_id();
-        let item_card_id = item_card_id_string.parse::<i32>().unwrap();
+        let item_card_id = catastrophic_damage_item_request_form.get_item_card_id();
 
         let check_protocol_hacking_response = self.is_valid_protocol(
             catastrophic_damage_item_request_form
@@ -950,13 +980,19 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(notify_player_action_info_service_guard);
 
-        CatastrophicDamageItemResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_opponent_multiple_unit_health_point_data_response,
-            generate_opponent_multiple_unit_death_data_response,
-            generate_opponent_main_character_health_point_data_response,
-            generate_opponent_main_character_survival_data_response,
-            generate_opponent_deck_card_lost_data_response)
+        self.respond_with_opponent_trap_card(
+            catastrophic_damage_item_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            CatastrophicDamageItemResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_opponent_multiple_unit_health_point_data_response,
+                generate_opponent_multiple_unit_death_data_response,
+                generate_opponent_main_character_health_point_data_response,
+                generate_opponent_main_character_survival_data_response,
+                generate_opponent_deck_card_lost_data_response)).await
     }
 
     async fn request_to_use_applying_multiple_target_damage_by_field_unit_death_item(
@@ -992,10 +1028,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
         // TODO: 프로토콜 검증은 추후 추가
 
         // 사용할 변수들 사전 parsing
-        let item_card_id_string =
-            multiple_target_damage_by_field_unit_death_item_request_form.get_item_card_id();
         let item_card_id =
-            item_card_id_string.to_string().parse::<i32>().unwrap();
+            multiple_target_damage_by_field_unit_death_item_request_form.get_item_card_id();
 
         let check_protocol_hacking_response = self.is_valid_protocol(
             multiple_target_damage_by_field_unit_death_item_request_form
@@ -1024,10 +1058,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
             return MultipleTargetDamageByFieldUnitDeathItemResponseForm::from_false_response_with_message(MythicalCardRoundLimit)
         }
 
-        let my_field_unit_index_string =
-            multiple_target_damage_by_field_unit_death_item_request_form.get_unit_index();
         let my_field_unit_index =
-            my_field_unit_index_string.to_string().parse::<i32>().unwrap();
+            multiple_target_damage_by_field_unit_death_item_request_form.get_unit_index();
 
         let opponent_target_unit_index_list_string =
             multiple_target_damage_by_field_unit_death_item_request_form.get_opponent_target_unit_index_list().to_vec();
@@ -1252,11 +1284,17 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         println!("notice_response: {:?}", notice_response);
 
-        MultipleTargetDamageByFieldUnitDeathItemResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_opponent_multiple_unit_health_point_data_response,
-            generate_opponent_multiple_unit_death_data_response,
-            generate_my_specific_unit_death_data_response)
+        self.respond_with_opponent_trap_card(
+            multiple_target_damage_by_field_unit_death_item_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            MultipleTargetDamageByFieldUnitDeathItemResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_opponent_multiple_unit_health_point_data_response,
+                generate_opponent_multiple_unit_death_data_response,
+                generate_my_specific_unit_death_data_response)).await
     }
 
     async fn request_to_use_opponent_field_unit_energy_removal_item(
@@ -1290,10 +1328,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         // TODO: 프로토콜 검증은 추후 추가
 
-        let item_card_id_string =
-            remove_opponent_field_unit_energy_item_request_form.get_item_card_id();
         let item_card_id =
-            item_card_id_string.parse::<i32>().unwrap();
+            remove_opponent_field_unit_energy_item_request_form.get_item_card_id();
 
         let check_protocol_hacking_response = self.is_valid_protocol(
             remove_opponent_field_unit_energy_item_request_form
@@ -1322,10 +1358,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
             return RemoveOpponentFieldUnitEnergyItemResponseForm::from_false_response_with_message(MythicalCardRoundLimit)
         }
 
-        let opponent_field_unit_index_string =
-            remove_opponent_field_unit_energy_item_request_form.get_opponent_target_unit_index();
         let opponent_field_unit_index =
-            opponent_field_unit_index_string.parse::<i32>().unwrap();
+            remove_opponent_field_unit_energy_item_request_form.get_opponent_target_unit_index();
 
         let mut summarized_item_effect_response = self.get_summary_of_item_card(
             remove_opponent_field_unit_energy_item_request_form
@@ -1455,10 +1489,16 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
             drop(notify_player_action_info_service_guard);
 
-            return RemoveOpponentFieldUnitEnergyItemResponseForm::from_alternative_response(
-                generate_use_my_hand_card_data_response,
-                generate_opponent_specific_unit_health_point_data_response,
-                generate_opponent_specific_unit_death_data_response)
+            return self.respond_with_opponent_trap_card(
+                remove_opponent_field_unit_energy_item_request_form
+                    .to_trigger_trap_card_request_form(
+                        account_unique_id,
+                        TrapTriggerCondition::OnItemUsed,
+                        -1),
+                RemoveOpponentFieldUnitEnergyItemResponseForm::from_alternative_response(
+                    generate_use_my_hand_card_data_response,
+                    generate_opponent_specific_unit_health_point_data_response,
+                    generate_opponent_specific_unit_death_data_response)).await
         }
 
         game_field_unit_service_guard.detach_multiple_energy_from_field_unit(
@@ -1520,9 +1560,15 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         println!("notice_response: {:?}", notice_response);
 
-        RemoveOpponentFieldUnitEnergyItemResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_opponent_field_unit_energy_data_response)
+        self.respond_with_opponent_trap_card(
+            remove_opponent_field_unit_energy_item_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            RemoveOpponentFieldUnitEnergyItemResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_opponent_field_unit_energy_data_response)).await
     }
 
 
@@ -1556,10 +1602,8 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let item_card_number_string =
-            remove_opponent_field_energy_item_request_form.get_item_card_id().to_string();
         let item_card_number =
-            item_card_number_string.parse::<i32>().unwrap();
+            remove_opponent_field_energy_item_request_form.get_item_card_id();
 
         let check_hand_hacking_response = self.is_valid_protocol(
             remove_opponent_field_energy_item_request_form
@@ -1668,8 +1712,14 @@ impl GameCardItemController for GameCardItemControllerImpl {
 
         drop(notify_player_action_info_service_guard);
 
-        RemoveOpponentFieldEnergyItemResponseForm::from_response(
-            generate_use_my_hand_card_data_response,
-            generate_opponent_field_energy_data_response)
+        self.respond_with_opponent_trap_card(
+            remove_opponent_field_energy_item_request_form
+                .to_trigger_trap_card_request_form(
+                    account_unique_id,
+                    TrapTriggerCondition::OnItemUsed,
+                    -1),
+            RemoveOpponentFieldEnergyItemResponseForm::from_response(
+                generate_use_my_hand_card_data_response,
+                generate_opponent_field_energy_data_response)).await
     }
 }
\ No newline at end of file
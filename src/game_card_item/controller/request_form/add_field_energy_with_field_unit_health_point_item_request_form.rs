@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_field_energy::service::request::add_field_energy_with_amount_request::AddFieldEnergyWithAmountRequest;
 use crate::game_field_energy::service::request::get_current_field_energy_request::GetCurrentFieldEnergyRequest;
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
@@ -25,24 +27,24 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct AddFieldEnergyWithFieldUnitHealthPointRequestForm {
     session_id: String,
-    item_card_id: String,
-    field_unit_index: String,
+    item_card_id: i32,
+    field_unit_index: i32,
 }
 
 impl AddFieldEnergyWithFieldUnitHealthPointRequestForm {
-    pub fn new(session_id: &str, item_card_id: &str, field_unit_index: &str) -> Self {
+    pub fn new(session_id: &str, item_card_id: i32, field_unit_index: i32) -> Self {
         AddFieldEnergyWithFieldUnitHealthPointRequestForm {
             session_id: session_id.to_string(),
-            item_card_id: item_card_id.to_string(),
-            field_unit_index: field_unit_index.to_string(),
+            item_card_id,
+            field_unit_index,
         }
     }
 
     pub fn get_session_id(&self) -> &str { &self.session_id }
 
-    pub fn get_item_card_id(&self) -> &str { &self.item_card_id }
+    pub fn get_item_card_id(&self) -> i32 { self.item_card_id }
 
-    pub fn get_field_unit_index(&self) -> &str { &self.field_unit_index }
+    pub fn get_field_unit_index(&self) -> i32 { self.field_unit_index }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
         GetValueWithKeyRequest::new(self.get_session_id())
@@ -207,4 +209,13 @@ impl AddFieldEnergyWithFieldUnitHealthPointRequestForm {
             player_field_energy_map_for_notice,
             player_field_unit_death_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
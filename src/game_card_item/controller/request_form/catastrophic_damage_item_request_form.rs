@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_deck::service::request::draw_cards_from_deck_request::DrawCardsFromDeckRequest;
 use crate::game_field_unit::service::request::apply_catastrophic_damage_to_field_unit_request::ApplyCatastrophicDamageToFieldUnitRequest;
 use crate::game_field_unit::service::request::find_target_unit_id_by_index_request::FindTargetUnitIdByIndexRequest;
@@ -34,14 +36,14 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct CatastrophicDamageItemRequestForm {
     session_id: String,
-    item_card_id: String,
+    item_card_id: i32,
 }
 
 impl CatastrophicDamageItemRequestForm {
-    pub fn new(session_id: &str, item_card_id: &str) -> Self {
+    pub fn new(session_id: &str, item_card_id: i32) -> Self {
         CatastrophicDamageItemRequestForm {
             session_id: session_id.to_string(),
-            item_card_id: item_card_id.to_string(),
+            item_card_id,
         }
     }
 
@@ -49,8 +51,8 @@ impl CatastrophicDamageItemRequestForm {
         &self.session_id
     }
 
-    pub fn get_item_card_id(&self) -> &str {
-        &self.item_card_id
+    pub fn get_item_card_id(&self) -> i32 {
+        self.item_card_id
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -240,4 +242,13 @@ impl CatastrophicDamageItemRequestForm {
             player_deck_card_lost_list_map_for_notice
         )
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
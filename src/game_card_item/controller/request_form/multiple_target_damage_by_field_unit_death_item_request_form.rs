@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_instant_death_to_target_unit_index_request::ApplyInstantDeathToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::find_target_unit_id_by_index_request::FindTargetUnitIdByIndexRequest;
@@ -25,30 +27,30 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 
 #[derive(Debug)]
 pub struct MultipleTargetDamageByFieldUnitDeathItemRequestForm {
-    unit_index: String,
+    unit_index: i32,
     session_id: String,
-    item_card_id: String,
+    item_card_id: i32,
     opponent_target_unit_index_list: Vec<String>,
 }
 
 impl MultipleTargetDamageByFieldUnitDeathItemRequestForm {
-    pub fn new(unit_index: &str, session_id: &str, item_card_id: &str, opponent_target_unit_index_list: Vec<String>,) -> Self {
+    pub fn new(unit_index: i32, session_id: &str, item_card_id: i32, opponent_target_unit_index_list: Vec<String>,) -> Self {
         MultipleTargetDamageByFieldUnitDeathItemRequestForm {
-            unit_index: unit_index.to_string(),
+            unit_index,
             session_id: session_id.to_string(),
-            item_card_id: item_card_id.to_string(),
+            item_card_id,
             opponent_target_unit_index_list
         }
     }
 
-    pub fn get_unit_index(&self) -> &str { &self.unit_index }
+    pub fn get_unit_index(&self) -> i32 { self.unit_index }
 
     pub fn get_session_id(&self) -> &str {
         &self.session_id
     }
 
-    pub fn get_item_card_id(&self) -> &str {
-        &self.item_card_id
+    pub fn get_item_card_id(&self) -> i32 {
+        self.item_card_id
     }
 
     pub fn get_opponent_target_unit_index_list(&self) -> &Vec<String> { &self.opponent_target_unit_index_list }
@@ -196,4 +198,13 @@ impl MultipleTargetDamageByFieldUnitDeathItemRequestForm {
             player_field_unit_health_point_map_for_notice,
             player_field_unit_death_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
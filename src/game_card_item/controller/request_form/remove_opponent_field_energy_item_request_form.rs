@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_field_energy::service::request::get_current_field_energy_request::GetCurrentFieldEnergyRequest;
 use crate::game_field_energy::service::request::remove_field_energy_with_amount_request::RemoveFieldEnergyWithAmountRequest;
 use crate::game_hand::service::request::use_game_hand_item_card_request::UseGameHandItemCardRequest;
@@ -19,17 +21,17 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct RemoveOpponentFieldEnergyItemRequestForm {
     session_id: String,
-    item_card_id: String,
+    item_card_id: i32,
 }
 
 impl RemoveOpponentFieldEnergyItemRequestForm {
-    pub fn new(session_id: &str, item_card_number: &str) -> Self {
+    pub fn new(session_id: &str, item_card_number: i32) -> Self {
         RemoveOpponentFieldEnergyItemRequestForm {
             session_id: session_id.to_string(),
-            item_card_id: item_card_number.to_string(),
+            item_card_id: item_card_number,
         }
     }
-    pub fn get_item_card_id(&self) -> &str { &self.item_card_id }
+    pub fn get_item_card_id(&self) -> i32 { self.item_card_id }
 
     pub fn to_session_validation_request(
         &self) -> GetValueWithKeyRequest {
@@ -145,4 +147,13 @@ impl RemoveOpponentFieldEnergyItemRequestForm {
             player_hand_use_map_for_notice,
             player_field_energy_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
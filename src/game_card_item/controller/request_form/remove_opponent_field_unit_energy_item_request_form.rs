@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_field_unit::entity::attached_energy_map::AttachedEnergyMap;
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::detach_multiple_energy_from_field_unit_request::DetachMultipleEnergyFromFieldUnitRequest;
@@ -31,16 +33,16 @@ use crate::ui_data_generator::service::response::generate_opponent_specific_unit
 #[derive(Debug)]
 pub struct RemoveOpponentFieldUnitEnergyItemRequestForm {
     session_id: String,
-    item_card_id: String,
-    opponent_target_unit_index: String,
+    item_card_id: i32,
+    opponent_target_unit_index: i32,
 }
 
 impl RemoveOpponentFieldUnitEnergyItemRequestForm {
-    pub fn new(session_id: &str, item_card_id: &str, opponent_target_unit_index: &str) -> Self {
+    pub fn new(session_id: &str, item_card_id: i32, opponent_target_unit_index: i32) -> Self {
         RemoveOpponentFieldUnitEnergyItemRequestForm {
             session_id: session_id.to_string(),
-            item_card_id: item_card_id.to_string(),
-            opponent_target_unit_index: opponent_target_unit_index.to_string(),
+            item_card_id,
+            opponent_target_unit_index,
         }
     }
 
@@ -48,11 +50,11 @@ impl RemoveOpponentFieldUnitEnergyItemRequestForm {
         &self.session_id
     }
 
-    pub fn get_item_card_id(&self) -> &str {
-        &self.item_card_id
+    pub fn get_item_card_id(&self) -> i32 {
+        self.item_card_id
     }
 
-    pub fn get_opponent_target_unit_index(&self) -> &str { &self.opponent_target_unit_index }
+    pub fn get_opponent_target_unit_index(&self) -> i32 { self.opponent_target_unit_index }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
         GetValueWithKeyRequest::new(self.session_id.clone().as_str())
@@ -217,4 +219,13 @@ impl RemoveOpponentFieldUnitEnergyItemRequestForm {
             player_field_unit_death_map_for_notice
         )
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_item::service::request::summary_item_card_effect_request::SummaryItemCardEffectRequest;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_instant_death_to_target_unit_index_request::ApplyInstantDeathToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::find_target_unit_id_by_index_request::FindTargetUnitIdByIndexRequest;
@@ -25,16 +27,16 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct TargetDeathItemRequestForm {
     session_id: String,
-    opponent_target_unit_index: String,
-    item_card_id: String,
+    opponent_target_unit_index: i32,
+    item_card_id: i32,
 }
 
 impl TargetDeathItemRequestForm {
-    pub fn new(session_id: String, opponent_target_unit_index: String, item_card_id: String) -> Self {
+    pub fn new(session_id: String, opponent_target_unit_index: i32, item_card_id: i32) -> Self {
         TargetDeathItemRequestForm {
             session_id: session_id.to_string(),
-            opponent_target_unit_index: opponent_target_unit_index.to_string(),
-            item_card_id: item_card_id.to_string(),
+            opponent_target_unit_index,
+            item_card_id,
         }
     }
 
@@ -42,12 +44,12 @@ impl TargetDeathItemRequestForm {
         &self.session_id
     }
 
-    pub fn get_opponent_target_unit_index(&self) -> &str {
-        &self.opponent_target_unit_index
+    pub fn get_opponent_target_unit_index(&self) -> i32 {
+        self.opponent_target_unit_index
     }
 
-    pub fn get_item_card_id(&self) -> &str {
-        &self.item_card_id
+    pub fn get_item_card_id(&self) -> i32 {
+        self.item_card_id
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -165,4 +167,13 @@ impl TargetDeathItemRequestForm {
             player_field_unit_health_point_map_for_notice,
             player_field_unit_death_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
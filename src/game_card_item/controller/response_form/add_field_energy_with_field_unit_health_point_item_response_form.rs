@@ -8,6 +8,8 @@ use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
 use crate::ui_data_generator::service::response::generate_my_field_energy_data_response::GenerateMyFieldEnergyDataResponse;
 use crate::ui_data_generator::service::response::generate_my_specific_unit_death_data_response::GenerateMySpecificUnitDeathDataResponse;
 use crate::ui_data_generator::service::response::generate_use_my_hand_card_data_response::GenerateUseMyHandCardDataResponse;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddFieldEnergyWithFieldUnitHealthPointResponseForm {
@@ -15,6 +17,7 @@ pub struct AddFieldEnergyWithFieldUnitHealthPointResponseForm {
     false_message_enum: i32,
     player_field_energy_map: HashMap<PlayerIndex, i32>,
     player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl AddFieldEnergyWithFieldUnitHealthPointResponseForm {
@@ -26,7 +29,8 @@ impl AddFieldEnergyWithFieldUnitHealthPointResponseForm {
             is_success,
             false_message_enum,
             player_field_energy_map,
-            player_field_unit_death_map
+            player_field_unit_death_map,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
@@ -64,4 +68,19 @@ impl AddFieldEnergyWithFieldUnitHealthPointResponseForm {
             HashMap::new()
         )
     }
-}
\ No newline at end of file
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for AddFieldEnergyWithFieldUnitHealthPointResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for AddFieldEnergyWithFieldUnitHealthPointResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
+}
@@ -12,6 +12,8 @@ use crate::ui_data_generator::service::response::generate_opponent_main_characte
 use crate::ui_data_generator::service::response::generate_opponent_multiple_unit_death_data_response::GenerateOpponentMultipleUnitDeathDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_multiple_unit_health_point_data_response::GenerateOpponentMultipleUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_use_my_hand_card_data_response::GenerateUseMyHandCardDataResponse;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CatastrophicDamageItemResponseForm {
@@ -22,6 +24,7 @@ pub struct CatastrophicDamageItemResponseForm {
     player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
     player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
     player_deck_card_lost_list_map: HashMap<PlayerIndex, Vec<i32>>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl CatastrophicDamageItemResponseForm {
@@ -40,7 +43,8 @@ impl CatastrophicDamageItemResponseForm {
             player_field_unit_death_map,
             player_main_character_health_point_map,
             player_main_character_survival_map,
-            player_deck_card_lost_list_map
+            player_deck_card_lost_list_map,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
@@ -87,4 +91,19 @@ impl CatastrophicDamageItemResponseForm {
             HashMap::new(),
             HashMap::new())
     }
-}
\ No newline at end of file
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for CatastrophicDamageItemResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for CatastrophicDamageItemResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
+}
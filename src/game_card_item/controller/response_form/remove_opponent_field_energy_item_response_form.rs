@@ -4,12 +4,15 @@ use crate::common::message::false_message_enum::FalseMessage;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
 use crate::ui_data_generator::service::response::generate_opponent_field_energy_data_response::GenerateOpponentFieldEnergyDataResponse;
 use crate::ui_data_generator::service::response::generate_use_my_hand_card_data_response::GenerateUseMyHandCardDataResponse;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoveOpponentFieldEnergyItemResponseForm {
     is_success: bool,
     false_message_enum: i32,
-    player_field_energy_map: HashMap<PlayerIndex, i32>
+    player_field_energy_map: HashMap<PlayerIndex, i32>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl RemoveOpponentFieldEnergyItemResponseForm {
@@ -19,7 +22,8 @@ impl RemoveOpponentFieldEnergyItemResponseForm {
         RemoveOpponentFieldEnergyItemResponseForm {
             is_success,
             false_message_enum,
-            player_field_energy_map
+            player_field_energy_map,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
@@ -49,4 +53,19 @@ impl RemoveOpponentFieldEnergyItemResponseForm {
             HashMap::new(),
         )
     }
-}
\ No newline at end of file
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for RemoveOpponentFieldEnergyItemResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for RemoveOpponentFieldEnergyItemResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
+}
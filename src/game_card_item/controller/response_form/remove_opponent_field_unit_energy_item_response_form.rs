@@ -9,6 +9,8 @@ use crate::ui_data_generator::service::response::generate_opponent_specific_unit
 use crate::ui_data_generator::service::response::generate_opponent_specific_unit_energy_data_response::GenerateOpponentSpecificUnitEnergyDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_specific_unit_health_point_data_response::GenerateOpponentSpecificUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_use_my_hand_card_data_response::GenerateUseMyHandCardDataResponse;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoveOpponentFieldUnitEnergyItemResponseForm {
@@ -17,6 +19,7 @@ pub struct RemoveOpponentFieldUnitEnergyItemResponseForm {
     player_field_unit_energy_map: HashMap<PlayerIndex, FieldUnitEnergyInfo>,
     player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
     player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl RemoveOpponentFieldUnitEnergyItemResponseForm {
@@ -32,6 +35,7 @@ impl RemoveOpponentFieldUnitEnergyItemResponseForm {
             player_field_unit_energy_map,
             player_field_unit_health_point_map,
             player_field_unit_death_map,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
@@ -80,4 +84,19 @@ impl RemoveOpponentFieldUnitEnergyItemResponseForm {
             HashMap::new(),
             HashMap::new())
     }
-}
\ No newline at end of file
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for RemoveOpponentFieldUnitEnergyItemResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for RemoveOpponentFieldUnitEnergyItemResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
+}
@@ -0,0 +1,60 @@
+use crate::card_effect_definition::entity::item_effect_definition::ItemEffectDefinition;
+use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+use crate::game_card_item::entity::game_card_item_effect::GameCardItemEffect;
+use crate::game_card_item::handler::game_card_item_handler::GameCardItemHandler;
+
+pub struct DataDrivenItemCardFunction {
+    item_effect_definition: ItemEffectDefinition,
+}
+
+impl DataDrivenItemCardFunction {
+    pub fn new(item_effect_definition: ItemEffectDefinition) -> Self {
+        DataDrivenItemCardFunction { item_effect_definition }
+    }
+}
+
+impl GameCardItemHandler for DataDrivenItemCardFunction {
+    unsafe fn summary_item_card(&self) -> GameCardItemEffect {
+        println!("DataDrivenItemCardFunction: summary_item_card()");
+
+        let definition = &self.item_effect_definition;
+        let (required_energy_race, required_energy_count) = match definition.get_required_energy() {
+            Some(required_energy) => (required_energy.get_race(), required_energy.get_count()),
+            None => (RaceEnum::Dummy, -1),
+        };
+
+        let mut game_card_item_effect = GameCardItemEffect::new(
+            required_energy_race,
+            required_energy_count,
+            definition.get_alternatives_damage().unwrap_or(-1),
+            definition.get_apply_target_minimum_grade().unwrap_or(GradeEnum::Dummy));
+
+        if let Some(divider) = definition.get_field_energy_addition_divider() {
+            game_card_item_effect.set_field_energy_addition_calculator(divider);
+        }
+        if let Some(damage) = definition.get_catastrophic_damage_for_field_unit() {
+            game_card_item_effect.set_catastrophic_damage_for_field_unit(damage);
+        }
+        if let Some(damage) = definition.get_catastrophic_damage_for_main_character() {
+            game_card_item_effect.set_catastrophic_damage_for_main_character(damage);
+        }
+        if let Some(count) = definition.get_will_be_lost_deck_card_count() {
+            game_card_item_effect.set_will_be_lost_deck_card_count(count);
+        }
+        if let Some(count) = definition.get_target_count_that_can_be_damaged() {
+            game_card_item_effect.set_target_count_that_can_be_damaged(count);
+        }
+        if let Some(unit_list) = definition.get_unit_list_that_can_be_sacrificed() {
+            game_card_item_effect.set_unit_list_that_can_be_sacrificed(unit_list.clone());
+        }
+        if let Some(count) = definition.get_will_be_removed_energy_count() {
+            game_card_item_effect.set_will_be_removed_energy_count(count);
+        }
+        if let Some(amount) = definition.get_removal_amount_of_opponent_field_energy() {
+            game_card_item_effect.set_removal_amount_of_opponent_field_energy(amount);
+        }
+
+        game_card_item_effect
+    }
+}
@@ -0,0 +1 @@
+pub mod game_card_item_data_driven_handler_impl;
@@ -5,3 +5,4 @@ pub mod handler_of_25;
 pub mod handler_of_33;
 pub mod handler_of_9;
 pub mod handler_of_36;
+pub mod data_driven;
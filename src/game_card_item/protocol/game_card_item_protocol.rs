@@ -0,0 +1,62 @@
+use crate::game_card_item::controller::game_card_item_controller::GameCardItemController;
+use crate::game_card_item::controller::game_card_item_controller_impl::GameCardItemControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::game_card_item_request_form_generator::{create_add_field_energy_by_field_unit_health_point_item_request_form, create_catastrophic_damage_item_request_form, create_multiple_target_damage_by_field_unit_sacrifice_item_request_form, create_opponent_field_unit_energy_removal_item_request_form, create_target_death_item_request_form};
+use crate::request_generator::opponent_field_energy_remove_item_request_form_generator::create_opponent_field_energy_remove_item_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_item_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Target Death Item Card Usage
+    protocol_registry.register_battle_room_action(1006, "TARGET_DEATH_ITEM_USAGE", create_target_death_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_target_death_item(request).await;
+        ResponseType::TARGET_DEATH_ITEM_USAGE(response)
+    });
+
+    // Catastrophic Damage Item Card Usage
+    protocol_registry.register_battle_room_action(1007, "CATASTROPHIC_DAMAGE_ITEM_USAGE", create_catastrophic_damage_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_catastrophic_damage_item(request).await;
+        ResponseType::CATASTROPHIC_DAMAGE_ITEM_USAGE(response)
+    });
+
+    // Remove Opponent Field Energy Support->Item Usage
+    protocol_registry.register_battle_room_action(1008, "REMOVE_OPPONENT_FIELD_ENERGY_ITEM_USAGE", create_opponent_field_energy_remove_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_remove_opponent_field_energy_item(request).await;
+        ResponseType::REMOVE_OPPONENT_FIELD_ENERGY_ITEM_USAGE(response)
+    });
+
+    // Add Field Energy by Field Unit HP Item Card Usage
+    protocol_registry.register_battle_room_action(1009, "ADD_FIELD_ENERGY_BY_FIELD_UNIT_HEALTH_POINT_ITEM_USAGE", create_add_field_energy_by_field_unit_health_point_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_add_field_energy_with_field_unit_health_point(request).await;
+        ResponseType::ADD_FIELD_ENERGY_BY_FIELD_UNIT_HEALTH_POINT_ITEM_USAGE(response)
+    });
+
+    // Multiple Target Damage by Field Unit Sacrifice Item Card Usage
+    protocol_registry.register_battle_room_action(1014, "MULTIPLE_TARGET_DAMAGE_BY_FIELD_UNIT_SACRIFICE_ITEM_USAGE", create_multiple_target_damage_by_field_unit_sacrifice_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_applying_multiple_target_damage_by_field_unit_death_item(request).await;
+        ResponseType::MULTIPLE_TARGET_DAMAGE_BY_FIELD_UNIT_SACRIFICE_ITEM_USAGE(response)
+    });
+
+    // Opponent Field Unit Energy Removal Item Usage
+    protocol_registry.register_battle_room_action(1015, "OPPONENT_FIElD_UNIT_ENERGY_REMOVAL_ITEM_USAGE", create_opponent_field_unit_energy_removal_item_request_form, |request| async move {
+        let game_card_item_controller_mutex = GameCardItemControllerImpl::get_instance();
+        let game_card_item_controller = game_card_item_controller_mutex.lock().await;
+
+        let response = game_card_item_controller.request_to_use_opponent_field_unit_energy_removal_item(request).await;
+        ResponseType::OPPONENT_FIElD_UNIT_ENERGY_REMOVAL_ITEM_USAGE(response)
+    });
+}
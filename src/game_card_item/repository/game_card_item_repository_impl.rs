@@ -8,6 +8,9 @@ use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
 use crate::game_card_item::entity::game_card_item_effect::GameCardItemEffect;
 use crate::game_card_item::handler::game_card_item_handler::GameCardItemHandler;
+use crate::game_card_item::handler::data_driven::game_card_item_data_driven_handler_impl::DataDrivenItemCardFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 use crate::game_card_item::handler::handler_of_25::game_card_item_25_handler_impl::ItemCard_25_Function;
 use crate::game_card_item::handler::handler_of_33::game_card_item_33_handler_impl::ItemCard_33_Function;
 use crate::game_card_item::handler::handler_of_35::game_card_item_35_handler_impl::ItemCard_35_Function;
@@ -59,6 +62,13 @@ impl GameCardItemRepositoryImpl {
         item_card_functions.insert(154, Box::new(NoneFunction) as Box<dyn GameCardItemHandler>);
         item_card_functions.insert(194, Box::new(NoneFunction) as Box<dyn GameCardItemHandler>);
 
+        // 카드 효과 정의 파일에 있는 카드는 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (item_card_number, item_effect_definition) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_item_effect_definition_map() {
+            item_card_functions.insert(*item_card_number, Box::new(DataDrivenItemCardFunction::new(item_effect_definition.clone())) as Box<dyn GameCardItemHandler>);
+        }
+
         GameCardItemRepositoryImpl { item_card_functions }
     }
 
@@ -106,5 +116,26 @@ mod tests {
         assert_eq!(response.get_required_energy().get_required_energy_race(), &RaceEnum::Undead);
         println!("item effect: {:?}", response)
     }
+
+    #[test]
+    fn test_data_driven_item_card_matches_compiled_handler() {
+        let repository = GameCardItemRepositoryImpl::new();
+
+        for item_number in [8, 9, 25, 33, 35, 36] {
+            let data_driven_effect = unsafe { repository.get_function(item_number).unwrap().summary_item_card() };
+            let compiled_effect = unsafe {
+                match item_number {
+                    8 => ItemCard_8_Function.summary_item_card(),
+                    9 => ItemCard_9_Function.summary_item_card(),
+                    25 => ItemCard_25_Function.summary_item_card(),
+                    33 => ItemCard_33_Function.summary_item_card(),
+                    35 => ItemCard_35_Function.summary_item_card(),
+                    _ => ItemCard_36_Function.summary_item_card(),
+                }
+            };
+
+            assert_eq!(data_driven_effect, compiled_effect, "item card {}", item_number);
+        }
+    }
 }
 
@@ -153,8 +153,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let unit_card_index_string = deploy_targeting_attack_passive_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = deploy_targeting_attack_passive_skill_request_form.get_unit_card_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -171,8 +170,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Passive Skill Summary 획득
-        let usage_skill_index_string = deploy_targeting_attack_passive_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = deploy_targeting_attack_passive_skill_request_form.get_usage_skill_index();
 
         let mut game_card_passive_skill_service_guard =
             self.game_card_passive_skill_service.lock().await;
@@ -217,8 +215,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
 
         // 타게팅 데미지 적용
         // TODO: 현재에는 단일 타겟팅밖에 없으나 다중 타겟팅이 존재하는 경우 추가 처리 필요
-        let target_card_index_string = deploy_targeting_attack_passive_skill_request_form.get_opponent_target_card_index();
-        let opponent_target_unit_card_index = target_card_index_string.parse::<i32>().unwrap();
+        let opponent_target_unit_card_index = deploy_targeting_attack_passive_skill_request_form.get_opponent_target_card_index();
 
         let target_skill_type = summary_passive_skill_effect_by_index_response.get_passive_skill_type();
         let target_skill_damage = summary_passive_skill_effect_by_index_response.get_skill_damage();
@@ -239,6 +236,8 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
             let attack_target_unit_with_extra_effect_response = game_field_unit_service_guard.attack_target_unit_with_extra_effect(
                     deploy_targeting_attack_passive_skill_request_form
                         .to_attack_target_with_extra_effect_request(
+                            account_unique_id,
+                            unit_card_index,
                             opponent_unique_id,
                             target_skill_damage,
                             extra_effect_list_of_unit_using_skill, opponent_target_unit_card_index)).await;
@@ -281,7 +280,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         if judge_death_of_opponent_unit_response.get_dead_unit_id() != -1 {
             println!("공격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-            game_tomb_service_guard.add_used_card_to_tomb(
+            game_tomb_service_guard.add_dead_unit_to_tomb(
                 deploy_targeting_attack_passive_skill_request_form
                     .to_place_dead_unit_to_tomb_request(
                         opponent_unique_id,
@@ -416,11 +415,9 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Passive Skill Summary 획득
-        let unit_card_index_string = deploy_non_targeting_attack_passive_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = deploy_non_targeting_attack_passive_skill_request_form.get_unit_card_index();
 
-        let usage_skill_index_string = deploy_non_targeting_attack_passive_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = deploy_non_targeting_attack_passive_skill_request_form.get_usage_skill_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -669,11 +666,9 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         drop(game_protocol_validation_service_guard);
 
         // Battle Field 에서 공격하는 유닛의 index 를 토대로 id 값 확보
-        let unit_card_index_string = deploy_targeting_attack_to_game_main_character_request_form.get_attacker_unit_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = deploy_targeting_attack_to_game_main_character_request_form.get_attacker_unit_index();
 
-        let usage_skill_index_string = deploy_targeting_attack_to_game_main_character_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = deploy_targeting_attack_to_game_main_character_request_form.get_usage_skill_index();
 
 
         // 액션 가능한 턴인지 검증
@@ -881,8 +876,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let unit_card_index_string = turn_start_targeting_attack_passive_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = turn_start_targeting_attack_passive_skill_request_form.get_unit_card_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -899,8 +893,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Passive Skill Summary 획득
-        let usage_skill_index_string = turn_start_targeting_attack_passive_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = turn_start_targeting_attack_passive_skill_request_form.get_usage_skill_index();
 
         let mut game_card_passive_skill_service_guard =
             self.game_card_passive_skill_service.lock().await;
@@ -945,8 +938,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
 
         // 타게팅 데미지 적용
         // TODO: 현재에는 단일 타겟팅밖에 없으나 다중 타겟팅이 존재하는 경우 추가 처리 필요
-        let target_card_index_string = turn_start_targeting_attack_passive_skill_request_form.get_opponent_target_card_index();
-        let opponent_target_unit_card_index = target_card_index_string.parse::<i32>().unwrap();
+        let opponent_target_unit_card_index = turn_start_targeting_attack_passive_skill_request_form.get_opponent_target_card_index();
 
         let target_skill_type = summary_passive_skill_effect_by_index_response.get_passive_skill_type();
         let target_skill_damage = summary_passive_skill_effect_by_index_response.get_skill_damage();
@@ -967,6 +959,8 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
             let attack_target_unit_with_extra_effect_response = game_field_unit_service_guard.attack_target_unit_with_extra_effect(
                     turn_start_targeting_attack_passive_skill_request_form
                         .to_attack_target_with_extra_effect_request(
+                            account_unique_id,
+                            unit_card_index,
                             opponent_unique_id,
                             target_skill_damage,
                             extra_effect_list_of_unit_using_skill, opponent_target_unit_card_index)).await;
@@ -1009,7 +1003,7 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         if judge_death_of_opponent_unit_response.get_dead_unit_id() != -1 {
             println!("공격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-            game_tomb_service_guard.add_used_card_to_tomb(
+            game_tomb_service_guard.add_dead_unit_to_tomb(
                 turn_start_targeting_attack_passive_skill_request_form
                     .to_place_dead_unit_to_tomb_request(
                         opponent_unique_id,
@@ -1140,11 +1134,9 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
         // TODO: 프로토콜 검증 할 때가 아니라 패스
 
         // Passive Skill Summary 획득
-        let unit_card_index_string = turn_start_non_targeting_attack_passive_skill_request_form.get_unit_card_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = turn_start_non_targeting_attack_passive_skill_request_form.get_unit_card_index();
 
-        let usage_skill_index_string = turn_start_non_targeting_attack_passive_skill_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = turn_start_non_targeting_attack_passive_skill_request_form.get_usage_skill_index();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -1393,11 +1385,9 @@ impl GameCardPassiveSkillController for GameCardPassiveSkillControllerImpl {
 
 
         // Battle Field 에서 공격하는 유닛의 index 를 토대로 id 값 확보
-        let unit_card_index_string = turn_start_targeting_attack_to_game_main_character_request_form.get_attacker_unit_index();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = turn_start_targeting_attack_to_game_main_character_request_form.get_attacker_unit_index();
 
-        let usage_skill_index_string = turn_start_targeting_attack_to_game_main_character_request_form.get_usage_skill_index();
-        let usage_skill_index = usage_skill_index_string.parse::<i32>().unwrap();
+        let usage_skill_index = turn_start_targeting_attack_to_game_main_character_request_form.get_usage_skill_index();
 
 
         // 액션 가능한 턴인지 검증
@@ -41,19 +41,19 @@ use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_
 
 pub struct DeployNonTargetingAttackPassiveSkillRequestForm {
     session_id: String,
-    unit_card_index: String,
-    usage_skill_index: String,
+    unit_card_index: i32,
+    usage_skill_index: i32,
 }
 
 impl DeployNonTargetingAttackPassiveSkillRequestForm {
     pub fn new(session_id: String,
-               unit_card_index: String,
-               usage_skill_index: String) -> Self {
+               unit_card_index: i32,
+               usage_skill_index: i32) -> Self {
 
         DeployNonTargetingAttackPassiveSkillRequestForm {
             session_id: session_id.to_string(),
-            unit_card_index: unit_card_index.to_string(),
-            usage_skill_index: usage_skill_index.to_string(),
+            unit_card_index,
+            usage_skill_index,
         }
     }
 
@@ -61,12 +61,12 @@ impl DeployNonTargetingAttackPassiveSkillRequestForm {
         &self.session_id
     }
 
-    pub fn get_unit_card_index(&self) -> &str {
-        &self.unit_card_index
+    pub fn get_unit_card_index(&self) -> i32 {
+        self.unit_card_index
     }
 
-    pub fn get_usage_skill_index(&self) -> &str {
-        &self.usage_skill_index
+    pub fn get_usage_skill_index(&self) -> i32 {
+        self.usage_skill_index
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -33,22 +33,22 @@ use crate::ui_data_generator::service::request::generate_opponent_specific_unit_
 
 pub struct TurnStartTargetingAttackPassiveSkillRequestForm {
     session_id: String,
-    unit_card_index: String,
-    opponent_target_card_index: String,
-    usage_skill_index: String,
+    unit_card_index: i32,
+    opponent_target_card_index: i32,
+    usage_skill_index: i32,
 }
 
 impl TurnStartTargetingAttackPassiveSkillRequestForm {
     pub fn new(session_id: String,
-               unit_card_index: String,
-               opponent_target_card_index: String,
-               usage_skill_index: String) -> Self {
+               unit_card_index: i32,
+               opponent_target_card_index: i32,
+               usage_skill_index: i32) -> Self {
 
         TurnStartTargetingAttackPassiveSkillRequestForm {
             session_id: session_id.to_string(),
-            unit_card_index: unit_card_index.to_string(),
-            opponent_target_card_index: opponent_target_card_index.to_string(),
-            usage_skill_index: usage_skill_index.to_string(),
+            unit_card_index,
+            opponent_target_card_index,
+            usage_skill_index,
         }
     }
 
@@ -56,16 +56,16 @@ impl TurnStartTargetingAttackPassiveSkillRequestForm {
         &self.session_id
     }
 
-    pub fn get_unit_card_index(&self) -> &str {
-        &self.unit_card_index
+    pub fn get_unit_card_index(&self) -> i32 {
+        self.unit_card_index
     }
 
-    pub fn get_opponent_target_card_index(&self) -> &str {
-        &self.opponent_target_card_index
+    pub fn get_opponent_target_card_index(&self) -> i32 {
+        self.opponent_target_card_index
     }
 
-    pub fn get_usage_skill_index(&self) -> &str {
-        &self.usage_skill_index
+    pub fn get_usage_skill_index(&self) -> i32 {
+        self.usage_skill_index
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -110,11 +110,15 @@ impl TurnStartTargetingAttackPassiveSkillRequestForm {
     }
 
     pub fn to_attack_target_with_extra_effect_request(&self,
+                                                      attacker_unique_id: i32,
+                                                      attacker_unit_index: i32,
                                                       opponent_unique_id: i32,
                                                       damage: i32,
                                                       extra_status_effect_list: Vec<ExtraStatusEffect>,
                                                       target_unit_index: i32) -> AttackTargetUnitWithExtraEffectRequest {
         AttackTargetUnitWithExtraEffectRequest::new(
+            attacker_unique_id,
+            attacker_unit_index,
             opponent_unique_id,
             damage,
             extra_status_effect_list,
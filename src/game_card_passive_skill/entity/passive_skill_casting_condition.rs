@@ -1,7 +1,12 @@
-#[derive(Debug, Clone, PartialEq)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub enum PassiveSkillCastingCondition {
     Dummy = 0,
     Deploy = 1,
     TurnStart = 2,
     TurnEnd = 3,
+    OnDeath = 4,
+    OnDamaged = 5,
+    OnAttack = 6,
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
-#[derive(Debug, Clone, PartialEq)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub enum PassiveSkillType {
     Dummy = 0,
     SingleTarget = 1,
     BroadArea = 2,
     DoubleTarget = 3,
+    // 사망, 턴 종료 등 이벤트 발생 시 summon_token_card_id 의 토큰을 자신의 필드에 소환
+    SummonToken = 4,
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::card_effect_definition::entity::passive_skill_effect_definition::PassiveSkillEffectDefinition;
+use crate::game_card_passive_skill::entity::summary_passive_skill_effect::SummaryPassiveSkillEffect;
+use crate::game_card_passive_skill::handler::game_card_passive_skill_handler::GameCardPassiveSkillHandler;
+
+pub struct DataDrivenPassiveSkillFunction {
+    passive_skill_effect_definition: PassiveSkillEffectDefinition,
+}
+
+impl DataDrivenPassiveSkillFunction {
+    pub fn new(passive_skill_effect_definition: PassiveSkillEffectDefinition) -> Self {
+        DataDrivenPassiveSkillFunction { passive_skill_effect_definition }
+    }
+}
+
+impl GameCardPassiveSkillHandler for DataDrivenPassiveSkillFunction {
+    unsafe fn summary_passive_skill(&self) -> SummaryPassiveSkillEffect {
+        println!("DataDrivenPassiveSkillFunction: summary_passive_skill()");
+
+        let definition = &self.passive_skill_effect_definition;
+
+        SummaryPassiveSkillEffect::new(definition.get_skill_type().clone(),
+                                       definition.get_casting_conditions().clone(),
+                                       definition.get_skill_damage())
+    }
+}
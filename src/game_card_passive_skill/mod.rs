@@ -2,4 +2,5 @@ pub mod service;
 pub mod repository;
 pub mod handler;
 pub mod entity;
-pub mod controller;
\ No newline at end of file
+pub mod controller;
+pub mod protocol;
@@ -0,0 +1,66 @@
+use crate::game_card_passive_skill::controller::game_card_passive_skill_controller::GameCardPassiveSkillController;
+use crate::game_card_passive_skill::controller::game_card_passive_skill_controller_impl::GameCardPassiveSkillControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::deploy_non_targeting_attack_passive_skill_request_generator::create_deploy_non_targeting_attack_passive_skill_request_form;
+use crate::request_generator::deploy_targeting_attack_passive_skill_request_generator::create_deploy_targeting_attack_passive_skill_request_form;
+use crate::request_generator::deploy_targeting_attack_to_game_main_character_request_generator::create_deploy_targeting_attack_to_game_main_character_request_form;
+use crate::request_generator::turn_start_non_targeting_attack_passive_skill_request_generator::create_turn_start_non_targeting_attack_passive_skill_request_form;
+use crate::request_generator::turn_start_targeting_attack_passive_skill_request_generator::create_turn_start_targeting_attack_passive_skill_request_form;
+use crate::request_generator::turn_start_targeting_attack_to_game_main_character_request_generator::create_turn_start_targeting_attack_to_game_main_character_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_passive_skill_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Deploy Passive Targeting Attack Unit
+    protocol_registry.register_battle_room_action(2000, "DEPLOY_TARGETING_ATTACK_PASSIVE_SKILL", create_deploy_targeting_attack_passive_skill_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_deploy_targeting_attack_passive_skill(request).await;
+        ResponseType::DEPLOY_TARGETING_ATTACK_PASSIVE_SKILL(response)
+    });
+
+    // Deploy Passive Targeting Attack To Game Main Character
+    protocol_registry.register_battle_room_action(2001, "DEPLOY_TARGETING_ATTACK_TO_MAIN_CHARACTER", create_deploy_targeting_attack_to_game_main_character_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_deploy_targeting_attack_to_game_main_character(request).await;
+        ResponseType::DEPLOY_TARGETING_ATTACK_TO_MAIN_CHARACTER(response)
+    });
+
+    // Deploy Passive Non Targeting Attack
+    protocol_registry.register_battle_room_action(2002, "DEPLOY_NON_TARGETING_ATTACK_PASSIVE_SKILL", create_deploy_non_targeting_attack_passive_skill_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_deploy_non_targeting_attack_passive_skill(request).await;
+        ResponseType::DEPLOY_NON_TARGETING_ATTACK_PASSIVE_SKILL(response)
+    });
+
+    // Turn Start Passive Targeting Attack Unit
+    protocol_registry.register_battle_room_action(2010, "TURN_START_TARGETING_ATTACK_PASSIVE_SKILL", create_turn_start_targeting_attack_passive_skill_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_turn_start_targeting_attack_passive_skill(request).await;
+        ResponseType::TURN_START_TARGETING_ATTACK_PASSIVE_SKILL(response)
+    });
+
+    // Turn Start Passive Targeting Attack To Game Main Character
+    protocol_registry.register_battle_room_action(2011, "TURN_START_TARGETING_ATTACK_TO_MAIN_CHARACTER", create_turn_start_targeting_attack_to_game_main_character_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_turn_start_targeting_attack_to_game_main_character(request).await;
+        ResponseType::TURN_START_TARGETING_ATTACK_TO_MAIN_CHARACTER(response)
+    });
+
+    // Turn Start Passive Non Targeting Attack
+    protocol_registry.register_battle_room_action(2012, "TURN_START_NON_TARGETING_ATTACK_PASSIVE_SKILL", create_turn_start_non_targeting_attack_passive_skill_request_form, |request| async move {
+        let game_card_passive_skill_controller_mutex = GameCardPassiveSkillControllerImpl::get_instance();
+        let game_card_passive_skill_controller = game_card_passive_skill_controller_mutex.lock().await;
+
+        let response = game_card_passive_skill_controller.request_turn_start_non_targeting_attack_passive_skill(request).await;
+        ResponseType::TURN_START_NON_TARGETING_ATTACK_PASSIVE_SKILL(response)
+    });
+}
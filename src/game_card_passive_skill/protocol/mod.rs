@@ -0,0 +1 @@
+pub mod game_card_passive_skill_protocol;
@@ -8,6 +8,9 @@ use crate::game_card_passive_skill::entity::passive_skill_casting_condition::Pas
 use crate::game_card_passive_skill::entity::passive_skill_type::PassiveSkillType;
 use crate::game_card_passive_skill::entity::summary_passive_skill_effect::SummaryPassiveSkillEffect;
 use crate::game_card_passive_skill::handler::game_card_passive_skill_handler::GameCardPassiveSkillHandler;
+use crate::game_card_passive_skill::handler::data_driven::game_card_passive_skill_data_driven_handler_impl::DataDrivenPassiveSkillFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 use crate::game_card_passive_skill::handler::slot::first_slot::handler_of_19::game_card_unit_19_passive_slot_1_handler_impl::UnitCard_19_Passive_Slot_1_Function;
 use crate::game_card_passive_skill::handler::slot::second_slot::handler_of_19::game_card_unit_19_passive_slot_2_handler_impl::UnitCard_19_Passive_Slot_2_Function;
 use crate::game_card_passive_skill::repository::game_card_passive_skill_repository::GameCardPassiveSkillRepository;
@@ -36,6 +39,16 @@ impl GameCardPassiveSkillRepositoryImpl {
             passive_skill_functions.insert(*passive_skill_index, inner_map);
         }
 
+        // 카드 효과 정의 파일에 있는 스킬은 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (unit_card_number, skill_slot_map) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_passive_skill_effect_definition_map() {
+            for (passive_skill_index, passive_skill_effect_definition) in skill_slot_map {
+                passive_skill_functions.entry(*passive_skill_index).or_insert_with(HashMap::new)
+                    .insert(*unit_card_number, Box::new(DataDrivenPassiveSkillFunction::new(passive_skill_effect_definition.clone())) as Box<dyn GameCardPassiveSkillHandler>);
+            }
+        }
+
         GameCardPassiveSkillRepositoryImpl { passive_skill_functions }
     }
 
@@ -219,8 +219,7 @@ impl GameCardSupportController for GameCardSupportControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let support_card_number_string = energy_boost_support_request_form.get_support_card_id();
-        let support_card_number = support_card_number_string.parse::<i32>().unwrap();
+        let support_card_number = energy_boost_support_request_form.get_support_card_id();
 
         // Hand 에 있는지 확인하여 해킹 여부 검증
         let check_protocol_hacking_response = self.is_valid_protocol(
@@ -316,8 +315,7 @@ impl GameCardSupportController for GameCardSupportControllerImpl {
         let energy_from_deck_info = calculated_effect_response.get_energy_from_deck();
         let boost_race_reference = energy_from_deck_info.get_race();
 
-        let unit_card_index_string = energy_boost_support_request_form.get_unit_unit_index_number();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = energy_boost_support_request_form.get_unit_unit_index_number();
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -428,8 +426,7 @@ impl GameCardSupportController for GameCardSupportControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let support_card_number_string = draw_support_request_form.get_support_card_id().to_string();
-        let support_card_number = support_card_number_string.parse::<i32>().unwrap();
+        let support_card_number = draw_support_request_form.get_support_card_id();
 
         let check_hand_hacking_response = self.is_valid_protocol(
             draw_support_request_form
@@ -592,10 +589,8 @@ impl GameCardSupportController for GameCardSupportControllerImpl {
 
         drop(game_protocol_validation_service_guard);
 
-        let support_card_number_string =
-            check_search_unit_support_available_request_form.get_support_card_number().to_string();
         let support_card_number =
-            support_card_number_string.parse::<i32>().unwrap();
+            check_search_unit_support_available_request_form.get_support_card_number();
 
         let check_hand_hacking_response = self.is_valid_protocol(
             check_search_unit_support_available_request_form
@@ -708,10 +703,8 @@ impl GameCardSupportController for GameCardSupportControllerImpl {
         //
         // drop(game_protocol_validation_service_guard);
         //
-        let support_card_number_string =
-            search_unit_support_request_form.get_support_card_number().to_string();
         let support_card_number =
-            support_card_number_string.parse::<i32>().unwrap();
+            search_unit_support_request_form.get_support_card_number();
         //
         // let check_hand_hacking_response = self.is_valid_protocol(
         //     search_unit_support_request_form
@@ -12,18 +12,18 @@ use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyR
 #[derive(Debug)]
 pub struct CheckSearchUnitSupportAvailableRequestForm {
     session_id: String,
-    support_card_number: String,
+    support_card_number: i32,
 }
 
 impl CheckSearchUnitSupportAvailableRequestForm {
-    pub fn new(session_id: &str, support_card_number: &str) -> Self {
+    pub fn new(session_id: &str, support_card_number: i32) -> Self {
         CheckSearchUnitSupportAvailableRequestForm {
             session_id: session_id.to_string(),
-            support_card_number: support_card_number.to_string(),
+            support_card_number,
         }
     }
 
-    pub fn get_support_card_number(&self) -> &str { &self.support_card_number }
+    pub fn get_support_card_number(&self) -> i32 { self.support_card_number }
 
     pub fn to_session_validation_request(
         &self) -> GetValueWithKeyRequest {
@@ -23,18 +23,18 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct DrawSupportRequestForm {
     session_id: String,
-    support_card_id: String,
+    support_card_id: i32,
 }
 
 impl DrawSupportRequestForm {
-    pub fn new(session_id: &str, support_card_id: &str) -> Self {
+    pub fn new(session_id: &str, support_card_id: i32) -> Self {
         DrawSupportRequestForm {
             session_id: session_id.to_string(),
-            support_card_id: support_card_id.to_string()
+            support_card_id
         }
     }
     pub fn get_support_card_id(
-        &self) -> &str { &self.support_card_id }
+        &self) -> i32 { self.support_card_id }
 
     pub fn to_session_validation_request(
         &self) -> GetValueWithKeyRequest {
@@ -28,24 +28,24 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct EnergyBoostSupportRequestForm {
     session_id: String,
-    unit_index_number: String,
-    support_card_id: String,
+    unit_index_number: i32,
+    support_card_id: i32,
 }
 
 impl EnergyBoostSupportRequestForm {
-    pub fn new(session_id: String, unit_index_number: String, support_card_number: String) -> Self {
+    pub fn new(session_id: String, unit_index_number: i32, support_card_number: i32) -> Self {
         EnergyBoostSupportRequestForm {
             session_id: session_id.to_string(),
-            unit_index_number: unit_index_number.to_string(),
-            support_card_id: support_card_number.to_string(),
+            unit_index_number,
+            support_card_id: support_card_number,
         }
     }
 
     pub fn get_unit_unit_index_number(
-        &self) -> &str { &self.unit_index_number }
+        &self) -> i32 { self.unit_index_number }
 
     pub fn get_support_card_id(
-        &self) -> &str { &self.support_card_id }
+        &self) -> i32 { self.support_card_id }
 
     pub fn to_session_validation_request(
         &self) -> GetValueWithKeyRequest {
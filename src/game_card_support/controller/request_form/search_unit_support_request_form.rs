@@ -25,20 +25,20 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 #[derive(Debug)]
 pub struct SearchUnitSupportRequestForm {
     session_id: String,
-    support_card_number: String,
+    support_card_number: i32,
     target_unit_card_index_list: Vec<String>,
 }
 
 impl SearchUnitSupportRequestForm {
-    pub fn new(session_id: &str, support_card_number: &str, target_unit_card_index_list: Vec<String>) -> Self {
+    pub fn new(session_id: &str, support_card_number: i32, target_unit_card_index_list: Vec<String>) -> Self {
         SearchUnitSupportRequestForm {
             session_id: session_id.to_string(),
-            support_card_number: support_card_number.to_string(),
+            support_card_number,
             target_unit_card_index_list,
         }
     }
 
-    pub fn get_support_card_number(&self) -> &str { &self.support_card_number }
+    pub fn get_support_card_number(&self) -> i32 { self.support_card_number }
 
     pub fn get_target_unit_card_index_list(&self) -> &Vec<String> {
         &self.target_unit_card_index_list
@@ -34,4 +34,6 @@ impl CheckSearchUnitSupportAvailableResponseForm {
             false_message as i32,
             HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
\ No newline at end of file
@@ -58,4 +58,6 @@ impl DrawSupportResponseForm {
             Vec::new()
         )
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
\ No newline at end of file
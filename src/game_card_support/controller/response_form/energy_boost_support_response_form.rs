@@ -63,4 +63,6 @@ impl EnergyBoostSupportResponseForm {
             HashMap::new(),
             Vec::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::card_effect_definition::entity::support_effect_definition::SupportEffectDefinition;
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+use crate::game_card_support::entity::game_card_support_effect::GameCardSupportEffect;
+use crate::game_card_support::handler::game_card_support_handler::GameCardSupportHandler;
+
+pub struct DataDrivenSupportCardFunction {
+    support_effect_definition: SupportEffectDefinition,
+}
+
+impl DataDrivenSupportCardFunction {
+    pub fn new(support_effect_definition: SupportEffectDefinition) -> Self {
+        DataDrivenSupportCardFunction { support_effect_definition }
+    }
+}
+
+impl GameCardSupportHandler for DataDrivenSupportCardFunction {
+    unsafe fn generate_support_card_effect_summary(&self) -> GameCardSupportEffect {
+        println!("DataDrivenSupportCardFunction: generate_support_card_effect_summary()");
+
+        let definition = &self.support_effect_definition;
+        let mut game_card_support_effect = match definition.get_energy_from_deck() {
+            Some(energy_from_deck) => GameCardSupportEffect::new(energy_from_deck.get_race(), energy_from_deck.get_count()),
+            None => GameCardSupportEffect::new(RaceEnum::Dummy, 0),
+        };
+
+        if let Some(card_id) = definition.get_need_to_find_card_id() {
+            game_card_support_effect.set_need_to_find_card_id(card_id);
+        }
+        if let Some(count) = definition.get_need_to_draw_card_count() {
+            game_card_support_effect.set_need_to_draw_card_count(count);
+        }
+        if let Some(unit_from_deck) = definition.get_unit_from_deck() {
+            game_card_support_effect.set_unit_from_deck(unit_from_deck.get_grade_limit(), unit_from_deck.get_count());
+        }
+
+        game_card_support_effect
+    }
+}
@@ -2,3 +2,4 @@ pub mod game_card_support_handler;
 pub mod handler_of_2;
 pub mod handler_of_20;
 pub mod handler_of_30;
+pub mod data_driven;
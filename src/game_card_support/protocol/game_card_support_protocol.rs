@@ -0,0 +1,45 @@
+use crate::game_card_support::controller::game_card_support_controller::GameCardSupportController;
+use crate::game_card_support::controller::game_card_support_controller_impl::GameCardSupportControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::energy_boost_support_request_form_generator::create_energy_boost_support_request_form;
+use crate::request_generator::general_draw_support_request_form_generator::create_general_draw_support_request_form;
+use crate::request_generator::search_unit_support_request_form_generator::{create_check_search_unit_support_available_request_form, create_search_unit_support_request_form};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_support_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Check Search Unit Support Usage
+    protocol_registry.register_battle_room_action(501, "CHECK_SEARCH_UNIT_SUPPORT_USAGE", create_check_search_unit_support_available_request_form, |request| async move {
+        let game_card_support_controller_mutex = GameCardSupportControllerImpl::get_instance();
+        let game_card_support_controller = game_card_support_controller_mutex.lock().await;
+
+        let response = game_card_support_controller.check_search_unit_support_available(request).await;
+        ResponseType::CHECK_SEARCH_UNIT_SUPPORT_USAGE(response)
+    });
+
+    // Energy Boost Support Usage
+    protocol_registry.register_battle_room_action(1005, "ENERGY_BOOST_SUPPORT_USAGE", create_energy_boost_support_request_form, |request| async move {
+        let game_card_support_controller_mutex = GameCardSupportControllerImpl::get_instance();
+        let game_card_support_controller = game_card_support_controller_mutex.lock().await;
+
+        let response = game_card_support_controller.request_to_use_energy_boost_support(request).await;
+        ResponseType::ENERGY_BOOST_SUPPORT_USAGE(response)
+    });
+
+    // Search Unit Support Usage
+    protocol_registry.register_battle_room_action(1011, "SEARCH_UNIT_SUPPORT_USAGE", create_search_unit_support_request_form, |request| async move {
+        let game_card_support_controller_mutex = GameCardSupportControllerImpl::get_instance();
+        let game_card_support_controller = game_card_support_controller_mutex.lock().await;
+
+        let response = game_card_support_controller.request_to_use_search_unit_support(request).await;
+        ResponseType::SEARCH_UNIT_SUPPORT_USAGE(response)
+    });
+
+    // Draw Support Usage
+    protocol_registry.register_battle_room_action(1013, "GENERAL_DRAW_SUPPORT_USAGE", create_general_draw_support_request_form, |request| async move {
+        let game_card_support_controller_mutex = GameCardSupportControllerImpl::get_instance();
+        let game_card_support_controller = game_card_support_controller_mutex.lock().await;
+
+        let response = game_card_support_controller.request_to_use_draw_support(request).await;
+        ResponseType::GENERAL_DRAW_SUPPORT_USAGE(response)
+    });
+}
@@ -7,6 +7,9 @@ use tokio::sync::Mutex as AsyncMutex;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum::Dummy;
 use crate::game_card_support::entity::game_card_support_effect::GameCardSupportEffect;
 use crate::game_card_support::handler::game_card_support_handler::GameCardSupportHandler;
+use crate::game_card_support::handler::data_driven::game_card_support_data_driven_handler_impl::DataDrivenSupportCardFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 
 use crate::game_card_support::handler::handler_of_20::game_card_support_20_handler_impl::SupportCard_20_Function;
 use crate::game_card_support::handler::handler_of_2::game_card_support_2_handler_impl::SupportCard_2_Function;
@@ -69,6 +72,13 @@ impl GameCardSupportRepositoryImpl {
         support_card_functions.insert(190, Box::new(NoneFunction) as Box<dyn GameCardSupportHandler>);
         support_card_functions.insert(195, Box::new(NoneFunction) as Box<dyn GameCardSupportHandler>);
 
+        // 카드 효과 정의 파일에 있는 카드는 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (support_card_number, support_effect_definition) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_support_effect_definition_map() {
+            support_card_functions.insert(*support_card_number, Box::new(DataDrivenSupportCardFunction::new(support_effect_definition.clone())) as Box<dyn GameCardSupportHandler>);
+        }
+
         GameCardSupportRepositoryImpl { support_card_functions }
     }
 
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_card_token::controller::response_form::notice_summoned_token_unit_response_form::NoticeSummonedTokenUnitResponseForm;
+
+#[async_trait]
+pub trait GameCardTokenController {
+    async fn notice_summoned_token_unit(
+        &self, notice_summoned_token_unit_request_form: NoticeSummonedTokenUnitRequestForm) -> NoticeSummonedTokenUnitResponseForm;
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::game_card_token::controller::game_card_token_controller::GameCardTokenController;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_card_token::controller::response_form::notice_summoned_token_unit_response_form::NoticeSummonedTokenUnitResponseForm;
+use crate::notify_player_action_info::service::notify_player_action_info_service::NotifyPlayerActionInfoService;
+use crate::notify_player_action_info::service::notify_player_action_info_service_impl::NotifyPlayerActionInfoServiceImpl;
+
+pub struct GameCardTokenControllerImpl {
+    notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+}
+
+impl GameCardTokenControllerImpl {
+    pub fn new(notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>) -> Self {
+
+        GameCardTokenControllerImpl {
+            notify_player_action_info_service,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardTokenControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardTokenControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardTokenControllerImpl::new(
+                            NotifyPlayerActionInfoServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl GameCardTokenController for GameCardTokenControllerImpl {
+    // 토큰 소환은 이벤트 버스의 SummonTokenUnit 반응으로 처리되고, 여기서는 그 결과를 양 플레이어에게 공지
+    async fn notice_summoned_token_unit(
+        &self, notice_summoned_token_unit_request_form: NoticeSummonedTokenUnitRequestForm) -> NoticeSummonedTokenUnitResponseForm {
+
+        println!("GameCardTokenControllerImpl: notice_summoned_token_unit()");
+
+        let notice_summon_token_unit_request_list =
+            notice_summoned_token_unit_request_form.to_notice_summon_token_unit_request_list();
+
+        if notice_summon_token_unit_request_list.is_empty() {
+            return NoticeSummonedTokenUnitResponseForm::new(0)
+        }
+
+        let mut notify_player_action_info_service_guard =
+            self.notify_player_action_info_service.lock().await;
+
+        let mut noticed_token_unit_count = 0;
+        for notice_summon_token_unit_request in notice_summon_token_unit_request_list {
+            let notice_response =
+                notify_player_action_info_service_guard.notice_summon_token_unit(
+                    notice_summon_token_unit_request).await;
+
+            if notice_response.is_success() {
+                noticed_token_unit_count += 1;
+            }
+        }
+
+        drop(notify_player_action_info_service_guard);
+
+        NoticeSummonedTokenUnitResponseForm::new(noticed_token_unit_count)
+    }
+}
\ No newline at end of file
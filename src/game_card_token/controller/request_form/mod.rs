@@ -0,0 +1 @@
+pub mod notice_summoned_token_unit_request_form;
\ No newline at end of file
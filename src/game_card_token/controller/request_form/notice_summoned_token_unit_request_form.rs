@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use crate::notify_player_action_info::service::request::notice_summon_token_unit_request::NoticeSummonTokenUnitRequest;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex::{Opponent, You};
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+
+// 이벤트 처리 결과로 소환된 토큰 목록 (소환한 플레이어 -> 토큰 목록)
+#[derive(Debug)]
+pub struct NoticeSummonedTokenUnitRequestForm {
+    account_unique_id: i32,
+    opponent_unique_id: i32,
+    summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>,
+}
+
+impl NoticeSummonedTokenUnitRequestForm {
+    pub fn new(account_unique_id: i32,
+               opponent_unique_id: i32,
+               summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>) -> Self {
+
+        NoticeSummonedTokenUnitRequestForm {
+            account_unique_id,
+            opponent_unique_id,
+            summoned_token_unit_map,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+
+    pub fn get_summoned_token_unit_map(&self) -> &HashMap<i32, Vec<SummonedTokenUnitInfo>> {
+        &self.summoned_token_unit_map
+    }
+
+    // 토큰은 응답을 거치지 않고 이벤트 반응으로 생기므로 소환한 쪽과 상대 모두에게 알림
+    pub fn to_notice_summon_token_unit_request_list(&self) -> Vec<NoticeSummonTokenUnitRequest> {
+        let mut notice_summon_token_unit_request_list = Vec::new();
+
+        for player_unique_id in [self.account_unique_id, self.opponent_unique_id] {
+            let other_player_unique_id =
+                if player_unique_id == self.account_unique_id { self.opponent_unique_id } else { self.account_unique_id };
+
+            let summoned_token_unit_list = match self.summoned_token_unit_map.get(&player_unique_id) {
+                Some(summoned_token_unit_list) => summoned_token_unit_list,
+                None => continue,
+            };
+
+            for summoned_token_unit_info in summoned_token_unit_list {
+                notice_summon_token_unit_request_list.push(NoticeSummonTokenUnitRequest::new(
+                    player_unique_id, HashMap::from([(You, summoned_token_unit_info.clone())])));
+                notice_summon_token_unit_request_list.push(NoticeSummonTokenUnitRequest::new(
+                    other_player_unique_id, HashMap::from([(Opponent, summoned_token_unit_info.clone())])));
+            }
+        }
+
+        notice_summon_token_unit_request_list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_notice_summon_token_unit_request_list() {
+        let notice_summoned_token_unit_request_form = NoticeSummonedTokenUnitRequestForm::new(
+            1, 2, HashMap::from([(2, vec![SummonedTokenUnitInfo::new(47, 3)])]));
+
+        let notice_summon_token_unit_request_list =
+            notice_summoned_token_unit_request_form.to_notice_summon_token_unit_request_list();
+
+        assert_eq!(notice_summon_token_unit_request_list.len(), 2);
+        assert_eq!(notice_summon_token_unit_request_list[0].get_opponent_unique_id(), 2);
+        assert_eq!(notice_summon_token_unit_request_list[0].get_player_summoned_token_unit_map().get(&You),
+                   Some(&SummonedTokenUnitInfo::new(47, 3)));
+        assert_eq!(notice_summon_token_unit_request_list[1].get_opponent_unique_id(), 1);
+        assert_eq!(notice_summon_token_unit_request_list[1].get_player_summoned_token_unit_map().get(&Opponent),
+                   Some(&SummonedTokenUnitInfo::new(47, 3)));
+    }
+}
@@ -0,0 +1 @@
+pub mod notice_summoned_token_unit_response_form;
\ No newline at end of file
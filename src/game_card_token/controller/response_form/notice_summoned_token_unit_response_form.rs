@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeSummonedTokenUnitResponseForm {
+    noticed_token_unit_count: i32,
+}
+
+impl NoticeSummonedTokenUnitResponseForm {
+    pub fn new(noticed_token_unit_count: i32) -> Self {
+        NoticeSummonedTokenUnitResponseForm {
+            noticed_token_unit_count
+        }
+    }
+
+    pub fn get_noticed_token_unit_count(&self) -> i32 { self.noticed_token_unit_count }
+}
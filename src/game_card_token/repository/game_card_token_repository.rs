@@ -0,0 +1,6 @@
+use crate::card_effect_definition::entity::token_unit_definition::TokenUnitDefinition;
+
+pub trait GameCardTokenRepository {
+    fn get_token_unit_definition(&self, token_card_id: i32) -> Option<TokenUnitDefinition>;
+    fn is_token_unit(&self, card_id: i32) -> bool;
+}
\ No newline at end of file
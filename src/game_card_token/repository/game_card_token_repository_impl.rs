@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::card_effect_definition::entity::token_unit_definition::TokenUnitDefinition;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
+use crate::game_card_token::repository::game_card_token_repository::GameCardTokenRepository;
+
+pub struct GameCardTokenRepositoryImpl {
+    // 토큰은 덱에 들어가지 않으므로 능력치를 카드 데이터의 token 항목에서만 가져옴
+    token_unit_definition_map: HashMap<i32, TokenUnitDefinition>,
+}
+
+impl GameCardTokenRepositoryImpl {
+    fn new() -> Self {
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        let token_unit_definition_map = card_effect_definition_repository
+            .get_card_effect_definition_table().get_token_unit_definition_map().clone();
+
+        GameCardTokenRepositoryImpl::with_token_unit_definition_map(token_unit_definition_map)
+    }
+
+    pub(crate) fn with_token_unit_definition_map(token_unit_definition_map: HashMap<i32, TokenUnitDefinition>) -> Self {
+        GameCardTokenRepositoryImpl {
+            token_unit_definition_map,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardTokenRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardTokenRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardTokenRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+impl GameCardTokenRepository for GameCardTokenRepositoryImpl {
+    fn get_token_unit_definition(&self, token_card_id: i32) -> Option<TokenUnitDefinition> {
+        println!("GameCardTokenRepositoryImpl: get_token_unit_definition()");
+
+        self.token_unit_definition_map.get(&token_card_id).cloned()
+    }
+
+    fn is_token_unit(&self, card_id: i32) -> bool {
+        self.token_unit_definition_map.contains_key(&card_id)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod game_card_token_repository;
+pub mod game_card_token_repository_impl;
\ No newline at end of file
@@ -170,8 +170,7 @@ impl GameCardToolController for GameCardToolControllerImpl {
         }
 
         // TODO: 세션을 제외하고 애초에 UI에서 숫자로 전송하면 더 좋다.
-        let tool_card_number_string = enhance_attack_point_tool_request_form.get_tool_card_id();
-        let tool_card_number = tool_card_number_string.parse::<i32>().unwrap();
+        let tool_card_number = enhance_attack_point_tool_request_form.get_tool_card_id();
 
         // 2. Hand 에 있는지 확인하여 해킹 여부 검증
         let check_protocol_hacking_response = self.is_valid_protocol(
@@ -224,8 +223,7 @@ impl GameCardToolController for GameCardToolControllerImpl {
         // let boost_race_reference = energy_from_deck_info.get_race();
 
         // // TODO: 세션을 제외하고 애초에 UI에서 숫자로 전송하면 더 좋다.
-        let unit_card_index_string = enhance_attack_point_tool_request_form.get_unit_index_number();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = enhance_attack_point_tool_request_form.get_unit_index_number();
         //
         // let mut game_field_unit_service_guard = self.game_field_unit_service.lock().await;
         // let attach_multiple_energy_to_unit_index_response = game_field_unit_service_guard.attach_multiple_energy_to_field_unit_index(
@@ -11,27 +11,27 @@ use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyR
 #[derive(Debug)]
 pub struct EnhanceAttackPointToolRequestForm {
     session_id: String,
-    unit_index_number: String,
-    tool_card_id: String,
+    unit_index_number: i32,
+    tool_card_id: i32,
 }
 
 impl EnhanceAttackPointToolRequestForm {
-    pub fn new(session_id: String, unit_index_number: String, tool_card_id: String) -> Self {
+    pub fn new(session_id: String, unit_index_number: i32, tool_card_id: i32) -> Self {
         EnhanceAttackPointToolRequestForm {
             session_id: session_id.to_string(),
-            unit_index_number: unit_index_number.to_string(),
-            tool_card_id: tool_card_id.to_string(),
+            unit_index_number,
+            tool_card_id,
         }
     }
 
     pub fn get_session_id(&self) -> &str {
         &self.session_id
     }
-    pub fn get_unit_index_number(&self) -> &str {
-        &self.unit_index_number
+    pub fn get_unit_index_number(&self) -> i32 {
+        self.unit_index_number
     }
-    pub fn get_tool_card_id(&self) -> &str {
-        &self.tool_card_id
+    pub fn get_tool_card_id(&self) -> i32 {
+        self.tool_card_id
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -0,0 +1,29 @@
+use crate::card_effect_definition::entity::tool_effect_definition::ToolEffectDefinition;
+use crate::game_card_tool::entity::game_card_tool_effect::GameCardToolEffect;
+use crate::game_card_tool::handler::game_card_tool_handler::GameCardToolHandler;
+
+pub struct DataDrivenToolCardFunction {
+    tool_effect_definition: ToolEffectDefinition,
+}
+
+impl DataDrivenToolCardFunction {
+    pub fn new(tool_effect_definition: ToolEffectDefinition) -> Self {
+        DataDrivenToolCardFunction { tool_effect_definition }
+    }
+}
+
+impl GameCardToolHandler for DataDrivenToolCardFunction {
+    unsafe fn summary_tool_card(&self) -> GameCardToolEffect {
+        println!("DataDrivenToolCardFunction: summary_tool_card()");
+
+        let definition = &self.tool_effect_definition;
+        let required_energy = definition.get_required_energy();
+        let mut game_card_tool_effect = GameCardToolEffect::new(required_energy.get_race(), required_energy.get_count());
+
+        if let Some(enhance_attack_point) = definition.get_enhance_attack_point() {
+            game_card_tool_effect.set_enhance_attack_point(enhance_attack_point);
+        }
+
+        game_card_tool_effect
+    }
+}
@@ -1,2 +1,3 @@
 pub mod game_card_tool_handler;
-pub mod handler_of_15;
\ No newline at end of file
+pub mod handler_of_15;
+pub mod data_driven;
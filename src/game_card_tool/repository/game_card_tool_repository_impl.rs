@@ -7,6 +7,9 @@ use tokio::sync::Mutex as AsyncMutex;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum::Dummy;
 use crate::game_card_tool::entity::game_card_tool_effect::GameCardToolEffect;
 use crate::game_card_tool::handler::game_card_tool_handler::GameCardToolHandler;
+use crate::game_card_tool::handler::data_driven::game_card_tool_data_driven_handler_impl::DataDrivenToolCardFunction;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
 
 use crate::game_card_tool::handler::handler_of_15::game_card_tool_15_handler_impl::ToolCard_15_Function;
 
@@ -37,6 +40,13 @@ impl GameCardToolRepositoryImpl {
         tool_card_functions.insert(129, Box::new(NoneFunction) as Box<dyn GameCardToolHandler>);
         tool_card_functions.insert(155, Box::new(NoneFunction) as Box<dyn GameCardToolHandler>);
 
+        // 카드 효과 정의 파일에 있는 카드는 컴파일된 핸들러보다 우선 적용
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (tool_card_number, tool_effect_definition) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_tool_effect_definition_map() {
+            tool_card_functions.insert(*tool_card_number, Box::new(DataDrivenToolCardFunction::new(tool_effect_definition.clone())) as Box<dyn GameCardToolHandler>);
+        }
+
         GameCardToolRepositoryImpl { tool_card_functions }
     }
 
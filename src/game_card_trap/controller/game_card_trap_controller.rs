@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::game_card_trap::controller::request_form::set_trap_card_request_form::SetTrapCardRequestForm;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::controller::response_form::set_trap_card_response_form::SetTrapCardResponseForm;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::TriggerTrapCardResponseForm;
+
+#[async_trait]
+pub trait GameCardTrapController {
+    async fn request_to_set_trap_card(
+        &self, set_trap_card_request_form: SetTrapCardRequestForm) -> SetTrapCardResponseForm;
+    async fn trigger_trap_card(
+        &self, trigger_trap_card_request_form: TriggerTrapCardRequestForm) -> TriggerTrapCardResponseForm;
+}
\ No newline at end of file
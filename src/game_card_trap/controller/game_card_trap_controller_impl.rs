@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::common::message::false_message_enum::FalseMessage::{MythicalCardRoundLimit, NotYourTurn, TrapZoneFull};
+use crate::game_card_trap::controller::game_card_trap_controller::GameCardTrapController;
+use crate::game_card_trap::controller::request_form::set_trap_card_request_form::SetTrapCardRequestForm;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::controller::response_form::set_trap_card_response_form::SetTrapCardResponseForm;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::TriggerTrapCardResponseForm;
+use crate::game_card_trap::service::game_card_trap_service::GameCardTrapService;
+use crate::game_card_trap::service::game_card_trap_service_impl::GameCardTrapServiceImpl;
+use crate::game_field_unit::service::game_field_unit_service::GameFieldUnitService;
+use crate::game_field_unit::service::game_field_unit_service_impl::GameFieldUnitServiceImpl;
+use crate::game_hand::service::game_hand_service::GameHandService;
+use crate::game_hand::service::game_hand_service_impl::GameHandServiceImpl;
+use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
+use crate::game_main_character::service::game_main_character_service::GameMainCharacterService;
+use crate::game_main_character::service::game_main_character_service_impl::GameMainCharacterServiceImpl;
+use crate::game_protocol_validation::service::game_protocol_validation_service::GameProtocolValidationService;
+use crate::game_protocol_validation::service::game_protocol_validation_service_impl::GameProtocolValidationServiceImpl;
+use crate::game_protocol_validation::service::request::can_use_card_request::CanUseCardRequest;
+use crate::game_protocol_validation::service::request::check_protocol_hacking_request::CheckProtocolHackingRequest;
+use crate::game_protocol_validation::service::request::is_it_trap_card_request::IsItTrapCardRequest;
+use crate::game_tomb::service::game_tomb_service::GameTombService;
+use crate::game_tomb::service::game_tomb_service_impl::GameTombServiceImpl;
+use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::notify_player_action_info::service::notify_player_action_info_service::NotifyPlayerActionInfoService;
+use crate::notify_player_action_info::service::notify_player_action_info_service_impl::NotifyPlayerActionInfoServiceImpl;
+use crate::redis::service::redis_in_memory_service::RedisInMemoryService;
+use crate::redis::service::redis_in_memory_service_impl::RedisInMemoryServiceImpl;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+use crate::ui_data_generator::service::ui_data_generator_service::UiDataGeneratorService;
+use crate::ui_data_generator::service::ui_data_generator_service_impl::UiDataGeneratorServiceImpl;
+
+pub struct GameCardTrapControllerImpl {
+    game_hand_service: Arc<AsyncMutex<GameHandServiceImpl>>,
+    battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+    game_card_trap_service: Arc<AsyncMutex<GameCardTrapServiceImpl>>,
+    game_field_unit_service: Arc<AsyncMutex<GameFieldUnitServiceImpl>>,
+    game_main_character_service: Arc<AsyncMutex<GameMainCharacterServiceImpl>>,
+    game_tomb_service: Arc<AsyncMutex<GameTombServiceImpl>>,
+    game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+    game_protocol_validation_service: Arc<AsyncMutex<GameProtocolValidationServiceImpl>>,
+    redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+    notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+    ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+}
+
+impl GameCardTrapControllerImpl {
+    pub fn new(game_hand_service: Arc<AsyncMutex<GameHandServiceImpl>>,
+               battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+               game_card_trap_service: Arc<AsyncMutex<GameCardTrapServiceImpl>>,
+               game_field_unit_service: Arc<AsyncMutex<GameFieldUnitServiceImpl>>,
+               game_main_character_service: Arc<AsyncMutex<GameMainCharacterServiceImpl>>,
+               game_tomb_service: Arc<AsyncMutex<GameTombServiceImpl>>,
+               game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+               game_protocol_validation_service: Arc<AsyncMutex<GameProtocolValidationServiceImpl>>,
+               redis_in_memory_service: Arc<AsyncMutex<RedisInMemoryServiceImpl>>,
+               notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+               ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+    ) -> Self {
+
+        GameCardTrapControllerImpl {
+            game_hand_service,
+            battle_room_service,
+            game_card_trap_service,
+            game_field_unit_service,
+            game_main_character_service,
+            game_tomb_service,
+            game_winner_check_service,
+            game_protocol_validation_service,
+            redis_in_memory_service,
+            notify_player_action_info_service,
+            ui_data_generator_service,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardTrapControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardTrapControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardTrapControllerImpl::new(
+                            GameHandServiceImpl::get_instance(),
+                            BattleRoomServiceImpl::get_instance(),
+                            GameCardTrapServiceImpl::get_instance(),
+                            GameFieldUnitServiceImpl::get_instance(),
+                            GameMainCharacterServiceImpl::get_instance(),
+                            GameTombServiceImpl::get_instance(),
+                            GameWinnerCheckServiceImpl::get_instance(),
+                            GameProtocolValidationServiceImpl::get_instance(),
+                            RedisInMemoryServiceImpl::get_instance(),
+                            NotifyPlayerActionInfoServiceImpl::get_instance(),
+                            UiDataGeneratorServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn is_valid_session(&self, request: GetValueWithKeyRequest) -> i32 {
+        let redis_in_memory_service_guard = self.redis_in_memory_service.lock().await;
+        let session_validation_response = redis_in_memory_service_guard.get_value_with_key(request).await;
+        drop(redis_in_memory_service_guard);
+        let value_string = session_validation_response.get_value();
+        value_string.parse::<i32>().unwrap_or_else(|_| { -1 })
+    }
+
+    async fn is_valid_protocol(&self, check_protocol_hacking_request: CheckProtocolHackingRequest) -> bool {
+        let mut game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let check_protocol_hacking_response = game_protocol_validation_service_guard.check_protocol_hacking(check_protocol_hacking_request).await;
+        drop(game_protocol_validation_service_guard);
+        check_protocol_hacking_response.is_success()
+    }
+
+    async fn is_it_trap_card(&self, is_it_trap_card_request: IsItTrapCardRequest) -> bool {
+        let game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let is_it_trap_card_response = game_protocol_validation_service_guard.is_it_trap_card(is_it_trap_card_request).await;
+        drop(game_protocol_validation_service_guard);
+        is_it_trap_card_response.is_success()
+    }
+
+    async fn is_able_to_use(&self, can_use_card_request: CanUseCardRequest) -> bool {
+        let mut game_protocol_validation_service_guard = self.game_protocol_validation_service.lock().await;
+        let can_use_card_response = game_protocol_validation_service_guard.can_use_card(can_use_card_request).await;
+        drop(game_protocol_validation_service_guard);
+        can_use_card_response.is_success()
+    }
+}
+
+#[async_trait]
+impl GameCardTrapController for GameCardTrapControllerImpl {
+    async fn request_to_set_trap_card(
+        &self, set_trap_card_request_form: SetTrapCardRequestForm) -> SetTrapCardResponseForm {
+
+        println!("GameCardTrapControllerImpl: request_to_set_trap_card()");
+
+        // 1. Redis 에서 토큰을 가지고 있는지 검증
+        let account_unique_id = self.is_valid_session(
+            set_trap_card_request_form
+                .to_session_validation_request()).await;
+
+        if account_unique_id == -1 {
+            println!("Invalid session");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 2. 자신의 턴인지 검증
+        let game_protocol_validation_service_guard =
+            self.game_protocol_validation_service.lock().await;
+
+        let is_this_your_turn_response =
+            game_protocol_validation_service_guard.is_this_your_turn(
+                set_trap_card_request_form
+                    .to_is_this_your_turn_request(account_unique_id)).await;
+
+        if !is_this_your_turn_response.is_success() {
+            println!("당신의 턴이 아닙니다.");
+            return SetTrapCardResponseForm::from_false_response_with_message(NotYourTurn)
+        }
+
+        drop(game_protocol_validation_service_guard);
+
+        let trap_card_id = set_trap_card_request_form.get_trap_card_id();
+
+        // 3. Hand 에 있는지 확인하여 해킹 여부 검증
+        let check_protocol_hacking_response = self.is_valid_protocol(
+            set_trap_card_request_form
+                .to_check_protocol_hacking_request(account_unique_id, trap_card_id)).await;
+
+        if !check_protocol_hacking_response {
+            println!("해킹범을 검거합니다!");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 4. 실제 함정 카드가 맞는지 확인
+        let is_it_trap_response = self.is_it_trap_card(
+            set_trap_card_request_form
+                .to_is_it_trap_card_request(trap_card_id)).await;
+
+        if !is_it_trap_response {
+            println!("함정 카드가 아닌데 요청이 왔으므로 당신도 해킹범입니다.");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 5. 신화 카드 라운드 제약 검사
+        let can_use_card_response = self.is_able_to_use(
+            set_trap_card_request_form
+                .to_can_use_card_request(account_unique_id, trap_card_id)).await;
+
+        if !can_use_card_response {
+            println!("신화 카드는 4라운드 이후부터 사용 할 수 있습니다!");
+            return SetTrapCardResponseForm::from_false_response_with_message(MythicalCardRoundLimit)
+        }
+
+        // 6. Hand 에서 꺼내기 전에 함정 존에 세팅 가능한지 먼저 확인
+        let game_card_trap_service_guard =
+            self.game_card_trap_service.lock().await;
+
+        let check_trap_card_settable_response =
+            game_card_trap_service_guard.check_trap_card_settable(
+                set_trap_card_request_form
+                    .to_check_trap_card_settable_request(account_unique_id, trap_card_id)).await;
+
+        drop(game_card_trap_service_guard);
+
+        if check_trap_card_settable_response.is_trap_zone_full() {
+            println!("함정 존이 가득 찼습니다.");
+            return SetTrapCardResponseForm::from_false_response_with_message(TrapZoneFull)
+        }
+
+        if !check_trap_card_settable_response.is_settable() {
+            println!("효과가 정의되지 않은 함정 카드입니다.");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 7. Hand 에서 함정 카드 사용 처리 (세팅된 함정은 발동 전까지 무덤으로 가지 않음)
+        let mut game_hand_service_guard =
+            self.game_hand_service.lock().await;
+
+        let used_trap_card_id =
+            game_hand_service_guard.use_trap_card(
+                set_trap_card_request_form
+                    .to_use_game_hand_trap_card_request(account_unique_id, trap_card_id)).await.get_found_trap_card_id();
+
+        drop(game_hand_service_guard);
+
+        if used_trap_card_id == -1 {
+            println!("Hand 에서 함정 카드를 꺼내지 못했습니다.");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 8. Hand 에서 꺼낸 카드만 함정 존에 뒷면으로 세팅
+        let mut game_card_trap_service_guard =
+            self.game_card_trap_service.lock().await;
+
+        let set_trap_card_response =
+            game_card_trap_service_guard.set_trap_card(
+                set_trap_card_request_form
+                    .to_set_trap_card_request(account_unique_id, used_trap_card_id)).await;
+
+        drop(game_card_trap_service_guard);
+
+        if !set_trap_card_response.is_success() {
+            println!("함정 존에 세팅하지 못했습니다.");
+            return SetTrapCardResponseForm::default()
+        }
+
+        // 9. 상대에게 카드 번호를 숨긴 채로 세팅 사실을 공지
+        let mut ui_data_generator_service_guard =
+            self.ui_data_generator_service.lock().await;
+
+        let generate_use_my_hand_card_data_response =
+            ui_data_generator_service_guard.generate_use_my_hand_card_data(
+                set_trap_card_request_form
+                    .to_generate_use_my_hand_card_data_request(used_trap_card_id)).await;
+
+        drop(ui_data_generator_service_guard);
+
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let opponent_unique_id =
+            battle_room_service_guard.find_opponent_by_account_unique_id(
+                set_trap_card_request_form
+                    .to_find_opponent_by_account_id_request(account_unique_id)).await.get_opponent_unique_id();
+
+        drop(battle_room_service_guard);
+
+        let mut notify_player_action_info_service_guard =
+            self.notify_player_action_info_service.lock().await;
+
+        let notice_response =
+            notify_player_action_info_service_guard.notice_set_trap_card(
+                set_trap_card_request_form
+                    .to_notice_set_trap_card_request(
+                        opponent_unique_id,
+                        generate_use_my_hand_card_data_response
+                            .get_player_hand_use_map_for_notice().clone())).await;
+
+        println!("notice_response: {:?}", notice_response);
+
+        drop(notify_player_action_info_service_guard);
+
+        SetTrapCardResponseForm::new(
+            generate_use_my_hand_card_data_response.is_success_for_response(), -1)
+    }
+
+    async fn trigger_trap_card(
+        &self, trigger_trap_card_request_form: TriggerTrapCardRequestForm) -> TriggerTrapCardResponseForm {
+
+        println!("GameCardTrapControllerImpl: trigger_trap_card()");
+
+        let account_unique_id = trigger_trap_card_request_form.get_account_unique_id();
+
+        // 1. 함정은 행동한 플레이어의 상대가 세팅한 것
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let trap_owner_unique_id =
+            battle_room_service_guard.find_opponent_by_account_unique_id(
+                trigger_trap_card_request_form
+                    .to_find_opponent_by_account_id_request()).await.get_opponent_unique_id();
+
+        drop(battle_room_service_guard);
+
+        if trap_owner_unique_id == -1 {
+            return TriggerTrapCardResponseForm::default()
+        }
+
+        // 2. 조건에 맞는 함정이 있으면 존에서 꺼내 발동
+        let mut game_card_trap_service_guard =
+            self.game_card_trap_service.lock().await;
+
+        let trigger_trap_card_response =
+            game_card_trap_service_guard.trigger_trap_card(
+                trigger_trap_card_request_form
+                    .to_trigger_trap_card_request(trap_owner_unique_id)).await;
+
+        drop(game_card_trap_service_guard);
+
+        let triggered_trap_card_id = trigger_trap_card_response.get_triggered_trap_card_id();
+        if triggered_trap_card_id == -1 {
+            return TriggerTrapCardResponseForm::default()
+        }
+
+        println!("함정 카드 {} 발동!", triggered_trap_card_id);
+
+        // 3. 발동된 함정은 세팅한 플레이어의 무덤으로 이동
+        let mut game_tomb_service_guard =
+            self.game_tomb_service.lock().await;
+
+        game_tomb_service_guard.add_used_card_to_tomb(
+            trigger_trap_card_request_form
+                .to_place_to_tomb_request(trap_owner_unique_id, triggered_trap_card_id)).await;
+
+        drop(game_tomb_service_guard);
+
+        // 4. 함정을 발동시킨 유닛에게 피해 적용
+        let triggering_unit_index = trigger_trap_card_request_form.get_triggering_unit_index();
+        let damage_to_triggering_unit = trigger_trap_card_response.get_damage_to_triggering_unit();
+
+        let mut triggering_unit_health_point = -1;
+        let mut dead_unit_index = -1;
+
+        if damage_to_triggering_unit != -1 && triggering_unit_index != -1 {
+            let mut game_field_unit_service_guard =
+                self.game_field_unit_service.lock().await;
+
+            game_field_unit_service_guard.apply_damage_to_target_unit_index(
+                trigger_trap_card_request_form
+                    .to_apply_damage_to_triggering_unit_request(damage_to_triggering_unit)).await;
+
+            triggering_unit_health_point =
+                game_field_unit_service_guard.get_current_health_point_of_field_unit_by_index(
+                    trigger_trap_card_request_form
+                        .to_get_current_health_point_of_triggering_unit_request()).await.get_current_unit_health_point();
+
+            let judge_death_of_unit_response =
+                game_field_unit_service_guard.judge_death_of_unit(
+                    trigger_trap_card_request_form
+                        .to_judge_death_of_triggering_unit_request()).await;
+
+            drop(game_field_unit_service_guard);
+
+            if judge_death_of_unit_response.get_dead_unit_id() != -1 {
+                println!("함정에 걸린 유닛이 사망했으므로 묘지로 이동합니다.");
+                dead_unit_index = judge_death_of_unit_response.get_dead_unit_index();
+
+                let mut game_tomb_service_guard =
+                    self.game_tomb_service.lock().await;
+
+                game_tomb_service_guard.add_dead_unit_to_tomb(
+                    trigger_trap_card_request_form
+                        .to_place_to_tomb_request(
+                            account_unique_id,
+                            judge_death_of_unit_response.get_dead_unit_id())).await;
+
+                drop(game_tomb_service_guard);
+            }
+        }
+
+        // 5. 함정을 발동시킨 플레이어의 본체에 피해 적용
+        let damage_to_main_character = trigger_trap_card_response.get_damage_to_opponent_main_character();
+
+        let mut main_character_health_point = -1;
+        let mut main_character_status = StatusMainCharacterEnum::Survival;
+
+        if damage_to_main_character != -1 {
+            let mut game_main_character_service_guard =
+                self.game_main_character_service.lock().await;
+
+            game_main_character_service_guard.apply_damage_to_main_character(
+                trigger_trap_card_request_form
+                    .to_apply_damage_to_main_character_request(damage_to_main_character)).await;
+
+            let check_main_character_of_account_unique_id_response =
+                game_main_character_service_guard.check_main_character_of_account_unique_id(
+                    trigger_trap_card_request_form
+                        .to_check_main_character_of_account_unique_id_request()).await;
+
+            drop(game_main_character_service_guard);
+
+            main_character_health_point =
+                check_main_character_of_account_unique_id_response.get_current_health_point();
+            main_character_status =
+                check_main_character_of_account_unique_id_response.get_status_main_character().clone();
+
+            // 사망하면 함정을 세팅한 플레이어의 승리
+            if main_character_status == StatusMainCharacterEnum::Death {
+                let mut game_winner_check_service_guard =
+                    self.game_winner_check_service.lock().await;
+
+                game_winner_check_service_guard.set_game_winner(
+                    trigger_trap_card_request_form
+                        .to_check_main_character_for_setting_game_winner_request(trap_owner_unique_id)).await;
+
+                drop(game_winner_check_service_guard);
+            }
+        }
+
+        // 6. 발동 시점에만 함정 카드 번호를 공개 (세팅한 쪽에는 공지, 발동시킨 쪽에는 응답으로 전달)
+        let mut ui_data_generator_service_guard =
+            self.ui_data_generator_service.lock().await;
+
+        let generate_opponent_trap_card_trigger_data_response =
+            ui_data_generator_service_guard.generate_opponent_trap_card_trigger_data(
+                trigger_trap_card_request_form
+                    .to_generate_opponent_trap_card_trigger_data_request(triggered_trap_card_id)).await;
+
+        let (unit_health_point_map_for_actor, unit_health_point_map_for_owner) =
+            if triggering_unit_health_point != -1 {
+                let response =
+                    ui_data_generator_service_guard.generate_my_specific_unit_health_point_data(
+                        trigger_trap_card_request_form
+                            .to_generate_my_specific_unit_health_point_data_request(triggering_unit_health_point)).await;
+
+                (response.get_player_field_unit_health_point_map_for_response().clone(),
+                 response.get_player_field_unit_health_point_map_for_notice().clone())
+            } else {
+                (HashMap::new(), HashMap::new())
+            };
+
+        let (unit_death_map_for_actor, unit_death_map_for_owner) =
+            if dead_unit_index != -1 {
+                let response =
+                    ui_data_generator_service_guard.generate_my_specific_unit_death_data(
+                        trigger_trap_card_request_form
+                            .to_generate_my_specific_unit_death_data_request(dead_unit_index)).await;
+
+                (response.get_player_field_unit_death_map_for_response().clone(),
+                 response.get_player_field_unit_death_map_for_notice().clone())
+            } else {
+                (HashMap::new(), HashMap::new())
+            };
+
+        let (main_character_health_point_map_for_actor, main_character_health_point_map_for_owner,
+             main_character_survival_map_for_actor, main_character_survival_map_for_owner) =
+            if main_character_health_point != -1 {
+                let health_point_response =
+                    ui_data_generator_service_guard.generate_my_main_character_health_point_data(
+                        trigger_trap_card_request_form
+                            .to_generate_my_main_character_health_point_data_request(main_character_health_point)).await;
+
+                let survival_response =
+                    ui_data_generator_service_guard.generate_my_main_character_survival_data(
+                        trigger_trap_card_request_form
+                            .to_generate_my_main_character_survival_data_request(main_character_status)).await;
+
+                (health_point_response.get_player_main_character_health_point_map_for_response().clone(),
+                 health_point_response.get_player_main_character_health_point_map_for_notice().clone(),
+                 survival_response.get_player_main_character_survival_map_for_response().clone(),
+                 survival_response.get_player_main_character_survival_map_for_notice().clone())
+            } else {
+                (HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new())
+            };
+
+        drop(ui_data_generator_service_guard);
+
+        let mut notify_player_action_info_service_guard =
+            self.notify_player_action_info_service.lock().await;
+
+        notify_player_action_info_service_guard.notice_trigger_trap_card(
+            trigger_trap_card_request_form
+                .to_notice_trigger_trap_card_request(
+                    trap_owner_unique_id,
+                    generate_opponent_trap_card_trigger_data_response
+                        .get_player_trap_card_trigger_map_for_notice().clone(),
+                    unit_health_point_map_for_owner,
+                    unit_death_map_for_owner,
+                    main_character_health_point_map_for_owner,
+                    main_character_survival_map_for_owner)).await;
+
+        drop(notify_player_action_info_service_guard);
+
+        // 발동시킨 플레이어에게는 행동 응답에 합쳐서 전달
+        TriggerTrapCardResponseForm::new(
+            true,
+            dead_unit_index != -1,
+            generate_opponent_trap_card_trigger_data_response
+                .get_player_trap_card_trigger_map_for_response().clone(),
+            unit_health_point_map_for_actor,
+            unit_death_map_for_actor,
+            main_character_health_point_map_for_actor,
+            main_character_survival_map_for_actor)
+    }
+}
\ No newline at end of file
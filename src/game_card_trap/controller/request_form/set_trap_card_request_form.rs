@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_card_trap::service::request::check_trap_card_settable_request::CheckTrapCardSettableRequest;
+use crate::game_card_trap::service::request::set_trap_card_request::SetTrapCardRequest;
+use crate::game_hand::service::request::use_game_hand_trap_card_request::UseGameHandTrapCardRequest;
+use crate::game_protocol_validation::service::request::can_use_card_request::CanUseCardRequest;
+use crate::game_protocol_validation::service::request::check_protocol_hacking_request::CheckProtocolHackingRequest;
+use crate::game_protocol_validation::service::request::is_it_trap_card_request::IsItTrapCardRequest;
+use crate::game_protocol_validation::service::request::is_this_your_turn_request::IsThisYourTurnRequest;
+use crate::notify_player_action_info::service::request::notice_set_trap_card_request::NoticeSetTrapCardRequest;
+use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_request::GenerateUseMyHandCardDataRequest;
+
+#[derive(Debug)]
+pub struct SetTrapCardRequestForm {
+    session_id: String,
+    trap_card_id: i32,
+}
+
+impl SetTrapCardRequestForm {
+    pub fn new(session_id: &str, trap_card_id: i32) -> Self {
+        SetTrapCardRequestForm {
+            session_id: session_id.to_string(),
+            trap_card_id,
+        }
+    }
+
+    pub fn get_trap_card_id(&self) -> i32 { self.trap_card_id }
+
+    pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
+        GetValueWithKeyRequest::new(self.session_id.clone().as_str())
+    }
+
+    pub fn to_is_this_your_turn_request(&self,
+                                        account_unique_id: i32) -> IsThisYourTurnRequest {
+        IsThisYourTurnRequest::new(account_unique_id)
+    }
+
+    pub fn to_check_protocol_hacking_request(&self,
+                                             account_unique_id: i32,
+                                             trap_card_id: i32) -> CheckProtocolHackingRequest {
+        CheckProtocolHackingRequest::new(account_unique_id, trap_card_id)
+    }
+
+    pub fn to_is_it_trap_card_request(&self,
+                                      trap_card_id: i32) -> IsItTrapCardRequest {
+        IsItTrapCardRequest::new(trap_card_id)
+    }
+
+    pub fn to_can_use_card_request(&self,
+                                   account_unique_id: i32,
+                                   trap_card_id: i32) -> CanUseCardRequest {
+        CanUseCardRequest::new(account_unique_id, trap_card_id)
+    }
+
+    pub fn to_check_trap_card_settable_request(&self,
+                                               account_unique_id: i32,
+                                               trap_card_id: i32) -> CheckTrapCardSettableRequest {
+        CheckTrapCardSettableRequest::new(account_unique_id, trap_card_id)
+    }
+
+    pub fn to_set_trap_card_request(&self,
+                                    account_unique_id: i32,
+                                    trap_card_id: i32) -> SetTrapCardRequest {
+        SetTrapCardRequest::new(account_unique_id, trap_card_id)
+    }
+
+    pub fn to_use_game_hand_trap_card_request(&self,
+                                              account_unique_id: i32,
+                                              trap_card_id: i32) -> UseGameHandTrapCardRequest {
+        UseGameHandTrapCardRequest::new(account_unique_id, trap_card_id)
+    }
+
+    pub fn to_find_opponent_by_account_id_request(&self,
+                                                  account_unique_id: i32) -> FindOpponentByAccountIdRequest {
+        FindOpponentByAccountIdRequest::new(account_unique_id)
+    }
+
+    pub fn to_generate_use_my_hand_card_data_request(&self,
+                                                     used_hand_card_id: i32) -> GenerateUseMyHandCardDataRequest {
+        GenerateUseMyHandCardDataRequest::new(used_hand_card_id)
+    }
+
+    pub fn to_notice_set_trap_card_request(
+        &self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> NoticeSetTrapCardRequest {
+
+        NoticeSetTrapCardRequest::new(
+            opponent_unique_id,
+            player_hand_use_map_for_notice)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+use crate::game_card_trap::service::request::trigger_trap_card_request::TriggerTrapCardRequest;
+use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
+use crate::game_field_unit::service::request::get_current_health_point_of_field_unit_by_index_request::GetCurrentHealthPointOfFieldUnitByIndexRequest;
+use crate::game_field_unit::service::request::judge_death_of_unit_request::JudgeDeathOfUnitRequest;
+use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
+use crate::game_main_character::service::request::apply_damage_to_main_character_request::ApplyDamageToMainCharacterRequest;
+use crate::game_main_character::service::request::check_main_character_of_account_unique_id_request::CheckMainCharacterOfAccountUniqueIdRequest;
+use crate::game_tomb::service::request::place_to_tomb_request::PlaceToTombRequest;
+use crate::game_winner_check::service::request::check_main_character_request::CheckMainCharacterRequest;
+use crate::notify_player_action_info::service::request::notice_trigger_trap_card_request::NoticeTriggerTrapCardRequest;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+use crate::ui_data_generator::service::request::generate_my_main_character_health_point_data_request::GenerateMyMainCharacterHealthPointDataRequest;
+use crate::ui_data_generator::service::request::generate_my_main_character_survival_data_request::GenerateMyMainCharacterSurvivalDataRequest;
+use crate::ui_data_generator::service::request::generate_my_specific_unit_death_data_request::GenerateMySpecificUnitDeathDataRequest;
+use crate::ui_data_generator::service::request::generate_my_specific_unit_health_point_data_request::GenerateMySpecificUnitHealthPointDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_trap_card_trigger_data_request::GenerateOpponentTrapCardTriggerDataRequest;
+
+// 함정을 발동시킨 플레이어(account_unique_id) 기준으로 작성
+#[derive(Debug)]
+pub struct TriggerTrapCardRequestForm {
+    account_unique_id: i32,
+    trigger_condition: TrapTriggerCondition,
+    triggering_unit_index: i32,
+}
+
+impl TriggerTrapCardRequestForm {
+    pub fn new(account_unique_id: i32,
+               trigger_condition: TrapTriggerCondition,
+               triggering_unit_index: i32) -> Self {
+        TriggerTrapCardRequestForm {
+            account_unique_id,
+            trigger_condition,
+            triggering_unit_index,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_trigger_condition(&self) -> TrapTriggerCondition { self.trigger_condition }
+
+    pub fn get_triggering_unit_index(&self) -> i32 { self.triggering_unit_index }
+
+    pub fn to_find_opponent_by_account_id_request(&self) -> FindOpponentByAccountIdRequest {
+        FindOpponentByAccountIdRequest::new(self.account_unique_id)
+    }
+
+    pub fn to_trigger_trap_card_request(&self,
+                                        trap_owner_unique_id: i32) -> TriggerTrapCardRequest {
+        TriggerTrapCardRequest::new(trap_owner_unique_id, self.trigger_condition)
+    }
+
+    pub fn to_place_to_tomb_request(&self,
+                                    account_unique_id: i32,
+                                    card_id: i32) -> PlaceToTombRequest {
+        PlaceToTombRequest::new(account_unique_id, card_id)
+    }
+
+    pub fn to_apply_damage_to_triggering_unit_request(&self,
+                                                      damage: i32) -> ApplyDamageToTargetUnitIndexRequest {
+        ApplyDamageToTargetUnitIndexRequest::new(
+            self.account_unique_id, self.triggering_unit_index, damage)
+    }
+
+    pub fn to_get_current_health_point_of_triggering_unit_request(&self) -> GetCurrentHealthPointOfFieldUnitByIndexRequest {
+        GetCurrentHealthPointOfFieldUnitByIndexRequest::new(
+            self.account_unique_id, self.triggering_unit_index)
+    }
+
+    pub fn to_judge_death_of_triggering_unit_request(&self) -> JudgeDeathOfUnitRequest {
+        JudgeDeathOfUnitRequest::new(
+            self.account_unique_id, self.triggering_unit_index)
+    }
+
+    pub fn to_apply_damage_to_main_character_request(&self,
+                                                     damage: i32) -> ApplyDamageToMainCharacterRequest {
+        ApplyDamageToMainCharacterRequest::new(self.account_unique_id, damage)
+    }
+
+    pub fn to_check_main_character_of_account_unique_id_request(&self) -> CheckMainCharacterOfAccountUniqueIdRequest {
+        CheckMainCharacterOfAccountUniqueIdRequest::new(self.account_unique_id)
+    }
+
+    pub fn to_check_main_character_for_setting_game_winner_request(&self,
+                                                                    trap_owner_unique_id: i32) -> CheckMainCharacterRequest {
+        CheckMainCharacterRequest::new(trap_owner_unique_id, self.account_unique_id)
+    }
+
+    pub fn to_generate_opponent_trap_card_trigger_data_request(&self,
+                                                               triggered_trap_card_id: i32) -> GenerateOpponentTrapCardTriggerDataRequest {
+        GenerateOpponentTrapCardTriggerDataRequest::new(triggered_trap_card_id)
+    }
+
+    pub fn to_generate_my_specific_unit_health_point_data_request(&self,
+                                                                  updated_health_point: i32) -> GenerateMySpecificUnitHealthPointDataRequest {
+        GenerateMySpecificUnitHealthPointDataRequest::new(
+            self.triggering_unit_index, updated_health_point)
+    }
+
+    pub fn to_generate_my_specific_unit_death_data_request(&self,
+                                                           dead_unit_index: i32) -> GenerateMySpecificUnitDeathDataRequest {
+        GenerateMySpecificUnitDeathDataRequest::new(dead_unit_index)
+    }
+
+    pub fn to_generate_my_main_character_health_point_data_request(&self,
+                                                                   main_character_health_point: i32) -> GenerateMyMainCharacterHealthPointDataRequest {
+        GenerateMyMainCharacterHealthPointDataRequest::new(-1, main_character_health_point)
+    }
+
+    pub fn to_generate_my_main_character_survival_data_request(&self,
+                                                               main_character_status: StatusMainCharacterEnum) -> GenerateMyMainCharacterSurvivalDataRequest {
+        GenerateMyMainCharacterSurvivalDataRequest::new(main_character_status)
+    }
+
+    pub fn to_notice_trigger_trap_card_request(
+        &self,
+        receiver_unique_id: i32,
+        player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+        player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+        player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>
+    ) -> NoticeTriggerTrapCardRequest {
+
+        NoticeTriggerTrapCardRequest::new(
+            receiver_unique_id,
+            player_trap_card_trigger_map,
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+            player_main_character_health_point_map,
+            player_main_character_survival_map)
+    }
+}
\ No newline at end of file
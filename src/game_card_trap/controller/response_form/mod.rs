@@ -0,0 +1,2 @@
+pub mod set_trap_card_response_form;
+pub mod trigger_trap_card_response_form;
\ No newline at end of file
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use crate::common::message::false_message_enum::FalseMessage;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTrapCardResponseForm {
+    is_success: bool,
+    false_message_enum: i32,
+}
+
+impl SetTrapCardResponseForm {
+    pub fn new(is_success: bool, false_message_enum: i32) -> Self {
+        SetTrapCardResponseForm {
+            is_success,
+            false_message_enum
+        }
+    }
+
+    pub fn default() -> SetTrapCardResponseForm {
+        SetTrapCardResponseForm::new(false, -1)
+    }
+
+    pub fn from_false_response_with_message(false_message: FalseMessage) -> SetTrapCardResponseForm {
+        SetTrapCardResponseForm::new(false, false_message as i32)
+    }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
\ No newline at end of file
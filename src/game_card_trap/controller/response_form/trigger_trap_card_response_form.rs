@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
+use crate::protocol_handshake::entity::negotiated_protocol::TRAP_CARD_FEATURE;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+
+// 발동 결과를 포함하는 응답 form 들이 trapCard 기능을 모르는 클라이언트에게서 제외할 필드
+pub const TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST: &[(&str, &str)] = &[(TRAP_CARD_FEATURE, "trigger_trap_card_response_form")];
+
+// 함정을 발동시킨(행동한) 플레이어 시점의 발동 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerTrapCardResponseForm {
+    is_triggered: bool,
+    is_triggering_unit_dead: bool,
+    player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+    player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+    player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+}
+
+impl TriggerTrapCardResponseForm {
+    pub fn new(is_triggered: bool,
+               is_triggering_unit_dead: bool,
+               player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+               player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+               player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+               player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>) -> Self {
+
+        TriggerTrapCardResponseForm {
+            is_triggered,
+            is_triggering_unit_dead,
+            player_trap_card_trigger_map,
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+            player_main_character_health_point_map,
+            player_main_character_survival_map,
+        }
+    }
+
+    pub fn default() -> TriggerTrapCardResponseForm {
+        TriggerTrapCardResponseForm::new(
+            false,
+            false,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new())
+    }
+
+    pub fn is_triggered(&self) -> bool { self.is_triggered }
+
+    pub fn is_triggering_unit_dead(&self) -> bool { self.is_triggering_unit_dead }
+
+    // 함정 효과로 본체가 쓰러져 승패가 결정되었는지 여부
+    pub fn is_winner_decided(&self) -> bool {
+        self.player_main_character_survival_map.values()
+            .any(|main_character_status| *main_character_status == StatusMainCharacterEnum::Death)
+    }
+
+    pub fn get_player_trap_card_trigger_map(&self) -> &HashMap<PlayerIndex, UsedHandCardInfo> {
+        &self.player_trap_card_trigger_map
+    }
+
+    pub fn get_player_field_unit_health_point_map(&self) -> &HashMap<PlayerIndex, FieldUnitHealthPointInfo> {
+        &self.player_field_unit_health_point_map
+    }
+
+    pub fn get_player_field_unit_death_map(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
+        &self.player_field_unit_death_map
+    }
+
+    pub fn get_player_main_character_health_point_map(&self) -> &HashMap<PlayerIndex, i32> {
+        &self.player_main_character_health_point_map
+    }
+
+    pub fn get_player_main_character_survival_map(&self) -> &HashMap<PlayerIndex, StatusMainCharacterEnum> {
+        &self.player_main_character_survival_map
+    }
+}
+
+// 함정을 발동시킬 수 있는 행동의 응답은 발동 결과를 함께 실어 보낸다
+pub trait MergeTriggerTrapCardResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::message::false_message_enum::FalseMessage::AttackCanceledByTrap;
+    use crate::game_card_unit::controller::response_form::attack_unit_response_form::AttackUnitResponseForm;
+
+    #[test]
+    fn test_merge_trigger_trap_card_response_form() {
+        let mut player_trap_card_trigger_map = HashMap::new();
+        player_trap_card_trigger_map.insert(PlayerIndex::Opponent, UsedHandCardInfo::new(151, 7));
+
+        let trigger_trap_card_response_form = TriggerTrapCardResponseForm::new(
+            true,
+            true,
+            player_trap_card_trigger_map,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new());
+
+        let attack_unit_response_form =
+            AttackUnitResponseForm::from_response_with_message(AttackCanceledByTrap)
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form);
+
+        let attack_unit_response_json = serde_json::to_value(&attack_unit_response_form).unwrap();
+        let merged_trap_card_json = &attack_unit_response_json["trigger_trap_card_response_form"];
+
+        assert_eq!(merged_trap_card_json["is_triggered"], true);
+        assert_eq!(merged_trap_card_json["is_triggering_unit_dead"], true);
+        assert_eq!(merged_trap_card_json["player_trap_card_trigger_map"]["Opponent"]["card_id"], 151);
+    }
+}
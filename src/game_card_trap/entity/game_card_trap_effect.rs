@@ -0,0 +1,46 @@
+use crate::card_effect_definition::entity::trap_effect_definition::TrapEffectDefinition;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameCardTrapEffect {
+    trigger_condition: TrapTriggerCondition,
+    damage_to_triggering_unit: i32,
+    damage_to_opponent_main_character: i32,
+}
+
+impl GameCardTrapEffect {
+    pub fn new(trigger_condition: TrapTriggerCondition) -> Self {
+        GameCardTrapEffect {
+            trigger_condition,
+            damage_to_triggering_unit: -1,
+            damage_to_opponent_main_character: -1,
+        }
+    }
+
+    pub fn from_definition(trap_effect_definition: &TrapEffectDefinition) -> Self {
+        let mut game_card_trap_effect = GameCardTrapEffect::new(trap_effect_definition.get_trigger_condition());
+
+        if let Some(damage_to_triggering_unit) = trap_effect_definition.get_damage_to_triggering_unit() {
+            game_card_trap_effect.set_damage_to_triggering_unit(damage_to_triggering_unit);
+        }
+        if let Some(damage_to_opponent_main_character) = trap_effect_definition.get_damage_to_opponent_main_character() {
+            game_card_trap_effect.set_damage_to_opponent_main_character(damage_to_opponent_main_character);
+        }
+
+        game_card_trap_effect
+    }
+
+    pub fn get_trigger_condition(&self) -> TrapTriggerCondition { self.trigger_condition }
+
+    // 함정을 발동시킨 유닛 (공격 선언 유닛, 배치된 유닛)에게 가하는 피해
+    pub fn get_damage_to_triggering_unit(&self) -> i32 { self.damage_to_triggering_unit }
+    pub fn set_damage_to_triggering_unit(&mut self, damage_to_triggering_unit: i32) {
+        self.damage_to_triggering_unit = damage_to_triggering_unit;
+    }
+
+    // 함정을 발동시킨 플레이어의 본체에 가하는 피해
+    pub fn get_damage_to_opponent_main_character(&self) -> i32 { self.damage_to_opponent_main_character }
+    pub fn set_damage_to_opponent_main_character(&mut self, damage_to_opponent_main_character: i32) {
+        self.damage_to_opponent_main_character = damage_to_opponent_main_character;
+    }
+}
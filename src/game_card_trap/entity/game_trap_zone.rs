@@ -0,0 +1,63 @@
+pub const MAX_TRAP_ZONE_CARD_COUNT: usize = 3;
+
+// 뒷면으로 세팅된 함정 카드 목록 (세팅한 순서대로 발동 우선권을 가짐)
+#[derive(Debug)]
+pub struct GameTrapZone {
+    face_down_trap_card_list: Vec<i32>,
+}
+
+impl GameTrapZone {
+    pub fn new() -> GameTrapZone {
+        GameTrapZone {
+            face_down_trap_card_list: Vec::new(),
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.face_down_trap_card_list.len() >= MAX_TRAP_ZONE_CARD_COUNT
+    }
+
+    pub fn set_trap_card(&mut self, trap_card_id: i32) -> bool {
+        if self.is_full() {
+            return false
+        }
+
+        self.face_down_trap_card_list.push(trap_card_id);
+        true
+    }
+
+    pub fn take_first_trap_card_matching<F>(&mut self, is_matching: F) -> Option<i32>
+        where F: Fn(i32) -> bool {
+
+        let position = self.face_down_trap_card_list.iter()
+            .position(|trap_card_id| is_matching(*trap_card_id))?;
+
+        Some(self.face_down_trap_card_list.remove(position))
+    }
+
+    pub fn get_face_down_trap_card_list(&self) -> &Vec<i32> {
+        &self.face_down_trap_card_list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_trap_zone() {
+        let mut game_trap_zone = GameTrapZone::new();
+
+        for trap_card_id in 0..MAX_TRAP_ZONE_CARD_COUNT as i32 {
+            assert!(game_trap_zone.set_trap_card(40 + trap_card_id));
+        }
+        assert!(game_trap_zone.is_full());
+        assert!(!game_trap_zone.set_trap_card(50));
+
+        assert_eq!(game_trap_zone.take_first_trap_card_matching(|trap_card_id| trap_card_id > 40), Some(41));
+        assert_eq!(game_trap_zone.take_first_trap_card_matching(|trap_card_id| trap_card_id == 41), None);
+        assert_eq!(game_trap_zone.get_face_down_trap_card_list(), &vec![40, 42]);
+
+        println!("{:?}", game_trap_zone);
+    }
+}
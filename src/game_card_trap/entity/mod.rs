@@ -0,0 +1,3 @@
+pub mod trap_trigger_condition;
+pub mod game_card_trap_effect;
+pub mod game_trap_zone;
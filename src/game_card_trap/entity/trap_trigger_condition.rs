@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum TrapTriggerCondition {
+    Dummy = 0,
+    OnAttackDeclared = 1,
+    OnUnitDeployed = 2,
+    OnItemUsed = 3,
+}
@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+pub mod controller;
+pub mod protocol;
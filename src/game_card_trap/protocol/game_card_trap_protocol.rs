@@ -0,0 +1,16 @@
+use crate::game_card_trap::controller::game_card_trap_controller::GameCardTrapController;
+use crate::game_card_trap::controller::game_card_trap_controller_impl::GameCardTrapControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::set_trap_card_request_form_generator::create_set_trap_card_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_trap_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Set Trap Card Face-down
+    protocol_registry.register_battle_room_action(1018, "SET_TRAP_CARD", create_set_trap_card_request_form, |request| async move {
+        let game_card_trap_controller_mutex = GameCardTrapControllerImpl::get_instance();
+        let game_card_trap_controller = game_card_trap_controller_mutex.lock().await;
+
+        let response = game_card_trap_controller.request_to_set_trap_card(request).await;
+        ResponseType::SET_TRAP_CARD(response)
+    });
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod game_card_trap_protocol;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::game_card_trap::entity::game_card_trap_effect::GameCardTrapEffect;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+
+pub trait GameCardTrapRepository {
+    fn create_game_trap_zone_object(&mut self, account_unique_id: i32) -> bool;
+    fn is_registered_trap_card(&self, trap_card_id: i32) -> bool;
+    fn is_trap_zone_full(&self, account_unique_id: i32) -> bool;
+    fn set_trap_card(&mut self, account_unique_id: i32, trap_card_id: i32) -> bool;
+    fn trigger_trap_card(&mut self, account_unique_id: i32, trigger_condition: TrapTriggerCondition) -> Option<(i32, GameCardTrapEffect)>;
+    fn remove_game_trap_zone_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool;
+}
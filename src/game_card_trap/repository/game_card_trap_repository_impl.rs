@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
+use crate::game_card_trap::entity::game_card_trap_effect::GameCardTrapEffect;
+use crate::game_card_trap::entity::game_trap_zone::GameTrapZone;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+use crate::game_card_trap::repository::game_card_trap_repository::GameCardTrapRepository;
+
+pub struct GameCardTrapRepositoryImpl {
+    trap_card_effect_map: HashMap<i32, GameCardTrapEffect>,
+    game_trap_zone_map: HashMap<i32, GameTrapZone>,
+}
+
+impl GameCardTrapRepositoryImpl {
+    fn new() -> Self {
+        // 함정 카드는 컴파일된 핸들러 없이 카드 효과 정의 파일만으로 동작
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        let trap_card_effect_map = card_effect_definition_repository
+            .get_card_effect_definition_table().get_trap_effect_definition_map().iter()
+            .map(|(trap_card_id, trap_effect_definition)|
+                (*trap_card_id, GameCardTrapEffect::from_definition(trap_effect_definition)))
+            .collect();
+
+        GameCardTrapRepositoryImpl::with_trap_card_effect_map(trap_card_effect_map)
+    }
+
+    fn with_trap_card_effect_map(trap_card_effect_map: HashMap<i32, GameCardTrapEffect>) -> Self {
+        GameCardTrapRepositoryImpl {
+            trap_card_effect_map,
+            game_trap_zone_map: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get_game_trap_zone_map(&self) -> &HashMap<i32, GameTrapZone> {
+        &self.game_trap_zone_map
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardTrapRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardTrapRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardTrapRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+impl GameCardTrapRepository for GameCardTrapRepositoryImpl {
+    fn create_game_trap_zone_object(&mut self, account_unique_id: i32) -> bool {
+        println!("GameCardTrapRepositoryImpl: create_game_trap_zone_object()");
+
+        self.game_trap_zone_map.insert(account_unique_id, GameTrapZone::new());
+        true
+    }
+
+    fn is_registered_trap_card(&self, trap_card_id: i32) -> bool {
+        self.trap_card_effect_map.contains_key(&trap_card_id)
+    }
+
+    fn is_trap_zone_full(&self, account_unique_id: i32) -> bool {
+        self.game_trap_zone_map.get(&account_unique_id)
+            .map_or(true, |game_trap_zone| game_trap_zone.is_full())
+    }
+
+    fn set_trap_card(&mut self, account_unique_id: i32, trap_card_id: i32) -> bool {
+        println!("GameCardTrapRepositoryImpl: set_trap_card()");
+
+        if !self.is_registered_trap_card(trap_card_id) {
+            return false
+        }
+
+        match self.game_trap_zone_map.get_mut(&account_unique_id) {
+            Some(game_trap_zone) => game_trap_zone.set_trap_card(trap_card_id),
+            None => false,
+        }
+    }
+
+    fn trigger_trap_card(&mut self, account_unique_id: i32, trigger_condition: TrapTriggerCondition) -> Option<(i32, GameCardTrapEffect)> {
+        println!("GameCardTrapRepositoryImpl: trigger_trap_card()");
+
+        let game_trap_zone = self.game_trap_zone_map.get_mut(&account_unique_id)?;
+        let trap_card_effect_map = &self.trap_card_effect_map;
+
+        let triggered_trap_card_id = game_trap_zone.take_first_trap_card_matching(|trap_card_id|
+            trap_card_effect_map.get(&trap_card_id)
+                .map_or(false, |trap_card_effect| trap_card_effect.get_trigger_condition() == trigger_condition))?;
+
+        let triggered_trap_card_effect = trap_card_effect_map.get(&triggered_trap_card_id)?.clone();
+        Some((triggered_trap_card_id, triggered_trap_card_effect))
+    }
+
+    fn remove_game_trap_zone_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool {
+        self.game_trap_zone_map.remove(&account_unique_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigger_trap_card_by_condition() {
+        let mut attack_trap_effect = GameCardTrapEffect::new(TrapTriggerCondition::OnAttackDeclared);
+        attack_trap_effect.set_damage_to_triggering_unit(20);
+        let item_trap_effect = GameCardTrapEffect::new(TrapTriggerCondition::OnItemUsed);
+
+        let mut repository = GameCardTrapRepositoryImpl::with_trap_card_effect_map(
+            HashMap::from([(40, attack_trap_effect.clone()), (41, item_trap_effect)]));
+
+        repository.create_game_trap_zone_object(1);
+        assert!(!repository.set_trap_card(1, 99));
+        assert!(repository.set_trap_card(1, 41));
+        assert!(repository.set_trap_card(1, 40));
+
+        assert_eq!(repository.trigger_trap_card(1, TrapTriggerCondition::OnUnitDeployed), None);
+        assert_eq!(repository.trigger_trap_card(1, TrapTriggerCondition::OnAttackDeclared), Some((40, attack_trap_effect)));
+        assert_eq!(repository.trigger_trap_card(1, TrapTriggerCondition::OnAttackDeclared), None);
+        assert_eq!(repository.get_game_trap_zone_map().get(&1).unwrap().get_face_down_trap_card_list(), &vec![41]);
+
+        assert!(repository.remove_game_trap_zone_hash_by_account_unique_id(1));
+        assert!(repository.is_trap_zone_full(1));
+    }
+}
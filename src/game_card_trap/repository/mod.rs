@@ -0,0 +1,2 @@
+pub mod game_card_trap_repository;
+pub mod game_card_trap_repository_impl;
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::game_card_trap::service::request::check_trap_card_settable_request::CheckTrapCardSettableRequest;
+use crate::game_card_trap::service::request::set_trap_card_request::SetTrapCardRequest;
+use crate::game_card_trap::service::request::trigger_trap_card_request::TriggerTrapCardRequest;
+use crate::game_card_trap::service::response::check_trap_card_settable_response::CheckTrapCardSettableResponse;
+use crate::game_card_trap::service::response::set_trap_card_response::SetTrapCardResponse;
+use crate::game_card_trap::service::response::trigger_trap_card_response::TriggerTrapCardResponse;
+
+#[async_trait]
+pub trait GameCardTrapService {
+    async fn check_trap_card_settable(&self, check_trap_card_settable_request: CheckTrapCardSettableRequest) -> CheckTrapCardSettableResponse;
+    async fn set_trap_card(&mut self, set_trap_card_request: SetTrapCardRequest) -> SetTrapCardResponse;
+    async fn trigger_trap_card(&mut self, trigger_trap_card_request: TriggerTrapCardRequest) -> TriggerTrapCardResponse;
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::game_card_trap::repository::game_card_trap_repository::GameCardTrapRepository;
+use crate::game_card_trap::repository::game_card_trap_repository_impl::GameCardTrapRepositoryImpl;
+use crate::game_card_trap::service::game_card_trap_service::GameCardTrapService;
+use crate::game_card_trap::service::request::check_trap_card_settable_request::CheckTrapCardSettableRequest;
+use crate::game_card_trap::service::request::set_trap_card_request::SetTrapCardRequest;
+use crate::game_card_trap::service::request::trigger_trap_card_request::TriggerTrapCardRequest;
+use crate::game_card_trap::service::response::check_trap_card_settable_response::CheckTrapCardSettableResponse;
+use crate::game_card_trap::service::response::set_trap_card_response::SetTrapCardResponse;
+use crate::game_card_trap::service::response::trigger_trap_card_response::TriggerTrapCardResponse;
+
+pub struct GameCardTrapServiceImpl {
+    game_card_trap_repository: Arc<AsyncMutex<GameCardTrapRepositoryImpl>>,
+}
+
+impl GameCardTrapServiceImpl {
+    pub fn new(game_card_trap_repository: Arc<AsyncMutex<GameCardTrapRepositoryImpl>>) -> Self {
+        GameCardTrapServiceImpl {
+            game_card_trap_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameCardTrapServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameCardTrapServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameCardTrapServiceImpl::new(
+                            GameCardTrapRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl GameCardTrapService for GameCardTrapServiceImpl {
+    async fn check_trap_card_settable(&self, check_trap_card_settable_request: CheckTrapCardSettableRequest) -> CheckTrapCardSettableResponse {
+        println!("GameCardTrapServiceImpl: check_trap_card_settable()");
+
+        let game_card_trap_repository_guard = self.game_card_trap_repository.lock().await;
+
+        if !game_card_trap_repository_guard.is_registered_trap_card(check_trap_card_settable_request.get_trap_card_id()) {
+            println!("효과가 정의되지 않은 함정 카드입니다: {}", check_trap_card_settable_request.get_trap_card_id());
+            return CheckTrapCardSettableResponse::new(false, false)
+        }
+
+        if game_card_trap_repository_guard.is_trap_zone_full(check_trap_card_settable_request.get_account_unique_id()) {
+            return CheckTrapCardSettableResponse::new(false, true)
+        }
+
+        CheckTrapCardSettableResponse::new(true, false)
+    }
+
+    async fn set_trap_card(&mut self, set_trap_card_request: SetTrapCardRequest) -> SetTrapCardResponse {
+        println!("GameCardTrapServiceImpl: set_trap_card()");
+
+        let account_unique_id = set_trap_card_request.get_account_unique_id();
+        let trap_card_id = set_trap_card_request.get_trap_card_id();
+
+        let mut game_card_trap_repository_guard = self.game_card_trap_repository.lock().await;
+
+        if !game_card_trap_repository_guard.is_registered_trap_card(trap_card_id) {
+            println!("효과가 정의되지 않은 함정 카드입니다: {}", trap_card_id);
+            return SetTrapCardResponse::new(false, false)
+        }
+
+        if game_card_trap_repository_guard.is_trap_zone_full(account_unique_id) {
+            return SetTrapCardResponse::new(false, true)
+        }
+
+        let result = game_card_trap_repository_guard.set_trap_card(account_unique_id, trap_card_id);
+        SetTrapCardResponse::new(result, false)
+    }
+
+    async fn trigger_trap_card(&mut self, trigger_trap_card_request: TriggerTrapCardRequest) -> TriggerTrapCardResponse {
+        println!("GameCardTrapServiceImpl: trigger_trap_card()");
+
+        let mut game_card_trap_repository_guard = self.game_card_trap_repository.lock().await;
+
+        let triggered_trap_card_option = game_card_trap_repository_guard.trigger_trap_card(
+            trigger_trap_card_request.get_trap_owner_unique_id(),
+            trigger_trap_card_request.get_trigger_condition());
+
+        match triggered_trap_card_option {
+            Some((triggered_trap_card_id, triggered_trap_card_effect)) => TriggerTrapCardResponse::new(
+                triggered_trap_card_id,
+                triggered_trap_card_effect.get_damage_to_triggering_unit(),
+                triggered_trap_card_effect.get_damage_to_opponent_main_character()),
+            None => TriggerTrapCardResponse::default(),
+        }
+    }
+}
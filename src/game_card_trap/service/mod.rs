@@ -0,0 +1,4 @@
+pub mod request;
+pub mod response;
+pub mod game_card_trap_service;
+pub mod game_card_trap_service_impl;
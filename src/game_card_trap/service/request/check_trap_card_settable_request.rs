@@ -0,0 +1,18 @@
+#[derive(Debug)]
+pub struct CheckTrapCardSettableRequest {
+    account_unique_id: i32,
+    trap_card_id: i32,
+}
+
+impl CheckTrapCardSettableRequest {
+    pub fn new(account_unique_id: i32, trap_card_id: i32) -> Self {
+        CheckTrapCardSettableRequest {
+            account_unique_id,
+            trap_card_id
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_trap_card_id(&self) -> i32 { self.trap_card_id }
+}
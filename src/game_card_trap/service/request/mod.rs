@@ -0,0 +1,3 @@
+pub mod set_trap_card_request;
+pub mod trigger_trap_card_request;
+pub mod check_trap_card_settable_request;
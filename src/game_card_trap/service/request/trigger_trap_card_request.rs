@@ -0,0 +1,20 @@
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
+
+#[derive(Debug)]
+pub struct TriggerTrapCardRequest {
+    trap_owner_unique_id: i32,
+    trigger_condition: TrapTriggerCondition,
+}
+
+impl TriggerTrapCardRequest {
+    pub fn new(trap_owner_unique_id: i32, trigger_condition: TrapTriggerCondition) -> Self {
+        TriggerTrapCardRequest {
+            trap_owner_unique_id,
+            trigger_condition
+        }
+    }
+
+    pub fn get_trap_owner_unique_id(&self) -> i32 { self.trap_owner_unique_id }
+
+    pub fn get_trigger_condition(&self) -> TrapTriggerCondition { self.trigger_condition }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CheckTrapCardSettableResponse {
+    is_settable: bool,
+    is_trap_zone_full: bool,
+}
+
+impl CheckTrapCardSettableResponse {
+    pub fn new(is_settable: bool, is_trap_zone_full: bool) -> Self {
+        CheckTrapCardSettableResponse {
+            is_settable,
+            is_trap_zone_full
+        }
+    }
+
+    pub fn is_settable(&self) -> bool { self.is_settable }
+
+    pub fn is_trap_zone_full(&self) -> bool { self.is_trap_zone_full }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SetTrapCardResponse {
+    is_success: bool,
+    is_trap_zone_full: bool,
+}
+
+impl SetTrapCardResponse {
+    pub fn new(is_success: bool, is_trap_zone_full: bool) -> Self {
+        SetTrapCardResponse {
+            is_success,
+            is_trap_zone_full
+        }
+    }
+
+    pub fn is_success(&self) -> bool { self.is_success }
+
+    pub fn is_trap_zone_full(&self) -> bool { self.is_trap_zone_full }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TriggerTrapCardResponse {
+    triggered_trap_card_id: i32,
+    damage_to_triggering_unit: i32,
+    damage_to_opponent_main_character: i32,
+}
+
+impl TriggerTrapCardResponse {
+    pub fn new(triggered_trap_card_id: i32,
+               damage_to_triggering_unit: i32,
+               damage_to_opponent_main_character: i32) -> Self {
+        TriggerTrapCardResponse {
+            triggered_trap_card_id,
+            damage_to_triggering_unit,
+            damage_to_opponent_main_character
+        }
+    }
+
+    pub fn default() -> Self {
+        TriggerTrapCardResponse::new(-1, -1, -1)
+    }
+
+    pub fn get_triggered_trap_card_id(&self) -> i32 { self.triggered_trap_card_id }
+
+    pub fn get_damage_to_triggering_unit(&self) -> i32 { self.damage_to_triggering_unit }
+
+    pub fn get_damage_to_opponent_main_character(&self) -> i32 { self.damage_to_opponent_main_character }
+}
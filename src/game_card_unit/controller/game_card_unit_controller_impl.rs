@@ -8,11 +8,18 @@ use tokio::sync::Mutex as AsyncMutex;
 
 use crate::battle_room::service::battle_room_service::BattleRoomService;
 use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
-use crate::common::message::false_message_enum::FalseMessage::{MythicalCardRoundLimit, NotYourTurn, UnattackableUnit};
+use crate::common::message::false_message_enum::FalseMessage::{AttackCanceledByTrap, MythicalCardRoundLimit, NotYourTurn, UnattackableUnit};
 use crate::game_card_passive_skill::entity::passive_skill_casting_condition::PassiveSkillCastingCondition;
 
 use crate::game_card_passive_skill::service::game_card_passive_skill_service::GameCardPassiveSkillService;
 use crate::game_card_passive_skill::service::game_card_passive_skill_service_impl::GameCardPassiveSkillServiceImpl;
+use crate::game_card_trap::controller::game_card_trap_controller::GameCardTrapController;
+use crate::game_card_trap::controller::game_card_trap_controller_impl::GameCardTrapControllerImpl;
+use crate::game_card_token::controller::game_card_token_controller::GameCardTokenController;
+use crate::game_card_token::controller::game_card_token_controller_impl::GameCardTokenControllerImpl;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TriggerTrapCardResponseForm};
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::game_card_unit::controller::game_card_unit_controller::GameCardUnitController;
 use crate::game_card_unit::controller::request_form::attack_game_main_character_request_form::AttackGameMainCharacterRequestForm;
 use crate::game_card_unit::controller::request_form::attack_unit_request_form::AttackUnitRequestForm;
@@ -27,6 +34,8 @@ use crate::game_card_unit::service::game_card_unit_service_impl::GameCardUnitSer
 use crate::game_field_unit::entity::extra_effect::ExtraEffect::{DarkFire, Freeze};
 use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
+use crate::game_event_bus::service::game_event_bus_service::GameEventBusService;
+use crate::game_event_bus::service::game_event_bus_service_impl::GameEventBusServiceImpl;
 use crate::game_field_unit::service::game_field_unit_service::GameFieldUnitService;
 use crate::game_field_unit::service::game_field_unit_service_impl::GameFieldUnitServiceImpl;
 use crate::game_field_unit_action_possibility_validator::service::game_field_unit_action_possibility_validator_service::GameFieldUnitActionPossibilityValidatorService;
@@ -68,6 +77,9 @@ pub struct GameCardUnitControllerImpl {
     game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
     notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
     ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+    game_card_trap_controller: Arc<AsyncMutex<GameCardTrapControllerImpl>>,
+    game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+    game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
 }
 
 impl GameCardUnitControllerImpl {
@@ -85,6 +97,9 @@ impl GameCardUnitControllerImpl {
                game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
                notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
                ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+               game_card_trap_controller: Arc<AsyncMutex<GameCardTrapControllerImpl>>,
+               game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+               game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
     ) -> Self {
 
         GameCardUnitControllerImpl {
@@ -102,6 +117,9 @@ impl GameCardUnitControllerImpl {
             game_winner_check_service,
             notify_player_action_info_service,
             ui_data_generator_service,
+            game_card_trap_controller,
+            game_event_bus_service,
+            game_card_token_controller,
         }
     }
     pub fn get_instance() -> Arc<AsyncMutex<GameCardUnitControllerImpl>> {
@@ -123,7 +141,10 @@ impl GameCardUnitControllerImpl {
                             GameMainCharacterServiceImpl::get_instance(),
                             GameWinnerCheckServiceImpl::get_instance(),
                             NotifyPlayerActionInfoServiceImpl::get_instance(),
-                            UiDataGeneratorServiceImpl::get_instance())));
+                            UiDataGeneratorServiceImpl::get_instance(),
+                            GameCardTrapControllerImpl::get_instance(),
+                            GameEventBusServiceImpl::get_instance(),
+                            GameCardTokenControllerImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -142,6 +163,15 @@ impl GameCardUnitControllerImpl {
         drop(game_protocol_validation_service_guard);
         can_use_card_response.is_success()
     }
+
+    // 함정 처리 중 같은 서비스를 잠그므로 호출 전에 모든 서비스 잠금을 해제해야 함
+    // 발동 결과는 이후 행동 응답에 merge_trigger_trap_card_response_form 으로 합쳐서 전달
+    async fn trigger_opponent_trap_card(&self, trigger_trap_card_request_form: TriggerTrapCardRequestForm) -> TriggerTrapCardResponseForm {
+        let game_card_trap_controller_guard = self.game_card_trap_controller.lock().await;
+        let trigger_trap_card_response_form = game_card_trap_controller_guard.trigger_trap_card(trigger_trap_card_request_form).await;
+        drop(game_card_trap_controller_guard);
+        trigger_trap_card_response_form
+    }
 }
 
 #[async_trait]
@@ -158,8 +188,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         }
 
         // TODO: 세션을 제외하고 애초에 UI에서 숫자로 전송하면 더 좋다.
-        let unit_id_string = deploy_unit_request_form.get_unit_id();
-        let unit_card_id = unit_id_string.parse::<i32>().unwrap();
+        let unit_card_id = deploy_unit_request_form.get_unit_id();
 
         // 2. Game Protocol Validation Service 호출하여 필수 요소 검증
         let mut game_protocol_validation_service_guard =
@@ -315,7 +344,24 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
 
         drop(notify_player_action_info_service_guard);
 
+        // 11. 상대가 출격에 반응하는 함정을 세팅해 두었다면 발동
+        let trigger_trap_card_response_form =
+            self.trigger_opponent_trap_card(
+                deploy_unit_request_form
+                    .to_trigger_trap_card_request_form(
+                        account_unique_id,
+                        TrapTriggerCondition::OnUnitDeployed,
+                        add_unit_to_game_field_response.get_placed_unit_index())).await;
+
+        if trigger_trap_card_response_form.is_triggering_unit_dead() ||
+            trigger_trap_card_response_form.is_winner_decided() {
+            println!("출격한 유닛이 함정에 걸려 사망했거나 승패가 결정되어 출격 패시브는 처리하지 않습니다.");
+            return DeployUnitResponseForm::new(true, -1, Vec::new())
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
+        }
+
         DeployUnitResponseForm::new(true, -1, passive_skill_index_list_to_handle)
+            .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
     }
 
     async fn request_to_attack_unit(
@@ -348,8 +394,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         drop(game_protocol_validation_service_guard);
 
         // Battle Field 에서 공격하는 유닛의 index 를 토대로 id 값 확보
-        let attacker_unit_card_index_string = attack_unit_request_form.get_attacker_unit_index();
-        let attacker_unit_card_index = attacker_unit_card_index_string.parse::<i32>().unwrap();
+        let attacker_unit_card_index = attack_unit_request_form.get_attacker_unit_index();
 
         // 액션 가능한 턴인지 검증
         let mut game_field_unit_service_guard =
@@ -392,22 +437,6 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
 
         drop(game_field_unit_action_possibility_validator_service_guard);
 
-        // 유닛 인덱스에서 기본 공격력 정보 확보
-        let find_attacker_unit_attack_point_response =
-            game_field_unit_service_guard.acquire_unit_attack_point(
-                attack_unit_request_form
-                    .to_acquire_unit_attack_point_request(
-                        account_unique_id,
-                        attacker_unit_card_index)).await;
-
-        // extra effect 가지고 있는지 여부
-        let attacker_unit_extra_effect_list =
-            game_field_unit_service_guard.acquire_unit_extra_effect(
-                attack_unit_request_form
-                    .to_acquire_unit_extra_effect_request(
-                        account_unique_id,
-                        attacker_unit_card_index)).await.get_extra_status_effect_list().clone();
-
         // 공격을 위해 상대방 고유값 획득
         let battle_room_service_guard =
             self.battle_room_service.lock().await;
@@ -421,8 +450,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         drop(battle_room_service_guard);
 
         // 피격 유닛이 기본 공격 면역을 가지고 있는지 확인
-        let opponent_target_unit_card_index_string = attack_unit_request_form.get_target_unit_index();
-        let opponent_target_unit_card_index = opponent_target_unit_card_index_string.parse::<i32>().unwrap();
+        let opponent_target_unit_card_index = attack_unit_request_form.get_target_unit_index();
 
         let opponent_target_unit_passive_status_list =
             game_field_unit_service_guard.acquire_unit_passive_status_list(
@@ -436,11 +464,62 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
             return AttackUnitResponseForm::from_response_with_message(UnattackableUnit)
         }
 
+        let opponent_target_unit_id =
+            game_field_unit_service_guard.find_target_unit_id_by_index(
+                attack_unit_request_form
+                    .to_find_unit_id_by_index_request(
+                        opponent_unique_id,
+                        opponent_target_unit_card_index)).await.get_found_opponent_unit_id();
+
+        if opponent_target_unit_id == -1 {
+            println!("필드에 존재하지 않는 유닛을 공격 대상으로 지정했습니다.");
+            return AttackUnitResponseForm::default()
+        }
+
+        drop(game_field_unit_service_guard);
+
+        // 거절될 수 있는 검증을 모두 마친 뒤에만 공격 선언에 반응하는 상대 함정 발동
+        let trigger_trap_card_response_form =
+            self.trigger_opponent_trap_card(
+                attack_unit_request_form
+                    .to_trigger_trap_card_request_form(
+                        account_unique_id,
+                        TrapTriggerCondition::OnAttackDeclared,
+                        attacker_unit_card_index)).await;
+
+        if trigger_trap_card_response_form.is_triggering_unit_dead() ||
+            trigger_trap_card_response_form.is_winner_decided() {
+            println!("함정으로 공격 유닛이 사망했거나 승패가 결정되어 공격이 취소되었습니다.");
+            return AttackUnitResponseForm::from_response_with_message(AttackCanceledByTrap)
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
+        }
+
+        let mut game_field_unit_service_guard =
+            self.game_field_unit_service.lock().await;
+
+        // 유닛 인덱스에서 기본 공격력 정보 확보
+        let find_attacker_unit_attack_point_response =
+            game_field_unit_service_guard.acquire_unit_attack_point(
+                attack_unit_request_form
+                    .to_acquire_unit_attack_point_request(
+                        account_unique_id,
+                        attacker_unit_card_index)).await;
+
+        // extra effect 가지고 있는지 여부
+        let attacker_unit_extra_effect_list =
+            game_field_unit_service_guard.acquire_unit_extra_effect(
+                attack_unit_request_form
+                    .to_acquire_unit_extra_effect_request(
+                        account_unique_id,
+                        attacker_unit_card_index)).await.get_extra_status_effect_list().clone();
+
         // 적 타겟 유닛을 효과를 가지고 공격
         let attack_opponent_target_unit_with_extra_effect_response =
             game_field_unit_service_guard.attack_target_unit_with_extra_effect(
                 attack_unit_request_form
                     .to_attack_target_unit_with_extra_effect_request(
+                        account_unique_id,
+                        attacker_unit_card_index,
                         opponent_unique_id,
                         find_attacker_unit_attack_point_response.get_attack_point(),
                         &attacker_unit_extra_effect_list,
@@ -449,6 +528,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         if !attack_opponent_target_unit_with_extra_effect_response.is_success() {
             println!("필드에 존재하지 않는 유닛을 공격 대상으로 지정하여 공격에 실패했습니다.");
             return AttackUnitResponseForm::default()
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
         }
 
         let opponent_target_unit_health_point =
@@ -501,7 +581,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
             if judge_death_of_opponent_unit_response.get_dead_unit_id() != -1 {
                 println!("공격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-                game_tomb_service_guard.add_used_card_to_tomb(
+                game_tomb_service_guard.add_dead_unit_to_tomb(
                     attack_unit_request_form
                         .to_place_dead_unit_to_tomb_request(
                             opponent_unique_id,
@@ -510,6 +590,50 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
 
             drop(game_tomb_service_guard);
 
+            // 공격으로 발생한 이벤트 (피격, 사망 등) 에 대한 카드 반응 처리
+            let mut game_event_bus_service_guard =
+                self.game_event_bus_service.lock().await;
+
+            let process_pending_game_event_response =
+                game_event_bus_service_guard.process_pending_game_event(
+                    attack_unit_request_form
+                        .to_process_pending_game_event_request(
+                            account_unique_id,
+                            opponent_unique_id)).await;
+
+            drop(game_event_bus_service_guard);
+
+            // 이벤트 반응으로 소환된 토큰 공지
+            let game_card_token_controller_guard =
+                self.game_card_token_controller.lock().await;
+
+            game_card_token_controller_guard.notice_summoned_token_unit(
+                attack_unit_request_form
+                    .to_notice_summoned_token_unit_request_form(
+                        account_unique_id,
+                        opponent_unique_id,
+                        process_pending_game_event_response.get_summoned_token_unit_map().clone())).await;
+
+            drop(game_card_token_controller_guard);
+
+            let mut opponent_dead_unit_index_list =
+                vec![judge_death_of_opponent_unit_response.get_dead_unit_index()];
+            opponent_dead_unit_index_list.extend(
+                process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id));
+
+            let my_dead_unit_index_list =
+                process_pending_game_event_response.get_dead_unit_index_list_of_account(account_unique_id);
+
+            // 반응이 피격 유닛에 추가 피해를 준 경우 반응 이후의 체력을 우선함
+            let mut opponent_unit_health_point_map =
+                process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(opponent_unique_id);
+            opponent_unit_health_point_map
+                .entry(opponent_target_unit_card_index)
+                .or_insert(opponent_target_unit_health_point);
+
+            let my_unit_health_point_map =
+                process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(account_unique_id);
+
             let mut ui_data_generator_service_guard =
                 self.ui_data_generator_service.lock().await;
 
@@ -520,12 +644,32 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                             attacker_unit_card_index,
                             opponent_target_unit_card_index)).await;
 
-            let generate_opponent_specific_unit_health_point_data_response =
-                ui_data_generator_service_guard.generate_opponent_specific_unit_health_point_data(
+            let generate_opponent_multiple_unit_health_point_data_response =
+                ui_data_generator_service_guard.generate_opponent_multiple_unit_health_point_data(
                     attack_unit_request_form
-                        .to_generate_opponent_specific_unit_health_point_data_request(
-                            opponent_target_unit_card_index,
-                            opponent_target_unit_health_point)).await;
+                        .to_generate_opponent_multiple_unit_health_point_data_request(
+                            opponent_unit_health_point_map.into_iter().collect())).await;
+
+            let mut unit_health_point_data_for_response =
+                generate_opponent_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_response().clone();
+            let mut unit_health_point_data_for_notice =
+                generate_opponent_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_notice().clone();
+
+            // 이벤트 반응으로 자신의 유닛이 피해를 입은 경우에만 추가
+            if !my_unit_health_point_map.is_empty() {
+                let generate_my_multiple_unit_health_point_data_response =
+                    ui_data_generator_service_guard.generate_my_multiple_unit_health_point_data(
+                        attack_unit_request_form
+                            .to_generate_my_multiple_unit_health_point_data_request(
+                                my_unit_health_point_map.into_iter().collect())).await;
+
+                unit_health_point_data_for_response.extend(
+                    generate_my_multiple_unit_health_point_data_response
+                        .get_player_field_unit_health_point_map_for_response().clone());
+                unit_health_point_data_for_notice.extend(
+                    generate_my_multiple_unit_health_point_data_response
+                        .get_player_field_unit_health_point_map_for_notice().clone());
+            }
 
             let generate_opponent_specific_unit_harmful_effect_data_response =
                 ui_data_generator_service_guard.generate_opponent_specific_unit_harmful_effect_data(
@@ -534,11 +678,32 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                             opponent_target_unit_card_index,
                             opponent_target_unit_harmful_effect_list)).await;
 
-            let generate_opponent_specific_unit_death_data_response =
-                ui_data_generator_service_guard.generate_opponent_specific_unit_death_data(
+            let generate_opponent_multiple_unit_death_data_response =
+                ui_data_generator_service_guard.generate_opponent_multiple_unit_death_data(
                     attack_unit_request_form
-                        .to_generate_opponent_specific_unit_death_data_request(
-                            judge_death_of_opponent_unit_response.get_dead_unit_index())).await;
+                        .to_generate_opponent_multiple_unit_death_data_request(
+                            opponent_dead_unit_index_list)).await;
+
+            let mut unit_death_data_for_response =
+                generate_opponent_multiple_unit_death_data_response.get_player_field_unit_death_map_for_response().clone();
+            let mut unit_death_data_for_notice =
+                generate_opponent_multiple_unit_death_data_response.get_player_field_unit_death_map_for_notice().clone();
+
+            // 이벤트 반응으로 자신의 유닛이 사망한 경우에만 추가
+            if !my_dead_unit_index_list.is_empty() {
+                let generate_my_multiple_unit_death_data_response =
+                    ui_data_generator_service_guard.generate_my_multiple_unit_death_data(
+                        attack_unit_request_form
+                            .to_generate_my_multiple_unit_death_data_request(
+                                my_dead_unit_index_list)).await;
+
+                unit_death_data_for_response.extend(
+                    generate_my_multiple_unit_death_data_response
+                        .get_player_field_unit_death_map_for_response().clone());
+                unit_death_data_for_notice.extend(
+                    generate_my_multiple_unit_death_data_response
+                        .get_player_field_unit_death_map_for_notice().clone());
+            }
 
             drop(ui_data_generator_service_guard);
 
@@ -551,24 +716,21 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                         opponent_unique_id,
                         generate_my_specific_unit_basic_attack_data_response
                             .get_player_field_unit_attack_map_for_notice().clone(),
-                        generate_opponent_specific_unit_health_point_data_response
-                            .get_player_field_unit_health_point_map_for_notice().clone(),
+                        unit_health_point_data_for_notice,
                         generate_opponent_specific_unit_harmful_effect_data_response
                             .get_player_field_unit_harmful_effect_map_for_notice().clone(),
-                        generate_opponent_specific_unit_death_data_response
-                            .get_player_field_unit_death_map_for_notice().clone())).await;
+                        unit_death_data_for_notice)).await;
 
             drop(notify_player_action_info_service_guard);
 
             return AttackUnitResponseForm::new(
                 true,
                 -1,
-                generate_opponent_specific_unit_health_point_data_response
-                    .get_player_field_unit_health_point_map_for_response().clone(),
+                unit_health_point_data_for_response,
                 generate_opponent_specific_unit_harmful_effect_data_response
                     .get_player_field_unit_harmful_effect_map_for_response().clone(),
-                generate_opponent_specific_unit_death_data_response
-                    .get_player_field_unit_death_map_for_response().clone())
+                unit_death_data_for_response)
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
         }
 
         // 반격을 위해 피격 유닛의 공격력 확보
@@ -591,6 +753,8 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         game_field_unit_service_guard.attack_target_unit_with_extra_effect(
             attack_unit_request_form
                 .to_attack_target_unit_with_extra_effect_request(
+                    opponent_unique_id,
+                    opponent_target_unit_card_index,
                     account_unique_id,
                     find_opponent_target_unit_attack_point_response.get_attack_point(),
                     &opponent_target_unit_extra_effect_list,
@@ -633,7 +797,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         if judge_death_of_opponent_unit_response.get_dead_unit_id() != -1 {
             println!("공격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-            game_tomb_service_guard.add_used_card_to_tomb(
+            game_tomb_service_guard.add_dead_unit_to_tomb(
                 attack_unit_request_form
                     .to_place_dead_unit_to_tomb_request(
                         opponent_unique_id,
@@ -650,7 +814,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         if judge_death_of_attacker_unit_response.get_dead_unit_id() != -1 {
             println!("반격 당한 유닛이 사망했으므로 묘지로 이동합니다.");
 
-            game_tomb_service_guard.add_used_card_to_tomb(
+            game_tomb_service_guard.add_dead_unit_to_tomb(
                 attack_unit_request_form
                     .to_place_dead_unit_to_tomb_request(
                         account_unique_id,
@@ -660,6 +824,55 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         drop(game_field_unit_service_guard);
         drop(game_tomb_service_guard);
 
+        // 공격으로 발생한 이벤트 (피격, 사망 등) 에 대한 카드 반응 처리
+        let mut game_event_bus_service_guard =
+            self.game_event_bus_service.lock().await;
+
+        let process_pending_game_event_response =
+            game_event_bus_service_guard.process_pending_game_event(
+                attack_unit_request_form
+                    .to_process_pending_game_event_request(
+                        account_unique_id,
+                        opponent_unique_id)).await;
+
+        drop(game_event_bus_service_guard);
+
+        // 이벤트 반응으로 소환된 토큰 공지
+        let game_card_token_controller_guard =
+            self.game_card_token_controller.lock().await;
+
+        game_card_token_controller_guard.notice_summoned_token_unit(
+            attack_unit_request_form
+                .to_notice_summoned_token_unit_request_form(
+                    account_unique_id,
+                    opponent_unique_id,
+                    process_pending_game_event_response.get_summoned_token_unit_map().clone())).await;
+
+        drop(game_card_token_controller_guard);
+
+        let mut opponent_dead_unit_index_list =
+            vec![judge_death_of_opponent_unit_response.get_dead_unit_index()];
+        opponent_dead_unit_index_list.extend(
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id));
+
+        let mut my_dead_unit_index_list =
+            vec![judge_death_of_attacker_unit_response.get_dead_unit_index()];
+        my_dead_unit_index_list.extend(
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(account_unique_id));
+
+        // 반응이 피격 유닛 또는 공격 유닛에 추가 피해를 준 경우 반응 이후의 체력을 우선함
+        let mut opponent_unit_health_point_map =
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(opponent_unique_id);
+        opponent_unit_health_point_map
+            .entry(opponent_target_unit_card_index)
+            .or_insert(opponent_target_unit_health_point);
+
+        let mut my_unit_health_point_map =
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(account_unique_id);
+        my_unit_health_point_map
+            .entry(attacker_unit_card_index)
+            .or_insert(attacker_unit_health_point);
+
         let mut ui_data_generator_service_guard =
             self.ui_data_generator_service.lock().await;
 
@@ -670,12 +883,11 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                         attacker_unit_card_index,
                         opponent_target_unit_card_index)).await;
 
-        let generate_opponent_specific_unit_health_point_data_response =
-            ui_data_generator_service_guard.generate_opponent_specific_unit_health_point_data(
+        let generate_opponent_multiple_unit_health_point_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_health_point_data(
                 attack_unit_request_form
-                    .to_generate_opponent_specific_unit_health_point_data_request(
-                        opponent_target_unit_card_index,
-                        opponent_target_unit_health_point)).await;
+                    .to_generate_opponent_multiple_unit_health_point_data_request(
+                        opponent_unit_health_point_map.into_iter().collect())).await;
 
         let generate_opponent_specific_unit_harmful_effect_data_response =
             ui_data_generator_service_guard.generate_opponent_specific_unit_harmful_effect_data(
@@ -684,18 +896,17 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                         opponent_target_unit_card_index,
                         opponent_target_unit_harmful_effect_list)).await;
 
-        let generate_opponent_specific_unit_death_data_response =
-            ui_data_generator_service_guard.generate_opponent_specific_unit_death_data(
+        let generate_opponent_multiple_unit_death_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_death_data(
                 attack_unit_request_form
-                    .to_generate_opponent_specific_unit_death_data_request(
-                        judge_death_of_opponent_unit_response.get_dead_unit_index())).await;
+                    .to_generate_opponent_multiple_unit_death_data_request(
+                        opponent_dead_unit_index_list)).await;
 
-        let generate_my_specific_unit_health_point_data_response =
-            ui_data_generator_service_guard.generate_my_specific_unit_health_point_data(
+        let generate_my_multiple_unit_health_point_data_response =
+            ui_data_generator_service_guard.generate_my_multiple_unit_health_point_data(
                 attack_unit_request_form
-                    .to_generate_my_specific_unit_health_point_data_request(
-                        attacker_unit_card_index,
-                        attacker_unit_health_point)).await;
+                    .to_generate_my_multiple_unit_health_point_data_request(
+                        my_unit_health_point_map.into_iter().collect())).await;
 
         let generate_my_specific_unit_harmful_effect_data_response =
             ui_data_generator_service_guard.generate_my_specific_unit_harmful_effect_data(
@@ -704,11 +915,11 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                         attacker_unit_card_index,
                         attacker_unit_harmful_effect_list)).await;
 
-        let generate_my_specific_unit_death_data_response =
-            ui_data_generator_service_guard.generate_my_specific_unit_death_data(
+        let generate_my_multiple_unit_death_data_response =
+            ui_data_generator_service_guard.generate_my_multiple_unit_death_data(
                 attack_unit_request_form
-                    .to_generate_my_specific_unit_death_data_request(
-                        judge_death_of_attacker_unit_response.get_dead_unit_index())).await;
+                    .to_generate_my_multiple_unit_death_data_request(
+                        my_dead_unit_index_list)).await;
 
         drop(ui_data_generator_service_guard);
 
@@ -721,10 +932,10 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         let mut combined_unit_death_data_for_notice = HashMap::new();
 
         combined_unit_health_point_data_for_response.extend(
-            generate_opponent_specific_unit_health_point_data_response
+            generate_opponent_multiple_unit_health_point_data_response
                 .get_player_field_unit_health_point_map_for_response().clone());
         combined_unit_health_point_data_for_response.extend(
-            generate_my_specific_unit_health_point_data_response
+            generate_my_multiple_unit_health_point_data_response
                 .get_player_field_unit_health_point_map_for_response().clone());
         combined_unit_harmful_effect_data_for_response.extend(
             generate_opponent_specific_unit_harmful_effect_data_response
@@ -733,17 +944,17 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
             generate_my_specific_unit_harmful_effect_data_response
                 .get_player_field_unit_harmful_effect_map_for_response().clone());
         combined_unit_death_data_for_response.extend(
-            generate_opponent_specific_unit_death_data_response
+            generate_opponent_multiple_unit_death_data_response
                 .get_player_field_unit_death_map_for_response().clone());
         combined_unit_death_data_for_response.extend(
-            generate_my_specific_unit_death_data_response
+            generate_my_multiple_unit_death_data_response
                 .get_player_field_unit_death_map_for_response().clone());
 
         combined_unit_health_point_data_for_notice.extend(
-            generate_opponent_specific_unit_health_point_data_response
+            generate_opponent_multiple_unit_health_point_data_response
                 .get_player_field_unit_health_point_map_for_notice().clone());
         combined_unit_health_point_data_for_notice.extend(
-            generate_my_specific_unit_health_point_data_response
+            generate_my_multiple_unit_health_point_data_response
                 .get_player_field_unit_health_point_map_for_notice().clone());
         combined_unit_harmful_effect_data_for_notice.extend(
             generate_opponent_specific_unit_harmful_effect_data_response
@@ -752,10 +963,10 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
             generate_my_specific_unit_harmful_effect_data_response
                 .get_player_field_unit_harmful_effect_map_for_notice().clone());
         combined_unit_death_data_for_notice.extend(
-            generate_opponent_specific_unit_death_data_response
+            generate_opponent_multiple_unit_death_data_response
                 .get_player_field_unit_death_map_for_notice().clone());
         combined_unit_death_data_for_notice.extend(
-            generate_my_specific_unit_death_data_response
+            generate_my_multiple_unit_death_data_response
                 .get_player_field_unit_death_map_for_notice().clone());
 
         let mut notify_player_action_info_service_guard =
@@ -779,6 +990,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
             combined_unit_health_point_data_for_response,
             combined_unit_harmful_effect_data_for_response,
             combined_unit_death_data_for_response)
+            .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
     }
 
     async fn request_to_attack_game_main_character(
@@ -810,8 +1022,7 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         }
 
         // Battle Field 에서 공격하는 유닛의 index 를 토대로 id 값 확보
-        let attacker_unit_card_index_string = attack_game_main_character_request_form.get_attacker_unit_index();
-        let attacker_unit_card_index = attacker_unit_card_index_string.parse::<i32>().unwrap();
+        let attacker_unit_card_index = attack_game_main_character_request_form.get_attacker_unit_index();
 
         // 액션 가능한 턴인지 검증
         let mut game_field_unit_service_guard =
@@ -856,6 +1067,26 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
         }
 
         drop(game_field_unit_action_possibility_validator_service_guard);
+        drop(game_field_unit_service_guard);
+
+        // 공격 선언에 반응하는 상대 함정 발동
+        let trigger_trap_card_response_form =
+            self.trigger_opponent_trap_card(
+                attack_game_main_character_request_form
+                    .to_trigger_trap_card_request_form(
+                        account_unique_id,
+                        TrapTriggerCondition::OnAttackDeclared,
+                        attacker_unit_card_index)).await;
+
+        if trigger_trap_card_response_form.is_triggering_unit_dead() ||
+            trigger_trap_card_response_form.is_winner_decided() {
+            println!("함정으로 공격 유닛이 사망했거나 승패가 결정되어 공격이 취소되었습니다.");
+            return AttackGameMainCharacterResponseForm::from_response_with_message(AttackCanceledByTrap)
+                .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
+        }
+
+        let mut game_field_unit_service_guard =
+            self.game_field_unit_service.lock().await;
 
         // 유닛 인덱스에서 기본 공격력 정보 확보
         let attacker_unit_attack_point =
@@ -918,6 +1149,32 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
 
         drop(game_main_character_service_guard);
 
+        // 공격으로 발생한 이벤트에 대한 카드 반응 처리 (복수로 공격 유닛이 사망할 수 있음)
+        let mut game_event_bus_service_guard =
+            self.game_event_bus_service.lock().await;
+
+        let process_pending_game_event_response =
+            game_event_bus_service_guard.process_pending_game_event(
+                attack_game_main_character_request_form
+                    .to_process_pending_game_event_request(
+                        account_unique_id,
+                        opponent_unique_id)).await;
+
+        drop(game_event_bus_service_guard);
+
+        // 이벤트 반응으로 소환된 토큰 공지
+        let game_card_token_controller_guard =
+            self.game_card_token_controller.lock().await;
+
+        game_card_token_controller_guard.notice_summoned_token_unit(
+            attack_game_main_character_request_form
+                .to_notice_summoned_token_unit_request_form(
+                    account_unique_id,
+                    opponent_unique_id,
+                    process_pending_game_event_response.get_summoned_token_unit_map().clone())).await;
+
+        drop(game_card_token_controller_guard);
+
         let mut ui_data_generator_service_guard =
             self.ui_data_generator_service.lock().await;
 
@@ -939,6 +1196,14 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                     .to_generate_opponent_main_character_survival_data_request(
                         check_main_character_of_account_unique_id_response.get_status_main_character().clone())).await;
 
+        let generate_game_event_reaction_data_response =
+            ui_data_generator_service_guard.generate_game_event_reaction_data(
+                attack_game_main_character_request_form
+                    .to_generate_game_event_reaction_data_request(
+                        account_unique_id,
+                        opponent_unique_id,
+                        &process_pending_game_event_response)).await;
+
         drop(ui_data_generator_service_guard);
 
         let mut notify_player_action_info_service_guard =
@@ -955,11 +1220,25 @@ impl GameCardUnitController for GameCardUnitControllerImpl {
                     generate_opponent_main_character_survival_data_response
                         .get_player_main_character_survival_map_for_notice().clone())).await;
 
+        // 이벤트 반응으로 체력이 바뀌거나 사망한 유닛 공지
+        if !generate_game_event_reaction_data_response.is_empty() {
+            notify_player_action_info_service_guard.notice_game_event_reaction(
+                attack_game_main_character_request_form
+                    .to_notice_game_event_reaction_request(
+                        opponent_unique_id,
+                        generate_game_event_reaction_data_response
+                            .get_player_field_unit_health_point_map_for_notice().clone(),
+                        generate_game_event_reaction_data_response
+                            .get_player_field_unit_death_map_for_notice().clone())).await;
+        }
+
         drop(notify_player_action_info_service_guard);
 
         AttackGameMainCharacterResponseForm::from_response(
             generate_opponent_main_character_health_point_data_response,
-            generate_opponent_main_character_survival_data_response)
+            generate_opponent_main_character_survival_data_response,
+            generate_game_event_reaction_data_response)
+            .merge_trigger_trap_card_response_form(trigger_trap_card_response_form)
     }
 }
 
@@ -1,6 +1,11 @@
 use std::collections::HashMap;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_unit::service::request::summary_unit_card_info_request::SummaryUnitCardInfoRequest;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
+use crate::game_event_bus::service::response::process_pending_game_event_response::ProcessPendingGameEventResponse;
 use crate::game_field_unit::service::request::execute_turn_action_request::ExecuteTurnActionRequest;
 use crate::game_field_unit::service::request::acquire_unit_attack_point_request::AcquireUnitAttackPointRequest;
 use crate::game_field_unit::service::request::acquire_unit_extra_effect_request::AcquireUnitExtraEffectRequest;
@@ -14,24 +19,29 @@ use crate::game_main_character::entity::status_main_character::StatusMainCharact
 use crate::game_protocol_validation::service::request::is_this_your_turn_request::IsThisYourTurnRequest;
 use crate::game_winner_check::service::request::check_main_character_request::CheckMainCharacterRequest;
 use crate::notify_player_action_info::service::request::notice_basic_attack_to_main_character_request::NoticeBasicAttackToMainCharacterRequest;
+use crate::notify_player_action_info::service::request::notice_game_event_reaction_request::NoticeGameEventReactionRequest;
 use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
 use crate::ui_data_generator::entity::field_unit_basic_attack_info::FieldUnitAttackInfo;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::service::request::generate_game_event_reaction_data_request::GenerateGameEventReactionDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_basic_attack_data_request::GenerateMySpecificUnitBasicAttackDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_main_character_health_point_data_request::GenerateOpponentMainCharacterHealthPointDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_main_character_survival_data_request::GenerateOpponentMainCharacterSurvivalDataRequest;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
 
 pub struct AttackGameMainCharacterRequestForm {
     session_id: String,
-    attacker_unit_index: String,
+    attacker_unit_index: i32,
     target_game_main_character_index: String,
 }
 
 impl AttackGameMainCharacterRequestForm {
-    pub fn new(session_id: String, attacker_unit_index: String, target_game_main_character_index: String) -> Self {
+    pub fn new(session_id: String, attacker_unit_index: i32, target_game_main_character_index: String) -> Self {
         AttackGameMainCharacterRequestForm {
             session_id: session_id.to_string(),
-            attacker_unit_index: attacker_unit_index.to_string(),
+            attacker_unit_index,
             target_game_main_character_index: target_game_main_character_index.to_string(),
         }
     }
@@ -40,8 +50,8 @@ impl AttackGameMainCharacterRequestForm {
         &self.session_id
     }
 
-    pub fn get_attacker_unit_index(&self) -> &str {
-        &self.attacker_unit_index
+    pub fn get_attacker_unit_index(&self) -> i32 {
+        self.attacker_unit_index
     }
 
     pub fn get_target_game_main_character_index(&self) -> &str {
@@ -77,6 +87,40 @@ impl AttackGameMainCharacterRequestForm {
             account_unique_id, attacker_unit_card_index)
     }
 
+    pub fn to_process_pending_game_event_request(&self,
+                                                 account_unique_id: i32,
+                                                 opponent_unique_id: i32) -> ProcessPendingGameEventRequest {
+        ProcessPendingGameEventRequest::new(
+            account_unique_id, opponent_unique_id)
+    }
+
+    pub fn to_notice_summoned_token_unit_request_form(&self,
+                                                      account_unique_id: i32,
+                                                      opponent_unique_id: i32,
+                                                      summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>) -> NoticeSummonedTokenUnitRequestForm {
+        NoticeSummonedTokenUnitRequestForm::new(
+            account_unique_id, opponent_unique_id, summoned_token_unit_map)
+    }
+
+    pub fn to_generate_game_event_reaction_data_request(&self,
+                                                        account_unique_id: i32,
+                                                        opponent_unique_id: i32,
+                                                        process_pending_game_event_response: &ProcessPendingGameEventResponse) -> GenerateGameEventReactionDataRequest {
+        GenerateGameEventReactionDataRequest::new(
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(account_unique_id).into_iter().collect(),
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(opponent_unique_id).into_iter().collect(),
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(account_unique_id),
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id))
+    }
+
+    pub fn to_notice_game_event_reaction_request(&self,
+                                                 opponent_unique_id: i32,
+                                                 player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+                                                 player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>) -> NoticeGameEventReactionRequest {
+        NoticeGameEventReactionRequest::new(
+            opponent_unique_id, player_field_unit_health_point_map_for_notice, player_field_unit_death_map_for_notice)
+    }
+
     pub fn to_acquire_unit_attack_point_request(&self,
                                                 account_unique_id: i32,
                                                 attacker_unit_card_index: i32) -> AcquireUnitAttackPointRequest {
@@ -170,4 +214,12 @@ impl AttackGameMainCharacterRequestForm {
             player_main_character_health_point_map_for_notice,
             player_main_character_survival_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
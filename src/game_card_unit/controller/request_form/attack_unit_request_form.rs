@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::game_card_passive_skill::service::request::summary_passive_skill_effect_request::SummaryPassiveSkillEffectRequest;
 use crate::game_card_unit::service::request::summary_unit_card_info_request::SummaryUnitCardInfoRequest;
@@ -9,6 +11,8 @@ use crate::game_field_unit::service::request::acquire_unit_extra_effect_request:
 use crate::game_field_unit::service::request::acquire_unit_harmful_status_effect_request::AcquireUnitHarmfulStatusEffectRequest;
 use crate::game_field_unit::service::request::acquire_unit_passive_status_list_request::AcquireUnitPassiveStatusListRequest;
 use crate::game_field_unit::service::request::attack_target_unit_with_extra_effect_request::AttackTargetUnitWithExtraEffectRequest;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
 use crate::game_field_unit::service::request::execute_turn_action_request::ExecuteTurnActionRequest;
 use crate::game_field_unit::service::request::find_target_unit_id_by_index_request::FindTargetUnitIdByIndexRequest;
 use crate::game_field_unit::service::request::get_current_health_point_of_field_unit_by_index_request::GetCurrentHealthPointOfFieldUnitByIndexRequest;
@@ -23,26 +27,29 @@ use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
 use crate::ui_data_generator::entity::field_unit_harmful_status_info::FieldUnitHarmfulStatusInfo;
 use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::service::request::generate_my_multiple_unit_death_data_request::GenerateMyMultipleUnitDeathDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_basic_attack_data_request::GenerateMySpecificUnitBasicAttackDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_death_data_request::GenerateMySpecificUnitDeathDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_harmful_effect_data_request::GenerateMySpecificUnitHarmfulEffectDataRequest;
-use crate::ui_data_generator::service::request::generate_my_specific_unit_health_point_data_request::GenerateMySpecificUnitHealthPointDataRequest;
+use crate::ui_data_generator::service::request::generate_my_multiple_unit_health_point_data_request::GenerateMyMultipleUnitHealthPointDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_death_data_request::GenerateOpponentMultipleUnitDeathDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_specific_unit_death_data_request::GenerateOpponentSpecificUnitDeathDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_specific_unit_harmful_effect_data_request::GenerateOpponentSpecificUnitHarmfulEffectDataRequest;
-use crate::ui_data_generator::service::request::generate_opponent_specific_unit_health_point_data_request::GenerateOpponentSpecificUnitHealthPointDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_health_point_data_request::GenerateOpponentMultipleUnitHealthPointDataRequest;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
 
 pub struct AttackUnitRequestForm {
     session_id: String,
-    attacker_unit_index: String,
-    target_unit_index: String,
+    attacker_unit_index: i32,
+    target_unit_index: i32,
 }
 
 impl AttackUnitRequestForm {
-    pub fn new(session_id: String, attacker_unit_index: String, target_unit_index: String) -> Self {
+    pub fn new(session_id: String, attacker_unit_index: i32, target_unit_index: i32) -> Self {
         AttackUnitRequestForm {
             session_id: session_id.to_string(),
-            attacker_unit_index: attacker_unit_index.to_string(),
-            target_unit_index: target_unit_index.to_string(),
+            attacker_unit_index,
+            target_unit_index,
         }
     }
 
@@ -50,12 +57,12 @@ impl AttackUnitRequestForm {
         &self.session_id
     }
 
-    pub fn get_attacker_unit_index(&self) -> &str {
-        &self.attacker_unit_index
+    pub fn get_attacker_unit_index(&self) -> i32 {
+        self.attacker_unit_index
     }
 
-    pub fn get_target_unit_index(&self) -> &str {
-        &self.target_unit_index
+    pub fn get_target_unit_index(&self) -> i32 {
+        self.target_unit_index
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -160,13 +167,15 @@ impl AttackUnitRequestForm {
 
     pub fn to_attack_target_unit_with_extra_effect_request(
         &self,
+        attacker_unique_id: i32,
+        attacker_unit_index: i32,
         opponent_unique_id: i32,
         damage: i32,
         extra_status_effect_list: &Vec<ExtraStatusEffect>,
         target_unit_index: i32) -> AttackTargetUnitWithExtraEffectRequest {
 
         AttackTargetUnitWithExtraEffectRequest::new(
-            opponent_unique_id, damage, extra_status_effect_list.clone(), target_unit_index)
+            attacker_unique_id, attacker_unit_index, opponent_unique_id, damage, extra_status_effect_list.clone(), target_unit_index)
     }
 
     pub fn to_get_current_health_point_of_field_unit_by_index_request(
@@ -207,14 +216,13 @@ impl AttackUnitRequestForm {
             attacker_unit_index, target_unit_index)
     }
 
-    pub fn to_generate_opponent_specific_unit_health_point_data_request(
+    pub fn to_generate_opponent_multiple_unit_health_point_data_request(
         &self,
-        opponent_unit_index: i32,
-        opponent_unit_updated_health_point: i32
-    ) -> GenerateOpponentSpecificUnitHealthPointDataRequest {
+        opponent_unit_health_point_tuple_list: Vec<(i32, i32)>
+    ) -> GenerateOpponentMultipleUnitHealthPointDataRequest {
 
-        GenerateOpponentSpecificUnitHealthPointDataRequest::new(
-            opponent_unit_index, opponent_unit_updated_health_point)
+        GenerateOpponentMultipleUnitHealthPointDataRequest::new(
+            opponent_unit_health_point_tuple_list)
     }
 
     pub fn to_generate_opponent_specific_unit_harmful_effect_data_request(
@@ -236,14 +244,13 @@ impl AttackUnitRequestForm {
             opponent_dead_unit_index)
     }
 
-    pub fn to_generate_my_specific_unit_health_point_data_request(
+    pub fn to_generate_my_multiple_unit_health_point_data_request(
         &self,
-        attacker_unit_index: i32,
-        attacker_unit_updated_health_point: i32
-    ) -> GenerateMySpecificUnitHealthPointDataRequest {
+        my_unit_health_point_tuple_list: Vec<(i32, i32)>
+    ) -> GenerateMyMultipleUnitHealthPointDataRequest {
 
-        GenerateMySpecificUnitHealthPointDataRequest::new(
-            attacker_unit_index, attacker_unit_updated_health_point)
+        GenerateMyMultipleUnitHealthPointDataRequest::new(
+            my_unit_health_point_tuple_list)
     }
 
     pub fn to_generate_my_specific_unit_harmful_effect_data_request(
@@ -256,6 +263,45 @@ impl AttackUnitRequestForm {
             attacker_unit_index, attacker_unit_harmful_status_list)
     }
 
+    pub fn to_generate_my_multiple_unit_death_data_request(
+        &self,
+        my_dead_unit_index_list: Vec<i32>
+    ) -> GenerateMyMultipleUnitDeathDataRequest {
+
+        GenerateMyMultipleUnitDeathDataRequest::new(
+            my_dead_unit_index_list)
+    }
+
+    pub fn to_generate_opponent_multiple_unit_death_data_request(
+        &self,
+        opponent_dead_unit_index_list: Vec<i32>
+    ) -> GenerateOpponentMultipleUnitDeathDataRequest {
+
+        GenerateOpponentMultipleUnitDeathDataRequest::new(
+            opponent_dead_unit_index_list)
+    }
+
+    pub fn to_process_pending_game_event_request(
+        &self,
+        account_unique_id: i32,
+        opponent_unique_id: i32
+    ) -> ProcessPendingGameEventRequest {
+
+        ProcessPendingGameEventRequest::new(
+            account_unique_id, opponent_unique_id)
+    }
+
+    pub fn to_notice_summoned_token_unit_request_form(
+        &self,
+        account_unique_id: i32,
+        opponent_unique_id: i32,
+        summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>
+    ) -> NoticeSummonedTokenUnitRequestForm {
+
+        NoticeSummonedTokenUnitRequestForm::new(
+            account_unique_id, opponent_unique_id, summoned_token_unit_map)
+    }
+
     pub fn to_generate_my_specific_unit_death_data_request(
         &self,
         dead_unit_index: i32
@@ -281,4 +327,12 @@ impl AttackUnitRequestForm {
             player_field_unit_harmful_effect_map_for_notice,
             player_field_unit_death_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use crate::game_card_trap::controller::request_form::trigger_trap_card_request_form::TriggerTrapCardRequestForm;
+use crate::game_card_trap::entity::trap_trigger_condition::TrapTriggerCondition;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
 use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
@@ -22,14 +24,14 @@ use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_r
 
 pub struct DeployUnitRequestForm{
     session_id: String,
-    unit_id: String,
+    unit_id: i32,
 }
 
 impl DeployUnitRequestForm {
-    pub fn new(session_id: String, unit_id: String) -> Self {
+    pub fn new(session_id: String, unit_id: i32) -> Self {
         DeployUnitRequestForm {
             session_id: session_id.to_string(),
-            unit_id: unit_id.to_string(),
+            unit_id,
         }
     }
 
@@ -37,8 +39,8 @@ impl DeployUnitRequestForm {
         &self.session_id
     }
 
-    pub fn get_unit_id(&self) -> &str {
-        &self.unit_id
+    pub fn get_unit_id(&self) -> i32 {
+        self.unit_id
     }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
@@ -138,4 +140,12 @@ impl DeployUnitRequestForm {
             opponent_unique_id,
             player_hand_use_map_for_notice)
     }
+
+    pub fn to_trigger_trap_card_request_form(&self,
+                                             account_unique_id: i32,
+                                             trigger_condition: TrapTriggerCondition,
+                                             triggering_unit_index: i32) -> TriggerTrapCardRequestForm {
+        TriggerTrapCardRequestForm::new(
+            account_unique_id, trigger_condition, triggering_unit_index)
+    }
 }
\ No newline at end of file
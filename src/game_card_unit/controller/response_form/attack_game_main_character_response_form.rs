@@ -2,16 +2,24 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use crate::common::message::false_message_enum::FalseMessage;
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::service::response::generate_game_event_reaction_data_response::GenerateGameEventReactionDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_main_character_health_point_data_response::GenerateOpponentMainCharacterHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_main_character_survival_data_response::GenerateOpponentMainCharacterSurvivalDataResponse;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttackGameMainCharacterResponseForm {
     is_success: bool,
     false_message_enum: i32,
     player_main_character_health_point_map_for_notice: HashMap<PlayerIndex, i32>,
-    player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>
+    player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+    player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl AttackGameMainCharacterResponseForm {
@@ -19,20 +27,26 @@ impl AttackGameMainCharacterResponseForm {
         is_success: bool,
         false_message_enum: i32,
         player_main_character_health_point_map_for_notice: HashMap<PlayerIndex, i32>,
-        player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>
+        player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>
     ) -> Self {
 
         AttackGameMainCharacterResponseForm {
             is_success,
             false_message_enum,
             player_main_character_health_point_map_for_notice,
-            player_main_character_survival_map_for_notice
+            player_main_character_survival_map_for_notice,
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
     pub fn from_response(
         generate_opponent_main_character_health_point_data_response: GenerateOpponentMainCharacterHealthPointDataResponse,
-        generate_opponent_main_character_survival_data_response: GenerateOpponentMainCharacterSurvivalDataResponse
+        generate_opponent_main_character_survival_data_response: GenerateOpponentMainCharacterSurvivalDataResponse,
+        generate_game_event_reaction_data_response: GenerateGameEventReactionDataResponse
     ) -> AttackGameMainCharacterResponseForm {
 
         AttackGameMainCharacterResponseForm::new(
@@ -41,15 +55,34 @@ impl AttackGameMainCharacterResponseForm {
             generate_opponent_main_character_health_point_data_response
                 .get_player_main_character_health_point_map_for_response().clone(),
             generate_opponent_main_character_survival_data_response
-                .get_player_main_character_survival_map_for_response().clone())
+                .get_player_main_character_survival_map_for_response().clone(),
+            generate_game_event_reaction_data_response
+                .get_player_field_unit_health_point_map_for_response().clone(),
+            generate_game_event_reaction_data_response
+                .get_player_field_unit_death_map_for_response().clone())
     }
 
     pub fn default() -> AttackGameMainCharacterResponseForm {
 
-        AttackGameMainCharacterResponseForm::new(false, -1, HashMap::new(), HashMap::new())
+        AttackGameMainCharacterResponseForm::new(false, -1, HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new())
     }
     pub fn from_response_with_message(false_message: FalseMessage) -> AttackGameMainCharacterResponseForm {
 
-        AttackGameMainCharacterResponseForm::new(false, false_message as i32, HashMap::new(), HashMap::new())
+        AttackGameMainCharacterResponseForm::new(false, false_message as i32, HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for AttackGameMainCharacterResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for AttackGameMainCharacterResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
 }
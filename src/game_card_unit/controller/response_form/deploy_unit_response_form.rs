@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
 use crate::common::message::false_message_enum::FalseMessage;
+use crate::game_card_trap::controller::response_form::trigger_trap_card_response_form::{MergeTriggerTrapCardResponseForm, TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST, TriggerTrapCardResponseForm};
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeployUnitResponseForm {
     is_success: bool,
     false_message_enum: i32,
     index_list_of_passive_skill_to_handle: Vec<i32>,
+    trigger_trap_card_response_form: TriggerTrapCardResponseForm,
 }
 
 impl DeployUnitResponseForm {
@@ -18,7 +21,8 @@ impl DeployUnitResponseForm {
         DeployUnitResponseForm {
             is_success,
             false_message_enum,
-            index_list_of_passive_skill_to_handle
+            index_list_of_passive_skill_to_handle,
+            trigger_trap_card_response_form: TriggerTrapCardResponseForm::default(),
         }
     }
 
@@ -30,4 +34,19 @@ impl DeployUnitResponseForm {
 
         DeployUnitResponseForm::new(false, false_message as i32, Vec::new())
     }
-}
\ No newline at end of file
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
+}
+
+impl MergeTriggerTrapCardResponseForm for DeployUnitResponseForm {
+    fn merge_trigger_trap_card_response_form(
+        mut self, trigger_trap_card_response_form: TriggerTrapCardResponseForm) -> Self {
+
+        self.trigger_trap_card_response_form = trigger_trap_card_response_form;
+        self
+    }
+}
+
+impl VersionedResponseForm for DeployUnitResponseForm {
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)] = TRIGGER_TRAP_CARD_FEATURE_FIELD_LIST;
+}
@@ -2,4 +2,6 @@
 pub enum PassiveStatus {
     PhysicalImmunity,
     Frozen,
+    // 모든 해로운 상태 효과에 면역
+    StatusEffectImmunity,
 }
\ No newline at end of file
@@ -2,4 +2,5 @@ pub mod repository;
 pub mod entity;
 pub mod handler;
 pub mod service;
-pub mod controller;
\ No newline at end of file
+pub mod controller;
+pub mod protocol;
@@ -0,0 +1,36 @@
+use crate::game_card_unit::controller::game_card_unit_controller::GameCardUnitController;
+use crate::game_card_unit::controller::game_card_unit_controller_impl::GameCardUnitControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::attack_game_main_character_request_form_generator::create_attack_game_main_character_request_form;
+use crate::request_generator::attack_unit_request_form_generator::create_attack_unit_request_form;
+use crate::request_generator::deploy_unit_request_form_generator::create_deploy_unit_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_card_unit_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Unit attack
+    protocol_registry.register_battle_room_action(1000, "ATTACK_UNIT", create_attack_unit_request_form, |request| async move {
+        let game_card_unit_controller_mutex = GameCardUnitControllerImpl::get_instance();
+        let game_card_unit_controller = game_card_unit_controller_mutex.lock().await;
+
+        let response = game_card_unit_controller.request_to_attack_unit(request).await;
+        ResponseType::ATTACK_UNIT(response)
+    });
+
+    // Unit Card Usage
+    protocol_registry.register_battle_room_action(1004, "DEPLOY_UNIT_USAGE", create_deploy_unit_request_form, |request| async move {
+        let game_card_unit_controller_mutex = GameCardUnitControllerImpl::get_instance();
+        let game_card_unit_controller = game_card_unit_controller_mutex.lock().await;
+
+        let response = game_card_unit_controller.request_to_deploy_unit(request).await;
+        ResponseType::DEPLOY_UNIT_USAGE(response)
+    });
+
+    // Attack Main Character
+    protocol_registry.register_battle_room_action(1016, "ATTACK_MAIN_CHARACTER", create_attack_game_main_character_request_form, |request| async move {
+        let game_card_unit_controller_mutex = GameCardUnitControllerImpl::get_instance();
+        let game_card_unit_controller = game_card_unit_controller_mutex.lock().await;
+
+        let response = game_card_unit_controller.request_to_attack_game_main_character(request).await;
+        ResponseType::ATTACK_MAIN_CHARACTER(response)
+    });
+}
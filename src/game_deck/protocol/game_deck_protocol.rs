@@ -0,0 +1,27 @@
+use crate::game_deck::service::game_deck_service::GameDeckService;
+use crate::game_deck::service::game_deck_service_impl::GameDeckServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::fake_create_game_deck_card_list_request_generator::fake_create_game_deck_card_list_request;
+use crate::request_generator::game_deck_card_list_request_generator::create_game_deck_card_list_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_deck_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Game Deck Card List
+    protocol_registry.register_battle_room_action(17, "BATTLE_START_SHUFFLED_GAME_DECK_CARD_LIST", create_game_deck_card_list_request, |request| async move {
+        let game_deck_card_service_mutex = GameDeckServiceImpl::get_instance();
+        let game_deck_card_service = game_deck_card_service_mutex.lock().await;
+
+        let response = game_deck_card_service.create_and_shuffle_deck(request).await;
+        ResponseType::BATTLE_START_SHUFFLED_GAME_DECK_CARD_LIST(response)
+    });
+
+    // TODO: Mocking 전용이라 8000번대에 있으면 안됨
+    //드로우 30장 하고 시작 테스트용 Game Deck Card List
+    protocol_registry.register_battle_room_action(8002, "FAKE_BATTLE_START_SHUFFLED_GAME_DECK_CARD_LIST", fake_create_game_deck_card_list_request, |request| async move {
+        let game_deck_card_service_mutex = GameDeckServiceImpl::get_instance();
+        let game_deck_card_service = game_deck_card_service_mutex.lock().await;
+
+        let response = game_deck_card_service.fake_create_and_shuffle_deck(request).await;
+        ResponseType::BATTLE_START_SHUFFLED_GAME_DECK_CARD_LIST(response)
+    });
+}
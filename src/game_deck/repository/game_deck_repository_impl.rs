@@ -4,6 +4,7 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
 use crate::game_deck::entity::game_deck::GameDeck;
 use crate::game_deck::entity::game_deck_card::GameDeckCard;
 use crate::game_deck::entity::game_deck_card_list;
@@ -41,6 +42,11 @@ impl GameDeckRepositoryImpl {
 
 
     pub fn get_instance() -> Arc<AsyncMutex<GameDeckRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_deck_repository()
+        }
+
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<GameDeckRepositoryImpl>> =
                 Arc::new(
@@ -13,6 +13,10 @@ use crate::game_deck::repository::game_deck_repository::GameDeckRepository;
 
 use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
 use crate::game_deck::service::game_deck_service::GameDeckService;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_deck::service::request::draw_cards_from_deck_request::DrawCardsFromDeckRequest;
 use crate::game_deck::service::request::find_deck_card_id_by_index_request::FindDeckCardIdByIndexRequest;
 use crate::game_deck::service::request::found_card_from_deck_request::FoundCardFromDeckRequest;
@@ -37,29 +41,23 @@ use crate::rock_paper_scissors_waiting_timer::repository::rock_paper_scissors_wa
 use crate::rock_paper_scissors_waiting_timer::repository::rock_paper_scissors_waiting_timer_repository_impl::RockPaperScissorsWaitingTimerRepositoryImpl;
 
 pub struct GameDeckServiceImpl {
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
     account_card_repository: Arc<AsyncMutex<AccountCardRepositoryImpl>>,
     account_deck_card_repository: Arc<AsyncMutex<AccountDeckCardRepositoryImpl>>,
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
-    rock_paper_scissors_waiting_timer_repository: Arc<AsyncMutex<RockPaperScissorsWaitingTimerRepositoryImpl>>
+    rock_paper_scissors_waiting_timer_repository: Arc<AsyncMutex<RockPaperScissorsWaitingTimerRepositoryImpl>>,
 }
 
 impl GameDeckServiceImpl {
-    pub fn new(game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-               account_card_repository: Arc<AsyncMutex<AccountCardRepositoryImpl>>,
+    pub fn new(account_card_repository: Arc<AsyncMutex<AccountCardRepositoryImpl>>,
                account_deck_card_repository: Arc<AsyncMutex<AccountDeckCardRepositoryImpl>>,
                redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
                rock_paper_scissors_waiting_timer_repository: Arc<AsyncMutex<RockPaperScissorsWaitingTimerRepositoryImpl>>) -> Self {
 
         GameDeckServiceImpl {
-            game_deck_repository,
-            game_hand_repository,
             account_card_repository,
             account_deck_card_repository,
             redis_in_memory_repository,
-            rock_paper_scissors_waiting_timer_repository
+            rock_paper_scissors_waiting_timer_repository,
         }
     }
 
@@ -69,8 +67,6 @@ impl GameDeckServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         GameDeckServiceImpl::new(
-                            GameDeckRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
                             AccountCardRepositoryImpl::get_instance(),
                             AccountDeckCardRepositoryImpl::get_instance(),
                             RedisInMemoryRepositoryImpl::get_instance(),
@@ -97,7 +93,8 @@ impl GameDeckServiceImpl {
         let account_deck_vector = HashToVectorConverter::hash_vector_to_vector(account_deck_hash_vector);
         drop(account_deck_card_repository_guard);
 
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         game_deck_repository_guard.set_game_deck_from_data(account_unique_id, account_deck_vector);
         drop(game_deck_repository_guard);
     }
@@ -110,7 +107,8 @@ impl GameDeckServiceImpl {
         let mutable_vector_deck_card = account_card_list_validate.get_all_card_list_mut();
 
         // initialize_game_deck 호출
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         let initialize_game_deck_for_validate = game_deck_repository_guard.get_game_deck_map().get_mut(&account_unique_id).unwrap();
         let game_card_list_for_validate = initialize_game_deck_for_validate.get_all_cards_in_game_deck_mut();
 
@@ -139,26 +137,37 @@ impl GameDeckServiceImpl {
     }
 
     async fn shuffle_game_deck(&self, account_unique_id: i32) -> bool {
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         return game_deck_repository_guard.shuffle_game_deck(account_unique_id);
     }
 
     async fn draw_deck_cards(&self, account_unique_id: i32, num_cards: usize) -> Vec<i32> {
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         let drawn_card_list = game_deck_repository_guard.draw_deck_card(account_unique_id, num_cards as i32);
         drop(game_deck_repository_guard);
 
+        if !drawn_card_list.is_empty() {
+            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+            game_event_bus_repository_guard.publish_game_event(
+                GameEvent::new(GameEventType::CardDrawn, account_unique_id, -1, -1, drawn_card_list.len() as i32));
+        }
+
         drawn_card_list
     }
 
     async fn add_drawn_cards_to_hand(&self, account_unique_id: i32, drawn_card_list: Vec<i32>) {
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         game_hand_repository_guard.add_card_list_to_hand(account_unique_id, drawn_card_list);
         drop(game_hand_repository_guard);
     }
 
     async fn get_game_deck_card_ids(&self, account_unique_id: i32) -> Vec<i32> {
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         let game_deck_card_vector = game_deck_repository_guard.get_game_deck_card_ids(account_unique_id);
         drop(game_deck_repository_guard);
 
@@ -244,7 +253,8 @@ impl GameDeckService for GameDeckServiceImpl {
     async fn find_by_card_id_with_count(&self, found_card_from_deck_request: FoundCardFromDeckRequest) -> FoundCardFromDeckResponse {
         println!("GameDeckServiceImpl: find_by_card_id_with_count()");
 
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         let found_card_list = game_deck_repository_guard.find_by_card_id_with_count(
             found_card_from_deck_request.get_account_unique_id(),
             found_card_from_deck_request.get_need_to_find_card_id(),
@@ -256,7 +266,8 @@ impl GameDeckService for GameDeckServiceImpl {
     async fn find_deck_card_id_by_index(&self, find_deck_card_id_by_index_request: FindDeckCardIdByIndexRequest) -> FindDeckCardIdByIndexResponse {
         println!("GameDeckServiceImpl: find_deck_card_id_by_index()");
 
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         let found_card_id = game_deck_repository_guard.find_deck_card_id_with_index(
             find_deck_card_id_by_index_request.get_account_unique_id(),
             find_deck_card_id_by_index_request.get_target_card_index());
@@ -267,7 +278,8 @@ impl GameDeckService for GameDeckServiceImpl {
     async fn search_specific_deck_card(&self, search_specific_deck_card_request: SearchSpecificDeckCardRequest) -> SearchSpecificDeckCardResponse {
         println!("GameDeckServiceImpl: search_specific_deck_card_by_index()");
 
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
 
         let account_unique_id = search_specific_deck_card_request.get_account_unique_id();
         let mut will_be_found_card_index_list = search_specific_deck_card_request.get_target_card_index_list().clone();
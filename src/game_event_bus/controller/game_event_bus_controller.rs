@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::game_event_bus::controller::request_form::settle_pending_game_event_request_form::SettlePendingGameEventRequestForm;
+use crate::game_event_bus::controller::response_form::settle_pending_game_event_response_form::SettlePendingGameEventResponseForm;
+
+#[async_trait]
+pub trait GameEventBusController {
+    async fn settle_pending_game_event(
+        &self, settle_pending_game_event_request_form: SettlePendingGameEventRequestForm) -> SettlePendingGameEventResponseForm;
+}
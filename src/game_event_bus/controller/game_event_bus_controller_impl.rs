@@ -0,0 +1,160 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::game_card_token::controller::game_card_token_controller::GameCardTokenController;
+use crate::game_card_token::controller::game_card_token_controller_impl::GameCardTokenControllerImpl;
+use crate::game_event_bus::controller::game_event_bus_controller::GameEventBusController;
+use crate::game_event_bus::controller::request_form::settle_pending_game_event_request_form::SettlePendingGameEventRequestForm;
+use crate::game_event_bus::controller::response_form::settle_pending_game_event_response_form::SettlePendingGameEventResponseForm;
+use crate::game_event_bus::service::game_event_bus_service::GameEventBusService;
+use crate::game_event_bus::service::game_event_bus_service_impl::GameEventBusServiceImpl;
+use crate::notify_player_action_info::service::notify_player_action_info_service::NotifyPlayerActionInfoService;
+use crate::notify_player_action_info::service::notify_player_action_info_service_impl::NotifyPlayerActionInfoServiceImpl;
+use crate::ui_data_generator::service::ui_data_generator_service::UiDataGeneratorService;
+use crate::ui_data_generator::service::ui_data_generator_service_impl::UiDataGeneratorServiceImpl;
+
+pub struct GameEventBusControllerImpl {
+    battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+    game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+    game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
+    notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+    ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>,
+}
+
+impl GameEventBusControllerImpl {
+    pub fn new(battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+               game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+               game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
+               notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
+               ui_data_generator_service: Arc<AsyncMutex<UiDataGeneratorServiceImpl>>) -> Self {
+
+        GameEventBusControllerImpl {
+            battle_room_service,
+            game_event_bus_service,
+            game_card_token_controller,
+            notify_player_action_info_service,
+            ui_data_generator_service,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameEventBusControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameEventBusControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameEventBusControllerImpl::new(
+                            BattleRoomServiceImpl::get_instance(),
+                            GameEventBusServiceImpl::get_instance(),
+                            GameCardTokenControllerImpl::get_instance(),
+                            NotifyPlayerActionInfoServiceImpl::get_instance(),
+                            UiDataGeneratorServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl GameEventBusController for GameEventBusControllerImpl {
+    // 아이템, 스킬, 함정, 턴 시작 효과 등으로 발행된 이벤트가 다음 행동까지 밀리지 않도록 행동이 끝날 때마다 정리
+    async fn settle_pending_game_event(
+        &self, settle_pending_game_event_request_form: SettlePendingGameEventRequestForm) -> SettlePendingGameEventResponseForm {
+
+        println!("GameEventBusControllerImpl: settle_pending_game_event()");
+
+        let account_unique_id = settle_pending_game_event_request_form.get_account_unique_id();
+
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let opponent_unique_id =
+            battle_room_service_guard.find_opponent_by_account_unique_id(
+                settle_pending_game_event_request_form
+                    .to_find_opponent_by_account_id_request(
+                        account_unique_id)).await.get_opponent_unique_id();
+
+        drop(battle_room_service_guard);
+
+        if opponent_unique_id == -1 {
+            return SettlePendingGameEventResponseForm::new(0)
+        }
+
+        let mut game_event_bus_service_guard =
+            self.game_event_bus_service.lock().await;
+
+        let process_pending_game_event_response =
+            game_event_bus_service_guard.process_pending_game_event(
+                settle_pending_game_event_request_form
+                    .to_process_pending_game_event_request(
+                        account_unique_id,
+                        opponent_unique_id)).await;
+
+        drop(game_event_bus_service_guard);
+
+        let processed_game_event_count = process_pending_game_event_response.get_processed_game_event_count();
+        if processed_game_event_count == 0 {
+            return SettlePendingGameEventResponseForm::new(0)
+        }
+
+        // 이벤트 반응으로 소환된 토큰 공지
+        let game_card_token_controller_guard =
+            self.game_card_token_controller.lock().await;
+
+        game_card_token_controller_guard.notice_summoned_token_unit(
+            settle_pending_game_event_request_form
+                .to_notice_summoned_token_unit_request_form(
+                    account_unique_id,
+                    opponent_unique_id,
+                    process_pending_game_event_response.get_summoned_token_unit_map().clone())).await;
+
+        drop(game_card_token_controller_guard);
+
+        // 이벤트 반응으로 체력이 바뀌거나 사망한 유닛 공지
+        let mut ui_data_generator_service_guard =
+            self.ui_data_generator_service.lock().await;
+
+        let generate_game_event_reaction_data_response =
+            ui_data_generator_service_guard.generate_game_event_reaction_data(
+                settle_pending_game_event_request_form
+                    .to_generate_game_event_reaction_data_request(
+                        account_unique_id,
+                        opponent_unique_id,
+                        &process_pending_game_event_response)).await;
+
+        drop(ui_data_generator_service_guard);
+
+        if generate_game_event_reaction_data_response.is_empty() {
+            return SettlePendingGameEventResponseForm::new(processed_game_event_count)
+        }
+
+        let mut notify_player_action_info_service_guard =
+            self.notify_player_action_info_service.lock().await;
+
+        // 행동한 플레이어는 response 기준, 상대는 notice 기준 데이터를 받음
+        notify_player_action_info_service_guard.notice_game_event_reaction(
+            settle_pending_game_event_request_form
+                .to_notice_game_event_reaction_request(
+                    account_unique_id,
+                    generate_game_event_reaction_data_response
+                        .get_player_field_unit_health_point_map_for_response().clone(),
+                    generate_game_event_reaction_data_response
+                        .get_player_field_unit_death_map_for_response().clone())).await;
+
+        notify_player_action_info_service_guard.notice_game_event_reaction(
+            settle_pending_game_event_request_form
+                .to_notice_game_event_reaction_request(
+                    opponent_unique_id,
+                    generate_game_event_reaction_data_response
+                        .get_player_field_unit_health_point_map_for_notice().clone(),
+                    generate_game_event_reaction_data_response
+                        .get_player_field_unit_death_map_for_notice().clone())).await;
+
+        drop(notify_player_action_info_service_guard);
+
+        SettlePendingGameEventResponseForm::new(processed_game_event_count)
+    }
+}
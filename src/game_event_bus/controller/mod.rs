@@ -0,0 +1,4 @@
+pub mod request_form;
+pub mod response_form;
+pub mod game_event_bus_controller;
+pub mod game_event_bus_controller_impl;
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
+use crate::game_event_bus::service::response::process_pending_game_event_response::ProcessPendingGameEventResponse;
+use crate::notify_player_action_info::service::request::notice_game_event_reaction_request::NoticeGameEventReactionRequest;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+use crate::ui_data_generator::service::request::generate_game_event_reaction_data_request::GenerateGameEventReactionDataRequest;
+
+// 행동을 마친 플레이어 기준으로 아직 처리되지 않은 이벤트를 정리
+#[derive(Debug)]
+pub struct SettlePendingGameEventRequestForm {
+    account_unique_id: i32,
+}
+
+impl SettlePendingGameEventRequestForm {
+    pub fn new(account_unique_id: i32) -> Self {
+        SettlePendingGameEventRequestForm {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn to_find_opponent_by_account_id_request(&self, account_unique_id: i32) -> FindOpponentByAccountIdRequest {
+        FindOpponentByAccountIdRequest::new(account_unique_id)
+    }
+
+    pub fn to_process_pending_game_event_request(&self,
+                                                 account_unique_id: i32,
+                                                 opponent_unique_id: i32) -> ProcessPendingGameEventRequest {
+        ProcessPendingGameEventRequest::new(account_unique_id, opponent_unique_id)
+    }
+
+    pub fn to_notice_summoned_token_unit_request_form(&self,
+                                                      account_unique_id: i32,
+                                                      opponent_unique_id: i32,
+                                                      summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>) -> NoticeSummonedTokenUnitRequestForm {
+        NoticeSummonedTokenUnitRequestForm::new(account_unique_id, opponent_unique_id, summoned_token_unit_map)
+    }
+
+    // 행동한 플레이어 기준으로 자신의 유닛은 my, 상대의 유닛은 opponent 로 구분
+    pub fn to_generate_game_event_reaction_data_request(&self,
+                                                        account_unique_id: i32,
+                                                        opponent_unique_id: i32,
+                                                        process_pending_game_event_response: &ProcessPendingGameEventResponse) -> GenerateGameEventReactionDataRequest {
+        GenerateGameEventReactionDataRequest::new(
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(account_unique_id).into_iter().collect(),
+            process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(opponent_unique_id).into_iter().collect(),
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(account_unique_id),
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id))
+    }
+
+    pub fn to_notice_game_event_reaction_request(&self,
+                                                 receiver_unique_id: i32,
+                                                 player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+                                                 player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>) -> NoticeGameEventReactionRequest {
+        NoticeGameEventReactionRequest::new(receiver_unique_id, player_field_unit_health_point_map, player_field_unit_death_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_generate_game_event_reaction_data_request() {
+        let settle_pending_game_event_request_form = SettlePendingGameEventRequestForm::new(1);
+
+        let process_pending_game_event_response = ProcessPendingGameEventResponse::new(
+            3,
+            HashMap::from([(2, vec![0, 3])]),
+            HashMap::from([(1, HashMap::from([(1, 4)])), (2, HashMap::from([(0, 0)]))]),
+            HashMap::new());
+
+        let generate_game_event_reaction_data_request =
+            settle_pending_game_event_request_form.to_generate_game_event_reaction_data_request(
+                1, 2, &process_pending_game_event_response);
+
+        assert_eq!(generate_game_event_reaction_data_request.get_my_unit_health_point_tuple_list(), vec![(1, 4)]);
+        assert_eq!(generate_game_event_reaction_data_request.get_opponent_unit_health_point_tuple_list(), vec![(0, 0)]);
+        assert!(generate_game_event_reaction_data_request.get_my_dead_unit_index_list().is_empty());
+        assert_eq!(generate_game_event_reaction_data_request.get_opponent_dead_unit_index_list(), vec![0, 3]);
+    }
+}
@@ -0,0 +1 @@
+pub mod settle_pending_game_event_response_form;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettlePendingGameEventResponseForm {
+    processed_game_event_count: i32,
+}
+
+impl SettlePendingGameEventResponseForm {
+    pub fn new(processed_game_event_count: i32) -> Self {
+        SettlePendingGameEventResponseForm {
+            processed_game_event_count
+        }
+    }
+
+    pub fn get_processed_game_event_count(&self) -> i32 { self.processed_game_event_count }
+}
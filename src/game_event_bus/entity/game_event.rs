@@ -0,0 +1,93 @@
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+
+// 방 안에서 발생한 게임 이벤트
+// account_unique_id 는 이벤트의 주체 (피격 유닛, 사용한 카드, 턴을 넘긴 플레이어 등) 의 소유자
+// 해당 사항이 없는 값은 -1 로 둠
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEvent {
+    sequence: i64,
+    event_type: GameEventType,
+    account_unique_id: i32,
+    unit_index: i32,
+    card_id: i32,
+    amount: i32,
+    source_account_unique_id: i32,
+    source_unit_index: i32,
+    chain_depth: i32,
+}
+
+impl GameEvent {
+    pub fn new(event_type: GameEventType,
+               account_unique_id: i32,
+               unit_index: i32,
+               card_id: i32,
+               amount: i32) -> GameEvent {
+
+        GameEvent {
+            sequence: -1,
+            event_type,
+            account_unique_id,
+            unit_index,
+            card_id,
+            amount,
+            source_account_unique_id: -1,
+            source_unit_index: -1,
+            chain_depth: 0,
+        }
+    }
+
+    pub fn get_sequence(&self) -> i64 {
+        self.sequence
+    }
+
+    pub fn set_sequence(&mut self, sequence: i64) {
+        self.sequence = sequence;
+    }
+
+    pub fn get_event_type(&self) -> GameEventType {
+        self.event_type
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+
+    pub fn get_unit_index(&self) -> i32 {
+        self.unit_index
+    }
+
+    pub fn get_card_id(&self) -> i32 {
+        self.card_id
+    }
+
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+
+    pub fn get_source_account_unique_id(&self) -> i32 {
+        self.source_account_unique_id
+    }
+
+    pub fn get_source_unit_index(&self) -> i32 {
+        self.source_unit_index
+    }
+
+    // 이벤트를 일으킨 유닛 (공격 유닛, 효과를 발동한 유닛 등)
+    pub fn set_source(&mut self, source_account_unique_id: i32, source_unit_index: i32) {
+        self.source_account_unique_id = source_account_unique_id;
+        self.source_unit_index = source_unit_index;
+    }
+
+    pub fn has_source_unit(&self) -> bool {
+        self.source_account_unique_id != -1 && self.source_unit_index != -1
+    }
+
+    pub fn get_chain_depth(&self) -> i32 {
+        self.chain_depth
+    }
+
+    // 다른 이벤트에 대한 반응으로 발생한 이벤트는 연쇄 깊이가 1 증가
+    pub fn set_chain_depth(&mut self, chain_depth: i32) {
+        self.chain_depth = chain_depth;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use std::collections::VecDeque;
+
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+
+// 플레이어별 이벤트 버스 (처리 시점에 같은 방의 두 버스를 발생 순서대로 합쳐서 처리)
+#[derive(Debug)]
+pub struct GameEventBus {
+    pending_game_event_queue: VecDeque<GameEvent>,
+    subscription_list: Vec<GameEventSubscription>,
+}
+
+impl GameEventBus {
+    pub fn new() -> GameEventBus {
+        GameEventBus {
+            pending_game_event_queue: VecDeque::new(),
+            subscription_list: Vec::new(),
+        }
+    }
+
+    pub fn push_game_event(&mut self, game_event: GameEvent) {
+        self.pending_game_event_queue.push_back(game_event);
+    }
+
+    pub fn take_pending_game_event_list(&mut self) -> Vec<GameEvent> {
+        self.pending_game_event_queue.drain(..).collect()
+    }
+
+    pub fn get_pending_game_event_count(&self) -> usize {
+        self.pending_game_event_queue.len()
+    }
+
+    pub fn add_subscription(&mut self, subscription: GameEventSubscription) {
+        self.subscription_list.push(subscription);
+    }
+
+    pub fn remove_subscription_of_unit(&mut self, unit_index: i32) -> bool {
+        let previous_subscription_count = self.subscription_list.len();
+        self.subscription_list.retain(|subscription| subscription.get_unit_index() != unit_index);
+
+        previous_subscription_count != self.subscription_list.len()
+    }
+
+    pub fn get_subscription_list(&self) -> &Vec<GameEventSubscription> {
+        &self.subscription_list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_event_bus::entity::game_event_listen_scope::GameEventListenScope;
+    use crate::game_event_bus::entity::game_event_subscribe_rule::GameEventSubscribeRule;
+    use crate::game_event_bus::entity::game_event_type::GameEventType;
+
+    #[test]
+    fn test_game_event_bus() {
+        let mut game_event_bus = GameEventBus::new();
+
+        game_event_bus.push_game_event(GameEvent::new(GameEventType::CardDrawn, 1, -1, -1, 2));
+        game_event_bus.push_game_event(GameEvent::new(GameEventType::TurnEnd, 1, -1, -1, 0));
+        assert_eq!(game_event_bus.get_pending_game_event_count(), 2);
+
+        let pending_game_event_list = game_event_bus.take_pending_game_event_list();
+        assert_eq!(pending_game_event_list[0].get_event_type(), GameEventType::CardDrawn);
+        assert_eq!(game_event_bus.get_pending_game_event_count(), 0);
+
+        game_event_bus.add_subscription(GameEventSubscription::new(
+            1, 0, 30, 0, GameEventSubscribeRule::new(GameEventType::UnitDeath, GameEventListenScope::SelfUnit, 0), 0));
+        assert!(game_event_bus.remove_subscription_of_unit(0));
+        assert!(!game_event_bus.remove_subscription_of_unit(0));
+
+        println!("{:?}", game_event_bus);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+// 구독한 유닛 기준으로 어떤 주체의 이벤트를 받을 것인지
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEventListenScope {
+    SelfUnit,
+    AllyUnit,
+    OpponentUnit,
+    OwnerPlayer,
+    OpponentPlayer,
+    Every,
+}
\ No newline at end of file
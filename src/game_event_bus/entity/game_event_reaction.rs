@@ -0,0 +1,22 @@
+use crate::game_event_bus::entity::game_event_reaction_type::GameEventReactionType;
+
+// 카드 핸들러가 이벤트에 반응하여 돌려주는 효과 요약
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEventReaction {
+    reaction_type: GameEventReactionType,
+    amount: i32,
+}
+
+impl GameEventReaction {
+    pub fn new(reaction_type: GameEventReactionType, amount: i32) -> GameEventReaction {
+        GameEventReaction { reaction_type, amount }
+    }
+
+    pub fn get_reaction_type(&self) -> GameEventReactionType {
+        self.reaction_type
+    }
+
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEventReactionType {
+    Dummy = 0,
+    // 구독 유닛과 마주보고 있는 (같은 인덱스의) 상대 유닛에게 데미지
+    DamageNearestOpponentUnit = 1,
+    DamageEveryOpponentUnit = 2,
+    // 이벤트를 일으킨 상대 유닛에게 데미지 (반격)
+    DamageEventSourceUnit = 3,
+    // 구독 유닛의 주인 필드에 토큰 소환 (amount 는 토큰 카드 번호)
+    SummonTokenUnit = 4,
+}
\ No newline at end of file
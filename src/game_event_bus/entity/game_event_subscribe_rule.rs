@@ -0,0 +1,28 @@
+use crate::game_event_bus::entity::game_event_listen_scope::GameEventListenScope;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+
+// priority 가 높을수록 같은 이벤트에 먼저 반응
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEventSubscribeRule {
+    event_type: GameEventType,
+    listen_scope: GameEventListenScope,
+    priority: i32,
+}
+
+impl GameEventSubscribeRule {
+    pub fn new(event_type: GameEventType, listen_scope: GameEventListenScope, priority: i32) -> GameEventSubscribeRule {
+        GameEventSubscribeRule { event_type, listen_scope, priority }
+    }
+
+    pub fn get_event_type(&self) -> GameEventType {
+        self.event_type
+    }
+
+    pub fn get_listen_scope(&self) -> GameEventListenScope {
+        self.listen_scope
+    }
+
+    pub fn get_priority(&self) -> i32 {
+        self.priority
+    }
+}
\ No newline at end of file
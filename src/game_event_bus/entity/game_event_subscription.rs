@@ -0,0 +1,122 @@
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_listen_scope::GameEventListenScope;
+use crate::game_event_bus::entity::game_event_subscribe_rule::GameEventSubscribeRule;
+
+// 필드 유닛이 자신의 카드 핸들러를 통해 등록한 이벤트 구독 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEventSubscription {
+    account_unique_id: i32,
+    unit_index: i32,
+    card_id: i32,
+    handler_index: usize,
+    subscribe_rule: GameEventSubscribeRule,
+    subscribed_sequence: i64,
+}
+
+impl GameEventSubscription {
+    pub fn new(account_unique_id: i32,
+               unit_index: i32,
+               card_id: i32,
+               handler_index: usize,
+               subscribe_rule: GameEventSubscribeRule,
+               subscribed_sequence: i64) -> GameEventSubscription {
+
+        GameEventSubscription {
+            account_unique_id,
+            unit_index,
+            card_id,
+            handler_index,
+            subscribe_rule,
+            subscribed_sequence,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+
+    pub fn get_unit_index(&self) -> i32 {
+        self.unit_index
+    }
+
+    pub fn get_card_id(&self) -> i32 {
+        self.card_id
+    }
+
+    pub fn get_handler_index(&self) -> usize {
+        self.handler_index
+    }
+
+    pub fn get_subscribe_rule(&self) -> &GameEventSubscribeRule {
+        &self.subscribe_rule
+    }
+
+    pub fn get_subscribed_sequence(&self) -> i64 {
+        self.subscribed_sequence
+    }
+
+    pub fn is_listening_to(&self, game_event: &GameEvent) -> bool {
+        if self.subscribe_rule.get_event_type() != game_event.get_event_type() {
+            return false
+        }
+
+        let is_owner_event = game_event.get_account_unique_id() == self.account_unique_id;
+        let is_unit_event = game_event.get_unit_index() != -1;
+
+        match self.subscribe_rule.get_listen_scope() {
+            GameEventListenScope::SelfUnit =>
+                is_owner_event && game_event.get_unit_index() == self.unit_index,
+            GameEventListenScope::AllyUnit =>
+                is_owner_event && is_unit_event && game_event.get_unit_index() != self.unit_index,
+            GameEventListenScope::OpponentUnit => !is_owner_event && is_unit_event,
+            GameEventListenScope::OwnerPlayer => is_owner_event,
+            GameEventListenScope::OpponentPlayer => !is_owner_event,
+            GameEventListenScope::Every => true,
+        }
+    }
+
+    // 자신의 반응으로 발생한 이벤트에 다시 반응하지 않도록 하기 위함
+    pub fn is_source_of(&self, game_event: &GameEvent) -> bool {
+        game_event.get_chain_depth() > 0 &&
+            game_event.get_source_account_unique_id() == self.account_unique_id &&
+            game_event.get_source_unit_index() == self.unit_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_event_bus::entity::game_event_type::GameEventType;
+
+    #[test]
+    fn test_listen_scope() {
+        let self_unit_subscription = GameEventSubscription::new(
+            1, 2, 30, 0, GameEventSubscribeRule::new(GameEventType::UnitDeath, GameEventListenScope::SelfUnit, 0), 0);
+        let ally_unit_subscription = GameEventSubscription::new(
+            1, 0, 31, 0, GameEventSubscribeRule::new(GameEventType::UnitDeath, GameEventListenScope::AllyUnit, 0), 1);
+        let opponent_unit_subscription = GameEventSubscription::new(
+            2, 0, 32, 0, GameEventSubscribeRule::new(GameEventType::UnitDeath, GameEventListenScope::OpponentUnit, 0), 2);
+
+        let unit_death_event = GameEvent::new(GameEventType::UnitDeath, 1, 2, 30, 0);
+
+        assert!(self_unit_subscription.is_listening_to(&unit_death_event));
+        assert!(ally_unit_subscription.is_listening_to(&unit_death_event));
+        assert!(opponent_unit_subscription.is_listening_to(&unit_death_event));
+
+        let unit_damaged_event = GameEvent::new(GameEventType::UnitDamaged, 1, 2, 30, 5);
+        assert!(!self_unit_subscription.is_listening_to(&unit_damaged_event));
+    }
+
+    #[test]
+    fn test_is_source_of() {
+        let subscription = GameEventSubscription::new(
+            1, 2, 30, 0, GameEventSubscribeRule::new(GameEventType::UnitDamaged, GameEventListenScope::Every, 0), 0);
+
+        let mut chained_event = GameEvent::new(GameEventType::UnitDamaged, 2, 0, 40, 3);
+        chained_event.set_source(1, 2);
+        assert!(!subscription.is_source_of(&chained_event));
+
+        chained_event.set_chain_depth(1);
+        assert!(subscription.is_source_of(&chained_event));
+    }
+}
\ No newline at end of file
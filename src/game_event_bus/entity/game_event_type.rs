@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameEventType {
+    Dummy = 0,
+    UnitDeployed = 1,
+    UnitAttack = 2,
+    UnitDamaged = 3,
+    UnitDeath = 4,
+    MainCharacterDamaged = 5,
+    CardDrawn = 6,
+    CardUsed = 7,
+    TurnEnd = 8,
+}
\ No newline at end of file
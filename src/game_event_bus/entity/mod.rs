@@ -0,0 +1,8 @@
+pub mod game_event_type;
+pub mod game_event;
+pub mod game_event_listen_scope;
+pub mod game_event_subscribe_rule;
+pub mod game_event_subscription;
+pub mod game_event_reaction_type;
+pub mod game_event_reaction;
+pub mod game_event_bus;
\ No newline at end of file
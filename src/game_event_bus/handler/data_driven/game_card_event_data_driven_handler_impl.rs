@@ -0,0 +1,123 @@
+use crate::card_effect_definition::entity::passive_skill_effect_definition::PassiveSkillEffectDefinition;
+use crate::game_card_passive_skill::entity::passive_skill_casting_condition::PassiveSkillCastingCondition;
+use crate::game_card_passive_skill::entity::passive_skill_type::PassiveSkillType;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_listen_scope::GameEventListenScope;
+use crate::game_event_bus::entity::game_event_reaction::GameEventReaction;
+use crate::game_event_bus::entity::game_event_reaction_type::GameEventReactionType;
+use crate::game_event_bus::entity::game_event_subscribe_rule::GameEventSubscribeRule;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::handler::game_card_event_handler::GameCardEventHandler;
+
+// 카드 효과 정의 파일의 패시브 스킬 중 이벤트로 발동하는 조건 (사망, 피격, 공격, 턴 종료) 을 가진 스킬
+// 소환형 (SummonToken) 스킬은 피해 대신 토큰 소환 반응을 돌려줌
+pub struct DataDrivenGameCardEventFunction {
+    passive_skill_effect_definition: PassiveSkillEffectDefinition,
+}
+
+impl DataDrivenGameCardEventFunction {
+    pub fn new(passive_skill_effect_definition: PassiveSkillEffectDefinition) -> Self {
+        DataDrivenGameCardEventFunction { passive_skill_effect_definition }
+    }
+
+    fn to_subscribe_rule(casting_condition: &PassiveSkillCastingCondition) -> Option<GameEventSubscribeRule> {
+        match casting_condition {
+            PassiveSkillCastingCondition::OnDeath =>
+                Some(GameEventSubscribeRule::new(GameEventType::UnitDeath, GameEventListenScope::SelfUnit, 0)),
+            PassiveSkillCastingCondition::OnDamaged =>
+                Some(GameEventSubscribeRule::new(GameEventType::UnitDamaged, GameEventListenScope::SelfUnit, 0)),
+            PassiveSkillCastingCondition::OnAttack =>
+                Some(GameEventSubscribeRule::new(GameEventType::UnitAttack, GameEventListenScope::SelfUnit, 0)),
+            PassiveSkillCastingCondition::TurnEnd =>
+                Some(GameEventSubscribeRule::new(GameEventType::TurnEnd, GameEventListenScope::OwnerPlayer, 0)),
+            _ => None,
+        }
+    }
+}
+
+impl GameCardEventHandler for DataDrivenGameCardEventFunction {
+    fn get_subscribe_rule_list(&self) -> Vec<GameEventSubscribeRule> {
+        self.passive_skill_effect_definition.get_casting_conditions().iter()
+            .filter_map(|casting_condition| Self::to_subscribe_rule(casting_condition))
+            .collect()
+    }
+
+    fn handle_game_event(&self, subscription: &GameEventSubscription, game_event: &GameEvent) -> Vec<GameEventReaction> {
+        println!("DataDrivenGameCardEventFunction: handle_game_event()");
+
+        let skill_damage = self.passive_skill_effect_definition.get_skill_damage();
+
+        match self.passive_skill_effect_definition.get_skill_type() {
+            PassiveSkillType::BroadArea =>
+                vec![GameEventReaction::new(GameEventReactionType::DamageEveryOpponentUnit, skill_damage)],
+            PassiveSkillType::SingleTarget => {
+                // 상대 유닛에게 당한 경우 해당 유닛에게 되갚음
+                let is_caused_by_opponent_unit = game_event.has_source_unit() &&
+                    game_event.get_source_account_unique_id() != subscription.get_account_unique_id();
+
+                if game_event.get_event_type() == GameEventType::UnitDamaged && is_caused_by_opponent_unit {
+                    return vec![GameEventReaction::new(GameEventReactionType::DamageEventSourceUnit, skill_damage)]
+                }
+
+                vec![GameEventReaction::new(GameEventReactionType::DamageNearestOpponentUnit, skill_damage)]
+            },
+            PassiveSkillType::SummonToken => match self.passive_skill_effect_definition.get_summon_token_card_id() {
+                Some(token_card_id) => vec![GameEventReaction::new(GameEventReactionType::SummonTokenUnit, token_card_id)],
+                None => Vec::new(),
+            },
+            _ => {
+                println!("이벤트 반응으로 지원하지 않는 패시브 스킬 타입입니다.");
+                Vec::new()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_definition(json: &str) -> PassiveSkillEffectDefinition {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_subscribe_rule_list() {
+        let handler = DataDrivenGameCardEventFunction::new(create_definition(
+            r#"{ "skill_type": "BroadArea", "casting_conditions": ["Deploy", "OnDeath", "TurnEnd"], "skill_damage": 10 }"#));
+
+        let subscribe_rule_list = handler.get_subscribe_rule_list();
+
+        assert_eq!(subscribe_rule_list.len(), 2);
+        assert_eq!(subscribe_rule_list[0].get_event_type(), GameEventType::UnitDeath);
+        assert_eq!(subscribe_rule_list[1].get_event_type(), GameEventType::TurnEnd);
+    }
+
+    #[test]
+    fn test_revenge_on_damaged() {
+        let handler = DataDrivenGameCardEventFunction::new(create_definition(
+            r#"{ "skill_type": "SingleTarget", "casting_conditions": ["OnDamaged"], "skill_damage": 5 }"#));
+        let subscription = GameEventSubscription::new(
+            1, 0, 30, 0, handler.get_subscribe_rule_list()[0].clone(), 0);
+
+        let mut unit_damaged_event = GameEvent::new(GameEventType::UnitDamaged, 1, 0, 30, 7);
+        unit_damaged_event.set_source(2, 3);
+
+        let reaction_list = handler.handle_game_event(&subscription, &unit_damaged_event);
+
+        assert_eq!(reaction_list, vec![GameEventReaction::new(GameEventReactionType::DamageEventSourceUnit, 5)]);
+    }
+
+    #[test]
+    fn test_summon_token_on_death() {
+        let handler = DataDrivenGameCardEventFunction::new(create_definition(
+            r#"{ "skill_type": "SummonToken", "casting_conditions": ["OnDeath"], "skill_damage": 0, "summon_token_card_id": 47 }"#));
+        let subscription = GameEventSubscription::new(
+            1, 0, 46, 0, handler.get_subscribe_rule_list()[0].clone(), 0);
+
+        let reaction_list = handler.handle_game_event(&subscription, &GameEvent::new(GameEventType::UnitDeath, 1, 0, 46, 0));
+
+        assert_eq!(reaction_list, vec![GameEventReaction::new(GameEventReactionType::SummonTokenUnit, 47)]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod game_card_event_data_driven_handler_impl;
\ No newline at end of file
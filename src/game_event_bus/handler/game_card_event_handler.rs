@@ -0,0 +1,9 @@
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_reaction::GameEventReaction;
+use crate::game_event_bus::entity::game_event_subscribe_rule::GameEventSubscribeRule;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+
+pub trait GameCardEventHandler: Send + Sync {
+    fn get_subscribe_rule_list(&self) -> Vec<GameEventSubscribeRule>;
+    fn handle_game_event(&self, subscription: &GameEventSubscription, game_event: &GameEvent) -> Vec<GameEventReaction>;
+}
\ No newline at end of file
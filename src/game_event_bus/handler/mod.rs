@@ -0,0 +1,2 @@
+pub mod game_card_event_handler;
+pub mod data_driven;
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod controller;
+pub mod entity;
+pub mod handler;
+pub mod repository;
+pub mod service;
\ No newline at end of file
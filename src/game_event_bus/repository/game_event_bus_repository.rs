@@ -0,0 +1,15 @@
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_reaction::GameEventReaction;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+
+pub trait GameEventBusRepository {
+    fn create_game_event_bus_object(&mut self, account_unique_id: i32) -> bool;
+    fn publish_game_event(&mut self, game_event: GameEvent) -> bool;
+    fn subscribe_field_unit(&mut self, account_unique_id: i32, unit_index: i32, unit_card_id: i32) -> i32;
+    fn unsubscribe_field_unit(&mut self, account_unique_id: i32, unit_index: i32) -> bool;
+    fn take_pending_game_event_list(&mut self, account_unique_id: i32, opponent_unique_id: i32) -> Vec<GameEvent>;
+    fn has_pending_game_event(&self) -> bool;
+    fn acquire_ordered_subscription_list(&self, game_event: &GameEvent, turn_owner_unique_id: i32, opponent_unique_id: i32) -> Vec<GameEventSubscription>;
+    fn handle_game_event(&self, subscription: &GameEventSubscription, game_event: &GameEvent) -> Vec<GameEventReaction>;
+    fn remove_game_event_bus_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool;
+}
\ No newline at end of file
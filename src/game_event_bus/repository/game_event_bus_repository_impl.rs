@@ -0,0 +1,289 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
+use crate::card_effect_definition::repository::card_effect_definition_repository::CardEffectDefinitionRepository;
+use crate::card_effect_definition::repository::card_effect_definition_repository_impl::CardEffectDefinitionRepositoryImpl;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_bus::GameEventBus;
+use crate::game_event_bus::entity::game_event_reaction::GameEventReaction;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+use crate::game_event_bus::handler::data_driven::game_card_event_data_driven_handler_impl::DataDrivenGameCardEventFunction;
+use crate::game_event_bus::handler::game_card_event_handler::GameCardEventHandler;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+
+pub struct GameEventBusRepositoryImpl {
+    // 카드 정의에서 만든 handler 는 읽기 전용이므로 모든 배틀 룸이 함께 사용
+    game_card_event_handler_map: Arc<HashMap<i32, Vec<Box<dyn GameCardEventHandler>>>>,
+    game_event_bus_map: HashMap<i32, GameEventBus>,
+    // 방 안의 두 버스에 걸쳐 발생 순서를 보장하기 위한 일련 번호
+    next_sequence: i64,
+}
+
+impl GameEventBusRepositoryImpl {
+    pub fn new() -> Self {
+        lazy_static! {
+            static ref GAME_CARD_EVENT_HANDLER_MAP: Arc<HashMap<i32, Vec<Box<dyn GameCardEventHandler>>>> =
+                Arc::new(GameEventBusRepositoryImpl::create_game_card_event_handler_map());
+        }
+
+        GameEventBusRepositoryImpl {
+            game_card_event_handler_map: GAME_CARD_EVENT_HANDLER_MAP.clone(),
+            game_event_bus_map: HashMap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    fn create_game_card_event_handler_map() -> HashMap<i32, Vec<Box<dyn GameCardEventHandler>>> {
+        let mut game_card_event_handler_map: HashMap<i32, Vec<Box<dyn GameCardEventHandler>>> = HashMap::new();
+
+        let card_effect_definition_repository = CardEffectDefinitionRepositoryImpl::get_instance();
+        for (unit_card_number, skill_slot_map) in card_effect_definition_repository
+            .get_card_effect_definition_table().get_passive_skill_effect_definition_map() {
+
+            let mut passive_skill_index_list: Vec<&i32> = skill_slot_map.keys().collect();
+            passive_skill_index_list.sort();
+
+            for passive_skill_index in passive_skill_index_list {
+                let handler = DataDrivenGameCardEventFunction::new(skill_slot_map[passive_skill_index].clone());
+                if handler.get_subscribe_rule_list().is_empty() {
+                    continue
+                }
+
+                game_card_event_handler_map.entry(*unit_card_number).or_insert_with(Vec::new)
+                    .push(Box::new(handler) as Box<dyn GameCardEventHandler>);
+            }
+        }
+
+        game_card_event_handler_map
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_game_card_event_handler_map(game_card_event_handler_map: HashMap<i32, Vec<Box<dyn GameCardEventHandler>>>) -> Self {
+        GameEventBusRepositoryImpl {
+            game_card_event_handler_map: Arc::new(game_card_event_handler_map),
+            game_event_bus_map: HashMap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    pub(crate) fn get_game_event_bus_map(&self) -> &HashMap<i32, GameEventBus> {
+        &self.game_event_bus_map
+    }
+
+    fn issue_sequence(&mut self) -> i64 {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        sequence
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameEventBusRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_event_bus_repository()
+        }
+
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameEventBusRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameEventBusRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+impl GameEventBusRepository for GameEventBusRepositoryImpl {
+    fn create_game_event_bus_object(&mut self, account_unique_id: i32) -> bool {
+        println!("GameEventBusRepositoryImpl: create_game_event_bus_object()");
+
+        self.game_event_bus_map.insert(account_unique_id, GameEventBus::new());
+        true
+    }
+
+    fn publish_game_event(&mut self, mut game_event: GameEvent) -> bool {
+        let sequence = self.issue_sequence();
+
+        if let Some(game_event_bus) = self.game_event_bus_map.get_mut(&game_event.get_account_unique_id()) {
+            game_event.set_sequence(sequence);
+            game_event_bus.push_game_event(game_event);
+            return true
+        }
+
+        false
+    }
+
+    fn subscribe_field_unit(&mut self, account_unique_id: i32, unit_index: i32, unit_card_id: i32) -> i32 {
+        println!("GameEventBusRepositoryImpl: subscribe_field_unit()");
+
+        let subscribe_rule_list: Vec<(usize, _)> = match self.game_card_event_handler_map.get(&unit_card_id) {
+            Some(handler_list) => handler_list.iter().enumerate()
+                .flat_map(|(handler_index, handler)|
+                    handler.get_subscribe_rule_list().into_iter().map(move |subscribe_rule| (handler_index, subscribe_rule)))
+                .collect(),
+            None => return 0,
+        };
+
+        if !self.game_event_bus_map.contains_key(&account_unique_id) {
+            return 0
+        }
+
+        let mut subscription_count = 0;
+        for (handler_index, subscribe_rule) in subscribe_rule_list {
+            let subscribed_sequence = self.issue_sequence();
+            let game_event_bus = self.game_event_bus_map.get_mut(&account_unique_id).unwrap();
+
+            game_event_bus.add_subscription(GameEventSubscription::new(
+                account_unique_id, unit_index, unit_card_id, handler_index, subscribe_rule, subscribed_sequence));
+            subscription_count += 1;
+        }
+
+        subscription_count
+    }
+
+    fn unsubscribe_field_unit(&mut self, account_unique_id: i32, unit_index: i32) -> bool {
+        println!("GameEventBusRepositoryImpl: unsubscribe_field_unit()");
+
+        if let Some(game_event_bus) = self.game_event_bus_map.get_mut(&account_unique_id) {
+            return game_event_bus.remove_subscription_of_unit(unit_index)
+        }
+
+        false
+    }
+
+    fn take_pending_game_event_list(&mut self, account_unique_id: i32, opponent_unique_id: i32) -> Vec<GameEvent> {
+        let mut pending_game_event_list = Vec::new();
+
+        for player_unique_id in [account_unique_id, opponent_unique_id] {
+            if let Some(game_event_bus) = self.game_event_bus_map.get_mut(&player_unique_id) {
+                pending_game_event_list.extend(game_event_bus.take_pending_game_event_list());
+            }
+        }
+
+        pending_game_event_list.sort_by_key(|game_event| game_event.get_sequence());
+        pending_game_event_list
+    }
+
+    fn has_pending_game_event(&self) -> bool {
+        self.game_event_bus_map.values().any(|game_event_bus| game_event_bus.get_pending_game_event_count() > 0)
+    }
+
+    // 반응 순서: 우선 순위가 높은 구독 -> 현재 턴 플레이어의 유닛 -> 필드 인덱스가 앞선 유닛 -> 먼저 구독한 유닛
+    fn acquire_ordered_subscription_list(&self, game_event: &GameEvent, turn_owner_unique_id: i32, opponent_unique_id: i32) -> Vec<GameEventSubscription> {
+        let mut ordered_subscription_list = Vec::new();
+
+        for player_unique_id in [turn_owner_unique_id, opponent_unique_id] {
+            if let Some(game_event_bus) = self.game_event_bus_map.get(&player_unique_id) {
+                ordered_subscription_list.extend(
+                    game_event_bus.get_subscription_list().iter()
+                        .filter(|subscription| subscription.is_listening_to(game_event))
+                        .filter(|subscription| !subscription.is_source_of(game_event))
+                        .cloned());
+            }
+        }
+
+        ordered_subscription_list.sort_by_key(|subscription| (
+            Reverse(subscription.get_subscribe_rule().get_priority()),
+            subscription.get_account_unique_id() != turn_owner_unique_id,
+            subscription.get_unit_index(),
+            subscription.get_subscribed_sequence()));
+
+        ordered_subscription_list
+    }
+
+    fn handle_game_event(&self, subscription: &GameEventSubscription, game_event: &GameEvent) -> Vec<GameEventReaction> {
+        println!("GameEventBusRepositoryImpl: handle_game_event()");
+
+        if let Some(handler_list) = self.game_card_event_handler_map.get(&subscription.get_card_id()) {
+            if let Some(handler) = handler_list.get(subscription.get_handler_index()) {
+                return handler.handle_game_event(subscription, game_event)
+            }
+        }
+
+        Vec::new()
+    }
+
+    fn remove_game_event_bus_hash_by_account_unique_id(&mut self, account_unique_id: i32) -> bool {
+        self.game_event_bus_map.remove(&account_unique_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_event_bus::entity::game_event_listen_scope::GameEventListenScope;
+    use crate::game_event_bus::entity::game_event_reaction_type::GameEventReactionType;
+    use crate::game_event_bus::entity::game_event_subscribe_rule::GameEventSubscribeRule;
+    use crate::game_event_bus::entity::game_event_type::GameEventType;
+
+    struct TestGameCardEventFunction {
+        listen_scope: GameEventListenScope,
+        priority: i32,
+    }
+
+    impl GameCardEventHandler for TestGameCardEventFunction {
+        fn get_subscribe_rule_list(&self) -> Vec<GameEventSubscribeRule> {
+            vec![GameEventSubscribeRule::new(GameEventType::UnitDeath, self.listen_scope, self.priority)]
+        }
+
+        fn handle_game_event(&self, _subscription: &GameEventSubscription, game_event: &GameEvent) -> Vec<GameEventReaction> {
+            vec![GameEventReaction::new(GameEventReactionType::DamageEveryOpponentUnit, game_event.get_card_id())]
+        }
+    }
+
+    fn create_repository() -> GameEventBusRepositoryImpl {
+        let mut game_card_event_handler_map: HashMap<i32, Vec<Box<dyn GameCardEventHandler>>> = HashMap::new();
+        game_card_event_handler_map.insert(9001, vec![Box::new(TestGameCardEventFunction { listen_scope: GameEventListenScope::Every, priority: 0 })]);
+        game_card_event_handler_map.insert(9002, vec![Box::new(TestGameCardEventFunction { listen_scope: GameEventListenScope::Every, priority: 1 })]);
+
+        let mut repository = GameEventBusRepositoryImpl::with_game_card_event_handler_map(game_card_event_handler_map);
+        repository.create_game_event_bus_object(1);
+        repository.create_game_event_bus_object(2);
+        repository
+    }
+
+    #[test]
+    fn test_pending_game_event_keeps_published_order_across_players() {
+        let mut repository = create_repository();
+
+        repository.publish_game_event(GameEvent::new(GameEventType::UnitDamaged, 2, 0, 9001, 5));
+        repository.publish_game_event(GameEvent::new(GameEventType::UnitDeath, 1, 0, 9001, 0));
+        repository.publish_game_event(GameEvent::new(GameEventType::UnitDeath, 2, 0, 9001, 0));
+        assert!(!repository.publish_game_event(GameEvent::new(GameEventType::UnitDeath, 3, 0, 9001, 0)));
+        assert!(repository.has_pending_game_event());
+
+        let pending_game_event_list = repository.take_pending_game_event_list(1, 2);
+        let published_player_list: Vec<i32> = pending_game_event_list.iter()
+            .map(|game_event| game_event.get_account_unique_id()).collect();
+
+        assert_eq!(published_player_list, vec![2, 1, 2]);
+        assert!(repository.take_pending_game_event_list(1, 2).is_empty());
+        assert!(!repository.has_pending_game_event());
+    }
+
+    #[test]
+    fn test_ordered_subscription_list() {
+        let mut repository = create_repository();
+
+        assert_eq!(repository.subscribe_field_unit(2, 0, 9001), 1);
+        assert_eq!(repository.subscribe_field_unit(1, 1, 9001), 1);
+        assert_eq!(repository.subscribe_field_unit(1, 0, 9001), 1);
+        assert_eq!(repository.subscribe_field_unit(2, 3, 9002), 1);
+        assert_eq!(repository.subscribe_field_unit(1, 2, 9099), 0);
+
+        let game_event = GameEvent::new(GameEventType::UnitDeath, 2, 4, 9050, 0);
+        let ordered_unit_list: Vec<(i32, i32)> = repository.acquire_ordered_subscription_list(&game_event, 1, 2).iter()
+            .map(|subscription| (subscription.get_account_unique_id(), subscription.get_unit_index())).collect();
+
+        assert_eq!(ordered_unit_list, vec![(2, 3), (1, 0), (1, 1), (2, 0)]);
+
+        assert!(repository.unsubscribe_field_unit(2, 3));
+        assert_eq!(repository.acquire_ordered_subscription_list(&game_event, 1, 2).len(), 3);
+        assert!(repository.remove_game_event_bus_hash_by_account_unique_id(2));
+        assert!(repository.get_game_event_bus_map().get(&2).is_none());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod game_event_bus_repository;
+pub mod game_event_bus_repository_impl;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
+use crate::game_event_bus::service::request::publish_turn_end_game_event_request::PublishTurnEndGameEventRequest;
+use crate::game_event_bus::service::response::process_pending_game_event_response::ProcessPendingGameEventResponse;
+use crate::game_event_bus::service::response::publish_turn_end_game_event_response::PublishTurnEndGameEventResponse;
+
+#[async_trait]
+pub trait GameEventBusService {
+    async fn publish_turn_end_game_event(&mut self, publish_turn_end_game_event_request: PublishTurnEndGameEventRequest) -> PublishTurnEndGameEventResponse;
+    async fn process_pending_game_event(&mut self, process_pending_game_event_request: ProcessPendingGameEventRequest) -> ProcessPendingGameEventResponse;
+}
\ No newline at end of file
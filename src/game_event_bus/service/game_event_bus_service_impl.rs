@@ -0,0 +1,498 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::game_card_token::repository::game_card_token_repository::GameCardTokenRepository;
+use crate::game_card_token::repository::game_card_token_repository_impl::GameCardTokenRepositoryImpl;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_reaction::GameEventReaction;
+use crate::game_event_bus::entity::game_event_reaction_type::GameEventReactionType;
+use crate::game_event_bus::entity::game_event_subscription::GameEventSubscription;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
+use crate::game_event_bus::service::game_event_bus_service::GameEventBusService;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
+use crate::game_event_bus::service::request::publish_turn_end_game_event_request::PublishTurnEndGameEventRequest;
+use crate::game_event_bus::service::response::process_pending_game_event_response::ProcessPendingGameEventResponse;
+use crate::game_event_bus::service::response::publish_turn_end_game_event_response::PublishTurnEndGameEventResponse;
+use crate::game_field_unit::repository::game_field_unit_repository::GameFieldUnitRepository;
+use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
+use crate::game_tomb::repository::game_tomb_repository::GameTombRepository;
+use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+
+// 죽음의 메아리끼리 서로를 계속 발동시키는 경우를 막기 위한 연쇄 깊이 제한
+const MAX_GAME_EVENT_CHAIN_DEPTH: i32 = 4;
+// 한 번의 처리에서 다룰 수 있는 최대 이벤트 수 (초과분은 폐기)
+const MAX_GAME_EVENT_COUNT_PER_PROCESS: i32 = 64;
+
+pub struct GameEventBusServiceImpl {
+    game_card_token_repository: Arc<AsyncMutex<GameCardTokenRepositoryImpl>>,
+}
+
+impl GameEventBusServiceImpl {
+    pub fn new(game_card_token_repository: Arc<AsyncMutex<GameCardTokenRepositoryImpl>>) -> Self {
+
+        GameEventBusServiceImpl {
+            game_card_token_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<GameEventBusServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<GameEventBusServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        GameEventBusServiceImpl::new(
+                            GameCardTokenRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    // 이미 사망한 유닛은 자기 자신의 사망 이벤트 (죽음의 메아리) 에만 반응할 수 있음
+    async fn is_subscriber_able_to_react(&self, subscription: &GameEventSubscription, game_event: &GameEvent) -> bool {
+        let is_own_death_event =
+            game_event.get_event_type() == GameEventType::UnitDeath &&
+                game_event.get_account_unique_id() == subscription.get_account_unique_id() &&
+                game_event.get_unit_index() == subscription.get_unit_index();
+
+        if is_own_death_event {
+            return true
+        }
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        match game_field_unit_repository_guard.get_game_field_unit_map().get_mut(&subscription.get_account_unique_id()) {
+            Some(game_field_unit) => game_field_unit.check_unit_alive(subscription.get_unit_index() as usize),
+            None => false,
+        }
+    }
+
+    // 반응을 필드에 적용하고 피해를 입은 유닛 목록 (계정, 인덱스, 카드 번호, 남은 체력) 을 돌려줌
+    async fn apply_game_event_reaction(&self,
+                                       subscription: &GameEventSubscription,
+                                       game_event: &GameEvent,
+                                       game_event_reaction: &GameEventReaction,
+                                       opponent_of_subscriber: i32) -> Vec<(i32, i32, i32, i32)> {
+
+        let mut damaged_unit_list = Vec::new();
+        let damage = game_event_reaction.get_amount();
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+
+        match game_event_reaction.get_reaction_type() {
+            GameEventReactionType::DamageNearestOpponentUnit => {
+                let target_unit_index = subscription.get_unit_index();
+                let target_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(opponent_of_subscriber, target_unit_index);
+                if game_field_unit_repository_guard.apply_damage_to_target_unit_index(opponent_of_subscriber, target_unit_index, damage) {
+                    let remaining_health_point = game_field_unit_repository_guard.acquire_health_point_of_indexed_unit(
+                        opponent_of_subscriber, target_unit_index).get_current_health_point();
+                    damaged_unit_list.push((opponent_of_subscriber, target_unit_index, target_unit_id, remaining_health_point));
+                }
+            },
+            GameEventReactionType::DamageEveryOpponentUnit => {
+                if !game_field_unit_repository_guard.get_game_field_unit_map().contains_key(&opponent_of_subscriber) {
+                    return damaged_unit_list
+                }
+
+                let living_unit_list = game_field_unit_repository_guard.acquire_current_health_point_of_all_unit(opponent_of_subscriber);
+                game_field_unit_repository_guard.apply_damage_to_every_unit(opponent_of_subscriber, damage);
+
+                for (unit_index, _) in living_unit_list {
+                    let unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(opponent_of_subscriber, unit_index);
+                    let remaining_health_point = game_field_unit_repository_guard.acquire_health_point_of_indexed_unit(
+                        opponent_of_subscriber, unit_index).get_current_health_point();
+                    damaged_unit_list.push((opponent_of_subscriber, unit_index, unit_id, remaining_health_point));
+                }
+            },
+            GameEventReactionType::DamageEventSourceUnit => {
+                if !game_event.has_source_unit() || game_event.get_source_account_unique_id() != opponent_of_subscriber {
+                    return damaged_unit_list
+                }
+
+                let source_unit_index = game_event.get_source_unit_index();
+                let source_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(opponent_of_subscriber, source_unit_index);
+                if game_field_unit_repository_guard.apply_damage_to_target_unit_index(opponent_of_subscriber, source_unit_index, damage) {
+                    let remaining_health_point = game_field_unit_repository_guard.acquire_health_point_of_indexed_unit(
+                        opponent_of_subscriber, source_unit_index).get_current_health_point();
+                    damaged_unit_list.push((opponent_of_subscriber, source_unit_index, source_unit_id, remaining_health_point));
+                }
+            },
+            // 소환은 피해를 주지 않으므로 summon_token_unit() 에서 따로 처리
+            GameEventReactionType::SummonTokenUnit => {},
+            GameEventReactionType::Dummy => {
+                println!("정의되지 않은 반응입니다: {:?}", game_event_reaction);
+            },
+        }
+
+        damaged_unit_list
+    }
+
+    // 토큰은 구독 유닛의 주인 필드에 배치되며 핸드를 거치지 않고 패시브 스킬도 없음
+    async fn summon_token_unit(&self, account_unique_id: i32, token_card_id: i32) -> Option<SummonedTokenUnitInfo> {
+        let game_card_token_repository_guard = self.game_card_token_repository.lock().await;
+        let token_unit_definition = game_card_token_repository_guard.get_token_unit_definition(token_card_id)?;
+        drop(game_card_token_repository_guard);
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let placed_unit_index = game_field_unit_repository_guard.add_unit_to_game_field(
+            account_unique_id,
+            token_card_id,
+            token_unit_definition.get_race(),
+            token_unit_definition.get_grade(),
+            token_unit_definition.get_attack_point(),
+            token_unit_definition.get_health_point(),
+            token_unit_definition.get_attack_required_energy(),
+            false,
+            false,
+            false);
+
+        if placed_unit_index == -1 {
+            println!("필드에 토큰 배치 중 문제가 발생하였습니다.");
+            return None
+        }
+
+        Some(SummonedTokenUnitInfo::new(token_card_id, placed_unit_index))
+    }
+
+    async fn is_token_card(&self, card_id: i32) -> bool {
+        let game_card_token_repository_guard = self.game_card_token_repository.lock().await;
+        game_card_token_repository_guard.is_token_unit(card_id)
+    }
+
+    // 반응으로 사망한 유닛을 정리 (토큰은 무덤으로 가지 않음) 하고 사망 유닛 목록 (인덱스, 카드 번호) 을 돌려줌
+    async fn judge_death_of_damaged_unit(&self, account_unique_id: i32) -> Vec<(i32, i32)> {
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let dead_unit_list = game_field_unit_repository_guard.judge_death_of_every_unit(account_unique_id);
+        drop(game_field_unit_repository_guard);
+
+        for (_, dead_unit_id) in dead_unit_list.iter() {
+            if self.is_token_card(*dead_unit_id).await {
+                continue
+            }
+
+            let game_tomb_repository_mutex = GameTombRepositoryImpl::get_instance();
+            let mut game_tomb_repository_guard = game_tomb_repository_mutex.lock().await;
+            game_tomb_repository_guard.add_dead_unit_to_tomb(account_unique_id, *dead_unit_id);
+        }
+
+        dead_unit_list
+    }
+
+    fn create_chained_game_event(event_type: GameEventType,
+                                 account_unique_id: i32,
+                                 unit_index: i32,
+                                 card_id: i32,
+                                 amount: i32,
+                                 subscription: &GameEventSubscription,
+                                 game_event: &GameEvent) -> GameEvent {
+
+        let mut chained_game_event = GameEvent::new(event_type, account_unique_id, unit_index, card_id, amount);
+        chained_game_event.set_source(subscription.get_account_unique_id(), subscription.get_unit_index());
+        chained_game_event.set_chain_depth(game_event.get_chain_depth() + 1);
+        chained_game_event
+    }
+}
+
+#[async_trait]
+impl GameEventBusService for GameEventBusServiceImpl {
+    async fn publish_turn_end_game_event(&mut self, publish_turn_end_game_event_request: PublishTurnEndGameEventRequest) -> PublishTurnEndGameEventResponse {
+        println!("GameEventBusServiceImpl: publish_turn_end_game_event()");
+
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+        let result = game_event_bus_repository_guard.publish_game_event(
+            GameEvent::new(GameEventType::TurnEnd, publish_turn_end_game_event_request.get_account_unique_id(), -1, -1, 0));
+
+        PublishTurnEndGameEventResponse::new(result)
+    }
+
+    async fn process_pending_game_event(&mut self, process_pending_game_event_request: ProcessPendingGameEventRequest) -> ProcessPendingGameEventResponse {
+        println!("GameEventBusServiceImpl: process_pending_game_event()");
+
+        let turn_owner_unique_id = process_pending_game_event_request.get_account_unique_id();
+        let opponent_unique_id = process_pending_game_event_request.get_opponent_unique_id();
+
+        let mut processed_game_event_count = 0;
+        let mut dead_unit_index_map: HashMap<i32, Vec<i32>> = HashMap::new();
+        let mut damaged_unit_health_point_map: HashMap<i32, HashMap<i32, i32>> = HashMap::new();
+        let mut summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>> = HashMap::new();
+
+        'processing: loop {
+            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+            let pending_game_event_list = game_event_bus_repository_guard.take_pending_game_event_list(turn_owner_unique_id, opponent_unique_id);
+            drop(game_event_bus_repository_guard);
+
+            if pending_game_event_list.is_empty() {
+                break
+            }
+
+            for game_event in pending_game_event_list {
+                if processed_game_event_count >= MAX_GAME_EVENT_COUNT_PER_PROCESS {
+                    println!("처리 가능한 이벤트 수를 초과하여 남은 이벤트를 폐기합니다");
+
+                    let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+                    let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+                    game_event_bus_repository_guard.take_pending_game_event_list(turn_owner_unique_id, opponent_unique_id);
+                    break 'processing
+                }
+
+                processed_game_event_count += 1;
+                println!("처리할 이벤트: {:?}", game_event);
+
+                let mut reaction_list_of_subscription = Vec::new();
+                if game_event.get_chain_depth() < MAX_GAME_EVENT_CHAIN_DEPTH {
+                    let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+                    let game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+                    for subscription in game_event_bus_repository_guard.acquire_ordered_subscription_list(&game_event, turn_owner_unique_id, opponent_unique_id) {
+                        let reaction_list = game_event_bus_repository_guard.handle_game_event(&subscription, &game_event);
+                        reaction_list_of_subscription.push((subscription, reaction_list));
+                    }
+                } else {
+                    println!("연쇄 깊이 제한으로 이벤트에 반응하지 않습니다: {:?}", game_event);
+                }
+
+                for (subscription, reaction_list) in reaction_list_of_subscription {
+                    if !self.is_subscriber_able_to_react(&subscription, &game_event).await {
+                        continue
+                    }
+
+                    let opponent_of_subscriber =
+                        if subscription.get_account_unique_id() == turn_owner_unique_id { opponent_unique_id } else { turn_owner_unique_id };
+
+                    for game_event_reaction in reaction_list {
+                        if game_event_reaction.get_reaction_type() == GameEventReactionType::SummonTokenUnit {
+                            let summoner_unique_id = subscription.get_account_unique_id();
+                            let summoned_token_unit_info = match self.summon_token_unit(
+                                summoner_unique_id, game_event_reaction.get_amount()).await {
+                                Some(summoned_token_unit_info) => summoned_token_unit_info,
+                                None => continue,
+                            };
+
+                            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+                            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+                            game_event_bus_repository_guard.publish_game_event(GameEventBusServiceImpl::create_chained_game_event(
+                                GameEventType::UnitDeployed, summoner_unique_id, summoned_token_unit_info.get_placed_unit_index(),
+                                summoned_token_unit_info.get_token_card_id(), 0, &subscription, &game_event));
+                            drop(game_event_bus_repository_guard);
+
+                            summoned_token_unit_map.entry(summoner_unique_id).or_insert_with(Vec::new).push(summoned_token_unit_info);
+                            continue
+                        }
+
+                        let damaged_unit_list = self.apply_game_event_reaction(
+                            &subscription, &game_event, &game_event_reaction, opponent_of_subscriber).await;
+
+                        if damaged_unit_list.is_empty() {
+                            continue
+                        }
+
+                        let mut chained_game_event_list = Vec::new();
+                        for (damaged_account_unique_id, damaged_unit_index, damaged_unit_id, remaining_health_point) in damaged_unit_list {
+                            // 같은 유닛이 여러 번 피해를 입으면 마지막 체력만 남김
+                            damaged_unit_health_point_map.entry(damaged_account_unique_id).or_insert_with(HashMap::new)
+                                .insert(damaged_unit_index, remaining_health_point);
+                            chained_game_event_list.push(GameEventBusServiceImpl::create_chained_game_event(
+                                GameEventType::UnitDamaged, damaged_account_unique_id, damaged_unit_index, damaged_unit_id,
+                                game_event_reaction.get_amount(), &subscription, &game_event));
+                        }
+
+                        for (dead_unit_index, dead_unit_id) in self.judge_death_of_damaged_unit(opponent_of_subscriber).await {
+                            dead_unit_index_map.entry(opponent_of_subscriber).or_insert_with(Vec::new).push(dead_unit_index);
+                            chained_game_event_list.push(GameEventBusServiceImpl::create_chained_game_event(
+                                GameEventType::UnitDeath, opponent_of_subscriber, dead_unit_index, dead_unit_id,
+                                0, &subscription, &game_event));
+                        }
+
+                        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+                        let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+                        for chained_game_event in chained_game_event_list {
+                            game_event_bus_repository_guard.publish_game_event(chained_game_event);
+                        }
+                    }
+                }
+
+                // 죽음의 메아리 처리가 끝난 유닛은 더 이상 이벤트를 구독하지 않음
+                if game_event.get_event_type() == GameEventType::UnitDeath {
+                    let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+                    let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+                    game_event_bus_repository_guard.unsubscribe_field_unit(game_event.get_account_unique_id(), game_event.get_unit_index());
+                }
+            }
+        }
+
+        ProcessPendingGameEventResponse::new(
+            processed_game_event_count, dead_unit_index_map, damaged_unit_health_point_map, summoned_token_unit_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use super::*;
+    use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
+    use crate::card_effect_definition::entity::passive_skill_effect_definition::PassiveSkillEffectDefinition;
+    use crate::card_effect_definition::entity::token_unit_definition::TokenUnitDefinition;
+    use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
+    use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
+    use crate::game_event_bus::handler::data_driven::game_card_event_data_driven_handler_impl::DataDrivenGameCardEventFunction;
+    use crate::game_event_bus::handler::game_card_event_handler::GameCardEventHandler;
+
+    const SUMMONER_UNIT_CARD_ID: i32 = 9001;
+    const TOKEN_UNIT_CARD_ID: i32 = 9002;
+    const BROAD_AREA_UNIT_CARD_ID: i32 = 9003;
+
+    fn create_data_driven_handler(json: &str) -> Box<dyn GameCardEventHandler> {
+        let passive_skill_effect_definition: PassiveSkillEffectDefinition = serde_json::from_str(json).unwrap();
+        Box::new(DataDrivenGameCardEventFunction::new(passive_skill_effect_definition))
+    }
+
+    // 테스트마다 새 배틀 룸 상태 안에서 실행하여 다른 테스트와 저장소를 공유하지 않음
+    async fn run_in_test_battle_room<TestFuture: Future<Output = ()>>(test_future: TestFuture) {
+        Arc::new(BattleRoomState::new()).scope(test_future).await
+    }
+
+    // 전역 카드 테이블 없이 소환 유닛, 토큰, 광역 피해 유닛만 정의한 테스트용 서비스
+    async fn create_test_service() -> (GameEventBusServiceImpl,
+                                       Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
+                                       Arc<AsyncMutex<GameTombRepositoryImpl>>) {
+
+        let mut game_card_event_handler_map: HashMap<i32, Vec<Box<dyn GameCardEventHandler>>> = HashMap::new();
+        game_card_event_handler_map.insert(SUMMONER_UNIT_CARD_ID, vec![create_data_driven_handler(
+            r#"{ "skill_type": "SummonToken", "casting_conditions": ["OnDeath"], "skill_damage": 0, "summon_token_card_id": 9002 }"#)]);
+        game_card_event_handler_map.insert(BROAD_AREA_UNIT_CARD_ID, vec![create_data_driven_handler(
+            r#"{ "skill_type": "BroadArea", "casting_conditions": ["TurnEnd"], "skill_damage": 20 }"#)]);
+
+        let token_unit_definition: TokenUnitDefinition = serde_json::from_str(
+            r#"{ "race": "Undead", "grade": "Common", "attack_point": 5, "health_point": 10 }"#).unwrap();
+
+        // 현재 배틀 룸의 이벤트 버스를 테스트용 handler 만 가진 버스로 교체
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        *game_event_bus_repository_mutex.lock().await =
+            GameEventBusRepositoryImpl::with_game_card_event_handler_map(game_card_event_handler_map);
+
+        let game_event_bus_service = GameEventBusServiceImpl::new(
+            Arc::new(AsyncMutex::new(GameCardTokenRepositoryImpl::with_token_unit_definition_map(
+                HashMap::from([(TOKEN_UNIT_CARD_ID, token_unit_definition)])))));
+
+        (game_event_bus_service, GameFieldUnitRepositoryImpl::get_instance(), GameTombRepositoryImpl::get_instance())
+    }
+
+    async fn acquire_tomb_card_list(game_tomb_repository: &Arc<AsyncMutex<GameTombRepositoryImpl>>, account_unique_id: i32) -> Vec<i32> {
+        let mut game_tomb_repository_guard = game_tomb_repository.lock().await;
+        game_tomb_repository_guard.get_game_tomb_map().get(&account_unique_id).unwrap()
+            .get_tomb_card_list().get_tomb_card_list().iter()
+            .map(|tomb_card| tomb_card.get_card()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_process_pending_game_event_without_subscription() {
+        run_in_test_battle_room(async {
+            let account_unique_id = 9101;
+            let opponent_unique_id = 9102;
+
+            let (mut game_event_bus_service, _, _) = create_test_service().await;
+
+            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+            game_event_bus_repository_guard.create_game_event_bus_object(account_unique_id);
+            game_event_bus_repository_guard.create_game_event_bus_object(opponent_unique_id);
+            drop(game_event_bus_repository_guard);
+
+            let publish_turn_end_game_event_response =
+                game_event_bus_service.publish_turn_end_game_event(
+                    PublishTurnEndGameEventRequest::new(account_unique_id)).await;
+            assert!(publish_turn_end_game_event_response.is_success());
+
+            let process_pending_game_event_response =
+                game_event_bus_service.process_pending_game_event(
+                    ProcessPendingGameEventRequest::new(account_unique_id, opponent_unique_id)).await;
+            assert_eq!(process_pending_game_event_response.get_processed_game_event_count(), 1);
+            assert!(process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id).is_empty());
+
+            // 이미 처리된 이벤트는 다시 처리되지 않음
+            let process_pending_game_event_response =
+                game_event_bus_service.process_pending_game_event(
+                    ProcessPendingGameEventRequest::new(account_unique_id, opponent_unique_id)).await;
+            assert_eq!(process_pending_game_event_response.get_processed_game_event_count(), 0);
+
+            // 버스가 없는 플레이어에게는 이벤트를 발행할 수 없음
+            let publish_turn_end_game_event_response =
+                game_event_bus_service.publish_turn_end_game_event(
+                    PublishTurnEndGameEventRequest::new(9103)).await;
+            assert!(!publish_turn_end_game_event_response.is_success());
+        }).await;
+    }
+
+    #[tokio::test]
+    async fn test_summoned_token_unit_dies_without_tomb_entry() {
+        run_in_test_battle_room(async {
+            let summoner_unique_id = 1;
+            let opponent_unique_id = 2;
+
+            let (mut game_event_bus_service, game_field_unit_repository, game_tomb_repository) =
+                create_test_service().await;
+
+            let mut game_field_unit_repository_guard = game_field_unit_repository.lock().await;
+            game_field_unit_repository_guard.create_game_field_unit_object(summoner_unique_id);
+            game_field_unit_repository_guard.create_game_field_unit_object(opponent_unique_id);
+            game_field_unit_repository_guard.add_unit_to_game_field(
+                summoner_unique_id, SUMMONER_UNIT_CARD_ID, RaceEnum::Human, GradeEnum::Common, 5, 15, 1, true, false, false);
+            game_field_unit_repository_guard.add_unit_to_game_field(
+                opponent_unique_id, BROAD_AREA_UNIT_CARD_ID, RaceEnum::Human, GradeEnum::Common, 5, 100, 1, true, false, false);
+            drop(game_field_unit_repository_guard);
+
+            let mut game_tomb_repository_guard = game_tomb_repository.lock().await;
+            game_tomb_repository_guard.create_game_tomb_object(summoner_unique_id);
+            game_tomb_repository_guard.create_game_tomb_object(opponent_unique_id);
+            drop(game_tomb_repository_guard);
+
+            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+            game_event_bus_repository_guard.create_game_event_bus_object(summoner_unique_id);
+            game_event_bus_repository_guard.create_game_event_bus_object(opponent_unique_id);
+            assert_eq!(game_event_bus_repository_guard.subscribe_field_unit(summoner_unique_id, 0, SUMMONER_UNIT_CARD_ID), 1);
+            assert_eq!(game_event_bus_repository_guard.subscribe_field_unit(opponent_unique_id, 0, BROAD_AREA_UNIT_CARD_ID), 1);
+            drop(game_event_bus_repository_guard);
+
+            // 상대 턴 종료 광역 피해로 소환 유닛이 죽으면 죽음의 메아리로 토큰이 필드에 배치됨
+            game_event_bus_service.publish_turn_end_game_event(PublishTurnEndGameEventRequest::new(opponent_unique_id)).await;
+            let process_pending_game_event_response = game_event_bus_service.process_pending_game_event(
+                ProcessPendingGameEventRequest::new(opponent_unique_id, summoner_unique_id)).await;
+
+            assert_eq!(process_pending_game_event_response.get_dead_unit_index_list_of_account(summoner_unique_id), vec![0]);
+            assert_eq!(process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(summoner_unique_id),
+                       HashMap::from([(0, 0)]));
+            assert_eq!(process_pending_game_event_response.get_summoned_token_unit_list_of_account(summoner_unique_id),
+                       vec![SummonedTokenUnitInfo::new(TOKEN_UNIT_CARD_ID, 1)]);
+
+            let mut game_field_unit_repository_guard = game_field_unit_repository.lock().await;
+            let game_field_unit = game_field_unit_repository_guard.get_game_field_unit_map().get_mut(&summoner_unique_id).unwrap();
+            assert_eq!(game_field_unit.get_all_unit_list_in_game_field()[1].get_card(), TOKEN_UNIT_CARD_ID);
+            assert!(game_field_unit.check_unit_alive(1));
+            drop(game_field_unit_repository_guard);
+
+            assert_eq!(acquire_tomb_card_list(&game_tomb_repository, summoner_unique_id).await, vec![SUMMONER_UNIT_CARD_ID]);
+
+            // 다음 광역 피해로 토큰이 죽어도 무덤에는 들어가지 않음
+            game_event_bus_service.publish_turn_end_game_event(PublishTurnEndGameEventRequest::new(opponent_unique_id)).await;
+            let process_pending_game_event_response = game_event_bus_service.process_pending_game_event(
+                ProcessPendingGameEventRequest::new(opponent_unique_id, summoner_unique_id)).await;
+
+            assert_eq!(process_pending_game_event_response.get_dead_unit_index_list_of_account(summoner_unique_id), vec![1]);
+            // 이미 사망한 소환 유닛은 다시 피해를 입지 않으므로 토큰의 체력만 전달됨
+            assert_eq!(process_pending_game_event_response.get_damaged_unit_health_point_map_of_account(summoner_unique_id),
+                       HashMap::from([(1, 0)]));
+            assert!(process_pending_game_event_response.get_summoned_token_unit_list_of_account(summoner_unique_id).is_empty());
+            assert_eq!(acquire_tomb_card_list(&game_tomb_repository, summoner_unique_id).await, vec![SUMMONER_UNIT_CARD_ID]);
+        }).await;
+    }
+}
\ No newline at end of file
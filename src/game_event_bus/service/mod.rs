@@ -0,0 +1,4 @@
+pub mod game_event_bus_service;
+pub mod game_event_bus_service_impl;
+pub mod request;
+pub mod response;
\ No newline at end of file
@@ -0,0 +1,19 @@
+// account_unique_id 는 현재 턴을 진행 중인 플레이어 (동시 반응 시 먼저 처리됨)
+#[derive(Debug)]
+pub struct ProcessPendingGameEventRequest {
+    account_unique_id: i32,
+    opponent_unique_id: i32,
+}
+
+impl ProcessPendingGameEventRequest {
+    pub fn new(account_unique_id: i32, opponent_unique_id: i32) -> Self {
+        ProcessPendingGameEventRequest {
+            account_unique_id,
+            opponent_unique_id
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod publish_turn_end_game_event_response;
+pub mod process_pending_game_event_response;
\ No newline at end of file
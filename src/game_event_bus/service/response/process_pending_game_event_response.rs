@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ProcessPendingGameEventResponse {
+    processed_game_event_count: i32,
+    dead_unit_index_map: HashMap<i32, Vec<i32>>,
+    damaged_unit_health_point_map: HashMap<i32, HashMap<i32, i32>>,
+    summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>,
+}
+
+impl ProcessPendingGameEventResponse {
+    pub fn new(processed_game_event_count: i32,
+               dead_unit_index_map: HashMap<i32, Vec<i32>>,
+               damaged_unit_health_point_map: HashMap<i32, HashMap<i32, i32>>,
+               summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>) -> Self {
+        ProcessPendingGameEventResponse {
+            processed_game_event_count,
+            dead_unit_index_map,
+            damaged_unit_health_point_map,
+            summoned_token_unit_map
+        }
+    }
+
+    pub fn default() -> Self {
+        ProcessPendingGameEventResponse::new(0, HashMap::new(), HashMap::new(), HashMap::new())
+    }
+
+    pub fn get_processed_game_event_count(&self) -> i32 { self.processed_game_event_count }
+
+    pub fn get_dead_unit_index_map(&self) -> &HashMap<i32, Vec<i32>> { &self.dead_unit_index_map }
+
+    // 이벤트 처리 도중 사망한 해당 플레이어의 유닛 인덱스 목록
+    pub fn get_dead_unit_index_list_of_account(&self, account_unique_id: i32) -> Vec<i32> {
+        self.dead_unit_index_map.get(&account_unique_id).cloned().unwrap_or_default()
+    }
+
+    pub fn get_damaged_unit_health_point_map(&self) -> &HashMap<i32, HashMap<i32, i32>> { &self.damaged_unit_health_point_map }
+
+    // 이벤트 반응으로 피해를 입은 해당 플레이어의 유닛 인덱스별 남은 체력
+    pub fn get_damaged_unit_health_point_map_of_account(&self, account_unique_id: i32) -> HashMap<i32, i32> {
+        self.damaged_unit_health_point_map.get(&account_unique_id).cloned().unwrap_or_default()
+    }
+
+    pub fn get_summoned_token_unit_map(&self) -> &HashMap<i32, Vec<SummonedTokenUnitInfo>> { &self.summoned_token_unit_map }
+
+    // 이벤트 반응으로 해당 플레이어의 필드에 소환된 토큰 목록
+    pub fn get_summoned_token_unit_list_of_account(&self, account_unique_id: i32) -> Vec<SummonedTokenUnitInfo> {
+        self.summoned_token_unit_map.get(&account_unique_id).cloned().unwrap_or_default()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PublishTurnEndGameEventResponse {
+    is_success: bool,
+}
+
+impl PublishTurnEndGameEventResponse {
+    pub fn new(is_success: bool) -> Self {
+        PublishTurnEndGameEventResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool { self.is_success }
+}
\ No newline at end of file
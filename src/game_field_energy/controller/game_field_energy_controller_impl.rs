@@ -113,10 +113,8 @@ impl GameFieldEnergyController for GameFieldEnergyControllerImpl {
         drop(game_protocol_validation_service_guard);
 
         // 3. 필드 에너지가 충분히 있는지 검증합니다.
-        let will_be_used_field_energy_quantity_string =
-            attach_field_energy_to_field_unit_request_form.get_quantity().to_string();
         let will_be_used_field_energy_quantity =
-            will_be_used_field_energy_quantity_string.parse::<i32>().unwrap();
+            attach_field_energy_to_field_unit_request_form.get_quantity();
 
         let mut game_field_energy_service_guard =
             self.game_field_energy_service.lock().await;
@@ -134,13 +132,9 @@ impl GameFieldEnergyController for GameFieldEnergyControllerImpl {
         }
 
         // 4. 필드 에너지를 수량에 따라 부착합니다.
-        let unit_card_index_string =
-            attach_field_energy_to_field_unit_request_form.get_unit_index().to_string();
-        let unit_card_index = unit_card_index_string.parse::<i32>().unwrap();
+        let unit_card_index = attach_field_energy_to_field_unit_request_form.get_unit_index();
 
-        let energy_race_string =
-            attach_field_energy_to_field_unit_request_form.get_energy_race().to_string();
-        let energy_race_enum = RaceEnum::from(energy_race_string.parse::<i32>().unwrap());
+        let energy_race_enum = RaceEnum::from(attach_field_energy_to_field_unit_request_form.get_energy_race());
 
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
@@ -19,31 +19,31 @@ use crate::ui_data_generator::service::request::generate_my_specific_unit_energy
 #[derive(Debug)]
 pub struct AttachFieldEnergyToFieldUnitRequestForm {
     session_id: String,
-    unit_index: String,
-    energy_race: String,
-    quantity: String,
+    unit_index: i32,
+    energy_race: i32,
+    quantity: i32,
 }
 
 impl AttachFieldEnergyToFieldUnitRequestForm {
     pub fn new(session_id: String,
-               unit_index: String,
-               energy_race: String,
-               quantity: String) -> Self {
+               unit_index: i32,
+               energy_race: i32,
+               quantity: i32) -> Self {
         AttachFieldEnergyToFieldUnitRequestForm {
             session_id: session_id.to_string(),
-            unit_index: unit_index.to_string(),
-            energy_race: energy_race.to_string(),
-            quantity: quantity.to_string()
+            unit_index,
+            energy_race,
+            quantity
         }
     }
 
     pub fn get_session_id(&self) -> &str { &self.session_id }
 
-    pub fn get_unit_index(&self) -> &str { &self.unit_index }
+    pub fn get_unit_index(&self) -> i32 { self.unit_index }
 
-    pub fn get_energy_race(&self) -> &str { &self.energy_race }
+    pub fn get_energy_race(&self) -> i32 { self.energy_race }
 
-    pub fn get_quantity(&self) -> &str { &self.quantity }
+    pub fn get_quantity(&self) -> i32 { self.quantity }
 
     pub fn to_session_validation_request(&self) -> GetValueWithKeyRequest {
         GetValueWithKeyRequest::new(self.session_id.clone().as_str())
@@ -51,4 +51,6 @@ impl AttachFieldEnergyToFieldUnitResponseForm {
             HashMap::new(),
             HashMap::new())
     }
+
+    pub fn get_false_message_enum(&self) -> i32 { self.false_message_enum }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod entity;
 pub mod repository;
 pub mod service;
-pub mod controller;
\ No newline at end of file
+pub mod controller;
+pub mod protocol;
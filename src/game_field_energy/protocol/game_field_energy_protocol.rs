@@ -0,0 +1,16 @@
+use crate::game_field_energy::controller::game_field_energy_controller::GameFieldEnergyController;
+use crate::game_field_energy::controller::game_field_energy_controller_impl::GameFieldEnergyControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::attach_field_energy_to_field_unit_request_form_generator::create_attach_field_energy_to_field_unit_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_field_energy_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Attach field energy to field unit
+    protocol_registry.register_battle_room_action(1003, "ATTACH_FIELD_ENERGY_TO_UNIT", create_attach_field_energy_to_field_unit_request_form, |request| async move {
+        let game_field_energy_controller_mutex = GameFieldEnergyControllerImpl::get_instance();
+        let game_field_energy_controller = game_field_energy_controller_mutex.lock().await;
+
+        let response = game_field_energy_controller.request_to_attach_field_energy_to_field_unit(request).await;
+        ResponseType::ATTACH_FIELD_ENERGY_TO_UNIT(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod game_field_energy_protocol;
@@ -1,20 +1,32 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
+use crate::game_card_environment::repository::environment_modifier_hook::EnvironmentModifierHook;
+use crate::game_card_environment::repository::game_card_environment_repository_impl::GameCardEnvironmentRepositoryImpl;
 use crate::game_field_energy::entity::game_field_energy::GameFieldEnergy;
 use crate::game_field_energy::repository::game_field_energy_repository::GameFieldEnergyRepository;
 
 pub struct GameFieldEnergyRepositoryImpl {
     game_field_energy_map: IndexMap<i32, GameFieldEnergy>,
+    // 현재 필드에 배치된 환경 카드의 보정치 조회용 (턴 시작 시 획득하는 필드 에너지에 반영)
+    environment_modifier_hook: EnvironmentModifierHook,
 }
 
 impl GameFieldEnergyRepositoryImpl {
     pub fn new() -> Self {
+        GameFieldEnergyRepositoryImpl::with_environment_modifier_hook(
+            GameCardEnvironmentRepositoryImpl::get_environment_modifier_hook())
+    }
+
+    pub fn with_environment_modifier_hook(environment_modifier_hook: EnvironmentModifierHook) -> Self {
         GameFieldEnergyRepositoryImpl {
             game_field_energy_map: IndexMap::new(),
+            environment_modifier_hook,
         }
     }
 
@@ -23,6 +35,11 @@ impl GameFieldEnergyRepositoryImpl {
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_field_energy_repository()
+        }
+
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>> =
                 Arc::new(
@@ -56,6 +73,15 @@ impl GameFieldEnergyRepository for GameFieldEnergyRepositoryImpl {
         false
     }
 
+    fn add_turn_start_field_energy(&mut self, account_unique_id: i32, base_amount: i32) -> bool {
+        println!("FieldEnergyRepositoryImpl: add_turn_start_field_energy()");
+
+        let extra_field_energy = self.environment_modifier_hook.find_environment_modifier(account_unique_id)
+            .map_or(0, |environment_modifier| environment_modifier.get_extra_field_energy_per_turn());
+
+        self.add_field_energy_with_amount(account_unique_id, base_amount + extra_field_energy)
+    }
+
     fn remove_field_energy_with_amount(&mut self, account_unique_id: i32, amount: i32) -> bool {
         println!("FieldEnergyRepositoryImpl: remove_field_energy_with_amount()");
 
@@ -97,6 +123,9 @@ impl GameFieldEnergyRepository for GameFieldEnergyRepositoryImpl {
 mod tests {
     use super::*;
     use tokio::test;
+    use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+    use crate::game_card_environment::entity::game_card_environment_effect::GameCardEnvironmentEffect;
+    use crate::game_card_environment::repository::game_card_environment_repository::GameCardEnvironmentRepository;
 
     #[tokio::test]
     async fn test_create_field_energy_object() {
@@ -156,6 +185,28 @@ mod tests {
         assert_eq!(result, false);
     }
 
+    #[tokio::test]
+    async fn test_add_turn_start_field_energy_with_environment_modifier() {
+        let mut environment_modifier = EnvironmentModifier::new();
+        environment_modifier.set_extra_field_energy_per_turn(2);
+
+        let mut game_card_environment_repository = GameCardEnvironmentRepositoryImpl::with_environment_modifier_hook(
+            EnvironmentModifierHook::new(HashMap::from([(45, GameCardEnvironmentEffect::new(1, environment_modifier))])));
+        let mut repository = GameFieldEnergyRepositoryImpl::with_environment_modifier_hook(
+            game_card_environment_repository.to_environment_modifier_hook());
+
+        let account_unique_id = 1;
+        repository.create_field_energy_object(account_unique_id);
+        game_card_environment_repository.deploy_environment_card(account_unique_id, 2, 45);
+
+        repository.add_turn_start_field_energy(account_unique_id, 1);
+        assert_eq!(repository.get_game_field_energy_map().get(&account_unique_id).unwrap().get_energy_count(), 3);
+
+        game_card_environment_repository.decrease_environment_remaining_turn(account_unique_id, 2);
+        repository.add_turn_start_field_energy(account_unique_id, 1);
+        assert_eq!(repository.get_game_field_energy_map().get(&account_unique_id).unwrap().get_energy_count(), 4);
+    }
+
     #[tokio::test]
     async fn test_get_instance() {
         let instance1 = GameFieldEnergyRepositoryImpl::get_instance();
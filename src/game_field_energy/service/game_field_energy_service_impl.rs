@@ -6,22 +6,22 @@ use crate::game_field_energy::repository::game_field_energy_repository::GameFiel
 use crate::game_field_energy::repository::game_field_energy_repository_impl::GameFieldEnergyRepositoryImpl;
 use crate::game_field_energy::service::game_field_energy_service::GameFieldEnergyService;
 use crate::game_field_energy::service::request::add_field_energy_with_amount_request::AddFieldEnergyWithAmountRequest;
+use crate::game_field_energy::service::request::add_turn_start_field_energy_request::AddTurnStartFieldEnergyRequest;
 use crate::game_field_energy::service::request::check_field_energy_enough_to_use_request::CheckFieldEnergyEnoughToUseRequest;
 use crate::game_field_energy::service::request::get_current_field_energy_request::GetCurrentFieldEnergyRequest;
 use crate::game_field_energy::service::request::remove_field_energy_with_amount_request::RemoveFieldEnergyWithAmountRequest;
 use crate::game_field_energy::service::response::add_field_energy_with_amount_response::AddFieldEnergyWithAmountResponse;
+use crate::game_field_energy::service::response::add_turn_start_field_energy_response::AddTurnStartFieldEnergyResponse;
 use crate::game_field_energy::service::response::check_field_energy_enough_to_use_response::CheckFieldEnergyEnoughToUseResponse;
 use crate::game_field_energy::service::response::get_current_field_energy_response::GetCurrentFieldEnergyResponse;
 use crate::game_field_energy::service::response::remove_field_energy_with_amount_response::RemoveFieldEnergyWithAmountResponse;
 
 pub struct GameFieldEnergyServiceImpl {
-    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
 }
 
 impl GameFieldEnergyServiceImpl {
-    pub fn new(game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>) -> Self {
+    pub fn new() -> Self {
         GameFieldEnergyServiceImpl {
-            game_field_energy_repository,
         }
     }
     pub fn get_instance() -> Arc<AsyncMutex<GameFieldEnergyServiceImpl>> {
@@ -29,8 +29,7 @@ impl GameFieldEnergyServiceImpl {
             static ref INSTANCE: Arc<AsyncMutex<GameFieldEnergyServiceImpl>> =
                 Arc::new(
                     AsyncMutex::new(
-                        GameFieldEnergyServiceImpl::new(
-                            GameFieldEnergyRepositoryImpl::get_instance())));
+                        GameFieldEnergyServiceImpl::new()));
         }
         INSTANCE.clone()
     }
@@ -41,7 +40,8 @@ impl GameFieldEnergyService for GameFieldEnergyServiceImpl {
     async fn add_field_energy_with_amount(&self, add_field_energy_with_amount_request: AddFieldEnergyWithAmountRequest) -> AddFieldEnergyWithAmountResponse {
         println!("GameFieldEnergyServiceImpl: add_field_energy_with_amount()");
 
-        let mut game_field_energy_repository_guard = self.game_field_energy_repository.lock().await;
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
 
         let account_unique_id = add_field_energy_with_amount_request.get_account_unique_id();
         let amount = add_field_energy_with_amount_request.get_amount_to_add();
@@ -51,10 +51,25 @@ impl GameFieldEnergyService for GameFieldEnergyServiceImpl {
         AddFieldEnergyWithAmountResponse::new(addition_of_field_energy_result)
     }
 
+    async fn add_turn_start_field_energy(&self, add_turn_start_field_energy_request: AddTurnStartFieldEnergyRequest) -> AddTurnStartFieldEnergyResponse {
+        println!("GameFieldEnergyServiceImpl: add_turn_start_field_energy()");
+
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
+
+        let account_unique_id = add_turn_start_field_energy_request.get_account_unique_id();
+        let base_amount = add_turn_start_field_energy_request.get_base_amount();
+
+        let addition_of_field_energy_result = game_field_energy_repository_guard.add_turn_start_field_energy(account_unique_id, base_amount);
+
+        AddTurnStartFieldEnergyResponse::new(addition_of_field_energy_result)
+    }
+
     async fn remove_field_energy_with_amount(&self, remove_field_energy_with_amount_request: RemoveFieldEnergyWithAmountRequest) -> RemoveFieldEnergyWithAmountResponse {
         println!("GameFieldEnergyServiceImpl: remove_field_energy_with_amount()");
 
-        let mut game_field_energy_repository_guard = self.game_field_energy_repository.lock().await;
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
 
         let account_unique_id = remove_field_energy_with_amount_request.get_account_unique_id();
         let amount = remove_field_energy_with_amount_request.get_amount_to_remove();
@@ -67,7 +82,8 @@ impl GameFieldEnergyService for GameFieldEnergyServiceImpl {
     async fn check_field_energy_enough_to_use(&self, check_field_energy_enough_to_use_request: CheckFieldEnergyEnoughToUseRequest) -> CheckFieldEnergyEnoughToUseResponse {
         println!("GameFieldEnergyServiceImpl: check_field_energy_enough_to_use()");
 
-        let mut game_field_energy_repository_guard = self.game_field_energy_repository.lock().await;
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
 
         let account_unique_id = check_field_energy_enough_to_use_request.get_account_unique_id();
         let amount = check_field_energy_enough_to_use_request.get_will_be_used_amount();
@@ -80,7 +96,8 @@ impl GameFieldEnergyService for GameFieldEnergyServiceImpl {
     async fn get_current_field_energy(&self, get_current_field_energy_request: GetCurrentFieldEnergyRequest) -> GetCurrentFieldEnergyResponse {
         println!("GameFieldEnergyServiceImpl: get_current_field_energy()");
 
-        let mut game_field_energy_repository_guard = self.game_field_energy_repository.lock().await;
+        let game_field_energy_repository_mutex = GameFieldEnergyRepositoryImpl::get_instance();
+        let mut game_field_energy_repository_guard = game_field_energy_repository_mutex.lock().await;
         let account_unique_id = get_current_field_energy_request.get_account_unique_id();
 
         if let Some(game_field_energy) = game_field_energy_repository_guard.get_game_field_energy_map().get_mut(&account_unique_id) {
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct AddTurnStartFieldEnergyRequest {
+    account_unique_id: i32,
+    base_amount: i32,
+}
+
+impl AddTurnStartFieldEnergyRequest {
+    pub fn new(account_unique_id: i32, base_amount: i32) -> Self {
+        AddTurnStartFieldEnergyRequest {
+            account_unique_id,
+            base_amount,
+        }
+    }
+    pub fn get_account_unique_id(&self) -> i32 { self.account_unique_id }
+    pub fn get_base_amount(&self) -> i32 { self.base_amount }
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod remove_field_energy_with_amount_request;
 pub mod add_field_energy_with_amount_request;
 pub mod check_field_energy_enough_to_use_request;
-pub mod get_current_field_energy_request;
\ No newline at end of file
+pub mod get_current_field_energy_request;
+pub mod add_turn_start_field_energy_request;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTurnStartFieldEnergyResponse {
+    is_success: bool,
+}
+
+impl AddTurnStartFieldEnergyResponse {
+    pub fn new(is_success: bool) -> Self { AddTurnStartFieldEnergyResponse { is_success } }
+    pub fn is_success(&self) -> bool { self.is_success }
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod remove_field_energy_with_amount_response;
 pub mod add_field_energy_with_amount_response;
 pub mod check_field_energy_enough_to_use_response;
-pub mod get_current_field_energy_response;
\ No newline at end of file
+pub mod get_current_field_energy_response;
+pub mod add_turn_start_field_energy_response;
\ No newline at end of file
@@ -1,10 +1,25 @@
 use serde::{Deserialize, Serialize};
 
+// 게임 내 모든 상태 효과의 단일 목록 (효과별 규칙은 StatusEffectRule 참고)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExtraEffect {
     Dummy,
     Freeze = 1,
+    #[serde(alias = "Darkfire")]
     DarkFire = 2,
+    Poison = 3,
+    Stun = 4,
+    Silence = 5,
+    Shield = 6,
+    AttackBuff = 7,
+    AttackDebuff = 8,
+    Regeneration = 9,
+}
+
+impl ExtraEffect {
+    pub fn to_i32(&self) -> i32 {
+        self.clone() as i32
+    }
 }
 
 impl From<i32> for ExtraEffect {
@@ -12,7 +27,37 @@ impl From<i32> for ExtraEffect {
         match value {
             1 => ExtraEffect::Freeze,
             2 => ExtraEffect::DarkFire,
+            3 => ExtraEffect::Poison,
+            4 => ExtraEffect::Stun,
+            5 => ExtraEffect::Silence,
+            6 => ExtraEffect::Shield,
+            7 => ExtraEffect::AttackBuff,
+            8 => ExtraEffect::AttackDebuff,
+            9 => ExtraEffect::Regeneration,
             _ => ExtraEffect::Dummy,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extra_effect_number_round_trip() {
+        let extra_effect_list = vec![
+            ExtraEffect::Freeze, ExtraEffect::DarkFire, ExtraEffect::Poison,
+            ExtraEffect::Stun, ExtraEffect::Silence, ExtraEffect::Shield,
+            ExtraEffect::AttackBuff, ExtraEffect::AttackDebuff, ExtraEffect::Regeneration];
+
+        for extra_effect in extra_effect_list {
+            assert_eq!(ExtraEffect::from(extra_effect.to_i32()), extra_effect);
+        }
+    }
+
+    #[test]
+    fn test_extra_effect_accepts_legacy_dark_fire_name() {
+        let extra_effect: ExtraEffect = serde_json::from_str("\"Darkfire\"").unwrap();
+        assert_eq!(extra_effect, ExtraEffect::DarkFire);
+    }
+}
\ No newline at end of file
@@ -34,6 +34,10 @@ impl ExtraStatusEffect {
         self.effect_damage
     }
 
+    pub fn set_effect_damage(&mut self, effect_damage: i32) {
+        self.effect_damage = effect_damage;
+    }
+
     pub fn get_reuse_turn(&self) -> i32 {
         self.reuse_turn
     }
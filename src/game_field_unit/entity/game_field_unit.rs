@@ -8,6 +8,7 @@ use crate::game_field_unit::entity::game_field_unit_card::GameFieldUnitCard;
 use crate::game_field_unit::entity::game_field_unit_card_list::GameFieldUnitCardList;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
 
 #[derive(Debug, Clone)]
 pub struct GameFieldUnit {
@@ -109,6 +110,14 @@ impl GameFieldUnit {
         self.game_field_unit.apply_status_effect_damage_iteratively();
     }
 
+    pub fn tick_status_effect_iteratively(&mut self, tick_timing: StatusEffectTickTiming, bonus_effect_damage: i32) {
+        self.game_field_unit.tick_status_effect_iteratively(tick_timing, bonus_effect_damage);
+    }
+
+    pub fn decrease_turn_based_status_duration_iteratively(&mut self) {
+        self.game_field_unit.decrease_turn_based_status_duration_iteratively();
+    }
+
     pub fn impose_extra_effect_state_to_indexed_unit(&mut self, unit_card_index: usize, extra_effect_state: SummaryPassiveSkillEffect) {
 
         let passive_skill_type = extra_effect_state.get_passive_skill_type();
@@ -128,6 +137,10 @@ impl GameFieldUnit {
         return self.game_field_unit.get_harmful_status_list_of_all_unit()
     }
 
+    pub fn get_extra_effect_list_of_all_indexed_unit(&self) -> Vec<(i32, Vec<ExtraEffect>)> {
+        return self.game_field_unit.get_extra_effect_list_of_all_unit()
+    }
+
     pub fn get_harmful_status_list_of_indexed_unit(&mut self, unit_card_index: usize) -> Vec<HarmfulStatusEffect> {
         return self.game_field_unit.get_harmful_status_list_of_indexed_unit(unit_card_index)
     }
@@ -7,6 +7,11 @@ use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
+use crate::game_field_unit::entity::status_effect_duration_unit::StatusEffectDurationUnit;
+use crate::game_field_unit::entity::status_effect_rule::StatusEffectRule;
+use crate::game_field_unit::entity::status_effect_stacking_rule::StatusEffectStackingRule;
+use crate::game_field_unit::entity::status_effect_tick_action::StatusEffectTickAction;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
 use crate::game_field_unit::entity::unit_health_point::UnitHealthPoint;
 
 #[derive(Debug, Clone)]
@@ -82,6 +87,10 @@ impl GameFieldUnitCard {
         self.unit_attack_point
     }
 
+    pub fn get_field_unit_race(&self) -> RaceEnum {
+        self.field_unit_race
+    }
+
     pub fn get_extra_status_effect_list(&self) -> &Vec<ExtraStatusEffect> {
         &self.extra_status_effect_list
     }
@@ -127,37 +136,101 @@ impl GameFieldUnitCard {
         self.unit_health_point.increase_max_health(increase_point);
     }
 
+    // 공격에 의한 피해는 Shield 가 먼저 흡수
     pub fn apply_damage(&mut self, damage: i32) {
-        let remaining_health = self.unit_health_point.get_current_health_point() - damage;
+        let remaining_damage = self.absorb_damage_with_shield(damage);
+        let remaining_health = self.unit_health_point.get_current_health_point() - remaining_damage;
         let current_health = remaining_health.max(0);
 
         self.unit_health_point.set_current_health_point(current_health);
     }
 
+    fn absorb_damage_with_shield(&mut self, damage: i32) -> i32 {
+        let mut remaining_damage = damage;
+
+        for extra_status_effect in self.extra_status_effect_list.iter_mut() {
+            if remaining_damage <= 0 {
+                break
+            }
+
+            if extra_status_effect.get_extra_effect() != &ExtraEffect::Shield {
+                continue
+            }
+
+            let absorbed_damage = remaining_damage.min(extra_status_effect.get_effect_damage());
+            extra_status_effect.set_effect_damage(extra_status_effect.get_effect_damage() - absorbed_damage);
+            remaining_damage -= absorbed_damage;
+        }
+
+        // 흡수량을 모두 소진한 Shield 는 제거
+        self.extra_status_effect_list.retain(|extra_status_effect|
+            extra_status_effect.get_extra_effect() != &ExtraEffect::Shield ||
+                extra_status_effect.get_effect_damage() > 0);
+
+        remaining_damage
+    }
+
+    fn recover_health(&mut self, recover_point: i32) {
+        let current_health = self.unit_health_point.get_current_health_point();
+        let max_health = self.unit_health_point.get_max_health_point();
+
+        self.unit_health_point.set_current_health_point((current_health + recover_point).min(max_health));
+    }
+
     // TODO: 이 부분도 Domain 이 점점 커지고 있음 (Deadline 고려하면 현재는 수습 불가)
     // 네이밍 이슈로 harmful_status_effect 를 별개로 구성 (해로운 효과와 에너지 부착으로 추가 획득한 효과가 구별되어야함)
     pub fn attach_special_energy(&mut self, race: RaceEnumValue, quantity: i32, status_effect_list: Vec<StatusEffect>) {
         self.attached_energy_map.add_energy(race, quantity);
         for status_effect in status_effect_list.iter().cloned() {
-            let effect_number = status_effect.get_effect().to_i32();
-            self.extra_status_effect_list.push(ExtraStatusEffect::new(
-                ExtraEffect::from(effect_number),
+            self.impose_extra_effect_state(ExtraStatusEffect::new(
+                status_effect.get_effect().clone(),
                 status_effect.get_status_duration_turn(),
                 status_effect.get_effect_damage(),
                 status_effect.get_reuse_turn()));
         }
     }
 
-    // 공격을 통한 상태 이상 부여
+    pub fn is_immune_to_harmful_status_effect(&self) -> bool {
+        self.passive_status_list.contains(&PassiveStatus::StatusEffectImmunity)
+    }
+
+    // 공격을 통한 상태 이상 부여 (면역 및 중첩 규칙은 StatusEffectRule 을 따름)
     pub fn impose_harmful_state(&mut self, harmful_state: ExtraStatusEffect) {
+        let harmful_effect = harmful_state.get_extra_effect().clone();
+        let status_effect_rule = StatusEffectRule::of(&harmful_effect);
+
+        if harmful_effect == ExtraEffect::Dummy || !status_effect_rule.is_harmful() {
+            return
+        }
+
+        if self.is_immune_to_harmful_status_effect() {
+            println!("상태 이상 면역 유닛에게는 {:?} 효과가 적용되지 않습니다.", harmful_effect);
+            return
+        }
+
         let harmful_status_effect = HarmfulStatusEffect::new(
-            harmful_state.get_extra_effect().clone(),
+            harmful_effect.clone(),
             harmful_state.get_status_duration_turn(),
             harmful_state.get_effect_damage(),
             harmful_state.get_reuse_turn(),
         );
 
-        self.harmful_status_effect_list.push(harmful_status_effect);
+        let current_harmful_status_effect = self.harmful_status_effect_list.iter_mut()
+            .find(|v| v.get_harmful_effect() == &harmful_effect);
+
+        match current_harmful_status_effect {
+            None => self.harmful_status_effect_list.push(harmful_status_effect),
+            Some(current_harmful_status_effect) => match status_effect_rule.get_stacking_rule() {
+                StatusEffectStackingRule::Ignore => {}
+                StatusEffectStackingRule::Refresh => *current_harmful_status_effect = harmful_status_effect,
+                StatusEffectStackingRule::Accumulate => {
+                    current_harmful_status_effect.set_effect_damage(
+                        current_harmful_status_effect.get_effect_damage() + harmful_state.get_effect_damage());
+                    current_harmful_status_effect.set_status_duration_round(
+                        current_harmful_status_effect.get_status_duration_round().max(harmful_state.get_status_duration_turn()));
+                }
+            }
+        }
     }
 
     pub fn remove_harmful_effect(&mut self, extra_effect: &ExtraEffect) {
@@ -174,33 +247,93 @@ impl GameFieldUnitCard {
         }
     }
 
-    // 상태 이상에 따른 데미지 및 효과 처리
+    // 턴 종료 시점의 상태 이상 처리 (테스트 및 기존 호출부 호환용)
     pub fn apply_status_effect_damage(&mut self) {
-        let mut index_to_remove = Vec::new();
+        self.tick_status_effect(StatusEffectTickTiming::TurnEnd, 0);
+    }
+
+    // 주인의 턴 시작 / 종료 시점에 해당하는 상태 효과 발동
+    // 환경 카드 등으로 상태 이상 피해가 증가하는 경우 bonus_effect_damage 만큼 추가 피해
+    pub fn tick_status_effect(&mut self, tick_timing: StatusEffectTickTiming, bonus_effect_damage: i32) {
+        for index in 0..self.harmful_status_effect_list.len() {
+            let status_effect_rule =
+                StatusEffectRule::of(self.harmful_status_effect_list[index].get_harmful_effect());
+
+            if status_effect_rule.get_tick_timing() != tick_timing {
+                continue
+            }
+
+            if status_effect_rule.get_tick_action() == StatusEffectTickAction::Damage {
+                self.apply_damage_from_effect(index, bonus_effect_damage);
+            }
 
-        for index in (0..self.harmful_status_effect_list.len()).rev() {
-            self.apply_damage_from_effect(index);
-            self.decrease_status_duration(index);
             self.decrease_reuse_turn(index);
 
-            // 만약 상태 지속 턴이 0이 되었을 경우 해당 상태 효과를 제거
-            if self.harmful_status_effect_list[index].get_status_duration_round() == 0 {
-                index_to_remove.push(index);
+            if status_effect_rule.get_duration_unit() == StatusEffectDurationUnit::Round {
+                self.decrease_status_duration(index);
+            }
+        }
+
+        for index in 0..self.extra_status_effect_list.len() {
+            let status_effect_rule =
+                StatusEffectRule::of(self.extra_status_effect_list[index].get_extra_effect());
+
+            // 공격 시 전이되는 해로운 효과는 보유 유닛에게 발동하지 않음
+            if status_effect_rule.is_harmful() || status_effect_rule.get_tick_timing() != tick_timing {
+                continue
+            }
+
+            if status_effect_rule.get_tick_action() == StatusEffectTickAction::Recover {
+                let recover_point = self.extra_status_effect_list[index].get_effect_damage();
+                self.recover_health(recover_point);
+            }
+
+            if status_effect_rule.get_duration_unit() == StatusEffectDurationUnit::Round {
+                self.decrease_extra_status_duration(index);
+            }
+        }
+
+        self.remove_expired_status_effect();
+    }
+
+    // 턴 단위 효과는 누구의 턴이든 턴 종료 시 지속 시간 감소
+    pub fn decrease_turn_based_status_duration(&mut self) {
+        for index in 0..self.harmful_status_effect_list.len() {
+            let status_effect_rule =
+                StatusEffectRule::of(self.harmful_status_effect_list[index].get_harmful_effect());
+
+            if status_effect_rule.get_duration_unit() == StatusEffectDurationUnit::Turn {
+                self.decrease_status_duration(index);
             }
         }
 
-        // index_to_remove 에 저장된 인덱스들을 제거
-        for index in index_to_remove {
-            self.harmful_status_effect_list.remove(index);
+        for index in 0..self.extra_status_effect_list.len() {
+            let status_effect_rule =
+                StatusEffectRule::of(self.extra_status_effect_list[index].get_extra_effect());
+
+            if !status_effect_rule.is_harmful() &&
+                status_effect_rule.get_duration_unit() == StatusEffectDurationUnit::Turn {
+                self.decrease_extra_status_duration(index);
+            }
         }
+
+        self.remove_expired_status_effect();
+    }
+
+    // 상태 지속 턴이 0이 된 효과 제거 (-1 은 영구 지속)
+    fn remove_expired_status_effect(&mut self) {
+        self.harmful_status_effect_list
+            .retain(|harmful_status_effect| harmful_status_effect.get_status_duration_round() != 0);
+        self.extra_status_effect_list
+            .retain(|extra_status_effect| extra_status_effect.get_status_duration_turn() != 0);
     }
 
     // ExtraStatusEffect 의 효과 데미지 적용
-    fn apply_damage_from_effect(&mut self, index: usize) {
+    fn apply_damage_from_effect(&mut self, index: usize, bonus_effect_damage: i32) {
         let effect_damage = self.harmful_status_effect_list[index].get_effect_damage();
         if effect_damage > 0 {
             let current_health = self.unit_health_point.get_current_health_point();
-            let new_health = current_health.saturating_sub(effect_damage);
+            let new_health = current_health.saturating_sub(effect_damage + bonus_effect_damage);
             self.unit_health_point.set_current_health_point(new_health);
         }
     }
@@ -213,6 +346,13 @@ impl GameFieldUnitCard {
         }
     }
 
+    fn decrease_extra_status_duration(&mut self, index: usize) {
+        let current_duration = self.extra_status_effect_list[index].get_status_duration_turn();
+        if current_duration > 0 {
+            self.extra_status_effect_list[index].set_status_duration_turn(current_duration - 1);
+        }
+    }
+
     // ExtraStatusEffect 의 재사용 턴 감소 (빙결의 경우 같은 유닛을 계속 얼릴 수 없음)
     fn decrease_reuse_turn(&mut self, index: usize) {
         let current_reuse_turn = self.harmful_status_effect_list[index].get_reapply_round();
@@ -221,8 +361,32 @@ impl GameFieldUnitCard {
         }
     }
 
+    // 해로운 효과는 공격 시 전이되도록 그대로 보관하고, 이로운 효과는 중첩 규칙을 따름
     pub fn impose_extra_effect_state(&mut self, extra_effect_state: ExtraStatusEffect) {
-        self.extra_status_effect_list.push(extra_effect_state);
+        let extra_effect = extra_effect_state.get_extra_effect().clone();
+        let status_effect_rule = StatusEffectRule::of(&extra_effect);
+
+        if status_effect_rule.is_harmful() {
+            self.extra_status_effect_list.push(extra_effect_state);
+            return
+        }
+
+        let current_extra_status_effect = self.extra_status_effect_list.iter_mut()
+            .find(|v| v.get_extra_effect() == &extra_effect);
+
+        match current_extra_status_effect {
+            None => self.extra_status_effect_list.push(extra_effect_state),
+            Some(current_extra_status_effect) => match status_effect_rule.get_stacking_rule() {
+                StatusEffectStackingRule::Ignore => {}
+                StatusEffectStackingRule::Refresh => *current_extra_status_effect = extra_effect_state,
+                StatusEffectStackingRule::Accumulate => {
+                    current_extra_status_effect.set_effect_damage(
+                        current_extra_status_effect.get_effect_damage() + extra_effect_state.get_effect_damage());
+                    current_extra_status_effect.set_status_duration_turn(
+                        current_extra_status_effect.get_status_duration_turn().max(extra_effect_state.get_status_duration_turn()));
+                }
+            }
+        }
     }
 
     fn impose_extra_effect_state_list(&mut self, extra_effect_state_list: Vec<ExtraStatusEffect>) {
@@ -231,6 +395,45 @@ impl GameFieldUnitCard {
         }
     }
 
+    // 공격 시 대상에게 전이되는 해로운 효과 목록
+    pub fn get_transferable_extra_status_effect_list(&self) -> Vec<ExtraStatusEffect> {
+        self.extra_status_effect_list.iter()
+            .filter(|extra_status_effect| StatusEffectRule::of(extra_status_effect.get_extra_effect()).is_harmful())
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_extra_effect_list(&self) -> Vec<ExtraEffect> {
+        self.extra_status_effect_list.iter()
+            .map(|extra_status_effect| extra_status_effect.get_extra_effect().clone())
+            .collect()
+    }
+
+    // 공격력 버프 / 디버프 보정치
+    pub fn get_status_effect_attack_modifier(&self) -> i32 {
+        let attack_buff_point: i32 = self.extra_status_effect_list.iter()
+            .filter(|extra_status_effect| extra_status_effect.get_extra_effect() == &ExtraEffect::AttackBuff)
+            .map(|extra_status_effect| extra_status_effect.get_effect_damage().max(0))
+            .sum();
+
+        let attack_debuff_point: i32 = self.harmful_status_effect_list.iter()
+            .filter(|harmful_status_effect| harmful_status_effect.get_harmful_effect() == &ExtraEffect::AttackDebuff)
+            .map(|harmful_status_effect| harmful_status_effect.get_effect_damage().max(0))
+            .sum();
+
+        attack_buff_point - attack_debuff_point
+    }
+
+    pub fn has_action_blocking_status(&self) -> bool {
+        self.harmful_status_effect_list.iter()
+            .any(|harmful_status_effect| StatusEffectRule::of(harmful_status_effect.get_harmful_effect()).blocks_action())
+    }
+
+    pub fn has_skill_blocking_status(&self) -> bool {
+        self.harmful_status_effect_list.iter()
+            .any(|harmful_status_effect| StatusEffectRule::of(harmful_status_effect.get_harmful_effect()).blocks_skill())
+    }
+
     pub fn set_deployed_round(&mut self, round: i32) {
         self.deployed_round = round;
     }
@@ -447,6 +650,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_poison_accumulate_and_tick_on_turn_start() {
+        let mut game_field_unit_card = GameFieldUnitCard::new(
+            5, RaceEnum::Chaos, GradeEnum::Hero, 20, 20, 1, false, false, false, true);
+
+        game_field_unit_card.impose_harmful_state(ExtraStatusEffect::new(ExtraEffect::Poison, 2, 2, 0));
+        game_field_unit_card.impose_harmful_state(ExtraStatusEffect::new(ExtraEffect::Poison, 3, 3, 0));
+
+        // 독은 중첩되므로 하나의 효과로 합쳐짐
+        assert_eq!(game_field_unit_card.get_harmful_status_list().len(), 1);
+
+        // 턴 종료 시점에는 독 데미지가 들어가지 않음
+        game_field_unit_card.tick_status_effect(StatusEffectTickTiming::TurnEnd, 0);
+        assert_eq!(game_field_unit_card.get_unit_health_point().get_current_health_point(), 20);
+
+        game_field_unit_card.tick_status_effect(StatusEffectTickTiming::TurnStart, 0);
+        assert_eq!(game_field_unit_card.get_unit_health_point().get_current_health_point(), 15);
+    }
+
+    #[test]
+    fn test_shield_absorb_damage() {
+        let mut game_field_unit_card = GameFieldUnitCard::new(
+            5, RaceEnum::Chaos, GradeEnum::Hero, 20, 20, 1, false, false, false, true);
+
+        game_field_unit_card.impose_extra_effect_state(ExtraStatusEffect::new(ExtraEffect::Shield, 2, 5, 0));
+
+        game_field_unit_card.apply_damage(3);
+        assert_eq!(game_field_unit_card.get_unit_health_point().get_current_health_point(), 20);
+
+        // 남은 보호막 2 를 소진하고 나머지 데미지만 체력에 적용
+        game_field_unit_card.apply_damage(6);
+        assert_eq!(game_field_unit_card.get_unit_health_point().get_current_health_point(), 16);
+        assert!(!game_field_unit_card.get_extra_effect_list().contains(&ExtraEffect::Shield));
+    }
+
+    #[test]
+    fn test_status_effect_immunity() {
+        let mut game_field_unit_card = GameFieldUnitCard::new(
+            5, RaceEnum::Chaos, GradeEnum::Hero, 20, 20, 1, false, false, false, true);
+        game_field_unit_card.set_passive_status_list(vec![PassiveStatus::StatusEffectImmunity]);
+
+        game_field_unit_card.impose_harmful_state(ExtraStatusEffect::new(ExtraEffect::Stun, 1, 0, 0));
+
+        assert!(game_field_unit_card.get_harmful_status_list().is_empty());
+        assert!(!game_field_unit_card.has_action_blocking_status());
+    }
+
+    #[test]
+    fn test_regeneration_does_not_exceed_max_health() {
+        let mut game_field_unit_card = GameFieldUnitCard::new(
+            5, RaceEnum::Chaos, GradeEnum::Hero, 20, 20, 1, false, false, false, true);
+
+        game_field_unit_card.apply_damage(3);
+        game_field_unit_card.impose_extra_effect_state(ExtraStatusEffect::new(ExtraEffect::Regeneration, 1, 5, 0));
+        game_field_unit_card.tick_status_effect(StatusEffectTickTiming::TurnStart, 0);
+
+        assert_eq!(game_field_unit_card.get_unit_health_point().get_current_health_point(), 20);
+        // 지속 시간이 끝난 재생 효과는 제거됨
+        assert!(game_field_unit_card.get_extra_effect_list().is_empty());
+    }
+
     #[test]
     fn test_game_field_unit_card_dead_set() {
         let mut game_field_unit_card = GameFieldUnitCard::new(
@@ -7,6 +7,7 @@ use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 use crate::game_field_unit::entity::game_field_unit_card::GameFieldUnitCard;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
 
 #[derive(Debug, Clone)]
 pub struct GameFieldUnitCardList {
@@ -159,6 +160,17 @@ impl GameFieldUnitCardList {
         harmful_status_list_with_index
     }
 
+    pub fn get_extra_effect_list_of_all_unit(&self) -> Vec<(i32, Vec<ExtraEffect>)> {
+        let mut extra_effect_list_with_index = Vec::new();
+        for (unit_index, unit) in self.game_field_unit_card_list.iter().enumerate() {
+            if unit.is_alive() {
+                extra_effect_list_with_index.push((unit_index as i32, unit.get_extra_effect_list()));
+            }
+        }
+
+        extra_effect_list_with_index
+    }
+
     pub fn get_harmful_status_list_of_indexed_unit(&mut self, unit_card_index: usize) -> Vec<HarmfulStatusEffect> {
         if let Some(unit) = self.game_field_unit_card_list.get_mut(unit_card_index) {
             return unit.get_harmful_status_effect_list_mut().clone()
@@ -183,9 +195,21 @@ impl GameFieldUnitCardList {
     }
 
     pub fn apply_status_effect_damage_iteratively(&mut self) {
+        self.tick_status_effect_iteratively(StatusEffectTickTiming::TurnEnd, 0);
+    }
+
+    pub fn tick_status_effect_iteratively(&mut self, tick_timing: StatusEffectTickTiming, bonus_effect_damage: i32) {
+        for unit in &mut self.game_field_unit_card_list {
+            if unit.is_alive() {
+                unit.tick_status_effect(tick_timing, bonus_effect_damage);
+            }
+        }
+    }
+
+    pub fn decrease_turn_based_status_duration_iteratively(&mut self) {
         for unit in &mut self.game_field_unit_card_list {
             if unit.is_alive() {
-                unit.apply_status_effect_damage();
+                unit.decrease_turn_based_status_duration();
             }
         }
     }
@@ -35,6 +35,10 @@ impl HarmfulStatusEffect {
         self.effect_damage
     }
 
+    pub fn set_effect_damage(&mut self, effect_damage: i32) {
+        self.effect_damage = effect_damage;
+    }
+
     pub fn get_reapply_round(&self) -> i32 {
         self.reapply_round
     }
@@ -6,4 +6,10 @@ pub mod attached_energy_map;
 pub mod unit_health_point;
 pub mod extra_effect;
 pub mod extra_status_effect;
-pub mod harmful_status_effect;
\ No newline at end of file
+pub mod harmful_status_effect;
+pub mod status_effect_category;
+pub mod status_effect_stacking_rule;
+pub mod status_effect_tick_timing;
+pub mod status_effect_duration_unit;
+pub mod status_effect_rule;
+pub mod status_effect_tick_action;
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffectCategory {
+    // 피격 시 대상에게 부여되어 harmful_status_effect_list 에 쌓임
+    Harmful,
+    // 유닛 자신에게 부여되어 extra_status_effect_list 에 쌓임
+    Beneficial,
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffectDurationUnit {
+    // 유닛 주인의 라운드 기준 (효과 발동 시점에 1 감소)
+    Round,
+    // 양 플레이어의 턴 기준 (누구의 턴이든 턴 종료 시 1 감소)
+    Turn,
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
+use crate::game_field_unit::entity::status_effect_category::StatusEffectCategory;
+use crate::game_field_unit::entity::status_effect_category::StatusEffectCategory::{Beneficial, Harmful};
+use crate::game_field_unit::entity::status_effect_duration_unit::StatusEffectDurationUnit;
+use crate::game_field_unit::entity::status_effect_duration_unit::StatusEffectDurationUnit::{Round, Turn};
+use crate::game_field_unit::entity::status_effect_stacking_rule::StatusEffectStackingRule;
+use crate::game_field_unit::entity::status_effect_stacking_rule::StatusEffectStackingRule::{Accumulate, Ignore, Refresh};
+use crate::game_field_unit::entity::status_effect_tick_action::StatusEffectTickAction;
+use crate::game_field_unit::entity::status_effect_tick_action::StatusEffectTickAction::{Damage, Nothing, Recover};
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming::{TurnEnd, TurnStart};
+
+// 상태 효과 규칙 등록부: 새로운 효과는 ExtraEffect 에 추가하고 여기에 규칙을 등록
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusEffectRule {
+    category: StatusEffectCategory,
+    stacking_rule: StatusEffectStackingRule,
+    tick_timing: StatusEffectTickTiming,
+    tick_action: StatusEffectTickAction,
+    duration_unit: StatusEffectDurationUnit,
+    blocks_action: bool,
+    blocks_skill: bool,
+}
+
+impl StatusEffectRule {
+    fn new(category: StatusEffectCategory,
+           stacking_rule: StatusEffectStackingRule,
+           tick_timing: StatusEffectTickTiming,
+           tick_action: StatusEffectTickAction,
+           duration_unit: StatusEffectDurationUnit,
+           blocks_action: bool,
+           blocks_skill: bool) -> Self {
+
+        StatusEffectRule {
+            category,
+            stacking_rule,
+            tick_timing,
+            tick_action,
+            duration_unit,
+            blocks_action,
+            blocks_skill,
+        }
+    }
+
+    // effect_damage 는 DarkFire, Poison 의 피해량 / Shield 의 흡수량 / 버프, 디버프의 공격력 증감치 / Regeneration 의 회복량
+    pub fn of(extra_effect: &ExtraEffect) -> StatusEffectRule {
+        match extra_effect {
+            ExtraEffect::Dummy => StatusEffectRule::new(Harmful, Ignore, TurnEnd, Nothing, Round, false, false),
+            ExtraEffect::Freeze => StatusEffectRule::new(Harmful, Ignore, TurnEnd, Nothing, Round, true, true),
+            ExtraEffect::DarkFire => StatusEffectRule::new(Harmful, Refresh, TurnEnd, Damage, Round, false, false),
+            ExtraEffect::Poison => StatusEffectRule::new(Harmful, Accumulate, TurnStart, Damage, Round, false, false),
+            ExtraEffect::Stun => StatusEffectRule::new(Harmful, Ignore, TurnEnd, Nothing, Round, true, true),
+            ExtraEffect::Silence => StatusEffectRule::new(Harmful, Refresh, TurnEnd, Nothing, Round, false, true),
+            ExtraEffect::Shield => StatusEffectRule::new(Beneficial, Accumulate, TurnEnd, Nothing, Turn, false, false),
+            ExtraEffect::AttackBuff => StatusEffectRule::new(Beneficial, Refresh, TurnEnd, Nothing, Turn, false, false),
+            ExtraEffect::AttackDebuff => StatusEffectRule::new(Harmful, Refresh, TurnEnd, Nothing, Turn, false, false),
+            ExtraEffect::Regeneration => StatusEffectRule::new(Beneficial, Refresh, TurnStart, Recover, Round, false, false),
+        }
+    }
+
+    pub fn get_category(&self) -> StatusEffectCategory {
+        self.category
+    }
+
+    pub fn get_stacking_rule(&self) -> StatusEffectStackingRule {
+        self.stacking_rule
+    }
+
+    pub fn get_tick_timing(&self) -> StatusEffectTickTiming {
+        self.tick_timing
+    }
+
+    pub fn get_tick_action(&self) -> StatusEffectTickAction {
+        self.tick_action
+    }
+
+    pub fn get_duration_unit(&self) -> StatusEffectDurationUnit {
+        self.duration_unit
+    }
+
+    pub fn is_harmful(&self) -> bool {
+        self.category == Harmful
+    }
+
+    pub fn blocks_action(&self) -> bool {
+        self.blocks_action
+    }
+
+    pub fn blocks_skill(&self) -> bool {
+        self.blocks_skill
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_effect_rule_of_control_effects() {
+        let freeze_rule = StatusEffectRule::of(&ExtraEffect::Freeze);
+        assert!(freeze_rule.blocks_action());
+        assert!(freeze_rule.blocks_skill());
+
+        let silence_rule = StatusEffectRule::of(&ExtraEffect::Silence);
+        assert!(!silence_rule.blocks_action());
+        assert!(silence_rule.blocks_skill());
+    }
+
+    #[test]
+    fn test_status_effect_rule_of_beneficial_effects() {
+        for extra_effect in [ExtraEffect::Shield, ExtraEffect::AttackBuff, ExtraEffect::Regeneration] {
+            assert!(!StatusEffectRule::of(&extra_effect).is_harmful());
+        }
+
+        assert_eq!(StatusEffectRule::of(&ExtraEffect::Poison).get_stacking_rule(), Accumulate);
+        assert_eq!(StatusEffectRule::of(&ExtraEffect::Poison).get_tick_timing(), TurnStart);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffectStackingRule {
+    // 이미 걸려 있다면 새로 부여된 효과를 무시
+    Ignore,
+    // 이미 걸려 있다면 새로 부여된 효과의 지속 시간과 수치로 교체
+    Refresh,
+    // 이미 걸려 있다면 수치를 누적하고 지속 시간은 더 긴 쪽을 유지
+    Accumulate,
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffectTickAction {
+    // 발동 시점에 지속 시간만 감소
+    Nothing,
+    // 발동 시점에 effect_damage 만큼 피해
+    Damage,
+    // 발동 시점에 effect_damage 만큼 체력 회복
+    Recover,
+}
\ No newline at end of file
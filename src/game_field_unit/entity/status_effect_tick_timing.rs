@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffectTickTiming {
+    // 효과를 가진 유닛 주인의 턴 시작 시 발동
+    TurnStart,
+    // 효과를 가진 유닛 주인의 턴 종료 시 발동
+    TurnEnd,
+}
\ No newline at end of file
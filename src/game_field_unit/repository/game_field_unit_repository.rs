@@ -9,6 +9,7 @@ use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 use crate::game_field_unit::entity::game_field_unit_card::GameFieldUnitCard;
 use crate::game_field_unit::entity::unit_health_point::UnitHealthPoint;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
 
 pub trait GameFieldUnitRepository {
     fn create_game_field_unit_object(
@@ -91,7 +92,11 @@ pub trait GameFieldUnitRepository {
         race_enum: RaceEnum,
         quantity: i32,
         status_effect_list: Vec<StatusEffect>) -> bool;
-    fn apply_harmful_status_effect_damage_iteratively(
+    fn tick_status_effect_of_every_unit(
+        &mut self,
+        account_unique_id: i32,
+        tick_timing: StatusEffectTickTiming) -> bool;
+    fn decrease_turn_based_status_duration_of_every_unit(
         &mut self,
         account_unique_id: i32) -> bool;
     fn impose_harmful_state_to_indexed_unit(
@@ -190,6 +195,9 @@ pub trait GameFieldUnitRepository {
     fn acquire_unit_harmful_status_effect_list_of_all_living_unit(
         &mut self,
         account_unique_id: i32) -> Vec<(i32, Vec<HarmfulStatusEffect>)>;
+    fn acquire_unit_extra_effect_list_of_all_living_unit(
+        &mut self,
+        account_unique_id: i32) -> Vec<(i32, Vec<ExtraEffect>)>;
 
     fn reset_all_passive_of_unit(
         &mut self,
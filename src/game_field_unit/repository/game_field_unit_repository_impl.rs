@@ -1,34 +1,46 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
 use crate::common::card_attributes::card_grade::card_grade_enum::GradeEnum;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
 use crate::game_card_energy::entity::status_effect::StatusEffect;
+use crate::game_card_environment::repository::environment_modifier_hook::EnvironmentModifierHook;
+use crate::game_card_environment::repository::game_card_environment_repository_impl::GameCardEnvironmentRepositoryImpl;
 use crate::game_card_passive_skill::entity::summary_passive_skill_effect::SummaryPassiveSkillEffect;
 use crate::game_card_unit::entity::passive_status::PassiveStatus;
 use crate::game_field_unit::entity::attached_energy_map::AttachedEnergyMap;
 use crate::game_field_unit::entity::extra_effect::ExtraEffect;
-use crate::game_field_unit::entity::extra_effect::ExtraEffect::{DarkFire, Freeze};
 use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 
 use crate::game_field_unit::entity::game_field_unit::GameFieldUnit;
 use crate::game_field_unit::entity::game_field_unit_card::GameFieldUnitCard;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
 use crate::game_field_unit::entity::unit_health_point::UnitHealthPoint;
 use crate::game_field_unit::repository::game_field_unit_repository::GameFieldUnitRepository;
 
 pub struct GameFieldUnitRepositoryImpl {
     game_field_unit_map: IndexMap<i32, GameFieldUnit>,
+    // 현재 필드에 배치된 환경 카드의 보정치 조회용 (공격력, 상태 이상 피해 계산 시 참조)
+    environment_modifier_hook: EnvironmentModifierHook,
 }
 
 impl GameFieldUnitRepositoryImpl {
     pub fn new() -> Self {
+        GameFieldUnitRepositoryImpl::with_environment_modifier_hook(
+            GameCardEnvironmentRepositoryImpl::get_environment_modifier_hook())
+    }
+
+    pub fn with_environment_modifier_hook(environment_modifier_hook: EnvironmentModifierHook) -> Self {
         GameFieldUnitRepositoryImpl {
             game_field_unit_map: IndexMap::new(),
+            environment_modifier_hook,
         }
     }
 
@@ -37,6 +49,11 @@ impl GameFieldUnitRepositoryImpl {
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<GameFieldUnitRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_field_unit_repository()
+        }
+
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>> =
                 Arc::new(
@@ -300,9 +317,25 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
     }
 
     // TODO: Game Field Unit 이 너무 거대해지고 있음 (그러나 당장 고려 할 수 없는 상황임)
-    fn apply_harmful_status_effect_damage_iteratively(&mut self, account_unique_id: i32) -> bool {
+    fn tick_status_effect_of_every_unit(&mut self, account_unique_id: i32, tick_timing: StatusEffectTickTiming) -> bool {
+        println!("GameFieldUnitRepositoryImpl: tick_status_effect_of_every_unit()");
+
+        let status_effect_damage_bonus = self.environment_modifier_hook.find_environment_modifier(account_unique_id)
+            .map_or(0, |environment_modifier| environment_modifier.get_status_effect_damage_bonus());
+
+        if let Some(game_field_unit) = self.game_field_unit_map.get_mut(&account_unique_id) {
+            game_field_unit.tick_status_effect_iteratively(tick_timing, status_effect_damage_bonus);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn decrease_turn_based_status_duration_of_every_unit(&mut self, account_unique_id: i32) -> bool {
+        println!("GameFieldUnitRepositoryImpl: decrease_turn_based_status_duration_of_every_unit()");
+
         if let Some(game_field_unit) = self.game_field_unit_map.get_mut(&account_unique_id) {
-            game_field_unit.apply_status_effect_damage_iteratively();
+            game_field_unit.decrease_turn_based_status_duration_iteratively();
             true
         } else {
             false
@@ -331,7 +364,13 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
     ) -> i32 {
         let indexed_unit_reference = self.find_indexed_unit(account_unique_id, attacker_unit_index).unwrap();
         if indexed_unit_reference.is_alive() {
-            return indexed_unit_reference.get_unit_attack_point();
+            let unit_attack_bonus = self.environment_modifier_hook.find_environment_modifier(account_unique_id)
+                .map_or(0, |environment_modifier|
+                    environment_modifier.get_unit_attack_bonus_of_race(indexed_unit_reference.get_field_unit_race()));
+
+            let status_effect_attack_modifier = indexed_unit_reference.get_status_effect_attack_modifier();
+
+            return (indexed_unit_reference.get_unit_attack_point() + unit_attack_bonus + status_effect_attack_modifier).max(0);
         }
 
         -1
@@ -377,6 +416,7 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
         false
     }
 
+    // 상태 효과의 면역 및 중첩 (빙결 중복 불가, 화상 지속시간 리셋 등) 은 GameFieldUnitCard 에서 StatusEffectRule 에 따라 처리
     fn attack_target_unit_with_extra_status_effect(
         &mut self,
         opponent_unique_id: i32,
@@ -387,44 +427,16 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
 
         println!("GameFieldUnitRepositoryImpl: attack_target_unit_with_extra_status_effect()");
 
-        let mut will_be_applied_effect_list = extra_status_effect_list.clone();
-
         if let Some(opponent_game_field_unit) = self.game_field_unit_map.get_mut(&opponent_unique_id) {
             let opponent_unit_index_usize = opponent_unit_index as usize;
 
             if opponent_game_field_unit.check_unit_alive(opponent_unit_index_usize) {
-                let opponent_field_unit_list =
-                    opponent_game_field_unit.get_all_field_unit_list_mut();
-                let current_opponent_target_unit_harmful_status_effect_list =
-                    opponent_field_unit_list[opponent_unit_index_usize].get_harmful_status_effect_list_mut();
-
-                let target_has_freeze_as_harmful =
-                    current_opponent_target_unit_harmful_status_effect_list.iter()
-                    .any(|effect| effect.get_harmful_effect() == &Freeze);
-
-                if target_has_freeze_as_harmful {
-                    will_be_applied_effect_list
-                        .retain(|effect| effect.get_extra_effect() != &Freeze);
-                }
-
-                let target_has_dark_fire_as_harmful = current_opponent_target_unit_harmful_status_effect_list
-                    .iter()
-                    .any(|effect| effect.get_harmful_effect() == &DarkFire);
-                let attacker_has_dark_fire_as_extra = will_be_applied_effect_list
-                    .iter()
-                    .any(|effect| effect.get_extra_effect() == &DarkFire);
-
-                if target_has_dark_fire_as_harmful && attacker_has_dark_fire_as_extra {
-                    opponent_game_field_unit
-                        .remove_harmful_status_of_indexed_unit(opponent_unit_index_usize, &DarkFire);
-                }
-
                 opponent_game_field_unit
                     .apply_damage_to_indexed_unit(
                         opponent_unit_index_usize, damage);
                 opponent_game_field_unit
                     .impose_harmful_states_to_indexed_unit(
-                        opponent_unit_index_usize, will_be_applied_effect_list);
+                        opponent_unit_index_usize, extra_status_effect_list);
 
                 return true
             }
@@ -443,41 +455,13 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
 
         if let Some(game_field_unit) = self.get_game_field_unit_map().get_mut(&opponent_unique_id) {
             for unit_index in (0..game_field_unit.get_all_unit_list_in_game_field().len()).rev() {
-                let mut will_be_applied_effect_list = extra_status_effect_list.clone();
-
                 if game_field_unit.check_unit_alive(unit_index) {
-                    let opponent_field_unit_list =
-                        game_field_unit.get_all_field_unit_list_mut();
-                    let current_opponent_target_unit_harmful_status_effect_list =
-                        opponent_field_unit_list[unit_index].get_harmful_status_effect_list_mut();
-
-                    let target_has_freeze_as_harmful =
-                        current_opponent_target_unit_harmful_status_effect_list.iter()
-                            .any(|effect| effect.get_harmful_effect() == &Freeze);
-
-                    if target_has_freeze_as_harmful {
-                        will_be_applied_effect_list
-                            .retain(|effect| effect.get_extra_effect() != &Freeze);
-                    }
-
-                    let target_has_dark_fire_as_harmful = current_opponent_target_unit_harmful_status_effect_list
-                        .iter()
-                        .any(|effect| effect.get_harmful_effect() == &DarkFire);
-                    let attacker_has_dark_fire_as_extra = will_be_applied_effect_list
-                        .iter()
-                        .any(|effect| effect.get_extra_effect() == &DarkFire);
-
-                    if target_has_dark_fire_as_harmful && attacker_has_dark_fire_as_extra {
-                        game_field_unit
-                            .remove_harmful_status_of_indexed_unit(unit_index, &DarkFire);
-                    }
-
                     game_field_unit
                         .apply_damage_to_indexed_unit(
                             unit_index, damage);
                     game_field_unit
                         .impose_harmful_states_to_indexed_unit(
-                            unit_index, will_be_applied_effect_list);
+                            unit_index, extra_status_effect_list.clone());
                 }
             }
 
@@ -672,6 +656,17 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
         Vec::new()
     }
 
+    fn acquire_unit_extra_effect_list_of_all_living_unit(
+        &mut self,
+        account_unique_id: i32) -> Vec<(i32, Vec<ExtraEffect>)> {
+
+        if let Some(game_field_unit) = self.game_field_unit_map.get(&account_unique_id) {
+            return game_field_unit.get_extra_effect_list_of_all_indexed_unit()
+        }
+
+        Vec::new()
+    }
+
     fn reset_all_passive_of_unit(
         &mut self,
         account_unique_id: i32,
@@ -729,7 +724,10 @@ impl GameFieldUnitRepository for GameFieldUnitRepositoryImpl {
 
 #[cfg(test)]
 mod tests {
-    use crate::game_field_unit::entity::extra_effect::ExtraEffect::Freeze;
+    use crate::game_field_unit::entity::extra_effect::ExtraEffect::{DarkFire, Freeze};
+    use crate::game_card_environment::entity::environment_modifier::EnvironmentModifier;
+    use crate::game_card_environment::entity::game_card_environment_effect::GameCardEnvironmentEffect;
+    use crate::game_card_environment::repository::game_card_environment_repository::GameCardEnvironmentRepository;
     use super::*;
 
     #[tokio::test]
@@ -1302,4 +1300,38 @@ mod tests {
         println!("{:?}", game_field_unit_repository.get_game_field_unit_map().get(&1))
     }
 
+    #[test]
+    fn test_environment_modifier_applies_to_attack_and_status_effect_damage() {
+        let mut environment_modifier = EnvironmentModifier::new();
+        environment_modifier.set_target_race(RaceEnum::Undead);
+        environment_modifier.set_unit_attack_bonus(10);
+        environment_modifier.set_status_effect_damage_bonus(3);
+
+        let mut game_card_environment_repository = GameCardEnvironmentRepositoryImpl::with_environment_modifier_hook(
+            EnvironmentModifierHook::new(HashMap::from([(45, GameCardEnvironmentEffect::new(1, environment_modifier))])));
+        let mut game_field_unit_repository = GameFieldUnitRepositoryImpl::with_environment_modifier_hook(
+            game_card_environment_repository.to_environment_modifier_hook());
+        game_field_unit_repository.create_game_field_unit_object(1);
+
+        game_field_unit_repository.add_unit_to_game_field(
+            1, 42, RaceEnum::Undead, GradeEnum::Legend, 35, 30, 2, false, false, false);
+        game_field_unit_repository.add_unit_to_game_field(
+            1, 43, RaceEnum::Human, GradeEnum::Legend, 20, 30, 2, false, false, false);
+        game_field_unit_repository.impose_harmful_state_to_indexed_unit(
+            1, 1, ExtraStatusEffect::new(DarkFire, 2, 5, 2));
+
+        assert_eq!(game_field_unit_repository.acquire_unit_attack_point(1, 0), 35);
+
+        game_card_environment_repository.deploy_environment_card(1, 2, 45);
+
+        assert_eq!(game_field_unit_repository.acquire_unit_attack_point(1, 0), 45);
+        assert_eq!(game_field_unit_repository.acquire_unit_attack_point(1, 1), 20);
+
+        game_field_unit_repository.tick_status_effect_of_every_unit(1, StatusEffectTickTiming::TurnEnd);
+        assert_eq!(game_field_unit_repository.acquire_health_point_of_indexed_unit(1, 1).get_current_health_point(), 22);
+
+        // 환경 저장소에서 만료되면 유닛 저장소도 곧바로 보정치가 사라진 값을 조회
+        game_card_environment_repository.decrease_environment_remaining_turn(1, 2);
+        assert_eq!(game_field_unit_repository.acquire_unit_attack_point(1, 0), 35);
+    }
 }
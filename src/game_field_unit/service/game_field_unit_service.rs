@@ -10,7 +10,9 @@ use crate::game_field_unit::service::request::apply_catastrophic_damage_to_field
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_instant_death_to_target_unit_index_request::ApplyInstantDeathToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_passive_skill_list_request::ApplyPassiveSkillListRequest;
-use crate::game_field_unit::service::request::apply_status_effect_damage_iteratively_request::ApplyStatusEffectDamageIterativelyRequest;
+use crate::game_field_unit::service::request::tick_status_effect_of_all_unit_request::TickStatusEffectOfAllUnitRequest;
+use crate::game_field_unit::service::request::decrease_turn_based_status_duration_of_all_unit_request::DecreaseTurnBasedStatusDurationOfAllUnitRequest;
+use crate::game_field_unit::service::request::acquire_extra_effect_of_all_unit_request::AcquireExtraEffectOfAllUnitRequest;
 use crate::game_field_unit::service::request::attach_single_energy_to_unit_index_request::AttachSingleEnergyToUnitIndexRequest;
 use crate::game_field_unit::service::request::attach_multiple_energy_to_unit_index_request::AttachMultipleEnergyToUnitIndexRequest;
 use crate::game_field_unit::service::request::attach_special_energy_to_unit_index_request::AttachSpecialEnergyToUnitIndexRequest;
@@ -41,7 +43,9 @@ use crate::game_field_unit::service::response::apply_catastrophic_damage_to_fiel
 use crate::game_field_unit::service::response::apply_damage_to_target_unit_index_response::ApplyDamageToTargetUnitIndexResponse;
 use crate::game_field_unit::service::response::apply_instant_death_to_target_unit_index_response::ApplyInstantDeathToTargetUnitIndexResponse;
 use crate::game_field_unit::service::response::apply_passive_skill_list_response::ApplyPassiveSkillListResponse;
-use crate::game_field_unit::service::response::apply_status_effect_damage_iteratively_response::ApplyStatusEffectDamageIterativelyResponse;
+use crate::game_field_unit::service::response::tick_status_effect_of_all_unit_response::TickStatusEffectOfAllUnitResponse;
+use crate::game_field_unit::service::response::decrease_turn_based_status_duration_of_all_unit_response::DecreaseTurnBasedStatusDurationOfAllUnitResponse;
+use crate::game_field_unit::service::response::acquire_extra_effect_of_all_unit_response::AcquireExtraEffectOfAllUnitResponse;
 use crate::game_field_unit::service::response::attach_single_energy_to_unit_index_response::AttachSingleEnergyToUnitIndexResponse;
 use crate::game_field_unit::service::response::attach_multiple_energy_to_unit_index_response::AttachMultipleEnergyToUnitIndexResponse;
 use crate::game_field_unit::service::response::attach_special_energy_to_unit_index_response::AttachSpecialEnergyToUnitIndexResponse;
@@ -78,7 +82,8 @@ pub trait GameFieldUnitService {
     async fn get_current_health_point_of_all_field_unit(&self, get_current_health_point_of_all_field_unit_request: GetCurrentHealthPointOfAllFieldUnitRequest) -> GetCurrentHealthPointOfAllFieldUnitResponse;
     async fn attach_special_energy_to_field_unit_index(&mut self, attach_special_energy_to_unit_index_request: AttachSpecialEnergyToUnitIndexRequest) -> AttachSpecialEnergyToUnitIndexResponse;
     async fn find_active_skill_usage_unit_id_by_index(&mut self, find_active_skill_usage_unit_id_by_index_request: FindActiveSkillUsageUnitIdByIndexRequest) -> FindActiveSkillUsageUnitIdByIndexResponse;
-    async fn apply_status_effect_damage_iteratively(&mut self, apply_status_effect_damage_iteratively_request: ApplyStatusEffectDamageIterativelyRequest) -> ApplyStatusEffectDamageIterativelyResponse;
+    async fn tick_status_effect_of_all_unit(&mut self, tick_status_effect_of_all_unit_request: TickStatusEffectOfAllUnitRequest) -> TickStatusEffectOfAllUnitResponse;
+    async fn decrease_turn_based_status_duration_of_all_unit(&mut self, decrease_turn_based_status_duration_of_all_unit_request: DecreaseTurnBasedStatusDurationOfAllUnitRequest) -> DecreaseTurnBasedStatusDurationOfAllUnitResponse;
     async fn acquire_unit_attack_point(&mut self, acquire_unit_attack_point_request: AcquireUnitAttackPointRequest) -> AcquireUnitAttackPointResponse;
     async fn acquire_unit_extra_effect(&mut self, acquire_unit_extra_effect_request: AcquireUnitExtraEffectRequest) -> AcquireUnitExtraEffectResponse;
     async fn attack_target_unit_with_extra_effect(&mut self, attack_target_unit_with_extra_effect_request: AttackTargetUnitWithExtraEffectRequest) -> AttackTargetUnitWithExtraEffectResponse;
@@ -91,6 +96,7 @@ pub trait GameFieldUnitService {
     async fn acquire_unit_passive_status_list(&mut self, acquire_unit_passive_status_list_request: AcquireUnitPassiveStatusListRequest) -> AcquireUnitPassiveStatusListResponse;
     async fn acquire_unit_harmful_status_effect(&mut self, acquire_unit_harmful_status_effect_request: AcquireUnitHarmfulStatusEffectRequest) -> AcquireUnitHarmfulStatusEffectResponse;
     async fn acquire_harmful_status_effect_of_all_unit(&mut self, acquire_harmful_status_effect_of_all_unit_request: AcquireHarmfulStatusEffectOfAllUnitRequest) -> AcquireHarmfulStatusEffectOfAllUnitResponse;
+    async fn acquire_extra_effect_of_all_unit(&mut self, acquire_extra_effect_of_all_unit_request: AcquireExtraEffectOfAllUnitRequest) -> AcquireExtraEffectOfAllUnitResponse;
     async fn reset_all_passive_of_unit(&mut self, reset_all_passive_of_unit_request: ResetAllPassiveOfUnitRequest) -> ResetAllPassiveOfUnitResponse;
     async fn execute_index_passive_of_unit(&mut self, execute_index_passive_of_unit_request: ExecuteIndexPassiveOfUnitRequest) -> ExecuteIndexPassiveOfUnitResponse;
     async fn get_passive_skill_usable(&self, get_passive_skill_usable_request: GetPassiveSkillUsableRequest) -> GetPassiveSkillUsableResponse;
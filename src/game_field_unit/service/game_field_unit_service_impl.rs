@@ -4,6 +4,10 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 use crate::game_card_passive_skill::entity::passive_skill_type::PassiveSkillType;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
 
 use crate::game_field_unit::repository::game_field_unit_repository::GameFieldUnitRepository;
@@ -20,7 +24,9 @@ use crate::game_field_unit::service::request::apply_catastrophic_damage_to_field
 use crate::game_field_unit::service::request::apply_damage_to_target_unit_index_request::ApplyDamageToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_instant_death_to_target_unit_index_request::ApplyInstantDeathToTargetUnitIndexRequest;
 use crate::game_field_unit::service::request::apply_passive_skill_list_request::ApplyPassiveSkillListRequest;
-use crate::game_field_unit::service::request::apply_status_effect_damage_iteratively_request::ApplyStatusEffectDamageIterativelyRequest;
+use crate::game_field_unit::service::request::tick_status_effect_of_all_unit_request::TickStatusEffectOfAllUnitRequest;
+use crate::game_field_unit::service::request::decrease_turn_based_status_duration_of_all_unit_request::DecreaseTurnBasedStatusDurationOfAllUnitRequest;
+use crate::game_field_unit::service::request::acquire_extra_effect_of_all_unit_request::AcquireExtraEffectOfAllUnitRequest;
 use crate::game_field_unit::service::request::attach_single_energy_to_unit_index_request::AttachSingleEnergyToUnitIndexRequest;
 use crate::game_field_unit::service::request::attach_multiple_energy_to_unit_index_request::AttachMultipleEnergyToUnitIndexRequest;
 use crate::game_field_unit::service::request::attach_special_energy_to_unit_index_request::AttachSpecialEnergyToUnitIndexRequest;
@@ -51,7 +57,9 @@ use crate::game_field_unit::service::response::apply_catastrophic_damage_to_fiel
 use crate::game_field_unit::service::response::apply_damage_to_target_unit_index_response::ApplyDamageToTargetUnitIndexResponse;
 use crate::game_field_unit::service::response::apply_instant_death_to_target_unit_index_response::ApplyInstantDeathToTargetUnitIndexResponse;
 use crate::game_field_unit::service::response::apply_passive_skill_list_response::ApplyPassiveSkillListResponse;
-use crate::game_field_unit::service::response::apply_status_effect_damage_iteratively_response::ApplyStatusEffectDamageIterativelyResponse;
+use crate::game_field_unit::service::response::tick_status_effect_of_all_unit_response::TickStatusEffectOfAllUnitResponse;
+use crate::game_field_unit::service::response::decrease_turn_based_status_duration_of_all_unit_response::DecreaseTurnBasedStatusDurationOfAllUnitResponse;
+use crate::game_field_unit::service::response::acquire_extra_effect_of_all_unit_response::AcquireExtraEffectOfAllUnitResponse;
 use crate::game_field_unit::service::response::attach_single_energy_to_unit_index_response::AttachSingleEnergyToUnitIndexResponse;
 use crate::game_field_unit::service::response::attach_multiple_energy_to_unit_index_response::AttachMultipleEnergyToUnitIndexResponse;
 use crate::game_field_unit::service::response::attach_special_energy_to_unit_index_response::AttachSpecialEnergyToUnitIndexResponse;
@@ -76,17 +84,14 @@ use crate::game_round::repository::game_round_repository_impl::GameRoundReposito
 
 
 pub struct GameFieldUnitServiceImpl {
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
 }
 
 impl GameFieldUnitServiceImpl {
-    pub fn new(game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
-               game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,) -> Self {
+    pub fn new(game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>) -> Self {
 
         GameFieldUnitServiceImpl {
-            game_field_unit_repository,
-            game_round_repository
+            game_round_repository,
         }
     }
 
@@ -96,7 +101,6 @@ impl GameFieldUnitServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         GameFieldUnitServiceImpl::new(
-                            GameFieldUnitRepositoryImpl::get_instance(),
                             GameRoundRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
@@ -109,6 +113,13 @@ impl GameFieldUnitServiceImpl {
             .get(&account_unique_id)
             .map(|user_round| user_round.get_round())
     }
+
+    // 이벤트는 쌓아두기만 하고 실제 반응 처리는 컨트롤러에서 GameEventBusService 를 통해 진행
+    async fn publish_game_event(&self, game_event: GameEvent) {
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+        game_event_bus_repository_guard.publish_game_event(game_event);
+    }
 }
 
 #[async_trait]
@@ -117,7 +128,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn add_unit_to_game_field(&mut self, add_unit_to_game_field_request: AddUnitToGameFieldRequest) -> AddUnitToGameFieldResponse {
         println!("GameFieldUnitServiceImpl: add_unit_to_game_field()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         let account_unique_id = add_unit_to_game_field_request.get_account_unique_id();
 
@@ -150,13 +162,26 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
             return AddUnitToGameFieldResponse::new(-1)
         }
 
+        drop(game_field_unit_repository_guard);
+
+        let unit_card_id = add_unit_to_game_field_request.get_unit_card_id();
+
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+        game_event_bus_repository_guard.subscribe_field_unit(account_unique_id, maybe_added_unit_index, unit_card_id);
+        drop(game_event_bus_repository_guard);
+
+        self.publish_game_event(
+            GameEvent::new(GameEventType::UnitDeployed, account_unique_id, maybe_added_unit_index, unit_card_id, 0)).await;
+
         AddUnitToGameFieldResponse::new(maybe_added_unit_index)
     }
 
     async fn attach_energy_to_field_unit_index(&mut self, attach_energy_to_unit_index_request: AttachSingleEnergyToUnitIndexRequest) -> AttachSingleEnergyToUnitIndexResponse {
         println!("GameFieldUnitServiceImpl: attach_energy_to_field_unit_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.attach_multiple_energy_to_indexed_unit(
             attach_energy_to_unit_index_request.get_account_unique_id(),
             attach_energy_to_unit_index_request.get_unit_card_index(),
@@ -169,7 +194,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn attach_multiple_energy_to_field_unit_index(&mut self, attach_multiple_energy_to_unit_index_request: AttachMultipleEnergyToUnitIndexRequest) -> AttachMultipleEnergyToUnitIndexResponse {
         println!("GameFieldUnitServiceImpl: attach_multiple_energy_to_field_unit_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.attach_multiple_energy_to_indexed_unit(
             attach_multiple_energy_to_unit_index_request.get_account_unique_id(),
             attach_multiple_energy_to_unit_index_request.get_unit_card_index(),
@@ -182,7 +208,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn find_target_unit_id_by_index(&mut self, find_target_unit_id_by_index_request: FindTargetUnitIdByIndexRequest) -> FindTargetUnitIdByIndexResponse {
         println!("GameFieldUnitServiceImpl: find_target_unit_id_by_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let found_target_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(
             find_target_unit_id_by_index_request.get_opponent_unique_id(),
             find_target_unit_id_by_index_request.get_opponent_target_unit_index());
@@ -193,11 +220,23 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn apply_damage_to_target_unit_index(&mut self, apply_damage_to_target_unit_index_response: ApplyDamageToTargetUnitIndexRequest) -> ApplyDamageToTargetUnitIndexResponse {
         println!("GameFieldUnitServiceImpl: apply_damage_to_target_unit_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let opponent_unique_id = apply_damage_to_target_unit_index_response.get_opponent_unique_id();
+        let opponent_target_unit_index = apply_damage_to_target_unit_index_response.get_opponent_target_unit_index();
+        let damage = apply_damage_to_target_unit_index_response.get_damage();
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let target_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(opponent_unique_id, opponent_target_unit_index);
         let response = game_field_unit_repository_guard.apply_damage_to_target_unit_index(
-            apply_damage_to_target_unit_index_response.get_opponent_unique_id(),
-            apply_damage_to_target_unit_index_response.get_opponent_target_unit_index(),
-            apply_damage_to_target_unit_index_response.get_damage());
+            opponent_unique_id,
+            opponent_target_unit_index,
+            damage);
+        drop(game_field_unit_repository_guard);
+
+        if response {
+            self.publish_game_event(
+                GameEvent::new(GameEventType::UnitDamaged, opponent_unique_id, opponent_target_unit_index, target_unit_id, damage)).await;
+        }
 
         ApplyDamageToTargetUnitIndexResponse::new(response)
     }
@@ -205,7 +244,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn apply_instant_death_to_target_unit_index(&mut self, apply_instant_death_to_target_unit_index_request: ApplyInstantDeathToTargetUnitIndexRequest) -> ApplyInstantDeathToTargetUnitIndexResponse {
         println!("GameFieldUnitServiceImpl: apply_instant_death_to_target_unit_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.apply_instant_death_to_target_unit_index(
             apply_instant_death_to_target_unit_index_request.get_opponent_unique_id(),
             apply_instant_death_to_target_unit_index_request.get_opponent_target_unit_index());
@@ -216,10 +256,19 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn judge_death_of_unit(&mut self, judge_death_of_unit_request: JudgeDeathOfUnitRequest) -> JudgeDeathOfUnitResponse {
         println!("GameFieldUnitServiceImpl: judge_death_of_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let account_unique_id = judge_death_of_unit_request.get_account_unique_id();
         let response = game_field_unit_repository_guard.judge_death_of_unit(
-            judge_death_of_unit_request.get_account_unique_id(),
+            account_unique_id,
             judge_death_of_unit_request.get_unit_card_index());
+        drop(game_field_unit_repository_guard);
+
+        let (dead_unit_index, dead_unit_id) = response;
+        if dead_unit_index != -1 {
+            self.publish_game_event(
+                GameEvent::new(GameEventType::UnitDeath, account_unique_id, dead_unit_index, dead_unit_id, 0)).await;
+        }
 
         JudgeDeathOfUnitResponse::new(response)
     }
@@ -227,9 +276,16 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn judge_death_of_every_field_unit(&mut self, judge_death_of_every_unit_request: JudgeDeathOfEveryUnitRequest) -> JudgeDeathOfEveryUnitResponse {
         println!("GameFieldUnitServiceImpl: judge_death_of_every_field_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
-        let response = game_field_unit_repository_guard.judge_death_of_every_unit(
-            judge_death_of_every_unit_request.get_account_unique_id());
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let account_unique_id = judge_death_of_every_unit_request.get_account_unique_id();
+        let response = game_field_unit_repository_guard.judge_death_of_every_unit(account_unique_id);
+        drop(game_field_unit_repository_guard);
+
+        for (dead_unit_index, dead_unit_id) in response.iter() {
+            self.publish_game_event(
+                GameEvent::new(GameEventType::UnitDeath, account_unique_id, *dead_unit_index, *dead_unit_id, 0)).await;
+        }
 
         JudgeDeathOfEveryUnitResponse::new(response)
     }
@@ -237,7 +293,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn execute_turn_action(&mut self, execute_turn_action_request: ExecuteTurnActionRequest) -> ExecuteTurnActionResponse {
         println!("GameFieldUnitServiceImpl: execute_turn_action_of_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.execute_turn_action_of_unit(
             execute_turn_action_request.get_account_unique_id(),
             execute_turn_action_request.get_unit_card_index());
@@ -248,7 +305,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn reset_turn_action_of_all_field_unit(&mut self, reset_turn_action_of_all_field_unit_request: ResetTurnActionOfAllFieldUnitRequest) -> ResetTurnActionOfAllFieldUnitResponse {
         println!("GameFieldUnitServiceImpl: reset_turn_action_of_all_field_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.reset_turn_action_of_all_unit(
             reset_turn_action_of_all_field_unit_request.get_account_unique_id());
 
@@ -258,7 +316,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn get_current_health_point_of_field_unit_by_index(&self, get_current_health_point_of_field_unit_by_index_request: GetCurrentHealthPointOfFieldUnitByIndexRequest) -> GetCurrentHealthPointOfFieldUnitByIndexResponse {
         println!("GameFieldUnitServiceImpl: get_current_health_point_of_field_unit_by_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let account_unique_id = get_current_health_point_of_field_unit_by_index_request.get_account_unique_id();
         let unit_index = get_current_health_point_of_field_unit_by_index_request.get_field_unit_index();
         if game_field_unit_repository_guard.find_target_unit_id_by_index(account_unique_id, unit_index) == -1 {
@@ -273,7 +332,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn get_current_health_point_of_all_field_unit(&self, get_current_health_point_of_all_field_unit_request: GetCurrentHealthPointOfAllFieldUnitRequest) -> GetCurrentHealthPointOfAllFieldUnitResponse {
         println!("GameFieldUnitServiceImpl: get_current_health_point_of_all_field_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let account_unique_id = get_current_health_point_of_all_field_unit_request.get_account_unique_id();
         let current_health_point_of_all_unit =
             game_field_unit_repository_guard.acquire_current_health_point_of_all_unit(account_unique_id);
@@ -284,7 +344,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn attach_special_energy_to_field_unit_index(&mut self, attach_special_energy_to_unit_index_request: AttachSpecialEnergyToUnitIndexRequest) -> AttachSpecialEnergyToUnitIndexResponse {
         println!("GameFieldUnitServiceImpl: attach_special_energy_to_field_unit_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.attach_special_energy_to_indexed_unit(
             attach_special_energy_to_unit_index_request.get_account_unique_id(),
             attach_special_energy_to_unit_index_request.get_unit_card_index(),
@@ -298,7 +359,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn find_active_skill_usage_unit_id_by_index(&mut self, find_active_skill_usage_unit_id_by_index_request: FindActiveSkillUsageUnitIdByIndexRequest) -> FindActiveSkillUsageUnitIdByIndexResponse {
         println!("GameFieldUnitServiceImpl: find_active_skill_usage_unit_id_by_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let found_target_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(
             find_active_skill_usage_unit_id_by_index_request.get_account_unique_id(),
             find_active_skill_usage_unit_id_by_index_request.get_active_skill_usage_unit_index());
@@ -306,21 +368,35 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
         FindActiveSkillUsageUnitIdByIndexResponse::new(found_target_unit_id)
     }
 
-    async fn apply_status_effect_damage_iteratively(&mut self, apply_status_effect_damage_iteratively_request: ApplyStatusEffectDamageIterativelyRequest) -> ApplyStatusEffectDamageIterativelyResponse {
-        println!("GameFieldUnitServiceImpl: apply_status_effect_damage_iteratively()");
+    async fn tick_status_effect_of_all_unit(&mut self, tick_status_effect_of_all_unit_request: TickStatusEffectOfAllUnitRequest) -> TickStatusEffectOfAllUnitResponse {
+        println!("GameFieldUnitServiceImpl: tick_status_effect_of_all_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         // TODO: 여기서 어떤 정보를 다룰 것인지에 대한 고찰이 필요함 (사망한 유닛들 ? 기타 등등) <- 이거 여기서 하는 것은 안됨 (SRP 위배)
-        game_field_unit_repository_guard.apply_harmful_status_effect_damage_iteratively(
-            apply_status_effect_damage_iteratively_request.get_account_unique_id());
+        let is_success = game_field_unit_repository_guard.tick_status_effect_of_every_unit(
+            tick_status_effect_of_all_unit_request.get_account_unique_id(),
+            tick_status_effect_of_all_unit_request.get_tick_timing());
+
+        TickStatusEffectOfAllUnitResponse::new(is_success)
+    }
 
-        ApplyStatusEffectDamageIterativelyResponse::new(true)
+    async fn decrease_turn_based_status_duration_of_all_unit(&mut self, decrease_turn_based_status_duration_of_all_unit_request: DecreaseTurnBasedStatusDurationOfAllUnitRequest) -> DecreaseTurnBasedStatusDurationOfAllUnitResponse {
+        println!("GameFieldUnitServiceImpl: decrease_turn_based_status_duration_of_all_unit()");
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let is_success = game_field_unit_repository_guard.decrease_turn_based_status_duration_of_every_unit(
+            decrease_turn_based_status_duration_of_all_unit_request.get_account_unique_id());
+
+        DecreaseTurnBasedStatusDurationOfAllUnitResponse::new(is_success)
     }
 
     async fn acquire_unit_attack_point(&mut self, acquire_unit_attack_point_request: AcquireUnitAttackPointRequest) -> AcquireUnitAttackPointResponse {
         println!("GameFieldUnitServiceImpl: acquire_unit_attack_point()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let attack_point = game_field_unit_repository_guard.acquire_unit_attack_point(
             acquire_unit_attack_point_request.get_account_unique_id(),
             acquire_unit_attack_point_request.get_attacker_unit_index());
@@ -331,7 +407,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn acquire_unit_extra_effect(&mut self, acquire_unit_extra_effect_request: AcquireUnitExtraEffectRequest) -> AcquireUnitExtraEffectResponse {
         println!("GameFieldUnitServiceImpl: acquire_unit_extra_effect()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let extra_effect_list = game_field_unit_repository_guard.acquire_unit_extra_status_effect_by_index(
             acquire_unit_extra_effect_request.get_account_unique_id(),
             acquire_unit_extra_effect_request.get_attacker_unit_index());
@@ -342,12 +419,32 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn attack_target_unit_with_extra_effect(&mut self, attack_target_unit_with_extra_effect_request: AttackTargetUnitWithExtraEffectRequest) -> AttackTargetUnitWithExtraEffectResponse {
         println!("GameFieldUnitServiceImpl: attack_target_unit_with_extra_effect()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let attacker_unique_id = attack_target_unit_with_extra_effect_request.get_attacker_unique_id();
+        let attacker_unit_index = attack_target_unit_with_extra_effect_request.get_attacker_unit_index();
+        let opponent_unique_id = attack_target_unit_with_extra_effect_request.get_opponent_unique_id();
+        let target_unit_index = attack_target_unit_with_extra_effect_request.get_target_unit_index();
+        let damage = attack_target_unit_with_extra_effect_request.get_damage();
+
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let attacker_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(attacker_unique_id, attacker_unit_index);
+        let target_unit_id = game_field_unit_repository_guard.find_target_unit_id_by_index(opponent_unique_id, target_unit_index);
         let attack_target_unit_with_extra_effect_response = game_field_unit_repository_guard.attack_target_unit_with_extra_status_effect(
-            attack_target_unit_with_extra_effect_request.get_opponent_unique_id(),
-            attack_target_unit_with_extra_effect_request.get_target_unit_index(),
-            attack_target_unit_with_extra_effect_request.get_damage(),
+            opponent_unique_id,
+            target_unit_index,
+            damage,
             attack_target_unit_with_extra_effect_request.get_extra_status_effect_list().clone());
+        drop(game_field_unit_repository_guard);
+
+        if attack_target_unit_with_extra_effect_response {
+            self.publish_game_event(
+                GameEvent::new(GameEventType::UnitAttack, attacker_unique_id, attacker_unit_index, attacker_unit_id, damage)).await;
+
+            let mut unit_damaged_game_event =
+                GameEvent::new(GameEventType::UnitDamaged, opponent_unique_id, target_unit_index, target_unit_id, damage);
+            unit_damaged_game_event.set_source(attacker_unique_id, attacker_unit_index);
+            self.publish_game_event(unit_damaged_game_event).await;
+        }
 
         AttackTargetUnitWithExtraEffectResponse::new(attack_target_unit_with_extra_effect_response)
     }
@@ -355,7 +452,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn attack_every_unit_with_extra_effect(&mut self, attack_every_unit_with_extra_effect_request: AttackEveryUnitWithExtraEffectRequest) -> AttackEveryUnitWithExtraEffectResponse {
         println!("GameFieldUnitServiceImpl: attack_every_unit_with_extra_effect()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.attack_every_unit_with_extra_effect(
             attack_every_unit_with_extra_effect_request.get_opponent_unique_id(),
             attack_every_unit_with_extra_effect_request.get_damage(),
@@ -369,8 +467,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
 
         let passive_skill_list = apply_passive_skill_list_request.get_passive_skill_list();
 
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         // TODO: Need to Refactor
         for passive_skill in passive_skill_list.iter() {
@@ -405,7 +503,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn apply_catastrophic_damage_to_field_unit(&mut self, apply_catastrophic_damage_to_field_unit_request: ApplyCatastrophicDamageToFieldUnitRequest) -> ApplyCatastrophicDamageToFieldUnitResponse {
         println!("GameFieldUnitServiceImpl: apply_catastrophic_damage_to_field_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let apply_catastrophic_damage_result = game_field_unit_repository_guard
             .apply_damage_to_every_unit(
                 apply_catastrophic_damage_to_field_unit_request.get_opponent_unique_id(),
@@ -417,7 +516,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn detach_multiple_energy_from_field_unit(&mut self, detach_multiple_energy_from_field_unit_request: DetachMultipleEnergyFromFieldUnitRequest) -> DetachMultipleEnergyFromFieldUnitResponse {
         println!("GameFieldUnitServiceImpl: detach_multiple_energy_from_field_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let detach_multiple_energy_from_field_unit_result = game_field_unit_repository_guard
             .detach_multiple_energy_from_indexed_unit(
                 detach_multiple_energy_from_field_unit_request.get_account_unique_id(),
@@ -431,7 +531,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn get_current_attached_energy_of_field_unit_by_index(&mut self, get_current_attached_energy_of_field_unit_by_index_request: GetCurrentAttachedEnergyOfFieldUnitByIndexRequest) -> GetCurrentAttachedEnergyOfFieldUnitByIndexResponse {
         println!("GameFieldUnitServiceImpl: get_current_attached_energy_of_field_unit_by_index()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let field_unit_energy_map = game_field_unit_repository_guard.acquire_energy_map_of_indexed_unit(
             get_current_attached_energy_of_field_unit_by_index_request.get_account_unique_id(),
             get_current_attached_energy_of_field_unit_by_index_request.get_field_unit_index());
@@ -445,7 +546,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
 
         let account_unique_id = get_game_field_unit_card_to_service_request.get_account_unique_id();
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let mut game_field_unit_account_unique_id_index = game_field_unit_repository_guard.get_game_field_unit_map().clone();
         let game_field_unit_account_unique_id = game_field_unit_account_unique_id_index.get_mut(&account_unique_id).unwrap();
         let game_field_unit_list_of_account_unique_id =game_field_unit_account_unique_id.get_all_unit_list_in_game_field().clone();
@@ -456,7 +558,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn acquire_unit_passive_status_list(&mut self, acquire_unit_passive_status_list_request: AcquireUnitPassiveStatusListRequest) -> AcquireUnitPassiveStatusListResponse {
         println!("GameFieldUnitServiceImpl: acquire_unit_passive_status_list()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let passive_status_list =
             game_field_unit_repository_guard.get_passive_status_list_of_unit(
                 acquire_unit_passive_status_list_request.get_account_unique_id(),
@@ -468,7 +571,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn acquire_unit_harmful_status_effect(&mut self, acquire_unit_harmful_status_effect_request: AcquireUnitHarmfulStatusEffectRequest) -> AcquireUnitHarmfulStatusEffectResponse {
         println!("GameFieldUnitServiceImpl: acquire_unit_harmful_status_effect()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let harmful_effect_list = game_field_unit_repository_guard.acquire_unit_harmful_status_effect_list_by_index(
             acquire_unit_harmful_status_effect_request.get_opponent_unique_id(),
             acquire_unit_harmful_status_effect_request.get_opponent_unit_index());
@@ -477,7 +581,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     }
 
     async fn acquire_harmful_status_effect_of_all_unit(&mut self, acquire_harmful_status_effect_of_all_unit_request: AcquireHarmfulStatusEffectOfAllUnitRequest) -> AcquireHarmfulStatusEffectOfAllUnitResponse {
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let harmful_effect_list_of_all_unit =
             game_field_unit_repository_guard.acquire_unit_harmful_status_effect_list_of_all_living_unit(
                 acquire_harmful_status_effect_of_all_unit_request.get_account_unique_id());
@@ -485,10 +590,21 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
         AcquireHarmfulStatusEffectOfAllUnitResponse::new(harmful_effect_list_of_all_unit)
     }
 
+    async fn acquire_extra_effect_of_all_unit(&mut self, acquire_extra_effect_of_all_unit_request: AcquireExtraEffectOfAllUnitRequest) -> AcquireExtraEffectOfAllUnitResponse {
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
+        let extra_effect_list_of_all_unit =
+            game_field_unit_repository_guard.acquire_unit_extra_effect_list_of_all_living_unit(
+                acquire_extra_effect_of_all_unit_request.get_account_unique_id());
+
+        AcquireExtraEffectOfAllUnitResponse::new(extra_effect_list_of_all_unit)
+    }
+
     async fn reset_all_passive_of_unit(&mut self, reset_all_passive_of_unit_request: ResetAllPassiveOfUnitRequest) -> ResetAllPassiveOfUnitResponse {
         println!("GameFieldUnitServiceImpl: reset_all_passive_of_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let reset_all_passive = game_field_unit_repository_guard.reset_all_passive_of_unit(
             reset_all_passive_of_unit_request.get_account_unique_id(),
             reset_all_passive_of_unit_request.get_unit_card_index(),
@@ -499,7 +615,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     async fn execute_index_passive_of_unit(&mut self, execute_index_passive_of_unit_request: ExecuteIndexPassiveOfUnitRequest) -> ExecuteIndexPassiveOfUnitResponse {
         println!("GameFieldUnitServiceImpl: execute_index_passive_of_unit()");
 
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.execute_index_passive_skill_of_unit(
             execute_index_passive_of_unit_request.get_account_unique_id(),
             execute_index_passive_of_unit_request.get_unit_card_index(),
@@ -509,7 +626,8 @@ impl GameFieldUnitService for GameFieldUnitServiceImpl {
     }
     async fn get_passive_skill_usable(&self, get_passive_skill_usable_request: GetPassiveSkillUsableRequest) -> GetPassiveSkillUsableResponse {
         println!("GameFieldUnitServiceImpl: get_passive_skill_usable()");
-        let mut game_field_unit_repository_guard = self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
         let response = game_field_unit_repository_guard.get_passive_skill_usable(
             get_passive_skill_usable_request.get_account_unique_id(),
             get_passive_skill_usable_request.get_unit_card_index());
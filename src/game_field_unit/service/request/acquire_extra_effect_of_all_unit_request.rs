@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub struct AcquireExtraEffectOfAllUnitRequest {
+    account_unique_id: i32,
+}
+
+impl AcquireExtraEffectOfAllUnitRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        AcquireExtraEffectOfAllUnitRequest {
+            account_unique_id,
+        }
+    }
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
\ No newline at end of file
@@ -46,7 +46,7 @@ impl AttachSpecialEnergyToUnitIndexRequest {
 mod tests {
     use super::*;
     use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
-    use crate::game_card_energy::entity::effect::Effect;
+    use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 
     #[test]
     fn test_attach_special_energy_request() {
@@ -55,8 +55,8 @@ mod tests {
         let unit_card_index = 456;
         let race_enum = RaceEnum::Undead;  // Replace with the actual race enum variant
         let status_effect_list = vec![
-            StatusEffect::new(Effect::Darkfire, 3, 10, -1),  // Replace with actual values
-            StatusEffect::new(Effect::Freeze, 4, 15, -2),  // Replace with actual values
+            StatusEffect::new(ExtraEffect::DarkFire, 3, 10, -1),  // Replace with actual values
+            StatusEffect::new(ExtraEffect::Freeze, 4, 15, -2),  // Replace with actual values
         ];
 
         let request = AttachSpecialEnergyToUnitIndexRequest::new(
@@ -2,6 +2,8 @@ use crate::game_field_unit::entity::extra_status_effect::ExtraStatusEffect;
 
 #[derive(Debug)]
 pub struct AttackTargetUnitWithExtraEffectRequest {
+    attacker_unique_id: i32,
+    attacker_unit_index: i32,
     opponent_unique_id: i32,
     damage: i32,
     extra_status_effect_list: Vec<ExtraStatusEffect>,
@@ -9,12 +11,16 @@ pub struct AttackTargetUnitWithExtraEffectRequest {
 }
 
 impl AttackTargetUnitWithExtraEffectRequest {
-    pub fn new(opponent_unique_id: i32,
+    pub fn new(attacker_unique_id: i32,
+               attacker_unit_index: i32,
+               opponent_unique_id: i32,
                damage: i32,
                extra_status_effect_list: Vec<ExtraStatusEffect>,
                target_unit_index: i32) -> Self {
 
         AttackTargetUnitWithExtraEffectRequest {
+            attacker_unique_id,
+            attacker_unit_index,
             opponent_unique_id,
             damage,
             extra_status_effect_list,
@@ -22,6 +28,14 @@ impl AttackTargetUnitWithExtraEffectRequest {
         }
     }
 
+    pub fn get_attacker_unique_id(&self) -> i32 {
+        self.attacker_unique_id
+    }
+
+    pub fn get_attacker_unit_index(&self) -> i32 {
+        self.attacker_unit_index
+    }
+
     pub fn get_opponent_unique_id(&self) -> i32 {
         self.opponent_unique_id
     }
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct DecreaseTurnBasedStatusDurationOfAllUnitRequest {
+    account_unique_id: i32,
+}
+
+impl DecreaseTurnBasedStatusDurationOfAllUnitRequest {
+    pub fn new(account_unique_id: i32) -> Self {
+        DecreaseTurnBasedStatusDurationOfAllUnitRequest {
+            account_unique_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+}
\ No newline at end of file
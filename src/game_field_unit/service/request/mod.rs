@@ -7,7 +7,7 @@ pub mod apply_instant_death_to_target_unit_index_request;
 pub mod get_current_health_point_of_field_unit_by_index_request;
 pub mod attach_special_energy_to_unit_index_request;
 pub mod find_active_skill_usage_unit_id_by_index_request;
-pub mod apply_status_effect_damage_iteratively_request;
+pub mod tick_status_effect_of_all_unit_request;
 pub mod acquire_unit_attack_point_request;
 pub mod acquire_unit_extra_effect_request;
 pub mod attack_target_unit_with_extra_effect_request;
@@ -27,4 +27,6 @@ pub mod acquire_unit_harmful_status_effect_request;
 pub mod reset_all_passive_of_unit_request;
 pub mod execute_index_passive_of_unit_request;
 pub mod get_passive_skill_usable_request;
-pub mod acquire_harmful_status_effect_of_all_unit_request;
\ No newline at end of file
+pub mod acquire_harmful_status_effect_of_all_unit_request;
+pub mod acquire_extra_effect_of_all_unit_request;
+pub mod decrease_turn_based_status_duration_of_all_unit_request;
\ No newline at end of file
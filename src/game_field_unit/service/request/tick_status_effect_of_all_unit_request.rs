@@ -0,0 +1,24 @@
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
+
+#[derive(Debug)]
+pub struct TickStatusEffectOfAllUnitRequest {
+    account_unique_id: i32,
+    tick_timing: StatusEffectTickTiming,
+}
+
+impl TickStatusEffectOfAllUnitRequest {
+    pub fn new(account_unique_id: i32, tick_timing: StatusEffectTickTiming) -> Self {
+        TickStatusEffectOfAllUnitRequest {
+            account_unique_id,
+            tick_timing,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+
+    pub fn get_tick_timing(&self) -> StatusEffectTickTiming {
+        self.tick_timing
+    }
+}
\ No newline at end of file
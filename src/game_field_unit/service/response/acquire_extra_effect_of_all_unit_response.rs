@@ -0,0 +1,16 @@
+use crate::game_field_unit::entity::extra_effect::ExtraEffect;
+
+#[derive(Debug, Clone)]
+pub struct AcquireExtraEffectOfAllUnitResponse {
+    extra_effect_list_of_all_unit: Vec<(i32, Vec<ExtraEffect>)>,
+}
+
+impl AcquireExtraEffectOfAllUnitResponse {
+    pub fn new(extra_effect_list_of_all_unit: Vec<(i32, Vec<ExtraEffect>)>) -> Self {
+        Self { extra_effect_list_of_all_unit }
+    }
+
+    pub fn get_extra_effect_list_of_all_unit(&self) -> Vec<(i32, Vec<ExtraEffect>)> {
+        self.extra_effect_list_of_all_unit.clone()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecreaseTurnBasedStatusDurationOfAllUnitResponse {
+    is_success: bool
+}
+
+impl DecreaseTurnBasedStatusDurationOfAllUnitResponse {
+    pub fn new(is_success: bool) -> Self {
+        DecreaseTurnBasedStatusDurationOfAllUnitResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
\ No newline at end of file
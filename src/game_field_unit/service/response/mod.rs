@@ -7,7 +7,7 @@ pub mod apply_instant_death_to_target_unit_index_response;
 pub mod get_current_health_point_of_field_unit_by_index_response;
 pub mod attach_special_energy_to_unit_index_response;
 pub mod find_active_skill_usage_unit_id_by_index_response;
-pub mod apply_status_effect_damage_iteratively_response;
+pub mod tick_status_effect_of_all_unit_response;
 pub mod acquire_unit_attack_point_response;
 pub mod acquire_unit_extra_effect_response;
 pub mod attack_target_unit_with_extra_effect_response;
@@ -27,4 +27,6 @@ pub mod acquire_unit_harmful_status_effect_response;
 pub mod reset_all_passive_of_unit_response;
 pub mod execute_index_passive_of_unit_response;
 pub mod get_passive_skill_usable_response;
-pub mod acquire_harmful_status_effect_of_all_unit_response;
\ No newline at end of file
+pub mod acquire_harmful_status_effect_of_all_unit_response;
+pub mod acquire_extra_effect_of_all_unit_response;
+pub mod decrease_turn_based_status_duration_of_all_unit_response;
\ No newline at end of file
@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ApplyStatusEffectDamageIterativelyResponse {
+pub struct TickStatusEffectOfAllUnitResponse {
     is_success: bool
 }
 
-impl ApplyStatusEffectDamageIterativelyResponse {
+impl TickStatusEffectOfAllUnitResponse {
     pub fn new(is_success: bool) -> Self {
-        ApplyStatusEffectDamageIterativelyResponse { is_success }
+        TickStatusEffectOfAllUnitResponse { is_success }
     }
 
     pub fn is_success(&self) -> bool {
@@ -5,10 +5,11 @@ use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
 use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
 use crate::common::message::false_message_enum::FalseMessage;
-use crate::common::message::false_message_enum::FalseMessage::{DeployedRoundAttack, Dummy, NotEnoughSkillEnergy, UnitActionLimitOver, UnitFrozen};
+use crate::common::message::false_message_enum::FalseMessage::{DeployedRoundAttack, Dummy, NotEnoughSkillEnergy, UnitActionLimitOver, UnitFrozen, UnitSilenced, UnitStunned};
 use crate::game_card_passive_skill::entity::passive_skill_casting_condition::PassiveSkillCastingCondition;
 use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_field_unit::entity::harmful_status_effect::HarmfulStatusEffect;
+use crate::game_field_unit::entity::status_effect_rule::StatusEffectRule;
 use crate::game_field_unit::repository::game_field_unit_repository::GameFieldUnitRepository;
 use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
 use crate::game_field_unit_action_possibility_validator::service::game_field_unit_action_possibility_validator_service::GameFieldUnitActionPossibilityValidatorService;
@@ -24,16 +25,13 @@ use crate::game_round::repository::game_round_repository_impl::GameRoundReposito
 
 pub struct GameFieldUnitActionPossibilityValidatorServiceImpl {
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
 }
 
 impl GameFieldUnitActionPossibilityValidatorServiceImpl {
-    pub fn new(game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
-               game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>) -> Self {
+    pub fn new(game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>) -> Self {
 
         GameFieldUnitActionPossibilityValidatorServiceImpl {
             game_round_repository,
-            game_field_unit_repository,
         }
     }
 
@@ -43,8 +41,7 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         GameFieldUnitActionPossibilityValidatorServiceImpl::new(
-                            GameRoundRepositoryImpl::get_instance(),
-                            GameFieldUnitRepositoryImpl::get_instance())));
+                            GameRoundRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -52,8 +49,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
     async fn get_field_unit_turn_action(&self,
                                         account_unique_id: i32,
                                         field_unit_index: i32) -> Option<bool> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -65,8 +62,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
     async fn get_field_unit_deployed_round(&self,
                                            account_unique_id: i32,
                                            field_unit_index: i32) -> Option<i32> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -78,8 +75,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
     async fn get_total_energy_count_of_field_unit(&self,
                                                   account_unique_id: i32,
                                                   field_unit_index: i32) -> Option<i32> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -92,8 +89,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
                                         account_unique_id: i32,
                                         field_unit_index: i32,
                                         race_enum: RaceEnum) -> Option<i32> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -106,8 +103,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
     async fn get_field_unit_harmful_effect(&self,
                                            account_unique_id: i32,
                                            field_unit_index: i32) -> Option<Vec<HarmfulStatusEffect>> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -130,8 +127,8 @@ impl GameFieldUnitActionPossibilityValidatorServiceImpl {
                                         account_unique_id: i32,
                                         field_unit_index: i32,
                                         passive_index: i32) -> Option<bool> {
-        let mut game_field_unit_repository_guard =
-            self.game_field_unit_repository.lock().await;
+        let game_field_unit_repository_mutex = GameFieldUnitRepositoryImpl::get_instance();
+        let mut game_field_unit_repository_guard = game_field_unit_repository_mutex.lock().await;
 
         game_field_unit_repository_guard
             .get_game_field_unit_map()
@@ -188,10 +185,18 @@ impl GameFieldUnitActionPossibilityValidatorService for GameFieldUnitActionPossi
             is_unit_basic_attack_possible_request.get_field_unit_index()).await.unwrap_or(Vec::new());
 
         for harmful_status in harmful_status_effect_list {
-            if harmful_status.get_harmful_effect() == &ExtraEffect::Freeze {
-                println!("빙결 상태의 유닛은 공격이 불가합니다.");
-                return IsUnitBasicAttackPossibleResponse::new(false, UnitFrozen)
+            let harmful_effect = harmful_status.get_harmful_effect();
+            if !StatusEffectRule::of(harmful_effect).blocks_action() {
+                continue
             }
+
+            if harmful_effect == &ExtraEffect::Stun {
+                println!("기절 상태의 유닛은 공격이 불가합니다.");
+                return IsUnitBasicAttackPossibleResponse::new(false, UnitStunned)
+            }
+
+            println!("빙결 상태의 유닛은 공격이 불가합니다.");
+            return IsUnitBasicAttackPossibleResponse::new(false, UnitFrozen)
         }
 
         IsUnitBasicAttackPossibleResponse::new(true, Dummy)
@@ -247,9 +252,24 @@ impl GameFieldUnitActionPossibilityValidatorService for GameFieldUnitActionPossi
             is_using_active_skill_possible_request.get_field_unit_index()).await.unwrap_or(Vec::new());
 
         for harmful_status in harmful_status_effect_list {
-            if harmful_status.get_harmful_effect() == &ExtraEffect::Freeze {
-                println!("빙결 상태의 유닛은 공격이 불가합니다.");
-                return IsUsingActiveSkillPossibleResponse::new(false, UnitFrozen)
+            let harmful_effect = harmful_status.get_harmful_effect();
+            if !StatusEffectRule::of(harmful_effect).blocks_skill() {
+                continue
+            }
+
+            match harmful_effect {
+                ExtraEffect::Stun => {
+                    println!("기절 상태의 유닛은 스킬 사용이 불가합니다.");
+                    return IsUsingActiveSkillPossibleResponse::new(false, UnitStunned)
+                },
+                ExtraEffect::Silence => {
+                    println!("침묵 상태의 유닛은 스킬 사용이 불가합니다.");
+                    return IsUsingActiveSkillPossibleResponse::new(false, UnitSilenced)
+                },
+                _ => {
+                    println!("빙결 상태의 유닛은 공격이 불가합니다.");
+                    return IsUsingActiveSkillPossibleResponse::new(false, UnitFrozen)
+                },
             }
         }
 
@@ -4,6 +4,7 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
 use crate::game_hand::entity::game_hand::GameHand;
 use crate::game_hand::entity::game_hand_card::GameHandCard;
 use crate::game_hand::repository::game_hand_repository::GameHandRepository;
@@ -24,6 +25,11 @@ impl GameHandRepositoryImpl {
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<GameHandRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_hand_repository()
+        }
+
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<GameHandRepositoryImpl>> =
                 Arc::new(
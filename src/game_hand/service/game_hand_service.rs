@@ -7,6 +7,8 @@ use crate::game_hand::service::request::use_game_hand_item_card_request::UseGame
 use crate::game_hand::service::request::use_game_hand_support_card_request::UseGameHandSupportCardRequest;
 use crate::game_hand::service::request::use_game_hand_unit_card_request::UseGameHandUnitCardRequest;
 use crate::game_hand::service::request::use_game_hand_tool_card_request::UseGameHandToolCardRequest;
+use crate::game_hand::service::request::use_game_hand_trap_card_request::UseGameHandTrapCardRequest;
+use crate::game_hand::service::request::use_game_hand_environment_card_request::UseGameHandEnvironmentCardRequest;
 use crate::game_hand::service::response::add_card_list_to_hand_response::AddCardListToHandResponse;
 
 use crate::game_hand::service::response::put_cards_on_deck_response::PutCardsOnDeckResponse;
@@ -15,6 +17,8 @@ use crate::game_hand::service::response::use_game_hand_item_card_response::UseGa
 use crate::game_hand::service::response::use_game_hand_support_card_response::UseGameHandSupportCardResponse;
 use crate::game_hand::service::response::use_game_hand_unit_card_response::UseGameHandUnitCardResponse;
 use crate::game_hand::service::response::use_game_hand_tool_card_response::UseGameHandToolCardResponse;
+use crate::game_hand::service::response::use_game_hand_trap_card_response::UseGameHandTrapCardResponse;
+use crate::game_hand::service::response::use_game_hand_environment_card_response::UseGameHandEnvironmentCardResponse;
 
 #[async_trait]
 pub trait GameHandService {
@@ -25,4 +29,6 @@ pub trait GameHandService {
     async fn use_energy_card(&mut self, use_game_hand_energy_card_request: UseGameHandEnergyCardRequest) -> UseGameHandEnergyCardResponse;
     async fn use_item_card(&mut self, use_game_hand_item_card_request: UseGameHandItemCardRequest) -> UseGameHandItemCardResponse;
     async fn use_tool_card(&mut self, use_game_hand_tool_card_request: UseGameHandToolCardRequest) -> UseGameHandToolCardResponse;
+    async fn use_trap_card(&mut self, use_game_hand_trap_card_request: UseGameHandTrapCardRequest) -> UseGameHandTrapCardResponse;
+    async fn use_environment_card(&mut self, use_game_hand_environment_card_request: UseGameHandEnvironmentCardRequest) -> UseGameHandEnvironmentCardResponse;
 }
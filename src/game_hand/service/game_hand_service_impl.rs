@@ -18,11 +18,14 @@ use crate::common::converter::vector_string_to_vector_integer::VectorStringToVec
 use crate::game_deck::entity::game_deck_card::GameDeckCard;
 use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
 use crate::game_field_unit::entity::race_enum_value::RaceEnumValue;
-use crate::game_field_unit::repository::game_field_unit_repository_impl::GameFieldUnitRepositoryImpl;
 use crate::game_hand::repository::game_hand_repository::GameHandRepository;
 
 use crate::game_hand::repository::game_hand_repository_impl::GameHandRepositoryImpl;
 use crate::game_hand::service::game_hand_service::GameHandService;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_hand::service::request::add_card_list_to_hand_request::AddCardListToHandRequest;
 use crate::game_hand::service::request::put_cards_on_deck_request::{PutCardsOnDeckRequest};
 
@@ -30,6 +33,8 @@ use crate::game_hand::service::request::use_game_hand_support_card_request::UseG
 use crate::game_hand::service::request::use_game_hand_energy_card_request::UseGameHandEnergyCardRequest;
 use crate::game_hand::service::request::use_game_hand_item_card_request::UseGameHandItemCardRequest;
 use crate::game_hand::service::request::use_game_hand_tool_card_request::UseGameHandToolCardRequest;
+use crate::game_hand::service::request::use_game_hand_trap_card_request::UseGameHandTrapCardRequest;
+use crate::game_hand::service::request::use_game_hand_environment_card_request::UseGameHandEnvironmentCardRequest;
 use crate::game_hand::service::request::use_game_hand_unit_card_request::UseGameHandUnitCardRequest;
 use crate::game_hand::service::response::add_card_list_to_hand_response::AddCardListToHandResponse;
 use crate::game_hand::service::response::put_cards_on_deck_response::PutCardsOnDeckResponse;
@@ -37,10 +42,11 @@ use crate::game_hand::service::response::use_game_hand_energy_card_response::Use
 use crate::game_hand::service::response::use_game_hand_item_card_response::UseGameHandItemCardResponse;
 use crate::game_hand::service::response::use_game_hand_support_card_response::UseGameHandSupportCardResponse;
 use crate::game_hand::service::response::use_game_hand_tool_card_response::UseGameHandToolCardResponse;
+use crate::game_hand::service::response::use_game_hand_trap_card_response::UseGameHandTrapCardResponse;
+use crate::game_hand::service::response::use_game_hand_environment_card_response::UseGameHandEnvironmentCardResponse;
 use crate::game_hand::service::response::use_game_hand_unit_card_response::UseGameHandUnitCardResponse;
 use crate::game_round::repository::game_round_repository_impl::GameRoundRepositoryImpl;
 use crate::game_tomb::repository::game_tomb_repository::GameTombRepository;
-use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
 use crate::notify_player_action::repository::notify_player_action_repository::NotifyPlayerActionRepository;
 use crate::notify_player_action::repository::notify_player_action_repository_impl::NotifyPlayerActionRepositoryImpl;
 use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
@@ -48,10 +54,6 @@ use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepo
 
 pub struct GameHandServiceImpl {
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-    game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
-    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
     card_kinds_repository: Arc<AsyncMutex<CardKindsRepositoryImpl>>,
     card_grade_repository: Arc<AsyncMutex<CardGradeRepositoryImpl>>,
     card_race_repository: Arc<AsyncMutex<CardRaceRepositoryImpl>>,
@@ -62,10 +64,6 @@ pub struct GameHandServiceImpl {
 
 impl GameHandServiceImpl {
     pub fn new(game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
-               game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
-               game_field_unit_repository: Arc<AsyncMutex<GameFieldUnitRepositoryImpl>>,
-               game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
                card_kinds_repository: Arc<AsyncMutex<CardKindsRepositoryImpl>>,
                card_grade_repository: Arc<AsyncMutex<CardGradeRepositoryImpl>>,
                card_race_repository: Arc<AsyncMutex<CardRaceRepositoryImpl>>,
@@ -75,16 +73,12 @@ impl GameHandServiceImpl {
     ) -> Self {
         GameHandServiceImpl {
             game_round_repository,
-            game_hand_repository,
-            game_deck_repository,
-            game_field_unit_repository,
-            game_tomb_repository,
             card_kinds_repository,
             card_grade_repository,
             card_race_repository,
             battle_room_repository,
             notify_player_action_repository,
-            redis_in_memory_repository
+            redis_in_memory_repository,
         }
     }
 
@@ -95,10 +89,6 @@ impl GameHandServiceImpl {
                     AsyncMutex::new(
                         GameHandServiceImpl::new(
                             GameRoundRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
-                            GameFieldUnitRepositoryImpl::get_instance(),
-                            GameTombRepositoryImpl::get_instance(),
                             CardKindsRepositoryImpl::get_instance(),
                             CardGradeRepositoryImpl::get_instance(),
                             CardRaceRepositoryImpl::get_instance(),
@@ -109,6 +99,13 @@ impl GameHandServiceImpl {
         INSTANCE.clone()
     }
 
+    async fn publish_card_used_game_event(&self, account_unique_id: i32, used_card_id: i32) {
+        let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+        let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+        game_event_bus_repository_guard.publish_game_event(
+            GameEvent::new(GameEventType::CardUsed, account_unique_id, -1, used_card_id, 0));
+    }
+
     async fn get_account_unique_id(&self, session_id: &str) -> i32 {
         let mut redis_in_memory_repository = self.redis_in_memory_repository.lock().await;
         let account_unique_id_option_string = redis_in_memory_repository.get(session_id).await;
@@ -119,7 +116,8 @@ impl GameHandServiceImpl {
 
     // TODO: 해킹 감지 (3회 이상 계정 영구 정지, 1회, 2회 -> 1시간 접속 불가)
     async fn check_protocol_hacking(&mut self, account_unique_id: i32, unit_card_number: i32) -> bool {
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let game_hand = game_hand_repository_guard.get_game_hand_map().get(&account_unique_id);
 
         if game_hand.is_none() {
@@ -158,7 +156,8 @@ impl GameHandService for GameHandServiceImpl {
     async fn add_card_list_to_hand(&mut self, add_card_list_to_hand_request: AddCardListToHandRequest) -> AddCardListToHandResponse {
         println!("GameHandServiceImpl: add_card_list_to_hand()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let result =
             game_hand_repository_guard.add_card_list_to_hand(
                 add_card_list_to_hand_request.get_account_unique_id(),
@@ -177,7 +176,8 @@ impl GameHandService for GameHandServiceImpl {
             VectorStringToVectorInteger::vector_string_to_vector_i32(card_string_list_to_be_changed);
 
         // removing hand cards
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         if let Some(user_game_hand) =
             game_hand_repository_guard.get_game_hand_map().get_mut(&account_unique_id) {
             user_game_hand.remove_card_list_from_hand(card_i32_list_to_be_changed.clone());
@@ -187,7 +187,8 @@ impl GameHandService for GameHandServiceImpl {
         }
 
         // adding deck cards
-        let mut game_deck_repository_guard = self.game_deck_repository.lock().await;
+        let game_deck_repository_mutex = GameDeckRepositoryImpl::get_instance();
+        let mut game_deck_repository_guard = game_deck_repository_mutex.lock().await;
         if let Some(user_game_deck) =
             game_deck_repository_guard.get_game_deck_map().get_mut(&account_unique_id) {
             for card in card_i32_list_to_be_changed {
@@ -205,7 +206,8 @@ impl GameHandService for GameHandServiceImpl {
     async fn use_support_card(&mut self, use_game_hand_support_card_request: UseGameHandSupportCardRequest) -> UseGameHandSupportCardResponse {
         println!("GameHandServiceImpl: use_support_card()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let specific_card_option = game_hand_repository_guard.use_specific_card(
             use_game_hand_support_card_request.get_account_unique_id(),
             use_game_hand_support_card_request.get_support_card_id());
@@ -215,13 +217,19 @@ impl GameHandService for GameHandServiceImpl {
         }
         let specific_card = specific_card_option.unwrap();
 
-        UseGameHandSupportCardResponse::new(specific_card.get_card())
+        let used_card_id = specific_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_support_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandSupportCardResponse::new(used_card_id)
     }
 
     async fn use_unit_card(&mut self, use_game_hand_unit_card_request: UseGameHandUnitCardRequest) -> UseGameHandUnitCardResponse {
         println!("GameHandServiceImpl: use_unit_card()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let maybe_unit_card = game_hand_repository_guard.use_specific_card(
             use_game_hand_unit_card_request.get_account_unique_id(),
             use_game_hand_unit_card_request.get_unit_card_id());
@@ -231,13 +239,19 @@ impl GameHandService for GameHandServiceImpl {
         }
         let unit_card = maybe_unit_card.unwrap();
 
-        UseGameHandUnitCardResponse::new(unit_card.get_card())
+        let used_card_id = unit_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_unit_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandUnitCardResponse::new(used_card_id)
     }
 
     async fn use_energy_card(&mut self, use_game_hand_energy_card_request: UseGameHandEnergyCardRequest) -> UseGameHandEnergyCardResponse {
         println!("GameHandServiceImpl: use_energy_card()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let maybe_energy_card = game_hand_repository_guard.use_specific_card(
             use_game_hand_energy_card_request.get_account_unique_id(),
             use_game_hand_energy_card_request.get_energy_card_id());
@@ -247,13 +261,19 @@ impl GameHandService for GameHandServiceImpl {
         }
         let energy_card = maybe_energy_card.unwrap();
 
-        UseGameHandEnergyCardResponse::new(energy_card.get_card())
+        let used_card_id = energy_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_energy_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandEnergyCardResponse::new(used_card_id)
     }
 
     async fn use_item_card(&mut self, use_game_hand_item_card_request: UseGameHandItemCardRequest) -> UseGameHandItemCardResponse {
         println!("GameHandServiceImpl: use_item_card()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let maybe_item_card = game_hand_repository_guard.use_specific_card(
             use_game_hand_item_card_request.get_account_unique_id(),
             use_game_hand_item_card_request.get_item_card_id());
@@ -263,13 +283,19 @@ impl GameHandService for GameHandServiceImpl {
         }
         let item_card = maybe_item_card.unwrap();
 
-        UseGameHandItemCardResponse::new(item_card.get_card())
+        let used_card_id = item_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_item_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandItemCardResponse::new(used_card_id)
     }
 
     async fn use_tool_card(&mut self, use_game_hand_tool_card_request: UseGameHandToolCardRequest) -> UseGameHandToolCardResponse {
         println!("GameHandServiceImpl: use_tool_card()");
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let specific_card_option = game_hand_repository_guard.use_specific_card(
             use_game_hand_tool_card_request.get_account_unique_id(),
             use_game_hand_tool_card_request.get_tool_card_id());
@@ -279,6 +305,55 @@ impl GameHandService for GameHandServiceImpl {
         }
         let specific_card = specific_card_option.unwrap();
 
-        UseGameHandToolCardResponse::new(specific_card.get_card())
+        let used_card_id = specific_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_tool_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandToolCardResponse::new(used_card_id)
+    }
+
+    async fn use_trap_card(&mut self, use_game_hand_trap_card_request: UseGameHandTrapCardRequest) -> UseGameHandTrapCardResponse {
+        println!("GameHandServiceImpl: use_trap_card()");
+
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+        let maybe_trap_card = game_hand_repository_guard.use_specific_card(
+            use_game_hand_trap_card_request.get_account_unique_id(),
+            use_game_hand_trap_card_request.get_trap_card_id());
+
+        if maybe_trap_card.is_none() {
+            return UseGameHandTrapCardResponse::new(-1)
+        }
+        let trap_card = maybe_trap_card.unwrap();
+
+        let used_card_id = trap_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_trap_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandTrapCardResponse::new(used_card_id)
+    }
+
+    async fn use_environment_card(&mut self, use_game_hand_environment_card_request: UseGameHandEnvironmentCardRequest) -> UseGameHandEnvironmentCardResponse {
+        println!("GameHandServiceImpl: use_environment_card()");
+
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
+        let maybe_environment_card = game_hand_repository_guard.use_specific_card(
+            use_game_hand_environment_card_request.get_account_unique_id(),
+            use_game_hand_environment_card_request.get_environment_card_id());
+
+        if maybe_environment_card.is_none() {
+            return UseGameHandEnvironmentCardResponse::new(-1)
+        }
+        let environment_card = maybe_environment_card.unwrap();
+
+        let used_card_id = environment_card.get_card();
+        drop(game_hand_repository_guard);
+
+        self.publish_card_used_game_event(use_game_hand_environment_card_request.get_account_unique_id(), used_card_id).await;
+
+        UseGameHandEnvironmentCardResponse::new(used_card_id)
     }
 }
\ No newline at end of file
@@ -4,4 +4,6 @@ pub mod use_game_hand_unit_card_request;
 pub mod use_game_hand_energy_card_request;
 pub mod use_game_hand_item_card_request;
 pub mod use_game_hand_tool_card_request;
+pub mod use_game_hand_trap_card_request;
+pub mod use_game_hand_environment_card_request;
 pub mod add_card_list_to_hand_request;
\ No newline at end of file
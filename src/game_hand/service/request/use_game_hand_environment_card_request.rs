@@ -0,0 +1,22 @@
+#[derive(Debug)]
+pub struct UseGameHandEnvironmentCardRequest {
+    account_unique_id: i32,
+    environment_card_id: i32,
+}
+
+impl UseGameHandEnvironmentCardRequest {
+    pub fn new(account_unique_id: i32, environment_card_id: i32) -> Self {
+        UseGameHandEnvironmentCardRequest {
+            account_unique_id,
+            environment_card_id,
+        }
+    }
+
+    pub fn get_account_unique_id(&self) -> i32 {
+        self.account_unique_id
+    }
+
+    pub fn get_environment_card_id(&self) -> i32 {
+        self.environment_card_id
+    }
+}
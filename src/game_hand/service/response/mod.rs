@@ -4,4 +4,6 @@ pub mod use_game_hand_support_card_response;
 pub mod use_game_hand_energy_card_response;
 pub mod use_game_hand_item_card_response;
 pub mod use_game_hand_tool_card_response;
+pub mod use_game_hand_trap_card_response;
+pub mod use_game_hand_environment_card_response;
 pub mod add_card_list_to_hand_response;
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UseGameHandEnvironmentCardResponse {
+    found_environment_card_id: i32,
+}
+
+impl UseGameHandEnvironmentCardResponse {
+    pub fn new(found_environment_card_id: i32) -> Self {
+        UseGameHandEnvironmentCardResponse { found_environment_card_id }
+    }
+
+    pub fn get_found_environment_card_id(&self) -> i32 {
+        self.found_environment_card_id
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UseGameHandTrapCardResponse {
+    found_trap_card_id: i32,
+}
+
+impl UseGameHandTrapCardResponse {
+    pub fn new(found_trap_card_id: i32) -> Self {
+        UseGameHandTrapCardResponse { found_trap_card_id }
+    }
+
+    pub fn get_found_trap_card_id(&self) -> i32 {
+        self.found_trap_card_id
+    }
+}
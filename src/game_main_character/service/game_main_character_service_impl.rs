@@ -3,6 +3,10 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
+use crate::game_event_bus::entity::game_event::GameEvent;
+use crate::game_event_bus::entity::game_event_type::GameEventType;
+use crate::game_event_bus::repository::game_event_bus_repository::GameEventBusRepository;
+use crate::game_event_bus::repository::game_event_bus_repository_impl::GameEventBusRepositoryImpl;
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum::{Death, Survival};
 use crate::game_main_character::repository::game_main_character_repository::GameMainCharacterRepository;
 use crate::game_main_character::repository::game_main_character_repository_impl::GameMainCharacterRepositoryImpl;
@@ -43,10 +47,21 @@ impl GameMainCharacterService for GameMainCharacterServiceImpl {
     async fn apply_damage_to_main_character(&self, apply_damage_to_main_character_request: ApplyDamageToMainCharacterRequest) -> ApplyDamageToMainCharacterResponse {
         println!("GameMainCharacterServiceImpl: apply_damage_to_main_character()");
 
+        let account_unique_id = apply_damage_to_main_character_request.get_account_unique_id();
+        let damage = apply_damage_to_main_character_request.get_damage();
+
         let mut game_main_character_repository_guard = self.game_main_character_repository.lock().await;
         let apply_damage_result = game_main_character_repository_guard.apply_damage_to_main_character(
-            apply_damage_to_main_character_request.get_account_unique_id(),
-            apply_damage_to_main_character_request.get_damage());
+            account_unique_id,
+            damage);
+        drop(game_main_character_repository_guard);
+
+        if apply_damage_result {
+            let game_event_bus_repository_mutex = GameEventBusRepositoryImpl::get_instance();
+            let mut game_event_bus_repository_guard = game_event_bus_repository_mutex.lock().await;
+            game_event_bus_repository_guard.publish_game_event(
+                GameEvent::new(GameEventType::MainCharacterDamaged, account_unique_id, -1, -1, damage));
+        }
 
         ApplyDamageToMainCharacterResponse::new(apply_damage_result)
     }
@@ -21,6 +21,8 @@ use crate::game_protocol_validation::service::request::is_it_item_card_request::
 use crate::game_protocol_validation::service::request::is_it_support_card_request::IsItSupportCardRequest;
 use crate::game_protocol_validation::service::request::is_it_unit_card_request::IsItUnitCardRequest;
 use crate::game_protocol_validation::service::request::is_it_tool_card_request::IsItToolCardRequest;
+use crate::game_protocol_validation::service::request::is_it_trap_card_request::IsItTrapCardRequest;
+use crate::game_protocol_validation::service::request::is_it_environment_card_request::IsItEnvironmentCardRequest;
 use crate::game_protocol_validation::service::request::is_this_your_turn_request::IsThisYourTurnRequest;
 use crate::game_protocol_validation::service::request::support_card_protocol_validation_request::SupportCardProtocolValidationRequest;
 
@@ -32,6 +34,8 @@ use crate::game_protocol_validation::service::response::is_it_item_card_response
 use crate::game_protocol_validation::service::response::is_it_support_card_response::IsItSupportCardResponse;
 use crate::game_protocol_validation::service::response::is_it_unit_card_response::IsItUnitCardResponse;
 use crate::game_protocol_validation::service::response::is_it_tool_card_response::IsItToolCardResponse;
+use crate::game_protocol_validation::service::response::is_it_trap_card_response::IsItTrapCardResponse;
+use crate::game_protocol_validation::service::response::is_it_environment_card_response::IsItEnvironmentCardResponse;
 use crate::game_protocol_validation::service::response::is_this_your_turn_response::IsThisYourTurnResponse;
 use crate::game_protocol_validation::service::response::support_card_protocol_validation_response::SupportCardProtocolValidationResponse;
 
@@ -42,9 +46,7 @@ use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepo
 
 pub struct GameProtocolValidationServiceImpl {
     game_protocol_validation_repository: Arc<AsyncMutex<GameProtocolValidationRepositoryImpl>>,
-    game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
     // account_deck_card_repository: Arc<AsyncMutex<AccountDeckCardRepositoryImpl>>,
-    game_turn_repository: Arc<AsyncMutex<GameTurnRepositoryImpl>>,
     game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
     card_kinds_repository: Arc<AsyncMutex<CardKindsRepositoryImpl>>,
     card_grade_repository: Arc<AsyncMutex<CardGradeRepositoryImpl>>,
@@ -53,9 +55,7 @@ pub struct GameProtocolValidationServiceImpl {
 
 impl GameProtocolValidationServiceImpl {
     pub fn new(game_protocol_validation_repository: Arc<AsyncMutex<GameProtocolValidationRepositoryImpl>>,
-               game_hand_repository: Arc<AsyncMutex<GameHandRepositoryImpl>>,
                // account_deck_card_repository: Arc<AsyncMutex<AccountDeckCardRepositoryImpl>>,
-               game_turn_repository: Arc<AsyncMutex<GameTurnRepositoryImpl>>,
                game_round_repository: Arc<AsyncMutex<GameRoundRepositoryImpl>>,
                card_kinds_repository: Arc<AsyncMutex<CardKindsRepositoryImpl>>,
                card_grade_repository: Arc<AsyncMutex<CardGradeRepositoryImpl>>,
@@ -63,9 +63,7 @@ impl GameProtocolValidationServiceImpl {
 
         GameProtocolValidationServiceImpl {
             game_protocol_validation_repository,
-            game_hand_repository,
             // account_deck_card_repository,
-            game_turn_repository,
             game_round_repository,
             card_kinds_repository,
             card_grade_repository,
@@ -80,9 +78,7 @@ impl GameProtocolValidationServiceImpl {
                     AsyncMutex::new(
                         GameProtocolValidationServiceImpl::new(
                             GameProtocolValidationRepositoryImpl::get_instance(),
-                            GameHandRepositoryImpl::get_instance(),
                             // AccountDeckCardRepositoryImpl::get_instance(),
-                            GameTurnRepositoryImpl::get_instance(),
                             GameRoundRepositoryImpl::get_instance(),
                             CardKindsRepositoryImpl::get_instance(),
                             CardGradeRepositoryImpl::get_instance(),
@@ -128,7 +124,8 @@ impl GameProtocolValidationService for GameProtocolValidationServiceImpl {
 
     // TODO: 확장성을 고려하여 추후 아래의 check_card_from_hand 로 교체 작업 필요
     async fn check_protocol_hacking(&mut self, support_card_protocol_validation_request: CheckProtocolHackingRequest) -> CheckProtocolHackingResponse {
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let game_hand = game_hand_repository_guard.get_game_hand_map().get(&support_card_protocol_validation_request.get_account_unique_id());
 
         if game_hand.is_none() {
@@ -160,7 +157,8 @@ impl GameProtocolValidationService for GameProtocolValidationServiceImpl {
         let account_session_id = check_cards_from_hand_request.get_account_session_id();
         let account_unique_id = self.get_account_unique_id(account_session_id).await;
 
-        let mut game_hand_repository_guard = self.game_hand_repository.lock().await;
+        let game_hand_repository_mutex = GameHandRepositoryImpl::get_instance();
+        let mut game_hand_repository_guard = game_hand_repository_mutex.lock().await;
         let game_hand = game_hand_repository_guard.get_game_hand_map().get(&account_unique_id);
 
         if game_hand.is_none() {
@@ -263,12 +261,35 @@ impl GameProtocolValidationService for GameProtocolValidationServiceImpl {
         IsItToolCardResponse::new(false)
     }
 
+    async fn is_it_trap_card(&self, is_it_trap_card_request: IsItTrapCardRequest) -> IsItTrapCardResponse {
+        println!("GameProtocolValidationServiceImpl: is_it_trap_card()");
+
+        let trap_card_id = is_it_trap_card_request.get_trap_card_id();
+
+        let card_kinds_repository_guard = self.card_kinds_repository.lock().await;
+        let maybe_trap_card = card_kinds_repository_guard.get_card_kind(&trap_card_id).await;
+
+        IsItTrapCardResponse::new(maybe_trap_card == KindsEnum::Trap)
+    }
+
+    async fn is_it_environment_card(&self, is_it_environment_card_request: IsItEnvironmentCardRequest) -> IsItEnvironmentCardResponse {
+        println!("GameProtocolValidationServiceImpl: is_it_environment_card()");
+
+        let environment_card_id = is_it_environment_card_request.get_environment_card_id();
+
+        let card_kinds_repository_guard = self.card_kinds_repository.lock().await;
+        let maybe_environment_card = card_kinds_repository_guard.get_card_kind(&environment_card_id).await;
+
+        IsItEnvironmentCardResponse::new(maybe_environment_card == KindsEnum::Environment)
+    }
+
     async fn is_this_your_turn(&self, is_it_tool_card_request: IsThisYourTurnRequest) -> IsThisYourTurnResponse {
         println!("GameProtocolValidationServiceImpl: is_this_your_turn_request()");
 
         let account_index_number = is_it_tool_card_request.get_account_unique_id_for_validation();
 
-        let mut game_turn_repository_guard = self.game_turn_repository.lock().await;
+        let game_turn_repository_mutex = GameTurnRepositoryImpl::get_instance();
+        let mut game_turn_repository_guard = game_turn_repository_mutex.lock().await;
         let account_unique_game_turn = game_turn_repository_guard
             .get_game_turn_map()
             .get(&account_index_number)
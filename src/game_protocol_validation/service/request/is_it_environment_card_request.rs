@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct IsItEnvironmentCardRequest {
+    environment_card_id: i32,
+}
+
+impl IsItEnvironmentCardRequest {
+    pub fn new(environment_card_id: i32) -> Self {
+        IsItEnvironmentCardRequest {
+            environment_card_id
+        }
+    }
+
+    pub fn get_environment_card_id(&self) -> i32 {
+        self.environment_card_id
+    }
+}
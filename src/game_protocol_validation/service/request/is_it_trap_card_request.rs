@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct IsItTrapCardRequest {
+    trap_card_id: i32,
+}
+
+impl IsItTrapCardRequest {
+    pub fn new(trap_card_id: i32) -> Self {
+        IsItTrapCardRequest {
+            trap_card_id
+        }
+    }
+
+    pub fn get_trap_card_id(&self) -> i32 {
+        self.trap_card_id
+    }
+}
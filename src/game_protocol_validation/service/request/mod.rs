@@ -7,4 +7,6 @@ pub mod check_cards_from_hand_request;
 pub mod is_it_energy_card_request;
 pub mod is_it_item_card_request;
 pub mod is_it_tool_card_request;
+pub mod is_it_trap_card_request;
+pub mod is_it_environment_card_request;
 pub mod is_this_your_turn_request;
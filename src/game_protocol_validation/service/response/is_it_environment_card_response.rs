@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct IsItEnvironmentCardResponse {
+    is_success: bool,
+}
+
+impl IsItEnvironmentCardResponse {
+    pub fn new(is_success: bool) -> Self {
+        IsItEnvironmentCardResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
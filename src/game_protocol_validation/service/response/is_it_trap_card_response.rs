@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct IsItTrapCardResponse {
+    is_success: bool,
+}
+
+impl IsItTrapCardResponse {
+    pub fn new(is_success: bool) -> Self {
+        IsItTrapCardResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
@@ -4,6 +4,7 @@ use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
 
+use crate::battle_room_actor::entity::battle_room_state::BattleRoomState;
 use crate::game_tomb::entity::game_tomb::GameTomb;
 use crate::game_tomb::repository::game_tomb_repository::GameTombRepository;
 
@@ -23,6 +24,11 @@ impl GameTombRepositoryImpl {
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<GameTombRepositoryImpl>> {
+        // 배틀 룸 Actor 안에서는 그 방이 소유한 저장소를 사용
+        if let Some(battle_room_state) = BattleRoomState::current() {
+            return battle_room_state.get_game_tomb_repository()
+        }
+
         lazy_static! {
             static ref INSTANCE: Arc<AsyncMutex<GameTombRepositoryImpl>> =
                 Arc::new(
@@ -3,6 +3,8 @@ use async_trait::async_trait;
 use lazy_static::lazy_static;
 
 use tokio::sync::Mutex as AsyncMutex;
+use crate::game_card_token::repository::game_card_token_repository::GameCardTokenRepository;
+use crate::game_card_token::repository::game_card_token_repository_impl::GameCardTokenRepositoryImpl;
 use crate::game_tomb::repository::game_tomb_repository::GameTombRepository;
 
 use crate::game_tomb::repository::game_tomb_repository_impl::GameTombRepositoryImpl;
@@ -14,14 +16,14 @@ use crate::game_tomb::service::response::add_dead_unit_list_to_tomb_response::Ad
 use crate::game_tomb::service::response::place_to_tomb_response::PlaceToTombResponse;
 
 pub struct GameTombServiceImpl {
-    game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>,
+    game_card_token_repository: Arc<AsyncMutex<GameCardTokenRepositoryImpl>>,
 }
 
 impl GameTombServiceImpl {
-    pub fn new(game_tomb_repository: Arc<AsyncMutex<GameTombRepositoryImpl>>) -> Self {
+    pub fn new(game_card_token_repository: Arc<AsyncMutex<GameCardTokenRepositoryImpl>>) -> Self {
 
         GameTombServiceImpl {
-            game_tomb_repository,
+            game_card_token_repository,
         }
     }
 
@@ -31,10 +33,16 @@ impl GameTombServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         GameTombServiceImpl::new(
-                            GameTombRepositoryImpl::get_instance())));
+                            GameCardTokenRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
     }
+
+    // 토큰은 사망 시 무덤으로 가지 않고 그대로 사라짐
+    async fn is_token_card(&self, card_id: i32) -> bool {
+        let game_card_token_repository_guard = self.game_card_token_repository.lock().await;
+        game_card_token_repository_guard.is_token_unit(card_id)
+    }
 }
 
 #[async_trait]
@@ -42,7 +50,8 @@ impl GameTombService for GameTombServiceImpl {
     async fn add_used_card_to_tomb(&mut self, place_to_tomb_request: PlaceToTombRequest) -> PlaceToTombResponse {
         println!("GameTombServiceImpl: add_used_card_to_tomb()");
 
-        let mut game_tomb_repository_guard = self.game_tomb_repository.lock().await;
+        let game_tomb_repository_mutex = GameTombRepositoryImpl::get_instance();
+        let mut game_tomb_repository_guard = game_tomb_repository_mutex.lock().await;
         let place_to_tomb_response = game_tomb_repository_guard.add_used_card_to_tomb(
             place_to_tomb_request.get_account_unique_id(), place_to_tomb_request.get_used_card_id());
 
@@ -51,7 +60,12 @@ impl GameTombService for GameTombServiceImpl {
     async fn add_dead_unit_to_tomb(&mut self, place_to_tomb_request: PlaceToTombRequest) -> PlaceToTombResponse {
         println!("GameTombServiceImpl: add_dead_unit_to_tomb()");
 
-        let mut game_tomb_repository_guard = self.game_tomb_repository.lock().await;
+        if self.is_token_card(place_to_tomb_request.get_used_card_id()).await {
+            return PlaceToTombResponse::new(true)
+        }
+
+        let game_tomb_repository_mutex = GameTombRepositoryImpl::get_instance();
+        let mut game_tomb_repository_guard = game_tomb_repository_mutex.lock().await;
         let place_to_tomb_response = game_tomb_repository_guard.add_dead_unit_to_tomb(
             place_to_tomb_request.get_account_unique_id(), place_to_tomb_request.get_used_card_id());
 
@@ -61,9 +75,17 @@ impl GameTombService for GameTombServiceImpl {
     async fn add_dead_unit_list_to_tomb(&mut self, add_dead_unit_list_to_tomb_request: AddDeadUnitListToTombRequest) -> AddDeadUnitListToTombResponse {
         println!("GameTombServiceImpl: add_dead_unit_list_to_tomb()");
 
-        let mut game_tomb_repository_guard = self.game_tomb_repository.lock().await;
         let dead_unit_list = add_dead_unit_list_to_tomb_request.get_dead_unit_list().clone();
+        let mut dead_unit_list_to_tomb = Vec::new();
         for dead_unit in dead_unit_list {
+            if !self.is_token_card(dead_unit).await {
+                dead_unit_list_to_tomb.push(dead_unit);
+            }
+        }
+
+        let game_tomb_repository_mutex = GameTombRepositoryImpl::get_instance();
+        let mut game_tomb_repository_guard = game_tomb_repository_mutex.lock().await;
+        for dead_unit in dead_unit_list_to_tomb {
             let result =
                 game_tomb_repository_guard.add_dead_unit_to_tomb(
                     add_dead_unit_list_to_tomb_request.get_account_unique_id(), dead_unit);
@@ -8,4 +8,6 @@ pub trait  GameTurnController {
 
     async fn request_turn_end(
         &self, turn_end_request_form: TurnEndRequestForm) -> TurnEndResponseForm;
-}
\ No newline at end of file
+    async fn request_turn_end_by_time_out(
+        &self, battle_room_id: i32) -> TurnEndResponseForm;
+}
@@ -10,6 +10,8 @@ use crate::action_waiting_timer::service::action_waiting_timer_service_impl::Act
 use crate::battle_room::service::battle_room_service::BattleRoomService;
 use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
 use crate::game_card_passive_skill::entity::passive_skill_casting_condition::PassiveSkillCastingCondition::TurnStart;
+use crate::game_card_environment::service::game_card_environment_service::GameCardEnvironmentService;
+use crate::game_card_environment::service::game_card_environment_service_impl::GameCardEnvironmentServiceImpl;
 use crate::game_card_passive_skill::service::game_card_passive_skill_service::GameCardPassiveSkillService;
 use crate::game_card_passive_skill::service::game_card_passive_skill_service_impl::GameCardPassiveSkillServiceImpl;
 use crate::game_card_support_usage_counter::service::game_card_support_usage_counter_service::GameCardSupportUsageCounterService;
@@ -18,10 +20,13 @@ use crate::game_card_unit::service::game_card_unit_service::GameCardUnitService;
 use crate::game_card_unit::service::game_card_unit_service_impl::GameCardUnitServiceImpl;
 
 use crate::game_deck::service::game_deck_service::GameDeckService;
+use crate::game_card_token::controller::game_card_token_controller::GameCardTokenController;
+use crate::game_card_token::controller::game_card_token_controller_impl::GameCardTokenControllerImpl;
+use crate::game_event_bus::service::game_event_bus_service::GameEventBusService;
+use crate::game_event_bus::service::game_event_bus_service_impl::GameEventBusServiceImpl;
 use crate::game_deck::service::game_deck_service_impl::GameDeckServiceImpl;
 use crate::game_field_energy::service::game_field_energy_service::GameFieldEnergyService;
 use crate::game_field_energy::service::game_field_energy_service_impl::GameFieldEnergyServiceImpl;
-use crate::game_field_unit::entity::extra_effect::ExtraEffect::Freeze;
 use crate::game_field_unit::service::game_field_unit_service::GameFieldUnitService;
 use crate::game_field_unit::service::game_field_unit_service_impl::GameFieldUnitServiceImpl;
 use crate::game_hand::service::game_hand_service::GameHandService;
@@ -52,6 +57,7 @@ use crate::game_winner_check::service::game_winner_check_service_impl::GameWinne
 use crate::notify_player_action_info::service::notify_player_action_info_service::NotifyPlayerActionInfoService;
 use crate::notify_player_action_info::service::notify_player_action_info_service_impl::NotifyPlayerActionInfoServiceImpl;
 use crate::ui_data_generator::service::ui_data_generator_service::UiDataGeneratorService;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
 use crate::ui_data_generator::service::ui_data_generator_service_impl::UiDataGeneratorServiceImpl;
 
 pub struct GameTurnControllerImpl {
@@ -73,6 +79,9 @@ pub struct GameTurnControllerImpl {
     notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
     game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
     game_card_passive_skill_service: Arc<AsyncMutex<GameCardPassiveSkillServiceImpl>>,
+    game_card_environment_service: Arc<AsyncMutex<GameCardEnvironmentServiceImpl>>,
+    game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+    game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
 }
 
 impl GameTurnControllerImpl {
@@ -94,6 +103,9 @@ impl GameTurnControllerImpl {
                notify_player_action_info_service: Arc<AsyncMutex<NotifyPlayerActionInfoServiceImpl>>,
                game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
                game_card_passive_skill_service: Arc<AsyncMutex<GameCardPassiveSkillServiceImpl>>,
+               game_card_environment_service: Arc<AsyncMutex<GameCardEnvironmentServiceImpl>>,
+               game_event_bus_service: Arc<AsyncMutex<GameEventBusServiceImpl>>,
+               game_card_token_controller: Arc<AsyncMutex<GameCardTokenControllerImpl>>,
              ) -> Self {
 
         GameTurnControllerImpl {
@@ -115,6 +127,9 @@ impl GameTurnControllerImpl {
             notify_player_action_info_service,
             game_winner_check_service,
             game_card_passive_skill_service,
+            game_card_environment_service,
+            game_event_bus_service,
+            game_card_token_controller,
         }
     }
     pub fn get_instance() -> Arc<AsyncMutex<GameTurnControllerImpl>> {
@@ -140,7 +155,10 @@ impl GameTurnControllerImpl {
                             UiDataGeneratorServiceImpl::get_instance(),
                             NotifyPlayerActionInfoServiceImpl::get_instance(),
                             GameWinnerCheckServiceImpl::get_instance(),
-                            GameCardPassiveSkillServiceImpl::get_instance())));
+                            GameCardPassiveSkillServiceImpl::get_instance(),
+                            GameCardEnvironmentServiceImpl::get_instance(),
+                            GameEventBusServiceImpl::get_instance(),
+                            GameCardTokenControllerImpl::get_instance())));
         }
         INSTANCE.clone()
     }
@@ -152,57 +170,42 @@ impl GameTurnControllerImpl {
         let value_string = session_validation_response.get_value();
         value_string.parse::<i32>().unwrap_or_else(|_| { -1 })
     }
-}
-
-#[async_trait]
-impl GameTurnController for GameTurnControllerImpl {
-    async fn request_turn_end(&self, turn_end_request_form: TurnEndRequestForm) -> TurnEndResponseForm {
-        // 세션 검증
-        let account_unique_id =
-            self.is_valid_session(turn_end_request_form.to_session_validation_request()).await;
 
-        if account_unique_id == -1 {
-            println!("Invalid session");
-            return TurnEndResponseForm::default()
-        }
-
-        // opponent unique id 찾기
-        let battle_room_service_guard =
-            self.battle_room_service.lock().await;
-
-        let opponent_unique_id =
-            battle_room_service_guard.find_opponent_by_account_unique_id(
-                turn_end_request_form
-                    .to_find_opponent_by_account_id_request(
-                        account_unique_id)).await.get_opponent_unique_id();
-
-        drop(battle_room_service_guard);
-
-        // 현재 요청한 사람이 이번 턴의 주도권을 가지고 있던 사람인지 검증
-        // TODO: 다음 과정은 모든 액션에 추가되어야 함
-        let mut game_protocol_validation_service_guard =
-            self.game_protocol_validation_service.lock().await;
+    // 턴 종료 요청과 턴 시간 초과가 공유하는 턴 종료 처리 과정
+    async fn end_turn(&self,
+                      turn_end_request_form: &TurnEndRequestForm,
+                      account_unique_id: i32,
+                      opponent_unique_id: i32,
+                      is_turn_timed_out: bool) -> TurnEndResponseForm {
 
-        let is_this_your_turn_response =
-            game_protocol_validation_service_guard.is_this_your_turn(
-                turn_end_request_form.to_is_this_your_turn_request(account_unique_id)).await;
+        // 턴 종료 이벤트를 가장 먼저 발행하여 이후 상태 효과로 인한 사망 이벤트보다 앞서 처리되도록 함
+        let mut game_event_bus_service_guard =
+            self.game_event_bus_service.lock().await;
 
-        if !is_this_your_turn_response.is_success() {
-            return TurnEndResponseForm::default()
-        }
+        game_event_bus_service_guard.publish_turn_end_game_event(
+            turn_end_request_form
+                .to_publish_turn_end_game_event_request(account_unique_id)).await;
 
-        drop(game_protocol_validation_service_guard);
+        drop(game_event_bus_service_guard);
 
-        // 자신의 필드 유닛들 중 턴 종료 시 데미지를 받는 케이스를 적용 (예: 화상 데미지)
+        // 자신의 필드 유닛들 중 턴 종료 시 발동하는 상태 효과를 적용 (예: 화상 데미지)
         let mut game_field_unit_service_guard =
             self.game_field_unit_service.lock().await;
 
-        game_field_unit_service_guard.apply_status_effect_damage_iteratively(
+        game_field_unit_service_guard.tick_status_effect_of_all_unit(
             turn_end_request_form
-                .to_apply_status_effect_damage_iteratively_request(
+                .to_tick_turn_end_status_effect_request(
                     account_unique_id)).await;
 
-        let health_point_of_all_unit =
+        // 턴 단위 상태 효과는 누구의 턴이든 양 플레이어 유닛 모두 지속 시간 감소
+        for player_unique_id in [account_unique_id, opponent_unique_id] {
+            game_field_unit_service_guard.decrease_turn_based_status_duration_of_all_unit(
+                turn_end_request_form
+                    .to_decrease_turn_based_status_duration_of_all_unit_request(
+                        player_unique_id)).await;
+        }
+
+        let mut health_point_of_all_unit =
             game_field_unit_service_guard.get_current_health_point_of_all_field_unit(
                 turn_end_request_form
                     .to_get_current_health_point_of_all_field_unit_request(
@@ -214,6 +217,12 @@ impl GameTurnController for GameTurnControllerImpl {
                     .to_acquire_harmful_status_effect_of_all_unit_request(
                         account_unique_id)).await.get_harmful_effect_list_of_all_unit();
 
+        let extra_effect_of_all_unit =
+            game_field_unit_service_guard.acquire_extra_effect_of_all_unit(
+                turn_end_request_form
+                    .to_acquire_extra_effect_of_all_unit_request(
+                        account_unique_id)).await.get_extra_effect_list_of_all_unit();
+
         let judge_death_of_every_field_unit_response =
             game_field_unit_service_guard.judge_death_of_every_field_unit(
                 turn_end_request_form
@@ -269,6 +278,99 @@ impl GameTurnController for GameTurnControllerImpl {
 
         drop(game_card_unit_service_guard);
 
+        // 상대 필드 유닛들 중 턴 시작 시 발동하는 상태 효과를 적용 (예: 독 데미지, 재생)
+        game_field_unit_service_guard.tick_status_effect_of_all_unit(
+            turn_end_request_form
+                .to_tick_turn_start_status_effect_request(
+                    opponent_unique_id)).await;
+
+        let mut opponent_health_point_of_all_unit =
+            game_field_unit_service_guard.get_current_health_point_of_all_field_unit(
+                turn_end_request_form
+                    .to_get_current_health_point_of_all_field_unit_request(
+                        opponent_unique_id)).await.get_current_unit_health_point().clone();
+
+        let opponent_harmful_effect_of_all_unit =
+            game_field_unit_service_guard.acquire_harmful_status_effect_of_all_unit(
+                turn_end_request_form
+                    .to_acquire_harmful_status_effect_of_all_unit_request(
+                        opponent_unique_id)).await.get_harmful_effect_list_of_all_unit();
+
+        let opponent_extra_effect_of_all_unit =
+            game_field_unit_service_guard.acquire_extra_effect_of_all_unit(
+                turn_end_request_form
+                    .to_acquire_extra_effect_of_all_unit_request(
+                        opponent_unique_id)).await.get_extra_effect_list_of_all_unit();
+
+        let judge_death_of_every_opponent_field_unit_response =
+            game_field_unit_service_guard.judge_death_of_every_field_unit(
+                turn_end_request_form
+                    .to_judge_death_of_every_unit_request(opponent_unique_id)).await;
+
+        if !judge_death_of_every_opponent_field_unit_response.get_dead_unit_id_list().is_empty() {
+            let mut game_tomb_service_guard =
+                self.game_tomb_service.lock().await;
+
+            game_tomb_service_guard.add_dead_unit_list_to_tomb(
+                turn_end_request_form.to_add_dead_unit_list_to_tomb_request(
+                    opponent_unique_id,
+                    judge_death_of_every_opponent_field_unit_response.get_dead_unit_id_list())).await;
+
+            drop(game_tomb_service_guard);
+        }
+
+        // 턴 종료 패시브, 죽음의 메아리 등 지금까지 쌓인 이벤트에 대한 카드 반응 처리
+        let mut game_event_bus_service_guard =
+            self.game_event_bus_service.lock().await;
+
+        let process_pending_game_event_response =
+            game_event_bus_service_guard.process_pending_game_event(
+                turn_end_request_form
+                    .to_process_pending_game_event_request(
+                        account_unique_id, opponent_unique_id)).await;
+
+        drop(game_event_bus_service_guard);
+
+        // 이벤트 반응으로 소환된 토큰 공지
+        let game_card_token_controller_guard =
+            self.game_card_token_controller.lock().await;
+
+        game_card_token_controller_guard.notice_summoned_token_unit(
+            turn_end_request_form
+                .to_notice_summoned_token_unit_request_form(
+                    account_unique_id,
+                    opponent_unique_id,
+                    process_pending_game_event_response.get_summoned_token_unit_map().clone())).await;
+
+        drop(game_card_token_controller_guard);
+
+        let mut my_dead_unit_index_list =
+            judge_death_of_every_field_unit_response.get_dead_unit_index_list();
+        my_dead_unit_index_list.extend(
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(account_unique_id));
+
+        let mut opponent_dead_unit_index_list =
+            judge_death_of_every_opponent_field_unit_response.get_dead_unit_index_list();
+        opponent_dead_unit_index_list.extend(
+            process_pending_game_event_response.get_dead_unit_index_list_of_account(opponent_unique_id));
+
+        // 이벤트 반응으로 체력이 바뀐 유닛 정보 갱신
+        if process_pending_game_event_response.get_processed_game_event_count() > 0 {
+            overwrite_health_point_list(
+                &mut health_point_of_all_unit,
+                game_field_unit_service_guard.get_current_health_point_of_all_field_unit(
+                    turn_end_request_form
+                        .to_get_current_health_point_of_all_field_unit_request(
+                            account_unique_id)).await.get_current_unit_health_point().clone());
+
+            overwrite_health_point_list(
+                &mut opponent_health_point_of_all_unit,
+                game_field_unit_service_guard.get_current_health_point_of_all_field_unit(
+                    turn_end_request_form
+                        .to_get_current_health_point_of_all_field_unit_request(
+                            opponent_unique_id)).await.get_current_unit_health_point().clone());
+        }
+
         // 상대 필드 위 턴 시작 시 패시브를 발동하는 유닛들에 대한 정보 알려주기
         // TODO: Need Refactor
         let opponent_field_unit_list =
@@ -283,7 +385,7 @@ impl GameTurnController for GameTurnControllerImpl {
 
         for (unit_index, field_unit) in opponent_field_unit_list.get_game_field_unit_card().iter().enumerate() {
             let mut turn_start_passive_skill_index_list_of_unit = Vec::new();
-            if field_unit.is_alive() && !field_unit.get_harmful_status_list().contains(&Freeze){
+            if field_unit.is_alive() && !field_unit.has_skill_blocking_status() {
                 let passive_skill_effect_list =
                     game_card_passive_skill_guard.summary_turn_start_passive_skill(
                         turn_end_request_form
@@ -333,7 +435,57 @@ impl GameTurnController for GameTurnControllerImpl {
 
         drop(game_turn_service_guard);
 
-        // 상대방이 덱에서 카드를 드로우
+        // 환경 카드 지속 턴 감소 (만료 시 주인의 무덤으로 보내고 양 플레이어에게 공지)
+        let mut game_card_environment_service_guard =
+            self.game_card_environment_service.lock().await;
+
+        let decrease_environment_remaining_turn_response =
+            game_card_environment_service_guard.decrease_environment_remaining_turn(
+                turn_end_request_form
+                    .to_decrease_environment_remaining_turn_request(
+                        account_unique_id, opponent_unique_id)).await;
+
+        let extra_draw_count =
+            game_card_environment_service_guard.get_environment_extra_draw_count(
+                turn_end_request_form
+                    .to_get_environment_extra_draw_count_request(
+                        opponent_unique_id)).await.get_extra_draw_count();
+
+        drop(game_card_environment_service_guard);
+
+        let expired_environment_card_id =
+            decrease_environment_remaining_turn_response.get_expired_environment_card_id();
+
+        if expired_environment_card_id != -1 {
+            let expired_environment_owner_unique_id =
+                decrease_environment_remaining_turn_response.get_expired_environment_owner_unique_id();
+
+            let mut game_tomb_service_guard =
+                self.game_tomb_service.lock().await;
+
+            game_tomb_service_guard.add_used_card_to_tomb(
+                turn_end_request_form
+                    .to_place_expired_environment_to_tomb_request(
+                        expired_environment_owner_unique_id, expired_environment_card_id)).await;
+
+            drop(game_tomb_service_guard);
+
+            let mut notify_player_action_info_service_guard =
+                self.notify_player_action_info_service.lock().await;
+
+            for receiver_unique_id in [account_unique_id, opponent_unique_id] {
+                notify_player_action_info_service_guard.notice_expire_environment_card(
+                    turn_end_request_form
+                        .to_notice_expire_environment_card_request(
+                            receiver_unique_id,
+                            expired_environment_owner_unique_id,
+                            expired_environment_card_id)).await;
+            }
+
+            drop(notify_player_action_info_service_guard);
+        }
+
+        // 상대방이 덱에서 카드를 드로우 (환경 효과에 따른 추가 드로우 포함)
         let mut game_deck_service_guard =
             self.game_deck_service.lock().await;
 
@@ -341,7 +493,7 @@ impl GameTurnController for GameTurnControllerImpl {
             game_deck_service_guard.draw_cards_from_deck(
                 turn_end_request_form
                     .to_draw_cards_from_deck_request(
-                        opponent_unique_id)).await.get_drawn_card_list().clone();
+                        opponent_unique_id, extra_draw_count)).await.get_drawn_card_list().clone();
 
         drop(game_deck_service_guard);
 
@@ -372,7 +524,14 @@ impl GameTurnController for GameTurnControllerImpl {
 
             notify_player_action_info_service_guard.notice_my_turn_end(
                 turn_end_request_form
-                    .to_notice_my_turn_end_in_case_of_no_more_opponent_deck(opponent_unique_id)).await;
+                    .to_notice_my_turn_end_in_case_of_no_more_opponent_deck(
+                        opponent_unique_id, is_turn_timed_out)).await;
+
+            if is_turn_timed_out {
+                notify_player_action_info_service_guard.notice_my_turn_end(
+                    turn_end_request_form
+                        .to_notice_turn_time_out_in_case_of_no_more_opponent_deck(account_unique_id)).await;
+            }
 
             drop(notify_player_action_info_service_guard);
 
@@ -389,12 +548,12 @@ impl GameTurnController for GameTurnControllerImpl {
 
         drop(game_hand_service_guard);
 
-        // 상대방이 필드에너지 획득
+        // 상대방이 필드에너지 획득 (환경 효과에 따른 추가 에너지 포함)
         let game_field_energy_service_guard =
             self.game_field_energy_service.lock().await;
 
-        game_field_energy_service_guard.add_field_energy_with_amount(
-                turn_end_request_form.to_add_field_energy_request(opponent_unique_id)).await;
+        game_field_energy_service_guard.add_turn_start_field_energy(
+                turn_end_request_form.to_add_turn_start_field_energy_request(opponent_unique_id)).await;
 
         let remaining_opponent_field_energy =
             game_field_energy_service_guard.get_current_field_energy(
@@ -404,7 +563,20 @@ impl GameTurnController for GameTurnControllerImpl {
 
         drop(game_field_energy_service_guard);
 
-        // TODO: Action Timer Setting
+        // 상대방의 턴 제한 시간 측정 시작
+        let action_waiting_timer_service_guard =
+            self.action_waiting_timer_service.lock().await;
+
+        action_waiting_timer_service_guard.set_action_waiting_time(
+            turn_end_request_form.to_action_waiting_timer(opponent_unique_id)).await;
+
+        let remaining_turn_time_seconds =
+            action_waiting_timer_service_guard.get_remaining_action_waiting_time(
+                turn_end_request_form
+                    .to_get_remaining_action_waiting_time_request(
+                        opponent_unique_id)).await.get_remaining_seconds();
+
+        drop(action_waiting_timer_service_guard);
 
         let mut ui_data_generator_service_guard =
             self.ui_data_generator_service.lock().await;
@@ -429,14 +601,72 @@ impl GameTurnController for GameTurnControllerImpl {
                 turn_end_request_form
                     .to_generate_my_multiple_unit_harmful_effect_data_request(harmful_effect_of_all_unit)).await;
 
+        let generate_my_multiple_unit_extra_effect_data_response =
+            ui_data_generator_service_guard.generate_my_multiple_unit_extra_effect_data(
+                turn_end_request_form
+                    .to_generate_my_multiple_unit_extra_effect_data_request(extra_effect_of_all_unit)).await;
+
         let generate_my_multiple_unit_death_data_response =
             ui_data_generator_service_guard.generate_my_multiple_unit_death_data(
                 turn_end_request_form
                     .to_generate_my_multiple_unit_death_data_request(
-                        judge_death_of_every_field_unit_response.get_dead_unit_index_list())).await;
+                        my_dead_unit_index_list)).await;
+
+        let generate_opponent_multiple_unit_health_point_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_health_point_data(
+                turn_end_request_form
+                    .to_generate_opponent_multiple_unit_health_point_data_request(
+                        opponent_health_point_of_all_unit)).await;
+
+        let generate_opponent_multiple_unit_harmful_effect_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_harmful_effect_data(
+                turn_end_request_form
+                    .to_generate_opponent_multiple_unit_harmful_effect_data_request(
+                        opponent_harmful_effect_of_all_unit)).await;
+
+        let generate_opponent_multiple_unit_extra_effect_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_extra_effect_data(
+                turn_end_request_form
+                    .to_generate_opponent_multiple_unit_extra_effect_data_request(
+                        opponent_extra_effect_of_all_unit)).await;
+
+        let generate_opponent_multiple_unit_death_data_response =
+            ui_data_generator_service_guard.generate_opponent_multiple_unit_death_data(
+                turn_end_request_form
+                    .to_generate_opponent_multiple_unit_death_data_request(
+                        opponent_dead_unit_index_list)).await;
 
         drop(ui_data_generator_service_guard);
 
+        // 턴을 넘긴 플레이어의 유닛 (턴 종료 효과) 과 턴을 시작하는 플레이어의 유닛 (턴 시작 효과) 정보를 병합
+        let player_field_unit_health_point_map_for_notice = merge_player_index_map(
+            generate_my_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_notice(),
+            generate_opponent_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_notice());
+        let player_field_unit_health_point_map_for_response = merge_player_index_map(
+            generate_my_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_response(),
+            generate_opponent_multiple_unit_health_point_data_response.get_player_field_unit_health_point_map_for_response());
+
+        let player_field_unit_harmful_effect_map_for_notice = merge_player_index_map(
+            generate_my_multiple_unit_harmful_effect_data_response.get_player_field_unit_harmful_effect_map_for_notice(),
+            generate_opponent_multiple_unit_harmful_effect_data_response.get_player_field_unit_harmful_effect_map_for_notice());
+        let player_field_unit_harmful_effect_map_for_response = merge_player_index_map(
+            generate_my_multiple_unit_harmful_effect_data_response.get_player_field_unit_harmful_effect_map_for_response(),
+            generate_opponent_multiple_unit_harmful_effect_data_response.get_player_field_unit_harmful_effect_map_for_response());
+
+        let player_field_unit_extra_effect_map_for_notice = merge_player_index_map(
+            generate_my_multiple_unit_extra_effect_data_response.get_player_field_unit_extra_effect_map_for_notice(),
+            generate_opponent_multiple_unit_extra_effect_data_response.get_player_field_unit_extra_effect_map_for_notice());
+        let player_field_unit_extra_effect_map_for_response = merge_player_index_map(
+            generate_my_multiple_unit_extra_effect_data_response.get_player_field_unit_extra_effect_map_for_response(),
+            generate_opponent_multiple_unit_extra_effect_data_response.get_player_field_unit_extra_effect_map_for_response());
+
+        let player_field_unit_death_map_for_notice = merge_player_index_map(
+            generate_my_multiple_unit_death_data_response.get_player_field_unit_death_map_for_notice(),
+            generate_opponent_multiple_unit_death_data_response.get_player_field_unit_death_map_for_notice());
+        let player_field_unit_death_map_for_response = merge_player_index_map(
+            generate_my_multiple_unit_death_data_response.get_player_field_unit_death_map_for_response(),
+            generate_opponent_multiple_unit_death_data_response.get_player_field_unit_death_map_for_response());
+
         let mut notify_player_action_info_service_guard =
             self.notify_player_action_info_service.lock().await;
 
@@ -448,21 +678,175 @@ impl GameTurnController for GameTurnControllerImpl {
                         .get_player_drawn_card_list_map_for_notice().clone(),
                     generate_opponent_field_energy_data_response
                         .get_player_field_energy_map_for_notice().clone(),
-                    generate_my_multiple_unit_health_point_data_response
-                        .get_player_field_unit_health_point_map_for_notice().clone(),
-                    generate_my_multiple_unit_harmful_effect_data_response
-                        .get_player_field_unit_harmful_effect_map_for_notice().clone(),
-                    generate_my_multiple_unit_death_data_response
-                        .get_player_field_unit_death_map_for_notice().clone(),
-                    turn_start_passive_skill_list_of_unit_index_map)).await;
+                    player_field_unit_health_point_map_for_notice,
+                    player_field_unit_harmful_effect_map_for_notice,
+                    player_field_unit_extra_effect_map_for_notice,
+                    player_field_unit_death_map_for_notice,
+                    turn_start_passive_skill_list_of_unit_index_map,
+                    is_turn_timed_out,
+                    remaining_turn_time_seconds)).await;
+
+        // 시간 초과로 턴이 넘어갔다면 턴을 놓친 플레이어에게도 결과를 알림
+        if is_turn_timed_out {
+            notify_player_action_info_service_guard.notice_my_turn_end(
+                turn_end_request_form
+                    .to_notice_turn_time_out_request(
+                        account_unique_id,
+                        generate_draw_opponent_deck_data_response
+                            .get_player_drawn_card_count_map_for_response().clone(),
+                        generate_opponent_field_energy_data_response
+                            .get_player_field_energy_map_for_response().clone(),
+                        player_field_unit_health_point_map_for_response.clone(),
+                        player_field_unit_harmful_effect_map_for_response.clone(),
+                        player_field_unit_extra_effect_map_for_response.clone(),
+                        player_field_unit_death_map_for_response.clone(),
+                        HashMap::new(),
+                        remaining_turn_time_seconds)).await;
+        }
 
         drop(notify_player_action_info_service_guard);
 
         TurnEndResponseForm::from_response(
             generate_draw_opponent_deck_data_response,
             generate_opponent_field_energy_data_response,
-            generate_my_multiple_unit_health_point_data_response,
-            generate_my_multiple_unit_harmful_effect_data_response,
-            generate_my_multiple_unit_death_data_response)
+            player_field_unit_health_point_map_for_response,
+            player_field_unit_harmful_effect_map_for_response,
+            player_field_unit_extra_effect_map_for_response,
+            player_field_unit_death_map_for_response,
+            remaining_turn_time_seconds)
+    }
+}
+
+fn merge_player_index_map<T: Clone>(my_side_map: &HashMap<PlayerIndex, T>,
+                                    opponent_side_map: &HashMap<PlayerIndex, T>) -> HashMap<PlayerIndex, T> {
+    let mut merged_map = my_side_map.clone();
+    for (player_index, info) in opponent_side_map {
+        merged_map.insert(player_index.clone(), info.clone());
+    }
+    merged_map
+}
+
+// 갱신 목록에 없는 (이미 사망한) 유닛은 기존 체력 정보를 유지
+fn overwrite_health_point_list(health_point_list: &mut Vec<(i32, i32)>,
+                               refreshed_health_point_list: Vec<(i32, i32)>) {
+    for (unit_index, health_point) in refreshed_health_point_list {
+        match health_point_list.iter_mut().find(|(index, _)| *index == unit_index) {
+            Some(health_point_info) => health_point_info.1 = health_point,
+            None => health_point_list.push((unit_index, health_point)),
+        }
+    }
+}
+
+#[async_trait]
+impl GameTurnController for GameTurnControllerImpl {
+    async fn request_turn_end(&self, turn_end_request_form: TurnEndRequestForm) -> TurnEndResponseForm {
+        // 세션 검증
+        let account_unique_id =
+            self.is_valid_session(turn_end_request_form.to_session_validation_request()).await;
+
+        if account_unique_id == -1 {
+            println!("Invalid session");
+            return TurnEndResponseForm::default()
+        }
+
+        // opponent unique id 찾기
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let opponent_unique_id =
+            battle_room_service_guard.find_opponent_by_account_unique_id(
+                turn_end_request_form
+                    .to_find_opponent_by_account_id_request(
+                        account_unique_id)).await.get_opponent_unique_id();
+
+        drop(battle_room_service_guard);
+
+        // 현재 요청한 사람이 이번 턴의 주도권을 가지고 있던 사람인지 검증
+        // TODO: 다음 과정은 모든 액션에 추가되어야 함
+        let mut game_protocol_validation_service_guard =
+            self.game_protocol_validation_service.lock().await;
+
+        let is_this_your_turn_response =
+            game_protocol_validation_service_guard.is_this_your_turn(
+                turn_end_request_form.to_is_this_your_turn_request(account_unique_id)).await;
+
+        if !is_this_your_turn_response.is_success() {
+            return TurnEndResponseForm::default()
+        }
+
+        drop(game_protocol_validation_service_guard);
+
+        self.end_turn(&turn_end_request_form, account_unique_id, opponent_unique_id, false).await
     }
-}
\ No newline at end of file
+
+    async fn request_turn_end_by_time_out(&self, battle_room_id: i32) -> TurnEndResponseForm {
+        println!("GameTurnControllerImpl: request_turn_end_by_time_out()");
+
+        // 서버가 직접 턴을 넘기는 것이므로 세션 없이 요청 폼을 구성
+        let turn_end_request_form = TurnEndRequestForm::new(String::new());
+
+        // 만료 감지 이후 플레이어가 턴을 종료했다면 타이머가 이미 재시작되어 있음
+        let action_waiting_timer_service_guard =
+            self.action_waiting_timer_service.lock().await;
+
+        let is_expired =
+            action_waiting_timer_service_guard.check_action_waiting_timer_expired(
+                turn_end_request_form
+                    .to_check_action_waiting_timer_expired_request(battle_room_id)).await.is_expired();
+
+        drop(action_waiting_timer_service_guard);
+
+        if !is_expired {
+            return TurnEndResponseForm::default()
+        }
+
+        let battle_room_service_guard =
+            self.battle_room_service.lock().await;
+
+        let player_list =
+            battle_room_service_guard.get_players_in_battle_room(
+                turn_end_request_form
+                    .to_get_players_in_battle_room_request(battle_room_id)).await.get_player_list().clone();
+
+        drop(battle_room_service_guard);
+
+        // 방 안에서 현재 턴의 주도권을 가진 플레이어 찾기
+        let game_protocol_validation_service_guard =
+            self.game_protocol_validation_service.lock().await;
+
+        let mut turn_owner_unique_id = None;
+
+        for &player_unique_id in player_list.iter() {
+            let is_this_your_turn_response =
+                game_protocol_validation_service_guard.is_this_your_turn(
+                    turn_end_request_form.to_is_this_your_turn_request(player_unique_id)).await;
+
+            if is_this_your_turn_response.is_success() {
+                turn_owner_unique_id = Some(player_unique_id);
+                break
+            }
+        }
+
+        drop(game_protocol_validation_service_guard);
+
+        let account_unique_id = match turn_owner_unique_id {
+            Some(account_unique_id) => account_unique_id,
+            None => {
+                println!("There is no turn owner in battle room {}", battle_room_id);
+                return TurnEndResponseForm::default()
+            }
+        };
+
+        let opponent_unique_id = match player_list.iter().find(|&&player_unique_id| player_unique_id != account_unique_id) {
+            Some(&opponent_unique_id) => opponent_unique_id,
+            None => {
+                println!("There is no opponent in battle room {}", battle_room_id);
+                return TurnEndResponseForm::default()
+            }
+        };
+
+        println!("계정 {}번의 턴 제한 시간이 초과되었습니다.", account_unique_id);
+
+        self.end_turn(&turn_end_request_form, account_unique_id, opponent_unique_id, true).await
+    }
+}
@@ -1,15 +1,26 @@
 use std::collections::HashMap;
 use crate::action_waiting_timer::service::request::action_waiting_timer_request::ActionWaitingTimerRequest;
+use crate::action_waiting_timer::service::request::check_action_waiting_timer_expired_request::CheckActionWaitingTimerExpiredRequest;
+use crate::action_waiting_timer::service::request::get_remaining_action_waiting_time_request::GetRemainingActionWaitingTimeRequest;
 use crate::battle_room::service::request::find_opponent_by_account_id_request::FindOpponentByAccountIdRequest;
+use crate::battle_room::service::request::get_players_in_battle_room_request::GetPlayersInBattleRoomRequest;
+use crate::game_card_environment::service::request::decrease_environment_remaining_turn_request::DecreaseEnvironmentRemainingTurnRequest;
+use crate::game_card_environment::service::request::get_environment_extra_draw_count_request::GetEnvironmentExtraDrawCountRequest;
 use crate::game_card_passive_skill::service::request::summary_turn_start_passive_skill_effect_request::SummaryTurnStartPassiveSkillEffectRequest;
 use crate::game_card_support_usage_counter::service::request::reset_support_card_usage_count_request::ResetSupportCardUsageCountRequest;
 use crate::game_card_unit::service::request::summary_unit_card_passive_default_request::SummaryUnitCardPassiveDefaultRequest;
 use crate::game_deck::service::request::draw_cards_from_deck_request::DrawCardsFromDeckRequest;
-use crate::game_field_unit::service::request::apply_status_effect_damage_iteratively_request::ApplyStatusEffectDamageIterativelyRequest;
+use crate::game_card_token::controller::request_form::notice_summoned_token_unit_request_form::NoticeSummonedTokenUnitRequestForm;
+use crate::game_event_bus::service::request::process_pending_game_event_request::ProcessPendingGameEventRequest;
+use crate::game_event_bus::service::request::publish_turn_end_game_event_request::PublishTurnEndGameEventRequest;
+use crate::game_field_unit::entity::status_effect_tick_timing::StatusEffectTickTiming;
+use crate::game_field_unit::service::request::tick_status_effect_of_all_unit_request::TickStatusEffectOfAllUnitRequest;
+use crate::game_field_unit::service::request::decrease_turn_based_status_duration_of_all_unit_request::DecreaseTurnBasedStatusDurationOfAllUnitRequest;
+use crate::game_field_unit::service::request::acquire_extra_effect_of_all_unit_request::AcquireExtraEffectOfAllUnitRequest;
 use crate::game_round::service::request::next_game_turn_request::NextGameRoundRequest;
 use crate::game_turn::service::request::next_turn_request::NextTurnRequest;
 use crate::redis::service::request::get_value_with_key_request::GetValueWithKeyRequest;
-use crate::game_field_energy::service::request::add_field_energy_with_amount_request::AddFieldEnergyWithAmountRequest;
+use crate::game_field_energy::service::request::add_turn_start_field_energy_request::AddTurnStartFieldEnergyRequest;
 use crate::game_field_energy::service::request::get_current_field_energy_request::GetCurrentFieldEnergyRequest;
 use crate::game_field_unit::entity::extra_effect::ExtraEffect;
 use crate::game_field_unit::service::request::acquire_harmful_status_effect_of_all_unit_request::AcquireHarmfulStatusEffectOfAllUnitRequest;
@@ -19,22 +30,32 @@ use crate::game_field_unit::service::request::judge_death_of_every_unit_request:
 use crate::game_field_unit::service::request::reset_all_passive_of_unit_request::ResetAllPassiveOfUnitRequest;
 use crate::game_field_unit::service::request::reset_turn_action_of_all_field_unit_request::ResetTurnActionOfAllFieldUnitRequest;
 use crate::game_hand::service::request::add_card_list_to_hand_request::AddCardListToHandRequest;
+use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum::Death;
 use crate::game_main_character::service::request::set_main_character_as_death_request::SetMainCharacterAsDeathRequest;
 use crate::game_protocol_validation::service::request::is_this_your_turn_request::IsThisYourTurnRequest;
 use crate::game_tomb::service::request::add_dead_unit_list_to_tomb_request::AddDeadUnitListToTombRequest;
+use crate::game_tomb::service::request::place_to_tomb_request::PlaceToTombRequest;
 use crate::game_winner_check::service::request::check_main_character_request::CheckMainCharacterRequest;
+use crate::notify_player_action_info::service::request::notice_expire_environment_card_request::NoticeExpireEnvironmentCardRequest;
 use crate::notify_player_action_info::service::request::notice_my_turn_end_request::NoticeMyTurnEndRequest;
 use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_extra_effect_info::FieldUnitExtraEffectInfo;
 use crate::ui_data_generator::entity::field_unit_harmful_status_info::FieldUnitHarmfulStatusInfo;
 use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
-use crate::ui_data_generator::entity::player_index_enum::PlayerIndex::You;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex::{Opponent, You};
 use crate::ui_data_generator::service::request::generate_draw_opponent_deck_data_request::GenerateDrawOpponentDeckDataRequest;
 use crate::ui_data_generator::service::request::generate_my_multiple_unit_death_data_request::GenerateMyMultipleUnitDeathDataRequest;
+use crate::ui_data_generator::service::request::generate_my_multiple_unit_extra_effect_data_request::GenerateMyMultipleUnitExtraEffectDataRequest;
 use crate::ui_data_generator::service::request::generate_my_multiple_unit_harmful_effect_data_request::GenerateMyMultipleUnitHarmfulEffectDataRequest;
 use crate::ui_data_generator::service::request::generate_my_multiple_unit_health_point_data_request::GenerateMyMultipleUnitHealthPointDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_field_energy_data_request::GenerateOpponentFieldEnergyDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_death_data_request::GenerateOpponentMultipleUnitDeathDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_extra_effect_data_request::GenerateOpponentMultipleUnitExtraEffectDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_harmful_effect_data_request::GenerateOpponentMultipleUnitHarmfulEffectDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_multiple_unit_health_point_data_request::GenerateOpponentMultipleUnitHealthPointDataRequest;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
 
 #[derive(Debug)]
 pub struct TurnEndRequestForm {
@@ -66,8 +87,35 @@ impl TurnEndRequestForm {
         IsThisYourTurnRequest::new(account_unique_id)
     }
 
-    pub fn to_apply_status_effect_damage_iteratively_request(&self, account_unique_id: i32) -> ApplyStatusEffectDamageIterativelyRequest {
-        ApplyStatusEffectDamageIterativelyRequest::new(account_unique_id)
+    pub fn to_tick_turn_end_status_effect_request(&self, account_unique_id: i32) -> TickStatusEffectOfAllUnitRequest {
+        TickStatusEffectOfAllUnitRequest::new(account_unique_id, StatusEffectTickTiming::TurnEnd)
+    }
+
+    pub fn to_publish_turn_end_game_event_request(&self, account_unique_id: i32) -> PublishTurnEndGameEventRequest {
+        PublishTurnEndGameEventRequest::new(account_unique_id)
+    }
+
+    pub fn to_process_pending_game_event_request(&self, account_unique_id: i32, opponent_unique_id: i32) -> ProcessPendingGameEventRequest {
+        ProcessPendingGameEventRequest::new(account_unique_id, opponent_unique_id)
+    }
+
+    pub fn to_notice_summoned_token_unit_request_form(&self,
+                                                      account_unique_id: i32,
+                                                      opponent_unique_id: i32,
+                                                      summoned_token_unit_map: HashMap<i32, Vec<SummonedTokenUnitInfo>>) -> NoticeSummonedTokenUnitRequestForm {
+        NoticeSummonedTokenUnitRequestForm::new(account_unique_id, opponent_unique_id, summoned_token_unit_map)
+    }
+
+    pub fn to_tick_turn_start_status_effect_request(&self, opponent_unique_id: i32) -> TickStatusEffectOfAllUnitRequest {
+        TickStatusEffectOfAllUnitRequest::new(opponent_unique_id, StatusEffectTickTiming::TurnStart)
+    }
+
+    pub fn to_decrease_turn_based_status_duration_of_all_unit_request(&self, account_unique_id: i32) -> DecreaseTurnBasedStatusDurationOfAllUnitRequest {
+        DecreaseTurnBasedStatusDurationOfAllUnitRequest::new(account_unique_id)
+    }
+
+    pub fn to_acquire_extra_effect_of_all_unit_request(&self, account_unique_id: i32) -> AcquireExtraEffectOfAllUnitRequest {
+        AcquireExtraEffectOfAllUnitRequest::new(account_unique_id)
     }
 
     pub fn to_judge_death_of_every_unit_request(&self, account_unique_id: i32) -> JudgeDeathOfEveryUnitRequest {
@@ -98,16 +146,43 @@ impl TurnEndRequestForm {
         NextGameRoundRequest::new(self.session_id.clone())
     }
 
-    pub fn to_draw_cards_from_deck_request(&self, opponent_account_unique_id: i32) -> DrawCardsFromDeckRequest {
-        DrawCardsFromDeckRequest::new(opponent_account_unique_id, 1)
+    pub fn to_decrease_environment_remaining_turn_request(&self, account_unique_id: i32, opponent_unique_id: i32) -> DecreaseEnvironmentRemainingTurnRequest {
+        DecreaseEnvironmentRemainingTurnRequest::new(account_unique_id, opponent_unique_id)
+    }
+
+    pub fn to_place_expired_environment_to_tomb_request(&self, environment_owner_unique_id: i32, expired_environment_card_id: i32) -> PlaceToTombRequest {
+        PlaceToTombRequest::new(environment_owner_unique_id, expired_environment_card_id)
+    }
+
+    pub fn to_notice_expire_environment_card_request(
+        &self,
+        receiver_unique_id: i32,
+        environment_owner_unique_id: i32,
+        expired_environment_card_id: i32) -> NoticeExpireEnvironmentCardRequest {
+
+        let mut player_expired_environment_card_map = HashMap::new();
+        let owner_index = if receiver_unique_id == environment_owner_unique_id { You } else { Opponent };
+        player_expired_environment_card_map.insert(owner_index, expired_environment_card_id);
+
+        NoticeExpireEnvironmentCardRequest::new(
+            receiver_unique_id,
+            player_expired_environment_card_map)
+    }
+
+    pub fn to_get_environment_extra_draw_count_request(&self, opponent_account_unique_id: i32) -> GetEnvironmentExtraDrawCountRequest {
+        GetEnvironmentExtraDrawCountRequest::new(opponent_account_unique_id)
+    }
+
+    pub fn to_draw_cards_from_deck_request(&self, opponent_account_unique_id: i32, extra_draw_count: i32) -> DrawCardsFromDeckRequest {
+        DrawCardsFromDeckRequest::new(opponent_account_unique_id, 1 + extra_draw_count)
     }
 
     pub fn to_add_card_list_to_hand_request(&self, account_unique_id: i32, card_list: Vec<i32>) -> AddCardListToHandRequest {
         AddCardListToHandRequest::new(account_unique_id, card_list)
     }
 
-    pub fn to_add_field_energy_request(&self, opponent_account_unique_id: i32) -> AddFieldEnergyWithAmountRequest {
-        AddFieldEnergyWithAmountRequest::new(opponent_account_unique_id, 1)
+    pub fn to_add_turn_start_field_energy_request(&self, opponent_account_unique_id: i32) -> AddTurnStartFieldEnergyRequest {
+        AddTurnStartFieldEnergyRequest::new(opponent_account_unique_id, 1)
     }
 
     pub fn to_get_current_field_energy_request(
@@ -171,6 +246,30 @@ impl TurnEndRequestForm {
             opponent_account_unique_id)
     }
 
+    pub fn to_get_remaining_action_waiting_time_request(
+        &self,
+        account_unique_id: i32) -> GetRemainingActionWaitingTimeRequest {
+
+        GetRemainingActionWaitingTimeRequest::new(
+            account_unique_id)
+    }
+
+    pub fn to_check_action_waiting_timer_expired_request(
+        &self,
+        battle_room_id: i32) -> CheckActionWaitingTimerExpiredRequest {
+
+        CheckActionWaitingTimerExpiredRequest::new(
+            battle_room_id)
+    }
+
+    pub fn to_get_players_in_battle_room_request(
+        &self,
+        battle_room_id: i32) -> GetPlayersInBattleRoomRequest {
+
+        GetPlayersInBattleRoomRequest::new(
+            battle_room_id)
+    }
+
     pub fn to_set_main_character_as_death_request(
         &self,
         account_unique_id: i32) -> SetMainCharacterAsDeathRequest {
@@ -241,6 +340,51 @@ impl TurnEndRequestForm {
             my_dead_unit_index_list)
     }
 
+    pub fn to_generate_my_multiple_unit_extra_effect_data_request(
+        &self,
+        my_unit_extra_effect_tuple_list: Vec<(i32, Vec<ExtraEffect>)>
+    ) -> GenerateMyMultipleUnitExtraEffectDataRequest {
+
+        GenerateMyMultipleUnitExtraEffectDataRequest::new(
+            my_unit_extra_effect_tuple_list)
+    }
+
+    pub fn to_generate_opponent_multiple_unit_health_point_data_request(
+        &self,
+        opponent_unit_health_point_tuple_list: Vec<(i32, i32)>
+    ) -> GenerateOpponentMultipleUnitHealthPointDataRequest {
+
+        GenerateOpponentMultipleUnitHealthPointDataRequest::new(
+            opponent_unit_health_point_tuple_list)
+    }
+
+    pub fn to_generate_opponent_multiple_unit_harmful_effect_data_request(
+        &self,
+        opponent_unit_harmful_status_tuple_list: Vec<(i32, Vec<ExtraEffect>)>
+    ) -> GenerateOpponentMultipleUnitHarmfulEffectDataRequest {
+
+        GenerateOpponentMultipleUnitHarmfulEffectDataRequest::new(
+            opponent_unit_harmful_status_tuple_list)
+    }
+
+    pub fn to_generate_opponent_multiple_unit_extra_effect_data_request(
+        &self,
+        opponent_unit_extra_effect_tuple_list: Vec<(i32, Vec<ExtraEffect>)>
+    ) -> GenerateOpponentMultipleUnitExtraEffectDataRequest {
+
+        GenerateOpponentMultipleUnitExtraEffectDataRequest::new(
+            opponent_unit_extra_effect_tuple_list)
+    }
+
+    pub fn to_generate_opponent_multiple_unit_death_data_request(
+        &self,
+        opponent_dead_unit_index_list: Vec<i32>
+    ) -> GenerateOpponentMultipleUnitDeathDataRequest {
+
+        GenerateOpponentMultipleUnitDeathDataRequest::new(
+            opponent_dead_unit_index_list)
+    }
+
     pub fn to_notice_my_turn_end_request(
         &self,
         opponent_unique_id: i32,
@@ -248,8 +392,11 @@ impl TurnEndRequestForm {
         player_field_energy_map_for_notice: HashMap<PlayerIndex, i32>,
         player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
         player_field_unit_harmful_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+        player_field_unit_extra_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
         player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>,
         unit_index_turn_start_passive_list_map: HashMap<i32, Vec<i32>>,
+        is_turn_timed_out: bool,
+        remaining_turn_time_seconds: u64,
     ) -> NoticeMyTurnEndRequest {
 
         NoticeMyTurnEndRequest::new(
@@ -258,14 +405,66 @@ impl TurnEndRequestForm {
             player_field_energy_map_for_notice,
             player_field_unit_health_point_map_for_notice,
             player_field_unit_harmful_effect_map_for_notice,
+            player_field_unit_extra_effect_map_for_notice,
             player_field_unit_death_map_for_notice,
             HashMap::new(),
-            unit_index_turn_start_passive_list_map)
+            unit_index_turn_start_passive_list_map,
+            HashMap::new(),
+            is_turn_timed_out,
+            remaining_turn_time_seconds)
+    }
+
+    pub fn to_notice_turn_time_out_request(
+        &self,
+        account_unique_id: i32,
+        player_drawn_card_count_map_for_response: HashMap<PlayerIndex, i32>,
+        player_field_energy_map_for_response: HashMap<PlayerIndex, i32>,
+        player_field_unit_health_point_map_for_response: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_harmful_effect_map_for_response: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+        player_field_unit_extra_effect_map_for_response: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
+        player_field_unit_death_map_for_response: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+        player_main_character_survival_map_for_response: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+        remaining_turn_time_seconds: u64,
+    ) -> NoticeMyTurnEndRequest {
+
+        NoticeMyTurnEndRequest::new(
+            account_unique_id,
+            HashMap::new(),
+            player_field_energy_map_for_response,
+            player_field_unit_health_point_map_for_response,
+            player_field_unit_harmful_effect_map_for_response,
+            player_field_unit_extra_effect_map_for_response,
+            player_field_unit_death_map_for_response,
+            player_main_character_survival_map_for_response,
+            HashMap::new(),
+            player_drawn_card_count_map_for_response,
+            true,
+            remaining_turn_time_seconds)
+    }
+
+    pub fn to_notice_turn_time_out_in_case_of_no_more_opponent_deck(
+        &self,
+        account_unique_id: i32,) -> NoticeMyTurnEndRequest {
+
+        let mut map_only_for_death_by_no_more_opponent_deck_card = HashMap::new();
+        map_only_for_death_by_no_more_opponent_deck_card.insert(Opponent, Death);
+
+        self.to_notice_turn_time_out_request(
+            account_unique_id,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            map_only_for_death_by_no_more_opponent_deck_card,
+            0)
     }
 
     pub fn to_notice_my_turn_end_in_case_of_no_more_opponent_deck(
         &self,
-        opponent_unique_id: i32,) -> NoticeMyTurnEndRequest {
+        opponent_unique_id: i32,
+        is_turn_timed_out: bool,) -> NoticeMyTurnEndRequest {
 
         let mut map_only_for_death_by_no_more_opponent_deck_card = HashMap::new();
         map_only_for_death_by_no_more_opponent_deck_card.insert(You, Death);
@@ -277,7 +476,11 @@ impl TurnEndRequestForm {
             HashMap::new(),
             HashMap::new(),
             HashMap::new(),
+            HashMap::new(),
             map_only_for_death_by_no_more_opponent_deck_card,
-            HashMap::new())
+            HashMap::new(),
+            HashMap::new(),
+            is_turn_timed_out,
+            0)
     }
 }
\ No newline at end of file
@@ -3,15 +3,13 @@ use serde::{Deserialize, Serialize};
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum::Death;
 use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_extra_effect_info::FieldUnitExtraEffectInfo;
 use crate::ui_data_generator::entity::field_unit_harmful_status_info::FieldUnitHarmfulStatusInfo;
 use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex::{Opponent, You};
 
 use crate::ui_data_generator::service::response::generate_draw_opponent_deck_data_response::GenerateDrawOpponentDeckDataResponse;
-use crate::ui_data_generator::service::response::generate_my_multiple_unit_death_data_response::GenerateMyMultipleUnitDeathDataResponse;
-use crate::ui_data_generator::service::response::generate_my_multiple_unit_harmful_effect_data_response::GenerateMyMultipleUnitHarmfulEffectDataResponse;
-use crate::ui_data_generator::service::response::generate_my_multiple_unit_health_point_data_response::GenerateMyMultipleUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_field_energy_data_response::GenerateOpponentFieldEnergyDataResponse;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +19,10 @@ pub struct TurnEndResponseForm {
     player_field_energy_map: HashMap<PlayerIndex, i32>,
     player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
     player_field_unit_harmful_effect_map: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+    player_field_unit_extra_effect_map: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
     player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
     player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+    remaining_turn_time_seconds: u64,
 }
 
 impl TurnEndResponseForm {
@@ -31,8 +31,10 @@ impl TurnEndResponseForm {
                player_field_energy_map: HashMap<PlayerIndex, i32>,
                player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
                player_field_unit_harmful_effect_map: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+               player_field_unit_extra_effect_map: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
                player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
                player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+               remaining_turn_time_seconds: u64,
     ) -> Self {
 
         TurnEndResponseForm {
@@ -41,17 +43,21 @@ impl TurnEndResponseForm {
             player_field_energy_map,
             player_field_unit_health_point_map,
             player_field_unit_harmful_effect_map,
+            player_field_unit_extra_effect_map,
             player_field_unit_death_map,
-            player_main_character_survival_map
+            player_main_character_survival_map,
+            remaining_turn_time_seconds,
         }
     }
 
     pub fn from_response(
         generate_draw_opponent_deck_data_response: GenerateDrawOpponentDeckDataResponse,
         generate_opponent_field_energy_data_response: GenerateOpponentFieldEnergyDataResponse,
-        generate_my_multiple_unit_health_point_data_response: GenerateMyMultipleUnitHealthPointDataResponse,
-        generate_my_multiple_unit_harmful_effect_data_response: GenerateMyMultipleUnitHarmfulEffectDataResponse,
-        generate_my_multiple_unit_death_data_response: GenerateMyMultipleUnitDeathDataResponse
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_harmful_effect_map: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+        player_field_unit_extra_effect_map: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+        remaining_turn_time_seconds: u64,
     ) -> TurnEndResponseForm {
 
         TurnEndResponseForm::new(
@@ -60,13 +66,12 @@ impl TurnEndResponseForm {
                 .get_player_drawn_card_count_map_for_response().clone(),
             generate_opponent_field_energy_data_response
                 .get_player_field_energy_map_for_response().clone(),
-            generate_my_multiple_unit_health_point_data_response
-                .get_player_field_unit_health_point_map_for_response().clone(),
-            generate_my_multiple_unit_harmful_effect_data_response
-                .get_player_field_unit_harmful_effect_map_for_response().clone(),
-            generate_my_multiple_unit_death_data_response
-                .get_player_field_unit_death_map_for_response().clone(),
-            HashMap::new()
+            player_field_unit_health_point_map,
+            player_field_unit_harmful_effect_map,
+            player_field_unit_extra_effect_map,
+            player_field_unit_death_map,
+            HashMap::new(),
+            remaining_turn_time_seconds
         )
     }
 
@@ -82,7 +87,9 @@ impl TurnEndResponseForm {
             HashMap::new(),
             HashMap::new(),
             HashMap::new(),
-            map_only_for_death_by_no_more_opponent_deck_card
+            HashMap::new(),
+            map_only_for_death_by_no_more_opponent_deck_card,
+            0
         )
     }
 
@@ -95,6 +102,8 @@ impl TurnEndResponseForm {
             HashMap::new(),
             HashMap::new(),
             HashMap::new(),
-            HashMap::new())
+            HashMap::new(),
+            HashMap::new(),
+            0)
     }
 }
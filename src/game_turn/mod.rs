@@ -2,4 +2,4 @@ pub mod entity;
 pub mod repository;
 pub(crate) mod service;
 pub(crate) mod controller;
-
+pub mod protocol;
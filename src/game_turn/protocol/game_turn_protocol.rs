@@ -0,0 +1,16 @@
+use crate::game_turn::controller::game_turn_controller::GameTurnController;
+use crate::game_turn::controller::game_turn_controller_impl::GameTurnControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::game_next_turn_request_generator::create_game_turn_request_form;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_turn_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Game Next Turn
+    protocol_registry.register_battle_room_action(3333, "GAME_NEXT_TURN", create_game_turn_request_form, |request| async move {
+        let game_turn_controller_impl_mutex = GameTurnControllerImpl::get_instance();
+        let game_turn_controller = game_turn_controller_impl_mutex.lock().await;
+
+        let response = game_turn_controller.request_turn_end(request).await;
+        ResponseType::GAME_NEXT_TURN(response)
+    });
+}
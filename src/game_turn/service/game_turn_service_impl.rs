@@ -17,18 +17,15 @@ use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepositor
 use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
 
 pub struct GameTurnServiceImpl {
-    game_turn_repository: Arc<AsyncMutex<GameTurnRepositoryImpl>>,
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
 
 
 }
 
 impl GameTurnServiceImpl {
-    pub fn new(game_turn_repository: Arc<AsyncMutex<GameTurnRepositoryImpl>>,
-               redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
+    pub fn new(redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
                ) -> Self {
         GameTurnServiceImpl {
-            game_turn_repository,
             redis_in_memory_repository,
 
         }
@@ -40,7 +37,6 @@ impl GameTurnServiceImpl {
                 Arc::new(
                     AsyncMutex::new(
                         GameTurnServiceImpl::new(
-                            GameTurnRepositoryImpl::get_instance(),
                             RedisInMemoryRepositoryImpl::get_instance(),
                        )));
         }
@@ -63,7 +59,8 @@ impl GameTurnService for GameTurnServiceImpl {
     async fn next_turn(&mut self, next_turn_request: NextTurnRequest) -> NextTurnResponse {
         println!("GameTurnServiceImpl: first_turn_decision_object()");
 
-        let mut game_turn_repository_guard = self.game_turn_repository.lock().await;
+        let game_turn_repository_mutex = GameTurnRepositoryImpl::get_instance();
+        let mut game_turn_repository_guard = game_turn_repository_mutex.lock().await;
         game_turn_repository_guard.next_game_turn(next_turn_request.get_account_unique_id());
 
         NextTurnResponse::new(true)
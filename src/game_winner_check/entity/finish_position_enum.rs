@@ -1,4 +1,4 @@
-#[derive(Debug, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub enum FinishPositionEnum {
     Dummy = 0,
     Winner = 1,
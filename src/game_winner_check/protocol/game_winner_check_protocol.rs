@@ -0,0 +1,16 @@
+use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::surrender_request_generator::create_surrender_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_game_winner_check_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Game Surrender
+    protocol_registry.register_battle_room_action(4443, "GAME_SURRENDER", create_surrender_request, |request| async move {
+        let game_winner_check_service_mutex = GameWinnerCheckServiceImpl::get_instance();
+        let mut game_winner_check_service = game_winner_check_service_mutex.lock().await;
+
+        let response = game_winner_check_service.set_game_winner_by_surrender(request).await;
+        ResponseType::GAME_SURRENDER(response)
+    });
+}
@@ -0,0 +1 @@
+pub mod game_winner_check_protocol;
@@ -1,10 +1,19 @@
 use async_trait::async_trait;
+use crate::game_winner_check::service::request::check_game_finished_request::CheckGameFinishedRequest;
 use crate::game_winner_check::service::request::check_main_character_request::CheckMainCharacterRequest;
+use crate::game_winner_check::service::request::draw_by_shutdown_request::DrawByShutdownRequest;
+use crate::game_winner_check::service::request::forfeit_request::ForfeitRequest;
 use crate::game_winner_check::service::request::surrender_request::SurrenderRequest;
+use crate::game_winner_check::service::response::check_game_finished_response::CheckGameFinishedResponse;
+use crate::game_winner_check::service::response::draw_by_shutdown_response::DrawByShutdownResponse;
+use crate::game_winner_check::service::response::forfeit_response::ForfeitResponse;
 use crate::game_winner_check::service::response::surrender_response::SurrenderResponse;
 
 #[async_trait]
 pub trait GameWinnerCheckService {
     async fn set_game_winner(&mut self, check_main_character_request: CheckMainCharacterRequest);
     async fn set_game_winner_by_surrender(&mut self, surrender_request: SurrenderRequest) -> SurrenderResponse;
-}
\ No newline at end of file
+    async fn set_game_winner_by_forfeit(&mut self, forfeit_request: ForfeitRequest) -> ForfeitResponse;
+    async fn set_game_draw_by_shutdown(&mut self, draw_by_shutdown_request: DrawByShutdownRequest) -> DrawByShutdownResponse;
+    async fn check_game_finished(&self, check_game_finished_request: CheckGameFinishedRequest) -> CheckGameFinishedResponse;
+}
@@ -9,11 +9,17 @@ use crate::battle_room::repository::battle_room_repository::BattleRoomRepository
 use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
 use crate::game_main_character::repository::game_main_character_repository::GameMainCharacterRepository;
 use crate::game_main_character::repository::game_main_character_repository_impl::GameMainCharacterRepositoryImpl;
-use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum::{Loser, Winner};
+use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum::{Draw, Loser, Winner};
 use crate::game_winner_check::repository::game_winner_check_repository::GameWinnerCheckRepository;
 use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::request::check_game_finished_request::CheckGameFinishedRequest;
 use crate::game_winner_check::service::request::check_main_character_request::CheckMainCharacterRequest;
+use crate::game_winner_check::service::request::draw_by_shutdown_request::DrawByShutdownRequest;
+use crate::game_winner_check::service::request::forfeit_request::ForfeitRequest;
 use crate::game_winner_check::service::request::surrender_request::SurrenderRequest;
+use crate::game_winner_check::service::response::check_game_finished_response::CheckGameFinishedResponse;
+use crate::game_winner_check::service::response::draw_by_shutdown_response::DrawByShutdownResponse;
+use crate::game_winner_check::service::response::forfeit_response::ForfeitResponse;
 use crate::game_winner_check::service::response::surrender_response::SurrenderResponse;
 use crate::notify_player_action_info::repository::notify_player_action_info_repository_impl::NotifyPlayerActionInfoRepositoryImpl;
 use crate::notify_player_action_info::entity::notify_form_surrender::NotifyFormSurrender;
@@ -61,6 +67,23 @@ impl GameWinnerCheckServiceImpl {
         }
         INSTANCE.clone()
     }
+
+    // 항복, 접속 종료 등으로 경기를 포기한 쪽을 패배 처리하고 상대 id 를 반환
+    async fn set_game_loser_by_giving_up(&self, account_unique_id: i32) -> Option<i32> {
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        println!("account_unique_id: {:?}", account_unique_id);
+        let opponent_unique_id_option = battle_room_repository_guard.find_opponent_unique_id(account_unique_id).await;
+        drop(battle_room_repository_guard);
+
+        let opponent_unique_id = opponent_unique_id_option?;
+
+        let mut game_winner_check_guard = self.game_winner_check_repository.lock().await;
+        game_winner_check_guard.create_finish_position_object(account_unique_id, Loser);
+        game_winner_check_guard.add_finish_position_object(opponent_unique_id, Winner);
+        drop(game_winner_check_guard);
+
+        Some(opponent_unique_id)
+    }
 }
 
 #[async_trait]
@@ -87,21 +110,66 @@ impl GameWinnerCheckService for GameWinnerCheckServiceImpl {
         let account_unique_id: i32 = account_unique_id_sting.parse().expect("Failed to parse account_unique_id_string as i32");
         drop(redis_repository_guard);
 
+        let opponent_unique_id = self.set_game_loser_by_giving_up(account_unique_id).await.unwrap();
+
+        let mut notify_player_action_info_guard = self.notify_player_action_info.lock().await;
+        notify_player_action_info_guard.notice_surrender(opponent_unique_id).await;
+        drop(notify_player_action_info_guard);
+
+        return SurrenderResponse::new(true)
+    }
+
+    async fn set_game_winner_by_forfeit(&mut self, forfeit_request: ForfeitRequest) -> ForfeitResponse {
+        println!("GameWinnerCheckServiceImpl: set_game_winner_by_forfeit()");
+
+        let account_unique_id = forfeit_request.get_account_unique_id();
+
+        let opponent_unique_id = match self.set_game_loser_by_giving_up(account_unique_id).await {
+            Some(opponent_unique_id) => opponent_unique_id,
+            None => {
+                println!("Opponent of account {} not found", account_unique_id);
+                return ForfeitResponse::new(None)
+            }
+        };
+
+        let mut notify_player_action_info_guard = self.notify_player_action_info.lock().await;
+        notify_player_action_info_guard.notice_opponent_abandoned(opponent_unique_id).await;
+        drop(notify_player_action_info_guard);
+
+        ForfeitResponse::new(Some(opponent_unique_id))
+    }
+
+    // 서버 종료 전까지 끝나지 않은 경기는 양측 모두 무승부 처리
+    async fn set_game_draw_by_shutdown(&mut self, draw_by_shutdown_request: DrawByShutdownRequest) -> DrawByShutdownResponse {
+        println!("GameWinnerCheckServiceImpl: set_game_draw_by_shutdown()");
+
+        let account_unique_id = draw_by_shutdown_request.get_account_unique_id();
+
         let battle_room_repository_guard = self.battle_room_repository.lock().await;
-         println!("account_unique_id: {:?}", account_unique_id);
-        let opponent_unique_id = battle_room_repository_guard.find_opponent_unique_id(account_unique_id).await.unwrap();
+        let opponent_unique_id_option = battle_room_repository_guard.find_opponent_unique_id(account_unique_id).await;
         drop(battle_room_repository_guard);
 
         let mut game_winner_check_guard = self.game_winner_check_repository.lock().await;
-        game_winner_check_guard.create_finish_position_object(account_unique_id, Loser);
-        game_winner_check_guard.add_finish_position_object(opponent_unique_id, Winner);
+        game_winner_check_guard.create_finish_position_object(account_unique_id, Draw);
+        if let Some(opponent_unique_id) = opponent_unique_id_option {
+            game_winner_check_guard.add_finish_position_object(opponent_unique_id, Draw);
+        }
         drop(game_winner_check_guard);
 
-        let mut notify_player_action_info_guard = self.notify_player_action_info.lock().await;
-        notify_player_action_info_guard.notice_surrender(opponent_unique_id).await;
-        drop(notify_player_action_info_guard);
+        DrawByShutdownResponse::new(opponent_unique_id_option)
+    }
 
-        return SurrenderResponse::new(true)
+    async fn check_game_finished(&self, check_game_finished_request: CheckGameFinishedRequest) -> CheckGameFinishedResponse {
+        println!("GameWinnerCheckServiceImpl: check_game_finished()");
+
+        let mut game_winner_check_repository_guard = self.game_winner_check_repository.lock().await;
+        let is_finished =
+            game_winner_check_repository_guard.get_finish_position_enum(
+                check_game_finished_request.get_account_unique_id()).is_some();
+
+        drop(game_winner_check_repository_guard);
+
+        CheckGameFinishedResponse::new(is_finished)
     }
 }
 
@@ -1,2 +1,5 @@
 pub mod check_main_character_request;
-pub mod surrender_request;
\ No newline at end of file
+pub mod surrender_request;
+pub mod check_game_finished_request;
+pub mod forfeit_request;
+pub mod draw_by_shutdown_request;
\ No newline at end of file
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct CheckGameFinishedResponse {
+    is_finished: bool,
+}
+
+impl CheckGameFinishedResponse {
+    pub fn new(is_finished: bool) -> Self {
+        CheckGameFinishedResponse {
+            is_finished,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct DrawByShutdownResponse {
+    opponent_unique_id: Option<i32>,
+}
+
+impl DrawByShutdownResponse {
+    pub fn new(opponent_unique_id: Option<i32>) -> Self {
+        DrawByShutdownResponse {
+            opponent_unique_id,
+        }
+    }
+
+    pub fn get_opponent_unique_id(&self) -> Option<i32> {
+        self.opponent_unique_id
+    }
+}
@@ -0,0 +1,20 @@
+#[derive(Debug)]
+pub struct ForfeitResponse {
+    opponent_unique_id: Option<i32>,
+}
+
+impl ForfeitResponse {
+    pub fn new(opponent_unique_id: Option<i32>) -> Self {
+        ForfeitResponse {
+            opponent_unique_id,
+        }
+    }
+
+    pub fn get_opponent_unique_id(&self) -> Option<i32> {
+        self.opponent_unique_id
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.opponent_unique_id.is_some()
+    }
+}
@@ -1,2 +1,5 @@
 pub mod check_main_character_response;
-pub mod surrender_response;
\ No newline at end of file
+pub mod surrender_response;
+pub mod check_game_finished_response;
+pub mod forfeit_response;
+pub mod draw_by_shutdown_response;
\ No newline at end of file
@@ -1,9 +1,12 @@
 use std::future::Future;
 use std::pin::Pin;
-use std::time::Duration;
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
 
 use crate::battle_match_monitor::service::battle_match_monitor_service::BattleMatchMonitorService;
 use crate::battle_match_monitor::service::battle_match_monitor_service_impl::BattleMatchMonitorServiceImpl;
+use crate::rate_limit::service::rate_limit_service::RateLimitService;
+use crate::rate_limit::service::rate_limit_service_impl::RateLimitServiceImpl;
 
 use crate::battle_prepare_task::service::battle_prepare_task_service::BattlePrepareTaskService;
 use crate::battle_prepare_task::service::battle_prepare_task_service_impl::BattlePrepareTaskServiceImpl;
@@ -11,10 +14,6 @@ use crate::battle_prepare_task::service::battle_prepare_task_service_impl::Battl
 use crate::client_socket_accept::controller::client_socket_accept_controller::ClientSocketAcceptController;
 use crate::client_socket_accept::controller::client_socket_accept_controller_impl::ClientSocketAcceptControllerImpl;
 
-use crate::common::env::env_detector::EnvDetector;
-
-use crate::common::ip_address::local_ip_finder::IPAddress;
-
 use crate::domain_initializer::initializer::DomainInitializer;
 use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller::GameBattleFieldMonitorController;
 use crate::game_battle_field_monitor::controller::game_battle_field_monitor_controller_impl::GameBattleFieldMonitorControllerImpl;
@@ -22,6 +21,10 @@ use crate::game_battle_field_monitor::controller::game_battle_field_monitor_cont
 use crate::receiver::controller::server_receiver_controller::ServerReceiverController;
 use crate::receiver::controller::server_receiver_controller_impl::ServerReceiverControllerImpl;
 
+use crate::server_config::server_config::ServerConfig;
+use crate::server_shutdown::controller::server_shutdown_controller::ServerShutdownController;
+use crate::server_shutdown::controller::server_shutdown_controller_impl::ServerShutdownControllerImpl;
+
 use crate::server_socket::service::server_socket_service::ServerSocketService;
 use crate::server_socket::service::server_socket_service_impl::ServerSocketServiceImpl;
 
@@ -39,7 +42,7 @@ mod client_socket_accept;
 mod receiver;
 mod request_generator;
 mod account;
-mod mysql_config;
+mod storage_config;
 mod transmitter;
 mod response_generator;
 mod redis;
@@ -65,6 +68,7 @@ mod game_main_character;
 mod battle_prepare_task;
 mod card_library;
 mod account_point;
+mod account_rating;
 mod card_kinds;
 mod card_grade;
 mod card_race;
@@ -88,6 +92,10 @@ mod card_health_point;
 mod game_card_active_skill;
 mod game_card_passive_skill;
 mod game_card_tool;
+mod game_card_trap;
+mod game_card_environment;
+mod game_card_token;
+mod game_event_bus;
 mod rock_paper_scissors;
 mod game_card_support_usage_counter;
 mod game_field_unit_action_possibility_validator;
@@ -103,19 +111,31 @@ mod rock_paper_scissors_waiting_timer;
 mod battle_start;
 mod mulligan_monitor;
 mod check_connecting;
+mod protocol_registry;
+mod card_effect_definition;
+mod server_config;
+mod connection_liveness;
+mod battle_resume;
+mod battle_room_actor;
+mod server_shutdown;
+mod rate_limit;
+mod protocol_handshake;
 
 #[tokio::main]
 async fn main() {
+    // 설정 오류는 도메인 초기화 전에 한 번에 보고 후 종료
+    let server_config = ServerConfig::initialize_from_process();
+
     let domain_initializer = DomainInitializer;
-    domain_initializer.init_every_domain().await;
+    if let Err(registration_error_list) = domain_initializer.init_every_domain().await {
+        eprintln!("Protocol registry has {} registration errors, aborting startup", registration_error_list.len());
+        std::process::exit(1);
+    }
 
     let server_socket_service = ServerSocketServiceImpl::get_instance();
 
-    let ip = IPAddress::get_local_ip_from_google().unwrap();
-    let port = EnvDetector::get_port().unwrap();
-
-    let binding = format!("{}:{}", ip, port).to_string();
-    let address = binding.as_str();;
+    let binding = server_config.get_bind_address();
+    let address = binding.as_str();
 
     let mut server_socket_service_guard = server_socket_service.lock().await;
 
@@ -128,14 +148,36 @@ async fn main() {
         }
     }
 
+    // WebSocket 포트가 설정된 경우에만 별도 listener 를 연다
+    let web_socket_address = server_config.get_web_socket_bind_address();
+    if let Some(web_socket_address) = &web_socket_address {
+        match server_socket_service_guard.web_socket_server_socket_bind(web_socket_address.as_str()).await {
+            Ok(()) => {
+                println!("WebSocket server bound to address: {}", web_socket_address);
+            }
+            Err(err) => {
+                eprintln!("Error binding web socket: {:?}", err);
+            }
+        }
+    }
+
     drop(server_socket_service_guard);
 
     let acceptor_function = || -> Pin<Box<dyn Future<Output = ()> + Send>> {
         Box::pin(async {
             let client_socket_accept_controller = ClientSocketAcceptControllerImpl::get_instance();
-            let client_socket_accept_controller_guard = client_socket_accept_controller.lock().await;
+            let client_socket_accept_controller_clone = client_socket_accept_controller.lock().await.clone();
             println!("Controller instance found. Executing accept_client().");
-            client_socket_accept_controller_guard.accept_client().await;
+            client_socket_accept_controller_clone.accept_client().await;
+        })
+    };
+
+    let web_socket_acceptor_function = || -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(async {
+            let client_socket_accept_controller = ClientSocketAcceptControllerImpl::get_instance();
+            let client_socket_accept_controller_clone = client_socket_accept_controller.lock().await.clone();
+            println!("Controller instance found. Executing accept_web_socket_client().");
+            client_socket_accept_controller_clone.accept_web_socket_client().await;
         })
     };
 
@@ -145,6 +187,11 @@ async fn main() {
     thread_worker_service_guard.save_async_thread_worker("Acceptor", Box::new(acceptor_function.clone()));
     thread_worker_service_guard.start_thread_worker("Acceptor").await;
 
+    if web_socket_address.is_some() {
+        thread_worker_service_guard.save_async_thread_worker("WebSocketAcceptor", Box::new(web_socket_acceptor_function.clone()));
+        thread_worker_service_guard.start_thread_worker("WebSocketAcceptor").await;
+    }
+
     let receiver_function = || -> Pin<Box<dyn Future<Output = ()> + Send>> {
         Box::pin(async {
             let server_receiver_controller_mutex = ServerReceiverControllerImpl::get_instance();
@@ -193,7 +240,59 @@ async fn main() {
     thread_worker_service_guard.save_async_thread_worker("BattlePrepareTask", Box::new(battle_prepare_task_function.clone()));
     thread_worker_service_guard.start_thread_worker("BattlePrepareTask").await;
 
-    loop {
-        tokio::time::sleep(Duration::from_secs(10)).await;
+    // 모니터링용으로 rate limit 누적 값을 주기적으로 남기고, 다 채워진 계정 bucket 을 정리
+    let rate_limit_statistics_reporter_function = || -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(async {
+            loop {
+                tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+
+                let rate_limit_service_mutex = RateLimitServiceImpl::get_instance();
+                let rate_limit_service_guard = rate_limit_service_mutex.lock().await;
+                let remove_idle_rate_limit_response = rate_limit_service_guard.remove_idle_rate_limit().await;
+                let get_rate_limit_statistics_response = rate_limit_service_guard.get_rate_limit_statistics().await;
+                drop(rate_limit_service_guard);
+
+                println!("RateLimitStatistics: {} (idle account buckets removed: {})",
+                         serde_json::to_string(get_rate_limit_statistics_response.get_rate_limit_statistics()).unwrap_or_default(),
+                         remove_idle_rate_limit_response.get_removed_count());
+            }
+        })
+    };
+
+    thread_worker_service_guard.save_async_thread_worker("RateLimitStatisticsReporter", Box::new(rate_limit_statistics_reporter_function.clone()));
+    thread_worker_service_guard.start_thread_worker("RateLimitStatisticsReporter").await;
+
+    wait_for_shutdown_signal().await;
+    println!("Shutdown signal received");
+
+    // 새 접속과 매칭만 막고, 진행 중인 경기는 Receiver / Transmitter 가 계속 처리
+    for thread_worker_name in ["Acceptor", "WebSocketAcceptor", "BattleMatchMonitor"] {
+        thread_worker_service_guard.stop_thread_worker(thread_worker_name).await;
+    }
+    drop(thread_worker_service_guard);
+
+    let server_shutdown_controller_mutex = ServerShutdownControllerImpl::get_instance();
+    let server_shutdown_controller_guard = server_shutdown_controller_mutex.lock().await;
+    let drawn_battle_room_count =
+        server_shutdown_controller_guard.drain_live_battle(server_config.get_shutdown_drain_timeout()).await;
+    drop(server_shutdown_controller_guard);
+
+    println!("Server shutdown complete: {} battle rooms ended in a draw", drawn_battle_room_count);
+}
+
+// SIGINT (Ctrl + C) 또는 SIGTERM 을 받을 때까지 대기
+async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        let mut terminate_signal = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {},
+            _ = terminate_signal.recv() => {},
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
     }
 }
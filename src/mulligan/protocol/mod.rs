@@ -0,0 +1 @@
+pub mod mulligan_protocol;
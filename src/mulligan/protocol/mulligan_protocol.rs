@@ -0,0 +1,25 @@
+use crate::mulligan::controller::mulligan_controller::MulliganController;
+use crate::mulligan::controller::mulligan_controller_impl::MulliganControllerImpl;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::mulligan_request_generator::{create_check_opponent_mulligan_status_request_form, create_mulligan_request_form};
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_mulligan_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Mulligan
+    protocol_registry.register_battle_room_action(18, "CHANGE_FIRST_HAND", create_mulligan_request_form, |request| async move {
+        let mulligan_controller_mutex = MulliganControllerImpl::get_instance();
+        let mulligan_controller = mulligan_controller_mutex.lock().await;
+
+        let response = mulligan_controller.execute_mulligan_procedure(request).await;
+        ResponseType::CHANGE_FIRST_HAND(response)
+    });
+
+    // Mulligan
+    protocol_registry.register_battle_room_action(22, "CHECK_OPPONENT_MULLIGAN", create_check_opponent_mulligan_status_request_form, |request| async move {
+        let mulligan_controller_mutex = MulliganControllerImpl::get_instance();
+        let mulligan_controller = mulligan_controller_mutex.lock().await;
+
+        let response = mulligan_controller.check_opponent_mulligan_status(request).await;
+        ResponseType::CHECK_OPPONENT_MULLIGAN(response)
+    });
+}
@@ -1,15 +1,18 @@
 use std::sync::Arc;
-use std::time::Duration;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::watch;
 use crate::mulligan::entity::mulligan_finished_player_list::MulliganFinishedPlayerList;
 use crate::mulligan::entity::mulligan_timer_hash::MulliganTimerHash;
 use crate::mulligan::repository::mulligan_repository::MulliganRepository;
+use crate::server_config::server_config::ServerConfig;
 
+// 누군가 멀리건을 마칠 때마다 mulligan_finish_sender 로 알려 멀리건 감시 작업이 바로 확인하도록 함
 pub struct MulliganRepositoryImpl {
     mulligan_finished_player_list: Arc<AsyncMutex<MulliganFinishedPlayerList>>,
     mulligan_timer_hash: Arc<AsyncMutex<MulliganTimerHash>>,
+    mulligan_finish_sender: watch::Sender<()>,
 }
 
 impl MulliganRepositoryImpl {
@@ -17,9 +20,14 @@ impl MulliganRepositoryImpl {
         MulliganRepositoryImpl {
             mulligan_finished_player_list: Arc::new(AsyncMutex::new(MulliganFinishedPlayerList::new())),
             mulligan_timer_hash: Arc::new(AsyncMutex::new(MulliganTimerHash::new())),
+            mulligan_finish_sender: watch::channel(()).0,
         }
     }
 
+    pub fn subscribe_mulligan_finish(&self) -> watch::Receiver<()> {
+        self.mulligan_finish_sender.subscribe()
+    }
+
     pub fn get_mulligan_finished_player_list(&self) -> Arc<AsyncMutex<MulliganFinishedPlayerList>> {
         Arc::clone(&self.mulligan_finished_player_list)
     }
@@ -55,7 +63,7 @@ impl MulliganRepository for MulliganRepositoryImpl {
 
         let mut mulligan_timer_hash_guard = self.mulligan_timer_hash.lock().await;
 
-        mulligan_timer_hash_guard.check(account_unique_id, Duration::from_secs(30)).await.unwrap()
+        mulligan_timer_hash_guard.check(account_unique_id, ServerConfig::get_instance().get_mulligan_time_limit()).await.unwrap()
     }
 
     async fn remove_mulligan_timer(&self, account_unique_id: i32) -> bool {
@@ -75,6 +83,9 @@ impl MulliganRepository for MulliganRepositoryImpl {
             self.mulligan_finished_player_list.lock().await;
 
         mulligan_finished_player_list_guard.set(account_unique_id).await;
+        drop(mulligan_finished_player_list_guard);
+
+        self.mulligan_finish_sender.send_replace(());
 
         true
     }
@@ -2,5 +2,5 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait MulliganMonitorService {
-    async fn mulligan_monitoring(&self, battle_room_number: usize);
+    async fn mulligan_monitoring(&self, battle_room_id: i32);
 }
\ No newline at end of file
@@ -29,22 +29,36 @@ impl MulliganMonitorServiceImpl {
 
 #[async_trait]
 impl MulliganMonitorService for MulliganMonitorServiceImpl {
-    async fn mulligan_monitoring(&self, battle_room_number: usize) {
-        
-        let mut battle_room_repository_guard =
+    async fn mulligan_monitoring(&self, battle_room_id: i32) {
+        // 확인 전에 먼저 구독해야 확인과 대기 사이에 끝난 멀리건 알림을 놓치지 않음
+        let mulligan_repository_guard = self.mulligan_repository.lock().await;
+        let mut mulligan_finish_receiver = mulligan_repository_guard.subscribe_mulligan_finish();
+        drop(mulligan_repository_guard);
+
+        let battle_room_repository_guard =
             self.battle_room_repository.lock().await;
 
+        let battle_room_closed_receiver_option =
+            battle_room_repository_guard.subscribe_battle_room_closed(battle_room_id).await;
         let player_list =
-            battle_room_repository_guard.get_players_in_battle_room(battle_room_number).await;
+            battle_room_repository_guard.get_players_in_battle_room(battle_room_id).await;
 
         drop(battle_room_repository_guard);
 
-        let account_list = player_list.unwrap();
+        let (mut battle_room_closed_receiver, account_list) =
+            match (battle_room_closed_receiver_option, player_list) {
+                (Some(battle_room_closed_receiver), Some(account_list)) => (battle_room_closed_receiver, account_list),
+                _ => {
+                    println!("Battle room {} is already closed. Mulligan monitoring is skipped.", battle_room_id);
+                    return
+                }
+            };
+
         let first_account = account_list[0];
         let second_account = account_list[1];
 
         loop {
-            println!("Mulligan monitoring for room number {} is on going", battle_room_number);
+            println!("Mulligan monitoring for room id {} is on going", battle_room_id);
 
             let mut mulligan_repository_guard =
                 self.mulligan_repository.lock().await;
@@ -94,7 +108,21 @@ impl MulliganMonitorService for MulliganMonitorServiceImpl {
 
             drop(mulligan_repository_guard);
 
-            tokio::time::sleep(tokio::time::Duration::from_millis(3000)).await;
+            let is_battle_room_closed = tokio::select! {
+                _ = mulligan_finish_receiver.changed() => false,
+                _ = battle_room_closed_receiver.wait_for(|is_closed| *is_closed) => true,
+            };
+
+            if is_battle_room_closed {
+                println!("Battle room {} closed before mulligan finished.", battle_room_id);
+
+                let mulligan_repository_guard = self.mulligan_repository.lock().await;
+                mulligan_repository_guard.remove_mulligan_finish_record(first_account).await;
+                mulligan_repository_guard.remove_mulligan_finish_record(second_account).await;
+                drop(mulligan_repository_guard);
+
+                break
+            }
         }
 
         println!("Mulligan monitoring is finished.")
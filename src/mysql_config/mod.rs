@@ -1 +0,0 @@
-pub mod mysql_connection;
\ No newline at end of file
@@ -21,6 +21,7 @@ use crate::notify_player_action::entity::notify_opponent_to_destroy_deck_item_us
 use crate::notify_player_action::entity::notify_opponent_to_field_energy_usage::NotifyOpponentToFieldEnergyUsage;
 use crate::notify_player_action::entity::notify_opponent_to_field_unit_energy_removal_item_usage::NotifyOpponentToFieldUnitEnergyRemovalItemUsage;
 use crate::notify_player_action::repository::notify_player_action_repository::NotifyPlayerActionRepository;
+use crate::response_generator::response_envelope::ResponseEnvelope;
 use crate::response_generator::response_type::ResponseType;
 
 // TODO: 추후 HashMap 을 구성하여 한 번 등록된 사용자에 대한 처리는 즉시 진행되도록 구성해
@@ -61,8 +62,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_UNIT_DEPLOY(
-                        NotifyOpponentToUnitDeploy::new(unit_card_number))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_UNIT_DEPLOY(
+                            NotifyOpponentToUnitDeploy::new(unit_card_number)))))).await;
 
         true
     }
@@ -84,8 +86,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_FIELD_ENERGY_USAGE(
-                        NotifyOpponentToFieldEnergyUsage::new(unit_card_index, energy_race, energy_count, current_unit_energy_count, remaining_field_energy))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_FIELD_ENERGY_USAGE(
+                            NotifyOpponentToFieldEnergyUsage::new(unit_card_index, energy_race, energy_count, current_unit_energy_count, remaining_field_energy)))))).await;
 
         true
     }
@@ -107,8 +110,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_ENERGY_USAGE(
-                        NotifyOpponentToEnergyUsage::new(usage_energy_card_id, unit_card_index, energy_race, energy_count))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_ENERGY_USAGE(
+                            NotifyOpponentToEnergyUsage::new(usage_energy_card_id, unit_card_index, energy_race, energy_count)))))).await;
 
         true
     }
@@ -136,8 +140,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_ENERGY_BOOST(
-                        NotifyOpponentToEnergyBoost::new(usage_support_card_id, unit_card_index, boosting_energy_card_id_list))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_ENERGY_BOOST(
+                            NotifyOpponentToEnergyBoost::new(usage_support_card_id, unit_card_index, boosting_energy_card_id_list)))))).await;
 
         true
     }
@@ -160,8 +165,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_INSTANT_DEATH_ITEM_USAGE(
-                        NotifyOpponentToInstantDeathItemUsage::new(unit_card_index, usage_item_card_id))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_INSTANT_DEATH_ITEM_USAGE(
+                            NotifyOpponentToInstantDeathItemUsage::new(unit_card_index, usage_item_card_id)))))).await;
 
         true
     }
@@ -183,8 +189,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_INSTANT_DEATH_ITEM_ALTERNATIVES_USAGE(
-                        NotifyOpponentToInstantDeathItemAlternativesUsage::new(unit_card_index, usage_item_card_id, alternatives_damage))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_INSTANT_DEATH_ITEM_ALTERNATIVES_USAGE(
+                            NotifyOpponentToInstantDeathItemAlternativesUsage::new(unit_card_index, usage_item_card_id, alternatives_damage)))))).await;
 
         true
     }
@@ -206,8 +213,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_DRAW_SUPPORT_USAGE(
-                        NotifyOpponentToDrawSupportUsage::new(usage_support_card_id, draw_card_count))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_DRAW_SUPPORT_USAGE(
+                            NotifyOpponentToDrawSupportUsage::new(usage_support_card_id, draw_card_count)))))).await;
 
         true
     }
@@ -229,8 +237,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_SEARCH_SUPPORT_USAGE(
-                        NotifyOpponentSearchSupportUsage::new(usage_support_card_id, found_card_count))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_SEARCH_SUPPORT_USAGE(
+                            NotifyOpponentSearchSupportUsage::new(usage_support_card_id, found_card_count)))))).await;
 
         true
     }
@@ -252,8 +261,9 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_REMOVE_FIELD_ENERGY_SUPPORT_USAGE(
-                        NotifyOpponentRemoveFieldEnergySupportUsage::new(usage_support_card_id, amount_to_remove))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_REMOVE_FIELD_ENERGY_SUPPORT_USAGE(
+                            NotifyOpponentRemoveFieldEnergySupportUsage::new(usage_support_card_id, amount_to_remove)))))).await;
 
         true
     }
@@ -275,7 +285,8 @@ impl NotifyPlayerActionRepository for NotifyPlayerActionRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_INCREASE_FIELD_ENERGY_ITEM_USAGE(NotifyOpponentIncreaseFieldEnergyItemUsage::new(usage_item_card_id, increased_field_energy))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_INCREASE_FIELD_ENERGY_ITEM_USAGE(NotifyOpponentIncreaseFieldEnergyItemUsage::new(usage_item_card_id, increased_field_energy)))))).await;
 
         true
     }
@@ -297,8 +308,9 @@ async fn notify_to_opponent_you_use_tool_card_to_enhance_attack_point(&mut self,
     opponent_receiver_transmitter_channel.send(
         Arc::new(
             AsyncMutex::new(
-                ResponseType::NOTIFY_OPPONENT_TO_ENHANCE_ATTACK_POINT_TOOL_USAGE(
-                    NotifyOpponentToEnhanceAttackPointToolUsage::new(unit_card_index, usage_item_card_id))))).await;
+                ResponseEnvelope::notification(
+                    ResponseType::NOTIFY_OPPONENT_TO_ENHANCE_ATTACK_POINT_TOOL_USAGE(
+                        NotifyOpponentToEnhanceAttackPointToolUsage::new(unit_card_index, usage_item_card_id)))))).await;
         true
     }
     async fn notify_to_opponent_you_use_catastrophic_damage_item_card(&mut self,opponent_unique_id: i32, usage_item_card_id: i32, damage_for_field_unit: i32) -> bool {
@@ -318,8 +330,9 @@ async fn notify_to_opponent_you_use_tool_card_to_enhance_attack_point(&mut self,
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_CATASTROPHIC_DAMAGE_ITEM_USAGE(
-                        NotifyOpponentToCatastrophicDamageItemUsage::new(usage_item_card_id, damage_for_field_unit))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_CATASTROPHIC_DAMAGE_ITEM_USAGE(
+                            NotifyOpponentToCatastrophicDamageItemUsage::new(usage_item_card_id, damage_for_field_unit)))))).await;
         true
     }
     async fn notify_to_opponent_you_use_damage_main_character_item_card(&mut self,opponent_unique_id: i32, usage_item_card_id: i32, damage_for_main_character: i32) -> bool {
@@ -339,8 +352,9 @@ async fn notify_to_opponent_you_use_tool_card_to_enhance_attack_point(&mut self,
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_DAMAGE_MAIN_CHARACTER_ITEM_USAGE(
-                        NotifyOpponentToDamageMainCharacterItemUsage::new(usage_item_card_id, damage_for_main_character))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_DAMAGE_MAIN_CHARACTER_ITEM_USAGE(
+                            NotifyOpponentToDamageMainCharacterItemUsage::new(usage_item_card_id, damage_for_main_character)))))).await;
         true
     }
     async fn notify_to_opponent_you_use_destroy_deck_item_card(&mut self,opponent_unique_id: i32, usage_item_card_id: i32, will_be_lost_card: i32) -> bool {
@@ -360,8 +374,9 @@ async fn notify_to_opponent_you_use_tool_card_to_enhance_attack_point(&mut self,
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_DESTORY_DECK_ITEM_USAGE(
-                        NotifyOpponentToDestroyDeckItemUsage::new(usage_item_card_id, will_be_lost_card))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_DESTORY_DECK_ITEM_USAGE(
+                            NotifyOpponentToDestroyDeckItemUsage::new(usage_item_card_id, will_be_lost_card)))))).await;
         true
     }
     async fn notify_to_opponent_you_use_field_unit_energy_removal_item_card(&mut self,opponent_unique_id: i32, usage_item_card_id: i32, energy_quantity: i32) -> bool {
@@ -379,8 +394,9 @@ async fn notify_to_opponent_you_use_tool_card_to_enhance_attack_point(&mut self,
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    ResponseType::NOTIFY_OPPONENT_TO_FIELD_UNIT_ENERGY_REMOVAL_ITEM_USAGE(
-                        NotifyOpponentToFieldUnitEnergyRemovalItemUsage::new(usage_item_card_id, energy_quantity))))).await;
+                    ResponseEnvelope::notification(
+                        ResponseType::NOTIFY_OPPONENT_TO_FIELD_UNIT_ENERGY_REMOVAL_ITEM_USAGE(
+                            NotifyOpponentToFieldUnitEnergyRemovalItemUsage::new(usage_item_card_id, energy_quantity)))))).await;
         true
     }
 
@@ -24,4 +24,11 @@ pub mod notify_form_turn_start_non_targeting_attack_passive_skill;
 pub mod notify_form_turn_start_targeting_attack_to_game_main_character;
 pub mod notify_form_mulligan_end;
 pub mod notify_form_targeting_attack_active_skill_to_game_main_character;
-pub mod notify_form_surrender;
\ No newline at end of file
+pub mod notify_form_surrender;
+pub mod notify_form_opponent_abandoned;
+pub mod notify_form_set_trap_card;
+pub mod notify_form_trigger_trap_card;
+pub mod notify_form_deploy_environment_card;
+pub mod notify_form_expire_environment_card;
+pub mod notify_form_summon_token_unit;
+pub mod notify_form_game_event_reaction;
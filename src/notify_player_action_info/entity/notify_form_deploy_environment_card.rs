@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormDeployEnvironmentCard {
+    player_hand_use_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+}
+
+impl NotifyFormDeployEnvironmentCard {
+    pub fn new(player_hand_use_map: HashMap<PlayerIndex, UsedHandCardInfo>) -> Self {
+
+        NotifyFormDeployEnvironmentCard {
+            player_hand_use_map,
+        }
+    }
+}
\ No newline at end of file
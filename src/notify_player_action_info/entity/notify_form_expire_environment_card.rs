@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormExpireEnvironmentCard {
+    player_expired_environment_card_map: HashMap<PlayerIndex, i32>,
+}
+
+impl NotifyFormExpireEnvironmentCard {
+    pub fn new(player_expired_environment_card_map: HashMap<PlayerIndex, i32>) -> Self {
+
+        NotifyFormExpireEnvironmentCard {
+            player_expired_environment_card_map,
+        }
+    }
+}
\ No newline at end of file
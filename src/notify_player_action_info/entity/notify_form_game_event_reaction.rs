@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormGameEventReaction {
+    player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+}
+
+impl NotifyFormGameEventReaction {
+    pub fn new(player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>) -> Self {
+
+        NotifyFormGameEventReaction {
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormOpponentAbandoned {
+    is_done: bool,
+}
+
+impl NotifyFormOpponentAbandoned {
+    pub fn new(is_done: bool) -> Self {
+        NotifyFormOpponentAbandoned { is_done }
+    }
+}
@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormSummonTokenUnit {
+    player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>,
+}
+
+impl NotifyFormSummonTokenUnit {
+    pub fn new(player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>) -> Self {
+
+        NotifyFormSummonTokenUnit {
+            player_summoned_token_unit_map,
+        }
+    }
+}
\ No newline at end of file
@@ -8,6 +8,7 @@ use crate::ui_data_generator::entity::field_unit_death_info::{FieldUnitDeathInfo
 use crate::ui_data_generator::entity::field_unit_extra_effect_info::FieldUnitExtraEffectInfo;
 use crate::ui_data_generator::entity::field_unit_harmful_status_info::FieldUnitHarmfulStatusInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
 use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
 
 #[async_trait]
@@ -122,9 +123,13 @@ pub trait NotifyPlayerActionInfoRepository {
         player_field_energy_map: HashMap<PlayerIndex, i32>,
         player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
         player_field_unit_harmful_effect_map: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+        player_field_unit_extra_effect_map: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
         player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
         player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
         unit_index_turn_start_passive_list_map: HashMap<i32, Vec<i32>>,
+        player_drawn_card_count_map: HashMap<PlayerIndex, i32>,
+        is_turn_timed_out: bool,
+        remaining_turn_time_seconds: u64,
     ) -> bool;
     async fn notice_targeting_attack_active_skill_to_unit(
         &mut self,
@@ -207,4 +212,45 @@ pub trait NotifyPlayerActionInfoRepository {
         &mut self,
         opponent_unique_id: i32,
     ) -> bool;
+
+    async fn notice_opponent_abandoned(
+        &mut self,
+        opponent_unique_id: i32,
+    ) -> bool;
+
+    async fn notice_set_trap_card(
+        &mut self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> bool;
+    async fn notice_trigger_trap_card(
+        &mut self,
+        receiver_unique_id: i32,
+        player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+        player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+        player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+    ) -> bool;
+    async fn notice_deploy_environment_card(
+        &mut self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> bool;
+    async fn notice_expire_environment_card(
+        &mut self,
+        receiver_unique_id: i32,
+        player_expired_environment_card_map: HashMap<PlayerIndex, i32>
+    ) -> bool;
+    async fn notice_summon_token_unit(
+        &mut self,
+        opponent_unique_id: i32,
+        player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>
+    ) -> bool;
+    async fn notice_game_event_reaction(
+        &mut self,
+        opponent_unique_id: i32,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>
+    ) -> bool;
 }
\ No newline at end of file
@@ -27,6 +27,13 @@ use crate::notify_player_action_info::entity::notify_form_use_unit_energy_boost_
 use crate::notify_player_action_info::entity::notify_form_use_field_energy_remove_item_card::NotifyFormUseFieldEnergyRemoveItemCard;
 use crate::notify_player_action_info::entity::notify_form_use_field_energy_to_unit::NotifyFormUseFieldEnergyToUnit;
 use crate::notify_player_action_info::entity::notify_form_surrender::NotifyFormSurrender;
+use crate::notify_player_action_info::entity::notify_form_opponent_abandoned::NotifyFormOpponentAbandoned;
+use crate::notify_player_action_info::entity::notify_form_set_trap_card::NotifyFormSetTrapCard;
+use crate::notify_player_action_info::entity::notify_form_trigger_trap_card::NotifyFormTriggerTrapCard;
+use crate::notify_player_action_info::entity::notify_form_deploy_environment_card::NotifyFormDeployEnvironmentCard;
+use crate::notify_player_action_info::entity::notify_form_expire_environment_card::NotifyFormExpireEnvironmentCard;
+use crate::notify_player_action_info::entity::notify_form_summon_token_unit::NotifyFormSummonTokenUnit;
+use crate::notify_player_action_info::entity::notify_form_game_event_reaction::NotifyFormGameEventReaction;
 use crate::ui_data_generator::entity::field_unit_energy_info::FieldUnitEnergyInfo;
 use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::field_unit_death_info::{FieldUnitDeathInfo};
@@ -37,8 +44,10 @@ use crate::notify_player_action_info::entity::notify_form_use_search_deck_suppor
 use crate::notify_player_action_info::entity::notify_form_use_special_energy_card_to_unit::NotifyFormUseSpecialEnergyCardToUnit;
 use crate::notify_player_action_info::entity::notify_form_use_unit_energy_remove_item_card::NotifyFormUseUnitEnergyRemoveItemCard;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
 use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
 use crate::notify_player_action_info::repository::notify_player_action_info_repository::NotifyPlayerActionInfoRepository;
+use crate::response_generator::response_envelope::ResponseEnvelope;
 use crate::response_generator::response_type::ResponseType::*;
 use crate::ui_data_generator::entity::field_unit_basic_attack_info::FieldUnitAttackInfo;
 use crate::ui_data_generator::entity::field_unit_extra_effect_info::FieldUnitExtraEffectInfo;
@@ -89,8 +98,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_DEPLOY_UNIT(
-                        notify_form_deploy_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_DEPLOY_UNIT(
+                            notify_form_deploy_unit))))).await;
 
         true
     }
@@ -123,8 +133,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_FIELD_ENERGY_TO_UNIT(
-                        notify_form_use_field_energy_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_FIELD_ENERGY_TO_UNIT(
+                            notify_form_use_field_energy_to_unit))))).await;
 
         true
     }
@@ -159,8 +170,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_GENERAL_ENERGY_CARD_TO_UNIT(
-                        notify_form_use_general_energy_card_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_GENERAL_ENERGY_CARD_TO_UNIT(
+                            notify_form_use_general_energy_card_to_unit))))).await;
 
         true
     }
@@ -196,8 +208,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_SPECIAL_ENERGY_CARD_TO_UNIT(
-                        notify_form_use_special_energy_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_SPECIAL_ENERGY_CARD_TO_UNIT(
+                            notify_form_use_special_energy_to_unit))))).await;
 
         true
     }
@@ -233,8 +246,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_UNIT_ENERGY_BOOST_SUPPORT_CARD(
-                        notify_form_use_unit_energy_boost_support_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_UNIT_ENERGY_BOOST_SUPPORT_CARD(
+                            notify_form_use_unit_energy_boost_support_card))))).await;
 
         true
     }
@@ -267,8 +281,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_DRAW_SUPPORT_CARD(
-                        notify_form_use_draw_support_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_DRAW_SUPPORT_CARD(
+                            notify_form_use_draw_support_card))))).await;
 
         true
     }
@@ -301,8 +316,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_SEARCH_DECK_SUPPORT_CARD(
-                        notify_form_use_search_deck_support_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_SEARCH_DECK_SUPPORT_CARD(
+                            notify_form_use_search_deck_support_card))))).await;
 
         true
     }
@@ -335,8 +351,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_FIELD_ENERGY_REMOVE_ITEM_CARD(
-                        notify_form_use_field_energy_remove_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_FIELD_ENERGY_REMOVE_ITEM_CARD(
+                            notify_form_use_field_energy_remove_item_card))))).await;
 
         true
     }
@@ -371,8 +388,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_INSTANT_UNIT_DEATH_ITEM_CARD(
-                        notify_form_use_instant_unit_death_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_INSTANT_UNIT_DEATH_ITEM_CARD(
+                            notify_form_use_instant_unit_death_item_card))))).await;
 
         true
     }
@@ -408,8 +426,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_FIELD_ENERGY_INCREASE_ITEM_CARD(
-                        notify_form_use_field_energy_increase_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_FIELD_ENERGY_INCREASE_ITEM_CARD(
+                            notify_form_use_field_energy_increase_item_card))))).await;
 
         true
     }
@@ -451,8 +470,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_CATASTROPHIC_DAMAGE_ITEM_CARD(
-                        notify_form_use_catastrophic_damage_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_CATASTROPHIC_DAMAGE_ITEM_CARD(
+                            notify_form_use_catastrophic_damage_item_card))))).await;
 
         true
     }
@@ -490,8 +510,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_UNIT_ENERGY_REMOVE_ITEM_CARD(
-                        notify_form_use_unit_energy_remove_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_UNIT_ENERGY_REMOVE_ITEM_CARD(
+                            notify_form_use_unit_energy_remove_item_card))))).await;
 
         true
     }
@@ -527,8 +548,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_USE_MULTIPLE_UNIT_DAMAGE_ITEM_CARD(
-                        notify_form_use_multiple_unit_damage_item_card)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_USE_MULTIPLE_UNIT_DAMAGE_ITEM_CARD(
+                            notify_form_use_multiple_unit_damage_item_card))))).await;
 
         true
     }
@@ -566,8 +588,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_BASIC_ATTACK_TO_UNIT(
-                        notify_form_basic_attack_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_BASIC_ATTACK_TO_UNIT(
+                            notify_form_basic_attack_to_unit))))).await;
 
         true
     }
@@ -603,8 +626,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_BASIC_ATTACK_TO_MAIN_CHARACTER(
-                        notify_form_basic_attack_to_main_character)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_BASIC_ATTACK_TO_MAIN_CHARACTER(
+                            notify_form_basic_attack_to_main_character))))).await;
 
         true
     }
@@ -616,9 +640,13 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         player_field_energy_map: HashMap<PlayerIndex, i32>,
         player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
         player_field_unit_harmful_effect_map: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+        player_field_unit_extra_effect_map: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
         player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
         player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
         unit_index_turn_start_passive_list_map: HashMap<i32, Vec<i32>>,
+        player_drawn_card_count_map: HashMap<PlayerIndex, i32>,
+        is_turn_timed_out: bool,
+        remaining_turn_time_seconds: u64,
     ) -> bool {
 
         println!("NotifyPlayerActionInfoRepositoryImpl: notice_turn_end()");
@@ -628,8 +656,11 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
         let connection_context_map_guard = connection_context_map_mutex.lock().await;
 
-        let opponent_socket_option = connection_context_map_guard.get(&opponent_unique_id);
-        let opponent_socket_mutex = opponent_socket_option.unwrap();
+        // 시간 초과로 턴이 넘어가는 경우 대상이 이미 접속을 끊었을 수 있음
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => return false,
+        };
         let opponent_socket_guard = opponent_socket_mutex.lock().await;
 
         let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
@@ -639,16 +670,21 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
                                    player_field_energy_map,
                                    player_field_unit_health_point_map,
                                    player_field_unit_harmful_effect_map,
+                                   player_field_unit_extra_effect_map,
                                    player_field_unit_death_map,
                                    player_main_character_survival_map,
-                                   unit_index_turn_start_passive_list_map);
+                                   unit_index_turn_start_passive_list_map,
+                                   player_drawn_card_count_map,
+                                   is_turn_timed_out,
+                                   remaining_turn_time_seconds);
 
         // 내 턴 종료 공지
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TURN_END(
-                        notify_form_turn_end)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TURN_END(
+                            notify_form_turn_end))))).await;
 
         true
     }
@@ -686,8 +722,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TARGETING_ATTACK_ACTIVE_SKILL_TO_UNIT(
-                        notify_form_targeting_attack_active_skill_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TARGETING_ATTACK_ACTIVE_SKILL_TO_UNIT(
+                            notify_form_targeting_attack_active_skill_to_unit))))).await;
 
         true
     }
@@ -724,8 +761,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_NON_TARGETING_ACTIVE_SKILL(
-                        notify_form_non_targeting_active_skill)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_NON_TARGETING_ACTIVE_SKILL(
+                            notify_form_non_targeting_active_skill))))).await;
 
         true
     }
@@ -759,8 +797,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER(
-                        notify_form_targeting_attack_active_skill_to_game_main_character)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER(
+                            notify_form_targeting_attack_active_skill_to_game_main_character))))).await;
 
         true
     }
@@ -796,8 +835,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_DEPLOY_TARGETING_ATTACK_PASSIVE_SKILL_TO_UNIT(
-                        notify_form_deploy_targeting_attack_passive_skill_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_DEPLOY_TARGETING_ATTACK_PASSIVE_SKILL_TO_UNIT(
+                            notify_form_deploy_targeting_attack_passive_skill_to_unit))))).await;
 
         true
     }
@@ -833,8 +873,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_DEPLOY_NON_TARGETING_ATTACK_PASSIVE_SKILL(
-                        notify_form_deploy_non_targeting_attack_passive_skill)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_DEPLOY_NON_TARGETING_ATTACK_PASSIVE_SKILL(
+                            notify_form_deploy_non_targeting_attack_passive_skill))))).await;
 
         true
     }
@@ -868,8 +909,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_DEPLOY_TARGETING_ATTACK_TO_GAME_MAIN_CHARACTER(
-                        notify_form_deploy_targeting_attack_to_game_main_character)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_DEPLOY_TARGETING_ATTACK_TO_GAME_MAIN_CHARACTER(
+                            notify_form_deploy_targeting_attack_to_game_main_character))))).await;
 
         true
     }
@@ -905,8 +947,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TURN_START_TARGETING_ATTACK_PASSIVE_SKILL_TO_UNIT(
-                        notify_form_targeting_attack_passive_skill_to_unit)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TURN_START_TARGETING_ATTACK_PASSIVE_SKILL_TO_UNIT(
+                            notify_form_targeting_attack_passive_skill_to_unit))))).await;
 
         true
     }
@@ -942,8 +985,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TURN_START_NON_TARGETING_ATTACK_PASSIVE_SKILL(
-                        notify_form_non_targeting_attack_passive_skill)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TURN_START_NON_TARGETING_ATTACK_PASSIVE_SKILL(
+                            notify_form_non_targeting_attack_passive_skill))))).await;
 
         true
     }
@@ -977,8 +1021,9 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_TURN_START_TARGETING_ATTACK_TO_GAME_MAIN_CHARACTER(
-                        notify_form_turn_start_targeting_attack_to_game_main_character)))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_TURN_START_TARGETING_ATTACK_TO_GAME_MAIN_CHARACTER(
+                            notify_form_turn_start_targeting_attack_to_game_main_character))))).await;
 
         true
     }
@@ -1013,14 +1058,16 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         first_account_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_MULLIGAN_END(
-                        notify_form_mulligan_end.clone())))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_MULLIGAN_END(
+                            notify_form_mulligan_end.clone()))))).await;
 
         second_account_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_MULLIGAN_END(
-                        notify_form_mulligan_end.clone())))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_MULLIGAN_END(
+                            notify_form_mulligan_end.clone()))))).await;
 
         true
     }
@@ -1048,8 +1095,281 @@ impl NotifyPlayerActionInfoRepository for NotifyPlayerActionInfoRepositoryImpl {
         opponent_receiver_transmitter_channel.send(
             Arc::new(
                 AsyncMutex::new(
-                    NOTIFY_SURRENDER(
-                        notify_form_surrender.clone())))).await;
+                    ResponseEnvelope::notification(
+                        NOTIFY_SURRENDER(
+                            notify_form_surrender.clone()))))).await;
+
+        true
+    }
+
+    async fn notice_opponent_abandoned(
+        &mut self,
+        opponent_unique_id: i32
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_opponent_abandoned()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        // 남아 있는 플레이어도 이미 나갔을 수 있으므로 소켓이 없으면 알림을 생략
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => {
+                println!("Socket of opponent {} not found", opponent_unique_id);
+                return false
+            }
+        };
+        let opponent_socket_guard = opponent_socket_mutex.lock().await;
+
+        let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
+
+        let notify_form_opponent_abandoned = NotifyFormOpponentAbandoned::new(true);
+
+        opponent_receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_OPPONENT_ABANDONED(
+                            notify_form_opponent_abandoned))))).await;
+
+        true
+    }
+
+    async fn notice_set_trap_card(
+        &mut self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_set_trap_card()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => {
+                println!("Socket of opponent {} not found", opponent_unique_id);
+                return false
+            }
+        };
+        let opponent_socket_guard = opponent_socket_mutex.lock().await;
+
+        let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
+
+        // 상대에게는 함정을 세팅했다는 사실만 알리고 카드 번호는 숨김
+        let notify_form_set_trap_card =
+            NotifyFormSetTrapCard::new(player_hand_use_map_for_notice);
+
+        opponent_receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_SET_TRAP_CARD(
+                            notify_form_set_trap_card))))).await;
+
+        true
+    }
+
+    async fn notice_trigger_trap_card(
+        &mut self,
+        receiver_unique_id: i32,
+        player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+        player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+        player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_trigger_trap_card()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let receiver_socket_mutex = match connection_context_map_guard.get(&receiver_unique_id) {
+            Some(receiver_socket_mutex) => receiver_socket_mutex,
+            None => {
+                println!("Socket of receiver {} not found", receiver_unique_id);
+                return false
+            }
+        };
+        let receiver_socket_guard = receiver_socket_mutex.lock().await;
+
+        let receiver_transmitter_channel = receiver_socket_guard.each_client_receiver_transmitter_channel();
+
+        let notify_form_trigger_trap_card =
+            NotifyFormTriggerTrapCard::new(
+                player_trap_card_trigger_map,
+                player_field_unit_health_point_map,
+                player_field_unit_death_map,
+                player_main_character_health_point_map,
+                player_main_character_survival_map);
+
+        // 함정 발동은 세팅한 쪽에 공지 (발동시킨 쪽은 행동 응답에 합쳐서 받음)
+        receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_TRIGGER_TRAP_CARD(
+                            notify_form_trigger_trap_card))))).await;
+
+        true
+    }
+
+    async fn notice_deploy_environment_card(
+        &mut self,
+        opponent_unique_id: i32,
+        player_hand_use_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_deploy_environment_card()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => {
+                println!("Socket of opponent {} not found", opponent_unique_id);
+                return false
+            }
+        };
+        let opponent_socket_guard = opponent_socket_mutex.lock().await;
+
+        let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
+
+        let notify_form_deploy_environment_card =
+            NotifyFormDeployEnvironmentCard::new(player_hand_use_map_for_notice);
+
+        opponent_receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_DEPLOY_ENVIRONMENT_CARD(
+                            notify_form_deploy_environment_card))))).await;
+
+        true
+    }
+
+    async fn notice_expire_environment_card(
+        &mut self,
+        receiver_unique_id: i32,
+        player_expired_environment_card_map: HashMap<PlayerIndex, i32>
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_expire_environment_card()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let receiver_socket_mutex = match connection_context_map_guard.get(&receiver_unique_id) {
+            Some(receiver_socket_mutex) => receiver_socket_mutex,
+            None => {
+                println!("Socket of receiver {} not found", receiver_unique_id);
+                return false
+            }
+        };
+        let receiver_socket_guard = receiver_socket_mutex.lock().await;
+
+        let receiver_transmitter_channel = receiver_socket_guard.each_client_receiver_transmitter_channel();
+
+        // 환경은 양 플레이어가 공유하므로 만료 사실은 두 플레이어 모두에게 공지
+        let notify_form_expire_environment_card =
+            NotifyFormExpireEnvironmentCard::new(player_expired_environment_card_map);
+
+        receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_EXPIRE_ENVIRONMENT_CARD(
+                            notify_form_expire_environment_card))))).await;
+
+        true
+    }
+
+    async fn notice_summon_token_unit(
+        &mut self,
+        opponent_unique_id: i32,
+        player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_summon_token_unit()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => {
+                println!("Socket of opponent {} not found", opponent_unique_id);
+                return false
+            }
+        };
+        let opponent_socket_guard = opponent_socket_mutex.lock().await;
+
+        let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
+
+        let notify_form_summon_token_unit =
+            NotifyFormSummonTokenUnit::new(player_summoned_token_unit_map);
+
+        opponent_receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_SUMMON_TOKEN_UNIT(
+                            notify_form_summon_token_unit))))).await;
+
+        true
+    }
+
+    async fn notice_game_event_reaction(
+        &mut self,
+        opponent_unique_id: i32,
+        player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+        player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>
+    ) -> bool {
+
+        println!("NotifyPlayerActionInfoRepositoryImpl: notice_game_event_reaction()");
+
+        let connection_context_repository_mutex = ConnectionContextRepositoryImpl::get_instance();
+        let connection_context_repository_guard = connection_context_repository_mutex.lock().await;
+        let connection_context_map_mutex = connection_context_repository_guard.connection_context_map();
+        let connection_context_map_guard = connection_context_map_mutex.lock().await;
+
+        let opponent_socket_mutex = match connection_context_map_guard.get(&opponent_unique_id) {
+            Some(opponent_socket_mutex) => opponent_socket_mutex,
+            None => {
+                println!("Socket of opponent {} not found", opponent_unique_id);
+                return false
+            }
+        };
+        let opponent_socket_guard = opponent_socket_mutex.lock().await;
+
+        let opponent_receiver_transmitter_channel = opponent_socket_guard.each_client_receiver_transmitter_channel();
+
+        let notify_form_game_event_reaction =
+            NotifyFormGameEventReaction::new(player_field_unit_health_point_map, player_field_unit_death_map);
+
+        // 이벤트 반응으로 바뀐 유닛 체력 및 사망 공지
+        opponent_receiver_transmitter_channel.send(
+            Arc::new(
+                AsyncMutex::new(
+                    ResponseEnvelope::notification(
+                        NOTIFY_GAME_EVENT_REACTION(
+                            notify_form_game_event_reaction))))).await;
 
         true
     }
@@ -50,6 +50,18 @@ use crate::notify_player_action_info::service::response::notice_use_search_deck_
 use crate::notify_player_action_info::service::response::notice_use_special_energy_card_to_unit_response::NoticeUseSpecialEnergyCardToUnitResponse;
 use crate::notify_player_action_info::service::response::notice_use_unit_card_response::NoticeUseUnitCardResponse;
 use crate::notify_player_action_info::service::response::notice_use_unit_energy_remove_item_card_response::NoticeUseUnitEnergyRemoveItemCardResponse;
+use crate::notify_player_action_info::service::request::notice_set_trap_card_request::NoticeSetTrapCardRequest;
+use crate::notify_player_action_info::service::request::notice_trigger_trap_card_request::NoticeTriggerTrapCardRequest;
+use crate::notify_player_action_info::service::response::notice_set_trap_card_response::NoticeSetTrapCardResponse;
+use crate::notify_player_action_info::service::response::notice_trigger_trap_card_response::NoticeTriggerTrapCardResponse;
+use crate::notify_player_action_info::service::request::notice_deploy_environment_card_request::NoticeDeployEnvironmentCardRequest;
+use crate::notify_player_action_info::service::request::notice_expire_environment_card_request::NoticeExpireEnvironmentCardRequest;
+use crate::notify_player_action_info::service::response::notice_deploy_environment_card_response::NoticeDeployEnvironmentCardResponse;
+use crate::notify_player_action_info::service::response::notice_expire_environment_card_response::NoticeExpireEnvironmentCardResponse;
+use crate::notify_player_action_info::service::request::notice_summon_token_unit_request::NoticeSummonTokenUnitRequest;
+use crate::notify_player_action_info::service::response::notice_summon_token_unit_response::NoticeSummonTokenUnitResponse;
+use crate::notify_player_action_info::service::request::notice_game_event_reaction_request::NoticeGameEventReactionRequest;
+use crate::notify_player_action_info::service::response::notice_game_event_reaction_response::NoticeGameEventReactionResponse;
 
 #[async_trait]
 pub trait NotifyPlayerActionInfoService {
@@ -138,4 +150,22 @@ pub trait NotifyPlayerActionInfoService {
     async fn notice_turn_start_targeting_attack_to_game_main_character(
         &mut self, notice_turn_start_targeting_attack_to_game_main_character_request: NoticeTurnStartTargetingAttackToGameMainCharacterRequest)
         -> NoticeTurnStartTargetingAttackToGameMainCharacterResponse;
+    async fn notice_set_trap_card(
+        &mut self, notice_set_trap_card_request: NoticeSetTrapCardRequest)
+        -> NoticeSetTrapCardResponse;
+    async fn notice_trigger_trap_card(
+        &mut self, notice_trigger_trap_card_request: NoticeTriggerTrapCardRequest)
+        -> NoticeTriggerTrapCardResponse;
+    async fn notice_deploy_environment_card(
+        &mut self, notice_deploy_environment_card_request: NoticeDeployEnvironmentCardRequest)
+        -> NoticeDeployEnvironmentCardResponse;
+    async fn notice_expire_environment_card(
+        &mut self, notice_expire_environment_card_request: NoticeExpireEnvironmentCardRequest)
+        -> NoticeExpireEnvironmentCardResponse;
+    async fn notice_summon_token_unit(
+        &mut self, notice_summon_token_unit_request: NoticeSummonTokenUnitRequest)
+        -> NoticeSummonTokenUnitResponse;
+    async fn notice_game_event_reaction(
+        &mut self, notice_game_event_reaction_request: NoticeGameEventReactionRequest)
+        -> NoticeGameEventReactionResponse;
 }
\ No newline at end of file
@@ -58,6 +58,18 @@ use crate::notify_player_action_info::service::response::notice_use_search_deck_
 use crate::notify_player_action_info::service::response::notice_use_special_energy_card_to_unit_response::NoticeUseSpecialEnergyCardToUnitResponse;
 use crate::notify_player_action_info::service::response::notice_use_unit_card_response::NoticeUseUnitCardResponse;
 use crate::notify_player_action_info::service::response::notice_use_unit_energy_remove_item_card_response::NoticeUseUnitEnergyRemoveItemCardResponse;
+use crate::notify_player_action_info::service::request::notice_set_trap_card_request::NoticeSetTrapCardRequest;
+use crate::notify_player_action_info::service::request::notice_trigger_trap_card_request::NoticeTriggerTrapCardRequest;
+use crate::notify_player_action_info::service::response::notice_set_trap_card_response::NoticeSetTrapCardResponse;
+use crate::notify_player_action_info::service::response::notice_trigger_trap_card_response::NoticeTriggerTrapCardResponse;
+use crate::notify_player_action_info::service::request::notice_deploy_environment_card_request::NoticeDeployEnvironmentCardRequest;
+use crate::notify_player_action_info::service::request::notice_expire_environment_card_request::NoticeExpireEnvironmentCardRequest;
+use crate::notify_player_action_info::service::response::notice_deploy_environment_card_response::NoticeDeployEnvironmentCardResponse;
+use crate::notify_player_action_info::service::response::notice_expire_environment_card_response::NoticeExpireEnvironmentCardResponse;
+use crate::notify_player_action_info::service::request::notice_summon_token_unit_request::NoticeSummonTokenUnitRequest;
+use crate::notify_player_action_info::service::response::notice_summon_token_unit_response::NoticeSummonTokenUnitResponse;
+use crate::notify_player_action_info::service::request::notice_game_event_reaction_request::NoticeGameEventReactionRequest;
+use crate::notify_player_action_info::service::response::notice_game_event_reaction_response::NoticeGameEventReactionResponse;
 
 pub struct NotifyPlayerActionInfoServiceImpl {
     notify_player_action_info_repository: Arc<AsyncMutex<NotifyPlayerActionInfoRepositoryImpl>>,
@@ -427,9 +439,13 @@ impl NotifyPlayerActionInfoService for NotifyPlayerActionInfoServiceImpl {
                 notice_my_turn_end_request.get_player_field_energy_map_for_notice().clone(),
                 notice_my_turn_end_request.get_player_field_unit_health_point_map_for_notice().clone(),
                 notice_my_turn_end_request.get_player_field_unit_harmful_effect_map_for_notice().clone(),
+                notice_my_turn_end_request.get_player_field_unit_extra_effect_map_for_notice().clone(),
                 notice_my_turn_end_request.get_player_field_unit_death_map_for_notice().clone(),
                 notice_my_turn_end_request.get_player_main_character_survival_map_for_notice().clone(),
-                notice_my_turn_end_request.get_unit_index_turn_start_passive_list_map().clone()).await;
+                notice_my_turn_end_request.get_unit_index_turn_start_passive_list_map().clone(),
+                notice_my_turn_end_request.get_player_drawn_card_count_map_for_notice().clone(),
+                notice_my_turn_end_request.is_turn_timed_out(),
+                notice_my_turn_end_request.get_remaining_turn_time_seconds()).await;
 
         drop(notify_player_action_info_repository_guard);
 
@@ -615,4 +631,123 @@ impl NotifyPlayerActionInfoService for NotifyPlayerActionInfoServiceImpl {
 
         NoticeTurnStartTargetingAttackToGameMainCharacterResponse::new(response)
     }
+
+    async fn notice_set_trap_card(
+        &mut self, notice_set_trap_card_request: NoticeSetTrapCardRequest)
+        -> NoticeSetTrapCardResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_set_trap_card()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_set_trap_card(
+                notice_set_trap_card_request.get_opponent_unique_id(),
+                notice_set_trap_card_request.get_player_hand_use_map_for_notice().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeSetTrapCardResponse::new(response)
+    }
+
+    async fn notice_trigger_trap_card(
+        &mut self, notice_trigger_trap_card_request: NoticeTriggerTrapCardRequest)
+        -> NoticeTriggerTrapCardResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_trigger_trap_card()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_trigger_trap_card(
+                notice_trigger_trap_card_request.get_receiver_unique_id(),
+                notice_trigger_trap_card_request.get_player_trap_card_trigger_map().clone(),
+                notice_trigger_trap_card_request.get_player_field_unit_health_point_map().clone(),
+                notice_trigger_trap_card_request.get_player_field_unit_death_map().clone(),
+                notice_trigger_trap_card_request.get_player_main_character_health_point_map().clone(),
+                notice_trigger_trap_card_request.get_player_main_character_survival_map().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeTriggerTrapCardResponse::new(response)
+    }
+
+    async fn notice_deploy_environment_card(
+        &mut self, notice_deploy_environment_card_request: NoticeDeployEnvironmentCardRequest)
+        -> NoticeDeployEnvironmentCardResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_deploy_environment_card()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_deploy_environment_card(
+                notice_deploy_environment_card_request.get_opponent_unique_id(),
+                notice_deploy_environment_card_request.get_player_hand_use_map_for_notice().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeDeployEnvironmentCardResponse::new(response)
+    }
+
+    async fn notice_expire_environment_card(
+        &mut self, notice_expire_environment_card_request: NoticeExpireEnvironmentCardRequest)
+        -> NoticeExpireEnvironmentCardResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_expire_environment_card()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_expire_environment_card(
+                notice_expire_environment_card_request.get_receiver_unique_id(),
+                notice_expire_environment_card_request.get_player_expired_environment_card_map().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeExpireEnvironmentCardResponse::new(response)
+    }
+
+    async fn notice_summon_token_unit(
+        &mut self, notice_summon_token_unit_request: NoticeSummonTokenUnitRequest)
+        -> NoticeSummonTokenUnitResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_summon_token_unit()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_summon_token_unit(
+                notice_summon_token_unit_request.get_opponent_unique_id(),
+                notice_summon_token_unit_request.get_player_summoned_token_unit_map().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeSummonTokenUnitResponse::new(response)
+    }
+
+    async fn notice_game_event_reaction(
+        &mut self, notice_game_event_reaction_request: NoticeGameEventReactionRequest)
+        -> NoticeGameEventReactionResponse {
+
+        println!("NotifyPlayerActionInfoServiceImpl: notice_game_event_reaction()");
+
+        let mut notify_player_action_info_repository_guard =
+            self.notify_player_action_info_repository.lock().await;
+
+        let response =
+            notify_player_action_info_repository_guard.notice_game_event_reaction(
+                notice_game_event_reaction_request.get_opponent_unique_id(),
+                notice_game_event_reaction_request.get_player_field_unit_health_point_map().clone(),
+                notice_game_event_reaction_request.get_player_field_unit_death_map().clone()).await;
+
+        drop(notify_player_action_info_repository_guard);
+
+        NoticeGameEventReactionResponse::new(response)
+    }
 }
\ No newline at end of file
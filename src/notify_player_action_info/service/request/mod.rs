@@ -22,4 +22,10 @@ pub mod notice_deploy_non_targeting_attack_passive_skill_request;
 pub mod notice_turn_start_targeting_attack_passive_skill_to_unit_request;
 pub mod notice_turn_start_targeting_attack_to_game_main_character_request;
 pub mod notice_turn_start_non_targeting_attack_passive_skill_request;
-pub mod notice_targeting_attack_active_skill_to_game_main_character_request;
\ No newline at end of file
+pub mod notice_targeting_attack_active_skill_to_game_main_character_request;
+pub mod notice_set_trap_card_request;
+pub mod notice_trigger_trap_card_request;
+pub mod notice_deploy_environment_card_request;
+pub mod notice_expire_environment_card_request;
+pub mod notice_summon_token_unit_request;
+pub mod notice_game_event_reaction_request;
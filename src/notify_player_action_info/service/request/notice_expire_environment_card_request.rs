@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+#[derive(Debug)]
+pub struct NoticeExpireEnvironmentCardRequest {
+    receiver_unique_id: i32,
+    player_expired_environment_card_map: HashMap<PlayerIndex, i32>
+}
+
+impl NoticeExpireEnvironmentCardRequest {
+    pub fn new(receiver_unique_id: i32,
+               player_expired_environment_card_map: HashMap<PlayerIndex, i32>
+    ) -> Self {
+
+        NoticeExpireEnvironmentCardRequest {
+            receiver_unique_id,
+            player_expired_environment_card_map,
+        }
+    }
+
+    pub fn get_receiver_unique_id(&self) -> i32 { self.receiver_unique_id }
+
+    pub fn get_player_expired_environment_card_map(&self) -> &HashMap<PlayerIndex, i32> {
+        &self.player_expired_environment_card_map
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+#[derive(Debug)]
+pub struct NoticeGameEventReactionRequest {
+    opponent_unique_id: i32,
+    player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>
+}
+
+impl NoticeGameEventReactionRequest {
+    pub fn new(opponent_unique_id: i32,
+               player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>
+    ) -> Self {
+
+        NoticeGameEventReactionRequest {
+            opponent_unique_id,
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+        }
+    }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+
+    pub fn get_player_field_unit_health_point_map(&self) -> &HashMap<PlayerIndex, FieldUnitHealthPointInfo> {
+        &self.player_field_unit_health_point_map
+    }
+
+    pub fn get_player_field_unit_death_map(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
+        &self.player_field_unit_death_map
+    }
+}
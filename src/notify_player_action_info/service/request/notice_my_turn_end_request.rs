@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
 use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_extra_effect_info::FieldUnitExtraEffectInfo;
 use crate::ui_data_generator::entity::field_unit_harmful_status_info::FieldUnitHarmfulStatusInfo;
 use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
 use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
@@ -13,9 +14,13 @@ pub struct NoticeMyTurnEndRequest {
     player_field_energy_map_for_notice: HashMap<PlayerIndex, i32>,
     player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
     player_field_unit_harmful_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+    player_field_unit_extra_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
     player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>,
     player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>,
     unit_index_turn_start_passive_list_map: HashMap<i32, Vec<i32>>,
+    player_drawn_card_count_map_for_notice: HashMap<PlayerIndex, i32>,
+    is_turn_timed_out: bool,
+    remaining_turn_time_seconds: u64,
 }
 
 impl NoticeMyTurnEndRequest {
@@ -24,9 +29,13 @@ impl NoticeMyTurnEndRequest {
                player_field_energy_map_for_notice: HashMap<PlayerIndex, i32>,
                player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
                player_field_unit_harmful_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitHarmfulStatusInfo>,
+               player_field_unit_extra_effect_map_for_notice: HashMap<PlayerIndex, FieldUnitExtraEffectInfo>,
                player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>,
                player_main_character_survival_map_for_notice: HashMap<PlayerIndex, StatusMainCharacterEnum>,
                unit_index_turn_start_passive_list_map: HashMap<i32, Vec<i32>>,
+               player_drawn_card_count_map_for_notice: HashMap<PlayerIndex, i32>,
+               is_turn_timed_out: bool,
+               remaining_turn_time_seconds: u64,
     ) -> Self {
         NoticeMyTurnEndRequest {
             opponent_unique_id,
@@ -34,9 +43,13 @@ impl NoticeMyTurnEndRequest {
             player_field_energy_map_for_notice,
             player_field_unit_health_point_map_for_notice,
             player_field_unit_harmful_effect_map_for_notice,
+            player_field_unit_extra_effect_map_for_notice,
             player_field_unit_death_map_for_notice,
             player_main_character_survival_map_for_notice,
-            unit_index_turn_start_passive_list_map
+            unit_index_turn_start_passive_list_map,
+            player_drawn_card_count_map_for_notice,
+            is_turn_timed_out,
+            remaining_turn_time_seconds,
         }
     }
 
@@ -58,6 +71,10 @@ impl NoticeMyTurnEndRequest {
         &self.player_field_unit_harmful_effect_map_for_notice
     }
 
+    pub fn get_player_field_unit_extra_effect_map_for_notice(&self) -> &HashMap<PlayerIndex, FieldUnitExtraEffectInfo> {
+        &self.player_field_unit_extra_effect_map_for_notice
+    }
+
     pub fn get_player_field_unit_death_map_for_notice(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
         &self.player_field_unit_death_map_for_notice
     }
@@ -69,4 +86,12 @@ impl NoticeMyTurnEndRequest {
     pub fn get_unit_index_turn_start_passive_list_map(&self) -> &HashMap<i32, Vec<i32>> {
         &self.unit_index_turn_start_passive_list_map
     }
-}
\ No newline at end of file
+
+    pub fn get_player_drawn_card_count_map_for_notice(&self) -> &HashMap<PlayerIndex, i32> {
+        &self.player_drawn_card_count_map_for_notice
+    }
+
+    pub fn is_turn_timed_out(&self) -> bool { self.is_turn_timed_out }
+
+    pub fn get_remaining_turn_time_seconds(&self) -> u64 { self.remaining_turn_time_seconds }
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::summoned_token_unit_info::SummonedTokenUnitInfo;
+
+#[derive(Debug)]
+pub struct NoticeSummonTokenUnitRequest {
+    opponent_unique_id: i32,
+    player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>
+}
+
+impl NoticeSummonTokenUnitRequest {
+    pub fn new(opponent_unique_id: i32,
+               player_summoned_token_unit_map: HashMap<PlayerIndex, SummonedTokenUnitInfo>
+    ) -> Self {
+
+        NoticeSummonTokenUnitRequest {
+            opponent_unique_id,
+            player_summoned_token_unit_map,
+        }
+    }
+
+    pub fn get_opponent_unique_id(&self) -> i32 { self.opponent_unique_id }
+
+    pub fn get_player_summoned_token_unit_map(&self) -> &HashMap<PlayerIndex, SummonedTokenUnitInfo> {
+        &self.player_summoned_token_unit_map
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use crate::game_main_character::entity::status_main_character::StatusMainCharacterEnum;
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+
+#[derive(Debug)]
+pub struct NoticeTriggerTrapCardRequest {
+    receiver_unique_id: i32,
+    player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+    player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+    player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+}
+
+impl NoticeTriggerTrapCardRequest {
+    pub fn new(receiver_unique_id: i32,
+               player_trap_card_trigger_map: HashMap<PlayerIndex, UsedHandCardInfo>,
+               player_field_unit_health_point_map: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_death_map: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+               player_main_character_health_point_map: HashMap<PlayerIndex, i32>,
+               player_main_character_survival_map: HashMap<PlayerIndex, StatusMainCharacterEnum>,
+    ) -> Self {
+
+        NoticeTriggerTrapCardRequest {
+            receiver_unique_id,
+            player_trap_card_trigger_map,
+            player_field_unit_health_point_map,
+            player_field_unit_death_map,
+            player_main_character_health_point_map,
+            player_main_character_survival_map,
+        }
+    }
+
+    pub fn get_receiver_unique_id(&self) -> i32 { self.receiver_unique_id }
+
+    pub fn get_player_trap_card_trigger_map(&self) -> &HashMap<PlayerIndex, UsedHandCardInfo> {
+        &self.player_trap_card_trigger_map
+    }
+
+    pub fn get_player_field_unit_health_point_map(&self) -> &HashMap<PlayerIndex, FieldUnitHealthPointInfo> {
+        &self.player_field_unit_health_point_map
+    }
+
+    pub fn get_player_field_unit_death_map(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
+        &self.player_field_unit_death_map
+    }
+
+    pub fn get_player_main_character_health_point_map(&self) -> &HashMap<PlayerIndex, i32> {
+        &self.player_main_character_health_point_map
+    }
+
+    pub fn get_player_main_character_survival_map(&self) -> &HashMap<PlayerIndex, StatusMainCharacterEnum> {
+        &self.player_main_character_survival_map
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeDeployEnvironmentCardResponse {
+    is_success: bool,
+}
+
+impl NoticeDeployEnvironmentCardResponse {
+    pub fn new(is_success: bool) -> Self {
+        NoticeDeployEnvironmentCardResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
\ No newline at end of file
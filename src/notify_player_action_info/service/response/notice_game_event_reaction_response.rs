@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeGameEventReactionResponse {
+    is_success: bool,
+}
+
+impl NoticeGameEventReactionResponse {
+    pub fn new(is_success: bool) -> Self {
+        NoticeGameEventReactionResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeSetTrapCardResponse {
+    is_success: bool,
+}
+
+impl NoticeSetTrapCardResponse {
+    pub fn new(is_success: bool) -> Self {
+        NoticeSetTrapCardResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeSummonTokenUnitResponse {
+    is_success: bool,
+}
+
+impl NoticeSummonTokenUnitResponse {
+    pub fn new(is_success: bool) -> Self {
+        NoticeSummonTokenUnitResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoticeTriggerTrapCardResponse {
+    is_success: bool,
+}
+
+impl NoticeTriggerTrapCardResponse {
+    pub fn new(is_success: bool) -> Self {
+        NoticeTriggerTrapCardResponse { is_success }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod protocol_version;
+pub mod negotiated_protocol;
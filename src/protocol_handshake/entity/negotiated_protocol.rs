@@ -0,0 +1,51 @@
+use serde::Serialize;
+use crate::protocol_handshake::entity::protocol_version::{CURRENT_PROTOCOL_VERSION, ProtocolVersion};
+
+// 함정 카드를 모르는 클라이언트에는 함정 발동 결과 필드를 내려보내지 않음
+pub const TRAP_CARD_FEATURE: &str = "trapCard";
+
+// 서버가 알고 있는 기능 이름이며, 클라이언트가 보낸 목록과 겹치는 것만 사용함
+pub const SERVER_FEATURE_LIST: [&str; 5] = [
+    "requestId",
+    "protocolError",
+    "resumeBattle",
+    "serverMaintenanceNotice",
+    TRAP_CARD_FEATURE,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NegotiatedProtocol {
+    protocol_version: ProtocolVersion,
+    feature_list: Vec<String>,
+}
+
+impl NegotiatedProtocol {
+    pub fn new(protocol_version: ProtocolVersion, client_feature_list: &[String]) -> Self {
+        let feature_list = SERVER_FEATURE_LIST.iter()
+            .filter(|server_feature| client_feature_list.iter().any(|client_feature| client_feature == *server_feature))
+            .map(|server_feature| server_feature.to_string())
+            .collect();
+
+        NegotiatedProtocol { protocol_version, feature_list }
+    }
+
+    // 아직 handshake 전이라면 현재 서버 버전과 모든 기능을 지원한다고 보고 응답함
+    pub fn current() -> Self {
+        NegotiatedProtocol {
+            protocol_version: CURRENT_PROTOCOL_VERSION,
+            feature_list: SERVER_FEATURE_LIST.iter().map(|server_feature| server_feature.to_string()).collect(),
+        }
+    }
+
+    pub fn get_protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn get_feature_list(&self) -> &Vec<String> {
+        &self.feature_list
+    }
+
+    pub fn supports_feature(&self, feature: &str) -> bool {
+        self.feature_list.iter().any(|negotiated_feature| negotiated_feature == feature)
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use serde::{Serialize, Serializer};
+
+// protocolNumber 0 은 handshake(HELLO) 전용으로 예약되어 ProtocolRegistry 에 등록하지 않음
+pub const HELLO_PROTOCOL_NUMBER: i64 = 0;
+
+pub const CURRENT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::new(1, 1);
+pub const MINIMUM_SUPPORTED_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::new(1, 0);
+
+// 1.1 부터 응답에 messageKind / requestId envelope 필드가 포함됨
+pub const ENVELOPE_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::new(1, 1);
+
+// major 가 다르면 호환되지 않으며, minor 는 낮은 쪽에 맞춰 응답 형태를 내려보냄
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProtocolVersion {
+    major: u32,
+    minor: u32,
+}
+
+impl ProtocolVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        ProtocolVersion { major, minor }
+    }
+
+    // "1.1" 형태만 허용
+    pub fn parse(value: &str) -> Option<ProtocolVersion> {
+        let (major, minor) = value.trim().split_once('.')?;
+        Some(ProtocolVersion::new(major.parse().ok()?, minor.parse().ok()?))
+    }
+
+    // 서버가 받아들일 수 없는 버전이면 None
+    pub fn negotiate(client_protocol_version: ProtocolVersion) -> Option<ProtocolVersion> {
+        if client_protocol_version.major != CURRENT_PROTOCOL_VERSION.major ||
+            client_protocol_version < MINIMUM_SUPPORTED_PROTOCOL_VERSION {
+            return None
+        }
+
+        Some(client_protocol_version.min(CURRENT_PROTOCOL_VERSION))
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl Serialize for ProtocolVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ProtocolVersion::parse("1.1"), Some(ProtocolVersion::new(1, 1)));
+        assert_eq!(ProtocolVersion::parse(" 2.10 "), Some(ProtocolVersion::new(2, 10)));
+        assert_eq!(ProtocolVersion::parse("1"), None);
+        assert_eq!(ProtocolVersion::parse("1.x"), None);
+        assert_eq!(ProtocolVersion::new(1, 10).to_string(), "1.10");
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(ProtocolVersion::negotiate(ProtocolVersion::new(1, 0)), Some(ProtocolVersion::new(1, 0)));
+        assert_eq!(ProtocolVersion::negotiate(ProtocolVersion::new(1, 1)), Some(CURRENT_PROTOCOL_VERSION));
+        assert_eq!(ProtocolVersion::negotiate(ProtocolVersion::new(1, 7)), Some(CURRENT_PROTOCOL_VERSION));
+        assert_eq!(ProtocolVersion::negotiate(ProtocolVersion::new(0, 9)), None);
+        assert_eq!(ProtocolVersion::negotiate(ProtocolVersion::new(2, 0)), None);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod protocol_handshake_repository;
+pub mod protocol_handshake_repository_impl;
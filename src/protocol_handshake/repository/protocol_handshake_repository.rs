@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+
+#[async_trait]
+pub trait ProtocolHandshakeRepository {
+    async fn save_negotiated_protocol(&mut self, client_address: &str, negotiated_protocol: NegotiatedProtocol) -> bool;
+    async fn find_negotiated_protocol(&self, client_address: &str) -> Option<NegotiatedProtocol>;
+    async fn remove_negotiated_protocol(&mut self, client_address: &str) -> bool;
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+use crate::protocol_handshake::repository::protocol_handshake_repository::ProtocolHandshakeRepository;
+
+// Receiver 가 handshake 결과를 저장하고, Transmitter 는 같은 client address 로 조회하여 응답 형태를 맞춤
+pub struct ProtocolHandshakeRepositoryImpl {
+    negotiated_protocol_map: HashMap<String, NegotiatedProtocol>,
+}
+
+impl ProtocolHandshakeRepositoryImpl {
+    pub fn new() -> Self {
+        ProtocolHandshakeRepositoryImpl {
+            negotiated_protocol_map: HashMap::new(),
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ProtocolHandshakeRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ProtocolHandshakeRepositoryImpl>> =
+                Arc::new(AsyncMutex::new(ProtocolHandshakeRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ProtocolHandshakeRepository for ProtocolHandshakeRepositoryImpl {
+    async fn save_negotiated_protocol(&mut self, client_address: &str, negotiated_protocol: NegotiatedProtocol) -> bool {
+        println!("ProtocolHandshakeRepositoryImpl: save_negotiated_protocol() -> {}", client_address);
+
+        self.negotiated_protocol_map.insert(client_address.to_string(), negotiated_protocol);
+        true
+    }
+
+    async fn find_negotiated_protocol(&self, client_address: &str) -> Option<NegotiatedProtocol> {
+        self.negotiated_protocol_map.get(client_address).cloned()
+    }
+
+    async fn remove_negotiated_protocol(&mut self, client_address: &str) -> bool {
+        println!("ProtocolHandshakeRepositoryImpl: remove_negotiated_protocol() -> {}", client_address);
+
+        self.negotiated_protocol_map.remove(client_address).is_some()
+    }
+}
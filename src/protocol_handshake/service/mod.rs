@@ -0,0 +1,4 @@
+pub mod protocol_handshake_service;
+pub mod protocol_handshake_service_impl;
+pub mod request;
+pub mod response;
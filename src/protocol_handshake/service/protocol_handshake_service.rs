@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::protocol_handshake::service::request::find_negotiated_protocol_request::FindNegotiatedProtocolRequest;
+use crate::protocol_handshake::service::request::hello_request::HelloRequest;
+use crate::protocol_handshake::service::request::remove_negotiated_protocol_request::RemoveNegotiatedProtocolRequest;
+use crate::protocol_handshake::service::response::find_negotiated_protocol_response::FindNegotiatedProtocolResponse;
+use crate::protocol_handshake::service::response::hello_response::HelloResponse;
+use crate::protocol_handshake::service::response::remove_negotiated_protocol_response::RemoveNegotiatedProtocolResponse;
+
+#[async_trait]
+pub trait ProtocolHandshakeService {
+    async fn hello(&self, hello_request: HelloRequest) -> HelloResponse;
+    async fn find_negotiated_protocol(&self, find_negotiated_protocol_request: FindNegotiatedProtocolRequest) -> FindNegotiatedProtocolResponse;
+    async fn remove_negotiated_protocol(&self, remove_negotiated_protocol_request: RemoveNegotiatedProtocolRequest) -> RemoveNegotiatedProtocolResponse;
+}
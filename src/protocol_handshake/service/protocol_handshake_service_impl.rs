@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+use crate::protocol_handshake::entity::protocol_version::{CURRENT_PROTOCOL_VERSION, MINIMUM_SUPPORTED_PROTOCOL_VERSION, ProtocolVersion};
+use crate::protocol_handshake::repository::protocol_handshake_repository::ProtocolHandshakeRepository;
+use crate::protocol_handshake::repository::protocol_handshake_repository_impl::ProtocolHandshakeRepositoryImpl;
+use crate::protocol_handshake::service::protocol_handshake_service::ProtocolHandshakeService;
+use crate::protocol_handshake::service::request::find_negotiated_protocol_request::FindNegotiatedProtocolRequest;
+use crate::protocol_handshake::service::request::hello_request::HelloRequest;
+use crate::protocol_handshake::service::request::remove_negotiated_protocol_request::RemoveNegotiatedProtocolRequest;
+use crate::protocol_handshake::service::response::find_negotiated_protocol_response::FindNegotiatedProtocolResponse;
+use crate::protocol_handshake::service::response::hello_response::HelloResponse;
+use crate::protocol_handshake::service::response::remove_negotiated_protocol_response::RemoveNegotiatedProtocolResponse;
+
+pub struct ProtocolHandshakeServiceImpl {
+    protocol_handshake_repository: Arc<AsyncMutex<ProtocolHandshakeRepositoryImpl>>,
+}
+
+impl ProtocolHandshakeServiceImpl {
+    pub fn new(protocol_handshake_repository: Arc<AsyncMutex<ProtocolHandshakeRepositoryImpl>>) -> Self {
+        ProtocolHandshakeServiceImpl {
+            protocol_handshake_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ProtocolHandshakeServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ProtocolHandshakeServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ProtocolHandshakeServiceImpl::new(
+                            ProtocolHandshakeRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ProtocolHandshakeService for ProtocolHandshakeServiceImpl {
+    async fn hello(&self, hello_request: HelloRequest) -> HelloResponse {
+        println!("ProtocolHandshakeServiceImpl: hello() -> {} (build {}, protocol {})",
+                 hello_request.get_client_address(),
+                 hello_request.get_client_build(),
+                 hello_request.get_client_protocol_version());
+
+        let negotiated_protocol_version = match ProtocolVersion::negotiate(hello_request.get_client_protocol_version()) {
+            Some(negotiated_protocol_version) => negotiated_protocol_version,
+            None => {
+                println!("Incompatible client protocol version: {}", hello_request.get_client_protocol_version());
+                return HelloResponse::new(false,
+                                          CURRENT_PROTOCOL_VERSION.to_string(),
+                                          MINIMUM_SUPPORTED_PROTOCOL_VERSION.to_string(),
+                                          None,
+                                          Vec::new())
+            }
+        };
+
+        let negotiated_protocol = NegotiatedProtocol::new(negotiated_protocol_version, hello_request.get_feature_list());
+        let feature_list = negotiated_protocol.get_feature_list().clone();
+
+        let mut protocol_handshake_repository_guard = self.protocol_handshake_repository.lock().await;
+        protocol_handshake_repository_guard.save_negotiated_protocol(hello_request.get_client_address(), negotiated_protocol).await;
+        drop(protocol_handshake_repository_guard);
+
+        HelloResponse::new(true,
+                           CURRENT_PROTOCOL_VERSION.to_string(),
+                           MINIMUM_SUPPORTED_PROTOCOL_VERSION.to_string(),
+                           Some(negotiated_protocol_version.to_string()),
+                           feature_list)
+    }
+
+    async fn find_negotiated_protocol(&self, find_negotiated_protocol_request: FindNegotiatedProtocolRequest) -> FindNegotiatedProtocolResponse {
+        let protocol_handshake_repository_guard = self.protocol_handshake_repository.lock().await;
+        let negotiated_protocol =
+            protocol_handshake_repository_guard.find_negotiated_protocol(
+                find_negotiated_protocol_request.get_client_address()).await;
+
+        FindNegotiatedProtocolResponse::new(negotiated_protocol)
+    }
+
+    async fn remove_negotiated_protocol(&self, remove_negotiated_protocol_request: RemoveNegotiatedProtocolRequest) -> RemoveNegotiatedProtocolResponse {
+        println!("ProtocolHandshakeServiceImpl: remove_negotiated_protocol()");
+
+        let mut protocol_handshake_repository_guard = self.protocol_handshake_repository.lock().await;
+        let is_success =
+            protocol_handshake_repository_guard.remove_negotiated_protocol(
+                remove_negotiated_protocol_request.get_client_address()).await;
+
+        RemoveNegotiatedProtocolResponse::new(is_success)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_protocol_handshake_service() -> ProtocolHandshakeServiceImpl {
+        ProtocolHandshakeServiceImpl::new(Arc::new(AsyncMutex::new(ProtocolHandshakeRepositoryImpl::new())))
+    }
+
+    fn make_hello_request(client_address: &str, client_protocol_version: ProtocolVersion) -> HelloRequest {
+        HelloRequest::new(client_address.to_string(),
+                          client_protocol_version,
+                          "test-build".to_string(),
+                          vec!["requestId".to_string(), "unknownFeature".to_string()])
+    }
+
+    #[tokio::test]
+    async fn test_hello_downgrades_older_client() {
+        let protocol_handshake_service = make_protocol_handshake_service();
+
+        let hello_response =
+            protocol_handshake_service.hello(make_hello_request("127.0.0.1:1", ProtocolVersion::new(1, 0))).await;
+
+        assert!(hello_response.is_accepted());
+        assert_eq!(hello_response.get_negotiated_protocol_version(), Some(&"1.0".to_string()));
+        assert_eq!(hello_response.get_feature_list(), &vec!["requestId".to_string()]);
+
+        let find_negotiated_protocol_response =
+            protocol_handshake_service.find_negotiated_protocol(
+                FindNegotiatedProtocolRequest::new("127.0.0.1:1".to_string())).await;
+        let negotiated_protocol = find_negotiated_protocol_response.get_negotiated_protocol().unwrap();
+        assert_eq!(negotiated_protocol.get_protocol_version(), ProtocolVersion::new(1, 0));
+        assert!(negotiated_protocol.supports_feature("requestId"));
+        assert!(!negotiated_protocol.supports_feature("unknownFeature"));
+    }
+
+    #[tokio::test]
+    async fn test_hello_rejects_incompatible_client() {
+        let protocol_handshake_service = make_protocol_handshake_service();
+
+        let hello_response =
+            protocol_handshake_service.hello(make_hello_request("127.0.0.1:2", ProtocolVersion::new(2, 0))).await;
+
+        assert!(!hello_response.is_accepted());
+        assert_eq!(hello_response.get_negotiated_protocol_version(), None);
+
+        let find_negotiated_protocol_response =
+            protocol_handshake_service.find_negotiated_protocol(
+                FindNegotiatedProtocolRequest::new("127.0.0.1:2".to_string())).await;
+        assert!(find_negotiated_protocol_response.get_negotiated_protocol().is_none());
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct FindNegotiatedProtocolRequest {
+    client_address: String,
+}
+
+impl FindNegotiatedProtocolRequest {
+    pub fn new(client_address: String) -> Self {
+        FindNegotiatedProtocolRequest {
+            client_address,
+        }
+    }
+
+    pub fn get_client_address(&self) -> &str {
+        &self.client_address
+    }
+}
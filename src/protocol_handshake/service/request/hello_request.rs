@@ -0,0 +1,40 @@
+use crate::protocol_handshake::entity::protocol_version::ProtocolVersion;
+
+#[derive(Debug)]
+pub struct HelloRequest {
+    client_address: String,
+    client_protocol_version: ProtocolVersion,
+    client_build: String,
+    feature_list: Vec<String>,
+}
+
+impl HelloRequest {
+    pub fn new(client_address: String,
+               client_protocol_version: ProtocolVersion,
+               client_build: String,
+               feature_list: Vec<String>) -> Self {
+
+        HelloRequest {
+            client_address,
+            client_protocol_version,
+            client_build,
+            feature_list,
+        }
+    }
+
+    pub fn get_client_address(&self) -> &str {
+        &self.client_address
+    }
+
+    pub fn get_client_protocol_version(&self) -> ProtocolVersion {
+        self.client_protocol_version
+    }
+
+    pub fn get_client_build(&self) -> &str {
+        &self.client_build
+    }
+
+    pub fn get_feature_list(&self) -> &Vec<String> {
+        &self.feature_list
+    }
+}
@@ -0,0 +1,3 @@
+pub mod hello_request;
+pub mod find_negotiated_protocol_request;
+pub mod remove_negotiated_protocol_request;
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveNegotiatedProtocolRequest {
+    client_address: String,
+}
+
+impl RemoveNegotiatedProtocolRequest {
+    pub fn new(client_address: String) -> Self {
+        RemoveNegotiatedProtocolRequest {
+            client_address,
+        }
+    }
+
+    pub fn get_client_address(&self) -> &str {
+        &self.client_address
+    }
+}
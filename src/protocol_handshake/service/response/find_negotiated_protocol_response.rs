@@ -0,0 +1,18 @@
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+
+#[derive(Debug)]
+pub struct FindNegotiatedProtocolResponse {
+    negotiated_protocol: Option<NegotiatedProtocol>,
+}
+
+impl FindNegotiatedProtocolResponse {
+    pub fn new(negotiated_protocol: Option<NegotiatedProtocol>) -> Self {
+        FindNegotiatedProtocolResponse {
+            negotiated_protocol,
+        }
+    }
+
+    pub fn get_negotiated_protocol(&self) -> Option<&NegotiatedProtocol> {
+        self.negotiated_protocol.as_ref()
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+// 거절된 경우 negotiated_protocol_version 은 비어 있으며 서버가 곧 연결을 끊음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelloResponse {
+    is_accepted: bool,
+    server_protocol_version: String,
+    minimum_protocol_version: String,
+    negotiated_protocol_version: Option<String>,
+    feature_list: Vec<String>,
+}
+
+impl HelloResponse {
+    pub fn new(is_accepted: bool,
+               server_protocol_version: String,
+               minimum_protocol_version: String,
+               negotiated_protocol_version: Option<String>,
+               feature_list: Vec<String>) -> Self {
+
+        HelloResponse {
+            is_accepted,
+            server_protocol_version,
+            minimum_protocol_version,
+            negotiated_protocol_version,
+            feature_list,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.is_accepted
+    }
+
+    pub fn get_negotiated_protocol_version(&self) -> Option<&String> {
+        self.negotiated_protocol_version.as_ref()
+    }
+
+    pub fn get_feature_list(&self) -> &Vec<String> {
+        &self.feature_list
+    }
+}
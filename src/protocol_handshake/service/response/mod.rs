@@ -0,0 +1,3 @@
+pub mod hello_response;
+pub mod find_negotiated_protocol_response;
+pub mod remove_negotiated_protocol_response;
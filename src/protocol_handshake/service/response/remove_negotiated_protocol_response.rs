@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveNegotiatedProtocolResponse {
+    is_success: bool,
+}
+
+impl RemoveNegotiatedProtocolResponse {
+    pub fn new(is_success: bool) -> Self {
+        RemoveNegotiatedProtocolResponse {
+            is_success,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
@@ -0,0 +1,4 @@
+pub mod protocol_handler;
+pub mod protocol_registration_error;
+pub mod protocol_registry;
+pub mod protocol_error_response_form;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use crate::common::message::false_message_enum::FalseMessage;
+use crate::common::message::protocol_error_code_enum::ProtocolErrorCode;
+use crate::request_generator::request_parse_error::RequestParseError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolErrorResponseForm {
+    protocol_number: i64,
+    error_code: i32,
+    false_message_enum: i32,
+    message: String,
+}
+
+impl ProtocolErrorResponseForm {
+    pub fn new(protocol_number: i64, error_code: ProtocolErrorCode, message: String) -> Self {
+        ProtocolErrorResponseForm {
+            protocol_number,
+            error_code: error_code as i32,
+            false_message_enum: FalseMessage::Dummy as i32,
+            message,
+        }
+    }
+
+    pub fn from_request_parse_error(protocol_number: i64, request_parse_error: &RequestParseError) -> Self {
+        let error_code = match request_parse_error {
+            RequestParseError::MissingField { .. } => ProtocolErrorCode::MissingField,
+            RequestParseError::WrongFieldType { .. } => ProtocolErrorCode::WrongFieldType,
+            RequestParseError::InvalidFieldValue { .. } => ProtocolErrorCode::InvalidFieldValue,
+        };
+
+        ProtocolErrorResponseForm::new(protocol_number, error_code, request_parse_error.get_message())
+    }
+
+    pub fn from_false_message(protocol_number: i64, false_message: FalseMessage) -> Self {
+        ProtocolErrorResponseForm {
+            protocol_number,
+            error_code: ProtocolErrorCode::ValidationFailed as i32,
+            false_message_enum: false_message as i32,
+            message: format!("validation failed: {:?}", false_message),
+        }
+    }
+
+    pub fn get_protocol_number(&self) -> i64 {
+        self.protocol_number
+    }
+
+    pub fn get_error_code(&self) -> i32 {
+        self.error_code
+    }
+
+    pub fn get_false_message_enum(&self) -> i32 {
+        self.false_message_enum
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_request_parse_error() {
+        let response_form = ProtocolErrorResponseForm::from_request_parse_error(
+            1004, &RequestParseError::MissingField { field_name: "unitId".to_string() });
+
+        assert_eq!(response_form.get_protocol_number(), 1004);
+        assert_eq!(response_form.get_error_code(), ProtocolErrorCode::MissingField as i32);
+        assert_eq!(response_form.get_message(), "missing field: unitId");
+    }
+
+    #[test]
+    fn test_from_false_message() {
+        let response_form = ProtocolErrorResponseForm::from_false_message(1000, FalseMessage::NotYourTurn);
+
+        assert_eq!(response_form.get_error_code(), ProtocolErrorCode::ValidationFailed as i32);
+        assert_eq!(response_form.get_false_message_enum(), FalseMessage::NotYourTurn as i32);
+    }
+}
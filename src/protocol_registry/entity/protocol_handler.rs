@@ -0,0 +1,15 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use serde_json::Value as JsonValue;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::response_generator::response_type::ResponseType;
+
+pub type ProtocolFuture = Pin<Box<dyn Future<Output = ResponseType> + Send>>;
+
+// request 파싱에 실패하면 RequestParseError 를 반환하고, 성공하면 service 를 호출할 future 를 반환
+pub type ProtocolHandler = Arc<dyn Fn(&JsonValue) -> Result<ProtocolFuture, RequestParseError> + Send + Sync>;
+
+// 각 도메인이 자신의 protocol 을 등록할 때 사용하는 함수
+pub type ProtocolRegisterFunction = fn(&mut ProtocolRegistry);
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtocolRegistrationError {
+    DuplicateProtocolNumber {
+        protocol_number: i64,
+        registered_protocol_name: String,
+        rejected_protocol_name: String,
+    },
+    InvalidProtocolNumber {
+        protocol_number: i64,
+        protocol_name: String,
+    },
+}
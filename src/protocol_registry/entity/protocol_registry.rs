@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+use serde_json::Value as JsonValue;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::dispatch_battle_room_action;
+use crate::common::message::false_message_enum::FalseMessage;
+use crate::protocol_registry::entity::protocol_error_response_form::ProtocolErrorResponseForm;
+use crate::protocol_registry::entity::protocol_handler::{ProtocolFuture, ProtocolHandler};
+use crate::protocol_registry::entity::protocol_registration_error::ProtocolRegistrationError;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::response_generator::response_type::ResponseType;
+
+#[derive(Clone)]
+pub struct ProtocolEntry {
+    protocol_name: String,
+    handler: ProtocolHandler,
+}
+
+impl ProtocolEntry {
+    pub fn get_protocol_name(&self) -> &str {
+        &self.protocol_name
+    }
+
+    pub fn get_handler(&self) -> ProtocolHandler {
+        self.handler.clone()
+    }
+}
+
+pub struct ProtocolRegistry {
+    protocol_map: BTreeMap<i64, ProtocolEntry>,
+    registration_error_list: Vec<ProtocolRegistrationError>,
+}
+
+impl ProtocolRegistry {
+    pub fn new() -> Self {
+        ProtocolRegistry {
+            protocol_map: BTreeMap::new(),
+            registration_error_list: Vec::new(),
+        }
+    }
+
+    pub fn register<Request, RequestParser, Handler, HandlerFuture>(&mut self,
+                                                                     protocol_number: i64,
+                                                                     protocol_name: &str,
+                                                                     request_parser: RequestParser,
+                                                                     handler: Handler)
+        where Request: Send + 'static,
+              RequestParser: Fn(&JsonValue) -> Result<Request, RequestParseError> + Send + Sync + 'static,
+              Handler: Fn(Request) -> HandlerFuture + Send + Sync + 'static,
+              HandlerFuture: Future<Output = ResponseType> + Send + 'static {
+
+        let handler = Arc::new(handler);
+        let protocol_handler: ProtocolHandler = Arc::new(move |data: &JsonValue| {
+            let request = request_parser(data)?;
+            let future: ProtocolFuture = Box::pin(handler(request));
+            Ok(future)
+        });
+
+        self.register_handler(protocol_number, protocol_name, protocol_handler);
+    }
+
+    // 게임 중 행동은 sessionInfo 로 배틀 룸을 찾아 해당 방의 Actor 에서 실행
+    // Actor 에는 요청 데이터와 이를 처리할 handler 를 보내고, 요청은 방의 상태 안에서 다시 읽어 service 를 호출
+    pub fn register_battle_room_action<Request, RequestParser, Handler, HandlerFuture>(&mut self,
+                                                                                        protocol_number: i64,
+                                                                                        protocol_name: &str,
+                                                                                        request_parser: RequestParser,
+                                                                                        handler: Handler)
+        where Request: Send + 'static,
+              RequestParser: Fn(&JsonValue) -> Result<Request, RequestParseError> + Send + Sync + 'static,
+              Handler: Fn(Request) -> HandlerFuture + Send + Sync + 'static,
+              HandlerFuture: Future<Output = ResponseType> + Send + 'static {
+
+        let request_parser = Arc::new(request_parser);
+        let handler = Arc::new(handler);
+
+        let action_request_parser = request_parser.clone();
+        let action_handler: ProtocolHandler = Arc::new(move |data: &JsonValue| {
+            let request = action_request_parser(data)?;
+            let action_future: ProtocolFuture = Box::pin(reject_validation_failure(protocol_number, handler(request)));
+            Ok(action_future)
+        });
+
+        let protocol_handler: ProtocolHandler = Arc::new(move |data: &JsonValue| {
+            // 잘못된 요청은 방으로 보내지 않고 바로 PROTOCOL_ERROR 로 응답
+            request_parser(data)?;
+            let session_id = read_str_field(data, "sessionInfo").ok().map(|session_id| session_id.to_string());
+
+            let future: ProtocolFuture =
+                Box::pin(dispatch_battle_room_action(protocol_number, session_id, action_handler.clone(), data.clone()));
+            Ok(future)
+        });
+
+        self.register_handler(protocol_number, protocol_name, protocol_handler);
+    }
+
+    pub fn register_handler(&mut self, protocol_number: i64, protocol_name: &str, handler: ProtocolHandler) {
+        if protocol_number <= 0 {
+            self.registration_error_list.push(
+                ProtocolRegistrationError::InvalidProtocolNumber {
+                    protocol_number,
+                    protocol_name: protocol_name.to_string(),
+                });
+            return
+        }
+
+        if let Some(registered_entry) = self.protocol_map.get(&protocol_number) {
+            self.registration_error_list.push(
+                ProtocolRegistrationError::DuplicateProtocolNumber {
+                    protocol_number,
+                    registered_protocol_name: registered_entry.get_protocol_name().to_string(),
+                    rejected_protocol_name: protocol_name.to_string(),
+                });
+            return
+        }
+
+        self.protocol_map.insert(protocol_number, ProtocolEntry {
+            protocol_name: protocol_name.to_string(),
+            handler,
+        });
+    }
+
+    pub fn find_handler(&self, protocol_number: i64) -> Option<ProtocolHandler> {
+        self.protocol_map.get(&protocol_number).map(|entry| entry.get_handler())
+    }
+
+    pub fn get_registered_protocol_list(&self) -> Vec<(i64, String)> {
+        self.protocol_map.iter()
+            .map(|(protocol_number, entry)| (*protocol_number, entry.get_protocol_name().to_string()))
+            .collect()
+    }
+
+    pub fn get_registration_error_list(&self) -> &Vec<ProtocolRegistrationError> {
+        &self.registration_error_list
+    }
+}
+
+// NotYourTurn 처럼 요청 자체를 받아들이지 않은 경우는 도메인 응답 대신 PROTOCOL_ERROR 로 돌려줌
+async fn reject_validation_failure<HandlerFuture>(protocol_number: i64, handler_future: HandlerFuture) -> ResponseType
+    where HandlerFuture: Future<Output = ResponseType> + Send + 'static {
+
+    let response = handler_future.await;
+
+    match response.find_false_message_enum().map(FalseMessage::from) {
+        Some(false_message) if false_message.is_validation_failure() =>
+            ResponseType::PROTOCOL_ERROR(ProtocolErrorResponseForm::from_false_message(protocol_number, false_message)),
+        _ => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+    use crate::client_program::service::response::client_program_exit_response::ClientProgramExitResponse;
+    use crate::common::message::protocol_error_code_enum::ProtocolErrorCode;
+    use crate::game_card_trap::controller::response_form::set_trap_card_response_form::SetTrapCardResponseForm;
+    use crate::request_generator::request_field_reader::read_bool_field;
+
+    fn parse_exit(data: &JsonValue) -> Result<bool, RequestParseError> {
+        read_bool_field(data, "exit")
+    }
+
+    fn register_exit(protocol_registry: &mut ProtocolRegistry, protocol_number: i64, protocol_name: &str) {
+        protocol_registry.register(protocol_number, protocol_name, parse_exit, |exit| async move {
+            ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(exit))
+        });
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_registered_protocol() {
+        let mut protocol_registry = ProtocolRegistry::new();
+        register_exit(&mut protocol_registry, 4444, "PROGRAM_EXIT");
+
+        let handler = protocol_registry.find_handler(4444).unwrap();
+        let response = handler(&json!({ "exit": true })).ok().unwrap().await;
+
+        match response {
+            ResponseType::PROGRAM_EXIT(exit_response) => assert!(exit_response.does_client_exit_success()),
+            _ => panic!("unexpected response"),
+        }
+
+        assert!(matches!(handler(&json!({ "exit": "wrong type" })),
+                         Err(RequestParseError::WrongFieldType { .. })));
+        assert!(protocol_registry.find_handler(4445).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_battle_room_action_without_session_runs_directly() {
+        let mut protocol_registry = ProtocolRegistry::new();
+        protocol_registry.register_battle_room_action(4444, "PROGRAM_EXIT", parse_exit, |exit| async move {
+            ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(exit))
+        });
+
+        let handler = protocol_registry.find_handler(4444).unwrap();
+        let response = handler(&json!({ "exit": true })).ok().unwrap().await;
+
+        assert!(matches!(response, ResponseType::PROGRAM_EXIT(_)));
+
+        // 잘못된 요청은 방을 찾기 전에 바로 실패
+        assert!(matches!(handler(&json!({ "exit": "wrong type", "sessionInfo": "session" })),
+                         Err(RequestParseError::WrongFieldType { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_battle_room_action_validation_failure_becomes_protocol_error() {
+        let mut protocol_registry = ProtocolRegistry::new();
+        protocol_registry.register_battle_room_action(4444, "SET_TRAP_CARD", parse_exit, |exit| async move {
+            if exit {
+                return ResponseType::SET_TRAP_CARD(SetTrapCardResponseForm::from_false_response_with_message(FalseMessage::NotYourTurn))
+            }
+            ResponseType::SET_TRAP_CARD(SetTrapCardResponseForm::from_false_response_with_message(FalseMessage::TrapZoneFull))
+        });
+
+        let handler = protocol_registry.find_handler(4444).unwrap();
+
+        match handler(&json!({ "exit": true })).ok().unwrap().await {
+            ResponseType::PROTOCOL_ERROR(protocol_error_response_form) => {
+                assert_eq!(protocol_error_response_form.get_protocol_number(), 4444);
+                assert_eq!(protocol_error_response_form.get_error_code(), ProtocolErrorCode::ValidationFailed as i32);
+                assert_eq!(protocol_error_response_form.get_false_message_enum(), FalseMessage::NotYourTurn as i32);
+            },
+            _ => panic!("unexpected response"),
+        }
+
+        // 게임 규칙상 실패는 그대로 도메인 응답으로 전달
+        let response = handler(&json!({ "exit": false })).ok().unwrap().await;
+        assert!(matches!(response, ResponseType::SET_TRAP_CARD(_)));
+    }
+
+    #[test]
+    fn test_duplicate_and_invalid_protocol_number() {
+        let mut protocol_registry = ProtocolRegistry::new();
+        register_exit(&mut protocol_registry, 4444, "PROGRAM_EXIT");
+        register_exit(&mut protocol_registry, 4444, "ANOTHER_EXIT");
+        register_exit(&mut protocol_registry, 0, "ZERO");
+
+        assert_eq!(protocol_registry.get_registered_protocol_list(), vec![(4444, "PROGRAM_EXIT".to_string())]);
+        assert_eq!(protocol_registry.get_registration_error_list(), &vec![
+            ProtocolRegistrationError::DuplicateProtocolNumber {
+                protocol_number: 4444,
+                registered_protocol_name: "PROGRAM_EXIT".to_string(),
+                rejected_protocol_name: "ANOTHER_EXIT".to_string(),
+            },
+            ProtocolRegistrationError::InvalidProtocolNumber {
+                protocol_number: 0,
+                protocol_name: "ZERO".to_string(),
+            },
+        ]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod protocol_registry_repository;
+pub mod protocol_registry_repository_impl;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::protocol_registry::entity::protocol_handler::{ProtocolHandler, ProtocolRegisterFunction};
+use crate::protocol_registry::entity::protocol_registration_error::ProtocolRegistrationError;
+
+#[async_trait]
+pub trait ProtocolRegistryRepository {
+    async fn register_domain_protocol(&mut self, register_function: ProtocolRegisterFunction);
+    async fn find_protocol_handler(&self, protocol_number: i64) -> Option<ProtocolHandler>;
+    async fn get_registered_protocol_list(&self) -> Vec<(i64, String)>;
+    async fn get_registration_error_list(&self) -> Vec<ProtocolRegistrationError>;
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::protocol_registry::entity::protocol_handler::{ProtocolHandler, ProtocolRegisterFunction};
+use crate::protocol_registry::entity::protocol_registration_error::ProtocolRegistrationError;
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::protocol_registry::repository::protocol_registry_repository::ProtocolRegistryRepository;
+
+pub struct ProtocolRegistryRepositoryImpl {
+    protocol_registry: ProtocolRegistry,
+}
+
+impl ProtocolRegistryRepositoryImpl {
+    pub fn new() -> Self {
+        ProtocolRegistryRepositoryImpl {
+            protocol_registry: ProtocolRegistry::new(),
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ProtocolRegistryRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ProtocolRegistryRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ProtocolRegistryRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ProtocolRegistryRepository for ProtocolRegistryRepositoryImpl {
+    async fn register_domain_protocol(&mut self, register_function: ProtocolRegisterFunction) {
+        println!("ProtocolRegistryRepositoryImpl: register_domain_protocol()");
+
+        register_function(&mut self.protocol_registry);
+    }
+
+    async fn find_protocol_handler(&self, protocol_number: i64) -> Option<ProtocolHandler> {
+        println!("ProtocolRegistryRepositoryImpl: find_protocol_handler()");
+
+        self.protocol_registry.find_handler(protocol_number)
+    }
+
+    async fn get_registered_protocol_list(&self) -> Vec<(i64, String)> {
+        println!("ProtocolRegistryRepositoryImpl: get_registered_protocol_list()");
+
+        self.protocol_registry.get_registered_protocol_list()
+    }
+
+    async fn get_registration_error_list(&self) -> Vec<ProtocolRegistrationError> {
+        println!("ProtocolRegistryRepositoryImpl: get_registration_error_list()");
+
+        self.protocol_registry.get_registration_error_list().clone()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod protocol_registry_service;
+pub mod protocol_registry_service_impl;
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::protocol_registry::entity::protocol_handler::{ProtocolHandler, ProtocolRegisterFunction};
+use crate::protocol_registry::entity::protocol_registration_error::ProtocolRegistrationError;
+use crate::protocol_registry::repository::protocol_registry_repository::ProtocolRegistryRepository;
+use crate::protocol_registry::repository::protocol_registry_repository_impl::ProtocolRegistryRepositoryImpl;
+use crate::protocol_registry::service::protocol_registry_service::ProtocolRegistryService;
+
+pub struct ProtocolRegistryServiceImpl {
+    protocol_registry_repository: Arc<AsyncMutex<ProtocolRegistryRepositoryImpl>>,
+}
+
+impl ProtocolRegistryServiceImpl {
+    pub fn new(protocol_registry_repository: Arc<AsyncMutex<ProtocolRegistryRepositoryImpl>>) -> Self {
+        ProtocolRegistryServiceImpl {
+            protocol_registry_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ProtocolRegistryServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ProtocolRegistryServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ProtocolRegistryServiceImpl::new(
+                            ProtocolRegistryRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ProtocolRegistryService for ProtocolRegistryServiceImpl {
+    async fn register_domain_protocol(&mut self, register_function: ProtocolRegisterFunction) {
+        println!("ProtocolRegistryServiceImpl: register_domain_protocol()");
+
+        let mut protocol_registry_repository_guard = self.protocol_registry_repository.lock().await;
+        protocol_registry_repository_guard.register_domain_protocol(register_function).await;
+    }
+
+    async fn find_protocol_handler(&self, protocol_number: i64) -> Option<ProtocolHandler> {
+        println!("ProtocolRegistryServiceImpl: find_protocol_handler()");
+
+        let protocol_registry_repository_guard = self.protocol_registry_repository.lock().await;
+        protocol_registry_repository_guard.find_protocol_handler(protocol_number).await
+    }
+
+    async fn get_registered_protocol_list(&self) -> Vec<(i64, String)> {
+        println!("ProtocolRegistryServiceImpl: get_registered_protocol_list()");
+
+        let protocol_registry_repository_guard = self.protocol_registry_repository.lock().await;
+        protocol_registry_repository_guard.get_registered_protocol_list().await
+    }
+
+    async fn report_protocol_registration(&self) -> Result<(), Vec<ProtocolRegistrationError>> {
+        println!("ProtocolRegistryServiceImpl: report_protocol_registration()");
+
+        let registered_protocol_list = self.get_registered_protocol_list().await;
+
+        let protocol_registry_repository_guard = self.protocol_registry_repository.lock().await;
+        let registration_error_list = protocol_registry_repository_guard.get_registration_error_list().await;
+        drop(protocol_registry_repository_guard);
+
+        println!("Registered protocol count: {}", registered_protocol_list.len());
+        for (protocol_number, protocol_name) in &registered_protocol_list {
+            println!("  {:>5} => {}", protocol_number, protocol_name);
+        }
+
+        for registration_error in &registration_error_list {
+            eprintln!("Protocol registration error: {:?}", registration_error);
+        }
+
+        if registration_error_list.is_empty() {
+            return Ok(())
+        }
+
+        Err(registration_error_list)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client_program::service::response::client_program_exit_response::ClientProgramExitResponse;
+    use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+    use crate::request_generator::request_field_reader::read_bool_field;
+    use crate::response_generator::response_type::ResponseType;
+
+    fn register_duplicate_exit(protocol_registry: &mut ProtocolRegistry) {
+        for protocol_name in ["PROGRAM_EXIT", "ANOTHER_EXIT"] {
+            protocol_registry.register(4444, protocol_name, |data| read_bool_field(data, "exit"), |exit| async move {
+                ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(exit))
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_protocol_registration_returns_registration_error() {
+        let mut protocol_registry_service = ProtocolRegistryServiceImpl::new(
+            Arc::new(AsyncMutex::new(ProtocolRegistryRepositoryImpl::new())));
+        protocol_registry_service.register_domain_protocol(register_duplicate_exit).await;
+
+        let registration_error_list = protocol_registry_service.report_protocol_registration().await.unwrap_err();
+
+        assert!(matches!(registration_error_list.as_slice(),
+                         [ProtocolRegistrationError::DuplicateProtocolNumber { protocol_number: 4444, .. }]));
+    }
+}
\ No newline at end of file
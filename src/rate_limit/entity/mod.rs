@@ -0,0 +1,5 @@
+pub mod rate_limit_class;
+pub mod token_bucket_limit;
+pub mod token_bucket;
+pub mod rate_limit_verdict;
+pub mod rate_limit_statistics;
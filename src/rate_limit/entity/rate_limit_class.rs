@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+// 프로토콜 번호 대역별로 따로 제한하며, 어디에도 속하지 않는 프로토콜(protocolNumber 가 없는 요청 포함)은 Default 로 제한
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitClass {
+    Auth,
+    Lobby,
+    Shop,
+    InGame,
+    Default,
+}
+
+impl RateLimitClass {
+    pub fn from_protocol_number(protocol_number: i64) -> RateLimitClass {
+        match protocol_number {
+            1..=6 => RateLimitClass::Auth,
+            11 | 13 | 93 | 94 => RateLimitClass::Lobby,
+            71..=92 => RateLimitClass::Shop,
+            1000..=3999 | 4442 | 4443 => RateLimitClass::InGame,
+            _ => RateLimitClass::Default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_protocol_number() {
+        assert_eq!(RateLimitClass::from_protocol_number(2), RateLimitClass::Auth);
+        assert_eq!(RateLimitClass::from_protocol_number(11), RateLimitClass::Lobby);
+        assert_eq!(RateLimitClass::from_protocol_number(13), RateLimitClass::Lobby);
+        assert_eq!(RateLimitClass::from_protocol_number(72), RateLimitClass::Shop);
+        assert_eq!(RateLimitClass::from_protocol_number(92), RateLimitClass::Shop);
+        assert_eq!(RateLimitClass::from_protocol_number(93), RateLimitClass::Lobby);
+        assert_eq!(RateLimitClass::from_protocol_number(94), RateLimitClass::Lobby);
+        assert_eq!(RateLimitClass::from_protocol_number(1000), RateLimitClass::InGame);
+        assert_eq!(RateLimitClass::from_protocol_number(3333), RateLimitClass::InGame);
+        assert_eq!(RateLimitClass::from_protocol_number(4442), RateLimitClass::InGame);
+        assert_eq!(RateLimitClass::from_protocol_number(4443), RateLimitClass::InGame);
+        assert_eq!(RateLimitClass::from_protocol_number(4441), RateLimitClass::Default);
+        assert_eq!(RateLimitClass::from_protocol_number(-1), RateLimitClass::Default);
+    }
+}
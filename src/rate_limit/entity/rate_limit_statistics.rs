@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::rate_limit::entity::rate_limit_class::RateLimitClass;
+
+// 모니터링용 누적 값이며 서버가 재시작되면 초기화됨
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitStatistics {
+    allowed_count_map: HashMap<RateLimitClass, u64>,
+    limited_count_map: HashMap<RateLimitClass, u64>,
+    disconnected_count: u64,
+}
+
+impl RateLimitStatistics {
+    pub fn new() -> Self {
+        RateLimitStatistics::default()
+    }
+
+    pub fn record_allowed(&mut self, rate_limit_class: RateLimitClass) {
+        *self.allowed_count_map.entry(rate_limit_class).or_insert(0) += 1;
+    }
+
+    pub fn record_limited(&mut self, rate_limit_class: RateLimitClass) {
+        *self.limited_count_map.entry(rate_limit_class).or_insert(0) += 1;
+    }
+
+    pub fn record_disconnected(&mut self) {
+        self.disconnected_count += 1;
+    }
+
+    pub fn get_allowed_count(&self, rate_limit_class: RateLimitClass) -> u64 {
+        self.allowed_count_map.get(&rate_limit_class).copied().unwrap_or(0)
+    }
+
+    pub fn get_limited_count(&self, rate_limit_class: RateLimitClass) -> u64 {
+        self.limited_count_map.get(&rate_limit_class).copied().unwrap_or(0)
+    }
+
+    pub fn get_disconnected_count(&self) -> u64 {
+        self.disconnected_count
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitVerdict {
+    Allowed,
+    Limited,
+    // 제한 구간 안에서 위반이 반복되어 연결을 끊어야 함
+    Disconnect,
+}
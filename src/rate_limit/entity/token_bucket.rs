@@ -0,0 +1,62 @@
+use tokio::time::Instant;
+use crate::rate_limit::entity::token_bucket_limit::TokenBucketLimit;
+
+#[derive(Debug, Clone, Copy)]
+pub struct TokenBucket {
+    limit: TokenBucketLimit,
+    tokens: f64,
+    last_refilled_at: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(limit: TokenBucketLimit, now: Instant) -> Self {
+        TokenBucket { limit, tokens: limit.get_burst() as f64, last_refilled_at: now }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed_seconds = now.saturating_duration_since(self.last_refilled_at).as_secs_f64();
+        let refilled_tokens = elapsed_seconds * self.limit.get_refill_per_minute() as f64 / 60.0;
+
+        self.tokens = (self.tokens + refilled_tokens).min(self.limit.get_burst() as f64);
+        self.last_refilled_at = now;
+    }
+
+    pub fn try_acquire_at(&mut self, now: Instant) -> bool {
+        self.refill(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return true
+        }
+
+        false
+    }
+
+    // 한 번도 쓰이지 않은 것과 같은 상태라면 정리해도 됨
+    pub fn is_full_at(&self, now: Instant) -> bool {
+        let mut refilled = *self;
+        refilled.refill(now);
+        refilled.tokens >= self.limit.get_burst() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_try_acquire_until_burst_then_refill() {
+        let now = Instant::now();
+        let mut token_bucket = TokenBucket::new(TokenBucketLimit::new(2, 60), now);
+
+        assert!(token_bucket.try_acquire_at(now));
+        assert!(token_bucket.try_acquire_at(now));
+        assert!(!token_bucket.try_acquire_at(now));
+
+        assert!(!token_bucket.try_acquire_at(now + Duration::from_millis(500)));
+        assert!(token_bucket.try_acquire_at(now + Duration::from_secs(1)));
+        assert!(!token_bucket.is_full_at(now + Duration::from_secs(1)));
+        assert!(token_bucket.is_full_at(now + Duration::from_secs(3)));
+    }
+}
@@ -0,0 +1,20 @@
+// burst 만큼 연속 요청을 허용하고, 이후에는 분당 refill_per_minute 만큼 다시 채워짐
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBucketLimit {
+    burst: u32,
+    refill_per_minute: u32,
+}
+
+impl TokenBucketLimit {
+    pub fn new(burst: u32, refill_per_minute: u32) -> Self {
+        TokenBucketLimit { burst, refill_per_minute }
+    }
+
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+
+    pub fn get_refill_per_minute(&self) -> u32 {
+        self.refill_per_minute
+    }
+}
@@ -0,0 +1,16 @@
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::rate_limit::service::rate_limit_service::RateLimitService;
+use crate::rate_limit::service::rate_limit_service_impl::RateLimitServiceImpl;
+use crate::request_generator::rate_limit_statistics_request_generator::create_rate_limit_statistics_request;
+use crate::response_generator::response_type::ResponseType;
+
+pub fn register_rate_limit_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Rate Limit Statistics (관리자 전용)
+    protocol_registry.register(9001, "RATE_LIMIT_STATISTICS", create_rate_limit_statistics_request, |request| async move {
+        let rate_limit_service_mutex = RateLimitServiceImpl::get_instance();
+        let rate_limit_service_guard = rate_limit_service_mutex.lock().await;
+
+        let response = rate_limit_service_guard.get_rate_limit_statistics_for_admin(request).await;
+        ResponseType::RATE_LIMIT_STATISTICS(response)
+    });
+}
@@ -0,0 +1,2 @@
+pub mod rate_limit_repository;
+pub mod rate_limit_repository_impl;
@@ -0,0 +1,18 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use tokio::time::Instant;
+use crate::rate_limit::entity::rate_limit_class::RateLimitClass;
+use crate::rate_limit::entity::rate_limit_statistics::RateLimitStatistics;
+use crate::rate_limit::entity::rate_limit_verdict::RateLimitVerdict;
+use crate::rate_limit::entity::token_bucket_limit::TokenBucketLimit;
+
+#[async_trait]
+pub trait RateLimitRepository {
+    async fn acquire_connection_token(&mut self, client_address: &str, rate_limit_class: RateLimitClass, token_bucket_limit: TokenBucketLimit, now: Instant) -> bool;
+    async fn acquire_account_token(&mut self, account_unique_id: i32, rate_limit_class: RateLimitClass, token_bucket_limit: TokenBucketLimit, now: Instant) -> bool;
+    async fn record_violation(&mut self, client_address: &str, violation_window: Duration, now: Instant) -> usize;
+    async fn record_rate_limit_verdict(&mut self, rate_limit_class: RateLimitClass, rate_limit_verdict: RateLimitVerdict);
+    async fn remove_connection_rate_limit(&mut self, client_address: &str) -> bool;
+    async fn remove_idle_account_token_bucket(&mut self, now: Instant) -> usize;
+    async fn get_rate_limit_statistics(&self) -> RateLimitStatistics;
+}
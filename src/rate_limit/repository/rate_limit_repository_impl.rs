@@ -0,0 +1,144 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::Instant;
+use crate::rate_limit::entity::rate_limit_class::RateLimitClass;
+use crate::rate_limit::entity::rate_limit_statistics::RateLimitStatistics;
+use crate::rate_limit::entity::rate_limit_verdict::RateLimitVerdict;
+use crate::rate_limit::entity::token_bucket::TokenBucket;
+use crate::rate_limit::entity::token_bucket_limit::TokenBucketLimit;
+use crate::rate_limit::repository::rate_limit_repository::RateLimitRepository;
+
+// 연결(client address) 과 계정에 각각 token bucket 을 두어, 여러 연결로 나눠 보내는 요청도 계정 단위로 제한함
+pub struct RateLimitRepositoryImpl {
+    connection_token_bucket_map: HashMap<(String, RateLimitClass), TokenBucket>,
+    account_token_bucket_map: HashMap<(i32, RateLimitClass), TokenBucket>,
+    connection_violation_map: HashMap<String, VecDeque<Instant>>,
+    rate_limit_statistics: RateLimitStatistics,
+}
+
+impl RateLimitRepositoryImpl {
+    pub fn new() -> Self {
+        RateLimitRepositoryImpl {
+            connection_token_bucket_map: HashMap::new(),
+            account_token_bucket_map: HashMap::new(),
+            connection_violation_map: HashMap::new(),
+            rate_limit_statistics: RateLimitStatistics::new(),
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<RateLimitRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<RateLimitRepositoryImpl>> =
+                Arc::new(AsyncMutex::new(RateLimitRepositoryImpl::new()));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RateLimitRepositoryImpl {
+    async fn acquire_connection_token(&mut self, client_address: &str, rate_limit_class: RateLimitClass, token_bucket_limit: TokenBucketLimit, now: Instant) -> bool {
+        self.connection_token_bucket_map
+            .entry((client_address.to_string(), rate_limit_class))
+            .or_insert_with(|| TokenBucket::new(token_bucket_limit, now))
+            .try_acquire_at(now)
+    }
+
+    async fn acquire_account_token(&mut self, account_unique_id: i32, rate_limit_class: RateLimitClass, token_bucket_limit: TokenBucketLimit, now: Instant) -> bool {
+        self.account_token_bucket_map
+            .entry((account_unique_id, rate_limit_class))
+            .or_insert_with(|| TokenBucket::new(token_bucket_limit, now))
+            .try_acquire_at(now)
+    }
+
+    // violation_window 안에 남아 있는 위반 횟수를 반환
+    async fn record_violation(&mut self, client_address: &str, violation_window: Duration, now: Instant) -> usize {
+        println!("RateLimitRepositoryImpl: record_violation() -> {}", client_address);
+
+        let violation_list = self.connection_violation_map
+            .entry(client_address.to_string())
+            .or_insert_with(VecDeque::new);
+
+        violation_list.push_back(now);
+        while let Some(oldest_violation) = violation_list.front() {
+            if now.saturating_duration_since(*oldest_violation) <= violation_window {
+                break
+            }
+            violation_list.pop_front();
+        }
+
+        violation_list.len()
+    }
+
+    async fn record_rate_limit_verdict(&mut self, rate_limit_class: RateLimitClass, rate_limit_verdict: RateLimitVerdict) {
+        match rate_limit_verdict {
+            RateLimitVerdict::Allowed => self.rate_limit_statistics.record_allowed(rate_limit_class),
+            RateLimitVerdict::Limited => self.rate_limit_statistics.record_limited(rate_limit_class),
+            RateLimitVerdict::Disconnect => {
+                self.rate_limit_statistics.record_limited(rate_limit_class);
+                self.rate_limit_statistics.record_disconnected();
+            }
+        }
+    }
+
+    async fn remove_connection_rate_limit(&mut self, client_address: &str) -> bool {
+        println!("RateLimitRepositoryImpl: remove_connection_rate_limit() -> {}", client_address);
+
+        let bucket_count_before = self.connection_token_bucket_map.len();
+        self.connection_token_bucket_map.retain(|(bucket_client_address, _), _| bucket_client_address != client_address);
+        let violation_removed = self.connection_violation_map.remove(client_address).is_some();
+
+        bucket_count_before != self.connection_token_bucket_map.len() || violation_removed
+    }
+
+    // 가득 찬 bucket 은 새로 만든 것과 같으므로 지워도 제한이 느슨해지지 않음
+    async fn remove_idle_account_token_bucket(&mut self, now: Instant) -> usize {
+        let bucket_count_before = self.account_token_bucket_map.len();
+        self.account_token_bucket_map.retain(|_, token_bucket| !token_bucket.is_full_at(now));
+
+        bucket_count_before - self.account_token_bucket_map.len()
+    }
+
+    async fn get_rate_limit_statistics(&self) -> RateLimitStatistics {
+        self.rate_limit_statistics.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_connection_and_account_token_bucket_are_separate() {
+        let mut rate_limit_repository = RateLimitRepositoryImpl::new();
+        let token_bucket_limit = TokenBucketLimit::new(1, 1);
+        let now = Instant::now();
+
+        assert!(rate_limit_repository.acquire_connection_token("127.0.0.1:1", RateLimitClass::Shop, token_bucket_limit, now).await);
+        assert!(!rate_limit_repository.acquire_connection_token("127.0.0.1:1", RateLimitClass::Shop, token_bucket_limit, now).await);
+        assert!(rate_limit_repository.acquire_connection_token("127.0.0.1:1", RateLimitClass::InGame, token_bucket_limit, now).await);
+        assert!(rate_limit_repository.acquire_connection_token("127.0.0.1:2", RateLimitClass::Shop, token_bucket_limit, now).await);
+
+        assert!(rate_limit_repository.acquire_account_token(7, RateLimitClass::Shop, token_bucket_limit, now).await);
+        assert!(!rate_limit_repository.acquire_account_token(7, RateLimitClass::Shop, token_bucket_limit, now).await);
+
+        assert!(rate_limit_repository.remove_connection_rate_limit("127.0.0.1:1").await);
+        assert!(rate_limit_repository.acquire_connection_token("127.0.0.1:1", RateLimitClass::Shop, token_bucket_limit, now).await);
+    }
+
+    #[tokio::test]
+    async fn test_record_violation_forgets_old_violation() {
+        let mut rate_limit_repository = RateLimitRepositoryImpl::new();
+        let violation_window = Duration::from_secs(10);
+        let now = Instant::now();
+
+        assert_eq!(rate_limit_repository.record_violation("127.0.0.1:1", violation_window, now).await, 1);
+        assert_eq!(rate_limit_repository.record_violation("127.0.0.1:1", violation_window, now + Duration::from_secs(5)).await, 2);
+        assert_eq!(rate_limit_repository.record_violation("127.0.0.1:1", violation_window, now + Duration::from_secs(12)).await, 2);
+        assert_eq!(rate_limit_repository.record_violation("127.0.0.1:2", violation_window, now).await, 1);
+    }
+}
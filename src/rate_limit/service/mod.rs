@@ -0,0 +1,4 @@
+pub mod rate_limit_service;
+pub mod rate_limit_service_impl;
+pub mod request;
+pub mod response;
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::rate_limit::service::request::check_rate_limit_request::CheckRateLimitRequest;
+use crate::rate_limit::service::request::get_rate_limit_statistics_for_admin_request::GetRateLimitStatisticsForAdminRequest;
+use crate::rate_limit::service::request::remove_connection_rate_limit_request::RemoveConnectionRateLimitRequest;
+use crate::rate_limit::service::response::check_rate_limit_response::CheckRateLimitResponse;
+use crate::rate_limit::service::response::get_rate_limit_statistics_for_admin_response::GetRateLimitStatisticsForAdminResponse;
+use crate::rate_limit::service::response::get_rate_limit_statistics_response::GetRateLimitStatisticsResponse;
+use crate::rate_limit::service::response::remove_connection_rate_limit_response::RemoveConnectionRateLimitResponse;
+use crate::rate_limit::service::response::remove_idle_rate_limit_response::RemoveIdleRateLimitResponse;
+
+#[async_trait]
+pub trait RateLimitService {
+    async fn check_rate_limit(&self, check_rate_limit_request: CheckRateLimitRequest) -> CheckRateLimitResponse;
+    async fn remove_connection_rate_limit(&self, remove_connection_rate_limit_request: RemoveConnectionRateLimitRequest) -> RemoveConnectionRateLimitResponse;
+    async fn remove_idle_rate_limit(&self) -> RemoveIdleRateLimitResponse;
+    async fn get_rate_limit_statistics(&self) -> GetRateLimitStatisticsResponse;
+    async fn get_rate_limit_statistics_for_admin(&self, get_rate_limit_statistics_for_admin_request: GetRateLimitStatisticsForAdminRequest) -> GetRateLimitStatisticsForAdminResponse;
+}
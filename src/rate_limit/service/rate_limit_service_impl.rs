@@ -0,0 +1,229 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::Instant;
+use crate::rate_limit::entity::rate_limit_class::RateLimitClass;
+use crate::rate_limit::entity::rate_limit_verdict::RateLimitVerdict;
+use crate::rate_limit::repository::rate_limit_repository::RateLimitRepository;
+use crate::rate_limit::repository::rate_limit_repository_impl::RateLimitRepositoryImpl;
+use crate::rate_limit::service::rate_limit_service::RateLimitService;
+use crate::rate_limit::service::request::check_rate_limit_request::CheckRateLimitRequest;
+use crate::rate_limit::service::request::get_rate_limit_statistics_for_admin_request::GetRateLimitStatisticsForAdminRequest;
+use crate::rate_limit::service::request::remove_connection_rate_limit_request::RemoveConnectionRateLimitRequest;
+use crate::rate_limit::service::response::check_rate_limit_response::CheckRateLimitResponse;
+use crate::rate_limit::service::response::get_rate_limit_statistics_for_admin_response::GetRateLimitStatisticsForAdminResponse;
+use crate::rate_limit::service::response::get_rate_limit_statistics_response::GetRateLimitStatisticsResponse;
+use crate::rate_limit::service::response::remove_connection_rate_limit_response::RemoveConnectionRateLimitResponse;
+use crate::rate_limit::service::response::remove_idle_rate_limit_response::RemoveIdleRateLimitResponse;
+use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
+use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
+use crate::server_config::server_config::ServerConfig;
+
+pub struct RateLimitServiceImpl {
+    redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
+    rate_limit_repository: Arc<AsyncMutex<RateLimitRepositoryImpl>>,
+}
+
+impl RateLimitServiceImpl {
+    pub fn new(redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
+               rate_limit_repository: Arc<AsyncMutex<RateLimitRepositoryImpl>>) -> Self {
+
+        RateLimitServiceImpl {
+            redis_in_memory_repository,
+            rate_limit_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<RateLimitServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<RateLimitServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        RateLimitServiceImpl::new(
+                            RedisInMemoryRepositoryImpl::get_instance(),
+                            RateLimitRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn find_account_unique_id(&self, session_id: &str) -> Option<i32> {
+        let mut redis_in_memory_repository_guard = self.redis_in_memory_repository.lock().await;
+        let account_unique_id_string = redis_in_memory_repository_guard.get(session_id).await?;
+        drop(redis_in_memory_repository_guard);
+
+        account_unique_id_string.parse().ok()
+    }
+}
+
+#[async_trait]
+impl RateLimitService for RateLimitServiceImpl {
+    async fn check_rate_limit(&self, check_rate_limit_request: CheckRateLimitRequest) -> CheckRateLimitResponse {
+        let rate_limit_class = RateLimitClass::from_protocol_number(check_rate_limit_request.get_protocol_number());
+
+        // 세션 조회는 rate limit 잠금 밖에서 먼저 끝내 둔다
+        let account_unique_id_option = match check_rate_limit_request.get_session_id() {
+            Some(session_id) => self.find_account_unique_id(session_id).await,
+            None => None,
+        };
+
+        let server_config = ServerConfig::get_instance();
+        let token_bucket_limit = server_config.get_rate_limit(rate_limit_class);
+        let client_address = check_rate_limit_request.get_client_address();
+        let now = Instant::now();
+
+        let mut rate_limit_repository_guard = self.rate_limit_repository.lock().await;
+
+        let mut is_allowed =
+            rate_limit_repository_guard.acquire_connection_token(
+                client_address, rate_limit_class, token_bucket_limit, now).await;
+
+        if let (true, Some(account_unique_id)) = (is_allowed, account_unique_id_option) {
+            is_allowed = rate_limit_repository_guard.acquire_account_token(
+                account_unique_id, rate_limit_class, token_bucket_limit, now).await;
+        }
+
+        let rate_limit_verdict = if is_allowed {
+            RateLimitVerdict::Allowed
+        } else {
+            let violation_count =
+                rate_limit_repository_guard.record_violation(
+                    client_address, server_config.get_rate_limit_violation_window(), now).await;
+
+            let disconnect_violation_count = server_config.get_rate_limit_disconnect_violation_count() as usize;
+            // 요청이 몰려도 로그가 같이 폭주하지 않도록 연결을 끊기로 결정한 순간에만 남김
+            if violation_count == disconnect_violation_count {
+                println!("RateLimitServiceImpl: {} is disconnected on {:?}", client_address, rate_limit_class);
+            }
+
+            if violation_count >= disconnect_violation_count {
+                RateLimitVerdict::Disconnect
+            } else {
+                RateLimitVerdict::Limited
+            }
+        };
+
+        rate_limit_repository_guard.record_rate_limit_verdict(rate_limit_class, rate_limit_verdict).await;
+        drop(rate_limit_repository_guard);
+
+        CheckRateLimitResponse::new(rate_limit_verdict)
+    }
+
+    async fn remove_connection_rate_limit(&self, remove_connection_rate_limit_request: RemoveConnectionRateLimitRequest) -> RemoveConnectionRateLimitResponse {
+        println!("RateLimitServiceImpl: remove_connection_rate_limit()");
+
+        let mut rate_limit_repository_guard = self.rate_limit_repository.lock().await;
+        let is_success =
+            rate_limit_repository_guard.remove_connection_rate_limit(
+                remove_connection_rate_limit_request.get_client_address()).await;
+
+        RemoveConnectionRateLimitResponse::new(is_success)
+    }
+
+    async fn remove_idle_rate_limit(&self) -> RemoveIdleRateLimitResponse {
+        println!("RateLimitServiceImpl: remove_idle_rate_limit()");
+
+        let mut rate_limit_repository_guard = self.rate_limit_repository.lock().await;
+        let removed_count = rate_limit_repository_guard.remove_idle_account_token_bucket(Instant::now()).await;
+
+        RemoveIdleRateLimitResponse::new(removed_count)
+    }
+
+    async fn get_rate_limit_statistics(&self) -> GetRateLimitStatisticsResponse {
+        let rate_limit_repository_guard = self.rate_limit_repository.lock().await;
+        let rate_limit_statistics = rate_limit_repository_guard.get_rate_limit_statistics().await;
+
+        GetRateLimitStatisticsResponse::new(rate_limit_statistics)
+    }
+
+    async fn get_rate_limit_statistics_for_admin(&self, get_rate_limit_statistics_for_admin_request: GetRateLimitStatisticsForAdminRequest) -> GetRateLimitStatisticsForAdminResponse {
+        println!("RateLimitServiceImpl: get_rate_limit_statistics_for_admin()");
+
+        let server_config = ServerConfig::get_instance();
+        let is_admin = server_config.get_admin_token()
+            .map_or(false, |admin_token| admin_token == get_rate_limit_statistics_for_admin_request.get_admin_token());
+
+        if !is_admin {
+            return GetRateLimitStatisticsForAdminResponse::unauthorized()
+        }
+
+        let rate_limit_repository_guard = self.rate_limit_repository.lock().await;
+        let rate_limit_statistics = rate_limit_repository_guard.get_rate_limit_statistics().await;
+
+        GetRateLimitStatisticsForAdminResponse::new(rate_limit_statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request_generator::request_generator::UNKNOWN_PROTOCOL_NUMBER;
+
+    #[tokio::test]
+    async fn test_repeat_offender_is_disconnected() {
+        let rate_limit_service = RateLimitServiceImpl::new(
+            Arc::new(AsyncMutex::new(RedisInMemoryRepositoryImpl::new_in_memory())),
+            Arc::new(AsyncMutex::new(RateLimitRepositoryImpl::new())));
+
+        let server_config = ServerConfig::get_instance();
+        let burst = server_config.get_rate_limit(RateLimitClass::Auth).get_burst();
+        let disconnect_violation_count = server_config.get_rate_limit_disconnect_violation_count();
+
+        let make_request = || CheckRateLimitRequest::new("10.0.0.1:5000".to_string(), 2, None);
+
+        for _ in 0..burst {
+            let check_rate_limit_response = rate_limit_service.check_rate_limit(make_request()).await;
+            assert_eq!(check_rate_limit_response.get_rate_limit_verdict(), RateLimitVerdict::Allowed);
+        }
+
+        for _ in 1..disconnect_violation_count {
+            let check_rate_limit_response = rate_limit_service.check_rate_limit(make_request()).await;
+            assert_eq!(check_rate_limit_response.get_rate_limit_verdict(), RateLimitVerdict::Limited);
+        }
+
+        let check_rate_limit_response = rate_limit_service.check_rate_limit(make_request()).await;
+        assert_eq!(check_rate_limit_response.get_rate_limit_verdict(), RateLimitVerdict::Disconnect);
+
+        let heartbeat_response =
+            rate_limit_service.check_rate_limit(
+                CheckRateLimitRequest::new("10.0.0.1:5000".to_string(), 4441, None)).await;
+        assert_eq!(heartbeat_response.get_rate_limit_verdict(), RateLimitVerdict::Allowed);
+
+        let rate_limit_statistics = rate_limit_service.get_rate_limit_statistics().await;
+        assert_eq!(rate_limit_statistics.get_rate_limit_statistics().get_allowed_count(RateLimitClass::Auth), burst as u64);
+        assert_eq!(rate_limit_statistics.get_rate_limit_statistics().get_limited_count(RateLimitClass::Auth), disconnect_violation_count as u64);
+        assert_eq!(rate_limit_statistics.get_rate_limit_statistics().get_disconnected_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_without_protocol_number_uses_default_bucket() {
+        let rate_limit_service = RateLimitServiceImpl::new(
+            Arc::new(AsyncMutex::new(RedisInMemoryRepositoryImpl::new_in_memory())),
+            Arc::new(AsyncMutex::new(RateLimitRepositoryImpl::new())));
+
+        let burst = ServerConfig::get_instance().get_rate_limit(RateLimitClass::Default).get_burst();
+        let make_request = || CheckRateLimitRequest::new("10.0.0.2:5000".to_string(), UNKNOWN_PROTOCOL_NUMBER, None);
+
+        for _ in 0..burst {
+            let check_rate_limit_response = rate_limit_service.check_rate_limit(make_request()).await;
+            assert_eq!(check_rate_limit_response.get_rate_limit_verdict(), RateLimitVerdict::Allowed);
+        }
+
+        let check_rate_limit_response = rate_limit_service.check_rate_limit(make_request()).await;
+        assert_eq!(check_rate_limit_response.get_rate_limit_verdict(), RateLimitVerdict::Limited);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_statistics_for_admin_rejects_wrong_token() {
+        let rate_limit_service = RateLimitServiceImpl::new(
+            Arc::new(AsyncMutex::new(RedisInMemoryRepositoryImpl::new_in_memory())),
+            Arc::new(AsyncMutex::new(RateLimitRepositoryImpl::new())));
+
+        let get_rate_limit_statistics_for_admin_response =
+            rate_limit_service.get_rate_limit_statistics_for_admin(
+                GetRateLimitStatisticsForAdminRequest::new("wrong token".to_string())).await;
+
+        assert!(!get_rate_limit_statistics_for_admin_response.is_success());
+        assert!(get_rate_limit_statistics_for_admin_response.get_rate_limit_statistics().is_none());
+    }
+}
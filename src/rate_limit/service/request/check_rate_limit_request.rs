@@ -0,0 +1,28 @@
+#[derive(Debug)]
+pub struct CheckRateLimitRequest {
+    client_address: String,
+    protocol_number: i64,
+    session_id: Option<String>,
+}
+
+impl CheckRateLimitRequest {
+    pub fn new(client_address: String, protocol_number: i64, session_id: Option<String>) -> Self {
+        CheckRateLimitRequest {
+            client_address,
+            protocol_number,
+            session_id,
+        }
+    }
+
+    pub fn get_client_address(&self) -> &str {
+        &self.client_address
+    }
+
+    pub fn get_protocol_number(&self) -> i64 {
+        self.protocol_number
+    }
+
+    pub fn get_session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct GetRateLimitStatisticsForAdminRequest {
+    admin_token: String,
+}
+
+impl GetRateLimitStatisticsForAdminRequest {
+    pub fn new(admin_token: String) -> Self {
+        GetRateLimitStatisticsForAdminRequest {
+            admin_token,
+        }
+    }
+
+    pub fn get_admin_token(&self) -> &str {
+        &self.admin_token
+    }
+}
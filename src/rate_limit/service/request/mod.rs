@@ -0,0 +1,3 @@
+pub mod check_rate_limit_request;
+pub mod remove_connection_rate_limit_request;
+pub mod get_rate_limit_statistics_for_admin_request;
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveConnectionRateLimitRequest {
+    client_address: String,
+}
+
+impl RemoveConnectionRateLimitRequest {
+    pub fn new(client_address: String) -> Self {
+        RemoveConnectionRateLimitRequest {
+            client_address,
+        }
+    }
+
+    pub fn get_client_address(&self) -> &str {
+        &self.client_address
+    }
+}
@@ -0,0 +1,18 @@
+use crate::rate_limit::entity::rate_limit_verdict::RateLimitVerdict;
+
+#[derive(Debug)]
+pub struct CheckRateLimitResponse {
+    rate_limit_verdict: RateLimitVerdict,
+}
+
+impl CheckRateLimitResponse {
+    pub fn new(rate_limit_verdict: RateLimitVerdict) -> Self {
+        CheckRateLimitResponse {
+            rate_limit_verdict,
+        }
+    }
+
+    pub fn get_rate_limit_verdict(&self) -> RateLimitVerdict {
+        self.rate_limit_verdict
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use crate::rate_limit::entity::rate_limit_statistics::RateLimitStatistics;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetRateLimitStatisticsForAdminResponse {
+    is_success: bool,
+    rate_limit_statistics: Option<RateLimitStatistics>,
+}
+
+impl GetRateLimitStatisticsForAdminResponse {
+    pub fn new(rate_limit_statistics: RateLimitStatistics) -> Self {
+        GetRateLimitStatisticsForAdminResponse {
+            is_success: true,
+            rate_limit_statistics: Some(rate_limit_statistics),
+        }
+    }
+
+    pub fn unauthorized() -> Self {
+        GetRateLimitStatisticsForAdminResponse {
+            is_success: false,
+            rate_limit_statistics: None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+
+    pub fn get_rate_limit_statistics(&self) -> Option<&RateLimitStatistics> {
+        self.rate_limit_statistics.as_ref()
+    }
+}
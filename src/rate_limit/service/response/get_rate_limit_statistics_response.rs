@@ -0,0 +1,18 @@
+use crate::rate_limit::entity::rate_limit_statistics::RateLimitStatistics;
+
+#[derive(Debug)]
+pub struct GetRateLimitStatisticsResponse {
+    rate_limit_statistics: RateLimitStatistics,
+}
+
+impl GetRateLimitStatisticsResponse {
+    pub fn new(rate_limit_statistics: RateLimitStatistics) -> Self {
+        GetRateLimitStatisticsResponse {
+            rate_limit_statistics,
+        }
+    }
+
+    pub fn get_rate_limit_statistics(&self) -> &RateLimitStatistics {
+        &self.rate_limit_statistics
+    }
+}
@@ -0,0 +1,5 @@
+pub mod check_rate_limit_response;
+pub mod remove_connection_rate_limit_response;
+pub mod remove_idle_rate_limit_response;
+pub mod get_rate_limit_statistics_response;
+pub mod get_rate_limit_statistics_for_admin_response;
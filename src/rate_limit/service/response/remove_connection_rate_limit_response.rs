@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveConnectionRateLimitResponse {
+    is_success: bool,
+}
+
+impl RemoveConnectionRateLimitResponse {
+    pub fn new(is_success: bool) -> Self {
+        RemoveConnectionRateLimitResponse {
+            is_success,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.is_success
+    }
+}
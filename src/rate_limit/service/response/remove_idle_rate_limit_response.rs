@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct RemoveIdleRateLimitResponse {
+    removed_count: usize,
+}
+
+impl RemoveIdleRateLimitResponse {
+    pub fn new(removed_count: usize) -> Self {
+        RemoveIdleRateLimitResponse {
+            removed_count,
+        }
+    }
+
+    pub fn get_removed_count(&self) -> usize {
+        self.removed_count
+    }
+}
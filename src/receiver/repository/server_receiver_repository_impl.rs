@@ -5,8 +5,6 @@ use chrono::{Timelike, Utc};
 use futures::SinkExt;
 use ipc_channel::ipc::IpcSender;
 use lazy_static::lazy_static;
-use tokio::io::AsyncReadExt;
-use tokio::net::TcpStream;
 use tokio::sync::{Mutex as AsyncMutex, Mutex};
 use crate::receiver::entity::receive_data::ReceiveData;
 use crate::receiver::repository::server_receiver_repository::ServerReceiverRepository;
@@ -16,9 +14,27 @@ use serde_json::Value as JsonValue;
 use tokio::time::error::Elapsed;
 use tokio::time::timeout;
 use crate::client_program::service::response::client_program_exit_response::ClientProgramExitResponse;
+use crate::client_socket_accept::entity::client_stream::ClientStream;
 use crate::client_socket_accept::repository::client_socket_accept_repository_impl::ReceiverTransmitterChannel;
-use crate::request_generator::request_generator::create_request_and_call_service;
+use crate::common::frame::length_prefixed_frame_codec::LengthPrefixedFrameCodec;
+use crate::common::message::protocol_error_code_enum::ProtocolErrorCode;
+use crate::connection_liveness::repository::connection_liveness_repository::ConnectionLivenessRepository;
+use crate::connection_liveness::repository::connection_liveness_repository_impl::ConnectionLivenessRepositoryImpl;
+use crate::protocol_handshake::entity::protocol_version::HELLO_PROTOCOL_NUMBER;
+use crate::protocol_handshake::service::protocol_handshake_service::ProtocolHandshakeService;
+use crate::protocol_handshake::service::protocol_handshake_service_impl::ProtocolHandshakeServiceImpl;
+use crate::protocol_handshake::service::request::remove_negotiated_protocol_request::RemoveNegotiatedProtocolRequest;
+use crate::protocol_registry::entity::protocol_error_response_form::ProtocolErrorResponseForm;
+use crate::rate_limit::entity::rate_limit_verdict::RateLimitVerdict;
+use crate::rate_limit::service::rate_limit_service::RateLimitService;
+use crate::rate_limit::service::rate_limit_service_impl::RateLimitServiceImpl;
+use crate::rate_limit::service::request::check_rate_limit_request::CheckRateLimitRequest;
+use crate::rate_limit::service::request::remove_connection_rate_limit_request::RemoveConnectionRateLimitRequest;
+use crate::request_generator::hello_request_generator::create_hello_request;
+use crate::request_generator::request_generator::{create_request_and_call_service, UNKNOWN_PROTOCOL_NUMBER};
+use crate::response_generator::response_envelope::ResponseEnvelope;
 use crate::response_generator::response_type::ResponseType;
+use crate::server_config::server_config::ServerConfig;
 
 pub struct ServerReceiverRepositoryImpl {
     receive_data: ReceiveData,
@@ -134,9 +150,10 @@ impl ServerReceiverRepository for ServerReceiverRepositoryImpl {
                     println!("Connected client address: {}", peer_addr);
                     let stream = stream_arc.clone();
                     let receiver_transmitter_tx_inner = receiver_transmitter_channel_arc.clone();
+                    let client_address = client_socket.address().to_string();
 
                     tokio::spawn(async move {
-                        handle_client(stream, receiver_transmitter_tx_inner).await;
+                        handle_client(client_address, stream, receiver_transmitter_tx_inner).await;
                     });
                 } else {
                     eprintln!("Failed to get peer address");
@@ -162,8 +179,12 @@ impl ServerReceiverRepository for ServerReceiverRepositoryImpl {
     }
 }
 
-async fn read_from_stream(stream: Arc<Mutex<TcpStream>>, buffer: &mut Vec<u8>) -> Result<usize, Elapsed> {
-    match timeout(Duration::from_secs(1), stream.lock().await.read(buffer)).await {
+async fn read_from_stream(stream: Arc<Mutex<ClientStream>>,
+                          buffer: &mut Vec<u8>,
+                          frame_codec: &mut LengthPrefixedFrameCodec) -> Result<usize, Elapsed> {
+    let mut client_stream = stream.lock().await;
+
+    match timeout(Duration::from_secs(1), client_stream.read_into_frame_codec(buffer, frame_codec)).await {
         Ok(result) => Ok(result.unwrap_or_else(|_| 0)),
         Err(e) => {
             // TODO: 당장 여기서 처리 할 일이 아직은 없음
@@ -173,51 +194,201 @@ async fn read_from_stream(stream: Arc<Mutex<TcpStream>>, buffer: &mut Vec<u8>) -
     }
 }
 
-async fn handle_client(stream: Arc<Mutex<TcpStream>>, receiver_transmitter_tx: Arc<ReceiverTransmitterChannel>) {
+async fn send_protocol_error(receiver_transmitter_tx: &Arc<ReceiverTransmitterChannel>, message: String) {
+    let protocol_error_response_form =
+        ProtocolErrorResponseForm::new(UNKNOWN_PROTOCOL_NUMBER, ProtocolErrorCode::MalformedMessage, message);
+
+    receiver_transmitter_tx.send(
+        Arc::new(
+            AsyncMutex::new(
+                ResponseEnvelope::response(
+                    None,
+                    ResponseType::PROTOCOL_ERROR(protocol_error_response_form))))).await;
+}
+
+// protocolNumber 가 없는 요청도 Default 한도로 제한하며, 허용되면 그대로 넘겨 PROTOCOL_ERROR 로 응답되도록 함
+async fn check_rate_limit(client_address: &str, decoded_object: &JsonValue) -> (i64, RateLimitVerdict) {
+    let protocol_number = decoded_object.get("protocolNumber").and_then(JsonValue::as_i64)
+        .unwrap_or(UNKNOWN_PROTOCOL_NUMBER);
+    let session_id = decoded_object.get("sessionInfo").and_then(JsonValue::as_str).map(str::to_string);
+
+    let rate_limit_service_mutex = RateLimitServiceImpl::get_instance();
+    let rate_limit_service_guard = rate_limit_service_mutex.lock().await;
+    let check_rate_limit_response =
+        rate_limit_service_guard.check_rate_limit(
+            CheckRateLimitRequest::new(client_address.to_string(), protocol_number, session_id)).await;
+
+    (protocol_number, check_rate_limit_response.get_rate_limit_verdict())
+}
+
+async fn remove_client_rate_limit(client_address: &str) {
+    let rate_limit_service_mutex = RateLimitServiceImpl::get_instance();
+    let rate_limit_service_guard = rate_limit_service_mutex.lock().await;
+    rate_limit_service_guard.remove_connection_rate_limit(
+        RemoveConnectionRateLimitRequest::new(client_address.to_string())).await;
+}
+
+// 형식이 잘못된 HELLO 는 PROTOCOL_ERROR 로 응답하여 다시 보낼 수 있도록 함
+async fn handle_hello(client_address: &str, decoded_object: &JsonValue) -> ResponseType {
+    let hello_request = match create_hello_request(client_address, decoded_object) {
+        Ok(hello_request) => hello_request,
+        Err(request_parse_error) => {
+            return ResponseType::PROTOCOL_ERROR(
+                ProtocolErrorResponseForm::from_request_parse_error(HELLO_PROTOCOL_NUMBER, &request_parse_error))
+        }
+    };
+
+    let protocol_handshake_service_mutex = ProtocolHandshakeServiceImpl::get_instance();
+    let protocol_handshake_service_guard = protocol_handshake_service_mutex.lock().await;
+    ResponseType::HELLO(protocol_handshake_service_guard.hello(hello_request).await)
+}
+
+async fn remove_client_negotiated_protocol(client_address: &str) {
+    let protocol_handshake_service_mutex = ProtocolHandshakeServiceImpl::get_instance();
+    let protocol_handshake_service_guard = protocol_handshake_service_mutex.lock().await;
+    protocol_handshake_service_guard.remove_negotiated_protocol(
+        RemoveNegotiatedProtocolRequest::new(client_address.to_string())).await;
+}
+
+async fn record_client_activity(client_address: &str) {
+    let connection_liveness_repository_mutex = ConnectionLivenessRepositoryImpl::get_instance();
+    let mut connection_liveness_repository_guard = connection_liveness_repository_mutex.lock().await;
+    connection_liveness_repository_guard.record_connection_activity(client_address).await;
+}
+
+async fn mark_client_closed(client_address: &str) {
+    let connection_liveness_repository_mutex = ConnectionLivenessRepositoryImpl::get_instance();
+    let mut connection_liveness_repository_guard = connection_liveness_repository_mutex.lock().await;
+    connection_liveness_repository_guard.mark_connection_closed(
+        client_address, ServerConfig::get_instance().get_connection_grace_period()).await;
+}
+
+async fn handle_client(client_address: String, stream: Arc<Mutex<ClientStream>>, receiver_transmitter_tx: Arc<ReceiverTransmitterChannel>) {
+    handle_client_with_frame_codec(client_address, stream, receiver_transmitter_tx, LengthPrefixedFrameCodec::from_env()).await;
+}
+
+async fn handle_client_with_frame_codec(client_address: String,
+                                        stream: Arc<Mutex<ClientStream>>,
+                                        receiver_transmitter_tx: Arc<ReceiverTransmitterChannel>,
+                                        mut frame_codec: LengthPrefixedFrameCodec) {
     let mut buffer = vec![0; 1024]; // Adjust the buffer size as needed
+    let mut should_disconnect_client = false;
+    let mut is_handshake_done = false;
 
-    loop {
-        match read_from_stream(Arc::clone(&stream), &mut buffer).await {
+    record_client_activity(&client_address).await;
+
+    'receive: loop {
+        match read_from_stream(Arc::clone(&stream), &mut buffer, &mut frame_codec).await {
             Ok(bytes_read) => {
                 if bytes_read == 0 {
                     break;
                 }
 
-                let stored_data = &buffer[..bytes_read];
+                record_client_activity(&client_address).await;
+
+                loop {
+                    let stored_data = match frame_codec.next_frame() {
+                        Ok(Some(frame)) => frame,
+                        Ok(None) => break,
+                        Err(err) => {
+                            println!("Error decoding frame: {:?}", err);
+                            send_protocol_error(&receiver_transmitter_tx, format!("{:?}", err)).await;
+                            break
+                        }
+                    };
 
-                match serde_json::from_slice::<serde_json::Value>(stored_data) {
-                    Ok(decoded_object) => {
-                        println!("Received content: {:?}", decoded_object);
-                        println!("Received time: {}.{} sec", Utc::now().second(), Utc::now().timestamp_subsec_millis());
+                    match serde_json::from_slice::<serde_json::Value>(&stored_data) {
+                        Ok(decoded_object) => {
+                            println!("Received content: {:?}", decoded_object);
+                            println!("Received time: {}.{} sec", Utc::now().second(), Utc::now().timestamp_subsec_millis());
 
-                        // TODO: This part could be cleaner; the loop logic should ideally go to the controller
-                        // Option<ResponseType>
-                        let response_option = create_request_and_call_service(&decoded_object).await;
-                        let response_option_arc = response_option.clone();
+                            // TODO: This part could be cleaner; the loop logic should ideally go to the controller
+                            let request_id = decoded_object.get("requestId").cloned();
 
-                        // &Arc<ReceiverTransmitterChannel>
-                        if let tx = &receiver_transmitter_tx {
-                            tx.send(Arc::new(AsyncMutex::new(response_option.unwrap()))).await;
-                            println!("handle_client: Sent response to Transmitter through channel");
-                        }
+                            let (protocol_number, rate_limit_verdict) = check_rate_limit(&client_address, &decoded_object).await;
+                            if rate_limit_verdict != RateLimitVerdict::Allowed {
+                                let protocol_error_response_form =
+                                    ProtocolErrorResponseForm::new(protocol_number,
+                                                                   ProtocolErrorCode::RateLimited,
+                                                                   "too many requests".to_string());
+
+                                receiver_transmitter_tx.send(
+                                    Arc::new(AsyncMutex::new(ResponseEnvelope::response(
+                                        request_id, ResponseType::PROTOCOL_ERROR(protocol_error_response_form))))).await;
+
+                                if rate_limit_verdict == RateLimitVerdict::Disconnect {
+                                    println!("Disconnect flooding client: {}", client_address);
+                                    should_disconnect_client = true;
+                                    break 'receive
+                                }
+
+                                continue
+                            }
 
-                        if let Some(response) = &response_option_arc {
-                            match response {
-                                ResponseType::PROGRAM_EXIT(client_program_exit_response) => {
-                                    if client_program_exit_response.does_client_exit_success() {
-                                        println!("Program Exit Detected!");
-                                        break
+                            if protocol_number == HELLO_PROTOCOL_NUMBER {
+                                let response = handle_hello(&client_address, &decoded_object).await;
+                                let is_rejected = match &response {
+                                    ResponseType::HELLO(hello_response) => {
+                                        is_handshake_done = hello_response.is_accepted();
+                                        !hello_response.is_accepted()
                                     }
+                                    _ => false,
+                                };
+
+                                receiver_transmitter_tx.send(
+                                    Arc::new(AsyncMutex::new(ResponseEnvelope::response(request_id, response)))).await;
+
+                                if is_rejected {
+                                    println!("Disconnect incompatible client: {}", client_address);
+                                    should_disconnect_client = true;
+                                    break 'receive
                                 }
+
+                                continue
+                            }
+
+                            if !is_handshake_done {
+                                let protocol_error_response_form =
+                                    ProtocolErrorResponseForm::new(protocol_number,
+                                                                   ProtocolErrorCode::HandshakeRequired,
+                                                                   format!("send protocol {} (HELLO) first", HELLO_PROTOCOL_NUMBER));
+
+                                receiver_transmitter_tx.send(
+                                    Arc::new(AsyncMutex::new(ResponseEnvelope::response(
+                                        request_id, ResponseType::PROTOCOL_ERROR(protocol_error_response_form))))).await;
+
+                                continue
+                            }
+
+                            let response = create_request_and_call_service(&decoded_object).await;
+                            let does_client_exit = match &response {
+                                ResponseType::PROGRAM_EXIT(client_program_exit_response) =>
+                                    client_program_exit_response.does_client_exit_success(),
                                 _ => {
                                     println!("Generated Response Type: {:?}", response);
+                                    false
                                 }
+                            };
+
+                            receiver_transmitter_tx.send(
+                                Arc::new(AsyncMutex::new(ResponseEnvelope::response(request_id, response)))).await;
+                            println!("handle_client: Sent response to Transmitter through channel");
+
+                            if does_client_exit {
+                                println!("Program Exit Detected!");
+                                break 'receive
                             }
                         }
+                        Err(err) => {
+                            println!("Error decoding JSON: {:?}", err);
+                            send_protocol_error(&receiver_transmitter_tx, err.to_string()).await;
+                        }
                     }
-                    Err(err) => {
-                        println!("Error decoding JSON: {:?}", err);
-                    }
+                }
+
+                // 조각난 frame 이 남아 있거나 너무 큰 frame 을 건너뛰는 중이라면 대기 없이 바로 나머지를 읽음
+                if frame_codec.buffered_length() > 0 || frame_codec.is_discarding() {
+                    continue;
                 }
             }
             Err(err) => {
@@ -231,10 +402,21 @@ async fn handle_client(stream: Arc<Mutex<TcpStream>>, receiver_transmitter_tx: A
         tokio::time::sleep(Duration::from_millis(500)).await;
     }
 
+    // 게임 중이었다면 유예 시간 동안 재접속을 기다린 뒤 기권 처리됨
+    mark_client_closed(&client_address).await;
+    remove_client_rate_limit(&client_address).await;
+    remove_client_negotiated_protocol(&client_address).await;
+
+    if should_disconnect_client {
+        if let Err(err) = stream.lock().await.shutdown().await {
+            println!("Failed to disconnect client {}: {:?}", client_address, err);
+        }
+    }
+
     println!("Finish to handle client receive");
 }
 
-// async fn handle_client(stream: Arc<Mutex<TcpStream>>, receiver_transmitter_tx: Option<Arc<ReceiverTransmitterChannel>>) {
+// async fn handle_client(stream: Arc<Mutex<ClientStream>>, receiver_transmitter_tx: Option<Arc<ReceiverTransmitterChannel>>) {
 //     let mut buffer = vec![0; 1024]; // Adjust the buffer size as needed
 //
 //     loop {
@@ -295,6 +477,53 @@ mod tests {
     use crate::common::ip_address::local_ip_finder::IPAddress;
     use crate::domain_initializer::initializer::AcceptorReceiverChannel;
 
+    async fn receive_protocol_error(receiver_transmitter_tx: &ReceiverTransmitterChannel) -> ProtocolErrorResponseForm {
+        let response = timeout(Duration::from_secs(5), receiver_transmitter_tx.receive()).await
+            .expect("no response from receiver").unwrap();
+        let response_envelope = response.lock().await;
+
+        match response_envelope.get_response() {
+            ResponseType::PROTOCOL_ERROR(protocol_error_response_form) => protocol_error_response_form.clone(),
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_reading_after_frame_over_max_size() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut client = TcpStream::connect(address).await.unwrap();
+        let (server_stream, peer_address) = listener.accept().await.unwrap();
+
+        let receiver_transmitter_tx = Arc::new(ReceiverTransmitterChannel::new(4));
+        let frame_codec = LengthPrefixedFrameCodec::new(64);
+        let valid_frame = frame_codec.encode(br#"{"protocolNumber":"#).unwrap();
+
+        let receive_task = tokio::spawn(handle_client_with_frame_codec(
+            peer_address.to_string(),
+            Arc::new(Mutex::new(ClientStream::Tcp(server_stream))),
+            receiver_transmitter_tx.clone(),
+            frame_codec));
+
+        // 너무 큰 frame 의 payload 가 두 번에 나뉘어 도착하고 그 뒤에 정상 frame 이 이어짐
+        let mut oversized_frame = (100u32).to_be_bytes().to_vec();
+        oversized_frame.extend(vec![b'{'; 100]);
+        client.write_all(&oversized_frame[..50]).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.write_all(&oversized_frame[50..]).await.unwrap();
+        client.write_all(&valid_frame).await.unwrap();
+
+        let frame_error = receive_protocol_error(&receiver_transmitter_tx).await;
+        assert!(frame_error.get_message().starts_with("FrameTooLarge"));
+
+        // 정상 frame 의 payload 만 정확히 잘라 냈다면 JSON 끝에서 파싱이 멈춤
+        let json_error = receive_protocol_error(&receiver_transmitter_tx).await;
+        assert_eq!(json_error.get_error_code(), ProtocolErrorCode::MalformedMessage as i32);
+        assert_eq!(json_error.get_message(), "EOF while parsing a value at line 1 column 18");
+
+        receive_task.abort();
+    }
+
     // #[tokio::test]
     // async fn test_server_receiver_repository_receive() {
     //     let acceptor_receiver_channel = AcceptorReceiverChannel::new(1);
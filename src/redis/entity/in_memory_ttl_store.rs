@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct InMemoryTtlEntry {
+    value: String,
+    expired_at: Option<Instant>,
+}
+
+impl InMemoryTtlEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        match self.expired_at {
+            Some(expired_at) => expired_at <= now,
+            None => false,
+        }
+    }
+}
+
+// Redis 없이 구동할 때 사용하는 세션 저장소 (만료된 key 는 조회 시점에 제거)
+#[derive(Debug, Default)]
+pub struct InMemoryTtlStore {
+    entry_map: HashMap<String, InMemoryTtlEntry>,
+}
+
+impl InMemoryTtlStore {
+    pub fn new() -> Self {
+        InMemoryTtlStore { entry_map: HashMap::new() }
+    }
+
+    pub fn set_permanent(&mut self, key: &str, value: &str) {
+        self.entry_map.insert(key.to_string(), InMemoryTtlEntry {
+            value: value.to_string(),
+            expired_at: None,
+        });
+    }
+
+    pub fn set_with_expiry(&mut self, key: &str, value: &str, expiry_seconds: u64) {
+        self.set_with_expiry_at(key, value, expiry_seconds, Instant::now());
+    }
+
+    pub fn set_with_expiry_at(&mut self, key: &str, value: &str, expiry_seconds: u64, now: Instant) {
+        self.remove_expired_entries(now);
+        self.entry_map.insert(key.to_string(), InMemoryTtlEntry {
+            value: value.to_string(),
+            expired_at: Some(now + Duration::from_secs(expiry_seconds)),
+        });
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<String> {
+        self.get_at(key, Instant::now())
+    }
+
+    pub fn get_at(&mut self, key: &str, now: Instant) -> Option<String> {
+        let is_expired = match self.entry_map.get(key) {
+            Some(entry) => entry.is_expired(now),
+            None => return None,
+        };
+
+        if is_expired {
+            self.entry_map.remove(key);
+            return None
+        }
+
+        self.entry_map.get(key).map(|entry| entry.value.clone())
+    }
+
+    pub fn del(&mut self, key: &str) {
+        self.entry_map.remove(key);
+    }
+
+    pub fn remove_expired_entries(&mut self, now: Instant) {
+        self.entry_map.retain(|_, entry| !entry.is_expired(now));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entry_map.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_permanent_and_get() {
+        let mut in_memory_ttl_store = InMemoryTtlStore::new();
+        in_memory_ttl_store.set_permanent("session", "1");
+
+        let far_future = Instant::now() + Duration::from_secs(365 * 24 * 3600);
+        assert_eq!(in_memory_ttl_store.get_at("session", far_future), Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_entry_expires_after_ttl() {
+        let mut in_memory_ttl_store = InMemoryTtlStore::new();
+        let now = Instant::now();
+        in_memory_ttl_store.set_with_expiry_at("session", "1", 10, now);
+
+        assert_eq!(in_memory_ttl_store.get_at("session", now + Duration::from_secs(9)), Some("1".to_string()));
+        assert_eq!(in_memory_ttl_store.get_at("session", now + Duration::from_secs(10)), None);
+        assert_eq!(in_memory_ttl_store.len(), 0);
+    }
+
+    #[test]
+    fn test_set_again_refreshes_expiry() {
+        let mut in_memory_ttl_store = InMemoryTtlStore::new();
+        let now = Instant::now();
+        in_memory_ttl_store.set_with_expiry_at("session", "1", 10, now);
+        in_memory_ttl_store.set_with_expiry_at("session", "1", 10, now + Duration::from_secs(8));
+
+        assert_eq!(in_memory_ttl_store.get_at("session", now + Duration::from_secs(15)), Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_del_and_remove_expired_entries() {
+        let mut in_memory_ttl_store = InMemoryTtlStore::new();
+        let now = Instant::now();
+        in_memory_ttl_store.set_permanent("permanent", "1");
+        in_memory_ttl_store.set_with_expiry_at("temporary", "2", 1, now);
+        in_memory_ttl_store.set_permanent("deleted", "3");
+
+        in_memory_ttl_store.del("deleted");
+        in_memory_ttl_store.remove_expired_entries(now + Duration::from_secs(2));
+
+        assert_eq!(in_memory_ttl_store.len(), 1);
+        assert_eq!(in_memory_ttl_store.get("permanent"), Some("1".to_string()));
+    }
+}
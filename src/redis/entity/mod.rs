@@ -0,0 +1,2 @@
+pub mod session_store_type;
+pub mod in_memory_ttl_store;
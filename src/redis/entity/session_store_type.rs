@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStoreType {
+    Redis,
+    InMemory,
+}
+
+impl SessionStoreType {
+    // 알 수 없는 값은 ServerConfig 검증 단계에서 오류로 보고 (미설정 시 기본값 Redis)
+    pub fn from_config_value(value: &str) -> Option<SessionStoreType> {
+        match value.trim().to_lowercase().as_str() {
+            "memory" | "in_memory" | "in-memory" => Some(SessionStoreType::InMemory),
+            "redis" => Some(SessionStoreType::Redis),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config_value() {
+        assert_eq!(SessionStoreType::from_config_value("redis"), Some(SessionStoreType::Redis));
+        assert_eq!(SessionStoreType::from_config_value("Memory"), Some(SessionStoreType::InMemory));
+        assert_eq!(SessionStoreType::from_config_value("in_memory"), Some(SessionStoreType::InMemory));
+        assert_eq!(SessionStoreType::from_config_value("unknown"), None);
+    }
+}
@@ -1,2 +1,3 @@
+pub mod entity;
 pub mod repository;
-pub mod service;
\ No newline at end of file
+pub mod service;
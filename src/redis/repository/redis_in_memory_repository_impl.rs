@@ -1,5 +1,4 @@
 use std::sync::Arc;
-use std::time::Duration;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 
@@ -7,15 +6,35 @@ use chrono::{Utc, Datelike, DateTime, TimeZone};
 
 use tokio::sync::Mutex as AsyncMutex;
 use crate::common::env::env_detector::EnvDetector;
+use crate::redis::entity::in_memory_ttl_store::InMemoryTtlStore;
+use crate::redis::entity::session_store_type::SessionStoreType;
 use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
+use crate::server_config::server_config::ServerConfig;
+
+
+enum SessionStoreBackend {
+    Redis(redis::Connection),
+    InMemory(InMemoryTtlStore),
+}
 
 pub struct RedisInMemoryRepositoryImpl {
-    connection: redis::Connection,
+    backend: SessionStoreBackend,
 }
 
 impl RedisInMemoryRepositoryImpl {
     fn new() -> Self {
-        let client = redis::Client::open("redis://127.0.0.1/")
+        let session_store_type = ServerConfig::get_instance().get_session_store();
+        println!("RedisInMemoryRepositoryImpl: session store -> {:?}", session_store_type);
+
+        match session_store_type {
+            SessionStoreType::Redis => Self::new_redis(),
+            SessionStoreType::InMemory => Self::new_in_memory(),
+        }
+    }
+
+    fn new_redis() -> Self {
+        let redis_url = ServerConfig::get_instance().get_redis_url().to_string();
+        let client = redis::Client::open(redis_url)
             .expect("Failed to connect to Redis");
 
         let mut connection = client
@@ -27,7 +46,11 @@ impl RedisInMemoryRepositoryImpl {
             .query::<()>(&mut connection)
             .expect("Failed to authenticate to Redis");
 
-        RedisInMemoryRepositoryImpl { connection }
+        RedisInMemoryRepositoryImpl { backend: SessionStoreBackend::Redis(connection) }
+    }
+
+    pub fn new_in_memory() -> Self {
+        RedisInMemoryRepositoryImpl { backend: SessionStoreBackend::InMemory(InMemoryTtlStore::new()) }
     }
 
     pub fn get_instance() -> Arc<AsyncMutex<RedisInMemoryRepositoryImpl>> {
@@ -37,84 +60,120 @@ impl RedisInMemoryRepositoryImpl {
         }
         INSTANCE.clone()
     }
+
+    // 협정세계시 기준 (UTC) : 대한민국 시간은 UTC+9 (ex. UTC 14 시 59 분 59 초 = 대한민국 23 시 59 분 59 초)
+    fn seconds_until_daily_reset() -> i64 {
+        let now = Utc::now;
+        println!("now_time: {:?}", now());
+        let target_time: DateTime<Utc> = Utc.with_ymd_and_hms(now().year(), now().month(), now().day(), 14, 59, 59).unwrap();
+        println!("target_time: {:?}", target_time);
+        let difference_time = target_time - now();
+        let difference_seconds = difference_time.num_seconds();
+        // 협정세계시의 일자가 대한민국의 일자 보다 늦을 경우, 일자를 +1(86400 초) 조정함 (참고: 대한민국 00 시 ~ 09 시 일 때 해당함)
+        let result_difference_seconds = if difference_seconds < 1 {
+            difference_seconds + 86400
+        } else {
+            difference_seconds
+        };
+        // 잔여시간을 시, 분, 초 으로 표현
+        let hours = result_difference_seconds / 3600;
+        let minutes = (result_difference_seconds % 3600) / 60;
+        let remaining_seconds = result_difference_seconds % 60;
+
+        println!("remaining_time: {:?} hours {:?} minutes {:?} seconds", hours, minutes, remaining_seconds);
+
+        result_difference_seconds
+    }
 }
 
 #[async_trait]
 impl RedisInMemoryRepository for RedisInMemoryRepositoryImpl {
     async fn set_permanent(&mut self, key: &str, value: &str) {
         println!("RedisInMemoryRepositoryImpl: set()");
-        let _: () = redis::cmd("SET")
-            .arg(key)
-            .arg(value)
-            .query(&mut self.connection)
-            .expect("Failed to set key");
+        match &mut self.backend {
+            SessionStoreBackend::Redis(connection) => {
+                let _: () = redis::cmd("SET")
+                    .arg(key)
+                    .arg(value)
+                    .query(connection)
+                    .expect("Failed to set key");
+            }
+            SessionStoreBackend::InMemory(in_memory_ttl_store) => {
+                in_memory_ttl_store.set_permanent(key, value);
+            }
+        }
     }
 
     async fn set_with_expired_time(&mut self, key: &str, value: &str, expiry_seconds: Option<u32>) {
         println!("RedisInMemoryRepositoryImpl: set_with_expired_time()");
 
         if let Some(expiry_seconds) = expiry_seconds {
-            redis::cmd("SET")
-                .arg(key)
-                .arg(value)
-                .arg("EX").arg(expiry_seconds)
-                .query::<()>(&mut self.connection)
-                .expect("Failed to set key");
+            match &mut self.backend {
+                SessionStoreBackend::Redis(connection) => {
+                    redis::cmd("SET")
+                        .arg(key)
+                        .arg(value)
+                        .arg("EX").arg(expiry_seconds)
+                        .query::<()>(connection)
+                        .expect("Failed to set key");
+                }
+                SessionStoreBackend::InMemory(in_memory_ttl_store) => {
+                    in_memory_ttl_store.set_with_expiry(key, value, expiry_seconds as u64);
+                }
+            }
         }
     }
 
     async fn get(&mut self, key: &str) -> Option<String> {
         println!("RedisInMemoryRepositoryImpl: get()");
-        let result: Option<String> = redis::cmd("GET")
-            .arg(key)
-            .query(&mut self.connection)
-            .expect("Failed to get key");
-        result
+        match &mut self.backend {
+            SessionStoreBackend::Redis(connection) => {
+                let result: Option<String> = redis::cmd("GET")
+                    .arg(key)
+                    .query(connection)
+                    .expect("Failed to get key");
+                result
+            }
+            SessionStoreBackend::InMemory(in_memory_ttl_store) => in_memory_ttl_store.get(key),
+        }
     }
 
     async fn update_expired_time(&mut self, key: &str, value: &str) {
         println!("RedisInMemoryRepositoryImpl: update_expired_time()");
-        self.set_with_expired_time(key, value, Some(3600)).await;
+        self.set_with_expired_time(key, value, Some(ServerConfig::get_instance().get_session_expired_seconds())).await;
     }
 
     async fn del(&mut self, key: &str) {
         println!("RedisInMemoryRepositoryImpl: del()");
-        redis::cmd("DEL")
-            .arg(key)
-            .query::<()>(&mut self.connection)
-            .expect("Failed to delete key");
+        match &mut self.backend {
+            SessionStoreBackend::Redis(connection) => {
+                redis::cmd("DEL")
+                    .arg(key)
+                    .query::<()>(connection)
+                    .expect("Failed to delete key");
+            }
+            SessionStoreBackend::InMemory(in_memory_ttl_store) => in_memory_ttl_store.del(key),
+        }
     }
 
     async fn set_with_expired_target_time(&mut self, key: &str, value: &str) {
         println!("RedisInMemoryRepositoryImpl: set_with_expired_target_time()");
 
-            let now = Utc::now;
-            println!("now_time: {:?}", now());
-            // 협정세계시 기준 (UTC) : 대한민국 시간은 UTC+9 (ex. UTC 14 시 59 분 59 초 = 대한민국 23 시 59 분 59 초)
-            let target_time: DateTime<Utc> = Utc.with_ymd_and_hms(now().year(), now().month(), now().day(), 14, 59, 59).unwrap();
-            println!("target_time: {:?}", target_time);
-            let difference_time = target_time - now();
-            let difference_seconds = difference_time.num_seconds();
-                // 협정세계시의 일자가 대한민국의 일자 보다 늦을 경우, 일자를 +1(86400 초) 조정함 (참고: 대한민국 00 시 ~ 09 시 일 때 해당함)
-                let mut result_difference_seconds;
-                if  Some(difference_seconds) < Some(1) {
-                    result_difference_seconds = difference_seconds + 86400;
-                } else {
-                    result_difference_seconds = difference_seconds;
-                }
-            // 잔여시간을 시, 분, 초 으로 표현
-            let hours = result_difference_seconds / 3600;
-            let minutes = (result_difference_seconds % 3600) / 60;
-            let remaining_seconds = result_difference_seconds % 60;
-
-            println!("remaining_time: {:?} hours {:?} minutes {:?} seconds", hours, minutes, remaining_seconds);
-
-            redis::cmd("SET")
-                .arg(key)
-                .arg(value)
-                .arg("EX").arg(result_difference_seconds)
-                .query::<()>(&mut self.connection)
-                .expect("Failed to set key");
+        let result_difference_seconds = Self::seconds_until_daily_reset();
+
+        match &mut self.backend {
+            SessionStoreBackend::Redis(connection) => {
+                redis::cmd("SET")
+                    .arg(key)
+                    .arg(value)
+                    .arg("EX").arg(result_difference_seconds)
+                    .query::<()>(connection)
+                    .expect("Failed to set key");
+            }
+            SessionStoreBackend::InMemory(in_memory_ttl_store) => {
+                in_memory_ttl_store.set_with_expiry(key, value, result_difference_seconds as u64);
+            }
+        }
     }
 }
 
@@ -168,4 +227,19 @@ mod tests {
     async fn dummy_test() {
         assert!(true);
     }
+
+    #[tokio::test]
+    async fn test_in_memory_session_store() {
+        let mut redis_in_memory_repository = RedisInMemoryRepositoryImpl::new_in_memory();
+
+        redis_in_memory_repository.set_with_expired_time("in_memory_session", "7", Some(60)).await;
+        redis_in_memory_repository.update_expired_time("in_memory_session", "7").await;
+        assert_eq!(redis_in_memory_repository.get("in_memory_session").await, Some("7".to_string()));
+
+        redis_in_memory_repository.set_with_expired_time("ignored_session", "8", None).await;
+        assert_eq!(redis_in_memory_repository.get("ignored_session").await, None);
+
+        redis_in_memory_repository.del("in_memory_session").await;
+        assert_eq!(redis_in_memory_repository.get("in_memory_session").await, None);
+    }
 }
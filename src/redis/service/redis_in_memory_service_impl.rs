@@ -16,6 +16,7 @@ use crate::redis::service::response::save_key_and_value_response::SaveKeyAndValu
 use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
 use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
 use crate::redis::service::redis_in_memory_service::RedisInMemoryService;
+use crate::server_config::server_config::ServerConfig;
 
 pub struct RedisInMemoryServiceImpl {
     redis_in_memory_repository: Arc<AsyncMutex<RedisInMemoryRepositoryImpl>>,
@@ -47,7 +48,7 @@ impl RedisInMemoryService for RedisInMemoryServiceImpl {
         println!("RedisInMemoryServiceImpl: save_key_and_value()");
 
         let mut redis_in_memory_repository_guard = self.redis_in_memory_repository.lock().await;
-        let result_redis = redis_in_memory_repository_guard.set_with_expired_time(save_key_and_value_request.key(), save_key_and_value_request.value(), Some(3600)).await;
+        let result_redis = redis_in_memory_repository_guard.set_with_expired_time(save_key_and_value_request.key(), save_key_and_value_request.value(), Some(ServerConfig::get_instance().get_session_expired_seconds())).await;
         if Some(result_redis).is_some() {
             return SaveKeyAndValueResponse::new(true)
         }
@@ -1,12 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 
 use crate::account_card::service::request::account_card_list_request::AccountCardListRequest;
 
-pub fn create_account_card_list_request(data: &JsonValue) -> Option<AccountCardListRequest> {
-    if let Some(account_session_id) =
-        data.get("sessionInfo").and_then(|v| v.as_str()) {
-        Some(AccountCardListRequest::new(account_session_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_account_card_list_request(data: &JsonValue) -> Result<AccountCardListRequest, RequestParseError> {
+    let account_session_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountCardListRequest::new(account_session_id.to_string()))
 }
\ No newline at end of file
@@ -1,60 +1,48 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_array_field, read_i64_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::account_deck::service::request::account_deck_modify_request::AccountDeckModifyRequest;
 
 use crate::account_deck_card::controller::request_form::account_deck_card_list_request_form::AccountDeckCardListRequestFrom;
 use crate::account_deck_card::controller::request_form::account_deck_card_modify_request_form::AccountDeckCardModifyRequestForm;
 use crate::account_deck_card::controller::request_form::account_deck_configuration_request_form::AccountDeckConfigurationRequestForm;
 
-pub fn create_account_deck_configuration_request_form(data: &JsonValue) -> Option<AccountDeckConfigurationRequestForm> {
-    if let (Some(deck_id), Some(card_list), Some(sessionInfo)) = (
-        data.get("deckId").and_then(|v| v.as_i64()),
-        data.get("cardIdList").and_then(|v| v.as_array()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-
-    ) {
-        let deck_id_i32 = deck_id as i32;
-        let mut card_vec_i32 = Vec::new();
-
-        for card_id_value  in card_list.iter() {
-            if let Some(card_id) = card_id_value.as_i64() {
-                let card_id_i32 = card_id as i32;
-                card_vec_i32.push(card_id_i32);
-            }
+pub fn create_account_deck_configuration_request_form(data: &JsonValue) -> Result<AccountDeckConfigurationRequestForm, RequestParseError> {
+    let deck_id = read_i64_field(data, "deckId")?;
+    let card_list = read_array_field(data, "cardIdList")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    let deck_id_i32 = deck_id as i32;
+    let mut card_vec_i32 = Vec::new();
+
+    for card_id_value  in card_list.iter() {
+        if let Some(card_id) = card_id_value.as_i64() {
+            let card_id_i32 = card_id as i32;
+            card_vec_i32.push(card_id_i32);
         }
-        Some(AccountDeckConfigurationRequestForm::new(deck_id_i32, card_vec_i32, sessionInfo.to_string()))
-    } else {
-        None
     }
+    Ok(AccountDeckConfigurationRequestForm::new(deck_id_i32, card_vec_i32, session_info.to_string()))
 }
 
-pub fn create_account_deck_card_list_request_form(data: &JsonValue) -> Option<AccountDeckCardListRequestFrom> {
-    if let Some(deck_id) = (
-        data.get("deckId").and_then(|v| v.as_i64())
-    ) {
-        let deck_id_i32 = deck_id as i32;
-        Some(AccountDeckCardListRequestFrom::new(deck_id_i32))
-    } else {
-        None
-    }
+pub fn create_account_deck_card_list_request_form(data: &JsonValue) -> Result<AccountDeckCardListRequestFrom, RequestParseError> {
+    let deck_id = read_i64_field(data, "deckId")?;
+
+    let deck_id_i32 = deck_id as i32;
+    Ok(AccountDeckCardListRequestFrom::new(deck_id_i32))
 }
-pub fn create_account_deck_card_modify_request_form(data: &JsonValue) -> Option<AccountDeckCardModifyRequestForm> {
-    if let (Some(deck_id), Some(card_list), Some(sessionInfo)) = (
-        data.get("deckId").and_then(|v| v.as_i64()),
-        data.get("cardIdList").and_then(|v| v.as_array()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-
-    ) {
-        let deck_id_i32 = deck_id as i32;
-        let mut card_vec_i32 = Vec::new();
-
-        for card_id_value  in card_list.iter() {
-            if let Some(card_id) = card_id_value.as_i64() {
-                let card_id_i32 = card_id as i32;
-                card_vec_i32.push(card_id_i32);
-            }
+pub fn create_account_deck_card_modify_request_form(data: &JsonValue) -> Result<AccountDeckCardModifyRequestForm, RequestParseError> {
+    let deck_id = read_i64_field(data, "deckId")?;
+    let card_list = read_array_field(data, "cardIdList")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    let deck_id_i32 = deck_id as i32;
+    let mut card_vec_i32 = Vec::new();
+
+    for card_id_value  in card_list.iter() {
+        if let Some(card_id) = card_id_value.as_i64() {
+            let card_id_i32 = card_id as i32;
+            card_vec_i32.push(card_id_i32);
         }
-        Some(AccountDeckCardModifyRequestForm::new(deck_id_i32, card_vec_i32, sessionInfo.to_string()))
-    } else {
-        None
     }
+    Ok(AccountDeckCardModifyRequestForm::new(deck_id_i32, card_vec_i32, session_info.to_string()))
 }
\ No newline at end of file
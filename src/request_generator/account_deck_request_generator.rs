@@ -1,50 +1,38 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i64_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 
 use crate::account_deck::service::request::account_deck_delete_request::AccountDeckDeleteRequest;
 use crate::account_deck::service::request::account_deck_list_request::AccountDeckListRequest;
 use crate::account_deck::service::request::account_deck_modify_request::AccountDeckModifyRequest;
 use crate::account_deck::service::request::account_deck_register_request::AccountDeckRegisterRequest;
 
-pub fn create_deck_register_request(data: &JsonValue) -> Option<AccountDeckRegisterRequest> {
-    if let (Some(account_id), Some(deck_name)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("deckName").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountDeckRegisterRequest::new(account_id.to_string(), deck_name.to_string()))
-    } else {
-        None
-    }
+pub fn create_deck_register_request(data: &JsonValue) -> Result<AccountDeckRegisterRequest, RequestParseError> {
+    let account_id = read_str_field(data, "sessionInfo")?;
+    let deck_name = read_str_field(data, "deckName")?;
+
+    Ok(AccountDeckRegisterRequest::new(account_id.to_string(), deck_name.to_string()))
 }
 
-pub fn create_deck_list_request(data: &JsonValue) -> Option<AccountDeckListRequest> {
-    if let Some(account_id) = data.get("sessionInfo").and_then(|v| v.as_str()) {
-        Some(AccountDeckListRequest::new(account_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_deck_list_request(data: &JsonValue) -> Result<AccountDeckListRequest, RequestParseError> {
+    let account_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountDeckListRequest::new(account_id.to_string()))
 }
 
-pub fn create_deck_modify_request(data: &JsonValue) -> Option<AccountDeckModifyRequest> {
-    if let (Some(deck_id), Some(account_id), Some(deck_name)) = (
-        data.get("deckId").and_then(|v| v.as_i64()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("deckName").and_then(|v| v.as_str())
-    ) {
-        let deck_id_opt_i32 = deck_id as i32;
-        Some(AccountDeckModifyRequest::new(deck_id_opt_i32, account_id.to_string(), deck_name.to_string()))
-    } else {
-        None
-    }
+pub fn create_deck_modify_request(data: &JsonValue) -> Result<AccountDeckModifyRequest, RequestParseError> {
+    let deck_id = read_i64_field(data, "deckId")?;
+    let account_id = read_str_field(data, "sessionInfo")?;
+    let deck_name = read_str_field(data, "deckName")?;
+
+    let deck_id_opt_i32 = deck_id as i32;
+    Ok(AccountDeckModifyRequest::new(deck_id_opt_i32, account_id.to_string(), deck_name.to_string()))
 }
 
-pub fn create_deck_delete_request(data: &JsonValue) -> Option<AccountDeckDeleteRequest> {
-    if let (Some(deck_unique_id), Some(account_session_id))= (
-        data.get("deckId").and_then(|v| v.as_i64()),
-        data.get("sessionInfo").and_then(|v| v.as_str())
-    ) {
-        let deck_unique_id_i32 = deck_unique_id as i32;
-        Some(AccountDeckDeleteRequest::new(deck_unique_id_i32, account_session_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_deck_delete_request(data: &JsonValue) -> Result<AccountDeckDeleteRequest, RequestParseError> {
+    let deck_unique_id = read_i64_field(data, "deckId")?;
+    let account_session_id = read_str_field(data, "sessionInfo")?;
+
+    let deck_unique_id_i32 = deck_unique_id as i32;
+    Ok(AccountDeckDeleteRequest::new(deck_unique_id_i32, account_session_id.to_string()))
 }
\ No newline at end of file
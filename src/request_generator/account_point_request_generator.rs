@@ -1,29 +1,23 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_i64_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::account_point::service::request::gain_gold_request::GainGoldRequest;
 use crate::account_point::service::request::pay_gold_request::PayGoldRequest;
 
-pub fn create_gain_gold_request(data: &JsonValue) -> Option<GainGoldRequest> {
-    if let (Some(account_id), Some(gold)) = (
-        data.get("accountId").and_then(|v| v.as_i64()),
-        data.get("gold").and_then(|v| v.as_i64())
-    ) {
-        let account_id_i32 = account_id as i32;
-        let gold_i32 = gold as i32;
-        Some(GainGoldRequest::new(account_id_i32, gold_i32))
-    } else {
-        None
-    }
+pub fn create_gain_gold_request(data: &JsonValue) -> Result<GainGoldRequest, RequestParseError> {
+    let account_id = read_i64_field(data, "accountId")?;
+    let gold = read_i64_field(data, "gold")?;
+
+    let account_id_i32 = account_id as i32;
+    let gold_i32 = gold as i32;
+    Ok(GainGoldRequest::new(account_id_i32, gold_i32))
 }
 
-pub fn create_pay_gold_request(data: &JsonValue) -> Option<PayGoldRequest> {
-    if let (Some(account_id), Some(gold)) = (
-        data.get("accountId").and_then(|v| v.as_i64()),
-        data.get("gold").and_then(|v| v.as_i64())
-    ) {
-        let account_id_i32 = account_id as i32;
-        let gold_i32 = gold as i32;
-        Some(PayGoldRequest::new(account_id_i32, gold_i32))
-    } else {
-        None
-    }
+pub fn create_pay_gold_request(data: &JsonValue) -> Result<PayGoldRequest, RequestParseError> {
+    let account_id = read_i64_field(data, "accountId")?;
+    let gold = read_i64_field(data, "gold")?;
+
+    let account_id_i32 = account_id as i32;
+    let gold_i32 = gold as i32;
+    Ok(PayGoldRequest::new(account_id_i32, gold_i32))
 }
\ No newline at end of file
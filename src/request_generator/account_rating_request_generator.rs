@@ -0,0 +1,18 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
+
+use crate::account_rating::service::request::account_rating_request::AccountRatingRequest;
+use crate::account_rating::service::request::match_history_list_request::MatchHistoryListRequest;
+
+pub fn create_account_rating_request(data: &JsonValue) -> Result<AccountRatingRequest, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountRatingRequest::new(session_id.to_string()))
+}
+
+pub fn create_match_history_list_request(data: &JsonValue) -> Result<MatchHistoryListRequest, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(MatchHistoryListRequest::new(session_id.to_string()))
+}
@@ -1,62 +1,44 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::account::service::request::account_login_request::AccountLoginRequest;
 use crate::account::service::request::account_register_request::AccountRegisterRequest;
 use crate::account::service::request::account_logout_request::AccountLogoutRequest;
 use crate::account::service::request::account_modify_request::AccountModifyRequest;
 use crate::account::service::request::account_delete_request::AccountDeleteRequest;
 
-pub fn create_register_request(data: &JsonValue) -> Option<AccountRegisterRequest> {
-    if let (Some(username), Some(password)) = (
-        data.get("userId").and_then(|v| v.as_str()),
-        data.get("password").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountRegisterRequest::new(username, password.to_string()))
-    } else {
-        None
-    }
+pub fn create_register_request(data: &JsonValue) -> Result<AccountRegisterRequest, RequestParseError> {
+    let username = read_str_field(data, "userId")?;
+    let password = read_str_field(data, "password")?;
+
+    Ok(AccountRegisterRequest::new(username, password.to_string()))
 }
 
-pub fn create_login_request(data: &JsonValue) -> Option<AccountLoginRequest> {
-    if let (Some(username), Some(password)) = (
-        data.get("userId").and_then(|v| v.as_str()),
-        data.get("password").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountLoginRequest::new(username, password.to_string()))
-    } else {
-        None
-    }
+pub fn create_login_request(data: &JsonValue) -> Result<AccountLoginRequest, RequestParseError> {
+    let username = read_str_field(data, "userId")?;
+    let password = read_str_field(data, "password")?;
+
+    Ok(AccountLoginRequest::new(username, password.to_string()))
 }
 
-pub fn create_logout_request(data: &JsonValue) -> Option<AccountLogoutRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountLogoutRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_logout_request(data: &JsonValue) -> Result<AccountLogoutRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountLogoutRequest::new(session_info.to_string()))
 }
 
-pub fn create_account_modify_request(data: &JsonValue) -> Option<AccountModifyRequest> {
-    if let (Some(username), Some(password), Some(new_password)) = (
-        data.get("userId").and_then(|v| v.as_str()),
-        data.get("password").and_then(|v| v.as_str()),
-        data.get("new_password").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountModifyRequest::new(username, password.to_string(), new_password.to_string()))
-    } else {
-        None
-    }
+pub fn create_account_modify_request(data: &JsonValue) -> Result<AccountModifyRequest, RequestParseError> {
+    let username = read_str_field(data, "userId")?;
+    let password = read_str_field(data, "password")?;
+    let new_password = read_str_field(data, "new_password")?;
+
+    Ok(AccountModifyRequest::new(username, password.to_string(), new_password.to_string()))
 }
 
-pub fn create_account_delete_request(data: &JsonValue) -> Option<AccountDeleteRequest> {
-    if let (Some(username), Some(password), Some(session_id)) = (
-        data.get("userId").and_then(|v| v.as_str()),
-        data.get("password").and_then(|v| v.as_str()),
-        data.get("session_id").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountDeleteRequest::new(username, password.to_string(), session_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_account_delete_request(data: &JsonValue) -> Result<AccountDeleteRequest, RequestParseError> {
+    let username = read_str_field(data, "userId")?;
+    let password = read_str_field(data, "password")?;
+    let session_id = read_str_field(data, "session_id")?;
+
+    Ok(AccountDeleteRequest::new(username, password.to_string(), session_id.to_string()))
 }
\ No newline at end of file
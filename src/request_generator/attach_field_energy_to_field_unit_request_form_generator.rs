@@ -1,18 +1,24 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::common::card_attributes::card_race::card_race_enum::RaceEnum;
 use crate::game_field_energy::controller::request_form::attach_field_energy_to_field_unit_request_form::AttachFieldEnergyToFieldUnitRequestForm;
 
-pub fn create_attach_field_energy_to_field_unit_request_form(data: &JsonValue) -> Option<AttachFieldEnergyToFieldUnitRequestForm> {
-    if let (Some(session_info), Some(unit_card_index), Some(energy_race), Some(energy_count)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("unitIndex").and_then(|v| v.as_str()),
-        data.get("energyRace").and_then(|v| v.as_str()),
-        data.get("energyCount").and_then(|v| v.as_str()),
-    ) {
-        Some(AttachFieldEnergyToFieldUnitRequestForm::new(session_info.to_string(),
-                                                          unit_card_index.to_string(),
-                                                          energy_race.to_string(),
-                                                          energy_count.to_string()))
-    } else {
-        None
+pub fn create_attach_field_energy_to_field_unit_request_form(data: &JsonValue) -> Result<AttachFieldEnergyToFieldUnitRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let unit_card_index = read_i32_string_field(data, "unitIndex")?;
+    let energy_race = read_i32_string_field(data, "energyRace")?;
+    let energy_count = read_i32_string_field(data, "energyCount")?;
+
+    if !(RaceEnum::Human as i32..=RaceEnum::Chaos as i32).contains(&energy_race) {
+        return Err(RequestParseError::InvalidFieldValue {
+            field_name: "energyRace".to_string(),
+            value: energy_race.to_string(),
+        })
     }
+
+    Ok(AttachFieldEnergyToFieldUnitRequestForm::new(session_info.to_string(),
+                                                      unit_card_index,
+                                                      energy_race,
+                                                      energy_count))
 }
\ No newline at end of file
@@ -1,14 +1,12 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_energy::controller::request_form::attach_general_energy_card_request_form::AttachGeneralEnergyCardRequestForm;
 
-pub fn create_attach_general_energy_card_request_form(data: &JsonValue) -> Option<AttachGeneralEnergyCardRequestForm> {
-    if let (Some(unit_index), Some(session_info), Some(energy_card_id)) = (
-        data.get("unitIndex").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("energyCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(AttachGeneralEnergyCardRequestForm::new(session_info.to_string(), unit_index.to_string(), energy_card_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_attach_general_energy_card_request_form(data: &JsonValue) -> Result<AttachGeneralEnergyCardRequestForm, RequestParseError> {
+    let unit_index = read_i32_string_field(data, "unitIndex")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let energy_card_id = read_i32_string_field(data, "energyCardId")?;
+
+    Ok(AttachGeneralEnergyCardRequestForm::new(session_info.to_string(), unit_index, energy_card_id))
 }
@@ -1,17 +1,15 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_unit::controller::request_form::attack_game_main_character_request_form::AttackGameMainCharacterRequestForm;
 
-pub fn create_attack_game_main_character_request_form(data: &JsonValue) -> Option<AttackGameMainCharacterRequestForm> {
-    if let (Some(sessionInfo), Some(attacker_unit_index), Some(target_game_main_character_index)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("attacker_unit_index").and_then(|v| v.as_str()),
-        data.get("target_game_main_character_index").and_then(|v| v.as_str()),
-    ) {
-        Some(AttackGameMainCharacterRequestForm::new(
-            sessionInfo.to_string(),
-            attacker_unit_index.to_string(),
-            target_game_main_character_index.to_string()))
-    } else {
-        None
-    }
+pub fn create_attack_game_main_character_request_form(data: &JsonValue) -> Result<AttackGameMainCharacterRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let attacker_unit_index = read_i32_string_field(data, "attacker_unit_index")?;
+    let target_game_main_character_index = read_str_field(data, "target_game_main_character_index")?;
+
+    Ok(AttackGameMainCharacterRequestForm::new(
+        session_info.to_string(),
+        attacker_unit_index,
+        target_game_main_character_index.to_string()))
 }
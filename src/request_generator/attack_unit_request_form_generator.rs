@@ -1,17 +1,15 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_unit::controller::request_form::attack_unit_request_form::AttackUnitRequestForm;
 
-pub fn create_attack_unit_request_form(data: &JsonValue) -> Option<AttackUnitRequestForm> {
-    if let (Some(sessionInfo), Some(attacker_unit_index), Some(target_unit_index)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("attacker_unit_index").and_then(|v| v.as_str()),
-        data.get("target_unit_index").and_then(|v| v.as_str()),
-    ) {
-        Some(AttackUnitRequestForm::new(
-            sessionInfo.to_string(),
-            attacker_unit_index.to_string(),
-            target_unit_index.to_string()))
-    } else {
-        None
-    }
+pub fn create_attack_unit_request_form(data: &JsonValue) -> Result<AttackUnitRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let attacker_unit_index = read_i32_string_field(data, "attacker_unit_index")?;
+    let target_unit_index = read_i32_string_field(data, "target_unit_index")?;
+
+    Ok(AttackUnitRequestForm::new(
+        session_info.to_string(),
+        attacker_unit_index,
+        target_unit_index))
 }
@@ -1,22 +1,23 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_finish::service::request::battle_finish_request::BattleFinishRequest;
 use crate::game_winner_check::entity::finish_position_enum::FinishPositionEnum;
 
-pub fn create_battle_finish_request(data: &JsonValue) -> Option<BattleFinishRequest> {
-    if let (Some(sessionInfo), Some(finishPositionEnum)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("finishPositionEnum").and_then(|v|v.as_str())
-    ) {
-        let finish_position_enum_match = match finishPositionEnum {
-            "Dummy" => Ok(FinishPositionEnum::Dummy),
-            "Winner" => Ok(FinishPositionEnum::Winner),
-            "Loser" => Ok(FinishPositionEnum::Loser),
-            "Draw" => Ok(FinishPositionEnum::Draw),
-            _ => Err(()),
-        }.unwrap();
+pub fn create_battle_finish_request(data: &JsonValue) -> Result<BattleFinishRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let finish_position_enum = read_str_field(data, "finishPositionEnum")?;
 
-        Some(BattleFinishRequest::new(sessionInfo.to_string(), finish_position_enum_match))
-    } else {
-        None
-    }
+    let finish_position_enum_match = match finish_position_enum {
+        "Dummy" => FinishPositionEnum::Dummy,
+        "Winner" => FinishPositionEnum::Winner,
+        "Loser" => FinishPositionEnum::Loser,
+        "Draw" => FinishPositionEnum::Draw,
+        _ => return Err(RequestParseError::InvalidFieldValue {
+            field_name: "finishPositionEnum".to_string(),
+            value: finish_position_enum.to_string(),
+        }),
+    };
+
+    Ok(BattleFinishRequest::new(session_info.to_string(), finish_position_enum_match))
 }
@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_ready_account_hash::service::request::battle_ready_account_hash_request::BattleReadyAccountHashRequest;
 
-pub fn create_battle_ready_account_hash_request(data: &JsonValue) -> Option<BattleReadyAccountHashRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(BattleReadyAccountHashRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_battle_ready_account_hash_request(data: &JsonValue) -> Result<BattleReadyAccountHashRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(BattleReadyAccountHashRequest::new(session_info.to_string()))
 }
 
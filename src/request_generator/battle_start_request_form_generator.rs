@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_start::controller::request_form::battle_start_request_form::BattleStartRequestForm;
 
-pub fn create_battle_start_request_form(data: &JsonValue) -> Option<BattleStartRequestForm> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(BattleStartRequestForm::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_battle_start_request_form(data: &JsonValue) -> Result<BattleStartRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(BattleStartRequestForm::new(session_info.to_string()))
 }
 
@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_wait_queue::service::request::battle_wait_queue_request::BattleWaitQueueRequest;
 
-pub fn create_battle_wait_queue_request(data: &JsonValue) -> Option<BattleWaitQueueRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(BattleWaitQueueRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_battle_wait_queue_request(data: &JsonValue) -> Result<BattleWaitQueueRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(BattleWaitQueueRequest::new(session_info.to_string()))
 }
 
@@ -1,14 +1,12 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_ready_account_hash::service::request::check_battle_prepare_request::CheckBattlePrepareRequest;
 
 
-pub fn create_check_battle_prepare_request(data: &JsonValue) -> Option<CheckBattlePrepareRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(CheckBattlePrepareRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_check_battle_prepare_request(data: &JsonValue) -> Result<CheckBattlePrepareRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(CheckBattlePrepareRequest::new(session_info.to_string()))
 }
 
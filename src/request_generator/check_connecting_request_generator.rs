@@ -1,12 +1,10 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::check_connecting::service::request::checked_response_request::CheckedResponseRequest;
 
-pub fn create_check_connecting_request(data: &JsonValue) -> Option<CheckedResponseRequest> {
-    if let (Some(address),) = (
-        data.get("address").and_then(|v| v.as_str()),
-    ) {
-        Some(CheckedResponseRequest::new(address))
-    } else {
-        None
-    }
+pub fn create_check_connecting_request(data: &JsonValue) -> Result<CheckedResponseRequest, RequestParseError> {
+    let address = read_str_field(data, "address")?;
+
+    Ok(CheckedResponseRequest::new(address))
 }
\ No newline at end of file
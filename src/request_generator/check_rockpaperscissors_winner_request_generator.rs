@@ -1,16 +1,12 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::rock_paper_scissors::controller::request_form::check_rock_paper_scissors_winner_request_form::CheckRockPaperScissorsWinnerRequestForm;
 
-pub fn create_check_rockpaperscissors_winner_request_form(data: &JsonValue) -> Option<CheckRockPaperScissorsWinnerRequestForm> {
-    if let (Some(sessionInfo), ) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
+pub fn create_check_rockpaperscissors_winner_request_form(data: &JsonValue) -> Result<CheckRockPaperScissorsWinnerRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
 
-
-    ) {
-        Some(CheckRockPaperScissorsWinnerRequestForm::new(
-            sessionInfo.to_string(),
-            ))
-    } else {
-        None
-    }
+    Ok(CheckRockPaperScissorsWinnerRequestForm::new(
+        session_info.to_string(),
+        ))
 }
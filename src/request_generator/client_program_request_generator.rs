@@ -1,7 +1,7 @@
 use serde_json::Value as JsonValue;
 use crate::client_program::service::request::client_program_exit_request::ClientProgramExitRequest;
+use crate::request_generator::request_parse_error::RequestParseError;
 
-pub fn create_client_program_exit_request(data: &JsonValue) -> Option<ClientProgramExitRequest> {
-    Some(ClientProgramExitRequest::new())
+pub fn create_client_program_exit_request(data: &JsonValue) -> Result<ClientProgramExitRequest, RequestParseError> {
+    Ok(ClientProgramExitRequest::new())
 }
-
@@ -0,0 +1,12 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::game_card_environment::controller::request_form::deploy_environment_card_request_form::DeployEnvironmentCardRequestForm;
+
+
+pub fn create_deploy_environment_card_request_form(data: &JsonValue) -> Result<DeployEnvironmentCardRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let environment_card_id = read_i32_string_field(data, "environmentCardId")?;
+
+    Ok(DeployEnvironmentCardRequestForm::new(session_id, environment_card_id))
+}
\ No newline at end of file
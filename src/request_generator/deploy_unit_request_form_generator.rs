@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_unit::controller::request_form::deploy_unit_request_form::DeployUnitRequestForm;
 
-pub fn create_deploy_unit_request_form(data: &JsonValue) -> Option<DeployUnitRequestForm> {
-    if let (Some(unit_number), Some(session_info)) = (
-        data.get("unitId").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str())
-    ) {
-        Some(DeployUnitRequestForm::new(session_info.to_string(), unit_number.to_string()))
-    } else {
-        None
-    }
+pub fn create_deploy_unit_request_form(data: &JsonValue) -> Result<DeployUnitRequestForm, RequestParseError> {
+    let unit_number = read_i32_string_field(data, "unitId")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(DeployUnitRequestForm::new(session_info.to_string(), unit_number))
 }
\ No newline at end of file
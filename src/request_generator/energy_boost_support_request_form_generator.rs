@@ -1,17 +1,15 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_support::controller::request_form::energy_boost_support_request_form::EnergyBoostSupportRequestForm;
 
-pub fn create_energy_boost_support_request_form(data: &JsonValue) -> Option<EnergyBoostSupportRequestForm> {
-    if let (Some(unit_index_number), Some(session_info), Some(support_card_id)) = (
-        data.get("unitIndex").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("supportCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(EnergyBoostSupportRequestForm::new(
-            session_info.to_string(),
-            unit_index_number.to_string(),
-            support_card_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_energy_boost_support_request_form(data: &JsonValue) -> Result<EnergyBoostSupportRequestForm, RequestParseError> {
+    let unit_index_number = read_i32_string_field(data, "unitIndex")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let support_card_id = read_i32_string_field(data, "supportCardId")?;
+
+    Ok(EnergyBoostSupportRequestForm::new(
+        session_info.to_string(),
+        unit_index_number,
+        support_card_id))
 }
@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_deck::service::request::game_deck_start_card_list_request::{GameDeckStartCardListRequest};
 
-pub fn fake_create_game_deck_card_list_request(data: &JsonValue) -> Option<GameDeckStartCardListRequest> {
-    if let (Some(deck_id), Some(session_info)) = (
-        data.get("deckId").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str())
-    ) {
-        Some(GameDeckStartCardListRequest::new(deck_id.to_string(), session_info.to_string()))
-    } else {
-        None
-    }
+pub fn fake_create_game_deck_card_list_request(data: &JsonValue) -> Result<GameDeckStartCardListRequest, RequestParseError> {
+    let deck_id = read_str_field(data, "deckId")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(GameDeckStartCardListRequest::new(deck_id.to_string(), session_info.to_string()))
 }
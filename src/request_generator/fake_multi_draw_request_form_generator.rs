@@ -1,12 +1,10 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::fake_battle_room::controller::request_form::fake_multi_draw_request_form::FakeMultiDrawRequestForm;
 
-pub fn create_fake_multi_draw_request_form(data: &JsonValue) -> Option<FakeMultiDrawRequestForm> {
-    if let (Some(session_info)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str())
-    ) {
-        Some(FakeMultiDrawRequestForm::new(session_info.to_string()))
-    } else {
-        None
-    }
+pub fn create_fake_multi_draw_request_form(data: &JsonValue) -> Result<FakeMultiDrawRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(FakeMultiDrawRequestForm::new(session_info.to_string()))
 }
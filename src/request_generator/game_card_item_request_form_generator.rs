@@ -1,81 +1,63 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_array_field, read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_item::controller::request_form::add_field_energy_with_field_unit_health_point_item_request_form::AddFieldEnergyWithFieldUnitHealthPointRequestForm;
 use crate::game_card_item::controller::request_form::catastrophic_damage_item_request_form::CatastrophicDamageItemRequestForm;
 use crate::game_card_item::controller::request_form::multiple_target_damage_by_field_unit_death_item_request_form::MultipleTargetDamageByFieldUnitDeathItemRequestForm;
 use crate::game_card_item::controller::request_form::remove_opponent_field_unit_energy_item_request_form::RemoveOpponentFieldUnitEnergyItemRequestForm;
 use crate::game_card_item::controller::request_form::target_death_item_request_form::TargetDeathItemRequestForm;
 
-pub fn create_add_field_energy_by_field_unit_health_point_item_request_form(data: &JsonValue) -> Option<AddFieldEnergyWithFieldUnitHealthPointRequestForm> {
-    if let (Some(unit_index_number), Some(session_info), Some(item_card_id)) = (
-        data.get("unitIndex").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("itemCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(AddFieldEnergyWithFieldUnitHealthPointRequestForm::new(session_info,
-                                                                    item_card_id,
-                                                                    unit_index_number))
-    } else {
-        None
-    }
+pub fn create_add_field_energy_by_field_unit_health_point_item_request_form(data: &JsonValue) -> Result<AddFieldEnergyWithFieldUnitHealthPointRequestForm, RequestParseError> {
+    let unit_index_number = read_i32_string_field(data, "unitIndex")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let item_card_id = read_i32_string_field(data, "itemCardId")?;
+
+    Ok(AddFieldEnergyWithFieldUnitHealthPointRequestForm::new(session_info,
+                                                                item_card_id,
+                                                                unit_index_number))
 }
 
-pub fn create_target_death_item_request_form(data: &JsonValue) -> Option<TargetDeathItemRequestForm> {
-    if let (Some(opponent_target_unit_index_number), Some(session_info), Some(item_card_id)) = (
-        data.get("opponentTargetUnitIndex").and_then(|v| v.as_str()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("itemCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(TargetDeathItemRequestForm::new(session_info.to_string(),
-                                             opponent_target_unit_index_number.to_string(),
-                                             item_card_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_target_death_item_request_form(data: &JsonValue) -> Result<TargetDeathItemRequestForm, RequestParseError> {
+    let opponent_target_unit_index_number = read_i32_string_field(data, "opponentTargetUnitIndex")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let item_card_id = read_i32_string_field(data, "itemCardId")?;
+
+    Ok(TargetDeathItemRequestForm::new(session_info.to_string(),
+                                         opponent_target_unit_index_number,
+                                         item_card_id))
 }
 
-pub fn create_catastrophic_damage_item_request_form(data: &JsonValue) -> Option<CatastrophicDamageItemRequestForm> {
-    if let (Some(session_info), Some(item_card_id)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("itemCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(CatastrophicDamageItemRequestForm::new(session_info, item_card_id))
-    } else {
-        None
-    }
+pub fn create_catastrophic_damage_item_request_form(data: &JsonValue) -> Result<CatastrophicDamageItemRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let item_card_id = read_i32_string_field(data, "itemCardId")?;
+
+    Ok(CatastrophicDamageItemRequestForm::new(session_info, item_card_id))
 }
 
-pub fn create_multiple_target_damage_by_field_unit_sacrifice_item_request_form(data: &JsonValue) -> Option<MultipleTargetDamageByFieldUnitDeathItemRequestForm> {
-    if let (Some(opponent_target_unit_index_list), Some(session_info), Some(item_card_id), Some(my_field_unit_index)) = (
-        data.get("opponentTargetUnitIndexList").and_then(|v| v.as_array()),
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("itemCardId").and_then(|v| v.as_str()),
-        data.get("unitIndex").and_then(|v| v.as_str()),
-    ) {
-        let mut opponent_target_unit_index_list_string = Vec::new();
-        for value in opponent_target_unit_index_list {
-            if let Some(target_unit_index) = value.as_str() {
-                opponent_target_unit_index_list_string.push(target_unit_index.to_string());
-            }
+pub fn create_multiple_target_damage_by_field_unit_sacrifice_item_request_form(data: &JsonValue) -> Result<MultipleTargetDamageByFieldUnitDeathItemRequestForm, RequestParseError> {
+    let opponent_target_unit_index_list = read_array_field(data, "opponentTargetUnitIndexList")?;
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let item_card_id = read_i32_string_field(data, "itemCardId")?;
+    let my_field_unit_index = read_i32_string_field(data, "unitIndex")?;
+
+    let mut opponent_target_unit_index_list_string = Vec::new();
+    for value in opponent_target_unit_index_list {
+        if let Some(target_unit_index) = value.as_str() {
+            opponent_target_unit_index_list_string.push(target_unit_index.to_string());
         }
-        Some(MultipleTargetDamageByFieldUnitDeathItemRequestForm::new(my_field_unit_index,
-                                                                      session_info,
-                                                                      item_card_id,
-                                                                      opponent_target_unit_index_list_string))
-    } else {
-        None
     }
+    Ok(MultipleTargetDamageByFieldUnitDeathItemRequestForm::new(my_field_unit_index,
+                                                                  session_info,
+                                                                  item_card_id,
+                                                                  opponent_target_unit_index_list_string))
 }
 
-pub fn create_opponent_field_unit_energy_removal_item_request_form(data: &JsonValue) -> Option<RemoveOpponentFieldUnitEnergyItemRequestForm> {
-    if let (Some(session_info), Some(item_card_id), Some(opponent_field_unit_index)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("itemCardId").and_then(|v| v.as_str()),
-        data.get("opponentTargetUnitIndex").and_then(|v| v.as_str()),
-    ) {
-        Some(RemoveOpponentFieldUnitEnergyItemRequestForm::new(session_info,
-                                                               item_card_id,
-                                                               opponent_field_unit_index))
-    } else {
-        None
-    }
+pub fn create_opponent_field_unit_energy_removal_item_request_form(data: &JsonValue) -> Result<RemoveOpponentFieldUnitEnergyItemRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let item_card_id = read_i32_string_field(data, "itemCardId")?;
+    let opponent_field_unit_index = read_i32_string_field(data, "opponentTargetUnitIndex")?;
+
+    Ok(RemoveOpponentFieldUnitEnergyItemRequestForm::new(session_info,
+                                                           item_card_id,
+                                                           opponent_field_unit_index))
 }
\ No newline at end of file
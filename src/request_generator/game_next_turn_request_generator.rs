@@ -1,12 +1,10 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_turn::controller::request_form::turn_end_request_form::TurnEndRequestForm;
 
-pub fn create_game_turn_request_form(data: &JsonValue) -> Option<TurnEndRequestForm> {
-    if let (Some(sessionInfo),) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(TurnEndRequestForm::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_game_turn_request_form(data: &JsonValue) -> Result<TurnEndRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(TurnEndRequestForm::new(session_info.to_string()))
 }
\ No newline at end of file
@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_support::controller::request_form::draw_support_request_form::DrawSupportRequestForm;
 
-pub fn create_general_draw_support_request_form(data: &JsonValue) -> Option<DrawSupportRequestForm> {
-    if let (Some(session_id), Some(support_card_id)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("supportCardId").and_then(|v| v.as_str())
-    ) {
-        Some(DrawSupportRequestForm::new(session_id, support_card_id))
-    } else {
-        None
-    }
+pub fn create_general_draw_support_request_form(data: &JsonValue) -> Result<DrawSupportRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let support_card_id = read_i32_string_field(data, "supportCardId")?;
+
+    Ok(DrawSupportRequestForm::new(session_id, support_card_id))
 }
\ No newline at end of file
@@ -0,0 +1,26 @@
+use serde_json::Value as JsonValue;
+use crate::protocol_handshake::entity::protocol_version::ProtocolVersion;
+use crate::protocol_handshake::service::request::hello_request::HelloRequest;
+use crate::request_generator::request_field_reader::{read_array_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
+
+// HELLO 는 어느 도메인에도 속하지 않고 연결 단위로 처리되므로 client_address 를 함께 받음
+pub fn create_hello_request(client_address: &str, data: &JsonValue) -> Result<HelloRequest, RequestParseError> {
+    let protocol_version_string = read_str_field(data, "protocolVersion")?;
+    let client_protocol_version = ProtocolVersion::parse(protocol_version_string).ok_or_else(|| RequestParseError::InvalidFieldValue {
+        field_name: "protocolVersion".to_string(),
+        value: protocol_version_string.to_string(),
+    })?;
+
+    let client_build = read_str_field(data, "clientVersion")?;
+
+    let feature_list = read_array_field(data, "featureList")?
+        .iter()
+        .map(|feature| feature.as_str().map(str::to_string).ok_or_else(|| RequestParseError::WrongFieldType {
+            field_name: "featureList".to_string(),
+            expected_type: "array of string".to_string(),
+        }))
+        .collect::<Result<Vec<String>, RequestParseError>>()?;
+
+    Ok(HelloRequest::new(client_address.to_string(), client_protocol_version, client_build.to_string(), feature_list))
+}
@@ -10,6 +10,7 @@ pub mod shop_request_generator;
 pub mod what_is_the_room_number_request_generator;
 pub mod check_battle_prepare_request_generator;
 pub mod account_point_request_generator;
+pub mod account_rating_request_generator;
 pub mod game_deck_card_list_request_generator;
 pub mod account_card_request_generator;
 pub mod deploy_unit_request_form_generator;
@@ -31,12 +32,12 @@ pub mod attach_field_energy_to_field_unit_request_form_generator;
 pub mod rockpaperscissors_request_generator;
 pub mod check_rockpaperscissors_winner_request_generator;
 pub mod surrender_request_generator;
-mod remain_deck_card_count_request_generator;
+pub mod remain_deck_card_count_request_generator;
 
 pub mod battle_match_cancel_request_generator;
 pub mod attack_game_main_character_request_form_generator;
 pub mod fake_battle_room_create_request_form_generator;
-mod fake_create_game_deck_card_list_request_generator;
+pub mod fake_create_game_deck_card_list_request_generator;
 mod test_mulligan_reqeust_generator;
 pub mod battle_finish_generator;
 pub mod deploy_targeting_attack_passive_skill_request_generator;
@@ -46,8 +47,15 @@ pub mod turn_start_non_targeting_attack_passive_skill_request_generator;
 pub mod turn_start_targeting_attack_to_game_main_character_request_generator;
 pub mod deploy_targeting_attack_to_game_main_character_request_generator;
 pub mod battle_start_request_form_generator;
-mod fake_multi_draw_request_form_generator;
+pub mod resume_battle_request_form_generator;
+pub mod fake_multi_draw_request_form_generator;
 pub mod targeting_attack_active_skill_to_game_main_character_request_generator;
 pub mod fake_get_nether_from_deck_request_form_generator;
-mod fake_get_all_specific_kind_cards_from_deck_request_form_generator;
-pub mod check_connecting_request_generator;
\ No newline at end of file
+pub mod fake_get_all_specific_kind_cards_from_deck_request_form_generator;
+pub mod check_connecting_request_generator;
+pub mod request_parse_error;
+pub mod request_field_reader;
+pub mod hello_request_generator;
+pub mod set_trap_card_request_form_generator;
+pub mod deploy_environment_card_request_form_generator;
+pub mod rate_limit_statistics_request_generator;
@@ -1,30 +1,26 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_array_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::mulligan::controller::request_form::check_opponent_mulligan_status_request_form::CheckOpponentMulliganStatusRequestForm;
 use crate::mulligan::controller::request_form::mulligan_request_form::MulliganRequestForm;
 
-pub fn create_mulligan_request_form(data: &JsonValue) -> Option<MulliganRequestForm> {
-    if let (Some(session_id), Some(hand_card_list)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("cardList").and_then(|v| v.as_array())
-    ) {
-        let mut hand_card_list_vector: Vec<String> = Vec::new();
-        for card_id_value in hand_card_list {
-            if let Some(card_id) = card_id_value.as_str() {
-                let card_id_string = card_id.to_string();
-                hand_card_list_vector.push(card_id_string);
-            }
-        }
+pub fn create_mulligan_request_form(data: &JsonValue) -> Result<MulliganRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let hand_card_list = read_array_field(data, "cardList")?;
 
-        Some(MulliganRequestForm::new(session_id.to_string(), hand_card_list_vector))
-    } else {
-        None
+    let mut hand_card_list_vector: Vec<String> = Vec::new();
+    for card_id_value in hand_card_list {
+        if let Some(card_id) = card_id_value.as_str() {
+            let card_id_string = card_id.to_string();
+            hand_card_list_vector.push(card_id_string);
+        }
     }
+
+    Ok(MulliganRequestForm::new(session_id.to_string(), hand_card_list_vector))
 }
 
-pub fn create_check_opponent_mulligan_status_request_form(data: &JsonValue) -> Option<CheckOpponentMulliganStatusRequestForm> {
-    if let Some(session_id) = data.get("sessionInfo").and_then(|v| v.as_str()) {
-        Some(CheckOpponentMulliganStatusRequestForm::new(session_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_check_opponent_mulligan_status_request_form(data: &JsonValue) -> Result<CheckOpponentMulliganStatusRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(CheckOpponentMulliganStatusRequestForm::new(session_id.to_string()))
 }
\ No newline at end of file
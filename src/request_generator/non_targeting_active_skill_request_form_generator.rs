@@ -1,17 +1,15 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_active_skill::controller::request_form::non_targeting_active_skill_request_form::NonTargetingActiveSkillRequestForm;
 
-pub fn create_non_targeting_active_skill_request_form(data: &JsonValue) -> Option<NonTargetingActiveSkillRequestForm> {
-    if let (Some(sessionInfo), Some(unit_card_index), Some(usage_skill_index)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("unitCardIndex").and_then(|v| v.as_str()),
-        data.get("usageSkillIndex").and_then(|v| v.as_str()),
-    ) {
-        Some(NonTargetingActiveSkillRequestForm::new(
-            sessionInfo.to_string(),
-            unit_card_index.to_string(),
-            usage_skill_index.to_string()))
-    } else {
-        None
-    }
+pub fn create_non_targeting_active_skill_request_form(data: &JsonValue) -> Result<NonTargetingActiveSkillRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let unit_card_index = read_i32_string_field(data, "unitCardIndex")?;
+    let usage_skill_index = read_i32_string_field(data, "usageSkillIndex")?;
+
+    Ok(NonTargetingActiveSkillRequestForm::new(
+        session_info.to_string(),
+        unit_card_index,
+        usage_skill_index))
 }
\ No newline at end of file
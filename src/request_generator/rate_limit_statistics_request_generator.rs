@@ -0,0 +1,10 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::rate_limit::service::request::get_rate_limit_statistics_for_admin_request::GetRateLimitStatisticsForAdminRequest;
+
+pub fn create_rate_limit_statistics_request(data: &JsonValue) -> Result<GetRateLimitStatisticsForAdminRequest, RequestParseError> {
+    let admin_token = read_str_field(data, "adminToken")?;
+
+    Ok(GetRateLimitStatisticsForAdminRequest::new(admin_token.to_string()))
+}
@@ -1,18 +1,15 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_field_info::service::request::remain_deck_card_count_request::RemainDeckCardCountRequest;
 
 
-pub fn create_remain_deck_card_count_request(data: &JsonValue) -> Option<RemainDeckCardCountRequest> {
-    if let (Some(sessionInfo), Some(who)  ) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("who").and_then(|v| v.as_str())
+pub fn create_remain_deck_card_count_request(data: &JsonValue) -> Result<RemainDeckCardCountRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let who = read_str_field(data, "who")?;
 
-    ) {
-        Some(RemainDeckCardCountRequest::new(
-            sessionInfo.to_string(),
-            who.to_string(),
-            ))
-    } else {
-        None
-    }
+    Ok(RemainDeckCardCountRequest::new(
+        session_info.to_string(),
+        who.to_string(),
+        ))
 }
@@ -0,0 +1,86 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_parse_error::RequestParseError;
+
+fn read_field<'a, T>(data: &'a JsonValue,
+                     field_name: &str,
+                     expected_type: &str,
+                     convert: fn(&'a JsonValue) -> Option<T>) -> Result<T, RequestParseError> {
+    let value = data.get(field_name).ok_or_else(|| RequestParseError::MissingField {
+        field_name: field_name.to_string(),
+    })?;
+
+    convert(value).ok_or_else(|| RequestParseError::WrongFieldType {
+        field_name: field_name.to_string(),
+        expected_type: expected_type.to_string(),
+    })
+}
+
+pub fn read_str_field<'a>(data: &'a JsonValue, field_name: &str) -> Result<&'a str, RequestParseError> {
+    read_field(data, field_name, "string", |v| v.as_str())
+}
+
+pub fn read_i32_string_field(data: &JsonValue, field_name: &str) -> Result<i32, RequestParseError> {
+    let value = read_str_field(data, field_name)?;
+
+    value.trim().parse::<i32>().map_err(|_| RequestParseError::InvalidFieldValue {
+        field_name: field_name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+pub fn read_i64_field(data: &JsonValue, field_name: &str) -> Result<i64, RequestParseError> {
+    read_field(data, field_name, "integer", |v| v.as_i64())
+}
+
+pub fn read_bool_field(data: &JsonValue, field_name: &str) -> Result<bool, RequestParseError> {
+    read_field(data, field_name, "boolean", |v| v.as_bool())
+}
+
+pub fn read_array_field<'a>(data: &'a JsonValue, field_name: &str) -> Result<&'a Vec<JsonValue>, RequestParseError> {
+    read_field(data, field_name, "array", |v| v.as_array())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_read_field() {
+        let data = json!({ "sessionInfo": "token", "deckId": 3, "cardIdList": [1, 2] });
+
+        assert_eq!(read_str_field(&data, "sessionInfo"), Ok("token"));
+        assert_eq!(read_i64_field(&data, "deckId"), Ok(3));
+        assert_eq!(read_array_field(&data, "cardIdList").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_read_missing_and_wrong_type_field() {
+        let data = json!({ "deckId": "3" });
+
+        assert_eq!(read_str_field(&data, "sessionInfo"),
+                   Err(RequestParseError::MissingField { field_name: "sessionInfo".to_string() }));
+        assert_eq!(read_i64_field(&data, "deckId"),
+                   Err(RequestParseError::WrongFieldType {
+                       field_name: "deckId".to_string(),
+                       expected_type: "integer".to_string(),
+                   }));
+    }
+
+    #[test]
+    fn test_read_i32_string_field() {
+        let data = json!({ "unitIndex": " 3 ", "itemCardId": "abc", "deckId": 3 });
+
+        assert_eq!(read_i32_string_field(&data, "unitIndex"), Ok(3));
+        assert_eq!(read_i32_string_field(&data, "itemCardId"),
+                   Err(RequestParseError::InvalidFieldValue {
+                       field_name: "itemCardId".to_string(),
+                       value: "abc".to_string(),
+                   }));
+        assert_eq!(read_i32_string_field(&data, "deckId"),
+                   Err(RequestParseError::WrongFieldType {
+                       field_name: "deckId".to_string(),
+                       expected_type: "string".to_string(),
+                   }));
+    }
+}
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestParseError {
+    MissingField { field_name: String },
+    WrongFieldType { field_name: String, expected_type: String },
+    InvalidFieldValue { field_name: String, value: String },
+}
+
+impl RequestParseError {
+    pub fn get_message(&self) -> String {
+        match self {
+            RequestParseError::MissingField { field_name } =>
+                format!("missing field: {}", field_name),
+            RequestParseError::WrongFieldType { field_name, expected_type } =>
+                format!("field {} must be {}", field_name, expected_type),
+            RequestParseError::InvalidFieldValue { field_name, value } =>
+                format!("field {} has invalid value: {}", field_name, value),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::battle_resume::controller::request_form::resume_battle_request_form::ResumeBattleRequestForm;
+
+pub fn create_resume_battle_request_form(data: &JsonValue) -> Result<ResumeBattleRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(ResumeBattleRequestForm::new(session_info.to_string()))
+}
@@ -1,16 +1,13 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::rock_paper_scissors::controller::request_form::rock_paper_scissors_request_form::RockPaperScissorsRequestForm;
 
-pub fn create_rockpaperscissors_request_form(data: &JsonValue) -> Option<RockPaperScissorsRequestForm> {
-    if let (Some(sessionInfo),  Some(choice)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("choice").and_then(|v| v.as_str()),
+pub fn create_rockpaperscissors_request_form(data: &JsonValue) -> Result<RockPaperScissorsRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let choice = read_str_field(data, "choice")?;
 
-    ) {
-        Some(RockPaperScissorsRequestForm::new(
-            sessionInfo.to_string(),
-            choice.to_string()))
-    } else {
-        None
-    }
+    Ok(RockPaperScissorsRequestForm::new(
+        session_info.to_string(),
+        choice.to_string()))
 }
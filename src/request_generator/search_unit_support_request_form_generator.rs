@@ -1,33 +1,27 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_array_field, read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_support::controller::request_form::check_search_unit_support_available_request_form::CheckSearchUnitSupportAvailableRequestForm;
 use crate::game_card_support::controller::request_form::search_unit_support_request_form::SearchUnitSupportRequestForm;
 
-pub fn create_search_unit_support_request_form(data: &JsonValue) -> Option<SearchUnitSupportRequestForm> {
-    if let (Some(session_id), Some(support_card_id), Some(target_unit_index_list)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("supportCardId").and_then(|v| v.as_str()),
-        data.get("targetUnitCardIndexList").and_then(|v| v.as_array())
-    ) {
-        let mut target_unit_card_list: Vec<String> = Vec::new();
-        for unit_card_index_value in target_unit_index_list {
-            if let Some(unit_card_id_str) = unit_card_index_value.as_str() {
-                let unit_card_id_string = unit_card_id_str.to_string();
-                target_unit_card_list.push(unit_card_id_string);
-            }
+pub fn create_search_unit_support_request_form(data: &JsonValue) -> Result<SearchUnitSupportRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let support_card_id = read_i32_string_field(data, "supportCardId")?;
+    let target_unit_index_list = read_array_field(data, "targetUnitCardIndexList")?;
+
+    let mut target_unit_card_list: Vec<String> = Vec::new();
+    for unit_card_index_value in target_unit_index_list {
+        if let Some(unit_card_id_str) = unit_card_index_value.as_str() {
+            let unit_card_id_string = unit_card_id_str.to_string();
+            target_unit_card_list.push(unit_card_id_string);
         }
-        Some(SearchUnitSupportRequestForm::new(session_id, support_card_id, target_unit_card_list))
-    } else {
-        None
     }
+    Ok(SearchUnitSupportRequestForm::new(session_id, support_card_id, target_unit_card_list))
 }
 
-pub fn create_check_search_unit_support_available_request_form(data: &JsonValue) -> Option<CheckSearchUnitSupportAvailableRequestForm> {
-    if let (Some(session_id), Some(support_card_id)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("supportCardId").and_then(|v| v.as_str()),
-    ) {
-        Some(CheckSearchUnitSupportAvailableRequestForm::new(session_id, support_card_id))
-    } else {
-        None
-    }
+pub fn create_check_search_unit_support_available_request_form(data: &JsonValue) -> Result<CheckSearchUnitSupportAvailableRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let support_card_id = read_i32_string_field(data, "supportCardId")?;
+
+    Ok(CheckSearchUnitSupportAvailableRequestForm::new(session_id, support_card_id))
 }
\ No newline at end of file
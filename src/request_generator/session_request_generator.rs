@@ -1,23 +1,17 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::account::service::request::account_session_login_request::AccountSessionLoginRequest;
 use crate::account::service::request::account_session_logout_request::AccountSessionLogoutRequest;
 
-pub fn create_session_login_request(data: &JsonValue) -> Option<AccountSessionLoginRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountSessionLoginRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_session_login_request(data: &JsonValue) -> Result<AccountSessionLoginRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountSessionLoginRequest::new(session_info.to_string()))
 }
 
-pub fn create_session_logout_request(data: &JsonValue) -> Option<AccountSessionLogoutRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(AccountSessionLogoutRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_session_logout_request(data: &JsonValue) -> Result<AccountSessionLogoutRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(AccountSessionLogoutRequest::new(session_info.to_string()))
 }
@@ -0,0 +1,12 @@
+use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
+use crate::game_card_trap::controller::request_form::set_trap_card_request_form::SetTrapCardRequestForm;
+
+
+pub fn create_set_trap_card_request_form(data: &JsonValue) -> Result<SetTrapCardRequestForm, RequestParseError> {
+    let session_id = read_str_field(data, "sessionInfo")?;
+    let trap_card_id = read_i32_string_field(data, "trapCardId")?;
+
+    Ok(SetTrapCardRequestForm::new(session_id, trap_card_id))
+}
\ No newline at end of file
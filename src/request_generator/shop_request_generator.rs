@@ -1,47 +1,33 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_bool_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::shop::controller::request_form::event_distribute_cards_request_form::EventDistributeCardsRequestForm;
 use crate::shop::controller::request_form::execute_free_gacha_request_form::ExecuteFreeGachaRequestForm;
 use crate::shop::controller::request_form::execute_shop_gacha_request_form::ExecuteShopGachaRequestForm;
 use crate::shop::service::request::data_to_display_in_shop_request::DataToDisplayInShopRequest;
 
 
-pub fn create_data_to_display_in_shop_request(data: &JsonValue) -> Option<DataToDisplayInShopRequest> {
-    if let (Some(sessionInfo),) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(DataToDisplayInShopRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_data_to_display_in_shop_request(data: &JsonValue) -> Result<DataToDisplayInShopRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(DataToDisplayInShopRequest::new(session_info.to_string()))
 }
-pub fn create_execute_shop_gacha_request_form(data: &JsonValue) -> Option<ExecuteShopGachaRequestForm> {
-    if let (Some(account_session_id), Some(race_name), Some(is_confirmed_upper_legend)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("race_name").and_then(|v| v.as_str()),
-        data.get("is_confirmed_upper_legend").and_then(|v| v.as_bool()))
-    {
-        Some(ExecuteShopGachaRequestForm::new(account_session_id.to_string(), race_name.to_string(), is_confirmed_upper_legend))
-    } else {
-        None
-    }
+pub fn create_execute_shop_gacha_request_form(data: &JsonValue) -> Result<ExecuteShopGachaRequestForm, RequestParseError> {
+    let account_session_id = read_str_field(data, "sessionInfo")?;
+    let race_name = read_str_field(data, "race_name")?;
+    let is_confirmed_upper_legend = read_bool_field(data, "is_confirmed_upper_legend")?;
+
+    Ok(ExecuteShopGachaRequestForm::new(account_session_id.to_string(), race_name.to_string(), is_confirmed_upper_legend))
 }
-pub fn create_execute_free_gacha_request_form(data: &JsonValue) -> Option<ExecuteFreeGachaRequestForm> {
-    if let (Some(account_session_id), Some(race_name), Some(is_confirmed_upper_legend)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("race_name").and_then(|v| v.as_str()),
-        data.get("is_confirmed_upper_legend").and_then(|v| v.as_bool()))
-    {
-        Some(ExecuteFreeGachaRequestForm::new(account_session_id.to_string(), race_name.to_string(), is_confirmed_upper_legend))
-    } else {
-        None
-    }
+pub fn create_execute_free_gacha_request_form(data: &JsonValue) -> Result<ExecuteFreeGachaRequestForm, RequestParseError> {
+    let account_session_id = read_str_field(data, "sessionInfo")?;
+    let race_name = read_str_field(data, "race_name")?;
+    let is_confirmed_upper_legend = read_bool_field(data, "is_confirmed_upper_legend")?;
+
+    Ok(ExecuteFreeGachaRequestForm::new(account_session_id.to_string(), race_name.to_string(), is_confirmed_upper_legend))
 }
-pub fn create_event_distribute_cards_request_form(data: &JsonValue) -> Option<EventDistributeCardsRequestForm> {
-    if let (Some(account_session_id)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()))
-    {
-        Some(EventDistributeCardsRequestForm::new(account_session_id.to_string()))
-    } else {
-        None
-    }
+pub fn create_event_distribute_cards_request_form(data: &JsonValue) -> Result<EventDistributeCardsRequestForm, RequestParseError> {
+    let account_session_id = read_str_field(data, "sessionInfo")?;
+
+    Ok(EventDistributeCardsRequestForm::new(account_session_id.to_string()))
 }
\ No newline at end of file
@@ -1,12 +1,10 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_winner_check::service::request::surrender_request::SurrenderRequest;
 
-pub fn create_surrender_request(data: &JsonValue) -> Option<SurrenderRequest> {
-    if let (Some(sessionInfo),) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(SurrenderRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_surrender_request(data: &JsonValue) -> Result<SurrenderRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(SurrenderRequest::new(session_info.to_string()))
 }
\ No newline at end of file
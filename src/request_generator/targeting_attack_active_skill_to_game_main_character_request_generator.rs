@@ -1,19 +1,17 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::{read_i32_string_field, read_str_field};
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::game_card_active_skill::controller::request_form::targeting_attack_active_skill_to_game_main_character_request_form::TargetingAttackActiveSkillToGameMainCharacterRequestForm;
 
-pub fn create_targeting_attack_active_skill_to_game_main_character_request_form(data: &JsonValue) -> Option<TargetingAttackActiveSkillToGameMainCharacterRequestForm> {
-    if let (Some(sessionInfo), Some(unit_card_index), Some(target_game_main_character_index), Some(usage_skill_index)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-        data.get("unitCardIndex").and_then(|v| v.as_str()),
-        data.get("targetGameMainCharacterIndex").and_then(|v| v.as_str()),
-        data.get("usageSkillIndex").and_then(|v| v.as_str()),
-    ) {
-        Some(TargetingAttackActiveSkillToGameMainCharacterRequestForm::new(
-            sessionInfo.to_string(),
-            unit_card_index.to_string(),
-            target_game_main_character_index.to_string(),
-            usage_skill_index.to_string()))
-    } else {
-        None
-    }
+pub fn create_targeting_attack_active_skill_to_game_main_character_request_form(data: &JsonValue) -> Result<TargetingAttackActiveSkillToGameMainCharacterRequestForm, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+    let unit_card_index = read_i32_string_field(data, "unitCardIndex")?;
+    let target_game_main_character_index = read_str_field(data, "targetGameMainCharacterIndex")?;
+    let usage_skill_index = read_i32_string_field(data, "usageSkillIndex")?;
+
+    Ok(TargetingAttackActiveSkillToGameMainCharacterRequestForm::new(
+        session_info.to_string(),
+        unit_card_index,
+        target_game_main_character_index.to_string(),
+        usage_skill_index))
 }
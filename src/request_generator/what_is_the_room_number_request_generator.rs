@@ -1,13 +1,11 @@
 use serde_json::Value as JsonValue;
+use crate::request_generator::request_field_reader::read_str_field;
+use crate::request_generator::request_parse_error::RequestParseError;
 use crate::battle_room::service::request::what_is_the_room_number_request::WhatIsTheRoomNumberRequest;
 
-pub fn create_what_is_the_room_number_request(data: &JsonValue) -> Option<WhatIsTheRoomNumberRequest> {
-    if let ((Some(sessionInfo),)) = (
-        data.get("sessionInfo").and_then(|v| v.as_str()),
-    ) {
-        Some(WhatIsTheRoomNumberRequest::new(sessionInfo.to_string()))
-    } else {
-        None
-    }
+pub fn create_what_is_the_room_number_request(data: &JsonValue) -> Result<WhatIsTheRoomNumberRequest, RequestParseError> {
+    let session_info = read_str_field(data, "sessionInfo")?;
+
+    Ok(WhatIsTheRoomNumberRequest::new(session_info.to_string()))
 }
 
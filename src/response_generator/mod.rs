@@ -1 +1,3 @@
-pub mod response_type;
\ No newline at end of file
+pub mod response_type;
+pub mod response_envelope;
+pub mod versioned_response_form;
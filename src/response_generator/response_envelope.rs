@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+use crate::protocol_handshake::entity::protocol_version::ENVELOPE_PROTOCOL_VERSION;
+use crate::response_generator::response_type::ResponseType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageKind {
+    RESPONSE,
+    NOTIFICATION,
+}
+
+// 요청에 대한 직접 응답은 requestId 를 그대로 돌려주고, 서버가 먼저 보내는 알림은 NOTIFICATION 으로 표시
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseEnvelope {
+    #[serde(rename = "messageKind")]
+    message_kind: MessageKind,
+    #[serde(rename = "requestId", skip_serializing_if = "Option::is_none", default)]
+    request_id: Option<JsonValue>,
+    #[serde(flatten)]
+    response: ResponseType,
+}
+
+impl ResponseEnvelope {
+    pub fn response(request_id: Option<JsonValue>, response: ResponseType) -> Self {
+        ResponseEnvelope {
+            message_kind: MessageKind::RESPONSE,
+            request_id,
+            response,
+        }
+    }
+
+    pub fn notification(response: ResponseType) -> Self {
+        ResponseEnvelope {
+            message_kind: MessageKind::NOTIFICATION,
+            request_id: None,
+            response,
+        }
+    }
+
+    pub fn get_message_kind(&self) -> MessageKind {
+        self.message_kind
+    }
+
+    pub fn get_request_id(&self) -> &Option<JsonValue> {
+        &self.request_id
+    }
+
+    pub fn get_response(&self) -> &ResponseType {
+        &self.response
+    }
+
+    // handshake 에서 합의한 버전과 기능에 맞춰 직렬화하며, 1.1 미만이면 envelope 필드를 붙이지 않음
+    pub fn to_versioned_json(&self, negotiated_protocol: &NegotiatedProtocol) -> JsonValue {
+        let mut serialized = self.response.to_versioned_json(negotiated_protocol);
+
+        if negotiated_protocol.get_protocol_version() >= ENVELOPE_PROTOCOL_VERSION {
+            if let Some(serialized_object) = serialized.as_object_mut() {
+                serialized_object.insert("messageKind".to_string(),
+                                         serde_json::to_value(self.message_kind).expect("Failed to serialize to JSON"));
+                if let Some(request_id) = &self.request_id {
+                    serialized_object.insert("requestId".to_string(), request_id.clone());
+                }
+            }
+        }
+
+        serialized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+    use crate::client_program::service::response::client_program_exit_response::ClientProgramExitResponse;
+    use crate::game_card_unit::controller::response_form::deploy_unit_response_form::DeployUnitResponseForm;
+    use crate::protocol_handshake::entity::negotiated_protocol::TRAP_CARD_FEATURE;
+    use crate::protocol_handshake::entity::protocol_version::ProtocolVersion;
+
+    #[test]
+    fn test_serialize_response_with_request_id() {
+        let envelope = ResponseEnvelope::response(
+            Some(json!("req-7")),
+            ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(true)));
+
+        let serialized = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(serialized["messageKind"], json!("RESPONSE"));
+        assert_eq!(serialized["requestId"], json!("req-7"));
+        assert!(serialized.get("PROGRAM_EXIT").is_some());
+    }
+
+    #[test]
+    fn test_serialize_notification_without_request_id() {
+        let envelope = ResponseEnvelope::notification(
+            ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(true)));
+
+        let serialized = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(serialized["messageKind"], json!("NOTIFICATION"));
+        assert!(serialized.get("requestId").is_none());
+    }
+
+    #[test]
+    fn test_versioned_json_drops_envelope_for_legacy_client() {
+        let envelope = ResponseEnvelope::response(
+            Some(json!("req-7")),
+            ResponseType::PROGRAM_EXIT(ClientProgramExitResponse::new(true)));
+
+        let legacy_serialized = envelope.to_versioned_json(&NegotiatedProtocol::new(ProtocolVersion::new(1, 0), &[]));
+        assert!(legacy_serialized.get("messageKind").is_none());
+        assert!(legacy_serialized.get("requestId").is_none());
+        assert!(legacy_serialized.get("PROGRAM_EXIT").is_some());
+
+        let current_serialized = envelope.to_versioned_json(&NegotiatedProtocol::current());
+        assert_eq!(current_serialized, serde_json::to_value(&envelope).unwrap());
+    }
+
+    #[test]
+    fn test_versioned_json_drops_trap_card_result_for_client_without_trap_card_feature() {
+        let envelope = ResponseEnvelope::response(
+            Some(json!("req-8")),
+            ResponseType::DEPLOY_UNIT_USAGE(DeployUnitResponseForm::new(true, -1, Vec::new())));
+
+        let without_trap_card_serialized =
+            envelope.to_versioned_json(&NegotiatedProtocol::new(ENVELOPE_PROTOCOL_VERSION, &["requestId".to_string()]));
+        assert_eq!(without_trap_card_serialized["requestId"], json!("req-8"));
+        assert_eq!(without_trap_card_serialized["DEPLOY_UNIT_USAGE"]["is_success"], json!(true));
+        assert!(without_trap_card_serialized["DEPLOY_UNIT_USAGE"].get("trigger_trap_card_response_form").is_none());
+
+        let with_trap_card_serialized =
+            envelope.to_versioned_json(&NegotiatedProtocol::new(ENVELOPE_PROTOCOL_VERSION, &[TRAP_CARD_FEATURE.to_string()]));
+        assert!(with_trap_card_serialized["DEPLOY_UNIT_USAGE"].get("trigger_trap_card_response_form").is_some());
+    }
+}
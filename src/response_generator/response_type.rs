@@ -1,5 +1,6 @@
 use crate::account::service::response::account_register_response::AccountRegisterResponse;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
 use crate::account::service::response::account_login_response::AccountLoginResponse;
 use crate::account::service::response::account_logout_response::AccountLogoutResponse;
 use crate::account::service::response::account_modify_response::AccountModifyResponse;
@@ -14,12 +15,15 @@ use crate::account_deck_card::controller::response_form::account_deck_card_modif
 use crate::account_deck_card::controller::response_form::account_deck_configuration_response_form::AccountDeckConfigurationResponseForm;
 use crate::account_point::service::response::gain_gold_response::GainGoldResponse;
 use crate::account_point::service::response::pay_gold_response::PayGoldResponse;
+use crate::account_rating::service::response::account_rating_response::AccountRatingResponse;
+use crate::account_rating::service::response::match_history_list_response::MatchHistoryListResponse;
 use crate::battle_field_info::service::response::remain_deck_card_count_response::RemainDeckCardCountResponse;
 use crate::battle_finish::service::response::battle_finish_response::BattleFinishResponse;
 use crate::battle_ready_account_hash::service::response::battle_ready_account_hash_response::BattleReadyAccountHashResponse;
 use crate::battle_ready_account_hash::service::response::check_battle_prepare_response::CheckBattlePrepareResponse;
 use crate::battle_room::service::response::what_is_the_room_number_response::WhatIsTheRoomNumberResponse;
 use crate::battle_start::controller::response_form::battle_start_response_form::BattleStartResponseForm;
+use crate::battle_resume::controller::response_form::resume_battle_response_form::ResumeBattleResponseForm;
 use crate::battle_wait_queue::service::response::battle_match_cancel_response::BattleMatchCancelResponse;
 use crate::battle_wait_queue::service::response::battle_wait_queue_response::BattleWaitQueueResponse;
 use crate::check_connecting::entity::check_connecting_message_form::CheckConnectingMessageForm;
@@ -51,6 +55,8 @@ use crate::game_card_support::controller::response_form::check_search_unit_suppo
 use crate::game_card_support::controller::response_form::draw_support_response_form::DrawSupportResponseForm;
 use crate::game_card_support::controller::response_form::energy_boost_support_response_form::EnergyBoostSupportResponseForm;
 use crate::game_card_support::controller::response_form::search_unit_support_response_form::SearchUnitSupportResponseForm;
+use crate::game_card_trap::controller::response_form::set_trap_card_response_form::SetTrapCardResponseForm;
+use crate::game_card_environment::controller::response_form::deploy_environment_card_response_form::DeployEnvironmentCardResponseForm;
 use crate::game_card_unit::controller::response_form::attack_game_main_character_response_form::AttackGameMainCharacterResponseForm;
 use crate::game_card_unit::controller::response_form::deploy_unit_response_form::DeployUnitResponseForm;
 use crate::game_card_unit::controller::response_form::attack_unit_response_form::AttackUnitResponseForm;
@@ -83,6 +89,14 @@ use crate::notify_player_action_info::entity::notify_form_deploy_targeting_attac
 use crate::notify_player_action_info::entity::notify_form_deploy_unit::NotifyFormDeployUnit;
 use crate::notify_player_action_info::entity::notify_form_mulligan_end::NotifyFormMulliganEnd;
 use crate::notify_player_action_info::entity::notify_form_surrender::NotifyFormSurrender;
+use crate::notify_player_action_info::entity::notify_form_opponent_abandoned::NotifyFormOpponentAbandoned;
+use crate::notify_player_action_info::entity::notify_form_set_trap_card::NotifyFormSetTrapCard;
+use crate::notify_player_action_info::entity::notify_form_trigger_trap_card::NotifyFormTriggerTrapCard;
+use crate::notify_player_action_info::entity::notify_form_deploy_environment_card::NotifyFormDeployEnvironmentCard;
+use crate::notify_player_action_info::entity::notify_form_expire_environment_card::NotifyFormExpireEnvironmentCard;
+use crate::notify_player_action_info::entity::notify_form_summon_token_unit::NotifyFormSummonTokenUnit;
+use crate::notify_player_action_info::entity::notify_form_game_event_reaction::NotifyFormGameEventReaction;
+use crate::server_shutdown::entity::notify_form_server_maintenance::NotifyFormServerMaintenance;
 use crate::notify_player_action_info::entity::notify_form_non_targeting_attack_active_skill::NotifyFormNonTargetingAttackActiveSkill;
 use crate::notify_player_action_info::entity::notify_form_targeting_attack_active_skill_to_game_main_character::NotifyFormTargetingAttackActiveSkillToGameMainCharacter;
 use crate::notify_player_action_info::entity::notify_form_targeting_attack_active_skill_to_unit::NotifyFormTargetingAttackActiveSkillToUnit;
@@ -102,9 +116,14 @@ use crate::notify_player_action_info::entity::notify_form_use_multiple_unit_dama
 use crate::notify_player_action_info::entity::notify_form_use_search_deck_support_card::NotifyFormUseSearchDeckSupportCard;
 use crate::notify_player_action_info::entity::notify_form_use_special_energy_card_to_unit::NotifyFormUseSpecialEnergyCardToUnit;
 use crate::notify_player_action_info::entity::notify_form_use_unit_energy_remove_item_card::NotifyFormUseUnitEnergyRemoveItemCard;
+use crate::protocol_handshake::service::response::hello_response::HelloResponse;
+use crate::protocol_registry::entity::protocol_error_response_form::ProtocolErrorResponseForm;
 use crate::rock_paper_scissors::controller::response_form::check_rock_paper_scissors_winner_response_form::CheckRockPaperScissorsWinnerResponseForm;
 use crate::rock_paper_scissors::controller::response_form::rock_paper_scissors_response_form::RockPaperScissorsResponseForm;
 use crate::shop::controller::response_form::event_distribute_cards_response_form::EventDistributeCardsResponseForm;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+use crate::rate_limit::service::response::get_rate_limit_statistics_for_admin_response::GetRateLimitStatisticsForAdminResponse;
+use crate::response_generator::versioned_response_form::VersionedResponseForm;
 use crate::shop::controller::response_form::execute_free_gacha_response_form::ExecuteFreeGachaResponseForm;
 use crate::shop::controller::response_form::execute_shop_gacha_response_form::ExecuteShopGachaResponseForm;
 use crate::shop::service::response::data_to_display_in_shop_response::DataToDisplayInShopResponse;
@@ -112,6 +131,9 @@ use crate::shop::service::response::data_to_display_in_shop_response::DataToDisp
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseType {
+    // Protocol Handshake
+    HELLO(HelloResponse),
+
     // Account
     ACCOUNT_REGISTER(AccountRegisterResponse),
     ACCOUNT_LOGIN(AccountLoginResponse),
@@ -159,6 +181,10 @@ pub enum ResponseType {
     GAIN_GOLD(GainGoldResponse),
     PAY_GOLD(PayGoldResponse),
 
+    // Account Rating
+    ACCOUNT_RATING(AccountRatingResponse),
+    MATCH_HISTORY_LIST(MatchHistoryListResponse),
+
     // Battle Field
     DEPLOY_UNIT_USAGE(DeployUnitResponseForm),
     ENERGY_BOOST_SUPPORT_USAGE(EnergyBoostSupportResponseForm),
@@ -179,6 +205,8 @@ pub enum ResponseType {
     TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER(TargetingAttackActiveSkillToGameMainCharacterResponseForm),
     ATTACH_FIELD_ENERGY_TO_UNIT(AttachFieldEnergyToFieldUnitResponseForm),
     ATTACK_MAIN_CHARACTER(AttackGameMainCharacterResponseForm),
+    SET_TRAP_CARD(SetTrapCardResponseForm),
+    DEPLOY_ENVIRONMENT_CARD(DeployEnvironmentCardResponseForm),
 
     // Battle Field Passive Controll
     DEPLOY_TARGETING_ATTACK_PASSIVE_SKILL(DeployTargetingAttackPassiveSkillResponseForm),
@@ -218,6 +246,12 @@ pub enum ResponseType {
     NOTIFY_USE_CATASTROPHIC_DAMAGE_ITEM_CARD(NotifyFormUseCatastrophicDamageItemCard),
     NOTIFY_USE_UNIT_ENERGY_REMOVE_ITEM_CARD(NotifyFormUseUnitEnergyRemoveItemCard),
     NOTIFY_USE_MULTIPLE_UNIT_DAMAGE_ITEM_CARD(NotifyFormUseMultipleUnitDamageItemCard),
+    NOTIFY_SET_TRAP_CARD(NotifyFormSetTrapCard),
+    NOTIFY_TRIGGER_TRAP_CARD(NotifyFormTriggerTrapCard),
+    NOTIFY_DEPLOY_ENVIRONMENT_CARD(NotifyFormDeployEnvironmentCard),
+    NOTIFY_EXPIRE_ENVIRONMENT_CARD(NotifyFormExpireEnvironmentCard),
+    NOTIFY_SUMMON_TOKEN_UNIT(NotifyFormSummonTokenUnit),
+    NOTIFY_GAME_EVENT_REACTION(NotifyFormGameEventReaction),
     NOTIFY_BASIC_ATTACK_TO_UNIT(NotifyFormBasicAttackToUnit),
     NOTIFY_BASIC_ATTACK_TO_MAIN_CHARACTER(NotifyFormBasicAttackToMainCharacter),
     NOTIFY_TARGETING_ATTACK_ACTIVE_SKILL_TO_UNIT(NotifyFormTargetingAttackActiveSkillToUnit),
@@ -233,15 +267,21 @@ pub enum ResponseType {
 
     NOTIFY_MULLIGAN_END(NotifyFormMulliganEnd),
     NOTIFY_SURRENDER(NotifyFormSurrender),
+    NOTIFY_OPPONENT_ABANDONED(NotifyFormOpponentAbandoned),
 
     NOTIFY_TURN_END(NotifyFormTurnEnd),
 
+    NOTIFY_SERVER_MAINTENANCE(NotifyFormServerMaintenance),
+
     // Game Next Turn
     GAME_NEXT_TURN(TurnEndResponseForm),
 
     // Battle Start
     BATTLE_START(BattleStartResponseForm),
 
+    // Resume Battle
+    RESUME_BATTLE(ResumeBattleResponseForm),
+
     // Check Connecting
     SEND_MESSAGE_CHECK_CONNECTING(CheckConnectingMessageForm),
     CHECK_CONNECTING(CheckedResponseResponse),
@@ -259,9 +299,62 @@ pub enum ResponseType {
     // Program Exit
     PROGRAM_EXIT(ClientProgramExitResponse),
 
+    // Rate Limit Statistics
+    RATE_LIMIT_STATISTICS(GetRateLimitStatisticsForAdminResponse),
+
+    // Protocol Error
+    PROTOCOL_ERROR(ProtocolErrorResponseForm),
+
     // Fake Battle Room Test
     FAKE_BATTLE_ROOM_CREATION(CreateFakeBattleRoomResponseForm),
     FAKE_MULTI_DRAW(FakeMultiDrawResponseForm),
     FAKE_GET_NETHER(FakeGetNetherFromDeckResponseForm),
     FAKE_GET_SPECIFIC_KIND_DECK_CARD(FakeGetAllCardsOfSpecificKindFromDeckResponseForm),
 }
+
+impl ResponseType {
+    // 턴 검증 등 요청을 거절한 이유가 담긴 게임 내 행동 응답의 false_message_enum
+    pub fn find_false_message_enum(&self) -> Option<i32> {
+        match self {
+            ResponseType::DEPLOY_UNIT_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ATTACK_UNIT(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ATTACK_MAIN_CHARACTER(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::TARGETING_ACTIVE_SKILL(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::NON_TARGETING_ACTIVE_SKILL(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::TARGETING_ATTACK_ACTIVE_SKILL_TO_GAME_MAIN_CHARACTER(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::TARGET_DEATH_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ADD_FIELD_ENERGY_BY_FIELD_UNIT_HEALTH_POINT_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::CATASTROPHIC_DAMAGE_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::MULTIPLE_TARGET_DAMAGE_BY_FIELD_UNIT_SACRIFICE_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::OPPONENT_FIElD_UNIT_ENERGY_REMOVAL_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::REMOVE_OPPONENT_FIELD_ENERGY_ITEM_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::DEPLOY_ENVIRONMENT_CARD(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ATTACH_GENERAL_ENERGY(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ATTACH_SPECIAL_ENERGY(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::SET_TRAP_CARD(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ENERGY_BOOST_SUPPORT_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::GENERAL_DRAW_SUPPORT_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::CHECK_SEARCH_UNIT_SUPPORT_USAGE(response_form) => Some(response_form.get_false_message_enum()),
+            ResponseType::ATTACH_FIELD_ENERGY_TO_UNIT(response_form) => Some(response_form.get_false_message_enum()),
+            _ => None,
+        }
+    }
+
+    // 합의한 기능에 따라 필드가 달라지는 form 은 클라이언트가 이해할 수 있는 형태로만 직렬화
+    pub fn to_versioned_json(&self, negotiated_protocol: &NegotiatedProtocol) -> JsonValue {
+        let (response_name, versioned_response_form) = match self {
+            ResponseType::DEPLOY_UNIT_USAGE(response_form) => ("DEPLOY_UNIT_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::ATTACK_UNIT(response_form) => ("ATTACK_UNIT", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::ATTACK_MAIN_CHARACTER(response_form) => ("ATTACK_MAIN_CHARACTER", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::TARGET_DEATH_ITEM_USAGE(response_form) => ("TARGET_DEATH_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::ADD_FIELD_ENERGY_BY_FIELD_UNIT_HEALTH_POINT_ITEM_USAGE(response_form) => ("ADD_FIELD_ENERGY_BY_FIELD_UNIT_HEALTH_POINT_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::CATASTROPHIC_DAMAGE_ITEM_USAGE(response_form) => ("CATASTROPHIC_DAMAGE_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::MULTIPLE_TARGET_DAMAGE_BY_FIELD_UNIT_SACRIFICE_ITEM_USAGE(response_form) => ("MULTIPLE_TARGET_DAMAGE_BY_FIELD_UNIT_SACRIFICE_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::OPPONENT_FIElD_UNIT_ENERGY_REMOVAL_ITEM_USAGE(response_form) => ("OPPONENT_FIElD_UNIT_ENERGY_REMOVAL_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            ResponseType::REMOVE_OPPONENT_FIELD_ENERGY_ITEM_USAGE(response_form) => ("REMOVE_OPPONENT_FIELD_ENERGY_ITEM_USAGE", response_form.to_versioned_json(negotiated_protocol)),
+            _ => return serde_json::to_value(self).expect("Failed to serialize to JSON"),
+        };
+
+        json!({ response_name: versioned_response_form })
+    }
+}
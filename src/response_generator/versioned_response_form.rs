@@ -0,0 +1,23 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+
+// handshake 에서 합의한 기능에 따라 필드 구성이 달라지는 응답 form
+pub trait VersionedResponseForm: Serialize {
+    // (기능 이름, 해당 기능을 모르는 클라이언트에게서 제외할 필드 이름)
+    const FEATURE_FIELD_LIST: &'static [(&'static str, &'static str)];
+
+    fn to_versioned_json(&self, negotiated_protocol: &NegotiatedProtocol) -> JsonValue {
+        let mut serialized = serde_json::to_value(self).expect("Failed to serialize to JSON");
+
+        if let Some(serialized_object) = serialized.as_object_mut() {
+            for (feature, field_name) in Self::FEATURE_FIELD_LIST {
+                if !negotiated_protocol.supports_feature(feature) {
+                    serialized_object.remove(*field_name);
+                }
+            }
+        }
+
+        serialized
+    }
+}
@@ -1,4 +1,5 @@
 pub mod controller;
 pub mod entity;
 pub mod repository;
-pub mod service;
\ No newline at end of file
+pub mod service;
+pub mod protocol;
@@ -0,0 +1 @@
+pub mod rock_paper_scissors_protocol;
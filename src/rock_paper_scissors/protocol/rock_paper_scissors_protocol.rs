@@ -0,0 +1,26 @@
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::check_rockpaperscissors_winner_request_generator::create_check_rockpaperscissors_winner_request_form;
+use crate::request_generator::rockpaperscissors_request_generator::create_rockpaperscissors_request_form;
+use crate::response_generator::response_type::ResponseType;
+use crate::rock_paper_scissors::controller::rock_paper_scissors_controller::RockPaperScissorsController;
+use crate::rock_paper_scissors::controller::rock_paper_scissors_controller_impl::RockPaperScissorsControllerImpl;
+
+pub fn register_rock_paper_scissors_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // First Turn wait queue 최신 버전
+    protocol_registry.register_battle_room_action(19, "ROCKPAPERSCISSORS", create_rockpaperscissors_request_form, |request| async move {
+        let rock_paper_scissors_controller_mutex = RockPaperScissorsControllerImpl::get_instance();
+        let rock_paper_scissors_controller_mutex_guard = rock_paper_scissors_controller_mutex.lock().await;
+
+        let response = rock_paper_scissors_controller_mutex_guard.execute_rock_paper_scissors_procedure(request).await;
+        ResponseType::ROCKPAPERSCISSORS(response)
+    });
+
+    // First Turn Decision 최신 버전
+    protocol_registry.register_battle_room_action(20, "CHECK_ROCKPAPERSCISSORS_WINNER", create_check_rockpaperscissors_winner_request_form, |request| async move {
+        let rock_paper_scissors_controller_mutex = RockPaperScissorsControllerImpl::get_instance();
+        let rock_paper_scissors_controller_mutex_guard = rock_paper_scissors_controller_mutex.lock().await;
+
+        let response = rock_paper_scissors_controller_mutex_guard.execute_check_rock_paper_scissors_winner_procedure(request).await;
+        ResponseType::CHECK_ROCKPAPERSCISSORS_WINNER(response)
+    });
+}
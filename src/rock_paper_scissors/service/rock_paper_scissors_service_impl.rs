@@ -11,9 +11,7 @@ use crate::account_card::entity::account_card::account_cards::account_id;
 use crate::battle_ready_account_hash::entity::battle_ready_account_hash_status::BattleReadyAccountHashStatus;
 
 use crate::game_deck::repository::game_deck_repository::GameDeckRepository;
-use crate::game_deck::repository::game_deck_repository_impl::GameDeckRepositoryImpl;
 use crate::game_field_energy::repository::game_field_energy_repository::GameFieldEnergyRepository;
-use crate::game_field_energy::repository::game_field_energy_repository_impl::GameFieldEnergyRepositoryImpl;
 use crate::game_turn::repository::game_turn_repository::GameTurnRepository;
 use crate::game_turn::repository::game_turn_repository_impl::GameTurnRepositoryImpl;
 
@@ -40,8 +38,6 @@ pub struct RockPaperScissorsServiceImpl {
     rock_paper_scissors_repository: Arc<AsyncMutex<RockPaperScissorsRepositoryImpl>>,
     match_waiting_timer_repository: Arc<AsyncMutex<MatchWaitingTimerRepositoryImpl>>,
     game_turn_repository:Arc<AsyncMutex<GameTurnRepositoryImpl>>,
-    game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-    game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
     rock_paper_scissors_waiting_timer_repository: Arc<AsyncMutex<RockPaperScissorsWaitingTimerRepositoryImpl>>
 }
 
@@ -50,8 +46,6 @@ impl RockPaperScissorsServiceImpl {
                rock_paper_scissors_repository: Arc<AsyncMutex<RockPaperScissorsRepositoryImpl>>,
                match_waiting_timer_repository: Arc<AsyncMutex<MatchWaitingTimerRepositoryImpl>>,
                game_turn_repository:Arc<AsyncMutex<GameTurnRepositoryImpl>>,
-               game_field_energy_repository: Arc<AsyncMutex<GameFieldEnergyRepositoryImpl>>,
-               game_deck_repository: Arc<AsyncMutex<GameDeckRepositoryImpl>>,
                rock_paper_scissors_waiting_timer_repository: Arc<AsyncMutex<RockPaperScissorsWaitingTimerRepositoryImpl>>
 
     ) -> Self {
@@ -61,8 +55,6 @@ impl RockPaperScissorsServiceImpl {
             rock_paper_scissors_repository,
             match_waiting_timer_repository,
             game_turn_repository,
-            game_field_energy_repository,
-            game_deck_repository,
             rock_paper_scissors_waiting_timer_repository
         }
     }
@@ -77,8 +69,6 @@ impl RockPaperScissorsServiceImpl {
                             RockPaperScissorsRepositoryImpl::get_instance(),
                             MatchWaitingTimerRepositoryImpl::get_instance(),
                             GameTurnRepositoryImpl::get_instance(),
-                            GameFieldEnergyRepositoryImpl::get_instance(),
-                            GameDeckRepositoryImpl::get_instance(),
                             RockPaperScissorsWaitingTimerRepositoryImpl::get_instance())));
         }
         INSTANCE.clone()
@@ -0,0 +1,4 @@
+pub mod server_config;
+pub mod server_config_error;
+pub mod server_config_key;
+pub mod server_config_source;
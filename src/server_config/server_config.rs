@@ -0,0 +1,594 @@
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use once_cell::sync::OnceCell;
+use redis::IntoConnectionInfo;
+
+use crate::common::env::env_detector::EnvDetector;
+use crate::common::path::root_path::RootPath;
+use crate::rate_limit::entity::rate_limit_class::RateLimitClass;
+use crate::rate_limit::entity::token_bucket_limit::TokenBucketLimit;
+use crate::redis::entity::session_store_type::SessionStoreType;
+use crate::server_config::server_config_error::ServerConfigError;
+use crate::server_config::server_config_key::ServerConfigKey;
+use crate::server_config::server_config_source::ServerConfigSource;
+use crate::storage_config::storage_backend_type::StorageBackendType;
+use crate::storage_config::storage_pool_config::StoragePoolConfig;
+
+const DEFAULT_SERVER_CONFIG_PATH: &str = "resources/server_config/server_config.json";
+const DEFAULT_BIND_HOST: &str = "0.0.0.0";
+const DEFAULT_PORT: u16 = 7373;
+const DEFAULT_STORAGE_POOL_MAX_SIZE: u32 = 10;
+const DEFAULT_STORAGE_POOL_CONNECTION_TIMEOUT_SECONDS: u64 = 5;
+const DEFAULT_REDIS_URL: &str = "redis://127.0.0.1/";
+const DEFAULT_SESSION_EXPIRED_SECONDS: u32 = 3600;
+const DEFAULT_MATCHMAKER_POLL_INTERVAL_MILLISECONDS: u64 = 1000;
+const DEFAULT_TURN_TIME_LIMIT_SECONDS: u64 = 60;
+const DEFAULT_MULLIGAN_TIME_LIMIT_SECONDS: u64 = 30;
+const DEFAULT_HEARTBEAT_INTERVAL_SECONDS: u64 = 15;
+const DEFAULT_CONNECTION_GRACE_PERIOD_SECONDS: u64 = 60;
+const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SECONDS: u64 = 120;
+const DEFAULT_RATE_LIMIT_AUTH_BURST: u32 = 5;
+const DEFAULT_RATE_LIMIT_AUTH_REFILL_PER_MINUTE: u32 = 10;
+const DEFAULT_RATE_LIMIT_LOBBY_BURST: u32 = 10;
+const DEFAULT_RATE_LIMIT_LOBBY_REFILL_PER_MINUTE: u32 = 60;
+const DEFAULT_RATE_LIMIT_SHOP_BURST: u32 = 10;
+const DEFAULT_RATE_LIMIT_SHOP_REFILL_PER_MINUTE: u32 = 60;
+const DEFAULT_RATE_LIMIT_IN_GAME_BURST: u32 = 20;
+const DEFAULT_RATE_LIMIT_IN_GAME_REFILL_PER_MINUTE: u32 = 600;
+const DEFAULT_RATE_LIMIT_DEFAULT_BURST: u32 = 30;
+const DEFAULT_RATE_LIMIT_DEFAULT_REFILL_PER_MINUTE: u32 = 300;
+const DEFAULT_RATE_LIMIT_DISCONNECT_VIOLATION_COUNT: u32 = 20;
+const DEFAULT_RATE_LIMIT_VIOLATION_WINDOW_SECONDS: u64 = 60;
+
+// main 에서 실행 인자까지 반영해 한 번 설정하며, 이후에는 읽기만 함
+static SERVER_CONFIG: OnceCell<Arc<ServerConfig>> = OnceCell::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfig {
+    bind_host: String,
+    port: u16,
+    web_socket_port: Option<u16>,
+    storage_backend: StorageBackendType,
+    database_url: Option<String>,
+    storage_pool_config: StoragePoolConfig,
+    session_store: SessionStoreType,
+    redis_url: String,
+    session_expired_seconds: u32,
+    matchmaker_poll_interval: Duration,
+    turn_time_limit: Duration,
+    mulligan_time_limit: Duration,
+    heartbeat_interval: Duration,
+    connection_grace_period: Duration,
+    shutdown_drain_timeout: Duration,
+    auth_rate_limit: TokenBucketLimit,
+    lobby_rate_limit: TokenBucketLimit,
+    shop_rate_limit: TokenBucketLimit,
+    in_game_rate_limit: TokenBucketLimit,
+    default_rate_limit: TokenBucketLimit,
+    rate_limit_disconnect_violation_count: u32,
+    rate_limit_violation_window: Duration,
+    admin_token: Option<String>,
+}
+
+impl ServerConfig {
+    // 우선 순위: 기본값 < 설정 파일 < 환경 변수 < 실행 인자
+    pub fn initialize_from_process() -> Arc<ServerConfig> {
+        let cli_args: Vec<String> = std::env::args().skip(1).collect();
+
+        let server_config = match Self::load(&cli_args).and_then(|server_config| {
+            let problems = server_config.validate_startup_requirements();
+            if problems.is_empty() { Ok(server_config) } else { Err(ServerConfigError::new(problems)) }
+        }) {
+            Ok(server_config) => Arc::new(server_config),
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        };
+
+        if SERVER_CONFIG.set(server_config.clone()).is_err() {
+            eprintln!("ServerConfig: already initialized, command line arguments are ignored");
+        }
+
+        Self::get_instance()
+    }
+
+    // main 을 거치지 않는 경우(테스트 등)에는 실행 인자 없이 설정 파일과 환경 변수만 반영
+    pub fn get_instance() -> Arc<ServerConfig> {
+        SERVER_CONFIG.get_or_init(|| {
+            match Self::load(&[]) {
+                Ok(server_config) => Arc::new(server_config),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+        }).clone()
+    }
+
+    fn load(cli_args: &[String]) -> Result<ServerConfig, ServerConfigError> {
+        let mut problems = Vec::new();
+        let mut sources = Vec::new();
+
+        let config_path = ServerConfigSource::find_config_path(cli_args)
+            .or_else(EnvDetector::get_server_config_path);
+
+        match config_path {
+            Some(config_path) => {
+                match ServerConfigSource::from_file(&Self::make_config_path(&config_path)) {
+                    Ok(file_source) => sources.push(file_source),
+                    Err(file_problems) => problems.extend(file_problems),
+                }
+            }
+            None => {
+                // 기본 설정 파일은 있을 때만 사용
+                let default_config_path = Self::make_config_path(DEFAULT_SERVER_CONFIG_PATH);
+                if default_config_path.exists() {
+                    match ServerConfigSource::from_file(&default_config_path) {
+                        Ok(file_source) => sources.push(file_source),
+                        Err(file_problems) => problems.extend(file_problems),
+                    }
+                }
+            }
+        }
+
+        sources.push(ServerConfigSource::from_env());
+
+        match ServerConfigSource::from_cli_args(cli_args) {
+            Ok(cli_source) => sources.push(cli_source),
+            Err(cli_problems) => problems.extend(cli_problems),
+        }
+
+        match Self::resolve(&sources) {
+            Ok(server_config) if problems.is_empty() => Ok(server_config),
+            Ok(_) => Err(ServerConfigError::new(problems)),
+            Err(err) => {
+                problems.extend(err.get_problems().clone());
+                Err(ServerConfigError::new(problems))
+            }
+        }
+    }
+
+    fn make_config_path(config_path: &str) -> PathBuf {
+        let config_path_buf = PathBuf::from(config_path);
+        if config_path_buf.is_absolute() {
+            return config_path_buf
+        }
+
+        RootPath::make_full_path(config_path).unwrap_or(config_path_buf)
+    }
+
+    // 뒤에 있는 source 가 앞의 값을 덮어쓰며, 잘못된 값은 중단하지 않고 모두 모아서 반환
+    pub fn resolve(sources: &[ServerConfigSource]) -> Result<ServerConfig, ServerConfigError> {
+        let mut resolver = ServerConfigResolver { sources, problems: Vec::new() };
+
+        let bind_host = resolver.parse(ServerConfigKey::BindHost, DEFAULT_BIND_HOST.to_string(), parse_bind_host);
+        let port = resolver.parse(ServerConfigKey::Port, DEFAULT_PORT, parse_port);
+        let web_socket_port = resolver.parse_optional(ServerConfigKey::WebSocketPort, parse_port);
+        let storage_backend = resolver.parse(ServerConfigKey::StorageBackend, StorageBackendType::Mysql, |value| {
+            StorageBackendType::from_config_value(value).ok_or("must be one of mysql, sqlite".to_string())
+        });
+        let database_url = resolver.parse_optional(ServerConfigKey::DatabaseUrl, parse_mysql_url);
+        let storage_pool_max_size = resolver.parse(
+            ServerConfigKey::StoragePoolMaxSize, DEFAULT_STORAGE_POOL_MAX_SIZE, parse_positive::<u32>);
+        let storage_pool_min_idle = resolver.parse_optional(
+            ServerConfigKey::StoragePoolMinIdle, parse_positive::<u32>);
+        let storage_pool_connection_timeout_seconds = resolver.parse(
+            ServerConfigKey::StoragePoolConnectionTimeoutSeconds, DEFAULT_STORAGE_POOL_CONNECTION_TIMEOUT_SECONDS, parse_positive::<u64>);
+        let storage_pool_idle_timeout_seconds = resolver.parse_optional(
+            ServerConfigKey::StoragePoolIdleTimeoutSeconds, parse_positive::<u64>);
+        let session_store = resolver.parse(ServerConfigKey::SessionStore, SessionStoreType::Redis, |value| {
+            SessionStoreType::from_config_value(value).ok_or("must be one of redis, memory".to_string())
+        });
+        let redis_url = resolver.parse(ServerConfigKey::RedisUrl, DEFAULT_REDIS_URL.to_string(), parse_redis_url);
+        let session_expired_seconds = resolver.parse(
+            ServerConfigKey::SessionExpiredSeconds, DEFAULT_SESSION_EXPIRED_SECONDS, parse_positive::<u32>);
+        let matchmaker_poll_interval_milliseconds = resolver.parse(
+            ServerConfigKey::MatchmakerPollIntervalMilliseconds, DEFAULT_MATCHMAKER_POLL_INTERVAL_MILLISECONDS, parse_positive::<u64>);
+        let turn_time_limit_seconds = resolver.parse(
+            ServerConfigKey::TurnTimeLimitSeconds, DEFAULT_TURN_TIME_LIMIT_SECONDS, parse_positive::<u64>);
+        let mulligan_time_limit_seconds = resolver.parse(
+            ServerConfigKey::MulliganTimeLimitSeconds, DEFAULT_MULLIGAN_TIME_LIMIT_SECONDS, parse_positive::<u64>);
+        let heartbeat_interval_seconds = resolver.parse(
+            ServerConfigKey::HeartbeatIntervalSeconds, DEFAULT_HEARTBEAT_INTERVAL_SECONDS, parse_positive::<u64>);
+        let connection_grace_period_seconds = resolver.parse(
+            ServerConfigKey::ConnectionGracePeriodSeconds, DEFAULT_CONNECTION_GRACE_PERIOD_SECONDS, parse_positive::<u64>);
+        let shutdown_drain_timeout_seconds = resolver.parse(
+            ServerConfigKey::ShutdownDrainTimeoutSeconds, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT_SECONDS, parse_positive::<u64>);
+        let rate_limit_auth_burst = resolver.parse(
+            ServerConfigKey::RateLimitAuthBurst, DEFAULT_RATE_LIMIT_AUTH_BURST, parse_positive::<u32>);
+        let rate_limit_auth_refill_per_minute = resolver.parse(
+            ServerConfigKey::RateLimitAuthRefillPerMinute, DEFAULT_RATE_LIMIT_AUTH_REFILL_PER_MINUTE, parse_positive::<u32>);
+        let rate_limit_lobby_burst = resolver.parse(
+            ServerConfigKey::RateLimitLobbyBurst, DEFAULT_RATE_LIMIT_LOBBY_BURST, parse_positive::<u32>);
+        let rate_limit_lobby_refill_per_minute = resolver.parse(
+            ServerConfigKey::RateLimitLobbyRefillPerMinute, DEFAULT_RATE_LIMIT_LOBBY_REFILL_PER_MINUTE, parse_positive::<u32>);
+        let rate_limit_shop_burst = resolver.parse(
+            ServerConfigKey::RateLimitShopBurst, DEFAULT_RATE_LIMIT_SHOP_BURST, parse_positive::<u32>);
+        let rate_limit_shop_refill_per_minute = resolver.parse(
+            ServerConfigKey::RateLimitShopRefillPerMinute, DEFAULT_RATE_LIMIT_SHOP_REFILL_PER_MINUTE, parse_positive::<u32>);
+        let rate_limit_in_game_burst = resolver.parse(
+            ServerConfigKey::RateLimitInGameBurst, DEFAULT_RATE_LIMIT_IN_GAME_BURST, parse_positive::<u32>);
+        let rate_limit_in_game_refill_per_minute = resolver.parse(
+            ServerConfigKey::RateLimitInGameRefillPerMinute, DEFAULT_RATE_LIMIT_IN_GAME_REFILL_PER_MINUTE, parse_positive::<u32>);
+        let rate_limit_default_burst = resolver.parse(
+            ServerConfigKey::RateLimitDefaultBurst, DEFAULT_RATE_LIMIT_DEFAULT_BURST, parse_positive::<u32>);
+        let rate_limit_default_refill_per_minute = resolver.parse(
+            ServerConfigKey::RateLimitDefaultRefillPerMinute, DEFAULT_RATE_LIMIT_DEFAULT_REFILL_PER_MINUTE, parse_positive::<u32>);
+        let rate_limit_disconnect_violation_count = resolver.parse(
+            ServerConfigKey::RateLimitDisconnectViolationCount, DEFAULT_RATE_LIMIT_DISCONNECT_VIOLATION_COUNT, parse_positive::<u32>);
+        let rate_limit_violation_window_seconds = resolver.parse(
+            ServerConfigKey::RateLimitViolationWindowSeconds, DEFAULT_RATE_LIMIT_VIOLATION_WINDOW_SECONDS, parse_positive::<u64>);
+        let admin_token = resolver.parse_optional(ServerConfigKey::AdminToken, parse_admin_token);
+
+        if web_socket_port == Some(port) {
+            resolver.problems.push(format!("{} and {} must be different ports: {}",
+                                           ServerConfigKey::Port.get_env_key(),
+                                           ServerConfigKey::WebSocketPort.get_env_key(),
+                                           port));
+        }
+
+        // 응답 확인 요청을 보낼 틈도 없이 기권 처리되는 것을 방지
+        if connection_grace_period_seconds <= heartbeat_interval_seconds {
+            resolver.problems.push(format!("{} must be greater than {}: {} <= {}",
+                                           ServerConfigKey::ConnectionGracePeriodSeconds.get_env_key(),
+                                           ServerConfigKey::HeartbeatIntervalSeconds.get_env_key(),
+                                           connection_grace_period_seconds,
+                                           heartbeat_interval_seconds));
+        }
+
+        if !resolver.problems.is_empty() {
+            return Err(ServerConfigError::new(resolver.problems))
+        }
+
+        Ok(ServerConfig {
+            bind_host,
+            port,
+            web_socket_port,
+            storage_backend,
+            database_url,
+            storage_pool_config: StoragePoolConfig::new(storage_pool_max_size,
+                                                        storage_pool_min_idle,
+                                                        Duration::from_secs(storage_pool_connection_timeout_seconds),
+                                                        storage_pool_idle_timeout_seconds.map(Duration::from_secs)),
+            session_store,
+            redis_url,
+            session_expired_seconds,
+            matchmaker_poll_interval: Duration::from_millis(matchmaker_poll_interval_milliseconds),
+            turn_time_limit: Duration::from_secs(turn_time_limit_seconds),
+            mulligan_time_limit: Duration::from_secs(mulligan_time_limit_seconds),
+            heartbeat_interval: Duration::from_secs(heartbeat_interval_seconds),
+            connection_grace_period: Duration::from_secs(connection_grace_period_seconds),
+            shutdown_drain_timeout: Duration::from_secs(shutdown_drain_timeout_seconds),
+            auth_rate_limit: TokenBucketLimit::new(rate_limit_auth_burst, rate_limit_auth_refill_per_minute),
+            lobby_rate_limit: TokenBucketLimit::new(rate_limit_lobby_burst, rate_limit_lobby_refill_per_minute),
+            shop_rate_limit: TokenBucketLimit::new(rate_limit_shop_burst, rate_limit_shop_refill_per_minute),
+            in_game_rate_limit: TokenBucketLimit::new(rate_limit_in_game_burst, rate_limit_in_game_refill_per_minute),
+            default_rate_limit: TokenBucketLimit::new(rate_limit_default_burst, rate_limit_default_refill_per_minute),
+            rate_limit_disconnect_violation_count,
+            rate_limit_violation_window: Duration::from_secs(rate_limit_violation_window_seconds),
+            admin_token,
+        })
+    }
+
+    // 값 자체는 올바르지만 서버 기동에 반드시 필요한 항목 검사
+    pub fn validate_startup_requirements(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.storage_backend == StorageBackendType::Mysql && self.database_url.is_none() {
+            problems.push(format!("{} must be set when {} is mysql",
+                                  ServerConfigKey::DatabaseUrl.get_env_key(),
+                                  ServerConfigKey::StorageBackend.get_env_key()));
+        }
+
+        problems
+    }
+
+    pub fn get_bind_address(&self) -> String {
+        Self::make_bind_address(&self.bind_host, self.port)
+    }
+
+    pub fn get_web_socket_bind_address(&self) -> Option<String> {
+        self.web_socket_port.map(|web_socket_port| Self::make_bind_address(&self.bind_host, web_socket_port))
+    }
+
+    fn make_bind_address(bind_host: &str, port: u16) -> String {
+        match bind_host.parse::<IpAddr>() {
+            Ok(IpAddr::V6(_)) => format!("[{}]:{}", bind_host, port),
+            _ => format!("{}:{}", bind_host, port),
+        }
+    }
+
+    pub fn get_storage_backend(&self) -> StorageBackendType {
+        self.storage_backend
+    }
+
+    pub fn get_database_url(&self) -> Option<&String> {
+        self.database_url.as_ref()
+    }
+
+    pub fn get_storage_pool_config(&self) -> &StoragePoolConfig {
+        &self.storage_pool_config
+    }
+
+    pub fn get_session_store(&self) -> SessionStoreType {
+        self.session_store
+    }
+
+    pub fn get_redis_url(&self) -> &str {
+        &self.redis_url
+    }
+
+    pub fn get_session_expired_seconds(&self) -> u32 {
+        self.session_expired_seconds
+    }
+
+    pub fn get_matchmaker_poll_interval(&self) -> Duration {
+        self.matchmaker_poll_interval
+    }
+
+    pub fn get_turn_time_limit(&self) -> Duration {
+        self.turn_time_limit
+    }
+
+    pub fn get_mulligan_time_limit(&self) -> Duration {
+        self.mulligan_time_limit
+    }
+
+    pub fn get_heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval
+    }
+
+    pub fn get_connection_grace_period(&self) -> Duration {
+        self.connection_grace_period
+    }
+
+    pub fn get_shutdown_drain_timeout(&self) -> Duration {
+        self.shutdown_drain_timeout
+    }
+
+    pub fn get_rate_limit(&self, rate_limit_class: RateLimitClass) -> TokenBucketLimit {
+        match rate_limit_class {
+            RateLimitClass::Auth => self.auth_rate_limit,
+            RateLimitClass::Lobby => self.lobby_rate_limit,
+            RateLimitClass::Shop => self.shop_rate_limit,
+            RateLimitClass::InGame => self.in_game_rate_limit,
+            RateLimitClass::Default => self.default_rate_limit,
+        }
+    }
+
+    pub fn get_rate_limit_disconnect_violation_count(&self) -> u32 {
+        self.rate_limit_disconnect_violation_count
+    }
+
+    pub fn get_rate_limit_violation_window(&self) -> Duration {
+        self.rate_limit_violation_window
+    }
+
+    // 설정하지 않으면 관리자 전용 프로토콜은 모두 거부됨
+    pub fn get_admin_token(&self) -> Option<&String> {
+        self.admin_token.as_ref()
+    }
+}
+
+struct ServerConfigResolver<'a> {
+    sources: &'a [ServerConfigSource],
+    problems: Vec<String>,
+}
+
+impl<'a> ServerConfigResolver<'a> {
+    fn lookup(&self, key: ServerConfigKey) -> Option<(&'a String, &'a str)> {
+        self.sources.iter().rev()
+            .find_map(|source| source.get(key).map(|value| (value, source.get_origin())))
+    }
+
+    fn parse<T>(&mut self, key: ServerConfigKey, default: T, parse: impl Fn(&str) -> Result<T, String>) -> T {
+        self.parse_optional(key, parse).unwrap_or(default)
+    }
+
+    fn parse_optional<T>(&mut self, key: ServerConfigKey, parse: impl Fn(&str) -> Result<T, String>) -> Option<T> {
+        let (value, origin) = self.lookup(key)?;
+
+        match parse(value.trim()) {
+            Ok(parsed) => Some(parsed),
+            Err(reason) => {
+                self.problems.push(format!("{} from {} {} (got \"{}\")",
+                                           display_key(key, origin), origin, reason, value));
+                None
+            }
+        }
+    }
+}
+
+// 사용자가 값을 넣은 위치에 맞는 이름으로 안내
+fn display_key(key: ServerConfigKey, origin: &str) -> String {
+    match origin {
+        "env" => key.get_env_key().to_string(),
+        "cli" => key.get_cli_flag(),
+        _ => key.get_file_key().to_string(),
+    }
+}
+
+fn parse_bind_host(value: &str) -> Result<String, String> {
+    let is_host_name = !value.is_empty() &&
+        value.chars().all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '.');
+
+    if value.parse::<IpAddr>().is_ok() || is_host_name {
+        Ok(value.to_string())
+    } else {
+        Err("must be an IP address or a host name".to_string())
+    }
+}
+
+fn parse_port(value: &str) -> Result<u16, String> {
+    match value.parse::<u16>() {
+        Ok(port) if port > 0 => Ok(port),
+        _ => Err("must be a port number between 1 and 65535".to_string()),
+    }
+}
+
+fn parse_positive<T>(value: &str) -> Result<T, String>
+    where T: std::str::FromStr + PartialOrd + Default {
+
+    match value.parse::<T>() {
+        Ok(parsed) if parsed > T::default() => Ok(parsed),
+        _ => Err("must be a positive integer".to_string()),
+    }
+}
+
+fn parse_mysql_url(value: &str) -> Result<String, String> {
+    if value.starts_with("mysql://") {
+        Ok(value.to_string())
+    } else {
+        Err("must start with mysql://".to_string())
+    }
+}
+
+fn parse_admin_token(value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        Err("must not be empty".to_string())
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+fn parse_redis_url(value: &str) -> Result<String, String> {
+    value.into_connection_info()
+        .map(|_| value.to_string())
+        .map_err(|e| format!("is not a valid redis url: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_source(origin: &str, values: &[(ServerConfigKey, &str)]) -> ServerConfigSource {
+        let mut source = ServerConfigSource::new(origin);
+        for (key, value) in values {
+            source.insert(*key, value.to_string());
+        }
+        source
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        let server_config = ServerConfig::resolve(&[]).unwrap();
+
+        assert_eq!(server_config.get_bind_address(), "0.0.0.0:7373");
+        assert_eq!(server_config.get_web_socket_bind_address(), None);
+        assert_eq!(server_config.get_storage_backend(), StorageBackendType::Mysql);
+        assert_eq!(server_config.get_storage_pool_config(), &StoragePoolConfig::new(
+            DEFAULT_STORAGE_POOL_MAX_SIZE, None, Duration::from_secs(DEFAULT_STORAGE_POOL_CONNECTION_TIMEOUT_SECONDS), None));
+        assert_eq!(server_config.get_session_store(), SessionStoreType::Redis);
+        assert_eq!(server_config.get_redis_url(), DEFAULT_REDIS_URL);
+        assert_eq!(server_config.get_session_expired_seconds(), DEFAULT_SESSION_EXPIRED_SECONDS);
+        assert_eq!(server_config.get_matchmaker_poll_interval(), Duration::from_millis(1000));
+        assert_eq!(server_config.get_turn_time_limit(), Duration::from_secs(60));
+        assert_eq!(server_config.get_mulligan_time_limit(), Duration::from_secs(30));
+        assert_eq!(server_config.get_heartbeat_interval(), Duration::from_secs(15));
+        assert_eq!(server_config.get_connection_grace_period(), Duration::from_secs(60));
+        assert_eq!(server_config.get_shutdown_drain_timeout(), Duration::from_secs(120));
+        assert_eq!(server_config.get_rate_limit(RateLimitClass::Auth), TokenBucketLimit::new(5, 10));
+        assert_eq!(server_config.get_rate_limit(RateLimitClass::Lobby), TokenBucketLimit::new(10, 60));
+        assert_eq!(server_config.get_rate_limit(RateLimitClass::Shop), TokenBucketLimit::new(10, 60));
+        assert_eq!(server_config.get_rate_limit(RateLimitClass::InGame), TokenBucketLimit::new(20, 600));
+        assert_eq!(server_config.get_rate_limit(RateLimitClass::Default), TokenBucketLimit::new(30, 300));
+        assert_eq!(server_config.get_rate_limit_disconnect_violation_count(), 20);
+        assert_eq!(server_config.get_rate_limit_violation_window(), Duration::from_secs(60));
+        assert_eq!(server_config.get_admin_token(), None);
+        assert_eq!(server_config.validate_startup_requirements().len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_later_source_overrides() {
+        let file_source = make_source("file", &[
+            (ServerConfigKey::BindHost, "127.0.0.1"),
+            (ServerConfigKey::Port, "7000"),
+            (ServerConfigKey::StorageBackend, "sqlite"),
+        ]);
+        let env_source = make_source("env", &[(ServerConfigKey::Port, "7100"), (ServerConfigKey::WebSocketPort, "7200")]);
+        let cli_source = make_source("cli", &[(ServerConfigKey::Port, "7300")]);
+
+        let server_config = ServerConfig::resolve(&[file_source, env_source, cli_source]).unwrap();
+
+        assert_eq!(server_config.get_bind_address(), "127.0.0.1:7300");
+        assert_eq!(server_config.get_web_socket_bind_address(), Some("127.0.0.1:7200".to_string()));
+        assert!(server_config.validate_startup_requirements().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_reports_every_problem() {
+        let env_source = make_source("env", &[
+            (ServerConfigKey::BindHost, "not a host"),
+            (ServerConfigKey::Port, "70000"),
+            (ServerConfigKey::StorageBackend, "postgres"),
+            (ServerConfigKey::DatabaseUrl, "postgres://localhost"),
+            (ServerConfigKey::RedisUrl, "http://127.0.0.1"),
+            (ServerConfigKey::TurnTimeLimitSeconds, "0"),
+        ]);
+        let cli_source = make_source("cli", &[(ServerConfigKey::MatchmakerPollIntervalMilliseconds, "-1")]);
+
+        let err = ServerConfig::resolve(&[env_source, cli_source]).unwrap_err();
+
+        assert_eq!(err.get_problems().len(), 7);
+        assert!(err.get_problems().iter().any(|problem| problem.starts_with("TARGET_PORT from env")));
+        assert!(err.get_problems().iter().any(|problem| problem.starts_with("--matchmaker-poll-interval-milliseconds from cli")));
+    }
+
+    #[test]
+    fn test_resolve_rejects_same_port() {
+        let env_source = make_source("env", &[(ServerConfigKey::Port, "7373"), (ServerConfigKey::WebSocketPort, "7373")]);
+
+        assert!(ServerConfig::resolve(&[env_source]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_storage_pool_config() {
+        let env_source = make_source("env", &[
+            (ServerConfigKey::StoragePoolMaxSize, "20"),
+            (ServerConfigKey::StoragePoolMinIdle, "4"),
+            (ServerConfigKey::StoragePoolConnectionTimeoutSeconds, "3"),
+            (ServerConfigKey::StoragePoolIdleTimeoutSeconds, "600"),
+        ]);
+
+        let server_config = ServerConfig::resolve(&[env_source]).unwrap();
+        let storage_pool_config = server_config.get_storage_pool_config();
+
+        assert_eq!(storage_pool_config.get_max_size(), 20);
+        assert_eq!(storage_pool_config.get_min_idle(), Some(4));
+        assert_eq!(storage_pool_config.get_connection_timeout(), Duration::from_secs(3));
+        assert_eq!(storage_pool_config.get_idle_timeout(), Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_resolve_rejects_invalid_storage_pool_config() {
+        let env_source = make_source("env", &[
+            (ServerConfigKey::StoragePoolMaxSize, "0"),
+            (ServerConfigKey::StoragePoolConnectionTimeoutSeconds, "abc"),
+            (ServerConfigKey::StoragePoolIdleTimeoutSeconds, "-1"),
+        ]);
+
+        let err = ServerConfig::resolve(&[env_source]).unwrap_err();
+
+        assert_eq!(err.get_problems().len(), 3);
+        assert!(err.get_problems().iter().any(|problem| problem.starts_with("STORAGE_POOL_MAX_SIZE from env")));
+    }
+
+    #[test]
+    fn test_default_config_file() {
+        let default_config_path = ServerConfig::make_config_path(DEFAULT_SERVER_CONFIG_PATH);
+        let file_source = ServerConfigSource::from_file(&default_config_path).unwrap();
+
+        assert_eq!(ServerConfig::resolve(&[file_source]).unwrap(), ServerConfig::resolve(&[]).unwrap());
+    }
+
+    #[test]
+    fn test_bind_address_ipv6() {
+        let env_source = make_source("env", &[(ServerConfigKey::BindHost, "::1")]);
+        let server_config = ServerConfig::resolve(&[env_source]).unwrap();
+
+        assert_eq!(server_config.get_bind_address(), "[::1]:7373");
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+// 기동 전에 잘못된 설정을 한 번에 보여주기 위해 모든 문제를 모아서 반환
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfigError {
+    problems: Vec<String>,
+}
+
+impl ServerConfigError {
+    pub fn new(problems: Vec<String>) -> Self {
+        ServerConfigError { problems }
+    }
+
+    pub fn get_problems(&self) -> &Vec<String> {
+        &self.problems
+    }
+}
+
+impl fmt::Display for ServerConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Invalid server configuration ({} problems)", self.problems.len())?;
+        for problem in &self.problems {
+            writeln!(f, "  - {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ServerConfigError {}
@@ -0,0 +1,170 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServerConfigKey {
+    BindHost,
+    Port,
+    WebSocketPort,
+    StorageBackend,
+    DatabaseUrl,
+    StoragePoolMaxSize,
+    StoragePoolMinIdle,
+    StoragePoolConnectionTimeoutSeconds,
+    StoragePoolIdleTimeoutSeconds,
+    SessionStore,
+    RedisUrl,
+    SessionExpiredSeconds,
+    MatchmakerPollIntervalMilliseconds,
+    TurnTimeLimitSeconds,
+    MulliganTimeLimitSeconds,
+    HeartbeatIntervalSeconds,
+    ConnectionGracePeriodSeconds,
+    ShutdownDrainTimeoutSeconds,
+    RateLimitAuthBurst,
+    RateLimitAuthRefillPerMinute,
+    RateLimitLobbyBurst,
+    RateLimitLobbyRefillPerMinute,
+    RateLimitShopBurst,
+    RateLimitShopRefillPerMinute,
+    RateLimitInGameBurst,
+    RateLimitInGameRefillPerMinute,
+    RateLimitDefaultBurst,
+    RateLimitDefaultRefillPerMinute,
+    RateLimitDisconnectViolationCount,
+    RateLimitViolationWindowSeconds,
+    AdminToken,
+}
+
+impl ServerConfigKey {
+    pub const ALL: [ServerConfigKey; 31] = [
+        ServerConfigKey::BindHost,
+        ServerConfigKey::Port,
+        ServerConfigKey::WebSocketPort,
+        ServerConfigKey::StorageBackend,
+        ServerConfigKey::DatabaseUrl,
+        ServerConfigKey::StoragePoolMaxSize,
+        ServerConfigKey::StoragePoolMinIdle,
+        ServerConfigKey::StoragePoolConnectionTimeoutSeconds,
+        ServerConfigKey::StoragePoolIdleTimeoutSeconds,
+        ServerConfigKey::SessionStore,
+        ServerConfigKey::RedisUrl,
+        ServerConfigKey::SessionExpiredSeconds,
+        ServerConfigKey::MatchmakerPollIntervalMilliseconds,
+        ServerConfigKey::TurnTimeLimitSeconds,
+        ServerConfigKey::MulliganTimeLimitSeconds,
+        ServerConfigKey::HeartbeatIntervalSeconds,
+        ServerConfigKey::ConnectionGracePeriodSeconds,
+        ServerConfigKey::ShutdownDrainTimeoutSeconds,
+        ServerConfigKey::RateLimitAuthBurst,
+        ServerConfigKey::RateLimitAuthRefillPerMinute,
+        ServerConfigKey::RateLimitLobbyBurst,
+        ServerConfigKey::RateLimitLobbyRefillPerMinute,
+        ServerConfigKey::RateLimitShopBurst,
+        ServerConfigKey::RateLimitShopRefillPerMinute,
+        ServerConfigKey::RateLimitInGameBurst,
+        ServerConfigKey::RateLimitInGameRefillPerMinute,
+        ServerConfigKey::RateLimitDefaultBurst,
+        ServerConfigKey::RateLimitDefaultRefillPerMinute,
+        ServerConfigKey::RateLimitDisconnectViolationCount,
+        ServerConfigKey::RateLimitViolationWindowSeconds,
+        ServerConfigKey::AdminToken,
+    ];
+
+    // 설정 파일(JSON)에서 사용하는 이름
+    pub fn get_file_key(&self) -> &'static str {
+        match self {
+            ServerConfigKey::BindHost => "bind_host",
+            ServerConfigKey::Port => "port",
+            ServerConfigKey::WebSocketPort => "web_socket_port",
+            ServerConfigKey::StorageBackend => "storage_backend",
+            ServerConfigKey::DatabaseUrl => "database_url",
+            ServerConfigKey::StoragePoolMaxSize => "storage_pool_max_size",
+            ServerConfigKey::StoragePoolMinIdle => "storage_pool_min_idle",
+            ServerConfigKey::StoragePoolConnectionTimeoutSeconds => "storage_pool_connection_timeout_seconds",
+            ServerConfigKey::StoragePoolIdleTimeoutSeconds => "storage_pool_idle_timeout_seconds",
+            ServerConfigKey::SessionStore => "session_store",
+            ServerConfigKey::RedisUrl => "redis_url",
+            ServerConfigKey::SessionExpiredSeconds => "session_expired_seconds",
+            ServerConfigKey::MatchmakerPollIntervalMilliseconds => "matchmaker_poll_interval_milliseconds",
+            ServerConfigKey::TurnTimeLimitSeconds => "turn_time_limit_seconds",
+            ServerConfigKey::MulliganTimeLimitSeconds => "mulligan_time_limit_seconds",
+            ServerConfigKey::HeartbeatIntervalSeconds => "heartbeat_interval_seconds",
+            ServerConfigKey::ConnectionGracePeriodSeconds => "connection_grace_period_seconds",
+            ServerConfigKey::ShutdownDrainTimeoutSeconds => "shutdown_drain_timeout_seconds",
+            ServerConfigKey::RateLimitAuthBurst => "rate_limit_auth_burst",
+            ServerConfigKey::RateLimitAuthRefillPerMinute => "rate_limit_auth_refill_per_minute",
+            ServerConfigKey::RateLimitLobbyBurst => "rate_limit_lobby_burst",
+            ServerConfigKey::RateLimitLobbyRefillPerMinute => "rate_limit_lobby_refill_per_minute",
+            ServerConfigKey::RateLimitShopBurst => "rate_limit_shop_burst",
+            ServerConfigKey::RateLimitShopRefillPerMinute => "rate_limit_shop_refill_per_minute",
+            ServerConfigKey::RateLimitInGameBurst => "rate_limit_in_game_burst",
+            ServerConfigKey::RateLimitInGameRefillPerMinute => "rate_limit_in_game_refill_per_minute",
+            ServerConfigKey::RateLimitDefaultBurst => "rate_limit_default_burst",
+            ServerConfigKey::RateLimitDefaultRefillPerMinute => "rate_limit_default_refill_per_minute",
+            ServerConfigKey::RateLimitDisconnectViolationCount => "rate_limit_disconnect_violation_count",
+            ServerConfigKey::RateLimitViolationWindowSeconds => "rate_limit_violation_window_seconds",
+            ServerConfigKey::AdminToken => "admin_token",
+        }
+    }
+
+    // 환경 변수 이름 (기존 TARGET_PORT, DATABASE_URL 등은 그대로 유지)
+    pub fn get_env_key(&self) -> &'static str {
+        match self {
+            ServerConfigKey::BindHost => "BIND_HOST",
+            ServerConfigKey::Port => "TARGET_PORT",
+            ServerConfigKey::WebSocketPort => "WEB_SOCKET_PORT",
+            ServerConfigKey::StorageBackend => "STORAGE_BACKEND",
+            ServerConfigKey::DatabaseUrl => "DATABASE_URL",
+            ServerConfigKey::StoragePoolMaxSize => "STORAGE_POOL_MAX_SIZE",
+            ServerConfigKey::StoragePoolMinIdle => "STORAGE_POOL_MIN_IDLE",
+            ServerConfigKey::StoragePoolConnectionTimeoutSeconds => "STORAGE_POOL_CONNECTION_TIMEOUT_SECONDS",
+            ServerConfigKey::StoragePoolIdleTimeoutSeconds => "STORAGE_POOL_IDLE_TIMEOUT_SECONDS",
+            ServerConfigKey::SessionStore => "SESSION_STORE",
+            ServerConfigKey::RedisUrl => "REDIS_URL",
+            ServerConfigKey::SessionExpiredSeconds => "SESSION_EXPIRED_SECONDS",
+            ServerConfigKey::MatchmakerPollIntervalMilliseconds => "MATCHMAKER_POLL_INTERVAL_MILLISECONDS",
+            ServerConfigKey::TurnTimeLimitSeconds => "TURN_TIME_LIMIT_SECONDS",
+            ServerConfigKey::MulliganTimeLimitSeconds => "MULLIGAN_TIME_LIMIT_SECONDS",
+            ServerConfigKey::HeartbeatIntervalSeconds => "HEARTBEAT_INTERVAL_SECONDS",
+            ServerConfigKey::ConnectionGracePeriodSeconds => "CONNECTION_GRACE_PERIOD_SECONDS",
+            ServerConfigKey::ShutdownDrainTimeoutSeconds => "SHUTDOWN_DRAIN_TIMEOUT_SECONDS",
+            ServerConfigKey::RateLimitAuthBurst => "RATE_LIMIT_AUTH_BURST",
+            ServerConfigKey::RateLimitAuthRefillPerMinute => "RATE_LIMIT_AUTH_REFILL_PER_MINUTE",
+            ServerConfigKey::RateLimitLobbyBurst => "RATE_LIMIT_LOBBY_BURST",
+            ServerConfigKey::RateLimitLobbyRefillPerMinute => "RATE_LIMIT_LOBBY_REFILL_PER_MINUTE",
+            ServerConfigKey::RateLimitShopBurst => "RATE_LIMIT_SHOP_BURST",
+            ServerConfigKey::RateLimitShopRefillPerMinute => "RATE_LIMIT_SHOP_REFILL_PER_MINUTE",
+            ServerConfigKey::RateLimitInGameBurst => "RATE_LIMIT_IN_GAME_BURST",
+            ServerConfigKey::RateLimitInGameRefillPerMinute => "RATE_LIMIT_IN_GAME_REFILL_PER_MINUTE",
+            ServerConfigKey::RateLimitDefaultBurst => "RATE_LIMIT_DEFAULT_BURST",
+            ServerConfigKey::RateLimitDefaultRefillPerMinute => "RATE_LIMIT_DEFAULT_REFILL_PER_MINUTE",
+            ServerConfigKey::RateLimitDisconnectViolationCount => "RATE_LIMIT_DISCONNECT_VIOLATION_COUNT",
+            ServerConfigKey::RateLimitViolationWindowSeconds => "RATE_LIMIT_VIOLATION_WINDOW_SECONDS",
+            ServerConfigKey::AdminToken => "ADMIN_TOKEN",
+        }
+    }
+
+    // 실행 인자 이름 ex) --bind-host 127.0.0.1
+    pub fn get_cli_flag(&self) -> String {
+        format!("--{}", self.get_file_key().replace('_', "-"))
+    }
+
+    pub fn from_file_key(file_key: &str) -> Option<ServerConfigKey> {
+        Self::ALL.iter().copied().find(|key| key.get_file_key() == file_key)
+    }
+
+    pub fn from_cli_flag(cli_flag: &str) -> Option<ServerConfigKey> {
+        Self::ALL.iter().copied().find(|key| key.get_cli_flag() == cli_flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_names() {
+        assert_eq!(ServerConfigKey::MatchmakerPollIntervalMilliseconds.get_cli_flag(), "--matchmaker-poll-interval-milliseconds");
+        assert_eq!(ServerConfigKey::from_cli_flag("--bind-host"), Some(ServerConfigKey::BindHost));
+        assert_eq!(ServerConfigKey::from_file_key("port"), Some(ServerConfigKey::Port));
+        assert_eq!(ServerConfigKey::from_file_key("unknown"), None);
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use serde_json::Value as JsonValue;
+
+use crate::common::env::env_detector::EnvDetector;
+use crate::server_config::server_config_key::ServerConfigKey;
+
+pub const CONFIG_PATH_CLI_FLAG: &str = "--config";
+
+// 설정 파일 / 환경 변수 / 실행 인자 중 하나에서 읽은 원본 값 (검증은 ServerConfig 에서 수행)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfigSource {
+    origin: String,
+    values: HashMap<ServerConfigKey, String>,
+}
+
+impl ServerConfigSource {
+    pub fn new(origin: &str) -> Self {
+        ServerConfigSource {
+            origin: origin.to_string(),
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn get_origin(&self) -> &str {
+        &self.origin
+    }
+
+    pub fn get(&self, key: ServerConfigKey) -> Option<&String> {
+        self.values.get(&key)
+    }
+
+    pub fn insert(&mut self, key: ServerConfigKey, value: String) {
+        self.values.insert(key, value);
+    }
+
+    pub fn from_env() -> Self {
+        let env_values = [
+            (ServerConfigKey::BindHost, EnvDetector::get_bind_host()),
+            (ServerConfigKey::Port, EnvDetector::get_port()),
+            (ServerConfigKey::WebSocketPort, EnvDetector::get_web_socket_port()),
+            (ServerConfigKey::StorageBackend, EnvDetector::get_storage_backend()),
+            (ServerConfigKey::DatabaseUrl, EnvDetector::get_mysql_url()),
+            (ServerConfigKey::StoragePoolMaxSize, EnvDetector::get_storage_pool_max_size()),
+            (ServerConfigKey::StoragePoolMinIdle, EnvDetector::get_storage_pool_min_idle()),
+            (ServerConfigKey::StoragePoolConnectionTimeoutSeconds, EnvDetector::get_storage_pool_connection_timeout_seconds()),
+            (ServerConfigKey::StoragePoolIdleTimeoutSeconds, EnvDetector::get_storage_pool_idle_timeout_seconds()),
+            (ServerConfigKey::SessionStore, EnvDetector::get_session_store()),
+            (ServerConfigKey::RedisUrl, EnvDetector::get_redis_url()),
+            (ServerConfigKey::SessionExpiredSeconds, EnvDetector::get_session_expired_seconds()),
+            (ServerConfigKey::MatchmakerPollIntervalMilliseconds, EnvDetector::get_matchmaker_poll_interval_milliseconds()),
+            (ServerConfigKey::TurnTimeLimitSeconds, EnvDetector::get_turn_time_limit_seconds()),
+            (ServerConfigKey::MulliganTimeLimitSeconds, EnvDetector::get_mulligan_time_limit_seconds()),
+            (ServerConfigKey::HeartbeatIntervalSeconds, EnvDetector::get_heartbeat_interval_seconds()),
+            (ServerConfigKey::ConnectionGracePeriodSeconds, EnvDetector::get_connection_grace_period_seconds()),
+            (ServerConfigKey::ShutdownDrainTimeoutSeconds, EnvDetector::get_shutdown_drain_timeout_seconds()),
+            (ServerConfigKey::RateLimitAuthBurst, EnvDetector::get_rate_limit_auth_burst()),
+            (ServerConfigKey::RateLimitAuthRefillPerMinute, EnvDetector::get_rate_limit_auth_refill_per_minute()),
+            (ServerConfigKey::RateLimitLobbyBurst, EnvDetector::get_rate_limit_lobby_burst()),
+            (ServerConfigKey::RateLimitLobbyRefillPerMinute, EnvDetector::get_rate_limit_lobby_refill_per_minute()),
+            (ServerConfigKey::RateLimitShopBurst, EnvDetector::get_rate_limit_shop_burst()),
+            (ServerConfigKey::RateLimitShopRefillPerMinute, EnvDetector::get_rate_limit_shop_refill_per_minute()),
+            (ServerConfigKey::RateLimitInGameBurst, EnvDetector::get_rate_limit_in_game_burst()),
+            (ServerConfigKey::RateLimitInGameRefillPerMinute, EnvDetector::get_rate_limit_in_game_refill_per_minute()),
+            (ServerConfigKey::RateLimitDefaultBurst, EnvDetector::get_rate_limit_default_burst()),
+            (ServerConfigKey::RateLimitDefaultRefillPerMinute, EnvDetector::get_rate_limit_default_refill_per_minute()),
+            (ServerConfigKey::RateLimitDisconnectViolationCount, EnvDetector::get_rate_limit_disconnect_violation_count()),
+            (ServerConfigKey::RateLimitViolationWindowSeconds, EnvDetector::get_rate_limit_violation_window_seconds()),
+            (ServerConfigKey::AdminToken, EnvDetector::get_admin_token()),
+        ];
+
+        let mut source = ServerConfigSource::new("env");
+        for (key, value) in env_values {
+            if let Some(value) = value {
+                source.insert(key, value);
+            }
+        }
+
+        source
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, Vec<String>> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| vec![format!("Failed to read config file {:?}: {}", path, e)])?;
+
+        Self::from_json_str(&format!("file {:?}", path), &json)
+    }
+
+    // 문자열, 숫자 모두 허용하며 알 수 없는 키는 오타로 간주
+    pub fn from_json_str(origin: &str, json: &str) -> Result<Self, Vec<String>> {
+        let json_value: JsonValue = serde_json::from_str(json)
+            .map_err(|e| vec![format!("Failed to parse {}: {}", origin, e)])?;
+
+        let json_object = match json_value {
+            JsonValue::Object(json_object) => json_object,
+            _ => return Err(vec![format!("{} must be a JSON object", origin)]),
+        };
+
+        let mut source = ServerConfigSource::new(origin);
+        let mut problems = Vec::new();
+
+        for (file_key, value) in json_object {
+            let key = match ServerConfigKey::from_file_key(&file_key) {
+                Some(key) => key,
+                None => {
+                    problems.push(format!("Unknown key \"{}\" in {}", file_key, origin));
+                    continue
+                }
+            };
+
+            match value {
+                JsonValue::String(value) => source.insert(key, value),
+                JsonValue::Number(value) => source.insert(key, value.to_string()),
+                JsonValue::Null => {}
+                _ => problems.push(format!("\"{}\" in {} must be a string or a number", file_key, origin)),
+            }
+        }
+
+        if problems.is_empty() { Ok(source) } else { Err(problems) }
+    }
+
+    // --key value 와 --key=value 모두 허용, --config 는 find_config_path 에서 처리
+    pub fn from_cli_args(args: &[String]) -> Result<Self, Vec<String>> {
+        let mut source = ServerConfigSource::new("cli");
+        let mut problems = Vec::new();
+        let mut args_iter = args.iter().peekable();
+
+        while let Some(arg) = args_iter.next() {
+            let (cli_flag, inline_value) = match arg.split_once('=') {
+                Some((cli_flag, value)) => (cli_flag, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+
+            // 값이 따로 주어진 경우 다음 인자를 값으로 사용 (다음 인자가 다른 flag 면 값 누락)
+            let value = inline_value.or_else(|| {
+                match args_iter.peek() {
+                    Some(next_arg) if !next_arg.starts_with("--") => args_iter.next().cloned(),
+                    _ => None,
+                }
+            });
+
+            if cli_flag == CONFIG_PATH_CLI_FLAG {
+                continue
+            }
+
+            let key = match ServerConfigKey::from_cli_flag(cli_flag) {
+                Some(key) => key,
+                None => {
+                    problems.push(format!("Unknown command line argument: {}", arg));
+                    continue
+                }
+            };
+
+            match value {
+                Some(value) => source.insert(key, value),
+                None => problems.push(format!("Missing value for command line argument: {}", cli_flag)),
+            }
+        }
+
+        if problems.is_empty() { Ok(source) } else { Err(problems) }
+    }
+
+    pub fn find_config_path(args: &[String]) -> Option<String> {
+        let mut args_iter = args.iter();
+
+        while let Some(arg) = args_iter.next() {
+            if arg == CONFIG_PATH_CLI_FLAG {
+                return args_iter.next().cloned()
+            }
+            if let Some(config_path) = arg.strip_prefix("--config=") {
+                return Some(config_path.to_string())
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_json_str() {
+        let source = ServerConfigSource::from_json_str(
+            "test",
+            r#"{ "bind_host": "127.0.0.1", "port": 7373, "web_socket_port": null }"#).unwrap();
+
+        assert_eq!(source.get(ServerConfigKey::BindHost), Some(&"127.0.0.1".to_string()));
+        assert_eq!(source.get(ServerConfigKey::Port), Some(&"7373".to_string()));
+        assert_eq!(source.get(ServerConfigKey::WebSocketPort), None);
+    }
+
+    #[test]
+    fn test_from_json_str_reports_every_problem() {
+        let problems = ServerConfigSource::from_json_str(
+            "test",
+            r#"{ "bind_hots": "127.0.0.1", "port": [7373], "redis_url": "redis://127.0.0.1/" }"#).unwrap_err();
+
+        assert_eq!(problems.len(), 2);
+    }
+
+    #[test]
+    fn test_from_cli_args() {
+        let args = to_args(&["--config", "server.json", "--bind-host", "0.0.0.0", "--port=7373"]);
+        let source = ServerConfigSource::from_cli_args(&args).unwrap();
+
+        assert_eq!(ServerConfigSource::find_config_path(&args), Some("server.json".to_string()));
+        assert_eq!(source.get(ServerConfigKey::BindHost), Some(&"0.0.0.0".to_string()));
+        assert_eq!(source.get(ServerConfigKey::Port), Some(&"7373".to_string()));
+    }
+
+    #[test]
+    fn test_from_cli_args_reports_every_problem() {
+        let args = to_args(&["--unknown", "1", "--bind-host"]);
+        let problems = ServerConfigSource::from_cli_args(&args).unwrap_err();
+
+        assert_eq!(problems.len(), 2);
+    }
+}
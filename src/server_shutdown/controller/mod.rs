@@ -0,0 +1,2 @@
+pub mod server_shutdown_controller;
+pub mod server_shutdown_controller_impl;
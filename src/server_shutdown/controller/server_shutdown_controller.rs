@@ -0,0 +1,7 @@
+use std::time::Duration;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ServerShutdownController {
+    async fn drain_live_battle(&self, drain_timeout: Duration) -> usize;
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use crate::battle_finish::service::battle_finish_service::BattleFinishService;
+use crate::battle_finish::service::battle_finish_service_impl::BattleFinishServiceImpl;
+use crate::battle_finish::service::request::battle_finish_for_drawn_player_request::BattleFinishForDrawnPlayerRequest;
+use crate::battle_room::service::battle_room_service::BattleRoomService;
+use crate::battle_room::service::battle_room_service_impl::BattleRoomServiceImpl;
+use crate::battle_room::service::request::get_players_in_battle_room_request::GetPlayersInBattleRoomRequest;
+use crate::battle_room_actor::protocol::battle_room_action_dispatcher::find_battle_room_actor_handle;
+use crate::game_winner_check::service::game_winner_check_service::GameWinnerCheckService;
+use crate::game_winner_check::service::game_winner_check_service_impl::GameWinnerCheckServiceImpl;
+use crate::game_winner_check::service::request::draw_by_shutdown_request::DrawByShutdownRequest;
+use crate::server_shutdown::controller::server_shutdown_controller::ServerShutdownController;
+use crate::server_shutdown::service::request::notify_server_maintenance_request::NotifyServerMaintenanceRequest;
+use crate::server_shutdown::service::request::wait_for_live_battle_room_request::WaitForLiveBattleRoomRequest;
+use crate::server_shutdown::service::server_shutdown_service::ServerShutdownService;
+use crate::server_shutdown::service::server_shutdown_service_impl::ServerShutdownServiceImpl;
+
+pub struct ServerShutdownControllerImpl {
+    server_shutdown_service: Arc<AsyncMutex<ServerShutdownServiceImpl>>,
+}
+
+impl ServerShutdownControllerImpl {
+    pub fn new(server_shutdown_service: Arc<AsyncMutex<ServerShutdownServiceImpl>>) -> Self {
+        ServerShutdownControllerImpl {
+            server_shutdown_service,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ServerShutdownControllerImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ServerShutdownControllerImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ServerShutdownControllerImpl::new(
+                            ServerShutdownServiceImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    // 방의 한 플레이어 기준으로 양측을 무승부로 기록한 뒤 각자 보상 지급 및 정리
+    // 진행 중인 행동과 섞이지 않도록 방의 Actor 에서 실행
+    pub(crate) async fn draw_battle_room(battle_room_service: Arc<AsyncMutex<BattleRoomServiceImpl>>,
+                                         game_winner_check_service: Arc<AsyncMutex<GameWinnerCheckServiceImpl>>,
+                                         battle_finish_service: Arc<AsyncMutex<BattleFinishServiceImpl>>,
+                                         battle_room_id: i32) -> bool {
+
+        println!("ServerShutdownControllerImpl: draw_battle_room() -> {}", battle_room_id);
+
+        let battle_room_service_guard = battle_room_service.lock().await;
+        let get_players_in_battle_room_response =
+            battle_room_service_guard.get_players_in_battle_room(
+                GetPlayersInBattleRoomRequest::new(battle_room_id)).await;
+        drop(battle_room_service_guard);
+
+        let player_list = get_players_in_battle_room_response.get_player_list().clone();
+        let account_unique_id = match player_list.first() {
+            Some(account_unique_id) => *account_unique_id,
+            None => return false,
+        };
+
+        let mut game_winner_check_service_guard = game_winner_check_service.lock().await;
+        game_winner_check_service_guard.set_game_draw_by_shutdown(
+            DrawByShutdownRequest::new(account_unique_id)).await;
+        drop(game_winner_check_service_guard);
+
+        let mut is_success = true;
+        for player_unique_id in player_list {
+            let battle_finish_service_guard = battle_finish_service.lock().await;
+            let battle_finish_response =
+                battle_finish_service_guard.battle_finish_for_drawn_player(
+                    BattleFinishForDrawnPlayerRequest::new(player_unique_id)).await;
+            drop(battle_finish_service_guard);
+
+            if !battle_finish_response.is_success() {
+                println!("Failed to finish battle of drawn player {}", player_unique_id);
+                is_success = false;
+            }
+        }
+
+        is_success
+    }
+}
+
+#[async_trait]
+impl ServerShutdownController for ServerShutdownControllerImpl {
+    // 점검을 알리고 제한 시간 동안 진행 중인 경기를 기다린 뒤, 남은 경기는 무승부로 끝내고 처리한 방 수를 반환
+    async fn drain_live_battle(&self, drain_timeout: Duration) -> usize {
+        println!("ServerShutdownControllerImpl: drain_live_battle()");
+
+        let server_shutdown_service_guard = self.server_shutdown_service.lock().await;
+        let notify_server_maintenance_response =
+            server_shutdown_service_guard.notify_server_maintenance(
+                NotifyServerMaintenanceRequest::new(drain_timeout)).await;
+        println!("Maintenance notice sent to {} clients", notify_server_maintenance_response.get_notified_client_count());
+
+        let wait_for_live_battle_room_response =
+            server_shutdown_service_guard.wait_for_live_battle_room(
+                WaitForLiveBattleRoomRequest::new(drain_timeout)).await;
+        drop(server_shutdown_service_guard);
+
+        let remaining_battle_room_id_list = wait_for_live_battle_room_response.get_remaining_battle_room_id_list();
+        for battle_room_id in remaining_battle_room_id_list {
+            // 진행 중인 행동과 섞이지 않도록 방의 Actor 에서 무승부 처리
+            let is_drawn = match find_battle_room_actor_handle(*battle_room_id).await {
+                Some(battle_room_actor_handle) => battle_room_actor_handle.draw_battle_room().await.unwrap_or(false),
+                None => false,
+            };
+
+            if !is_drawn {
+                println!("Failed to draw battle room {}", battle_room_id);
+            }
+        }
+
+        remaining_battle_room_id_list.len()
+    }
+}
@@ -0,0 +1 @@
+pub mod notify_form_server_maintenance;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+// 서버 점검 예고: drain_timeout_seconds 안에 끝나지 않은 경기는 무승부 처리됨
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyFormServerMaintenance {
+    drain_timeout_seconds: u64,
+}
+
+impl NotifyFormServerMaintenance {
+    pub fn new(drain_timeout_seconds: u64) -> Self {
+        NotifyFormServerMaintenance { drain_timeout_seconds }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod server_shutdown_repository;
+pub mod server_shutdown_repository_impl;
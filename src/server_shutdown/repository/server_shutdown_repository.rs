@@ -0,0 +1,7 @@
+use std::time::Duration;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ServerShutdownRepository {
+    async fn broadcast_maintenance_notice(&self, drain_timeout: Duration) -> usize;
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::timeout;
+use crate::client_socket_accept::repository::client_socket_accept_repository_impl::ClientSocketAcceptRepositoryImpl;
+use crate::response_generator::response_envelope::ResponseEnvelope;
+use crate::response_generator::response_type::ResponseType::NOTIFY_SERVER_MAINTENANCE;
+use crate::server_shutdown::entity::notify_form_server_maintenance::NotifyFormServerMaintenance;
+use crate::server_shutdown::repository::server_shutdown_repository::ServerShutdownRepository;
+
+const MAINTENANCE_NOTICE_SEND_TIMEOUT_MILLISECONDS: u64 = 1000;
+
+pub struct ServerShutdownRepositoryImpl {
+    client_socket_accept_repository: Arc<AsyncMutex<ClientSocketAcceptRepositoryImpl>>,
+}
+
+impl ServerShutdownRepositoryImpl {
+    pub fn new(client_socket_accept_repository: Arc<AsyncMutex<ClientSocketAcceptRepositoryImpl>>) -> Self {
+        ServerShutdownRepositoryImpl {
+            client_socket_accept_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ServerShutdownRepositoryImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ServerShutdownRepositoryImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ServerShutdownRepositoryImpl::new(
+                            ClientSocketAcceptRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+}
+
+#[async_trait]
+impl ServerShutdownRepository for ServerShutdownRepositoryImpl {
+    // 접속했던 모든 클라이언트에게 알림 (이미 끊긴 소켓 때문에 멈추지 않도록 소켓마다 시간 제한을 둠)
+    async fn broadcast_maintenance_notice(&self, drain_timeout: Duration) -> usize {
+        println!("ServerShutdownRepositoryImpl: broadcast_maintenance_notice()");
+
+        let client_socket_accept_repository_guard = self.client_socket_accept_repository.lock().await;
+        let client_list_mutex = client_socket_accept_repository_guard.get_client_list().clone();
+        drop(client_socket_accept_repository_guard);
+
+        let client_socket_list: Vec<_> = client_list_mutex.lock().await.values().cloned().collect();
+
+        let send_task_list: Vec<_> = client_socket_list.into_iter().map(|client_socket| {
+            let drain_timeout_seconds = drain_timeout.as_secs();
+            tokio::spawn(async move {
+                let send_future = client_socket.each_client_receiver_transmitter_channel().send(
+                    Arc::new(
+                        AsyncMutex::new(
+                            ResponseEnvelope::notification(
+                                NOTIFY_SERVER_MAINTENANCE(
+                                    NotifyFormServerMaintenance::new(drain_timeout_seconds))))));
+
+                timeout(Duration::from_millis(MAINTENANCE_NOTICE_SEND_TIMEOUT_MILLISECONDS), send_future).await.is_ok()
+            })
+        }).collect();
+
+        let mut notified_client_count = 0;
+        for send_task in send_task_list {
+            if let Ok(true) = send_task.await {
+                notified_client_count += 1;
+            }
+        }
+
+        notified_client_count
+    }
+}
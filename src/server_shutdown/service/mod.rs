@@ -0,0 +1,4 @@
+pub mod request;
+pub mod response;
+pub mod server_shutdown_service;
+pub mod server_shutdown_service_impl;
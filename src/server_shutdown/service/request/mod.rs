@@ -0,0 +1,2 @@
+pub mod notify_server_maintenance_request;
+pub mod wait_for_live_battle_room_request;
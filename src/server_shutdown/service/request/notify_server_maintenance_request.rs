@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct NotifyServerMaintenanceRequest {
+    drain_timeout: Duration,
+}
+
+impl NotifyServerMaintenanceRequest {
+    pub fn new(drain_timeout: Duration) -> Self {
+        NotifyServerMaintenanceRequest {
+            drain_timeout,
+        }
+    }
+
+    pub fn get_drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+}
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct WaitForLiveBattleRoomRequest {
+    drain_timeout: Duration,
+}
+
+impl WaitForLiveBattleRoomRequest {
+    pub fn new(drain_timeout: Duration) -> Self {
+        WaitForLiveBattleRoomRequest {
+            drain_timeout,
+        }
+    }
+
+    pub fn get_drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct NotifyServerMaintenanceResponse {
+    notified_client_count: usize,
+}
+
+impl NotifyServerMaintenanceResponse {
+    pub fn new(notified_client_count: usize) -> Self {
+        NotifyServerMaintenanceResponse {
+            notified_client_count,
+        }
+    }
+
+    pub fn get_notified_client_count(&self) -> usize {
+        self.notified_client_count
+    }
+}
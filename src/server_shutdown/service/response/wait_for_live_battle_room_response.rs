@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub struct WaitForLiveBattleRoomResponse {
+    remaining_battle_room_id_list: Vec<i32>,
+}
+
+impl WaitForLiveBattleRoomResponse {
+    pub fn new(remaining_battle_room_id_list: Vec<i32>) -> Self {
+        WaitForLiveBattleRoomResponse {
+            remaining_battle_room_id_list,
+        }
+    }
+
+    pub fn get_remaining_battle_room_id_list(&self) -> &Vec<i32> {
+        &self.remaining_battle_room_id_list
+    }
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::server_shutdown::service::request::notify_server_maintenance_request::NotifyServerMaintenanceRequest;
+use crate::server_shutdown::service::request::wait_for_live_battle_room_request::WaitForLiveBattleRoomRequest;
+use crate::server_shutdown::service::response::notify_server_maintenance_response::NotifyServerMaintenanceResponse;
+use crate::server_shutdown::service::response::wait_for_live_battle_room_response::WaitForLiveBattleRoomResponse;
+
+#[async_trait]
+pub trait ServerShutdownService {
+    async fn notify_server_maintenance(&self, notify_server_maintenance_request: NotifyServerMaintenanceRequest) -> NotifyServerMaintenanceResponse;
+    async fn wait_for_live_battle_room(&self, wait_for_live_battle_room_request: WaitForLiveBattleRoomRequest) -> WaitForLiveBattleRoomResponse;
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::{Instant, sleep};
+use crate::battle_room::repository::battle_room_repository::BattleRoomRepository;
+use crate::battle_room::repository::battle_room_repository_impl::BattleRoomRepositoryImpl;
+use crate::server_shutdown::repository::server_shutdown_repository::ServerShutdownRepository;
+use crate::server_shutdown::repository::server_shutdown_repository_impl::ServerShutdownRepositoryImpl;
+use crate::server_shutdown::service::request::notify_server_maintenance_request::NotifyServerMaintenanceRequest;
+use crate::server_shutdown::service::request::wait_for_live_battle_room_request::WaitForLiveBattleRoomRequest;
+use crate::server_shutdown::service::response::notify_server_maintenance_response::NotifyServerMaintenanceResponse;
+use crate::server_shutdown::service::response::wait_for_live_battle_room_response::WaitForLiveBattleRoomResponse;
+use crate::server_shutdown::service::server_shutdown_service::ServerShutdownService;
+
+const LIVE_BATTLE_ROOM_POLL_INTERVAL_MILLISECONDS: u64 = 1000;
+
+pub struct ServerShutdownServiceImpl {
+    server_shutdown_repository: Arc<AsyncMutex<ServerShutdownRepositoryImpl>>,
+    battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>,
+}
+
+impl ServerShutdownServiceImpl {
+    pub fn new(server_shutdown_repository: Arc<AsyncMutex<ServerShutdownRepositoryImpl>>,
+               battle_room_repository: Arc<AsyncMutex<BattleRoomRepositoryImpl>>) -> Self {
+
+        ServerShutdownServiceImpl {
+            server_shutdown_repository,
+            battle_room_repository,
+        }
+    }
+
+    pub fn get_instance() -> Arc<AsyncMutex<ServerShutdownServiceImpl>> {
+        lazy_static! {
+            static ref INSTANCE: Arc<AsyncMutex<ServerShutdownServiceImpl>> =
+                Arc::new(
+                    AsyncMutex::new(
+                        ServerShutdownServiceImpl::new(
+                            ServerShutdownRepositoryImpl::get_instance(),
+                            BattleRoomRepositoryImpl::get_instance())));
+        }
+        INSTANCE.clone()
+    }
+
+    async fn find_live_battle_room_id_list(&self) -> Vec<i32> {
+        let battle_room_repository_guard = self.battle_room_repository.lock().await;
+        battle_room_repository_guard.find_every_battle_room_id().await
+    }
+}
+
+#[async_trait]
+impl ServerShutdownService for ServerShutdownServiceImpl {
+    async fn notify_server_maintenance(&self, notify_server_maintenance_request: NotifyServerMaintenanceRequest) -> NotifyServerMaintenanceResponse {
+        println!("ServerShutdownServiceImpl: notify_server_maintenance()");
+
+        let server_shutdown_repository_guard = self.server_shutdown_repository.lock().await;
+        let notified_client_count =
+            server_shutdown_repository_guard.broadcast_maintenance_notice(
+                notify_server_maintenance_request.get_drain_timeout()).await;
+
+        NotifyServerMaintenanceResponse::new(notified_client_count)
+    }
+
+    // 모든 방이 끝나거나 제한 시간이 지날 때까지 기다린 뒤 남은 방 목록을 반환
+    async fn wait_for_live_battle_room(&self, wait_for_live_battle_room_request: WaitForLiveBattleRoomRequest) -> WaitForLiveBattleRoomResponse {
+        println!("ServerShutdownServiceImpl: wait_for_live_battle_room()");
+
+        let deadline = Instant::now() + wait_for_live_battle_room_request.get_drain_timeout();
+
+        loop {
+            let live_battle_room_id_list = self.find_live_battle_room_id_list().await;
+            if live_battle_room_id_list.is_empty() || Instant::now() >= deadline {
+                return WaitForLiveBattleRoomResponse::new(live_battle_room_id_list)
+            }
+
+            println!("Waiting for {} live battle rooms", live_battle_room_id_list.len());
+            sleep(Duration::from_millis(LIVE_BATTLE_ROOM_POLL_INTERVAL_MILLISECONDS)
+                .min(deadline.saturating_duration_since(Instant::now()))).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wait_for_live_battle_room_returns_remaining_room_after_deadline() {
+        let battle_room_repository = Arc::new(AsyncMutex::new(BattleRoomRepositoryImpl::new()));
+        let battle_room_id = battle_room_repository.lock().await.set_players_to_battle_room(vec![1, 2]).await.unwrap();
+
+        let server_shutdown_service = ServerShutdownServiceImpl::new(
+            ServerShutdownRepositoryImpl::get_instance(), battle_room_repository.clone());
+
+        let response = server_shutdown_service.wait_for_live_battle_room(
+            WaitForLiveBattleRoomRequest::new(Duration::from_millis(50))).await;
+        assert_eq!(response.get_remaining_battle_room_id_list(), &vec![battle_room_id]);
+
+        battle_room_repository.lock().await.remove_battle_room_player(1).await;
+        battle_room_repository.lock().await.remove_battle_room_player(2).await;
+
+        let response = server_shutdown_service.wait_for_live_battle_room(
+            WaitForLiveBattleRoomRequest::new(Duration::from_millis(50))).await;
+        assert!(response.get_remaining_battle_room_id_list().is_empty());
+    }
+}
@@ -7,4 +7,6 @@ use async_trait::async_trait;
 pub trait ServerSocketRepository {
     async fn bind_socket(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>>;
     async fn get_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>>;
+    async fn bind_web_socket_socket(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>>;
+    async fn get_web_socket_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>>;
 }
\ No newline at end of file
@@ -8,12 +8,14 @@ use crate::server_socket::repository::server_socket_repository::ServerSocketRepo
 #[derive(Debug)]
 pub struct ServerSocketRepositoryImpl {
     listener: Option<Arc<AsyncMutex<TcpListener>>>,
+    web_socket_listener: Option<Arc<AsyncMutex<TcpListener>>>,
 }
 
 impl Clone for ServerSocketRepositoryImpl {
     fn clone(&self) -> Self {
         ServerSocketRepositoryImpl {
             listener: None,
+            web_socket_listener: None,
         }
     }
 }
@@ -22,7 +24,8 @@ impl Eq for ServerSocketRepositoryImpl {}
 
 impl PartialEq for ServerSocketRepositoryImpl {
     fn eq(&self, other: &Self) -> bool {
-        self.listener.is_none() && other.listener.is_none()
+        self.listener.is_none() && other.listener.is_none() &&
+            self.web_socket_listener.is_none() && other.web_socket_listener.is_none()
     }
 }
 
@@ -30,6 +33,7 @@ impl ServerSocketRepositoryImpl {
     pub fn new() -> Self {
         ServerSocketRepositoryImpl {
             listener: None,
+            web_socket_listener: None,
         }
     }
 
@@ -61,6 +65,21 @@ impl ServerSocketRepository for ServerSocketRepositoryImpl {
     async fn get_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>> {
         self.listener.clone()
     }
+
+    async fn bind_web_socket_socket(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let listener = TcpListener::bind(address)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        println!("WebSocket listener bound successfully: {}", listener.local_addr().unwrap());
+        self.web_socket_listener = Some(Arc::new(AsyncMutex::new(listener)));
+
+        Ok(())
+    }
+
+    async fn get_web_socket_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>> {
+        self.web_socket_listener.clone()
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +126,18 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_bind_web_socket_socket_separately() {
+        let mut repository = ServerSocketRepositoryImpl::new();
+        let address = "127.0.0.1:37374";
+
+        repository.bind_web_socket_socket(address).await.expect("Error binding web socket");
+
+        assert!(repository.get_listener().await.is_none());
+        let web_socket_listener = repository.get_web_socket_listener().await.unwrap();
+        assert_eq!(web_socket_listener.lock().await.local_addr().unwrap().to_string(), address);
+    }
+
     #[tokio::test]
     async fn test_get_instance() {
         let instance1 = ServerSocketRepositoryImpl::get_instance();
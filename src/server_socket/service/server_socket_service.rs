@@ -7,4 +7,6 @@ use tokio::sync::Mutex as AsyncMutex;
 pub trait ServerSocketService {
     async fn server_socket_bind(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>>;
     async fn get_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>>;
+    async fn web_socket_server_socket_bind(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>>;
+    async fn get_web_socket_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>>;
 }
\ No newline at end of file
@@ -40,6 +40,16 @@ impl ServerSocketService for ServerSocketServiceImpl {
         let repository_guard = self.repository.lock().await;
         repository_guard.get_listener().await
     }
+
+    async fn web_socket_server_socket_bind(&mut self, address: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut repository_guard = self.repository.lock().await;
+        repository_guard.bind_web_socket_socket(address).await
+    }
+
+    async fn get_web_socket_listener(&self) -> Option<Arc<AsyncMutex<TcpListener>>> {
+        let repository_guard = self.repository.lock().await;
+        repository_guard.get_web_socket_listener().await
+    }
 }
 
 impl AsRef<ServerSocketRepositoryImpl> for ServerSocketRepositoryImpl {
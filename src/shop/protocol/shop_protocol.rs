@@ -0,0 +1,45 @@
+use crate::protocol_registry::entity::protocol_registry::ProtocolRegistry;
+use crate::request_generator::shop_request_generator::{create_data_to_display_in_shop_request, create_event_distribute_cards_request_form, create_execute_free_gacha_request_form, create_execute_shop_gacha_request_form};
+use crate::response_generator::response_type::ResponseType;
+use crate::shop::controller::shop_controller::ShopController;
+use crate::shop::controller::shop_controller_impl::ShopControllerImpl;
+use crate::shop::service::shop_service::ShopService;
+use crate::shop::service::shop_service_impl::ShopServiceImpl;
+
+pub fn register_shop_protocol(protocol_registry: &mut ProtocolRegistry) {
+    // Shop Data Display
+    protocol_registry.register(71, "SHOP_DATA", create_data_to_display_in_shop_request, |request| async move {
+        let shop_service_mutex = ShopServiceImpl::get_instance();
+        let shop_service = shop_service_mutex.lock().await;
+
+        let response = shop_service.data_to_display_in_shop(request).await;
+        ResponseType::SHOP_DATA(response)
+    });
+
+    // Shop Get Specific Race Card Default
+    protocol_registry.register(72, "SHOP_GACHA", create_execute_shop_gacha_request_form, |request| async move {
+        let shop_controller_mutex = ShopControllerImpl::get_instance();
+        let shop_controller = shop_controller_mutex.lock().await;
+
+        let response = shop_controller.execute_shop_gacha(request).await;
+        ResponseType::SHOP_GACHA(response)
+    });
+
+    // Shop Free Get Specific Race Card
+    protocol_registry.register(73, "FREE_GACHA", create_execute_free_gacha_request_form, |request| async move {
+        let shop_controller_mutex = ShopControllerImpl::get_instance();
+        let shop_controller = shop_controller_mutex.lock().await;
+
+        let response = shop_controller.execute_free_gacha(request).await;
+        ResponseType::FREE_GACHA(response)
+    });
+
+    // Shop Distirbute Cards Deck
+    protocol_registry.register(90, "EVENT_DISTRIBUTE_CARDS", create_event_distribute_cards_request_form, |request| async move {
+        let shop_controller_mutex = ShopControllerImpl::get_instance();
+        let shop_controller = shop_controller_mutex.lock().await;
+
+        let response = shop_controller.event_distribute_cards(request).await;
+        ResponseType::EVENT_DISTRIBUTE_CARDS(response)
+    });
+}
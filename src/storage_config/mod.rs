@@ -0,0 +1,7 @@
+pub mod storage_backend_type;
+pub mod storage_connection;
+pub mod storage_connection_manager;
+pub mod storage_connection_pool;
+pub mod storage_pool_config;
+pub mod sqlite_migration;
+pub mod mysql_migration;
@@ -0,0 +1,16 @@
+use diesel::MysqlConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+// 계정 관련 기존 테이블은 MySQL 에 이미 존재하므로 이후 추가된 테이블만 마이그레이션으로 관리
+pub const MYSQL_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql");
+
+pub fn run_mysql_migrations(connection: &mut MysqlConnection) {
+    match connection.run_pending_migrations(MYSQL_MIGRATIONS) {
+        Ok(applied_migration_list) => {
+            for applied_migration in applied_migration_list {
+                println!("MySQL migration applied: {}", applied_migration);
+            }
+        }
+        Err(e) => panic!("MySQL migration 실패: {:?}", e),
+    }
+}
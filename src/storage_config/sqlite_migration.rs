@@ -0,0 +1,15 @@
+use diesel::SqliteConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+pub const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite");
+
+pub fn run_sqlite_migrations(connection: &mut SqliteConnection) {
+    match connection.run_pending_migrations(SQLITE_MIGRATIONS) {
+        Ok(applied_migration_list) => {
+            for applied_migration in applied_migration_list {
+                println!("SQLite migration applied: {}", applied_migration);
+            }
+        }
+        Err(e) => panic!("SQLite migration 실패: {:?}", e),
+    }
+}
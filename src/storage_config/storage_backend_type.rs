@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageBackendType {
+    Mysql,
+    Sqlite,
+}
+
+impl StorageBackendType {
+    // 알 수 없는 값은 ServerConfig 검증 단계에서 오류로 보고 (미설정 시 기본값 MySQL)
+    pub fn from_config_value(value: &str) -> Option<StorageBackendType> {
+        match value.trim().to_lowercase().as_str() {
+            "sqlite" => Some(StorageBackendType::Sqlite),
+            "mysql" => Some(StorageBackendType::Mysql),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config_value() {
+        assert_eq!(StorageBackendType::from_config_value("SQLite"), Some(StorageBackendType::Sqlite));
+        assert_eq!(StorageBackendType::from_config_value("mysql"), Some(StorageBackendType::Mysql));
+        assert_eq!(StorageBackendType::from_config_value("postgres"), None);
+    }
+}
@@ -0,0 +1,49 @@
+use diesel::{Connection, ConnectionResult, MysqlConnection, SqliteConnection};
+use diesel::connection::SimpleConnection;
+use crate::common::env::env_detector::EnvDetector;
+use crate::server_config::server_config::ServerConfig;
+use crate::storage_config::storage_backend_type::StorageBackendType;
+
+const DEFAULT_SQLITE_DATABASE_URL: &str = "tcg_card_game.sqlite3";
+const SQLITE_BUSY_TIMEOUT_MILLISECONDS: u32 = 5000;
+
+// 동일한 diesel 쿼리를 MySQL, SQLite 양쪽에서 실행할 수 있도록 하는 연결
+#[derive(diesel::MultiConnection)]
+pub enum StorageConnection {
+    Mysql(MysqlConnection),
+    Sqlite(SqliteConnection),
+}
+
+impl StorageConnection {
+    pub fn get_backend_type() -> StorageBackendType {
+        ServerConfig::get_instance().get_storage_backend()
+    }
+
+    pub fn get_database_url(backend_type: StorageBackendType) -> String {
+        match backend_type {
+            StorageBackendType::Mysql => ServerConfig::get_instance().get_database_url()
+                .cloned()
+                .expect("DATABASE_URL이 설정되어 있어야 합니다."),
+            StorageBackendType::Sqlite => EnvDetector::get_sqlite_database_url()
+                .unwrap_or(DEFAULT_SQLITE_DATABASE_URL.to_string()),
+        }
+    }
+
+    // MultiConnection::establish 는 URL 로 백엔드를 추측하므로 설정된 백엔드로만 연결하도록 분리
+    pub fn establish_backend(backend_type: StorageBackendType, database_url: &str) -> ConnectionResult<StorageConnection> {
+        match backend_type {
+            StorageBackendType::Mysql => {
+                let mysql_connection = MysqlConnection::establish(database_url)?;
+                Ok(StorageConnection::Mysql(mysql_connection))
+            }
+            StorageBackendType::Sqlite => {
+                let mut sqlite_connection = SqliteConnection::establish(database_url)?;
+                // 풀에서 여러 연결이 같은 파일을 쓰므로 잠금 시 즉시 실패하지 않고 대기
+                sqlite_connection
+                    .batch_execute(&format!("PRAGMA busy_timeout = {};", SQLITE_BUSY_TIMEOUT_MILLISECONDS))
+                    .map_err(diesel::ConnectionError::CouldntSetupConfiguration)?;
+                Ok(StorageConnection::Sqlite(sqlite_connection))
+            }
+        }
+    }
+}
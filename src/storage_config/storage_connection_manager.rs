@@ -0,0 +1,54 @@
+use diesel::r2d2::{ManageConnection, R2D2Connection};
+
+use crate::storage_config::storage_backend_type::StorageBackendType;
+use crate::storage_config::storage_connection::StorageConnection;
+
+#[derive(Debug)]
+pub enum StorageConnectionManagerError {
+    ConnectionError(diesel::ConnectionError),
+    QueryError(diesel::result::Error),
+}
+
+impl std::fmt::Display for StorageConnectionManagerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageConnectionManagerError::ConnectionError(e) => write!(f, "{}", e),
+            StorageConnectionManagerError::QueryError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StorageConnectionManagerError {}
+
+pub struct StorageConnectionManager {
+    backend_type: StorageBackendType,
+    database_url: String,
+}
+
+impl StorageConnectionManager {
+    pub fn new(backend_type: StorageBackendType, database_url: String) -> Self {
+        StorageConnectionManager { backend_type, database_url }
+    }
+
+    pub fn get_backend_type(&self) -> StorageBackendType {
+        self.backend_type
+    }
+}
+
+impl ManageConnection for StorageConnectionManager {
+    type Connection = StorageConnection;
+    type Error = StorageConnectionManagerError;
+
+    fn connect(&self) -> Result<StorageConnection, StorageConnectionManagerError> {
+        StorageConnection::establish_backend(self.backend_type, &self.database_url)
+            .map_err(StorageConnectionManagerError::ConnectionError)
+    }
+
+    fn is_valid(&self, connection: &mut StorageConnection) -> Result<(), StorageConnectionManagerError> {
+        connection.ping().map_err(StorageConnectionManagerError::QueryError)
+    }
+
+    fn has_broken(&self, connection: &mut StorageConnection) -> bool {
+        connection.is_broken()
+    }
+}
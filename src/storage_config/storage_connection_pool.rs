@@ -0,0 +1,80 @@
+use diesel::r2d2::{Pool, PooledConnection};
+use diesel::result::{DatabaseErrorKind, Error};
+use lazy_static::lazy_static;
+
+use crate::storage_config::mysql_migration::run_mysql_migrations;
+use crate::storage_config::sqlite_migration::run_sqlite_migrations;
+use crate::storage_config::storage_backend_type::StorageBackendType;
+use crate::storage_config::storage_connection::StorageConnection;
+use crate::storage_config::storage_connection_manager::StorageConnectionManager;
+use crate::server_config::server_config::ServerConfig;
+
+pub struct StorageConnectionPool;
+
+impl StorageConnectionPool {
+    pub fn get_pool() -> Pool<StorageConnectionManager> {
+        lazy_static! {
+            static ref POOL: Pool<StorageConnectionManager> = StorageConnectionPool::build_pool();
+        }
+        POOL.clone()
+    }
+
+    fn build_pool() -> Pool<StorageConnectionManager> {
+        let backend_type = StorageConnection::get_backend_type();
+        let database_url = StorageConnection::get_database_url(backend_type);
+        let server_config = ServerConfig::get_instance();
+        let pool_config = server_config.get_storage_pool_config();
+        println!("StorageConnectionPool: build_pool() backend: {:?}, config: {:?}", backend_type, pool_config);
+
+        let pool = Pool::builder()
+            .max_size(pool_config.get_max_size())
+            .min_idle(pool_config.get_min_idle())
+            .connection_timeout(pool_config.get_connection_timeout())
+            .idle_timeout(pool_config.get_idle_timeout())
+            .build(StorageConnectionManager::new(backend_type, database_url))
+            .expect("Failed to build storage connection pool");
+
+        if backend_type == StorageBackendType::Sqlite {
+            let mut connection = pool.get().expect("Failed to get sqlite connection for migration");
+            if let StorageConnection::Sqlite(sqlite_connection) = &mut *connection {
+                run_sqlite_migrations(sqlite_connection);
+            }
+        }
+
+        if backend_type == StorageBackendType::Mysql {
+            let mut connection = pool.get().expect("Failed to get mysql connection for migration");
+            if let StorageConnection::Mysql(mysql_connection) = &mut *connection {
+                run_mysql_migrations(mysql_connection);
+            }
+        }
+
+        pool
+    }
+
+    pub fn get_connection() -> Result<PooledConnection<StorageConnectionManager>, Error> {
+        Self::get_pool().get().map_err(|e| {
+            eprintln!("StorageConnectionPool: failed to get connection: {:?}", e);
+            Error::DatabaseError(DatabaseErrorKind::UnableToSendCommand, Box::new(e.to_string()))
+        })
+    }
+
+    // diesel 은 블로킹 I/O 이므로 tokio 워커를 막지 않도록 blocking 스레드에서 쿼리 실행
+    pub async fn run_blocking<F, R>(job: F) -> Result<R, Error>
+        where F: FnOnce(&mut StorageConnection) -> Result<R, Error> + Send + 'static,
+              R: Send + 'static {
+
+        let join_result = tokio::task::spawn_blocking(move || {
+            let mut connection = Self::get_connection()?;
+            job(&mut connection)
+        }).await;
+
+        match join_result {
+            Ok(result) => result,
+            Err(join_error) => match join_error.try_into_panic() {
+                Ok(panic_payload) => std::panic::resume_unwind(panic_payload),
+                Err(join_error) => Err(Error::DatabaseError(
+                    DatabaseErrorKind::UnableToSendCommand, Box::new(join_error.to_string()))),
+            },
+        }
+    }
+}
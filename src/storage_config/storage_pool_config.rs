@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoragePoolConfig {
+    max_size: u32,
+    min_idle: Option<u32>,
+    connection_timeout: Duration,
+    idle_timeout: Option<Duration>,
+}
+
+impl StoragePoolConfig {
+    // 값 검증은 ServerConfig 에서 끝내며, 여기서는 min_idle 이 max_size 를 넘지 않도록만 보정
+    pub fn new(max_size: u32, min_idle: Option<u32>, connection_timeout: Duration, idle_timeout: Option<Duration>) -> Self {
+        StoragePoolConfig {
+            max_size,
+            min_idle: min_idle.map(|min_idle| min_idle.min(max_size)),
+            connection_timeout,
+            idle_timeout,
+        }
+    }
+
+    pub fn get_max_size(&self) -> u32 {
+        self.max_size
+    }
+
+    pub fn get_min_idle(&self) -> Option<u32> {
+        self.min_idle
+    }
+
+    pub fn get_connection_timeout(&self) -> Duration {
+        self.connection_timeout
+    }
+
+    pub fn get_idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_idle_is_capped_by_max_size() {
+        let config = StoragePoolConfig::new(10, Some(50), Duration::from_secs(5), None);
+
+        assert_eq!(config.get_max_size(), 10);
+        assert_eq!(config.get_min_idle(), Some(10));
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use tokio::task::JoinHandle;
 
 use crate::thread_worker::entity::closure::Closure;
 use crate::thread_worker::entity::custom_future::CustomFuture;
@@ -8,6 +9,7 @@ use crate::thread_worker::entity::custom_future::CustomFuture;
 pub struct ThreadWorker {
     name: String,
     will_be_execute_function: Option<Closure>,
+    join_handle: Option<JoinHandle<()>>,
 }
 
 impl ThreadWorker {
@@ -15,6 +17,7 @@ impl ThreadWorker {
         ThreadWorker {
             name: name.to_string(),
             will_be_execute_function: None,
+            join_handle: None,
         }
     }
 
@@ -41,6 +44,15 @@ impl ThreadWorker {
         }
     }
 
+    // 실행 중인 async worker 를 멈추고 기다릴 수 있도록 보관
+    pub fn save_join_handle(&mut self, join_handle: JoinHandle<()>) {
+        self.join_handle = Some(join_handle);
+    }
+
+    pub fn take_join_handle(&mut self) -> Option<JoinHandle<()>> {
+        self.join_handle.take()
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
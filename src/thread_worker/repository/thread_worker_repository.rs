@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use tokio::task::JoinHandle;
 use crate::thread_worker::entity::closure::Closure;
 
 #[async_trait]
@@ -9,4 +10,5 @@ pub trait ThreadWorkerRepositoryTrait {
         will_be_execute_function: Option<Closure>,
     );
     async fn start_thread_worker(&self, name: &str);
+    fn take_thread_worker_join_handle(&mut self, name: &str) -> Option<JoinHandle<()>>;
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use tokio::spawn;
+use tokio::task::JoinHandle;
 use crate::thread_worker::entity::closure::Closure;
 use crate::thread_worker::entity::thread_worker::ThreadWorker;
 use crate::thread_worker::repository::thread_worker_repository::ThreadWorkerRepositoryTrait;
@@ -54,12 +55,17 @@ impl ThreadWorkerRepositoryTrait for ThreadWorkerRepositoryImpl {
             println!("check worker name: {}", worker.name());
             if let Some(closure) = worker.get_will_be_execute_function() {
                 println!("found closure!");
-                spawn(closure.0);
+                let join_handle = spawn(closure.0);
+                worker.save_join_handle(join_handle);
             }
         } else {
             panic!("Thread worker not found: {}", name);
         }
     }
+
+    fn take_thread_worker_join_handle(&mut self, name: &str) -> Option<JoinHandle<()>> {
+        self.thread_worker_list.lock().unwrap().get_mut(name)?.take_join_handle()
+    }
 }
 
 #[cfg(test)]
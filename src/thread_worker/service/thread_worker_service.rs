@@ -11,5 +11,6 @@ pub trait ThreadWorkerServiceTrait {
         where
             F: FnOnce() -> () + 'static;
     async fn start_thread_worker(&self, name: &str);
+    async fn stop_thread_worker(&self, name: &str) -> bool;
 }
 
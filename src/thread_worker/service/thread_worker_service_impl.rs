@@ -59,6 +59,25 @@ impl ThreadWorkerServiceTrait for ThreadWorkerServiceImpl {
             });
         });
     }
+
+    // 실행 중인 worker 를 취소하고 실제로 종료될 때까지 기다림
+    async fn stop_thread_worker(&self, name: &str) -> bool {
+        println!("ThreadWorkerServiceImpl: stop_thread_worker() -> {}", name);
+
+        let join_handle = match self.repository.lock().unwrap().take_thread_worker_join_handle(name) {
+            Some(join_handle) => join_handle,
+            None => {
+                println!("Thread worker {} is not running", name);
+                return false
+            }
+        };
+
+        join_handle.abort();
+        match join_handle.await {
+            Ok(()) => true,
+            Err(join_error) => join_error.is_cancelled(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +155,27 @@ mod tests {
         service.start_thread_worker("SyncTestWorker2").await;
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_stop_thread_worker() {
+        let thread_worker_repository = Arc::new(Mutex::new(ThreadWorkerRepositoryImpl::new()));
+        let mut service = ThreadWorkerServiceImpl::new(thread_worker_repository);
+
+        let endless_function = || -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            Box::pin(async {
+                loop {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                }
+            })
+        };
+
+        service.save_async_thread_worker("EndlessTestWorker", Box::new(endless_function));
+        service.start_thread_worker("EndlessTestWorker").await;
+
+        assert!(service.stop_thread_worker("EndlessTestWorker").await);
+        assert!(!service.stop_thread_worker("EndlessTestWorker").await);
+        assert!(!service.stop_thread_worker("UnknownTestWorker").await);
+    }
+
     #[test]
     async fn test_singleton() {
         let instance1 = ThreadWorkerServiceImpl::get_instance();
@@ -1,3 +1,4 @@
+use std::io::ErrorKind;
 use std::sync::Arc;
 use std::time::Duration;
 use async_trait::async_trait;
@@ -7,10 +8,15 @@ use lazy_static::lazy_static;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::{Mutex as AsyncMutex, Mutex};
 use tokio::time::timeout;
+use crate::common::frame::length_prefixed_frame_codec::LengthPrefixedFrameCodec;
 use crate::connection_context::repository::connection_context_repository::ConnectionContextRepository;
 use crate::connection_context::repository::connection_context_repository_impl::ConnectionContextRepositoryImpl;
 use crate::domain_initializer::initializer::{AcceptorTransmitterChannel, ReceiverTransmitterLegacyChannel};
 use crate::match_waiting_timer::repository::match_waiting_timer_repository_impl::MatchWaitingTimerRepositoryImpl;
+use crate::protocol_handshake::entity::negotiated_protocol::NegotiatedProtocol;
+use crate::protocol_handshake::service::protocol_handshake_service::ProtocolHandshakeService;
+use crate::protocol_handshake::service::protocol_handshake_service_impl::ProtocolHandshakeServiceImpl;
+use crate::protocol_handshake::service::request::find_negotiated_protocol_request::FindNegotiatedProtocolRequest;
 use crate::redis::repository::redis_in_memory_repository::RedisInMemoryRepository;
 use crate::redis::repository::redis_in_memory_repository_impl::RedisInMemoryRepositoryImpl;
 use crate::response_generator::response_type::ResponseType;
@@ -119,6 +125,7 @@ impl TransmitterRepository for TransmitterRepositoryImpl {
             // 변경된 부분: tokio::task::spawn을 사용하여 각 클라이언트에 대한 통신을 별도의 태스크로 분리
             tokio::task::spawn(async move {
                 println!("Transmitter transmit() loop");
+                let frame_codec = LengthPrefixedFrameCodec::from_env();
 
                 // Arc<ReceiverTransmitterLegacyChannel>
                 if let receiver_transmitter_channel = receiver_transmitter_channel_clone {
@@ -138,17 +145,24 @@ impl TransmitterRepository for TransmitterRepositoryImpl {
                                     let mut client_socket_stream = stream_arc.lock().await;
                                     println!("Transmitter lock socket");
 
-                                    // MutexGaurd<ResponseType>
+                                    // MutexGaurd<ResponseEnvelope>
                                     let response_data = response.lock().await;
-                                    let json_data = serde_json::to_string(&*response_data).expect("Failed to serialize to JSON");
+                                    let negotiated_protocol = find_negotiated_protocol(client_socket.address()).await;
+                                    let json_data = response_data.to_versioned_json(&negotiated_protocol).to_string();
 
                                     println!("Transmitting data: {}", json_data);
                                     println!("Transmitted time: {}.{} sec", Utc::now().second(), Utc::now().timestamp_subsec_millis());
 
-                                    client_socket_stream.write_all(json_data.as_bytes()).await.expect("Failed to write to client");
+                                    match client_socket_stream.write_frame(&frame_codec, json_data.as_bytes()).await {
+                                        Ok(()) => {}
+                                        Err(err) if err.kind() == ErrorKind::InvalidInput => {
+                                            eprintln!("Failed to encode frame: {:?}", err);
+                                        }
+                                        Err(err) => panic!("Failed to write to client: {:?}", err),
+                                    }
 
                                     // TODO: Dirty <- Need to Refactor!
-                                    if let ResponseType::ACCOUNT_LOGIN(login_response) = &*response_data {
+                                    if let ResponseType::ACCOUNT_LOGIN(login_response) = response_data.get_response() {
                                         if login_response.get_redis_token() != "" {
                                             println!("로그인 성공: Connection Context 생성");
 
@@ -166,7 +180,19 @@ impl TransmitterRepository for TransmitterRepositoryImpl {
                                         }
                                     }
 
-                                    if let ResponseType::FAKE_BATTLE_ROOM_CREATION(create_fake_battle_room_response_form) = &*response_data {
+                                    // 재접속한 소켓으로 게임 알림이 전달되도록 Connection Context 를 교체
+                                    if let ResponseType::RESUME_BATTLE(resume_battle_response_form) = response_data.get_response() {
+                                        if resume_battle_response_form.is_success() {
+                                            println!("전투 재개 성공: Connection Context 교체");
+
+                                            let mut connection_context_repository = connection_context_repository_clone.lock().await;
+                                            connection_context_repository.add_connection_context(resume_battle_response_form.get_account_unique_id(),
+                                                                                                 Arc::new(AsyncMutex::new(client_socket.clone()))).await;
+                                            drop(connection_context_repository);
+                                        }
+                                    }
+
+                                    if let ResponseType::FAKE_BATTLE_ROOM_CREATION(create_fake_battle_room_response_form) = response_data.get_response() {
                                         let fake_your_session = create_fake_battle_room_response_form.get_first_fake_session();
                                         let fake_opponent_session = create_fake_battle_room_response_form.get_second_fake_session();
 
@@ -196,7 +222,7 @@ impl TransmitterRepository for TransmitterRepositoryImpl {
                                         }
                                     }
 
-                                    if let ResponseType::PROGRAM_EXIT(exit_response) = &*response_data {
+                                    if let ResponseType::PROGRAM_EXIT(exit_response) = response_data.get_response() {
                                         if exit_response.does_client_exit_success() {
                                             println!("종료 요청 수신: 전용 Transmitter 종료");
                                             break;
@@ -235,3 +261,15 @@ impl TransmitterRepository for TransmitterRepositoryImpl {
     }
 }
 
+
+// 아직 handshake 전이라면 현재 서버 버전 형태로 보냄
+pub async fn find_negotiated_protocol(client_address: &str) -> NegotiatedProtocol {
+    let protocol_handshake_service_mutex = ProtocolHandshakeServiceImpl::get_instance();
+    let protocol_handshake_service_guard = protocol_handshake_service_mutex.lock().await;
+    let find_negotiated_protocol_response =
+        protocol_handshake_service_guard.find_negotiated_protocol(
+            FindNegotiatedProtocolRequest::new(client_address.to_string())).await;
+
+    find_negotiated_protocol_response.get_negotiated_protocol().cloned()
+        .unwrap_or_else(NegotiatedProtocol::current)
+}
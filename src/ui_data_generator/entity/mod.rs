@@ -1,6 +1,7 @@
 pub mod player_index_enum;
 pub mod field_unit_info;
 pub mod used_hand_card_info;
+pub mod summoned_token_unit_info;
 pub mod attached_energy_info;
 pub mod player_field_unit_info;
 pub mod player_hand_card_use_info;
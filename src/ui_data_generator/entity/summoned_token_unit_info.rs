@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummonedTokenUnitInfo {
+    token_card_id: i32,
+    placed_unit_index: i32
+}
+
+impl SummonedTokenUnitInfo {
+    pub fn new(token_card_id: i32,
+               placed_unit_index: i32) -> Self {
+        SummonedTokenUnitInfo {
+            token_card_id,
+            placed_unit_index
+        }
+    }
+
+    pub fn get_token_card_id(&self) -> i32 { self.token_card_id }
+
+    pub fn get_placed_unit_index(&self) -> i32 { self.placed_unit_index }
+}
\ No newline at end of file
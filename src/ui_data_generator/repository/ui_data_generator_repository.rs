@@ -203,4 +203,9 @@ pub trait UiDataGeneratorRepository {
         passive_skill_index: i32,
     ) -> (PlayerFieldUnitAttackInfo,
           PlayerFieldUnitAttackInfo);
+    async fn generate_opponent_trap_card_trigger_data(
+        &mut self,
+        opponent_trap_card_id: i32
+    ) -> (PlayerHandCardUseInfo,
+          PlayerHandCardUseInfo);
 }
\ No newline at end of file
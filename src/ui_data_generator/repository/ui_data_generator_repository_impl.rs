@@ -210,6 +210,19 @@ impl UiDataGeneratorRepositoryImpl {
         PlayerHandCardUseInfo::new(player_hand_use_map)
     }
 
+    // 함정은 세팅 시점에는 숨기고 발동 시점에만 카드 번호를 공개
+    fn get_player_trap_card_trigger_info(&self,
+                                         notify_player_index: PlayerIndex,
+                                         triggered_trap_card_id: i32
+    ) -> PlayerHandCardUseInfo {
+
+        let triggered_trap_card_info = UsedHandCardInfo::new(triggered_trap_card_id, KindsEnum::Trap as i32);
+        let mut player_trap_card_trigger_map = HashMap::new();
+        player_trap_card_trigger_map.insert(notify_player_index, triggered_trap_card_info);
+
+        PlayerHandCardUseInfo::new(player_trap_card_trigger_map)
+    }
+
     fn get_player_deck_card_list_use_info(&self,
                                           notify_player_index: PlayerIndex,
                                           used_deck_card_list: Vec<i32>
@@ -1048,4 +1061,20 @@ impl UiDataGeneratorRepository for UiDataGeneratorRepositoryImpl {
          player_field_unit_attack_info_for_notice)
     }
 
-}
\ No newline at end of file
+    async fn generate_opponent_trap_card_trigger_data(
+        &mut self,
+        opponent_trap_card_id: i32
+    ) -> (PlayerHandCardUseInfo,
+          PlayerHandCardUseInfo) {
+
+        println!("UiDataGeneratorRepositoryImpl: generate_opponent_trap_card_trigger_data()");
+
+        let player_trap_card_trigger_info_for_response =
+            self.get_player_trap_card_trigger_info(Opponent, opponent_trap_card_id);
+        let player_trap_card_trigger_info_for_notice =
+            self.get_player_trap_card_trigger_info(You, opponent_trap_card_id);
+
+        (player_trap_card_trigger_info_for_response,
+         player_trap_card_trigger_info_for_notice)
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(Debug)]
+pub struct GenerateGameEventReactionDataRequest {
+    my_unit_health_point_tuple_list: Vec<(i32, i32)>,
+    opponent_unit_health_point_tuple_list: Vec<(i32, i32)>,
+    my_dead_unit_index_list: Vec<i32>,
+    opponent_dead_unit_index_list: Vec<i32>,
+}
+
+impl GenerateGameEventReactionDataRequest {
+    pub fn new(my_unit_health_point_tuple_list: Vec<(i32, i32)>,
+               opponent_unit_health_point_tuple_list: Vec<(i32, i32)>,
+               my_dead_unit_index_list: Vec<i32>,
+               opponent_dead_unit_index_list: Vec<i32>) -> Self {
+
+        GenerateGameEventReactionDataRequest {
+            my_unit_health_point_tuple_list,
+            opponent_unit_health_point_tuple_list,
+            my_dead_unit_index_list,
+            opponent_dead_unit_index_list,
+        }
+    }
+
+    pub fn get_my_unit_health_point_tuple_list(&self) -> Vec<(i32, i32)> {
+        self.my_unit_health_point_tuple_list.clone()
+    }
+
+    pub fn get_opponent_unit_health_point_tuple_list(&self) -> Vec<(i32, i32)> {
+        self.opponent_unit_health_point_tuple_list.clone()
+    }
+
+    pub fn get_my_dead_unit_index_list(&self) -> Vec<i32> {
+        self.my_dead_unit_index_list.clone()
+    }
+
+    pub fn get_opponent_dead_unit_index_list(&self) -> Vec<i32> {
+        self.opponent_dead_unit_index_list.clone()
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Debug)]
+pub struct GenerateOpponentTrapCardTriggerDataRequest {
+    triggered_trap_card_id: i32,
+}
+
+impl GenerateOpponentTrapCardTriggerDataRequest {
+    pub fn new(triggered_trap_card_id: i32) -> Self {
+        GenerateOpponentTrapCardTriggerDataRequest {
+            triggered_trap_card_id,
+        }
+    }
+
+    pub fn get_triggered_trap_card_id(&self) -> i32 { self.triggered_trap_card_id }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::field_unit_death_info::FieldUnitDeathInfo;
+use crate::ui_data_generator::entity::field_unit_health_point_info::FieldUnitHealthPointInfo;
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateGameEventReactionDataResponse {
+    player_field_unit_health_point_map_for_response: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+    player_field_unit_death_map_for_response: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+    player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+}
+
+impl GenerateGameEventReactionDataResponse {
+    pub fn new(player_field_unit_health_point_map_for_response: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_health_point_map_for_notice: HashMap<PlayerIndex, FieldUnitHealthPointInfo>,
+               player_field_unit_death_map_for_response: HashMap<PlayerIndex, FieldUnitDeathInfo>,
+               player_field_unit_death_map_for_notice: HashMap<PlayerIndex, FieldUnitDeathInfo>) -> Self {
+
+        GenerateGameEventReactionDataResponse {
+            player_field_unit_health_point_map_for_response,
+            player_field_unit_health_point_map_for_notice,
+            player_field_unit_death_map_for_response,
+            player_field_unit_death_map_for_notice,
+        }
+    }
+
+    // 반응으로 바뀐 유닛이 하나도 없으면 알릴 필요가 없음
+    pub fn is_empty(&self) -> bool {
+        self.player_field_unit_health_point_map_for_response.is_empty() &&
+            self.player_field_unit_death_map_for_response.is_empty()
+    }
+
+    pub fn get_player_field_unit_health_point_map_for_response(&self) -> &HashMap<PlayerIndex, FieldUnitHealthPointInfo> {
+        &self.player_field_unit_health_point_map_for_response
+    }
+
+    pub fn get_player_field_unit_health_point_map_for_notice(&self) -> &HashMap<PlayerIndex, FieldUnitHealthPointInfo> {
+        &self.player_field_unit_health_point_map_for_notice
+    }
+
+    pub fn get_player_field_unit_death_map_for_response(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
+        &self.player_field_unit_death_map_for_response
+    }
+
+    pub fn get_player_field_unit_death_map_for_notice(&self) -> &HashMap<PlayerIndex, FieldUnitDeathInfo> {
+        &self.player_field_unit_death_map_for_notice
+    }
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::ui_data_generator::entity::player_index_enum::PlayerIndex;
+use crate::ui_data_generator::entity::used_hand_card_info::UsedHandCardInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateOpponentTrapCardTriggerDataResponse {
+    player_trap_card_trigger_map_for_response: HashMap<PlayerIndex, UsedHandCardInfo>,
+    player_trap_card_trigger_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>,
+}
+
+impl GenerateOpponentTrapCardTriggerDataResponse {
+    pub fn new(
+        player_trap_card_trigger_map_for_response: HashMap<PlayerIndex, UsedHandCardInfo>,
+        player_trap_card_trigger_map_for_notice: HashMap<PlayerIndex, UsedHandCardInfo>,) -> Self {
+        GenerateOpponentTrapCardTriggerDataResponse {
+            player_trap_card_trigger_map_for_response,
+            player_trap_card_trigger_map_for_notice,
+        }
+    }
+
+    pub fn get_player_trap_card_trigger_map_for_response(&self) -> &HashMap<PlayerIndex, UsedHandCardInfo> {
+        &self.player_trap_card_trigger_map_for_response
+    }
+
+    pub fn get_player_trap_card_trigger_map_for_notice(&self) -> &HashMap<PlayerIndex, UsedHandCardInfo> {
+        &self.player_trap_card_trigger_map_for_notice
+    }
+}
\ No newline at end of file
@@ -30,4 +30,6 @@ pub mod generate_opponent_specific_unit_harmful_effect_data_response;
 pub mod generate_opponent_multiple_unit_harmful_effect_data_response;
 pub mod generate_my_specific_unit_basic_attack_data_response;
 pub mod generate_my_specific_unit_active_skill_use_data_response;
-pub mod generate_my_specific_unit_passive_skill_use_data_response;
\ No newline at end of file
+pub mod generate_my_specific_unit_passive_skill_use_data_response;
+pub mod generate_opponent_trap_card_trigger_data_response;
+pub mod generate_game_event_reaction_data_response;
\ No newline at end of file
@@ -19,6 +19,7 @@ use crate::ui_data_generator::service::request::generate_my_specific_unit_extra_
 use crate::ui_data_generator::service::request::generate_my_specific_unit_harmful_effect_data_request::GenerateMySpecificUnitHarmfulEffectDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_health_point_data_request::GenerateMySpecificUnitHealthPointDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_passive_skill_use_data_request::GenerateMySpecificUnitPassiveSkillUseDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_trap_card_trigger_data_request::GenerateOpponentTrapCardTriggerDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_deck_card_lost_data_request::GenerateOpponentDeckCardLostDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_field_energy_data_request::GenerateOpponentFieldEnergyDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_main_character_health_point_data_request::GenerateOpponentMainCharacterHealthPointDataRequest;
@@ -52,6 +53,7 @@ use crate::ui_data_generator::service::response::generate_my_specific_unit_extra
 use crate::ui_data_generator::service::response::generate_my_specific_unit_harmful_effect_data_response::GenerateMySpecificUnitHarmfulEffectDataResponse;
 use crate::ui_data_generator::service::response::generate_my_specific_unit_health_point_data_response::GenerateMySpecificUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_my_specific_unit_passive_skill_use_data_response::GenerateMySpecificUnitPassiveSkillUseDataResponse;
+use crate::ui_data_generator::service::response::generate_opponent_trap_card_trigger_data_response::GenerateOpponentTrapCardTriggerDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_deck_card_lost_data_response::GenerateOpponentDeckCardLostDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_field_energy_data_response::GenerateOpponentFieldEnergyDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_main_character_health_point_data_response::GenerateOpponentMainCharacterHealthPointDataResponse;
@@ -65,6 +67,8 @@ use crate::ui_data_generator::service::response::generate_opponent_specific_unit
 use crate::ui_data_generator::service::response::generate_opponent_specific_unit_harmful_effect_data_response::GenerateOpponentSpecificUnitHarmfulEffectDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_specific_unit_health_point_data_response::GenerateOpponentSpecificUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_search_my_deck_data_response::GenerateSearchMyDeckDataResponse;
+use crate::ui_data_generator::service::request::generate_game_event_reaction_data_request::GenerateGameEventReactionDataRequest;
+use crate::ui_data_generator::service::response::generate_game_event_reaction_data_response::GenerateGameEventReactionDataResponse;
 
 #[async_trait]
 pub trait UiDataGeneratorService {
@@ -185,4 +189,10 @@ pub trait UiDataGeneratorService {
     async fn generate_my_specific_unit_passive_skill_use_data(
         &mut self, generate_my_specific_unit_passive_skill_use_data_request: GenerateMySpecificUnitPassiveSkillUseDataRequest)
         -> GenerateMySpecificUnitPassiveSkillUseDataResponse;
+    async fn generate_opponent_trap_card_trigger_data(
+        &mut self, generate_opponent_trap_card_trigger_data_request: GenerateOpponentTrapCardTriggerDataRequest)
+        -> GenerateOpponentTrapCardTriggerDataResponse;
+    async fn generate_game_event_reaction_data(
+        &mut self, generate_game_event_reaction_data_request: GenerateGameEventReactionDataRequest)
+        -> GenerateGameEventReactionDataResponse;
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
@@ -11,6 +12,8 @@ use crate::ui_data_generator::service::request::generate_my_specific_unit_energy
 use crate::ui_data_generator::service::request::generate_my_field_energy_data_request::{GenerateMyFieldEnergyDataRequest};
 use crate::ui_data_generator::service::request::generate_opponent_specific_unit_death_data_request::{GenerateOpponentSpecificUnitDeathDataRequest};
 use crate::ui_data_generator::service::request::generate_use_my_hand_card_data_request::GenerateUseMyHandCardDataRequest;
+use crate::ui_data_generator::service::request::generate_game_event_reaction_data_request::GenerateGameEventReactionDataRequest;
+use crate::ui_data_generator::service::response::generate_game_event_reaction_data_response::GenerateGameEventReactionDataResponse;
 use crate::ui_data_generator::service::request::generate_use_my_deck_card_list_data_request::{GenerateUseMyDeckCardListDataRequest};
 use crate::ui_data_generator::service::request::generate_draw_my_deck_data_request::{GenerateDrawMyDeckDataRequest};
 use crate::ui_data_generator::service::request::generate_draw_opponent_deck_data_request::GenerateDrawOpponentDeckDataRequest;
@@ -27,6 +30,7 @@ use crate::ui_data_generator::service::request::generate_my_specific_unit_extra_
 use crate::ui_data_generator::service::request::generate_my_specific_unit_harmful_effect_data_request::GenerateMySpecificUnitHarmfulEffectDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_health_point_data_request::GenerateMySpecificUnitHealthPointDataRequest;
 use crate::ui_data_generator::service::request::generate_my_specific_unit_passive_skill_use_data_request::GenerateMySpecificUnitPassiveSkillUseDataRequest;
+use crate::ui_data_generator::service::request::generate_opponent_trap_card_trigger_data_request::GenerateOpponentTrapCardTriggerDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_deck_card_lost_data_request::GenerateOpponentDeckCardLostDataRequest;
 use crate::ui_data_generator::service::request::generate_opponent_field_energy_data_request::{GenerateOpponentFieldEnergyDataRequest};
 use crate::ui_data_generator::service::request::generate_opponent_main_character_health_point_data_request::GenerateOpponentMainCharacterHealthPointDataRequest;
@@ -60,6 +64,7 @@ use crate::ui_data_generator::service::response::generate_my_specific_unit_extra
 use crate::ui_data_generator::service::response::generate_my_specific_unit_harmful_effect_data_response::GenerateMySpecificUnitHarmfulEffectDataResponse;
 use crate::ui_data_generator::service::response::generate_my_specific_unit_health_point_data_response::GenerateMySpecificUnitHealthPointDataResponse;
 use crate::ui_data_generator::service::response::generate_my_specific_unit_passive_skill_use_data_response::GenerateMySpecificUnitPassiveSkillUseDataResponse;
+use crate::ui_data_generator::service::response::generate_opponent_trap_card_trigger_data_response::GenerateOpponentTrapCardTriggerDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_deck_card_lost_data_response::GenerateOpponentDeckCardLostDataResponse;
 use crate::ui_data_generator::service::response::generate_opponent_field_energy_data_response::{GenerateOpponentFieldEnergyDataResponse};
 use crate::ui_data_generator::service::response::generate_opponent_main_character_health_point_data_response::GenerateOpponentMainCharacterHealthPointDataResponse;
@@ -909,4 +914,92 @@ impl UiDataGeneratorService for UiDataGeneratorServiceImpl {
             info_tuple.0.get_player_field_unit_attack_map().clone(),
             info_tuple.1.get_player_field_unit_attack_map().clone())
     }
+    async fn generate_opponent_trap_card_trigger_data(
+        &mut self, generate_opponent_trap_card_trigger_data_request: GenerateOpponentTrapCardTriggerDataRequest)
+        -> GenerateOpponentTrapCardTriggerDataResponse {
+
+        println!("UiDataGeneratorServiceImpl: generate_opponent_trap_card_trigger_data()");
+
+        let mut ui_data_generator_repository_guard =
+            self.ui_data_generator_repository.lock().await;
+
+        let info_tuple =
+            ui_data_generator_repository_guard.generate_opponent_trap_card_trigger_data(
+                generate_opponent_trap_card_trigger_data_request.get_triggered_trap_card_id()).await;
+
+        drop(ui_data_generator_repository_guard);
+
+        GenerateOpponentTrapCardTriggerDataResponse::new(
+            info_tuple.0.get_player_hand_card_use_map().clone(),
+            info_tuple.1.get_player_hand_card_use_map().clone())
+    }
+
+    // 이벤트 반응으로 체력이 바뀌거나 사망한 양측 유닛 정보를 한 번에 생성 (변화가 없는 쪽은 제외)
+    async fn generate_game_event_reaction_data(
+        &mut self, generate_game_event_reaction_data_request: GenerateGameEventReactionDataRequest)
+        -> GenerateGameEventReactionDataResponse {
+
+        println!("UiDataGeneratorServiceImpl: generate_game_event_reaction_data()");
+
+        let my_unit_health_point_tuple_list =
+            generate_game_event_reaction_data_request.get_my_unit_health_point_tuple_list();
+        let opponent_unit_health_point_tuple_list =
+            generate_game_event_reaction_data_request.get_opponent_unit_health_point_tuple_list();
+        let my_dead_unit_index_list =
+            generate_game_event_reaction_data_request.get_my_dead_unit_index_list();
+        let opponent_dead_unit_index_list =
+            generate_game_event_reaction_data_request.get_opponent_dead_unit_index_list();
+
+        let mut player_field_unit_health_point_map_for_response = HashMap::new();
+        let mut player_field_unit_health_point_map_for_notice = HashMap::new();
+        let mut player_field_unit_death_map_for_response = HashMap::new();
+        let mut player_field_unit_death_map_for_notice = HashMap::new();
+
+        let mut ui_data_generator_repository_guard =
+            self.ui_data_generator_repository.lock().await;
+
+        if !my_unit_health_point_tuple_list.is_empty() {
+            let info_tuple =
+                ui_data_generator_repository_guard.generate_my_multiple_unit_health_point_data(
+                    my_unit_health_point_tuple_list).await;
+
+            player_field_unit_health_point_map_for_response.extend(info_tuple.0.get_player_field_unit_health_point_map().clone());
+            player_field_unit_health_point_map_for_notice.extend(info_tuple.1.get_player_field_unit_health_point_map().clone());
+        }
+
+        if !opponent_unit_health_point_tuple_list.is_empty() {
+            let info_tuple =
+                ui_data_generator_repository_guard.generate_opponent_multiple_unit_health_point_data(
+                    opponent_unit_health_point_tuple_list).await;
+
+            player_field_unit_health_point_map_for_response.extend(info_tuple.0.get_player_field_unit_health_point_map().clone());
+            player_field_unit_health_point_map_for_notice.extend(info_tuple.1.get_player_field_unit_health_point_map().clone());
+        }
+
+        if !my_dead_unit_index_list.is_empty() {
+            let info_tuple =
+                ui_data_generator_repository_guard.generate_my_multiple_unit_death_data(
+                    my_dead_unit_index_list).await;
+
+            player_field_unit_death_map_for_response.extend(info_tuple.0.get_player_field_unit_death_map().clone());
+            player_field_unit_death_map_for_notice.extend(info_tuple.1.get_player_field_unit_death_map().clone());
+        }
+
+        if !opponent_dead_unit_index_list.is_empty() {
+            let info_tuple =
+                ui_data_generator_repository_guard.generate_opponent_multiple_unit_death_data(
+                    opponent_dead_unit_index_list).await;
+
+            player_field_unit_death_map_for_response.extend(info_tuple.0.get_player_field_unit_death_map().clone());
+            player_field_unit_death_map_for_notice.extend(info_tuple.1.get_player_field_unit_death_map().clone());
+        }
+
+        drop(ui_data_generator_repository_guard);
+
+        GenerateGameEventReactionDataResponse::new(
+            player_field_unit_health_point_map_for_response,
+            player_field_unit_health_point_map_for_notice,
+            player_field_unit_death_map_for_response,
+            player_field_unit_death_map_for_notice)
+    }
 }
\ No newline at end of file